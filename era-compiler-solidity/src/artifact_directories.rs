@@ -0,0 +1,135 @@
+//!
+//! The per-source artifact directories of the output directory.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::path::Component;
+use std::path::Path;
+use std::path::PathBuf;
+
+///
+/// The per-source artifact directories of the output directory.
+///
+/// Artifacts of a source are written to the directory named after its file name, as `solc` does.
+/// If several sources share the file name, e.g. `a/Token.sol` and `b/Token.sol`, their artifacts
+/// are written to directories named after their relative paths instead, so they do not overwrite each other.
+///
+#[derive(Debug, Default)]
+pub struct ArtifactDirectories {
+    /// The relative artifact directories, keyed by source path.
+    pub directories: BTreeMap<String, PathBuf>,
+    /// The source paths sharing the file name, keyed by the file name.
+    pub collisions: BTreeMap<String, BTreeSet<String>>,
+}
+
+impl ArtifactDirectories {
+    /// The directory replacing the `..` components of source paths.
+    pub const PARENT_DIRECTORY: &'static str = "__parent__";
+
+    /// The directory replacing the root of absolute source paths.
+    pub const ROOT_DIRECTORY: &'static str = "__root__";
+
+    ///
+    /// Assigns the artifact directories to the source `paths`.
+    ///
+    pub fn new<'a>(paths: impl IntoIterator<Item = &'a str>) -> Self {
+        let mut file_names: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for path in paths.into_iter() {
+            file_names
+                .entry(Self::file_name(path))
+                .or_default()
+                .insert(path.to_owned());
+        }
+
+        let mut result = Self::default();
+        for (file_name, paths) in file_names.into_iter() {
+            if paths.len() == 1 {
+                let path = paths.into_iter().next().expect("Always exists");
+                result.directories.insert(path, PathBuf::from(file_name));
+                continue;
+            }
+
+            for path in paths.iter() {
+                result
+                    .directories
+                    .insert(path.to_owned(), Self::relative_path(path));
+            }
+            result.collisions.insert(file_name, paths);
+        }
+        result
+    }
+
+    ///
+    /// Returns the source `path` relative to the output directory.
+    ///
+    /// The `.` components are removed. The `..` components, the root, and the prefix are replaced
+    /// with the `__parent__`, `__root__`, and `__<prefix>__` components respectively, so sources
+    /// outside of the base path, e.g. `../Token.sol` and `Token.sol`, do not share the directory.
+    ///
+    pub fn relative_path(path: &str) -> PathBuf {
+        Path::new(path)
+            .components()
+            .filter_map(|component| match component {
+                Component::Normal(component) => Some(component.to_owned()),
+                Component::CurDir => None,
+                Component::ParentDir => Some(OsString::from(Self::PARENT_DIRECTORY)),
+                Component::RootDir => Some(OsString::from(Self::ROOT_DIRECTORY)),
+                Component::Prefix(prefix) => Some(OsString::from(format!(
+                    "__{}__",
+                    prefix
+                        .as_os_str()
+                        .to_string_lossy()
+                        .chars()
+                        .filter(char::is_ascii_alphanumeric)
+                        .collect::<String>()
+                ))),
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the relative artifact directory of the source at `path`.
+    ///
+    /// # Panics
+    /// If the source has not been passed to the constructor.
+    ///
+    pub fn get(&self, path: &str) -> &Path {
+        self.directories.get(path).expect("Always exists").as_path()
+    }
+
+    ///
+    /// Returns the warnings listing the sources sharing the file name.
+    ///
+    pub fn warnings(&self) -> Vec<era_solc::StandardJsonOutputError> {
+        self.collisions
+            .iter()
+            .map(|(file_name, paths)| {
+                era_solc::StandardJsonOutputError::new_warning(
+                    format!(
+                        "Sources {} share the file name `{file_name}`, so their artifacts are written to directories named after their full paths.",
+                        paths
+                            .iter()
+                            .map(|path| format!("`{path}`"))
+                            .collect::<Vec<String>>()
+                            .join(", "),
+                    ),
+                    None,
+                    None,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the file name of the source at `path`.
+    ///
+    fn file_name(path: &str) -> String {
+        Path::new(path)
+            .file_name()
+            .expect("Always exists")
+            .to_string_lossy()
+            .to_string()
+    }
+}
@@ -0,0 +1,47 @@
+//!
+//! The output artifact format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The output artifact format.
+///
+/// Replaces the separate per-output files written to the output directory with one artifact JSON
+/// per contract in the layout expected by a third-party toolchain.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArtifactFormat {
+    /// The Hardhat artifact schema, as consumed by the ZKsync Hardhat plugin.
+    Hardhat,
+    /// The Foundry artifact schema, as consumed by `forge` and `foundry-zksync`.
+    Foundry,
+}
+
+impl FromStr for ArtifactFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "hardhat" => Ok(Self::Hardhat),
+            "foundry" => Ok(Self::Foundry),
+            string => anyhow::bail!(
+                "Invalid artifact format: `{string}`. Available options: {}.",
+                [Self::Hardhat, Self::Foundry]
+                    .into_iter()
+                    .map(|format| format.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for ArtifactFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Hardhat => write!(f, "hardhat"),
+            Self::Foundry => write!(f, "foundry"),
+        }
+    }
+}
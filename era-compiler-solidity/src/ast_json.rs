@@ -0,0 +1,55 @@
+//!
+//! The source AST output of the basic CLI mode.
+//!
+
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::path::Path;
+
+use crate::artifact_directories::ArtifactDirectories;
+
+///
+/// Writes the `ast_jsons` to the terminal in the `solc --ast-compact-json` format.
+///
+pub fn write_to_terminal(ast_jsons: BTreeMap<String, serde_json::Value>) -> anyhow::Result<()> {
+    for (path, ast_json) in ast_jsons.into_iter() {
+        writeln!(
+            std::io::stdout(),
+            "\n======= {path} =======\nJSON AST (compact format):\n{ast_json}"
+        )?;
+    }
+    Ok(())
+}
+
+///
+/// Writes the `ast_jsons` to `<file name>_json.ast` files in the artifact directories of their
+/// sources, so the ASTs of sources sharing the file name do not overwrite each other.
+///
+pub fn write_to_directory(
+    ast_jsons: BTreeMap<String, serde_json::Value>,
+    output_directory: &Path,
+    artifact_directories: &ArtifactDirectories,
+    overwrite: bool,
+) -> anyhow::Result<()> {
+    for (path, ast_json) in ast_jsons.into_iter() {
+        let file_name = Path::new(path.as_str())
+            .file_name()
+            .expect("Always exists")
+            .to_string_lossy()
+            .to_string();
+
+        let mut output_path = output_directory.to_owned();
+        output_path.push(artifact_directories.get(path.as_str()));
+        std::fs::create_dir_all(output_path.as_path())?;
+        output_path.push(format!("{file_name}_json.ast"));
+
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+        std::fs::write(output_path.as_path(), ast_json.to_string().as_bytes())
+            .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+    }
+    Ok(())
+}
@@ -0,0 +1,125 @@
+//!
+//! The `BALANCE` and `SELFBALANCE` lowering.
+//!
+
+use inkwell::types::BasicType;
+
+use era_compiler_llvm_context::IContext;
+
+/// The name of the shared balance query routine.
+pub const FUNCTION_NAME: &str = "__balance";
+
+///
+/// Prepares the module of `context` for the translation with `strategy`.
+///
+/// With the shared strategy, the shared balance query routine is declared in advance, so the
+/// strategy is carried by the module itself, and the balance queries are translated into its
+/// calls. Its body is only defined once it is called, or by [`finalize`] otherwise.
+///
+pub fn declare<D>(
+    context: &mut era_compiler_llvm_context::EraVMContext<D>,
+    strategy: era_solc::StandardJsonInputBalanceLowering,
+) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    if let era_solc::StandardJsonInputBalanceLowering::Inline = strategy {
+        return Ok(());
+    }
+
+    let function_type =
+        context.function_type(vec![context.field_type().as_basic_type_enum()], 1, false);
+    let function = context.add_function(
+        FUNCTION_NAME,
+        function_type,
+        1,
+        Some(inkwell::module::Linkage::Private),
+    )?;
+    era_compiler_llvm_context::EraVMFunction::set_attributes(
+        context.llvm(),
+        function.borrow().declaration().value,
+        vec![(era_compiler_llvm_context::Attribute::NoInline, None)],
+        true,
+    );
+    Ok(())
+}
+
+///
+/// Translates the balance query of `address` according to the strategy the module has been
+/// prepared with by [`declare`].
+///
+pub fn balance<'ctx, D>(
+    context: &mut era_compiler_llvm_context::EraVMContext<'ctx, D>,
+    address: inkwell::values::IntValue<'ctx>,
+) -> anyhow::Result<inkwell::values::BasicValueEnum<'ctx>>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    let Some(function) = context.get_function(FUNCTION_NAME) else {
+        return era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address);
+    };
+
+    finalize(context)?;
+    let declaration = function.borrow().declaration();
+    let balance = context
+        .build_call(declaration, &[address.into()], "balance")?
+        .expect("Always exists");
+    Ok(balance)
+}
+
+///
+/// Defines the body of the shared balance query routine, if it has been declared and is not
+/// defined yet.
+///
+/// Must be called after the translation, so the module is valid even if the routine is unused.
+///
+pub fn finalize<D>(context: &mut era_compiler_llvm_context::EraVMContext<D>) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    let Some(function) = context.get_function(FUNCTION_NAME) else {
+        return Ok(());
+    };
+    if function.borrow().entry_block().get_terminator().is_some() {
+        return Ok(());
+    }
+
+    define(context)
+}
+
+///
+/// Defines the body of the shared balance query routine.
+///
+/// The routine is not inlined, so the system contract call sequence is only emitted once.
+///
+fn define<D>(context: &mut era_compiler_llvm_context::EraVMContext<D>) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    let caller_name = context.current_function().borrow().name().to_owned();
+    let caller_block = context.basic_block();
+
+    context.set_current_function(FUNCTION_NAME)?;
+    context.set_basic_block(context.current_function().borrow().entry_block());
+    let address = context
+        .current_function()
+        .borrow()
+        .get_nth_param(0)
+        .into_int_value();
+    let balance = era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address)?;
+    let era_compiler_llvm_context::FunctionReturn::Primitive { pointer } =
+        context.current_function().borrow().r#return()
+    else {
+        anyhow::bail!("The shared balance routine must return a single value");
+    };
+    context.build_store(pointer, balance)?;
+    context.build_unconditional_branch(context.current_function().borrow().return_block())?;
+
+    context.set_basic_block(context.current_function().borrow().return_block());
+    let return_value = context.build_load(pointer, "return_value")?;
+    context.build_return(Some(&return_value))?;
+
+    context.set_current_function(caller_name.as_str())?;
+    context.set_basic_block(caller_block);
+    Ok(())
+}
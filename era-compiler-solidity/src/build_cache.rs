@@ -0,0 +1,171 @@
+//!
+//! The EraVM contract build cache.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+
+use crate::build_eravm::contract::Contract as EraVMContractBuild;
+
+///
+/// The EraVM contract build cache.
+///
+/// The builds are stored as JSON files named after the hash of the contract full path, and the
+/// content of its source and all sources it imports according to the import graph. Therefore,
+/// editing a source only invalidates the contracts defined in it and in the sources depending on it.
+/// The hash also covers the compiler versions and the settings affecting the builds.
+///
+/// The builds are cached before linking, so changing library addresses does not invalidate them.
+/// The least recently used entries are removed once the cache exceeds its size limit.
+///
+#[derive(Debug)]
+pub struct BuildCache {
+    /// The cache directory.
+    directory: era_solc::CacheDirectory,
+    /// The source keys, keyed by source path.
+    keys: BTreeMap<String, String>,
+    /// The number of builds taken from the cache.
+    hits: AtomicUsize,
+    /// The number of builds missing from the cache.
+    misses: AtomicUsize,
+}
+
+impl BuildCache {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// `settings` must be the serialized compiler versions and settings affecting the builds,
+    /// as returned by [`Self::settings`].
+    ///
+    pub fn new(
+        directory: &Path,
+        settings: &str,
+        import_graph: &era_solc::StandardJsonOutputImportGraph,
+        sources: &BTreeMap<String, era_solc::StandardJsonInputSource>,
+    ) -> Self {
+        let content_hashes: BTreeMap<&str, String> = sources
+            .iter()
+            .filter_map(|(path, source)| {
+                let hash = era_compiler_common::Hash::keccak256(source.content()?.as_bytes());
+                Some((path.as_str(), hex::encode(hash.as_bytes())))
+            })
+            .collect();
+
+        let mut keys = BTreeMap::new();
+        for path in import_graph.sources.keys() {
+            let mut preimage = settings.to_owned();
+            let mut is_complete = true;
+            for path in import_graph.closure(path.as_str()).into_iter() {
+                match content_hashes.get(path.as_str()) {
+                    Some(hash) => preimage.push_str(format!("\0{path}\0{hash}").as_str()),
+                    None => {
+                        is_complete = false;
+                        break;
+                    }
+                }
+            }
+            if is_complete {
+                let key = era_compiler_common::Hash::keccak256(preimage.as_bytes());
+                keys.insert(path.to_owned(), hex::encode(key.as_bytes()));
+            }
+        }
+
+        Self {
+            directory: era_solc::CacheDirectory::new(
+                directory,
+                era_solc::cache::MAX_ENTRIES_DEFAULT,
+            ),
+            keys,
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        }
+    }
+
+    ///
+    /// Serializes the compiler versions and the settings affecting the builds.
+    ///
+    /// The `solc` settings are taken as a whole, except for the output selection, so changing any
+    /// of them, e.g. the EVM version, the `solc` optimizer, or the metadata settings, invalidates
+    /// the builds. `extra` must contain the zksolc settings affecting the builds, which are not
+    /// passed to `solc`, in their resolved form.
+    ///
+    /// Used for the build cache keys and the dependency manifest settings stamp.
+    ///
+    pub fn settings<T>(
+        solc_version: &era_solc::Version,
+        solc_settings: &era_solc::StandardJsonInputSettings,
+        extra: T,
+    ) -> String
+    where
+        T: serde::Serialize,
+    {
+        let mut solc_settings = serde_json::to_value(solc_settings).expect("Always valid");
+        if let Some(solc_settings) = solc_settings.as_object_mut() {
+            solc_settings.remove("outputSelection");
+        }
+        serde_json::to_string(&(
+            crate::version(),
+            solc_version.long.as_str(),
+            solc_settings,
+            extra,
+        ))
+        .expect("Always valid")
+    }
+
+    ///
+    /// Returns the cached build of the contract at `full_path`, if the entry exists and is valid.
+    ///
+    pub fn get(&self, full_path: &str) -> Option<EraVMContractBuild> {
+        let build = self
+            .entry_key(full_path)
+            .and_then(|key| self.directory.get(key.as_str()));
+        let counter = if build.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        build
+    }
+
+    ///
+    /// Caches the build of the contract at `full_path`.
+    ///
+    /// Caching is best-effort, so write errors are ignored, and the contract is simply
+    /// recompiled on the next run.
+    ///
+    pub fn insert(&self, full_path: &str, build: &EraVMContractBuild) {
+        if let Some(key) = self.entry_key(full_path) {
+            self.directory.insert(key.as_str(), build);
+        }
+    }
+
+    ///
+    /// Removes the least recently used entries exceeding the cache size limit.
+    ///
+    pub fn evict(&self) {
+        self.directory.evict();
+    }
+
+    ///
+    /// Returns the numbers of builds taken from the cache and missing from it so far.
+    ///
+    pub fn statistics(&self) -> era_solc::StandardJsonOutputSummaryCache {
+        era_solc::StandardJsonOutputSummaryCache::new(
+            self.hits.load(Ordering::Relaxed),
+            self.misses.load(Ordering::Relaxed),
+        )
+    }
+
+    ///
+    /// Returns the cache entry key, if the contract sources are known.
+    ///
+    fn entry_key(&self, full_path: &str) -> Option<String> {
+        let (path, _name) = full_path.rsplit_once(':')?;
+        let preimage = format!("{}\0{full_path}", self.keys.get(path)?);
+        let key = era_compiler_common::Hash::keccak256(preimage.as_bytes());
+        Some(hex::encode(key.as_bytes()))
+    }
+}
@@ -0,0 +1,123 @@
+//!
+//! The build configuration.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::time::Instant;
+
+use crate::events::Subscribers;
+use crate::limits::Limits;
+
+///
+/// The build configuration, shared by all contracts of a build.
+///
+/// Carries the settings which are not part of the compilation input, but affect how the build
+/// is run, so that several builds with different settings can run in the same process.
+///
+#[derive(Debug, Default, Clone)]
+pub struct BuildConfig {
+    /// The project size limits, which are not enforced if unset.
+    pub limits: Option<Limits>,
+    /// The build deadline, after which the remaining contracts are skipped.
+    pub deadline: Option<Instant>,
+    /// The compilation and map iteration order shuffling seed, which enables the shuffling if set.
+    pub shuffle_seed: Option<u64>,
+    /// Whether the debug assertions are enabled for all contracts.
+    pub debug_assertions: bool,
+    /// Whether each contract is compiled in a subprocess of [`EXECUTABLE`](crate::EXECUTABLE),
+    /// instead of the current process.
+    pub subprocesses: bool,
+    /// The subscribers notified of the compiler events of the build.
+    pub subscribers: Subscribers,
+    /// The SARIF log the diagnostics are deferred to in the basic CLI mode, if the SARIF error
+    /// format is enabled.
+    pub sarif: Option<era_solc::StandardJsonOutputErrorSarifDeferred>,
+    /// The source unit name of the source read from the standard input, if specified.
+    pub stdin_name: Option<String>,
+    /// Whether only the phases populating the caches are run: `solc` with the AST cache, and the
+    /// contract compilation if the build cache is enabled. Linking, reports, and artifacts are skipped.
+    pub cache_warming: bool,
+    /// The directory the subprocesses are pointed to for their intermediate files, if set.
+    pub temp_dir: Option<PathBuf>,
+    /// The maximum nesting depth of the Yul and EVM legacy assembly inputs, if it differs from
+    /// the default one.
+    pub max_nesting_depth: Option<usize>,
+}
+
+impl BuildConfig {
+    ///
+    /// Checks the number and the total size of the source files at `paths` against the limits,
+    /// if they are set.
+    ///
+    pub fn check_paths(&self, paths: &[PathBuf]) -> anyhow::Result<()> {
+        match self.limits {
+            Some(ref limits) => limits.check_paths(paths),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks the number and the total size of `sources` against the limits, if they are set.
+    ///
+    pub fn check_sources(
+        &self,
+        sources: &BTreeMap<String, era_solc::StandardJsonInputSource>,
+    ) -> anyhow::Result<()> {
+        match self.limits {
+            Some(ref limits) => limits.check_sources(sources),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Checks the number and the total size of the standard JSON input `sources` against the limits,
+    /// if they are set.
+    ///
+    /// The violation is pushed to `messages`, so it is reported in the standard JSON output.
+    /// Returns whether the sources are within the limits.
+    ///
+    pub fn check_standard_json_sources(
+        &self,
+        sources: &BTreeMap<String, era_solc::StandardJsonInputSource>,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    ) -> bool {
+        let Err(error) = self.check_sources(sources) else {
+            return true;
+        };
+        messages.push(
+            era_solc::StandardJsonOutputError::new_error(error.to_string(), None, None)
+                .with_exit_code(era_solc::ExitCode::from(&error)),
+        );
+        false
+    }
+
+    ///
+    /// Checks the number of `contracts` against the limits, if they are set.
+    ///
+    pub fn check_contracts(&self, contracts: usize) -> anyhow::Result<()> {
+        match self.limits {
+            Some(ref limits) => limits.check_contracts(contracts),
+            None => Ok(()),
+        }
+    }
+
+    ///
+    /// Returns the maximum nesting depth of the Yul and EVM legacy assembly inputs.
+    ///
+    /// Unlike the project size limits, the depth is always limited, since the parsers would
+    /// overflow the stack on deeper inputs.
+    ///
+    pub fn nesting_depth_limit(&self) -> usize {
+        self.max_nesting_depth
+            .unwrap_or(era_yul::yul::lexer::Lexer::DEFAULT_MAX_DEPTH)
+    }
+
+    ///
+    /// Whether the build deadline has passed.
+    ///
+    pub fn is_deadline_exceeded(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
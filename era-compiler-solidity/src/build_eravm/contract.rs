@@ -2,12 +2,20 @@
 //! The Solidity contract build.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::collections::HashMap;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::artifact_format::ArtifactFormat;
+use crate::disassembly::Disassembly;
+use crate::interface_freeze::Interface;
+
+use super::foundry_artifact::FoundryArtifact;
+use super::foundry_artifact::FoundryArtifactBytecode;
+use super::hardhat_artifact::HardhatArtifact;
+
 ///
 /// The Solidity contract build.
 ///
@@ -19,15 +27,22 @@ pub struct Contract {
     pub build: era_compiler_llvm_context::EraVMBuild,
     /// The metadata JSON.
     pub metadata_json: serde_json::Value,
+    /// The ABI JSON, only set if requested.
+    pub abi_json: Option<serde_json::Value>,
     /// The unlinked missing libraries.
     pub missing_libraries: BTreeSet<String>,
     /// The unresolved factory dependencies.
     pub factory_dependencies: BTreeSet<String>,
     /// The resolved factory dependencies.
     pub factory_dependencies_resolved:
-        HashMap<[u8; era_compiler_common::BYTE_LENGTH_FIELD], String>,
+        BTreeMap<[u8; era_compiler_common::BYTE_LENGTH_FIELD], String>,
     /// The binary object format.
     pub object_format: era_compiler_common::ObjectFormat,
+    /// The deployment payload summary, only available after linking.
+    pub deployment_payload: Option<era_solc::StandardJsonOutputContractDeploymentPayload>,
+    /// The backend compilation time, only set if the contract has been compiled in this run.
+    #[serde(skip)]
+    pub duration: Option<std::time::Duration>,
 }
 
 impl Contract {
@@ -46,10 +61,13 @@ impl Contract {
             name,
             build,
             metadata_json,
+            abi_json: None,
             missing_libraries,
             factory_dependencies,
-            factory_dependencies_resolved: HashMap::new(),
+            factory_dependencies_resolved: BTreeMap::new(),
             object_format,
+            deployment_payload: None,
+            duration: None,
         }
     }
 
@@ -61,6 +79,8 @@ impl Contract {
         path: String,
         output_metadata: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
     ) -> anyhow::Result<()> {
         writeln!(std::io::stdout(), "\n======= {path} =======",)?;
         if let Some(assembly) = self.build.assembly {
@@ -69,6 +89,16 @@ impl Contract {
         if output_metadata {
             writeln!(std::io::stdout(), "Metadata:\n{}", self.metadata_json)?;
         }
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_abi) {
+            writeln!(std::io::stdout(), "ABI:\n{abi_json}")?;
+        }
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_hashes) {
+            write!(
+                std::io::stdout(),
+                "Function signatures:\n{}",
+                Interface::from_abi(abi_json).function_signatures()
+            )?;
+        }
         if output_binary {
             writeln!(
                 std::io::stdout(),
@@ -83,13 +113,18 @@ impl Contract {
     ///
     /// Writes the contract text assembly and bytecode to files.
     ///
+    /// Returns the paths of the written files.
+    ///
     pub fn write_to_directory(
         self,
         output_path: &Path,
+        directory: &Path,
         output_metadata: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
         overwrite: bool,
-    ) -> anyhow::Result<()> {
+    ) -> anyhow::Result<Vec<PathBuf>> {
         let file_path = PathBuf::from(self.name.path);
         let file_name = file_path
             .file_name()
@@ -98,9 +133,11 @@ impl Contract {
             .expect("Always valid");
 
         let mut output_path = output_path.to_owned();
-        output_path.push(file_name);
+        output_path.push(directory);
         std::fs::create_dir_all(output_path.as_path())?;
 
+        let mut artifact_paths = Vec::new();
+
         if output_metadata {
             let output_name = format!(
                 "{}_meta.{}",
@@ -120,6 +157,7 @@ impl Contract {
                     self.metadata_json.to_string().as_bytes(),
                 )
                 .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                artifact_paths.push(output_path);
             }
         }
 
@@ -139,6 +177,52 @@ impl Contract {
             } else {
                 std::fs::write(output_path.as_path(), assembly.as_bytes())
                     .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                artifact_paths.push(output_path);
+            }
+        }
+
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_abi) {
+            let output_name = format!(
+                "{}.{}",
+                self.name.name.as_deref().unwrap_or(file_name),
+                crate::EXTENSION_ABI
+            );
+            let mut output_path = output_path.clone();
+            output_path.push(output_name.as_str());
+
+            if output_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+                );
+            } else {
+                std::fs::write(output_path.as_path(), abi_json.to_string().as_bytes())
+                    .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                artifact_paths.push(output_path);
+            }
+        }
+
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_hashes) {
+            let output_name = format!(
+                "{}.{}",
+                self.name.name.as_deref().unwrap_or(file_name),
+                crate::EXTENSION_SIGNATURES
+            );
+            let mut output_path = output_path.clone();
+            output_path.push(output_name.as_str());
+
+            if output_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+                );
+            } else {
+                std::fs::write(
+                    output_path.as_path(),
+                    Interface::from_abi(abi_json)
+                        .function_signatures()
+                        .as_bytes(),
+                )
+                .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                artifact_paths.push(output_path);
             }
         }
 
@@ -161,32 +245,148 @@ impl Contract {
                     hex::encode(self.build.bytecode.as_slice()).as_bytes(),
                 )
                 .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+                artifact_paths.push(output_path);
             }
         }
 
-        Ok(())
+        Ok(artifact_paths)
+    }
+
+    ///
+    /// Writes the contract artifact in the specified `format` to `<contract name>.json` in
+    /// `directory` inside `output_directory`.
+    ///
+    /// Returns the path of the written file.
+    ///
+    pub fn write_artifact(
+        self,
+        output_directory: &Path,
+        directory: &Path,
+        format: ArtifactFormat,
+        overwrite: bool,
+    ) -> anyhow::Result<PathBuf> {
+        let contract_name = match self.name.name {
+            Some(ref name) => name.to_owned(),
+            None => Path::new(self.name.path.as_str())
+                .file_stem()
+                .expect("Always exists")
+                .to_string_lossy()
+                .into_owned(),
+        };
+
+        let mut output_path = output_directory.to_owned();
+        output_path.push(directory);
+        std::fs::create_dir_all(output_path.as_path())?;
+        output_path.push(format!(
+            "{contract_name}.{}",
+            era_compiler_common::EXTENSION_JSON
+        ));
+        if output_path.exists() && !overwrite {
+            anyhow::bail!(
+                "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+            );
+        }
+
+        let unlinked_libraries = self.unlinked_libraries();
+        let link_references = self.link_references(unlinked_libraries.as_slice());
+        let bytecode =
+            era_solc::StandardJsonOutputContractEVMBytecode::new(self.build.bytecode, None, None)
+                .with_unlinked_libraries(link_references);
+        let object = format!("0x{}", bytecode.object);
+        let abi = self
+            .abi_json
+            .unwrap_or_else(|| serde_json::Value::Array(vec![]));
+        let factory_dependencies = self
+            .factory_dependencies_resolved
+            .into_iter()
+            .map(|(hash, path)| (hex::encode(hash), path))
+            .collect();
+
+        let artifact = match format {
+            ArtifactFormat::Hardhat => serde_json::to_vec_pretty(&HardhatArtifact {
+                format: HardhatArtifact::FORMAT.to_owned(),
+                contract_name,
+                source_name: self.name.path,
+                abi,
+                bytecode: object.clone(),
+                deployed_bytecode: object,
+                link_references: bytecode.link_references.clone(),
+                deployed_link_references: bytecode.link_references,
+                factory_deps: factory_dependencies,
+            }),
+            ArtifactFormat::Foundry => {
+                let method_identifiers = Interface::from_abi(&abi)
+                    .functions
+                    .into_iter()
+                    .map(|(selector, signature)| (signature, selector))
+                    .collect();
+                serde_json::to_vec_pretty(&FoundryArtifact {
+                    abi,
+                    bytecode: FoundryArtifactBytecode {
+                        object: object.clone(),
+                        link_references: bytecode.link_references.clone(),
+                    },
+                    deployed_bytecode: FoundryArtifactBytecode {
+                        object,
+                        link_references: bytecode.link_references,
+                    },
+                    method_identifiers,
+                    raw_metadata: self.metadata_json.to_string(),
+                    metadata: self.metadata_json,
+                    factory_dependencies,
+                })
+            }
+        }
+        .expect("Always valid");
+        std::fs::write(output_path.as_path(), artifact)
+            .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+        Ok(output_path)
     }
 
     ///
     /// Writes the contract text assembly and bytecode to the standard JSON.
     ///
+    /// The opcodes are decoded from the linked bytecode with the `target_machine` disassembler,
+    /// as an ELF object cannot be disassembled before linking.
+    ///
     pub fn write_to_standard_json(
         self,
         standard_json_contract: &mut era_solc::StandardJsonOutputContract,
+        target_machine: &era_compiler_llvm_context::TargetMachine,
     ) -> anyhow::Result<()> {
-        let bytecode = hex::encode(self.build.bytecode.as_slice());
+        let hash = self.build.bytecode_hash.map(hex::encode);
+        let opcodes = match self.object_format {
+            era_compiler_common::ObjectFormat::Raw => Some(
+                Disassembly::try_from_bytecode(target_machine, self.build.bytecode.as_slice())?
+                    .assembly()
+                    .instructions
+                    .iter()
+                    .map(|instruction| instruction.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            era_compiler_common::ObjectFormat::ELF => None,
+        };
+        let unlinked_libraries = self.unlinked_libraries();
+        let bytecode = era_solc::StandardJsonOutputContractEVMBytecode::new(
+            self.build.bytecode,
+            opcodes,
+            hash.clone(),
+        )
+        .with_unlinked_libraries(self.link_references(unlinked_libraries.as_slice()));
         let assembly = self.build.assembly;
 
         standard_json_contract.metadata = self.metadata_json;
         standard_json_contract.eravm = Some(era_solc::StandardJsonOutputContractEraVM::new(
-            bytecode.clone(),
+            bytecode.object.clone(),
             assembly.clone(),
+            self.deployment_payload,
         ));
         standard_json_contract
             .evm
             .get_or_insert_with(era_solc::StandardJsonOutputContractEVM::default)
             .modify_eravm(bytecode, assembly);
-        standard_json_contract.hash = self.build.bytecode_hash.map(hex::encode);
+        standard_json_contract.hash = hash;
         standard_json_contract
             .missing_libraries
             .extend(self.missing_libraries);
@@ -203,6 +403,109 @@ impl Contract {
         Ok(())
     }
 
+    ///
+    /// Returns the full paths of the libraries the ELF object still has to be linked with.
+    ///
+    fn unlinked_libraries(&self) -> Vec<String> {
+        match self.object_format {
+            era_compiler_common::ObjectFormat::ELF => {
+                let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+                    self.build.bytecode.as_slice(),
+                    self.name.full_path.as_str(),
+                    false,
+                );
+                memory_buffer.get_undefined_references_eravm().0
+            }
+            era_compiler_common::ObjectFormat::Raw => vec![],
+        }
+    }
+
+    ///
+    /// Returns the link references of the unlinked libraries, keyed by library full path.
+    ///
+    /// The offsets are taken from the relocations of the ELF object, so they point at the library
+    /// address fields in the emitted object. A library address may be split into several relocated
+    /// parts, named after the library symbol with the part index appended, in which case the adjacent
+    /// parts are merged into one reference. If the relocations cannot be read, the lists of offsets
+    /// are left empty.
+    ///
+    fn link_references(
+        &self,
+        unlinked_libraries: &[String],
+    ) -> BTreeMap<String, Vec<era_solc::StandardJsonOutputContractEVMBytecodeLinkReference>> {
+        let mut link_references: BTreeMap<String, Vec<_>> = unlinked_libraries
+            .iter()
+            .map(|library| (library.to_owned(), vec![]))
+            .collect();
+        if unlinked_libraries.is_empty() {
+            return link_references;
+        }
+        let relocations = match crate::linker::elf::relocations(self.build.bytecode.as_slice()) {
+            Ok(relocations) => relocations,
+            Err(_) => return link_references,
+        };
+
+        for library in unlinked_libraries.iter() {
+            let hash =
+                hex::encode(era_compiler_common::Hash::keccak256(library.as_bytes()).as_bytes());
+            let hash = &hash[..crate::linker::SOLC_PLACEHOLDER_HASH_LENGTH];
+
+            let mut parts: BTreeMap<&str, Vec<usize>> = BTreeMap::new();
+            for relocation in relocations.iter() {
+                let symbol = relocation.symbol.as_str();
+                if symbol.contains(hash) || symbol == library {
+                    parts.entry(symbol).or_default().push(relocation.offset);
+                }
+            }
+            let mut parts_count: BTreeMap<&str, usize> = BTreeMap::new();
+            for symbol in parts.keys() {
+                *parts_count
+                    .entry(Self::linker_symbol_base(symbol))
+                    .or_default() += 1;
+            }
+
+            let mut references: Vec<(usize, usize)> = Vec::new();
+            for (symbol, offsets) in parts.iter() {
+                let length = era_compiler_common::BYTE_LENGTH_ETH_ADDRESS
+                    / parts_count
+                        .get(Self::linker_symbol_base(symbol))
+                        .copied()
+                        .unwrap_or(1);
+                references.extend(offsets.iter().map(|offset| (*offset, length)));
+            }
+            references.sort_unstable();
+
+            let library_references = link_references
+                .get_mut(library.as_str())
+                .expect("Always exists");
+            for (start, length) in references.into_iter() {
+                match library_references
+                    .last_mut()
+                    .filter(|last| last.start + last.length == start)
+                {
+                    Some(last) => last.length += length,
+                    None => library_references.push(
+                        era_solc::StandardJsonOutputContractEVMBytecodeLinkReference {
+                            start,
+                            length,
+                        },
+                    ),
+                }
+            }
+        }
+        link_references
+    }
+
+    ///
+    /// Returns the linker symbol without the index of the address part it relocates, e.g. `.0`.
+    ///
+    fn linker_symbol_base(symbol: &str) -> &str {
+        match symbol.rsplit_once('.') {
+            Some((base, index)) if index.parse::<usize>().is_ok() => base,
+            _ => symbol,
+        }
+    }
+
     ///
     /// Writes the contract text assembly and bytecode to the combined JSON.
     ///
@@ -0,0 +1,45 @@
+//!
+//! The Foundry contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The Foundry contract artifact.
+///
+/// Follows the artifact schema of `forge`, so `foundry-zksync` and similar integrations can read
+/// the output directory as is, without translating the output.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryArtifact {
+    /// The ABI JSON.
+    pub abi: serde_json::Value,
+    /// The bytecode.
+    pub bytecode: FoundryArtifactBytecode,
+    /// The deployed bytecode, which is the same as the bytecode on EraVM.
+    pub deployed_bytecode: FoundryArtifactBytecode,
+    /// The function selectors, keyed by function signature.
+    pub method_identifiers: BTreeMap<String, String>,
+    /// The metadata JSON serialized to string.
+    pub raw_metadata: String,
+    /// The metadata JSON.
+    pub metadata: serde_json::Value,
+    /// The factory dependencies, keyed by bytecode hash.
+    pub factory_dependencies: BTreeMap<String, String>,
+}
+
+///
+/// The Foundry contract artifact bytecode.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FoundryArtifactBytecode {
+    /// The `0x`-prefixed bytecode.
+    pub object: String,
+    /// The unlinked library references, keyed by source name and library name.
+    pub link_references: BTreeMap<
+        String,
+        BTreeMap<String, Vec<era_solc::StandardJsonOutputContractEVMBytecodeLinkReference>>,
+    >,
+}
@@ -0,0 +1,46 @@
+//!
+//! The Hardhat contract artifact.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The Hardhat contract artifact.
+///
+/// Follows the format of the artifacts of the ZKsync Hardhat plugin, so the plugin can use the
+/// output directory as is, without restructuring the output.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HardhatArtifact {
+    /// The artifact format.
+    #[serde(rename = "_format")]
+    pub format: String,
+    /// The contract name.
+    pub contract_name: String,
+    /// The source name.
+    pub source_name: String,
+    /// The ABI JSON.
+    pub abi: serde_json::Value,
+    /// The `0x`-prefixed bytecode.
+    pub bytecode: String,
+    /// The `0x`-prefixed deployed bytecode, which is the same as the bytecode on EraVM.
+    pub deployed_bytecode: String,
+    /// The unlinked library references, keyed by source name and library name.
+    pub link_references: BTreeMap<
+        String,
+        BTreeMap<String, Vec<era_solc::StandardJsonOutputContractEVMBytecodeLinkReference>>,
+    >,
+    /// The unlinked library references of the deployed bytecode.
+    pub deployed_link_references: BTreeMap<
+        String,
+        BTreeMap<String, Vec<era_solc::StandardJsonOutputContractEVMBytecodeLinkReference>>,
+    >,
+    /// The factory dependencies, keyed by bytecode hash.
+    pub factory_deps: BTreeMap<String, String>,
+}
+
+impl HardhatArtifact {
+    /// The artifact format identifier.
+    pub const FORMAT: &'static str = "hh-zksolc-artifact-1";
+}
@@ -3,9 +3,12 @@
 //!
 
 pub mod contract;
+pub mod foundry_artifact;
+pub mod hardhat_artifact;
 
 use std::collections::BTreeMap;
-use std::collections::HashMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
 use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
@@ -14,6 +17,10 @@ use normpath::PathExt;
 
 use era_solc::CollectableError;
 
+use crate::artifact_directories::ArtifactDirectories;
+use crate::artifact_format::ArtifactFormat;
+use crate::depfile::Depfile;
+
 use self::contract::Contract;
 
 ///
@@ -25,19 +32,32 @@ pub struct Build {
     pub results: BTreeMap<String, Result<Contract, era_solc::StandardJsonOutputError>>,
     /// The additional message to output.
     pub messages: Vec<era_solc::StandardJsonOutputError>,
+    /// The source ASTs, only set if requested.
+    pub ast_jsons: BTreeMap<String, serde_json::Value>,
+    /// The dependency manifest, only set if requested.
+    pub depfile: Option<Depfile>,
+    /// The seed permuting the linking order, only set if requested.
+    pub shuffle_seed: Option<u64>,
 }
 
 impl Build {
     ///
     /// A shortcut constructor.
     ///
+    /// If some contracts have been skipped due to the build timeout, the completed ones are reported.
+    ///
     pub fn new(
         results: BTreeMap<String, Result<Contract, era_solc::StandardJsonOutputError>>,
         messages: &mut Vec<era_solc::StandardJsonOutputError>,
     ) -> Self {
+        messages.extend(crate::process::build_timeout_warning(&results));
+
         Self {
             results,
             messages: std::mem::take(messages),
+            ast_jsons: BTreeMap::new(),
+            depfile: None,
+            shuffle_seed: None,
         }
     }
 
@@ -48,7 +68,7 @@ impl Build {
         mut self,
         linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
     ) -> Self {
-        let mut contracts: HashMap<String, Contract> = self
+        let mut contracts: BTreeMap<String, Contract> = self
             .results
             .into_iter()
             .map(|(path, result)| (path, result.expect("Cannot link a project with errors")))
@@ -56,9 +76,12 @@ impl Build {
 
         loop {
             let mut linkage_data = BTreeMap::new();
-            for (path, contract) in contracts.iter().filter(|(_path, contract)| {
-                contract.object_format == era_compiler_common::ObjectFormat::ELF
-            }) {
+            for (path, contract) in crate::shuffle::shuffled(
+                self.shuffle_seed,
+                contracts.iter().filter(|(_path, contract)| {
+                    contract.object_format == era_compiler_common::ObjectFormat::ELF
+                }),
+            ) {
                 let factory_dependencies: BTreeMap<
                     String,
                     [u8; era_compiler_common::BYTE_LENGTH_FIELD],
@@ -105,7 +128,9 @@ impl Build {
                 break;
             }
 
-            for (path, (memory_buffer_linked, bytecode_hash)) in linkage_data.into_iter() {
+            for (path, (memory_buffer_linked, bytecode_hash)) in
+                crate::shuffle::shuffled(self.shuffle_seed, linkage_data)
+            {
                 let contract = contracts.get(path.as_str()).expect("Always exists");
                 let factory_dependencies_resolved = contract
                     .factory_dependencies
@@ -132,6 +157,18 @@ impl Build {
             }
         }
 
+        let deployment_payloads: Vec<_> = contracts
+            .keys()
+            .filter_map(|path| {
+                Self::deployment_payload(&contracts, path.as_str())
+                    .map(|payload| (path.to_owned(), payload))
+            })
+            .collect();
+        for (path, deployment_payload) in deployment_payloads.into_iter() {
+            let contract = contracts.get_mut(path.as_str()).expect("Always exists");
+            contract.deployment_payload = Some(deployment_payload);
+        }
+
         Self::new(
             contracts
                 .into_iter()
@@ -141,6 +178,136 @@ impl Build {
         )
     }
 
+    ///
+    /// Links the callers of the `libraries` deployed by the library deployer.
+    ///
+    /// The library addresses are derived from their bytecode hashes, so libraries are linked
+    /// in dependency order until no more library can be linked.
+    ///
+    pub fn link_deployed_libraries(
+        mut self,
+        mut linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+        libraries: &BTreeSet<String>,
+    ) -> Self {
+        loop {
+            let library_addresses: BTreeMap<
+                String,
+                [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS],
+            > = libraries
+                .iter()
+                .filter(|path| !linker_symbols.contains_key(path.as_str()))
+                .filter_map(|path| {
+                    let contract = self.results.get(path.as_str())?.as_ref().ok()?;
+                    if contract.object_format != era_compiler_common::ObjectFormat::Raw {
+                        return None;
+                    }
+                    let bytecode_hash = contract.build.bytecode_hash.as_ref()?;
+                    Some((
+                        path.to_owned(),
+                        crate::library_deployer::address(bytecode_hash),
+                    ))
+                })
+                .collect();
+            if library_addresses.is_empty() {
+                break;
+            }
+
+            linker_symbols.extend(library_addresses);
+            self = self.link(linker_symbols.clone());
+        }
+        self
+    }
+
+    ///
+    /// Summarizes the deployment payload of the linked contract at `path`.
+    ///
+    /// Each contract in the factory dependency tree is visited once, and its direct dependencies
+    /// are counted towards the naive payload size. Dependencies with the same bytecode hash are
+    /// only published once, so the difference is reported as the deduplicated size.
+    ///
+    /// Returns `None` if the contract or any of its dependencies is not linked.
+    ///
+    fn deployment_payload(
+        contracts: &BTreeMap<String, Contract>,
+        path: &str,
+    ) -> Option<era_solc::StandardJsonOutputContractDeploymentPayload> {
+        let contract = contracts.get(path)?;
+        let mut hashes = HashSet::from([contract.build.bytecode_hash?]);
+        let mut size = contract.build.bytecode.len();
+        let mut naive_size = size;
+
+        let mut visited = BTreeSet::from([path]);
+        let mut queue = vec![contract];
+        while let Some(contract) = queue.pop() {
+            for dependency_path in contract.factory_dependencies.iter() {
+                let dependency = contracts.get(dependency_path.as_str())?;
+                let dependency_size = dependency.build.bytecode.len();
+                naive_size += dependency_size;
+                if hashes.insert(dependency.build.bytecode_hash?) {
+                    size += dependency_size;
+                }
+                if visited.insert(dependency_path.as_str()) {
+                    queue.push(dependency);
+                }
+            }
+        }
+
+        Some(era_solc::StandardJsonOutputContractDeploymentPayload::new(
+            hashes.len() - 1,
+            size,
+            naive_size - size,
+        ))
+    }
+
+    ///
+    /// Merges the builds of several `solc` invocations, e.g. one per `solc` version.
+    ///
+    /// If a contract has been built more than once, e.g. as part of a source imported by sources
+    /// compiled with different `solc` versions, the build from the first of `builds` is kept.
+    ///
+    pub fn merge(builds: Vec<Self>) -> Self {
+        let mut merged = Self {
+            results: BTreeMap::new(),
+            messages: vec![],
+            ast_jsons: BTreeMap::new(),
+            depfile: None,
+            shuffle_seed: builds.first().and_then(|build| build.shuffle_seed),
+        };
+        for build in builds.into_iter() {
+            for (path, result) in build.results.into_iter() {
+                merged.results.entry(path).or_insert(result);
+            }
+            merged.messages.extend(build.messages);
+            for (path, ast_json) in build.ast_jsons.into_iter() {
+                merged.ast_jsons.entry(path).or_insert(ast_json);
+            }
+        }
+        merged
+    }
+
+    ///
+    /// Retains only the contract matching `target`, along with the ones that failed to compile.
+    ///
+    /// Returns the full path of the selected contract.
+    ///
+    pub fn select_contract(&mut self, target: &str) -> anyhow::Result<String> {
+        let full_path = crate::contract_selection::select(self.results.keys(), target)?;
+        self.results
+            .retain(|path, result| path == &full_path || result.is_err());
+        Ok(full_path)
+    }
+
+    ///
+    /// Attaches the ABIs to the contracts, keyed by contract full path.
+    ///
+    pub fn attach_abi_jsons(&mut self, mut abi_jsons: BTreeMap<String, serde_json::Value>) {
+        for (path, result) in self.results.iter_mut() {
+            if let Ok(contract) = result {
+                contract.abi_json = abi_jsons.remove(path.as_str());
+            }
+        }
+    }
+
     ///
     /// Writes all contracts to the terminal.
     ///
@@ -149,22 +316,39 @@ impl Build {
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
-
-        if !output_metadata && !output_assembly && !output_binary {
-            writeln!(
-                std::io::stderr(),
-                "Compiler run successful. No output requested. Use flags --metadata, --asm, --bin."
-            )?;
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
+
+        if !output_metadata
+            && !output_assembly
+            && !output_binary
+            && !output_abi
+            && !output_hashes
+            && self.ast_jsons.is_empty()
+        {
+            if sarif.is_none() {
+                writeln!(
+                    std::io::stderr(),
+                    "Compiler run successful. No output requested. Use flags --metadata, --asm, --bin, --ast-json."
+                )?;
+            }
             return Ok(());
         }
 
+        crate::ast_json::write_to_terminal(self.ast_jsons)?;
+
         for (path, build) in self.results.into_iter() {
-            build
-                .expect("Always valid")
-                .write_to_terminal(path, output_metadata, output_binary)?;
+            build.expect("Always valid").write_to_terminal(
+                path,
+                output_metadata,
+                output_binary,
+                output_abi,
+                output_hashes,
+            )?;
         }
 
         Ok(())
@@ -173,32 +357,119 @@ impl Build {
     ///
     /// Writes all contracts to the specified directory.
     ///
+    /// Returns the paths of the written files, keyed by contract full path.
+    ///
     pub fn write_to_directory(
         mut self,
         output_directory: &Path,
         output_metadata: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
         overwrite: bool,
-    ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
+    ) -> anyhow::Result<BTreeMap<String, Vec<PathBuf>>> {
+        let artifact_directories = ArtifactDirectories::new(
+            self.ast_jsons.keys().map(|path| path.as_str()).chain(
+                self.results
+                    .values()
+                    .filter_map(|result| result.as_ref().ok())
+                    .map(|contract| contract.name.path.as_str()),
+            ),
+        );
+        self.messages.extend(artifact_directories.warnings());
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
 
         std::fs::create_dir_all(output_directory)?;
 
-        for build in self.results.into_values() {
-            build.expect("Always valid").write_to_directory(
+        crate::ast_json::write_to_directory(
+            self.ast_jsons,
+            output_directory,
+            &artifact_directories,
+            overwrite,
+        )?;
+
+        let mut artifact_paths = BTreeMap::new();
+        for (full_path, build) in self.results.into_iter() {
+            let build = build.expect("Always valid");
+            let directory = artifact_directories
+                .get(build.name.path.as_str())
+                .to_owned();
+            let paths = build.write_to_directory(
                 output_directory,
+                directory.as_path(),
                 output_metadata,
                 output_binary,
+                output_abi,
+                output_hashes,
                 overwrite,
             )?;
+            artifact_paths.insert(full_path, paths);
         }
 
-        writeln!(
-            std::io::stderr(),
-            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
-        )?;
-        Ok(())
+        if sarif.is_none() {
+            writeln!(
+                std::io::stderr(),
+                "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+            )?;
+        }
+        Ok(artifact_paths)
+    }
+
+    ///
+    /// Writes the contract artifacts in the specified `format` to `output_directory`.
+    ///
+    /// In the Hardhat format, each artifact is written to `<source name>/<contract name>.json`,
+    /// where the Hardhat plugin expects it. In the Foundry format, like in `forge`, each artifact
+    /// is written to `<file name>/<contract name>.json`, with the directories of sources sharing
+    /// the file name disambiguated by their relative paths.
+    /// Returns the paths of the written artifacts, keyed by contract full path.
+    ///
+    pub fn write_artifacts(
+        mut self,
+        output_directory: &Path,
+        format: ArtifactFormat,
+        overwrite: bool,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
+    ) -> anyhow::Result<BTreeMap<String, Vec<PathBuf>>> {
+        let artifact_directories = match format {
+            ArtifactFormat::Hardhat => None,
+            ArtifactFormat::Foundry => Some(ArtifactDirectories::new(
+                self.results
+                    .values()
+                    .filter_map(|result| result.as_ref().ok())
+                    .map(|contract| contract.name.path.as_str()),
+            )),
+        };
+        if let Some(ref artifact_directories) = artifact_directories {
+            self.messages.extend(artifact_directories.warnings());
+        }
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
+
+        std::fs::create_dir_all(output_directory)?;
+        let mut artifact_paths = BTreeMap::new();
+        for (full_path, build) in self.results.into_iter() {
+            let build = build.expect("Always valid");
+            let directory = match artifact_directories {
+                Some(ref artifact_directories) => artifact_directories
+                    .get(build.name.path.as_str())
+                    .to_owned(),
+                None => ArtifactDirectories::relative_path(build.name.path.as_str()),
+            };
+            let path =
+                build.write_artifact(output_directory, directory.as_path(), format, overwrite)?;
+            artifact_paths.insert(full_path, vec![path]);
+        }
+
+        if sarif.is_none() {
+            writeln!(
+                std::io::stderr(),
+                "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+            )?;
+        }
+        Ok(artifact_paths)
     }
 
     ///
@@ -209,6 +480,12 @@ impl Build {
         standard_json: &mut era_solc::StandardJsonOutput,
         solc_version: Option<&era_solc::Version>,
     ) -> anyhow::Result<()> {
+        let target_machine = era_compiler_llvm_context::TargetMachine::new(
+            era_compiler_common::Target::EraVM,
+            &era_compiler_llvm_context::OptimizerSettings::cycles(),
+            &[],
+        )?;
+
         let mut errors = Vec::with_capacity(self.results.len());
         for result in self.results.into_values() {
             let build = match result {
@@ -219,6 +496,11 @@ impl Build {
                 }
             };
             let name = build.name.clone();
+            if let Some(duration) = build.duration {
+                standard_json
+                    .backend_durations
+                    .insert(name.full_path.clone(), duration);
+            }
 
             match standard_json
                 .contracts
@@ -227,7 +509,7 @@ impl Build {
                     contracts.get_mut(name.name.as_deref().unwrap_or(name.path.as_str()))
                 }) {
                 Some(contract) => {
-                    build.write_to_standard_json(contract)?;
+                    build.write_to_standard_json(contract, &target_machine)?;
                 }
                 None => {
                     let contracts = standard_json
@@ -235,7 +517,7 @@ impl Build {
                         .entry(name.path.clone())
                         .or_default();
                     let mut contract = era_solc::StandardJsonOutputContract::default();
-                    build.write_to_standard_json(&mut contract)?;
+                    build.write_to_standard_json(&mut contract, &target_machine)?;
                     contracts.insert(name.name.unwrap_or(name.path), contract);
                 }
             }
@@ -253,42 +535,34 @@ impl Build {
     ///
     /// Writes all contracts assembly and bytecode to the combined JSON.
     ///
+    /// Contracts are matched by their full paths, so contracts with the same name in different
+    /// sources do not overwrite each other.
+    ///
     pub fn write_to_combined_json(
         mut self,
         combined_json: &mut era_solc::CombinedJson,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
 
         for result in self.results.into_values() {
             let build = result.expect("Exits on an error above");
             let name = build.name.clone();
 
-            let combined_json_contract =
-                match combined_json
-                    .contracts
-                    .iter_mut()
-                    .find_map(|(json_path, contract)| {
-                        if Self::normalize_full_path(name.full_path.as_str())
-                            .ends_with(Self::normalize_full_path(json_path).as_str())
-                        {
-                            Some(contract)
-                        } else {
-                            None
-                        }
-                    }) {
-                    Some(contract) => contract,
-                    None => {
-                        combined_json.contracts.insert(
-                            name.full_path.clone(),
-                            era_solc::CombinedJsonContract::default(),
-                        );
-                        combined_json
-                            .contracts
-                            .get_mut(name.full_path.as_str())
-                            .expect("Always exists")
-                    }
-                };
+            let full_path = Self::normalize_full_path(name.full_path.as_str());
+            let json_path = combined_json
+                .contracts
+                .keys()
+                .map(|json_path| (json_path, Self::normalize_full_path(json_path)))
+                .filter(|(_json_path, normalized_path)| {
+                    full_path == *normalized_path
+                        || full_path.ends_with(format!("/{normalized_path}").as_str())
+                })
+                .max_by_key(|(_json_path, normalized_path)| normalized_path.len())
+                .map(|(json_path, _normalized_path)| json_path.to_owned())
+                .unwrap_or(name.full_path);
+            let combined_json_contract = combined_json.contracts.entry(json_path).or_default();
 
             build.write_to_combined_json(combined_json_contract)?;
         }
@@ -303,16 +577,13 @@ impl Build {
     /// If the path does not contain a colon.
     ///
     fn normalize_full_path(path: &str) -> String {
-        let mut iterator = path.split(':');
-        let path = iterator.next().expect("Always exists");
-        let name = iterator.next().expect("Always exists");
+        let (path, name) = path.rsplit_once(':').expect("Always exists");
 
-        let mut full_path = PathBuf::from(path)
+        let full_path = PathBuf::from(path)
             .normalize()
             .expect("Path normalization error")
-            .as_os_str()
-            .to_string_lossy()
-            .into_owned();
+            .into_path_buf();
+        let mut full_path = era_solc::path::normalize_path(full_path.as_path());
         full_path.push(':');
         full_path.push_str(name);
         full_path
@@ -6,6 +6,10 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 
+use crate::interface_freeze::Interface;
+
+use super::opcodes::opcodes;
+
 ///
 /// The Solidity contract build.
 ///
@@ -23,6 +27,13 @@ pub struct Contract {
     pub metadata_hash: Option<era_compiler_common::Hash>,
     /// The metadata JSON.
     pub metadata_json: serde_json::Value,
+    /// The deployment payload summary, only available in Yul mode.
+    pub deployment_payload: Option<era_solc::StandardJsonOutputContractDeploymentPayload>,
+    /// The ABI JSON, only set if requested.
+    pub abi_json: Option<serde_json::Value>,
+    /// The backend compilation time, only set if the contract has been compiled in this run.
+    #[serde(skip)]
+    pub duration: Option<std::time::Duration>,
 }
 
 impl Contract {
@@ -44,6 +55,9 @@ impl Contract {
             runtime_build,
             metadata_hash,
             metadata_json,
+            deployment_payload: None,
+            abi_json: None,
+            duration: None,
         }
     }
 
@@ -56,6 +70,8 @@ impl Contract {
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
     ) -> anyhow::Result<()> {
         writeln!(std::io::stdout(), "\n======= {path} =======")?;
         if output_assembly {
@@ -64,6 +80,16 @@ impl Contract {
         if output_metadata {
             writeln!(std::io::stdout(), "Metadata:\n{}", self.metadata_json)?;
         }
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_abi) {
+            writeln!(std::io::stdout(), "ABI:\n{abi_json}")?;
+        }
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_hashes) {
+            write!(
+                std::io::stdout(),
+                "Function signatures:\n{}",
+                Interface::from_abi(abi_json).function_signatures()
+            )?;
+        }
         if output_binary {
             writeln!(
                 std::io::stdout(),
@@ -82,9 +108,12 @@ impl Contract {
     pub fn write_to_directory(
         self,
         output_path: &Path,
+        directory: &Path,
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
         overwrite: bool,
     ) -> anyhow::Result<()> {
         let file_path = PathBuf::from(self.name.path);
@@ -95,7 +124,7 @@ impl Contract {
             .expect("Always valid");
 
         let mut output_path = output_path.to_owned();
-        output_path.push(file_name);
+        output_path.push(directory);
         std::fs::create_dir_all(output_path.as_path())?;
 
         if output_metadata {
@@ -139,6 +168,49 @@ impl Contract {
             }
         }
 
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_abi) {
+            let output_name = format!(
+                "{}.{}",
+                self.name.name.as_deref().unwrap_or(file_name),
+                crate::EXTENSION_ABI
+            );
+            let mut output_path = output_path.clone();
+            output_path.push(output_name.as_str());
+
+            if output_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+                );
+            } else {
+                std::fs::write(output_path.as_path(), abi_json.to_string().as_bytes())
+                    .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+            }
+        }
+
+        if let Some(abi_json) = self.abi_json.as_ref().filter(|_| output_hashes) {
+            let output_name = format!(
+                "{}.{}",
+                self.name.name.as_deref().unwrap_or(file_name),
+                crate::EXTENSION_SIGNATURES
+            );
+            let mut output_path = output_path.clone();
+            output_path.push(output_name.as_str());
+
+            if output_path.exists() && !overwrite {
+                anyhow::bail!(
+                    "Refusing to overwrite an existing file {output_path:?} (use --overwrite to force)."
+                );
+            } else {
+                std::fs::write(
+                    output_path.as_path(),
+                    Interface::from_abi(abi_json)
+                        .function_signatures()
+                        .as_bytes(),
+                )
+                .map_err(|error| anyhow::anyhow!("File {output_path:?} writing: {error}"))?;
+            }
+        }
+
         if output_binary {
             let output_name = format!(
                 "{}.{}",
@@ -170,14 +242,27 @@ impl Contract {
         self,
         standard_json_contract: &mut era_solc::StandardJsonOutputContract,
     ) -> anyhow::Result<()> {
-        let deploy_bytecode = hex::encode(self.deploy_build.as_slice());
-        let runtime_bytecode = hex::encode(self.runtime_build.as_slice());
-
         standard_json_contract.metadata = self.metadata_json;
-        standard_json_contract
+        let evm = standard_json_contract
             .evm
-            .get_or_insert_with(era_solc::StandardJsonOutputContractEVM::default)
-            .modify_evm(deploy_bytecode, runtime_bytecode);
+            .get_or_insert_with(era_solc::StandardJsonOutputContractEVM::default);
+        let runtime_opcodes = opcodes(self.runtime_build.as_slice());
+        let mut deploy_build = self.deploy_build;
+        deploy_build.extend_from_slice(self.runtime_build.as_slice());
+        let deploy_opcodes = opcodes(deploy_build.as_slice());
+        evm.modify_evm(
+            era_solc::StandardJsonOutputContractEVMBytecode::new(
+                deploy_build,
+                Some(deploy_opcodes),
+                None,
+            ),
+            era_solc::StandardJsonOutputContractEVMBytecode::new(
+                self.runtime_build,
+                Some(runtime_opcodes),
+                None,
+            ),
+        );
+        evm.deployment_payload = self.deployment_payload;
 
         Ok(())
     }
@@ -3,6 +3,7 @@
 //!
 
 pub mod contract;
+pub mod opcodes;
 
 use std::collections::BTreeMap;
 use std::io::Write;
@@ -13,6 +14,8 @@ use normpath::PathExt;
 
 use era_solc::CollectableError;
 
+use crate::artifact_directories::ArtifactDirectories;
+
 use self::contract::Contract;
 
 ///
@@ -24,19 +27,49 @@ pub struct Build {
     pub results: BTreeMap<String, Result<Contract, era_solc::StandardJsonOutputError>>,
     /// The additional message to output.
     pub messages: Vec<era_solc::StandardJsonOutputError>,
+    /// The source ASTs, only set if requested.
+    pub ast_jsons: BTreeMap<String, serde_json::Value>,
 }
 
 impl Build {
     ///
     /// A shortcut constructor.
     ///
+    /// If some contracts have been skipped due to the build timeout, the completed ones are reported.
+    ///
     pub fn new(
         results: BTreeMap<String, Result<Contract, era_solc::StandardJsonOutputError>>,
         messages: &mut Vec<era_solc::StandardJsonOutputError>,
     ) -> Self {
+        messages.extend(crate::process::build_timeout_warning(&results));
+
         Self {
             results,
             messages: std::mem::take(messages),
+            ast_jsons: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Retains only the contract matching `target`, along with the ones that failed to compile.
+    ///
+    /// Returns the full path of the selected contract.
+    ///
+    pub fn select_contract(&mut self, target: &str) -> anyhow::Result<String> {
+        let full_path = crate::contract_selection::select(self.results.keys(), target)?;
+        self.results
+            .retain(|path, result| path == &full_path || result.is_err());
+        Ok(full_path)
+    }
+
+    ///
+    /// Attaches the ABIs to the contracts, keyed by contract full path.
+    ///
+    pub fn attach_abi_jsons(&mut self, mut abi_jsons: BTreeMap<String, serde_json::Value>) {
+        for (path, result) in self.results.iter_mut() {
+            if let Ok(contract) = result {
+                contract.abi_json = abi_jsons.remove(path.as_str());
+            }
         }
     }
 
@@ -48,24 +81,39 @@ impl Build {
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
 
-        if !output_metadata && !output_assembly && !output_binary {
-            writeln!(
-                std::io::stderr(),
-                "Compiler run successful. No output requested. Use flags --metadata, --asm, --bin."
-            )?;
+        if !output_metadata
+            && !output_assembly
+            && !output_binary
+            && !output_abi
+            && !output_hashes
+            && self.ast_jsons.is_empty()
+        {
+            if sarif.is_none() {
+                writeln!(
+                    std::io::stderr(),
+                    "Compiler run successful. No output requested. Use flags --metadata, --asm, --bin, --ast-json."
+                )?;
+            }
             return Ok(());
         }
 
+        crate::ast_json::write_to_terminal(self.ast_jsons)?;
+
         for (path, build) in self.results.into_iter() {
             build.expect("Always valid").write_to_terminal(
                 path,
                 output_metadata,
                 output_assembly,
                 output_binary,
+                output_abi,
+                output_hashes,
             )?;
         }
 
@@ -81,27 +129,55 @@ impl Build {
         output_metadata: bool,
         output_assembly: bool,
         output_binary: bool,
+        output_abi: bool,
+        output_hashes: bool,
         overwrite: bool,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        let artifact_directories = ArtifactDirectories::new(
+            self.ast_jsons.keys().map(|path| path.as_str()).chain(
+                self.results
+                    .values()
+                    .filter_map(|result| result.as_ref().ok())
+                    .map(|contract| contract.name.path.as_str()),
+            ),
+        );
+        self.messages.extend(artifact_directories.warnings());
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
 
         std::fs::create_dir_all(output_directory)?;
 
+        crate::ast_json::write_to_directory(
+            self.ast_jsons,
+            output_directory,
+            &artifact_directories,
+            overwrite,
+        )?;
+
         for build in self.results.into_values() {
-            build.expect("Always valid").write_to_directory(
+            let build = build.expect("Always valid");
+            let directory = artifact_directories
+                .get(build.name.path.as_str())
+                .to_owned();
+            build.write_to_directory(
                 output_directory,
+                directory.as_path(),
                 output_metadata,
                 output_assembly,
                 output_binary,
+                output_abi,
+                output_hashes,
                 overwrite,
             )?;
         }
 
-        writeln!(
-            std::io::stderr(),
-            "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
-        )?;
+        if sarif.is_none() {
+            writeln!(
+                std::io::stderr(),
+                "Compiler run successful. Artifact(s) can be found in directory {output_directory:?}."
+            )?;
+        }
         Ok(())
     }
 
@@ -123,6 +199,11 @@ impl Build {
                 }
             };
             let name = build.name.clone();
+            if let Some(duration) = build.duration {
+                standard_json
+                    .backend_durations
+                    .insert(name.full_path.clone(), duration);
+            }
 
             match standard_json
                 .contracts
@@ -157,42 +238,34 @@ impl Build {
     ///
     /// Writes all contracts assembly and bytecode to the combined JSON.
     ///
+    /// Contracts are matched by their full paths, so contracts with the same name in different
+    /// sources do not overwrite each other.
+    ///
     pub fn write_to_combined_json(
         mut self,
         combined_json: &mut era_solc::CombinedJson,
+        sarif: Option<&era_solc::StandardJsonOutputErrorSarifDeferred>,
     ) -> anyhow::Result<()> {
-        self.take_and_write_warnings();
-        self.exit_on_error();
+        self.take_and_write_warnings(sarif);
+        self.exit_on_error(sarif);
 
         for result in self.results.into_values() {
             let build = result.expect("Exits on an error above");
             let name = build.name.clone();
 
-            let combined_json_contract =
-                match combined_json
-                    .contracts
-                    .iter_mut()
-                    .find_map(|(json_path, contract)| {
-                        if Self::normalize_full_path(name.full_path.as_str())
-                            .ends_with(Self::normalize_full_path(json_path).as_str())
-                        {
-                            Some(contract)
-                        } else {
-                            None
-                        }
-                    }) {
-                    Some(contract) => contract,
-                    None => {
-                        combined_json.contracts.insert(
-                            name.full_path.clone(),
-                            era_solc::CombinedJsonContract::default(),
-                        );
-                        combined_json
-                            .contracts
-                            .get_mut(name.full_path.as_str())
-                            .expect("Always exists")
-                    }
-                };
+            let full_path = Self::normalize_full_path(name.full_path.as_str());
+            let json_path = combined_json
+                .contracts
+                .keys()
+                .map(|json_path| (json_path, Self::normalize_full_path(json_path)))
+                .filter(|(_json_path, normalized_path)| {
+                    full_path == *normalized_path
+                        || full_path.ends_with(format!("/{normalized_path}").as_str())
+                })
+                .max_by_key(|(_json_path, normalized_path)| normalized_path.len())
+                .map(|(json_path, _normalized_path)| json_path.to_owned())
+                .unwrap_or(name.full_path);
+            let combined_json_contract = combined_json.contracts.entry(json_path).or_default();
 
             build.write_to_combined_json(combined_json_contract)?;
         }
@@ -207,16 +280,13 @@ impl Build {
     /// If the path does not contain a colon.
     ///
     fn normalize_full_path(path: &str) -> String {
-        let mut iterator = path.split(':');
-        let path = iterator.next().expect("Always exists");
-        let name = iterator.next().expect("Always exists");
+        let (path, name) = path.rsplit_once(':').expect("Always exists");
 
-        let mut full_path = PathBuf::from(path)
+        let full_path = PathBuf::from(path)
             .normalize()
             .expect("Path normalization error")
-            .as_os_str()
-            .to_string_lossy()
-            .into_owned();
+            .into_path_buf();
+        let mut full_path = era_solc::path::normalize_path(full_path.as_path());
         full_path.push(':');
         full_path.push_str(name);
         full_path
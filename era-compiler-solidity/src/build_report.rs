@@ -0,0 +1,197 @@
+//!
+//! The signed build report.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::build_eravm::Build as EraVMBuild;
+use crate::resource_usage::ResourceUsage;
+
+///
+/// The signature of an emitted artifact.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ArtifactSignature {
+    /// The `keccak256` hash of the artifact file contents.
+    pub hash: String,
+    /// The recoverable ECDSA signature of the hash in the `r || s || v` format.
+    pub signature: String,
+}
+
+///
+/// The report of a contract.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ContractReport {
+    /// The artifact file signatures, keyed by file path relative to the output directory.
+    /// Only set if the report is signed.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub artifacts: BTreeMap<String, ArtifactSignature>,
+    /// The `BALANCE` and `SELFBALANCE` lowering strategy used for the contract.
+    #[serde(default, rename = "balanceLowering")]
+    pub balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+}
+
+///
+/// The build report.
+///
+/// Records the `BALANCE` and `SELFBALANCE` lowering strategy of each contract and the resource usage.
+/// If requested, artifacts are signed with a user-provided secp256k1 key, so deployment pipelines
+/// can verify that the artifacts have not been modified since they were emitted by the compiler.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BuildReport {
+    /// The `zksolc` version.
+    pub zksolc_version: String,
+    /// The public key identifier, which is the Ethereum address of the signing key.
+    /// Only set if the report is signed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// The contract reports, keyed by contract full path.
+    pub contracts: BTreeMap<String, ContractReport>,
+    /// The resource usage of the run.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resource_usage: Option<ResourceUsage>,
+    /// The signature of the compact JSON of the report without this field.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+}
+
+impl BuildReport {
+    /// The build report file name.
+    pub const FILE_NAME: &'static str = "build_report.json";
+
+    ///
+    /// Creates an unsigned report of the `build` to be emitted.
+    ///
+    /// If the artifacts must be signed, the report is signed with [`Self::try_sign`] after
+    /// they are written. The `resource_usage` is covered by the report signature.
+    ///
+    pub fn new(build: &EraVMBuild, resource_usage: ResourceUsage) -> Self {
+        let contracts = build
+            .results
+            .iter()
+            .filter_map(|(path, result)| Some((path, result.as_ref().ok()?)))
+            .map(|(path, contract)| {
+                let report = ContractReport {
+                    balance_lowering: contract
+                        .metadata_json
+                        .get("balance_lowering")
+                        .and_then(|value| serde_json::from_value(value.to_owned()).ok())
+                        .unwrap_or_default(),
+                    ..Default::default()
+                };
+                (path.to_owned(), report)
+            })
+            .collect();
+
+        Self {
+            zksolc_version: crate::version(),
+            key_id: None,
+            contracts,
+            resource_usage: Some(resource_usage),
+            signature: None,
+        }
+    }
+
+    ///
+    /// Signs the files written to `output_directory` with the key at `key_path`.
+    ///
+    /// `artifact_paths` are the files written for each contract, keyed by contract full path.
+    /// The key file must contain a hexadecimal secp256k1 private key.
+    ///
+    pub fn try_sign(
+        mut self,
+        key_path: &Path,
+        output_directory: &Path,
+        artifact_paths: &BTreeMap<String, Vec<PathBuf>>,
+    ) -> anyhow::Result<Self> {
+        let signing_key = Self::read_signing_key(key_path)?;
+
+        for (full_path, paths) in artifact_paths.iter() {
+            let report = self.contracts.entry(full_path.to_owned()).or_default();
+            for path in paths.iter() {
+                let contents = std::fs::read(path)
+                    .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+                let name = path
+                    .strip_prefix(output_directory)
+                    .unwrap_or(path.as_path())
+                    .components()
+                    .map(|component| component.as_os_str().to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join("/");
+                report
+                    .artifacts
+                    .insert(name, Self::sign(&signing_key, contents.as_slice())?);
+            }
+        }
+
+        self.key_id = Some(Self::key_id(&signing_key));
+        self.signature = None;
+        let report_json = serde_json::to_vec(&self).expect("Always valid");
+        self.signature = Some(Self::sign(&signing_key, report_json.as_slice())?.signature);
+        Ok(self)
+    }
+
+    ///
+    /// Writes the report to the specified file in JSON format.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Reads the hexadecimal secp256k1 private key from the file at `path`.
+    ///
+    fn read_signing_key(path: &Path) -> anyhow::Result<k256::ecdsa::SigningKey> {
+        let key = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Signing key file {path:?} reading: {error}"))?;
+        let key = key.trim();
+        let key = hex::decode(key.strip_prefix("0x").unwrap_or(key))
+            .map_err(|error| anyhow::anyhow!("Signing key file {path:?} parsing: {error}"))?;
+        k256::ecdsa::SigningKey::from_slice(key.as_slice())
+            .map_err(|error| anyhow::anyhow!("Signing key file {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Returns the Ethereum address of the signing key.
+    ///
+    fn key_id(signing_key: &k256::ecdsa::SigningKey) -> String {
+        let public_key = signing_key.verifying_key().to_encoded_point(false);
+        let hash = era_compiler_common::Hash::keccak256(&public_key.as_bytes()[1..]);
+        format!(
+            "0x{}",
+            hex::encode(
+                &hash.as_bytes()[era_compiler_common::BYTE_LENGTH_FIELD
+                    - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..]
+            )
+        )
+    }
+
+    ///
+    /// Signs the `keccak256` hash of `data`.
+    ///
+    fn sign(
+        signing_key: &k256::ecdsa::SigningKey,
+        data: &[u8],
+    ) -> anyhow::Result<ArtifactSignature> {
+        let hash = era_compiler_common::Hash::keccak256(data);
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(hash.as_bytes())
+            .map_err(|error| anyhow::anyhow!("Artifact signing: {error}"))?;
+
+        let mut signature = signature.to_bytes().to_vec();
+        signature.push(recovery_id.to_byte() + 27);
+        Ok(ArtifactSignature {
+            hash: hash.to_string(),
+            signature: hex::encode(signature.as_slice()),
+        })
+    }
+}
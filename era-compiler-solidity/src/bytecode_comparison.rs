@@ -0,0 +1,173 @@
+//!
+//! The metadata-aware bytecode comparison.
+//!
+
+///
+/// The result of comparing two bytecodes.
+///
+/// Bytecodes of the same source and settings compiled on different machines, or with different
+/// metadata settings, often only differ in the metadata trailer: either a CBOR map, or a raw
+/// `keccak256` hash. Such differences do not affect the code, and are reported separately, so
+/// they are not mistaken for code changes.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BytecodeComparison {
+    /// The bytecodes are byte-for-byte identical.
+    Identical,
+    /// The bytecodes only differ in the metadata trailers.
+    EquivalentModuloMetadata,
+    /// The code of the bytecodes differs.
+    Different {
+        /// The offset of the first differing byte of the code.
+        offset: usize,
+    },
+}
+
+impl BytecodeComparison {
+    /// The CBOR major type of maps.
+    const CBOR_MAJOR_TYPE_MAP: u8 = 5;
+
+    /// The size of the big-endian CBOR trailer length, following the trailer.
+    const TRAILER_LENGTH_SIZE: usize = 2;
+
+    /// The size of the raw `keccak256` hash trailer.
+    const KECCAK256_TRAILER_SIZE: usize = era_compiler_common::BYTE_LENGTH_FIELD;
+
+    ///
+    /// Compares the `left` and `right` bytecodes, ignoring their metadata trailers of
+    /// `metadata_hash_type`.
+    ///
+    pub fn new(
+        left: &[u8],
+        right: &[u8],
+        metadata_hash_type: era_compiler_common::HashType,
+    ) -> Self {
+        if left == right {
+            return Self::Identical;
+        }
+
+        let (left, _) = Self::split_metadata(left, metadata_hash_type);
+        let (right, _) = Self::split_metadata(right, metadata_hash_type);
+        match left.iter().zip(right.iter()).position(|(a, b)| a != b) {
+            Some(offset) => Self::Different { offset },
+            None if left.len() != right.len() => Self::Different {
+                offset: left.len().min(right.len()),
+            },
+            None => Self::EquivalentModuloMetadata,
+        }
+    }
+
+    ///
+    /// Splits the `bytecode` into the code and the metadata trailer of `metadata_hash_type`.
+    ///
+    /// The `keccak256` trailer is the raw 32-byte hash at the end of the bytecode. As it cannot be
+    /// told apart from the code, the last 32 bytes are always considered the trailer.
+    ///
+    /// Otherwise, the trailer is a CBOR map followed by its 2-byte big-endian length, as appended
+    /// by `solc` and `zksolc`, which is also there with the `none` hash type if `solc` appends its
+    /// version. The trailing zero bytes after the map are considered a part of the trailer.
+    ///
+    /// If there is no trailer, the trailer slice is empty.
+    ///
+    pub fn split_metadata(
+        bytecode: &[u8],
+        metadata_hash_type: era_compiler_common::HashType,
+    ) -> (&[u8], &[u8]) {
+        if let era_compiler_common::HashType::Keccak256 = metadata_hash_type {
+            return bytecode.split_at(bytecode.len().saturating_sub(Self::KECCAK256_TRAILER_SIZE));
+        }
+
+        let padding_start = bytecode
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |position| position + 1);
+
+        for end in padding_start..=bytecode.len() {
+            if end < Self::TRAILER_LENGTH_SIZE {
+                continue;
+            }
+            let length = u16::from_be_bytes([bytecode[end - 2], bytecode[end - 1]]) as usize;
+            let Some(start) = (end - Self::TRAILER_LENGTH_SIZE).checked_sub(length) else {
+                continue;
+            };
+            if length > 0 && Self::is_cbor_map(&bytecode[start..end - Self::TRAILER_LENGTH_SIZE]) {
+                return bytecode.split_at(start);
+            }
+        }
+        (bytecode, &[])
+    }
+
+    ///
+    /// Whether the code of the bytecodes is the same.
+    ///
+    pub fn is_equivalent(&self) -> bool {
+        !matches!(self, Self::Different { .. })
+    }
+
+    ///
+    /// Whether `bytes` are exactly one non-empty CBOR map of scalar keys and values, such as the
+    /// metadata trailer. Nested arrays, maps, and tags are not expected in the trailer, and are rejected.
+    ///
+    fn is_cbor_map(bytes: &[u8]) -> bool {
+        let mut offset = 0;
+        let Some((Self::CBOR_MAJOR_TYPE_MAP, pairs)) = Self::cbor_header(bytes, &mut offset) else {
+            return false;
+        };
+        if pairs == 0 {
+            return false;
+        }
+        for _ in 0..pairs.saturating_mul(2) {
+            match Self::cbor_header(bytes, &mut offset) {
+                Some((0 | 1 | 7, _)) => {}
+                Some((2 | 3, length)) => {
+                    match usize::try_from(length)
+                        .ok()
+                        .and_then(|length| offset.checked_add(length))
+                    {
+                        Some(end) if end <= bytes.len() => offset = end,
+                        _ => return false,
+                    }
+                }
+                _ => return false,
+            }
+        }
+        offset == bytes.len()
+    }
+
+    ///
+    /// Reads the CBOR item header at `offset`, and returns its major type and argument.
+    ///
+    fn cbor_header(bytes: &[u8], offset: &mut usize) -> Option<(u8, u64)> {
+        let initial = *bytes.get(*offset)?;
+        *offset += 1;
+        let size = match initial & 0x1f {
+            argument @ 0..=23 => return Some((initial >> 5, argument as u64)),
+            24 => 1,
+            25 => 2,
+            26 => 4,
+            27 => 8,
+            _ => return None,
+        };
+        let argument = bytes
+            .get(*offset..*offset + size)?
+            .iter()
+            .fold(0u64, |argument, byte| (argument << 8) | *byte as u64);
+        *offset += size;
+        Some((initial >> 5, argument))
+    }
+}
+
+impl std::fmt::Display for BytecodeComparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Identical => write!(f, "Bytecodes are identical."),
+            Self::EquivalentModuloMetadata => {
+                write!(f, "Bytecodes are equivalent modulo metadata.")
+            }
+            Self::Different { offset } => {
+                write!(f, "Bytecodes differ at code offset {offset:#x}.")
+            }
+        }
+    }
+}
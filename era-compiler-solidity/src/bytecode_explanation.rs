@@ -0,0 +1,264 @@
+//!
+//! The bytecode offset explanation.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The prefixes of the `solc` Yul function names ending with the AST identifier of their declaration.
+pub const DECLARATION_FUNCTION_PREFIXES: [&str; 4] =
+    ["getter_fun_", "fun_", "modifier_", "constructor_"];
+
+///
+/// The bytecode offset explanation.
+///
+/// Maps an offset in the EraVM bytecode, e.g. taken from a revert trace, to the instruction at
+/// the offset, the function containing it, and the Solidity declaration the function is generated from.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BytecodeExplanation {
+    /// The contract full path.
+    pub contract: String,
+    /// The bytecode offset in bytes.
+    pub offset: usize,
+    /// The instruction at the offset.
+    pub instruction: era_solc::StandardJsonOutputContractEraVMAssemblyInstruction,
+    /// The Solidity declaration the function is generated from, e.g. `function transfer`.
+    pub declaration: Option<String>,
+}
+
+impl BytecodeExplanation {
+    ///
+    /// Explains the `offset` in the bytecode of a contract from the standard JSON output at `path`.
+    ///
+    /// The output must contain the EraVM assembly of the contract, and the source ASTs for the
+    /// Solidity declarations to be resolved. If `contract` is not specified, the output must contain
+    /// exactly one contract with assembly.
+    ///
+    pub fn try_from_standard_json(
+        path: &Path,
+        offset: usize,
+        contract: Option<&str>,
+    ) -> anyhow::Result<Self> {
+        let output = Self::read_standard_json(path)?;
+        let mut assemblies = Self::get_assemblies(&output);
+
+        let full_path = match contract {
+            Some(contract) => crate::contract_selection::select(assemblies.keys(), contract)?,
+            None if assemblies.len() == 1 => assemblies
+                .keys()
+                .next()
+                .cloned()
+                .expect("Always exists"),
+            None if assemblies.is_empty() => anyhow::bail!(
+                "No contracts with EraVM assembly found in {path:?}. Select `eravm.assemblyJson` or `eravm.assembly` in the standard JSON output selection."
+            ),
+            None => anyhow::bail!(
+                "Multiple contracts found in {path:?}. Use `--contract` to select one of them."
+            ),
+        };
+        let assembly = assemblies
+            .remove(full_path.as_str())
+            .expect("Always exists");
+
+        let instruction = Self::find_instruction(assembly, offset).ok_or_else(|| {
+            anyhow::anyhow!("Offset {offset} is out of the code of contract `{full_path}`.")
+        })?;
+
+        let declaration = instruction
+            .function
+            .as_deref()
+            .and_then(Self::get_declaration_ast_id)
+            .and_then(|ast_id| Self::resolve_declaration(&output, ast_id));
+        Ok(Self {
+            contract: full_path,
+            offset,
+            instruction,
+            declaration,
+        })
+    }
+
+    ///
+    /// Parses the offset in decimal or `0x`-prefixed hexadecimal format.
+    ///
+    pub fn parse_offset(offset: &str) -> anyhow::Result<usize> {
+        let result = match offset.strip_prefix("0x") {
+            Some(hexadecimal) => {
+                usize::from_str_radix(hexadecimal, era_compiler_common::BASE_HEXADECIMAL)
+            }
+            None => offset.parse::<usize>(),
+        };
+        result.map_err(|error| anyhow::anyhow!("Invalid bytecode offset `{offset}`: {error}"))
+    }
+
+    ///
+    /// Reads the standard JSON output at `path`.
+    ///
+    pub fn read_standard_json(path: &Path) -> anyhow::Result<serde_json::Value> {
+        let output = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("JSON file {path:?} reading: {error}"))?;
+        serde_json::from_str(output.as_str())
+            .map_err(|error| anyhow::anyhow!("JSON file {path:?} parsing: {error}"))
+    }
+
+    ///
+    /// Returns the structured assembly of the contracts in the standard JSON `output`, keyed by full path.
+    ///
+    /// Contracts without assembly are skipped.
+    ///
+    pub fn get_assemblies(
+        output: &serde_json::Value,
+    ) -> BTreeMap<String, era_solc::StandardJsonOutputContractEraVMAssembly> {
+        let mut assemblies = BTreeMap::new();
+        if let Some(files) = output.get("contracts").and_then(|files| files.as_object()) {
+            for (file, contracts) in files.iter() {
+                let contracts = match contracts.as_object() {
+                    Some(contracts) => contracts,
+                    None => continue,
+                };
+                for (name, contract) in contracts.iter() {
+                    if let Some(assembly) = contract.get("eravm").and_then(Self::get_assembly) {
+                        assemblies.insert(format!("{file}:{name}"), assembly);
+                    }
+                }
+            }
+        }
+        assemblies
+    }
+
+    ///
+    /// Returns the structured assembly of the contract EraVM output.
+    ///
+    /// The structured assembly emitted by the compiler is preferred, and the text one is only
+    /// parsed if the former is not selected.
+    ///
+    fn get_assembly(
+        eravm: &serde_json::Value,
+    ) -> Option<era_solc::StandardJsonOutputContractEraVMAssembly> {
+        if let Some(assembly) = eravm
+            .get("assemblyJson")
+            .and_then(|assembly| serde_json::from_value(assembly.to_owned()).ok())
+        {
+            return Some(assembly);
+        }
+        eravm
+            .get("assembly")
+            .and_then(|assembly| assembly.as_str())
+            .map(era_solc::StandardJsonOutputContractEraVMAssembly::from_text)
+    }
+
+    ///
+    /// Returns the instruction occupying the `offset` in the code section.
+    ///
+    fn find_instruction(
+        assembly: era_solc::StandardJsonOutputContractEraVMAssembly,
+        offset: usize,
+    ) -> Option<era_solc::StandardJsonOutputContractEraVMAssemblyInstruction> {
+        let index = assembly
+            .instructions
+            .partition_point(|instruction| instruction.offset <= offset);
+        let instruction = assembly
+            .instructions
+            .into_iter()
+            .nth(index.checked_sub(1)?)?;
+        (offset
+            < instruction.offset
+                + era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE)
+            .then_some(instruction)
+    }
+
+    ///
+    /// Returns the AST identifier of the declaration, if the function name is generated by `solc`, e.g. `fun_transfer_123`.
+    ///
+    fn get_declaration_ast_id(function: &str) -> Option<usize> {
+        let function = function.split('.').next().expect("Always exists");
+        if !DECLARATION_FUNCTION_PREFIXES
+            .iter()
+            .any(|prefix| function.starts_with(prefix))
+        {
+            return None;
+        }
+        function.rsplit('_').next()?.parse::<usize>().ok()
+    }
+
+    ///
+    /// Resolves the declaration with `ast_id` from the source ASTs.
+    ///
+    fn resolve_declaration(output: &serde_json::Value, ast_id: usize) -> Option<String> {
+        let node = output
+            .get("sources")?
+            .as_object()?
+            .values()
+            .filter_map(|source| source.get("ast"))
+            .find_map(|ast| Self::find_node(ast, ast_id))?;
+
+        let name = node
+            .get("name")
+            .and_then(|name| name.as_str())
+            .unwrap_or_default();
+        let kind = match node.get("nodeType").and_then(|kind| kind.as_str()) {
+            Some("FunctionDefinition") => node
+                .get("kind")
+                .and_then(|kind| kind.as_str())
+                .unwrap_or("function"),
+            Some("ModifierDefinition") => "modifier",
+            Some("VariableDeclaration") => "getter",
+            Some("ContractDefinition") => "contract",
+            Some(kind) => kind,
+            None => "declaration",
+        };
+        Some(if name.is_empty() {
+            kind.to_owned()
+        } else {
+            format!("{kind} {name}")
+        })
+    }
+
+    ///
+    /// Finds the AST node with `id`.
+    ///
+    fn find_node(node: &serde_json::Value, id: usize) -> Option<&serde_json::Value> {
+        match node {
+            serde_json::Value::Object(object) => {
+                if object.get("id").and_then(|node_id| node_id.as_u64()) == Some(id as u64)
+                    && object.contains_key("nodeType")
+                {
+                    return Some(node);
+                }
+                object.values().find_map(|value| Self::find_node(value, id))
+            }
+            serde_json::Value::Array(array) => {
+                array.iter().find_map(|value| Self::find_node(value, id))
+            }
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BytecodeExplanation {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        writeln!(f, "Contract: {}", self.contract)?;
+        writeln!(
+            f,
+            "Offset: {:#x} (instruction at {:#x})",
+            self.offset, self.instruction.offset
+        )?;
+        writeln!(f, "Instruction: {}", self.instruction)?;
+        writeln!(
+            f,
+            "Function: {}",
+            self.instruction.function.as_deref().unwrap_or("<unknown>")
+        )?;
+        if let Some(ref declaration) = self.declaration {
+            writeln!(f, "Declaration: {declaration}")?;
+        }
+        if let Some(ref source_location) = self.instruction.source_location {
+            writeln!(
+                f,
+                "Location: {}:{}:{}",
+                source_location.file, source_location.line, source_location.column
+            )?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,554 @@
+//!
+//! The call graph derived from the IR before optimization.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use era_yul::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::function_call::FunctionCall;
+use era_yul::yul::parser::statement::expression::literal::Literal;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::artifact_directories::ArtifactDirectories;
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::assembly::instruction::Instruction;
+use crate::evmla::assembly::Assembly;
+use crate::yul::parser::dialect::era::EraDialect;
+
+/// The highest address of system contracts and simulations.
+pub const SYSTEM_ADDRESS_MAX: u128 = 0xFFFF;
+
+/// The project-wide call graph file name, without extension.
+pub const FILE_NAME: &str = "call_graph";
+
+/// The DOT file extension.
+pub const EXTENSION_DOT: &str = "dot";
+
+///
+/// Quotes the identifier for the DOT format.
+///
+/// Only double quotes and backslashes are escaped, so non-ASCII characters are kept as they are,
+/// unlike with the Rust debug formatting, whose `\u{...}` escapes are not valid in DOT.
+///
+pub fn quote_dot(identifier: &str) -> String {
+    let mut quoted = String::with_capacity(identifier.len() + 2);
+    quoted.push('"');
+    for character in identifier.chars() {
+        match character {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(character);
+            }
+            '\n' => quoted.push_str("\\n"),
+            character => quoted.push(character),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+///
+/// The call kind.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum CallKind {
+    /// A call to a function of the same object.
+    Internal,
+    /// A call to another contract.
+    External,
+    /// A call to a linked library.
+    Library,
+    /// A call to a system contract or an EraVM simulation.
+    Simulation,
+}
+
+impl std::fmt::Display for CallKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Internal => write!(f, "internal"),
+            Self::External => write!(f, "external"),
+            Self::Library => write!(f, "library"),
+            Self::Simulation => write!(f, "simulation"),
+        }
+    }
+}
+
+///
+/// The call graph edge.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Edge {
+    /// The Yul object identifier, distinguishing the deploy and runtime code.
+    /// For EVM assembly, the contract full path, with the `_deployed` suffix for the runtime code.
+    pub object: String,
+    /// The calling function, or the object identifier for the top-level code.
+    pub caller: String,
+    /// The called function, address, library, or simulation.
+    /// Unset for external calls whose target is not known statically.
+    pub callee: Option<String>,
+    /// The call kind.
+    pub kind: CallKind,
+    /// The number of call sites.
+    pub count: usize,
+}
+
+///
+/// The contract call graph.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ContractCallGraph {
+    /// The call graph edges.
+    pub edges: Vec<Edge>,
+}
+
+impl ContractCallGraph {
+    ///
+    /// Builds the call graph of the Yul object and its inner objects.
+    ///
+    pub fn from_object(object: &Object<EraDialect>) -> Self {
+        let mut edges = BTreeMap::new();
+        let mut current = Some(object);
+        while let Some(object) = current {
+            let mut collector = Collector {
+                object: object.identifier.as_str(),
+                edges: &mut edges,
+            };
+            collector.block(&object.code.block, object.identifier.as_str());
+            current = object.inner_object.as_deref();
+        }
+        Self::from_edges(edges)
+    }
+
+    ///
+    /// Builds the call graph of the EVM assembly deploy and runtime code.
+    ///
+    /// The internal calls are the jumps into functions, that is, `JUMP [in]` preceded by the push
+    /// of the function entry tag. As functions are laid out contiguously, the caller is the last
+    /// function entry tag defined before the call, or the code itself at the top level.
+    ///
+    pub fn from_evmla(full_path: &str, assembly: &Assembly) -> Self {
+        let mut edges = BTreeMap::new();
+        let runtime_object = format!("{full_path}_deployed");
+        let runtime_code = assembly
+            .get_runtime_code()
+            .ok()
+            .and_then(|runtime| runtime.code.as_deref());
+        for (object, code) in [
+            (full_path, assembly.code.as_deref()),
+            (runtime_object.as_str(), runtime_code),
+        ] {
+            if let Some(code) = code {
+                AssemblyCollector {
+                    object,
+                    edges: &mut edges,
+                }
+                .code(code);
+            }
+        }
+        Self::from_edges(edges)
+    }
+
+    ///
+    /// Converts the collected edges along with their numbers of call sites.
+    ///
+    fn from_edges(edges: BTreeMap<EdgeKey, usize>) -> Self {
+        Self {
+            edges: edges
+                .into_iter()
+                .map(|((object, caller, callee, kind), count)| Edge {
+                    object,
+                    caller,
+                    callee,
+                    kind,
+                    count,
+                })
+                .collect(),
+        }
+    }
+
+    ///
+    /// Writes the call graph as a DOT cluster or graph body.
+    ///
+    fn write_dot(&self, dot: &mut String, indent: &str) {
+        for edge in self.edges.iter() {
+            let caller = format!("{}.{}", edge.object, edge.caller);
+            let callee = match (edge.kind, edge.callee.as_deref()) {
+                (CallKind::Internal, Some(callee)) => format!("{}.{callee}", edge.object),
+                (kind, Some(callee)) => format!("{kind}:{callee}"),
+                (kind, None) => format!("{kind}:dynamic"),
+            };
+            dot.push_str(
+                format!(
+                    "{indent}{} -> {} [label=\"{} x{}\"];\n",
+                    quote_dot(caller.as_str()),
+                    quote_dot(callee.as_str()),
+                    edge.kind,
+                    edge.count
+                )
+                .as_str(),
+            );
+        }
+    }
+
+    ///
+    /// Returns the call graph in the DOT format.
+    ///
+    pub fn to_dot(&self, name: &str) -> String {
+        let mut dot = format!("digraph {} {{\n", quote_dot(name));
+        self.write_dot(&mut dot, "  ");
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+///
+/// The project call graph.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CallGraph {
+    /// The contract call graphs, keyed by contract full path.
+    pub contracts: BTreeMap<String, ContractCallGraph>,
+}
+
+impl CallGraph {
+    ///
+    /// Returns the project call graph in the DOT format, with a cluster per contract.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph {} {{\n", quote_dot(FILE_NAME));
+        for (path, contract) in self.contracts.iter() {
+            dot.push_str(
+                format!(
+                    "  subgraph {} {{\n",
+                    quote_dot(format!("cluster_{path}").as_str())
+                )
+                .as_str(),
+            );
+            dot.push_str(format!("    label={};\n", quote_dot(path)).as_str());
+            contract.write_dot(&mut dot, "    ");
+            dot.push_str("  }\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Writes the project-wide and per-contract call graphs in the JSON and DOT formats.
+    ///
+    /// The per-contract graphs are written to `<file name>/<contract name>.<extension>`,
+    /// following the layout of other artifacts, including the directories of sources sharing the file name.
+    ///
+    pub fn write_to_directory(&self, output_directory: &Path) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output_directory)?;
+
+        let artifact_directories = ArtifactDirectories::new(self.contracts.keys().map(|path| {
+            path.rsplit_once(':')
+                .map(|(file_path, _)| file_path)
+                .unwrap_or(path.as_str())
+        }));
+
+        let mut files = vec![
+            (
+                output_directory.join(format!(
+                    "{FILE_NAME}.{}",
+                    era_compiler_common::EXTENSION_JSON
+                )),
+                serde_json::to_string_pretty(self).expect("Always valid"),
+            ),
+            (
+                output_directory.join(format!("{FILE_NAME}.{EXTENSION_DOT}")),
+                self.to_dot(),
+            ),
+        ];
+        for (path, contract) in self.contracts.iter() {
+            let (file_path, name) = path
+                .rsplit_once(':')
+                .unwrap_or((path.as_str(), path.as_str()));
+            let contract_directory = output_directory.join(artifact_directories.get(file_path));
+            std::fs::create_dir_all(contract_directory.as_path())?;
+
+            files.push((
+                contract_directory.join(format!(
+                    "{name}.{FILE_NAME}.{}",
+                    era_compiler_common::EXTENSION_JSON
+                )),
+                serde_json::to_string_pretty(contract).expect("Always valid"),
+            ));
+            files.push((
+                contract_directory.join(format!("{name}.{FILE_NAME}.{EXTENSION_DOT}")),
+                contract.to_dot(path.as_str()),
+            ));
+        }
+
+        for (path, contents) in files.into_iter() {
+            std::fs::write(path.as_path(), contents)
+                .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        }
+        Ok(())
+    }
+}
+
+///
+/// The call graph edge key: object, caller, callee, and call kind.
+///
+type EdgeKey = (String, String, Option<String>, CallKind);
+
+///
+/// The Yul object traversal collecting the call graph edges.
+///
+struct Collector<'a> {
+    /// The current Yul object identifier.
+    object: &'a str,
+    /// The edges collected so far, along with the numbers of call sites.
+    edges: &'a mut BTreeMap<EdgeKey, usize>,
+}
+
+impl<'a> Collector<'a> {
+    ///
+    /// Collects the calls in the block.
+    ///
+    fn block(&mut self, block: &Block<EraDialect>, caller: &str) {
+        for statement in block.statements.iter() {
+            match statement {
+                Statement::Block(block) => self.block(block, caller),
+                Statement::Expression(expression) => self.expression(expression, caller),
+                Statement::FunctionDefinition(function) => {
+                    self.block(&function.body, function.identifier.as_str())
+                }
+                Statement::VariableDeclaration(declaration) => {
+                    if let Some(expression) = declaration.expression.as_ref() {
+                        self.expression(expression, caller);
+                    }
+                }
+                Statement::Assignment(assignment) => {
+                    self.expression(&assignment.initializer, caller)
+                }
+                Statement::IfConditional(if_conditional) => {
+                    self.expression(&if_conditional.condition, caller);
+                    self.block(&if_conditional.block, caller);
+                }
+                Statement::Switch(switch) => {
+                    self.expression(&switch.expression, caller);
+                    for case in switch.cases.iter() {
+                        self.block(&case.block, caller);
+                    }
+                    if let Some(default) = switch.default.as_ref() {
+                        self.block(default, caller);
+                    }
+                }
+                Statement::ForLoop(for_loop) => {
+                    self.block(&for_loop.initializer, caller);
+                    self.expression(&for_loop.condition, caller);
+                    self.block(&for_loop.finalizer, caller);
+                    self.block(&for_loop.body, caller);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Collects the calls in the expression.
+    ///
+    fn expression(&mut self, expression: &Expression, caller: &str) {
+        let Expression::FunctionCall(call) = expression else {
+            return;
+        };
+
+        if let Some((callee, kind)) = Self::classify(call) {
+            *self
+                .edges
+                .entry((self.object.to_owned(), caller.to_owned(), callee, kind))
+                .or_default() += 1;
+        }
+        for argument in call.arguments.iter() {
+            self.expression(argument, caller);
+        }
+    }
+
+    ///
+    /// Returns the callee and call kind, if the function call is a call.
+    ///
+    fn classify(call: &FunctionCall) -> Option<(Option<String>, CallKind)> {
+        match call.name {
+            FunctionName::UserDefined(ref name) => {
+                Some((Some(name.to_owned()), CallKind::Internal))
+            }
+            FunctionName::Call
+            | FunctionName::CallCode
+            | FunctionName::DelegateCall
+            | FunctionName::StaticCall => Some(Self::target(call.arguments.get(1))),
+            FunctionName::Verbatim { .. } => {
+                let name = match call.arguments.first() {
+                    Some(Expression::Literal(Literal {
+                        inner: LexicalLiteral::String(name),
+                        ..
+                    })) => Some(name.inner.to_owned()),
+                    _ => None,
+                };
+                Some((name, CallKind::Simulation))
+            }
+            FunctionName::ZkMimicCall
+            | FunctionName::ZkSystemMimicCall
+            | FunctionName::ZkMimicCallByRef
+            | FunctionName::ZkSystemMimicCallByRef
+            | FunctionName::ZkRawCall
+            | FunctionName::ZkRawCallByRef
+            | FunctionName::ZkSystemCall
+            | FunctionName::ZkSystemCallByRef
+            | FunctionName::ZkStaticRawCall
+            | FunctionName::ZkStaticRawCallByRef
+            | FunctionName::ZkStaticSystemCall
+            | FunctionName::ZkStaticSystemCallByRef
+            | FunctionName::ZkDelegateRawCall
+            | FunctionName::ZkDelegateRawCallByRef
+            | FunctionName::ZkDelegateSystemCall
+            | FunctionName::ZkDelegateSystemCallByRef => {
+                let (callee, _) = Self::target(call.arguments.first());
+                Some((callee, CallKind::Simulation))
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Resolves the call target from the address argument, if it is known statically.
+    ///
+    fn target(address: Option<&Expression>) -> (Option<String>, CallKind) {
+        match address {
+            Some(Expression::Literal(Literal {
+                inner: LexicalLiteral::Integer(integer),
+                ..
+            })) => {
+                let value = match integer {
+                    IntegerLiteral::Decimal { inner } => inner.parse::<u128>().ok(),
+                    IntegerLiteral::Hexadecimal { inner } => {
+                        u128::from_str_radix(inner.trim_start_matches("0x"), 16).ok()
+                    }
+                };
+                match value {
+                    Some(value) if value <= SYSTEM_ADDRESS_MAX => {
+                        (Some(format!("0x{value:x}")), CallKind::Simulation)
+                    }
+                    _ => (Some(integer.to_string()), CallKind::External),
+                }
+            }
+            Some(Expression::FunctionCall(FunctionCall {
+                name: FunctionName::LinkerSymbol,
+                arguments,
+                ..
+            })) => match arguments.first() {
+                Some(Expression::Literal(Literal {
+                    inner: LexicalLiteral::String(path),
+                    ..
+                })) => (Some(path.inner.to_owned()), CallKind::Library),
+                _ => (None, CallKind::Library),
+            },
+            _ => (None, CallKind::External),
+        }
+    }
+}
+
+///
+/// The EVM assembly traversal collecting the call graph edges.
+///
+struct AssemblyCollector<'a> {
+    /// The current code object identifier.
+    object: &'a str,
+    /// The edges collected so far, along with the numbers of call sites.
+    edges: &'a mut BTreeMap<EdgeKey, usize>,
+}
+
+impl<'a> AssemblyCollector<'a> {
+    ///
+    /// Collects the calls in the deploy or runtime code.
+    ///
+    fn code(&mut self, code: &[Instruction]) {
+        let entries: BTreeSet<&str> = code
+            .windows(2)
+            .filter_map(|pair| Self::internal_callee(&pair[0], &pair[1]))
+            .collect();
+
+        let mut caller = self.object.to_owned();
+        for (index, instruction) in code.iter().enumerate() {
+            let previous = index.checked_sub(1).map(|index| &code[index]);
+            let (callee, kind) = match instruction.name {
+                InstructionName::Tag => {
+                    if let Some(tag) = instruction
+                        .value
+                        .as_deref()
+                        .filter(|tag| entries.contains(tag))
+                    {
+                        caller = format!("tag_{tag}");
+                    }
+                    continue;
+                }
+                InstructionName::JUMP => {
+                    match previous.and_then(|previous| Self::internal_callee(previous, instruction))
+                    {
+                        Some(tag) => (Some(format!("tag_{tag}")), CallKind::Internal),
+                        None => continue,
+                    }
+                }
+                InstructionName::CALL
+                | InstructionName::CALLCODE
+                | InstructionName::DELEGATECALL
+                | InstructionName::STATICCALL => Self::target(&code[..index]),
+                InstructionName::PUSHLIB => (instruction.value.to_owned(), CallKind::Library),
+                _ => continue,
+            };
+            *self
+                .edges
+                .entry((self.object.to_owned(), caller.to_owned(), callee, kind))
+                .or_default() += 1;
+        }
+    }
+
+    ///
+    /// Returns the entry tag of the function called by the jump, if the instructions are a jump into a function.
+    ///
+    fn internal_callee<'i>(push: &'i Instruction, jump: &Instruction) -> Option<&'i str> {
+        match (&push.name, &jump.name, jump.value.as_deref()) {
+            (InstructionName::PUSH_Tag, InstructionName::JUMP, Some("[in]")) => {
+                push.value.as_deref()
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Resolves the call target from the instructions preceding the call, if it is known statically.
+    ///
+    /// The target is only known if the address is pushed as a literal right before the gas,
+    /// which is the order the arguments are pushed in by `solc`.
+    ///
+    fn target(preceding: &[Instruction]) -> (Option<String>, CallKind) {
+        let [.., address, gas] = preceding else {
+            return (None, CallKind::External);
+        };
+        match (&address.name, &gas.name, address.value.as_deref()) {
+            (InstructionName::PUSH, InstructionName::GAS, Some(value)) => {
+                match u128::from_str_radix(value, 16) {
+                    Ok(value) if value <= SYSTEM_ADDRESS_MAX => {
+                        (Some(format!("0x{value:x}")), CallKind::Simulation)
+                    }
+                    _ => (
+                        Some(format!("0x{}", value.to_lowercase())),
+                        CallKind::External,
+                    ),
+                }
+            }
+            _ => (None, CallKind::External),
+        }
+    }
+}
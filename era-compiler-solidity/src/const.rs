@@ -5,6 +5,12 @@
 /// The default executable name.
 pub static DEFAULT_EXECUTABLE_NAME: &str = "zksolc";
 
+/// The ABI JSON file extension.
+pub const EXTENSION_ABI: &str = "abi";
+
+/// The function signature hashes file extension.
+pub const EXTENSION_SIGNATURES: &str = "signatures";
+
 /// The `keccak256` scratch space offset.
 pub const OFFSET_SCRATCH_SPACE: usize = 0;
 
@@ -0,0 +1,88 @@
+//!
+//! The contract allow-list filter.
+//!
+
+///
+/// The contract allow-list filter.
+///
+/// Contracts are matched by their full paths, e.g. `contracts/Router.sol:Router`, against glob patterns,
+/// where `*` matches any sequence of characters, and `?` matches any single character.
+/// If the target contract is set, only the one matching it among the allowed contracts is compiled.
+///
+#[derive(Debug, Clone)]
+pub struct ContractFilter {
+    /// The glob patterns. All contracts are allowed if empty.
+    pub patterns: Vec<String>,
+    /// The target contract, specified as `<path>:<name>` or `<name>`.
+    pub contract: Option<String>,
+}
+
+impl ContractFilter {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Returns `None` if there are no patterns and no target contract, that is, all contracts are allowed.
+    ///
+    pub fn new(patterns: Vec<String>, contract: Option<String>) -> Option<Self> {
+        if patterns.is_empty() && contract.is_none() {
+            return None;
+        }
+
+        let patterns = patterns
+            .into_iter()
+            .map(|pattern| era_solc::path::normalize(pattern.as_str()))
+            .collect();
+        Some(Self { patterns, contract })
+    }
+
+    ///
+    /// Checks whether the contract full path matches any of the patterns.
+    ///
+    pub fn is_match(&self, full_path: &str) -> bool {
+        self.patterns.is_empty()
+            || self
+                .patterns
+                .iter()
+                .any(|pattern| Self::is_glob_match(pattern.as_bytes(), full_path.as_bytes()))
+    }
+
+    ///
+    /// Matches `text` against the glob `pattern`, backtracking to the last `*` on mismatch.
+    ///
+    fn is_glob_match(pattern: &[u8], text: &[u8]) -> bool {
+        let mut pattern_index = 0;
+        let mut text_index = 0;
+        let mut backtrack = None;
+
+        while text_index < text.len() {
+            match pattern.get(pattern_index) {
+                Some(b'*') => {
+                    backtrack = Some((pattern_index, text_index));
+                    pattern_index += 1;
+                }
+                Some(&character) if character == b'?' || character == text[text_index] => {
+                    pattern_index += 1;
+                    text_index += 1;
+                }
+                _ => match backtrack {
+                    Some((star_index, star_text_index)) => {
+                        pattern_index = star_index + 1;
+                        text_index = star_text_index + 1;
+                        backtrack = Some((star_index, star_text_index + 1));
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        pattern[pattern_index..]
+            .iter()
+            .all(|character| *character == b'*')
+    }
+}
+
+impl std::fmt::Display for ContractFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.patterns.join("`, `"))
+    }
+}
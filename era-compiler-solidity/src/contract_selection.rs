@@ -0,0 +1,66 @@
+//!
+//! The explicit target contract selection.
+//!
+
+///
+/// Selects the contract full path matching `target` among `full_paths`.
+///
+/// The target is either a full path, e.g. `contracts/Token.sol:Token`, a path suffix, e.g. `Token.sol:Token`,
+/// or a bare contract name, e.g. `Token`. The exact full path match always takes precedence.
+///
+pub fn select<'a, I>(full_paths: I, target: &str) -> anyhow::Result<String>
+where
+    I: IntoIterator<Item = &'a String>,
+{
+    let target = era_solc::path::normalize(target);
+    let full_paths = full_paths.into_iter().collect::<Vec<&String>>();
+    if let Some(full_path) = full_paths
+        .iter()
+        .find(|full_path| full_path.as_str() == target.as_str())
+    {
+        return Ok((*full_path).to_owned());
+    }
+
+    let candidates = full_paths
+        .iter()
+        .filter(|full_path| is_match(full_path.as_str(), target.as_str()))
+        .collect::<Vec<_>>();
+    match candidates.as_slice() {
+        [full_path] => Ok((**full_path).to_owned()),
+        [] => anyhow::bail!(
+            "Contract `{target}` not found. Available contracts:\n{}",
+            list(full_paths.into_iter())
+        ),
+        candidates => anyhow::bail!(
+            "Contract `{target}` is ambiguous. Please specify it as `<path>:<name>`. Candidates:\n{}",
+            list(candidates.iter().map(|full_path| **full_path))
+        ),
+    }
+}
+
+///
+/// Checks whether the full path matches the name or path suffix.
+///
+fn is_match(full_path: &str, target: &str) -> bool {
+    if target.contains(':') {
+        return full_path.ends_with(format!("/{target}").as_str());
+    }
+
+    match full_path.rsplit_once(':') {
+        Some((_, name)) => name == target,
+        None => full_path == target || full_path.ends_with(format!("/{target}").as_str()),
+    }
+}
+
+///
+/// Formats the full paths as a list for error messages.
+///
+fn list<'a, I>(full_paths: I) -> String
+where
+    I: Iterator<Item = &'a String>,
+{
+    full_paths
+        .map(|full_path| format!("    {full_path}"))
+        .collect::<Vec<String>>()
+        .join("\n")
+}
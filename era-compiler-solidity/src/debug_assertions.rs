@@ -0,0 +1,296 @@
+//!
+//! The debug assertions mode.
+//!
+
+use era_compiler_llvm_context::IContext;
+
+/// The named metadata marking the modules translated with the debug assertions enabled.
+pub const ENABLED_METADATA_NAME: &str = "zksolc.debug_assertions";
+
+/// The metadata kind of the provenance tags.
+pub const PROVENANCE_METADATA_KIND: &str = "zksolc.provenance";
+
+/// The provenance of the instructions generated outside of the front-end constructs,
+/// such as the contract entry point and function prologues.
+pub const PROVENANCE_GENERATED: &str = "generated";
+
+///
+/// Enables the debug assertions for the module of `context`.
+///
+/// The module is marked with the named metadata, so the setting is carried by the module itself.
+///
+pub fn enable<D>(context: &era_compiler_llvm_context::EraVMContext<D>) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    let node = context.llvm().metadata_node(&[]);
+    context
+        .module()
+        .add_global_metadata(ENABLED_METADATA_NAME, &node)
+        .map_err(|error| anyhow::anyhow!("Debug assertions enabling: {error}"))
+}
+
+///
+/// Whether the debug assertions are enabled for the module of `context`.
+///
+pub fn is_enabled<D>(context: &era_compiler_llvm_context::EraVMContext<D>) -> bool
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    context
+        .module()
+        .get_global_metadata_size(ENABLED_METADATA_NAME)
+        > 0
+}
+
+///
+/// The position in the current function the instructions of a front-end construct are generated from.
+///
+#[derive(Debug, Clone, Copy)]
+pub struct Marker<'ctx> {
+    /// The current basic block.
+    block: inkwell::basic_block::BasicBlock<'ctx>,
+    /// The last instruction of the current basic block.
+    instruction: Option<inkwell::values::InstructionValue<'ctx>>,
+    /// The last basic block of the current function.
+    last_block: Option<inkwell::basic_block::BasicBlock<'ctx>>,
+}
+
+///
+/// Marks the position the instructions of the front-end construct about to be translated
+/// are generated from.
+///
+/// Returns `None` if the debug assertions are disabled.
+///
+pub fn mark<'ctx, D>(
+    context: &era_compiler_llvm_context::EraVMContext<'ctx, D>,
+) -> Option<Marker<'ctx>>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    if !is_enabled(context) {
+        return None;
+    }
+    let block = context.basic_block();
+    let function = context.current_function().borrow().declaration().value;
+    Some(Marker {
+        block,
+        instruction: block.get_last_instruction(),
+        last_block: function.get_last_basic_block(),
+    })
+}
+
+///
+/// Tags the untagged instructions generated since the `marker` with the `provenance` of the
+/// front-end construct that has just been translated.
+///
+/// Only the instructions appended to the marked basic block and to the basic blocks created
+/// afterwards are visited, so the tagging is linear in the size of the construct.
+/// Nested constructs are translated first, so each instruction is tagged with the innermost one.
+/// The `provenance` is only evaluated if the debug assertions are enabled.
+///
+/// # Errors
+/// If the construct has generated instructions after a basic block terminator.
+///
+pub fn tag<'ctx, D>(
+    context: &era_compiler_llvm_context::EraVMContext<'ctx, D>,
+    marker: Option<Marker<'ctx>>,
+    provenance: impl FnOnce() -> String,
+) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    let Some(marker) = marker else {
+        return Ok(());
+    };
+    let provenance = provenance();
+    let mut tagger = Tagger::new(context.llvm(), provenance.as_str());
+
+    if let Some(instruction) = marker.instruction {
+        if is_terminator(instruction) && instruction.get_next_instruction().is_some() {
+            anyhow::bail!(
+                "Debug assertion failed: `{provenance}` has generated instructions after a basic block terminator."
+            );
+        }
+    }
+    tagger.tag_from(match marker.instruction {
+        Some(instruction) => instruction.get_next_instruction(),
+        None => marker.block.get_first_instruction(),
+    })?;
+
+    let mut block = match marker.last_block {
+        Some(block) => block.get_next_basic_block(),
+        None => context
+            .current_function()
+            .borrow()
+            .declaration()
+            .value
+            .get_first_basic_block(),
+    };
+    while let Some(current) = block {
+        tagger.tag_from(current.get_first_instruction())?;
+        block = current.get_next_basic_block();
+    }
+
+    let current = context.basic_block();
+    if current != marker.block {
+        tagger.tag_from(current.get_first_instruction())?;
+    }
+    Ok(())
+}
+
+///
+/// Tags the untagged instructions of the current function with the `provenance` of the
+/// function definition that has just been translated.
+///
+/// # Errors
+/// If the function has instructions after a basic block terminator.
+///
+pub fn tag_function<D>(
+    context: &era_compiler_llvm_context::EraVMContext<D>,
+    provenance: impl FnOnce() -> String,
+) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    if !is_enabled(context) {
+        return Ok(());
+    }
+    let provenance = provenance();
+    let mut tagger = Tagger::new(context.llvm(), provenance.as_str());
+    let function = context.current_function().borrow().declaration().value;
+    for block in function.get_basic_blocks() {
+        tagger.tag_from(block.get_first_instruction())?;
+    }
+    Ok(())
+}
+
+///
+/// Tags the remaining untagged instructions of the module as generated, and checks that the
+/// translation `phase` has terminated every basic block.
+///
+/// Unlike the LLVM IR verification, the error names the provenance of the offending instructions,
+/// so the translation rule that produced them can be pinpointed.
+///
+pub fn check<D>(
+    context: &era_compiler_llvm_context::EraVMContext<D>,
+    phase: &str,
+) -> anyhow::Result<()>
+where
+    D: era_compiler_llvm_context::Dependency,
+{
+    if !is_enabled(context) {
+        return Ok(());
+    }
+    let mut tagger = Tagger::new(context.llvm(), PROVENANCE_GENERATED);
+    for function in context.module().get_functions() {
+        for block in function.get_basic_blocks() {
+            tagger.tag_from(block.get_first_instruction())?;
+            if block.get_terminator().is_none() {
+                let provenance = block
+                    .get_last_instruction()
+                    .and_then(|instruction| tagger.provenance(instruction))
+                    .unwrap_or_else(|| PROVENANCE_GENERATED.to_owned());
+                anyhow::bail!(
+                    "Debug assertion failed after {phase}: a basic block of function `{}` is not terminated after `{provenance}`.",
+                    function.get_name().to_string_lossy(),
+                );
+            }
+        }
+    }
+    Ok(())
+}
+
+///
+/// Whether the `instruction` terminates its basic block.
+///
+fn is_terminator(instruction: inkwell::values::InstructionValue) -> bool {
+    matches!(
+        instruction.get_opcode(),
+        inkwell::values::InstructionOpcode::Return
+            | inkwell::values::InstructionOpcode::Br
+            | inkwell::values::InstructionOpcode::Switch
+            | inkwell::values::InstructionOpcode::IndirectBr
+            | inkwell::values::InstructionOpcode::Invoke
+            | inkwell::values::InstructionOpcode::Resume
+            | inkwell::values::InstructionOpcode::Unreachable
+            | inkwell::values::InstructionOpcode::CallBr
+            | inkwell::values::InstructionOpcode::CatchRet
+            | inkwell::values::InstructionOpcode::CatchSwitch
+            | inkwell::values::InstructionOpcode::CleanupRet
+    )
+}
+
+///
+/// The instruction tagger of a single provenance.
+///
+struct Tagger<'ctx, 'a> {
+    /// The LLVM context.
+    llvm: &'ctx inkwell::context::Context,
+    /// The provenance metadata kind identifier.
+    kind_id: u32,
+    /// The provenance to tag the instructions with.
+    provenance: &'a str,
+    /// The provenance metadata node, created on first use.
+    node: Option<inkwell::values::MetadataValue<'ctx>>,
+}
+
+impl<'ctx, 'a> Tagger<'ctx, 'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(llvm: &'ctx inkwell::context::Context, provenance: &'a str) -> Self {
+        Self {
+            llvm,
+            kind_id: llvm.get_kind_id(PROVENANCE_METADATA_KIND),
+            provenance,
+            node: None,
+        }
+    }
+
+    ///
+    /// Tags the untagged instructions from `first` to the end of its basic block.
+    ///
+    /// # Errors
+    /// If there are instructions after the basic block terminator.
+    ///
+    fn tag_from(
+        &mut self,
+        first: Option<inkwell::values::InstructionValue<'ctx>>,
+    ) -> anyhow::Result<()> {
+        let mut instruction = first;
+        while let Some(current) = instruction {
+            if current.get_metadata(self.kind_id).is_none() {
+                let node = *self.node.get_or_insert_with(|| {
+                    self.llvm
+                        .metadata_node(&[self.llvm.metadata_string(self.provenance).into()])
+                });
+                current
+                    .set_metadata(node, self.kind_id)
+                    .expect("Always valid");
+            }
+            instruction = current.get_next_instruction();
+            if let Some(next) = instruction.filter(|_| is_terminator(current)) {
+                anyhow::bail!(
+                    "Debug assertion failed: `{}` has generated instructions after a basic block terminator.",
+                    self.provenance(next)
+                        .unwrap_or_else(|| self.provenance.to_owned()),
+                );
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the provenance the `instruction` is tagged with.
+    ///
+    fn provenance(&self, instruction: inkwell::values::InstructionValue<'ctx>) -> Option<String> {
+        let node = instruction.get_metadata(self.kind_id)?;
+        match node.get_node_values().into_iter().next()? {
+            inkwell::values::BasicMetadataValueEnum::MetadataValue(value) => {
+                Some(value.get_string_value()?.to_string_lossy().into_owned())
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,299 @@
+//!
+//! The bytecode deduplication report.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::bytecode_explanation::BytecodeExplanation;
+
+/// The minimal number of instructions of a function to be reported as a duplicate.
+/// Smaller functions are usually cheaper to keep than to call.
+pub const MIN_FUNCTION_SIZE: usize = 8;
+
+/// The minimal similarity of two contracts to be reported as near-duplicates.
+pub const MIN_CONTRACT_SIMILARITY: f64 = 0.8;
+
+/// The number of consecutive instructions in a shingle used to compare contracts.
+pub const SHINGLE_SIZE: usize = 4;
+
+///
+/// The function occurrence in the build.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct FunctionOccurrence {
+    /// The contract full path.
+    pub contract: String,
+    /// The function name.
+    pub function: String,
+}
+
+///
+/// The group of functions with equal normalized instruction sequences.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DuplicateFunctions {
+    /// The function size in bytes.
+    pub size: usize,
+    /// The estimated savings in bytes if the functions are consolidated into one.
+    pub estimated_savings: usize,
+    /// The function occurrences.
+    pub occurrences: Vec<FunctionOccurrence>,
+}
+
+///
+/// The pair of contracts with similar normalized instruction sequences.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SimilarContracts {
+    /// The contract full paths.
+    pub contracts: [String; 2],
+    /// The Jaccard similarity of the instruction shingles, from 0 to 1.
+    pub similarity: f64,
+    /// The estimated savings in bytes if the common code is moved to a shared library or base.
+    pub estimated_savings: usize,
+}
+
+///
+/// The bytecode deduplication report.
+///
+/// Instructions are normalized by replacing label and constant references with a placeholder,
+/// so functions and contracts only differing in their code layout are considered equal.
+/// The savings are estimated from the EraVM instruction sizes, and do not account for the
+/// calls introduced by the consolidation.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DedupReport {
+    /// The duplicate functions, sorted by estimated savings in descending order.
+    pub functions: Vec<DuplicateFunctions>,
+    /// The near-duplicate contracts, sorted by estimated savings in descending order.
+    pub contracts: Vec<SimilarContracts>,
+    /// The total estimated savings of the duplicate functions in bytes.
+    pub estimated_savings: usize,
+}
+
+impl DedupReport {
+    ///
+    /// Extracts the report from the EraVM assembly of the contracts in the standard JSON output at `path`.
+    ///
+    pub fn try_from_standard_json(path: &Path) -> anyhow::Result<Self> {
+        let output = BytecodeExplanation::read_standard_json(path)?;
+        let assemblies = BytecodeExplanation::get_assemblies(&output);
+        if assemblies.is_empty() {
+            anyhow::bail!(
+                "No contracts with EraVM assembly found in {path:?}. Select `eravm.assemblyJson` or `eravm.assembly` in the standard JSON output selection."
+            );
+        }
+        Ok(Self::new(&assemblies))
+    }
+
+    ///
+    /// Extracts the report from the EraVM assembly of the contracts, keyed by full path.
+    ///
+    pub fn new(
+        assemblies: &BTreeMap<String, era_solc::StandardJsonOutputContractEraVMAssembly>,
+    ) -> Self {
+        let mut functions: BTreeMap<Vec<String>, BTreeSet<FunctionOccurrence>> = BTreeMap::new();
+        let mut contract_shingles = Vec::with_capacity(assemblies.len());
+        for (path, assembly) in assemblies.iter() {
+            let mut bodies: BTreeMap<&str, Vec<String>> = BTreeMap::new();
+            let mut sequence = Vec::with_capacity(assembly.instructions.len());
+            for instruction in assembly.instructions.iter() {
+                let normalized = Self::normalize(instruction);
+                if let Some(function) = instruction.function.as_deref() {
+                    bodies.entry(function).or_default().push(normalized.clone());
+                }
+                sequence.push(normalized);
+            }
+            for (function, body) in bodies.into_iter() {
+                if body.len() < MIN_FUNCTION_SIZE {
+                    continue;
+                }
+                functions
+                    .entry(body)
+                    .or_default()
+                    .insert(FunctionOccurrence {
+                        contract: path.to_owned(),
+                        function: function.to_owned(),
+                    });
+            }
+
+            let shingles = sequence
+                .windows(SHINGLE_SIZE)
+                .map(|window| window.join(";"))
+                .collect::<BTreeSet<String>>();
+            contract_shingles.push((path.as_str(), shingles, sequence.len()));
+        }
+
+        let mut report = Self::default();
+        for (body, occurrences) in functions.into_iter() {
+            if occurrences.len() < 2 {
+                continue;
+            }
+            let size =
+                body.len() * era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE;
+            report.functions.push(DuplicateFunctions {
+                size,
+                estimated_savings: size * (occurrences.len() - 1),
+                occurrences: occurrences.into_iter().collect(),
+            });
+        }
+        report
+            .functions
+            .sort_by(|a, b| b.estimated_savings.cmp(&a.estimated_savings));
+        report.estimated_savings = report
+            .functions
+            .iter()
+            .map(|functions| functions.estimated_savings)
+            .sum();
+
+        report.contracts = Self::find_similar_contracts(contract_shingles.as_slice());
+        report
+    }
+
+    ///
+    /// Writes the report to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let report = serde_json::to_vec_pretty(self).expect("Always valid");
+        std::fs::write(path, report)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        Ok(())
+    }
+
+    ///
+    /// Finds the pairs of contracts whose shingle sets have the Jaccard similarity of at least
+    /// `MIN_CONTRACT_SIMILARITY`, given as tuples of the full path, the shingles, and the number of instructions.
+    ///
+    /// Instead of comparing every pair, the prefix filtering similarity join is used. The shingles
+    /// are ordered from the rarest to the most common across the contracts, and two sets with the
+    /// required similarity always share a shingle in their short prefixes, so only the contracts
+    /// sharing a prefix shingle are compared. Contracts are processed in ascending size order,
+    /// so the ones too small to reach the required similarity are skipped as well.
+    ///
+    fn find_similar_contracts(
+        contracts: &[(&str, BTreeSet<String>, usize)],
+    ) -> Vec<SimilarContracts> {
+        let mut frequencies: BTreeMap<&str, usize> = BTreeMap::new();
+        for (_, shingles, _) in contracts.iter() {
+            for shingle in shingles.iter() {
+                *frequencies.entry(shingle.as_str()).or_default() += 1;
+            }
+        }
+        let mut order = frequencies.into_iter().collect::<Vec<(&str, usize)>>();
+        order.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(b.0)));
+        let ranks = order
+            .into_iter()
+            .enumerate()
+            .map(|(rank, (shingle, _))| (shingle, rank))
+            .collect::<BTreeMap<&str, usize>>();
+
+        let tokens = contracts
+            .iter()
+            .map(|(_, shingles, _)| {
+                let mut tokens = shingles
+                    .iter()
+                    .map(|shingle| ranks[shingle.as_str()])
+                    .collect::<Vec<usize>>();
+                tokens.sort_unstable();
+                tokens
+            })
+            .collect::<Vec<Vec<usize>>>();
+        let mut indexes = (0..contracts.len()).collect::<Vec<usize>>();
+        indexes.sort_by_key(|index| tokens[*index].len());
+
+        let mut result = Vec::new();
+        let mut inverted_index: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for index_a in indexes.into_iter() {
+            let tokens_a = tokens[index_a].as_slice();
+            if tokens_a.is_empty() {
+                continue;
+            }
+            // Rounded down, so the filters never skip a similar pair.
+            let min_overlap = (MIN_CONTRACT_SIMILARITY * tokens_a.len() as f64).floor() as usize;
+            let prefix = &tokens_a[..(tokens_a.len() - min_overlap + 1).min(tokens_a.len())];
+
+            let candidates = prefix
+                .iter()
+                .filter_map(|token| inverted_index.get(token))
+                .flatten()
+                .copied()
+                .filter(|index_b| tokens[*index_b].len() >= min_overlap)
+                .collect::<BTreeSet<usize>>();
+            for index_b in candidates.into_iter() {
+                let tokens_b = tokens[index_b].as_slice();
+                let intersection = Self::count_common(tokens_a, tokens_b);
+                let union = tokens_a.len() + tokens_b.len() - intersection;
+                let similarity = intersection as f64 / union as f64;
+                if similarity < MIN_CONTRACT_SIMILARITY {
+                    continue;
+                }
+
+                let (path_a, _, size_a) = &contracts[index_a];
+                let (path_b, _, size_b) = &contracts[index_b];
+                let size = *size_a.min(size_b)
+                    * era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE;
+                let mut pair = [path_a.to_string(), path_b.to_string()];
+                pair.sort();
+                result.push(SimilarContracts {
+                    contracts: pair,
+                    similarity,
+                    estimated_savings: (size as f64 * similarity) as usize,
+                });
+            }
+
+            for token in prefix.iter() {
+                inverted_index.entry(*token).or_default().push(index_a);
+            }
+        }
+        result.sort_by(|a, b| {
+            b.estimated_savings
+                .cmp(&a.estimated_savings)
+                .then_with(|| a.contracts.cmp(&b.contracts))
+        });
+        result
+    }
+
+    ///
+    /// Counts the common elements of two sorted slices.
+    ///
+    fn count_common(a: &[usize], b: &[usize]) -> usize {
+        let (mut index_a, mut index_b, mut count) = (0, 0, 0);
+        while index_a < a.len() && index_b < b.len() {
+            match a[index_a].cmp(&b[index_b]) {
+                std::cmp::Ordering::Less => index_a += 1,
+                std::cmp::Ordering::Greater => index_b += 1,
+                std::cmp::Ordering::Equal => {
+                    count += 1;
+                    index_a += 1;
+                    index_b += 1;
+                }
+            }
+        }
+        count
+    }
+
+    ///
+    /// Normalizes the instruction by replacing the operands referencing labels and constants with `@`.
+    ///
+    fn normalize(
+        instruction: &era_solc::StandardJsonOutputContractEraVMAssemblyInstruction,
+    ) -> String {
+        let operands = instruction
+            .operands
+            .iter()
+            .map(|operand| {
+                if operand.contains('@') || operand.starts_with('.') {
+                    "@"
+                } else {
+                    operand.as_str()
+                }
+            })
+            .collect::<Vec<&str>>();
+        format!("{} {}", instruction.mnemonic, operands.join(","))
+    }
+}
@@ -0,0 +1,226 @@
+//!
+//! The factory dependency cycle detection.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::lexer::token::location::Location;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::literal::Literal;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// The factory dependency edge: the dependency full path and the location it is referenced at, if known.
+///
+pub type Edge = (String, Option<String>);
+
+///
+/// Finds the elementary factory dependency cycles with Johnson's algorithm.
+///
+/// `dependencies` maps each contract full path to its factory dependencies.
+/// Each cycle is returned once, starting from its lexicographically smallest contract,
+/// as the list of contracts along with the locations their successors are referenced at.
+///
+pub fn find(dependencies: &BTreeMap<String, Vec<Edge>>) -> Vec<Vec<Edge>> {
+    let mut cycles = BTreeSet::new();
+    for start in dependencies.keys() {
+        let mut search = Search {
+            start: start.as_str(),
+            dependencies,
+            stack: Vec::new(),
+            blocked: BTreeSet::new(),
+            blocked_by: BTreeMap::new(),
+            cycles: &mut cycles,
+        };
+        search.circuit(start.as_str());
+    }
+    cycles.into_iter().collect()
+}
+
+///
+/// Formats the cycle as a chain of contracts, closed with its first contract.
+///
+pub fn format(cycle: &[Edge]) -> String {
+    let mut chain = String::new();
+    for (path, location) in cycle.iter() {
+        chain.push_str(format!("\n    {path}").as_str());
+        if let Some(location) = location {
+            chain.push_str(format!("\n      --> deploys the next contract at {location}").as_str());
+        }
+    }
+    if let Some((path, _)) = cycle.first() {
+        chain.push_str(format!("\n    {path}").as_str());
+    }
+    format!(
+        "Cyclic factory dependency: contracts deploying each other would have to include each other's bytecode hashes. The cycle is:{chain}"
+    )
+}
+
+///
+/// Returns the locations of the `dataoffset` and `datasize` calls referencing the factory dependencies,
+/// keyed by dependency object identifier.
+///
+/// Only the first reference to each dependency is returned.
+///
+pub fn get_yul_locations(object: &Object<EraDialect>) -> BTreeMap<String, Location> {
+    let mut locations = BTreeMap::new();
+    let mut current = Some(object);
+    while let Some(object) = current {
+        get_block_locations(&object.code.block, &mut locations);
+        current = object.inner_object.as_deref();
+    }
+    locations
+}
+
+///
+/// The search for the cycles starting from a contract.
+///
+/// Only the contracts not preceding the start one are visited, so each cycle is only found from
+/// its smallest contract.
+///
+struct Search<'a> {
+    /// The contract the cycles start from.
+    start: &'a str,
+    /// The factory dependencies of each contract.
+    dependencies: &'a BTreeMap<String, Vec<Edge>>,
+    /// The current path from the start contract.
+    stack: Vec<Edge>,
+    /// The contracts that cannot close a cycle until one of their successors is unblocked.
+    blocked: BTreeSet<&'a str>,
+    /// The blocked contracts to unblock along with each contract.
+    blocked_by: BTreeMap<&'a str, BTreeSet<&'a str>>,
+    /// The cycles found so far.
+    cycles: &'a mut BTreeSet<Vec<Edge>>,
+}
+
+impl<'a> Search<'a> {
+    ///
+    /// Visits the contract, recording the cycles closed by its dependencies.
+    ///
+    /// Returns whether any cycle passes through the contract.
+    ///
+    fn circuit(&mut self, path: &'a str) -> bool {
+        let mut is_found = false;
+        self.blocked.insert(path);
+
+        let dependencies = self.dependencies;
+        let start = self.start;
+        let edges = dependencies
+            .get(path)
+            .into_iter()
+            .flatten()
+            .filter(|(dependency, _)| dependency.as_str() >= start);
+        for (dependency, location) in edges.clone() {
+            self.stack.push((path.to_owned(), location.to_owned()));
+            if dependency.as_str() == start {
+                self.cycles.insert(self.stack.clone());
+                is_found = true;
+            } else if !self.blocked.contains(dependency.as_str())
+                && self.circuit(dependency.as_str())
+            {
+                is_found = true;
+            }
+            self.stack.pop();
+        }
+
+        if is_found {
+            self.unblock(path);
+        } else {
+            for (dependency, _) in edges {
+                self.blocked_by
+                    .entry(dependency.as_str())
+                    .or_default()
+                    .insert(path);
+            }
+        }
+        is_found
+    }
+
+    ///
+    /// Unblocks the contract and the contracts blocked by it.
+    ///
+    fn unblock(&mut self, path: &'a str) {
+        self.blocked.remove(path);
+        for blocked in self.blocked_by.remove(path).unwrap_or_default() {
+            if self.blocked.contains(blocked) {
+                self.unblock(blocked);
+            }
+        }
+    }
+}
+
+///
+/// Collects the factory dependency references in the block.
+///
+fn get_block_locations(block: &Block<EraDialect>, locations: &mut BTreeMap<String, Location>) {
+    for statement in block.statements.iter() {
+        match statement {
+            Statement::Block(block) => get_block_locations(block, locations),
+            Statement::Expression(expression) => get_expression_locations(expression, locations),
+            Statement::FunctionDefinition(function) => {
+                get_block_locations(&function.body, locations)
+            }
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(expression) = declaration.expression.as_ref() {
+                    get_expression_locations(expression, locations);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                get_expression_locations(&assignment.initializer, locations)
+            }
+            Statement::IfConditional(if_conditional) => {
+                get_expression_locations(&if_conditional.condition, locations);
+                get_block_locations(&if_conditional.block, locations);
+            }
+            Statement::Switch(switch) => {
+                get_expression_locations(&switch.expression, locations);
+                for case in switch.cases.iter() {
+                    get_block_locations(&case.block, locations);
+                }
+                if let Some(default) = switch.default.as_ref() {
+                    get_block_locations(default, locations);
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                get_block_locations(&for_loop.initializer, locations);
+                get_expression_locations(&for_loop.condition, locations);
+                get_block_locations(&for_loop.finalizer, locations);
+                get_block_locations(&for_loop.body, locations);
+            }
+            _ => {}
+        }
+    }
+}
+
+///
+/// Collects the factory dependency references in the expression.
+///
+fn get_expression_locations(expression: &Expression, locations: &mut BTreeMap<String, Location>) {
+    let Expression::FunctionCall(call) = expression else {
+        return;
+    };
+
+    if let FunctionName::DataOffset | FunctionName::DataSize = call.name {
+        if let Some(Expression::Literal(Literal {
+            inner: LexicalLiteral::String(identifier),
+            ..
+        })) = call.arguments.first()
+        {
+            locations
+                .entry(identifier.inner.to_owned())
+                .or_insert(call.location);
+        }
+        return;
+    }
+
+    for argument in call.arguments.iter() {
+        get_expression_locations(argument, locations);
+    }
+}
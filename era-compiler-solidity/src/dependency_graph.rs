@@ -0,0 +1,151 @@
+//!
+//! The project dependency graph of source imports and contract creation.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+use crate::call_graph::quote_dot;
+use crate::call_graph::EXTENSION_DOT;
+
+/// The dependency graph file name, without extension.
+pub const FILE_NAME: &str = "dependency_graph";
+
+///
+/// The dependency graph source node.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Source {
+    /// The paths of the sources imported directly.
+    pub imports: BTreeSet<String>,
+}
+
+///
+/// The dependency graph contract node.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The path of the source defining the contract.
+    pub source: String,
+    /// The full paths of the contracts deployed with `CREATE` or `CREATE2`, that is, the factory dependencies.
+    pub factory_dependencies: BTreeSet<String>,
+    /// The full paths of the libraries linked to the contract.
+    pub libraries: BTreeSet<String>,
+}
+
+///
+/// The project dependency graph.
+///
+/// Combines the source import graph with the contract creation and linking dependencies, so build
+/// systems can schedule and invalidate the compilation, and auditors can see the deployment topology.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DependencyGraph {
+    /// The source nodes, keyed by source path.
+    pub sources: BTreeMap<String, Source>,
+    /// The contract nodes, keyed by contract full path.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl DependencyGraph {
+    ///
+    /// A shortcut constructor.
+    ///
+    /// Every source defining a contract gets a node, even if it is missing from the `import_graph`.
+    ///
+    pub fn new(
+        import_graph: &era_solc::StandardJsonOutputImportGraph,
+        contracts: BTreeMap<String, Contract>,
+    ) -> Self {
+        let mut sources: BTreeMap<String, Source> = import_graph
+            .sources
+            .iter()
+            .map(|(path, node)| {
+                let imports = node
+                    .imports
+                    .iter()
+                    .map(|import| import.path.to_owned())
+                    .collect();
+                (path.to_owned(), Source { imports })
+            })
+            .collect();
+        for contract in contracts.values() {
+            sources.entry(contract.source.to_owned()).or_default();
+        }
+        Self { sources, contracts }
+    }
+
+    ///
+    /// Returns the graph in the DOT format.
+    ///
+    /// Sources are drawn as boxes and contracts as ellipses. The edges are labeled with the
+    /// dependency kind: `imports`, `defines`, `creates`, or `links`.
+    ///
+    pub fn to_dot(&self) -> String {
+        let mut dot = format!("digraph {} {{\n", quote_dot(FILE_NAME));
+        for (path, source) in self.sources.iter() {
+            let path = quote_dot(path);
+            dot.push_str(format!("  {path} [shape=box];\n").as_str());
+            for import in source.imports.iter() {
+                dot.push_str(
+                    format!("  {path} -> {} [label=\"imports\"];\n", quote_dot(import)).as_str(),
+                );
+            }
+        }
+        for (path, contract) in self.contracts.iter() {
+            let path = quote_dot(path);
+            dot.push_str(format!("  {path} [shape=ellipse];\n").as_str());
+            dot.push_str(
+                format!(
+                    "  {} -> {path} [label=\"defines\", style=dashed];\n",
+                    quote_dot(contract.source.as_str())
+                )
+                .as_str(),
+            );
+            for dependency in contract.factory_dependencies.iter() {
+                dot.push_str(
+                    format!(
+                        "  {path} -> {} [label=\"creates\"];\n",
+                        quote_dot(dependency)
+                    )
+                    .as_str(),
+                );
+            }
+            for library in contract.libraries.iter() {
+                dot.push_str(
+                    format!("  {path} -> {} [label=\"links\"];\n", quote_dot(library)).as_str(),
+                );
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    ///
+    /// Writes the graph to `output_directory` in the JSON and DOT formats.
+    ///
+    pub fn write_to_directory(&self, output_directory: &Path) -> anyhow::Result<()> {
+        std::fs::create_dir_all(output_directory)?;
+
+        let files = [
+            (
+                output_directory.join(format!(
+                    "{FILE_NAME}.{}",
+                    era_compiler_common::EXTENSION_JSON
+                )),
+                serde_json::to_string_pretty(self).expect("Always valid"),
+            ),
+            (
+                output_directory.join(format!("{FILE_NAME}.{EXTENSION_DOT}")),
+                self.to_dot(),
+            ),
+        ];
+        for (path, contents) in files.into_iter() {
+            std::fs::write(path.as_path(), contents)
+                .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        }
+        Ok(())
+    }
+}
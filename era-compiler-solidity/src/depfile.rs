@@ -0,0 +1,152 @@
+//!
+//! The contract-level dependency manifest.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::lock::Lock;
+
+///
+/// The contract-level dependency manifest in the Make depfile format, which is also understood
+/// by Ninja and the build systems wrapping them, e.g.:
+///
+/// ```text
+/// build/Token.sol/Token.zbin build/Token.sol/Token.abi: contracts/Token.sol contracts/Base.sol /usr/local/bin/solc deps.d.settings
+/// ```
+///
+/// The targets of each rule are the artifact files written for a contract, and the prerequisites
+/// are its source, the sources it imports transitively, the `solc` executable, and the settings
+/// stamp file. The stamp file contains the hash of the compiler versions and the settings affecting
+/// the builds, and is only rewritten if the hash changes, so external build systems invalidate
+/// the contracts on settings changes by its timestamp.
+///
+#[derive(Debug, Clone)]
+pub struct Depfile {
+    /// The dependencies, keyed by contract full path.
+    pub contracts: BTreeMap<String, BTreeSet<String>>,
+    /// The `keccak256` hash of the compiler versions and settings.
+    pub settings_hash: String,
+}
+
+impl Depfile {
+    /// The extension appended to the manifest file name to get the settings stamp file name.
+    pub const SETTINGS_EXTENSION: &'static str = "settings";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// `settings` must be the serialized compiler versions and settings affecting the builds.
+    ///
+    pub fn try_new(
+        solc_executable: &str,
+        settings: &str,
+        import_graph: &era_solc::StandardJsonOutputImportGraph,
+        contracts: &BTreeMap<String, BTreeMap<String, era_solc::StandardJsonOutputContract>>,
+    ) -> anyhow::Result<Self> {
+        let solc_path = Lock::resolve_executable(solc_executable)?;
+        let solc_path = solc_path.to_string_lossy();
+
+        let contracts = contracts
+            .iter()
+            .flat_map(|(path, contracts)| {
+                let mut dependencies = import_graph.closure(path.as_str());
+                dependencies.insert(solc_path.to_string());
+                contracts
+                    .keys()
+                    .map(move |name| (format!("{path}:{name}"), dependencies.clone()))
+            })
+            .collect();
+        let preimage = format!("{}\0{settings}", crate::version());
+        let settings_hash = era_compiler_common::Hash::keccak256(preimage.as_bytes()).to_string();
+
+        Ok(Self {
+            contracts,
+            settings_hash,
+        })
+    }
+
+    ///
+    /// Writes the manifest to `path`, and the settings stamp file next to it.
+    ///
+    /// `artifact_paths` are the files written for each contract, keyed by contract full path.
+    /// They become the targets of the contract rules.
+    ///
+    pub fn write_to_file(
+        &self,
+        path: &Path,
+        artifact_paths: &BTreeMap<String, Vec<PathBuf>>,
+    ) -> anyhow::Result<()> {
+        let settings_path = Self::settings_path(path);
+        let is_settings_changed = std::fs::read_to_string(settings_path.as_path())
+            .map(|settings_hash| settings_hash.trim() != self.settings_hash)
+            .unwrap_or(true);
+        if is_settings_changed {
+            std::fs::write(settings_path.as_path(), format!("{}\n", self.settings_hash))
+                .map_err(|error| anyhow::anyhow!("File {settings_path:?} writing: {error}"))?;
+        }
+
+        let settings_path = Self::escape(settings_path.to_string_lossy().as_ref());
+        let mut manifest = String::new();
+        for (full_path, dependencies) in self.contracts.iter() {
+            let targets = match artifact_paths.get(full_path) {
+                Some(targets) if !targets.is_empty() => targets,
+                _ => continue,
+            };
+            for (index, target) in targets.iter().enumerate() {
+                if index > 0 {
+                    manifest.push(' ');
+                }
+                manifest.push_str(Self::escape(target.to_string_lossy().as_ref()).as_str());
+            }
+            manifest.push(':');
+            for dependency in dependencies.iter() {
+                manifest.push(' ');
+                manifest.push_str(Self::escape(dependency).as_str());
+            }
+            manifest.push(' ');
+            manifest.push_str(settings_path.as_str());
+            manifest.push('\n');
+        }
+        std::fs::write(path, manifest)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Returns the path to the settings stamp file of the manifest at `path`.
+    ///
+    pub fn settings_path(path: &Path) -> PathBuf {
+        let mut file_name = path.file_name().unwrap_or_default().to_owned();
+        file_name.push(".");
+        file_name.push(Self::SETTINGS_EXTENSION);
+        path.with_file_name(file_name)
+    }
+
+    ///
+    /// Escapes the characters with a special meaning in depfiles, following the GNU make rules.
+    ///
+    /// Whitespace is escaped with a backslash, and the backslashes preceding it are doubled,
+    /// so they are not taken for escapes themselves. `#` is escaped with a backslash, and `$` is doubled.
+    ///
+    fn escape(path: &str) -> String {
+        let mut escaped = String::with_capacity(path.len());
+        let mut backslashes = 0;
+        for character in path.chars() {
+            match character {
+                ' ' | '\t' => escaped.push_str("\\".repeat(backslashes + 1).as_str()),
+                '#' => escaped.push('\\'),
+                '$' => escaped.push('$'),
+                _ => {}
+            }
+            backslashes = if character == '\\' {
+                backslashes + 1
+            } else {
+                0
+            };
+            escaped.push(character);
+        }
+        escaped
+    }
+}
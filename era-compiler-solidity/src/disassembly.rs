@@ -0,0 +1,80 @@
+//!
+//! The EraVM bytecode disassembly.
+//!
+
+use std::path::Path;
+
+///
+/// The EraVM bytecode disassembly.
+///
+/// Decodes deployed bytecode, e.g. fetched from a chain, back into EraVM assembly,
+/// so the bytecode can be inspected without the sources.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Disassembly {
+    /// The disassembler output, with the instruction offsets, encodings, labels, and comments.
+    pub text: String,
+}
+
+impl Disassembly {
+    ///
+    /// Disassembles the EraVM `bytecode` with the `target_machine`.
+    ///
+    pub fn try_from_bytecode(
+        target_machine: &era_compiler_llvm_context::TargetMachine,
+        bytecode: &[u8],
+    ) -> anyhow::Result<Self> {
+        let bytecode_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
+            bytecode, "bytecode", false,
+        );
+        let text = era_compiler_llvm_context::eravm_disassemble(target_machine, &bytecode_buffer)?;
+        Ok(Self { text })
+    }
+
+    ///
+    /// Parses the disassembly into structured instructions.
+    ///
+    pub fn assembly(&self) -> era_solc::StandardJsonOutputContractEraVMAssembly {
+        era_solc::StandardJsonOutputContractEraVMAssembly::from_text(self.text.as_str())
+    }
+
+    ///
+    /// Reads the bytecode from `input`, which is either a file path or a `0x`-prefixed hexadecimal string.
+    ///
+    /// Files may contain either raw binary bytecode or a hexadecimal string.
+    ///
+    pub fn read_bytecode(input: &str) -> anyhow::Result<Vec<u8>> {
+        if Self::is_hexadecimal_string(input) {
+            return hex::decode(&input.trim()[2..]).map_err(|error| {
+                anyhow::anyhow!("Hexadecimal bytecode string `{input}` decoding: {error}")
+            });
+        }
+
+        let path = Path::new(input);
+        let bytes = std::fs::read(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        match std::str::from_utf8(bytes.as_slice()) {
+            Ok(string) => {
+                let string = string.trim();
+                let hexadecimal_string = string.strip_prefix("0x").unwrap_or(string);
+                hex::decode(hexadecimal_string).map_err(|error| {
+                    anyhow::anyhow!("File {path:?} hexadecimal string decoding: {error}")
+                })
+            }
+            Err(_) => Ok(bytes),
+        }
+    }
+
+    ///
+    /// Whether `input` is a hexadecimal string passed directly rather than a file path.
+    ///
+    pub fn is_hexadecimal_string(input: &str) -> bool {
+        input.trim().starts_with("0x")
+    }
+}
+
+impl std::fmt::Display for Disassembly {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.text)
+    }
+}
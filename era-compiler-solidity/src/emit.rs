@@ -0,0 +1,40 @@
+//!
+//! The additional project-wide output.
+//!
+
+use std::str::FromStr;
+
+///
+/// The additional project-wide output, written to the output directory.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Emit {
+    /// The dependency graph of source imports, contract creation, and library linking.
+    DependencyGraph,
+}
+
+impl FromStr for Emit {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "dep-graph" => Ok(Self::DependencyGraph),
+            string => anyhow::bail!(
+                "Invalid output to emit: `{string}`. Available options: {}.",
+                [Self::DependencyGraph]
+                    .into_iter()
+                    .map(|emit| emit.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for Emit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::DependencyGraph => write!(f, "dep-graph"),
+        }
+    }
+}
@@ -0,0 +1,113 @@
+//!
+//! The compiler events for library embedders.
+//!
+
+use std::sync::Arc;
+use std::time::Duration;
+
+///
+/// The compiler event.
+///
+/// The events allow GUIs and orchestration layers to display progress and collect metrics
+/// without parsing the compiler logs.
+///
+#[derive(Debug, Clone, Copy)]
+pub enum Event<'a> {
+    /// The contract compilation has been started.
+    ContractStarted {
+        /// The contract full path.
+        path: &'a str,
+        /// The compilation target.
+        target: era_compiler_common::Target,
+    },
+    /// The `solc` standard JSON compilation is about to be started.
+    SolcStarted,
+    /// The `solc` standard JSON compilation has been finished.
+    SolcFinished {
+        /// The `solc` running time.
+        duration: Duration,
+    },
+    /// Warnings have been produced.
+    WarningsProduced {
+        /// The warnings.
+        warnings: &'a [era_solc::StandardJsonOutputError],
+    },
+    /// The contract bytecode has been emitted.
+    BytecodeEmitted {
+        /// The contract full path.
+        path: &'a str,
+        /// The deploy bytecode size in bytes.
+        size: usize,
+    },
+    /// The contract build has been taken from the build cache.
+    CacheHit {
+        /// The contract full path.
+        path: &'a str,
+    },
+}
+
+///
+/// The compiler event subscriber.
+///
+/// With the multi-process compilation, the contract events are emitted by the parent process,
+/// and can be delivered from multiple threads concurrently.
+///
+pub trait Subscriber: Send + Sync {
+    ///
+    /// Handles the compiler event.
+    ///
+    fn on_event(&self, event: &Event);
+}
+
+///
+/// The subscribers notified of the events of a build.
+///
+/// The subscribers are passed with the [`BuildConfig`](crate::BuildConfig), so the events of
+/// concurrent builds in the same process are only delivered to the subscribers of each build.
+///
+#[derive(Default, Clone)]
+pub struct Subscribers(Vec<Arc<dyn Subscriber>>);
+
+impl Subscribers {
+    ///
+    /// Subscribes to the compiler events.
+    ///
+    pub fn subscribe(&mut self, subscriber: Arc<dyn Subscriber>) {
+        self.0.push(subscriber);
+    }
+
+    ///
+    /// Notifies the subscribers of the event.
+    ///
+    pub fn emit(&self, event: Event) {
+        for subscriber in self.0.iter() {
+            subscriber.on_event(&event);
+        }
+    }
+
+    ///
+    /// Notifies the subscribers of the warnings among `messages`, if there are any.
+    ///
+    pub fn emit_warnings(&self, messages: &[era_solc::StandardJsonOutputError]) {
+        if self.0.is_empty() {
+            return;
+        }
+
+        let warnings: Vec<era_solc::StandardJsonOutputError> = messages
+            .iter()
+            .filter(|message| message.severity == "warning")
+            .cloned()
+            .collect();
+        if !warnings.is_empty() {
+            self.emit(Event::WarningsProduced {
+                warnings: warnings.as_slice(),
+            });
+        }
+    }
+}
+
+impl std::fmt::Debug for Subscribers {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "Subscribers({})", self.0.len())
+    }
+}
@@ -31,6 +31,19 @@ pub struct Instruction {
 }
 
 impl Instruction {
+    ///
+    /// Returns the provenance tag of the instruction, with the location in the `solc` source map format.
+    ///
+    pub fn provenance(&self) -> String {
+        format!(
+            "evmla:{}@{}:{}:{}",
+            self.name,
+            self.begin,
+            self.end - self.begin,
+            self.source.unwrap_or(-1),
+        )
+    }
+
     ///
     /// Returns the number of input stack arguments.
     ///
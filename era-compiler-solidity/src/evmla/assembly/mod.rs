@@ -7,7 +7,6 @@ pub mod instruction;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::collections::HashSet;
 
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
@@ -40,8 +39,8 @@ pub struct Assembly {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub full_path: Option<String>,
     /// The factory dependency paths.
-    #[serde(default, skip_serializing_if = "HashSet::is_empty")]
-    pub factory_dependencies: HashSet<String>,
+    #[serde(default, skip_serializing_if = "BTreeSet::is_empty")]
+    pub factory_dependencies: BTreeSet<String>,
     /// The EVMLA extra metadata.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub extra_metadata: Option<era_solc::StandardJsonOutputContractEVMExtraMetadata>,
@@ -116,16 +115,67 @@ impl Assembly {
         era_compiler_common::Hash::keccak256(json.as_slice()).to_string()
     }
 
+    ///
+    /// Checks that the assembly `json` does not nest the sub-assemblies deeper than `max_depth`.
+    ///
+    /// The assemblies are traversed iteratively, so the check is safe to run before the recursive
+    /// deserialization and passes.
+    ///
+    pub fn check_nesting_depth(
+        json: &serde_json::Value,
+        full_path: &str,
+        max_depth: usize,
+    ) -> anyhow::Result<()> {
+        let mut stack = vec![(json, 1)];
+        while let Some((assembly, depth)) = stack.pop() {
+            if depth > max_depth {
+                anyhow::bail!(era_solc::ExitCodeError::new(
+                    era_solc::ExitCode::ResourceLimit,
+                    format!("The legacy assembly of contract `{full_path}` is too deeply nested: the maximum nesting depth is {max_depth}."),
+                ));
+            }
+            if let Some(data) = assembly.get(".data").and_then(serde_json::Value::as_object) {
+                stack.extend(
+                    data.values()
+                        .filter(|data| data.is_object())
+                        .map(|data| (data, depth + 1)),
+                );
+            }
+        }
+        Ok(())
+    }
+
     ///
     /// The pass, which replaces with dependency indexes with actual data.
     ///
+    /// The assemblies nested deeper than `max_nesting_depth` are rejected before any processing.
+    ///
     pub fn preprocess_dependencies(
         contracts: &mut BTreeMap<String, BTreeMap<String, era_solc::StandardJsonOutputContract>>,
+        max_nesting_depth: usize,
+        shuffle_seed: Option<u64>,
     ) -> anyhow::Result<()> {
-        let mut hash_path_mapping = BTreeMap::new();
-
         for (path, file) in contracts.iter() {
             for (name, contract) in file.iter() {
+                if let Some(assembly) = contract
+                    .evm
+                    .as_ref()
+                    .map(|evm| &evm.legacy_assembly)
+                    .filter(|json| json.is_object())
+                {
+                    Self::check_nesting_depth(
+                        assembly,
+                        format!("{path}:{name}").as_str(),
+                        max_nesting_depth,
+                    )?;
+                }
+            }
+        }
+
+        let mut hash_path_mapping = BTreeMap::new();
+
+        for (path, file) in crate::shuffle::shuffled(shuffle_seed, contracts.iter()) {
+            for (name, contract) in crate::shuffle::shuffled(shuffle_seed, file.iter()) {
                 let full_path = format!("{path}:{name}");
                 let hash = match contract
                     .evm
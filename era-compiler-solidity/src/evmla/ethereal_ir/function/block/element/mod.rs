@@ -1210,7 +1210,7 @@ where
                 let arguments = self.pop_arguments_llvm(context)?;
 
                 let address = arguments[0].into_int_value();
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                crate::balance_lowering::balance(context, address).map(Some)
             }
             InstructionName::SELFBALANCE => {
                 let address = context
@@ -1218,7 +1218,7 @@ where
                     .expect("Always exists")
                     .into_int_value();
 
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                crate::balance_lowering::balance(context, address).map(Some)
             }
 
             InstructionName::GASLIMIT => {
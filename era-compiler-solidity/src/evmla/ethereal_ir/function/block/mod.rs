@@ -131,7 +131,10 @@ where
         context.set_code_segment(self.key.code_segment);
 
         for element in self.elements.into_iter() {
+            let marker = crate::debug_assertions::mark(context);
+            let provenance = marker.map(|_| element.instruction.provenance());
             element.into_llvm(context)?;
+            crate::debug_assertions::tag(context, marker, || provenance.unwrap_or_default())?;
         }
 
         Ok(())
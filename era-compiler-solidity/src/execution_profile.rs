@@ -0,0 +1,231 @@
+//!
+//! The execution profile used for profile-guided code layout.
+//!
+
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// The execution profile used for profile-guided code layout.
+///
+/// The profile is usually exported from a node or a test runner, e.g.:
+///
+/// ```json
+/// {
+///   "contracts": {
+///     "Test.sol:Test": {
+///       "fun_transfer_42": 1500,
+///       "revert_error_ca66": 0
+///     }
+///   }
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ExecutionProfile {
+    /// The Yul function call counts, grouped by contract full path.
+    pub contracts: BTreeMap<String, BTreeMap<String, u64>>,
+}
+
+impl ExecutionProfile {
+    ///
+    /// Reads the execution profile from the JSON file at `path`.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let profile = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Execution profile {path:?} reading: {error}"))?;
+        let profile = serde_json::from_str(profile.as_str())
+            .map_err(|error| anyhow::anyhow!("Execution profile {path:?} parsing: {error}"))?;
+        Ok(profile)
+    }
+
+    ///
+    /// Returns the function call counts of the contract, if it has been profiled.
+    ///
+    pub fn get(&self, contract_path: &str) -> Option<&BTreeMap<String, u64>> {
+        self.contracts.get(contract_path)
+    }
+}
+
+///
+/// Lays out the functions and basic blocks of the Yul object and its inner objects according to
+/// the call counts.
+///
+pub fn apply_to_object(object: &mut Object<EraDialect>, call_counts: &BTreeMap<String, u64>) {
+    apply_to_block(&mut object.code.block, call_counts);
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        apply_to_object(inner_object, call_counts);
+    }
+}
+
+///
+/// Lays out the functions and basic blocks of the Yul block according to the call counts.
+///
+/// LLVM emits functions in the order of their declaration, so the most frequently called ones
+/// are moved to the beginning of the block, followed by the ones missing from the profile.
+/// Functions that have never been called are moved to the end and marked as cold, which also
+/// makes LLVM move the basic blocks calling them out of the hot paths.
+///
+/// Yul functions are hoisted, so only the relative order of function definitions is changed.
+///
+/// The cases of `switch` statements, such as the function dispatcher, are laid out the same way,
+/// as LLVM emits their basic blocks in the order of the cases. A case is as hot as the most
+/// frequently called function it calls. The default case is always left at the end.
+///
+pub fn apply_to_block(block: &mut Block<EraDialect>, call_counts: &BTreeMap<String, u64>) {
+    let mut functions = Vec::with_capacity(block.statements.len());
+    let mut statements = Vec::with_capacity(block.statements.len());
+    for statement in std::mem::take(&mut block.statements).into_iter() {
+        match statement {
+            Statement::FunctionDefinition(mut function) => {
+                apply_to_block(&mut function.body, call_counts);
+                let call_count = call_counts.get(function.identifier.as_str()).copied();
+                if call_count == Some(0) {
+                    function
+                        .attributes
+                        .insert(era_compiler_llvm_context::Attribute::Cold);
+                }
+                functions.push((call_count, function));
+                statements.push(None);
+            }
+            Statement::Block(mut inner_block) => {
+                apply_to_block(&mut inner_block, call_counts);
+                statements.push(Some(Statement::Block(inner_block)));
+            }
+            Statement::IfConditional(mut if_conditional) => {
+                apply_to_block(&mut if_conditional.block, call_counts);
+                statements.push(Some(Statement::IfConditional(if_conditional)));
+            }
+            Statement::Switch(mut switch) => {
+                for case in switch.cases.iter_mut() {
+                    apply_to_block(&mut case.block, call_counts);
+                }
+                if let Some(default) = switch.default.as_mut() {
+                    apply_to_block(default, call_counts);
+                }
+                switch.cases.sort_by_cached_key(|case| {
+                    layout_key(block_call_count(&case.block, call_counts))
+                });
+                statements.push(Some(Statement::Switch(switch)));
+            }
+            Statement::ForLoop(mut for_loop) => {
+                apply_to_block(&mut for_loop.body, call_counts);
+                statements.push(Some(Statement::ForLoop(for_loop)));
+            }
+            statement => statements.push(Some(statement)),
+        }
+    }
+
+    functions.sort_by_key(|(call_count, _)| layout_key(*call_count));
+
+    let mut functions = functions.into_iter();
+    block.statements = statements
+        .into_iter()
+        .map(|statement| {
+            statement.unwrap_or_else(|| {
+                let (_, function) = functions.next().expect("Always exists");
+                Statement::FunctionDefinition(function)
+            })
+        })
+        .collect();
+}
+
+///
+/// Returns the sorting key placing the hot code first, then the unprofiled code, then the cold code.
+///
+fn layout_key(call_count: Option<u64>) -> (u8, Reverse<u64>) {
+    match call_count {
+        Some(0) => (2, Reverse(0)),
+        Some(call_count) => (0, Reverse(call_count)),
+        None => (1, Reverse(0)),
+    }
+}
+
+///
+/// Returns the highest call count of the functions called in the block.
+///
+/// Returns `None` if none of the called functions has been profiled.
+/// Function definitions are skipped, as they are laid out separately.
+///
+fn block_call_count(block: &Block<EraDialect>, call_counts: &BTreeMap<String, u64>) -> Option<u64> {
+    block
+        .statements
+        .iter()
+        .map(|statement| statement_call_count(statement, call_counts))
+        .max()
+        .flatten()
+}
+
+///
+/// Returns the highest call count of the functions called in the statement.
+///
+fn statement_call_count(
+    statement: &Statement<EraDialect>,
+    call_counts: &BTreeMap<String, u64>,
+) -> Option<u64> {
+    match statement {
+        Statement::Block(block) => block_call_count(block, call_counts),
+        Statement::Expression(expression) => expression_call_count(expression, call_counts),
+        Statement::VariableDeclaration(declaration) => declaration
+            .expression
+            .as_ref()
+            .and_then(|expression| expression_call_count(expression, call_counts)),
+        Statement::Assignment(assignment) => {
+            expression_call_count(&assignment.initializer, call_counts)
+        }
+        Statement::IfConditional(if_conditional) => {
+            expression_call_count(&if_conditional.condition, call_counts)
+                .max(block_call_count(&if_conditional.block, call_counts))
+        }
+        Statement::Switch(switch) => switch
+            .cases
+            .iter()
+            .map(|case| block_call_count(&case.block, call_counts))
+            .chain(
+                switch
+                    .default
+                    .iter()
+                    .map(|default| block_call_count(default, call_counts)),
+            )
+            .max()
+            .flatten()
+            .max(expression_call_count(&switch.expression, call_counts)),
+        Statement::ForLoop(for_loop) => block_call_count(&for_loop.initializer, call_counts)
+            .max(expression_call_count(&for_loop.condition, call_counts))
+            .max(block_call_count(&for_loop.finalizer, call_counts))
+            .max(block_call_count(&for_loop.body, call_counts)),
+        _ => None,
+    }
+}
+
+///
+/// Returns the highest call count of the functions called in the expression.
+///
+fn expression_call_count(
+    expression: &Expression,
+    call_counts: &BTreeMap<String, u64>,
+) -> Option<u64> {
+    let Expression::FunctionCall(call) = expression else {
+        return None;
+    };
+
+    let call_count = match call.name {
+        FunctionName::UserDefined(ref name) => call_counts.get(name.as_str()).copied(),
+        _ => None,
+    };
+    call.arguments
+        .iter()
+        .map(|argument| expression_call_count(argument, call_counts))
+        .max()
+        .flatten()
+        .max(call_count)
+}
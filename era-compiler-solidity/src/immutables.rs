@@ -0,0 +1,160 @@
+//!
+//! The compile-time immutable value propagation.
+//!
+
+use std::collections::BTreeMap;
+
+use era_yul::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::literal::Literal;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::evmla::assembly::instruction::name::Name as InstructionName;
+use crate::evmla::assembly::Assembly;
+use crate::yul::parser::dialect::era::EraDialect;
+
+/// The maximum number of hexadecimal digits in an immutable value.
+pub const VALUE_HEX_LENGTH_MAX: usize = era_compiler_common::BYTE_LENGTH_FIELD * 2;
+
+///
+/// Normalizes the immutable values to lowercase hexadecimal strings with the `0x` prefix.
+///
+/// The keys are `solc` immutable identifiers, as they are represented in the `immutableReferences` output.
+///
+pub fn normalize(values: &BTreeMap<String, String>) -> anyhow::Result<BTreeMap<String, String>> {
+    values
+        .iter()
+        .map(|(key, value)| {
+            let digits = value
+                .strip_prefix("0x")
+                .unwrap_or(value.as_str())
+                .to_lowercase();
+            if digits.is_empty()
+                || digits.len() > VALUE_HEX_LENGTH_MAX
+                || !digits.chars().all(|character| character.is_ascii_hexdigit())
+            {
+                anyhow::bail!(
+                    "Invalid value `{value}` of immutable `{key}`: expected up to {VALUE_HEX_LENGTH_MAX} hexadecimal digits"
+                );
+            }
+            Ok((key.to_owned(), format!("0x{digits}")))
+        })
+        .collect()
+}
+
+///
+/// Replaces the immutable loads in the Yul object and its inner objects with the known values.
+///
+/// The stores in the deploy code are left intact, so the immutables layout does not change.
+///
+pub fn apply_to_object(object: &mut Object<EraDialect>, values: &BTreeMap<String, String>) {
+    apply_to_block(&mut object.code.block, values);
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        apply_to_object(inner_object, values);
+    }
+}
+
+///
+/// Replaces the immutable loads in the EVM legacy assembly and its data with the known values.
+///
+pub fn apply_to_assembly(assembly: &mut Assembly, values: &BTreeMap<String, String>) {
+    if let Some(code) = assembly.code.as_mut() {
+        for instruction in code.iter_mut() {
+            if !matches!(instruction.name, InstructionName::PUSHIMMUTABLE) {
+                continue;
+            }
+            if let Some(value) = instruction
+                .value
+                .as_ref()
+                .and_then(|key| values.get(key.as_str()))
+            {
+                instruction.name = InstructionName::PUSH;
+                instruction.value = Some(value["0x".len()..].to_owned());
+            }
+        }
+    }
+    if let Some(data) = assembly.data.as_mut() {
+        for data in data.values_mut() {
+            if let Some(assembly) = data.get_assembly_mut() {
+                apply_to_assembly(assembly, values);
+            }
+        }
+    }
+}
+
+///
+/// Replaces the immutable loads in the Yul block.
+///
+fn apply_to_block(block: &mut Block<EraDialect>, values: &BTreeMap<String, String>) {
+    for statement in block.statements.iter_mut() {
+        match statement {
+            Statement::Block(block) => apply_to_block(block, values),
+            Statement::Expression(expression) => apply_to_expression(expression, values),
+            Statement::FunctionDefinition(function) => apply_to_block(&mut function.body, values),
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(expression) = declaration.expression.as_mut() {
+                    apply_to_expression(expression, values);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                apply_to_expression(&mut assignment.initializer, values)
+            }
+            Statement::IfConditional(if_conditional) => {
+                apply_to_expression(&mut if_conditional.condition, values);
+                apply_to_block(&mut if_conditional.block, values);
+            }
+            Statement::Switch(switch) => {
+                apply_to_expression(&mut switch.expression, values);
+                for case in switch.cases.iter_mut() {
+                    apply_to_block(&mut case.block, values);
+                }
+                if let Some(default) = switch.default.as_mut() {
+                    apply_to_block(default, values);
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                apply_to_block(&mut for_loop.initializer, values);
+                apply_to_expression(&mut for_loop.condition, values);
+                apply_to_block(&mut for_loop.finalizer, values);
+                apply_to_block(&mut for_loop.body, values);
+            }
+            _ => {}
+        }
+    }
+}
+
+///
+/// Replaces the immutable loads in the Yul expression.
+///
+fn apply_to_expression(expression: &mut Expression, values: &BTreeMap<String, String>) {
+    let Expression::FunctionCall(call) = expression else {
+        return;
+    };
+
+    if let FunctionName::LoadImmutable = call.name {
+        let value = match call.arguments.first() {
+            Some(Expression::Literal(Literal {
+                inner: LexicalLiteral::String(key),
+                ..
+            })) => values.get(key.inner.as_str()),
+            _ => None,
+        };
+        if let Some(value) = value {
+            let location = call.location;
+            *expression = Expression::Literal(Literal {
+                location,
+                inner: LexicalLiteral::Integer(IntegerLiteral::new_hexadecimal(value.to_owned())),
+                yul_type: None,
+            });
+        }
+        return;
+    }
+
+    for argument in call.arguments.iter_mut() {
+        apply_to_expression(argument, values);
+    }
+}
@@ -0,0 +1,270 @@
+//!
+//! The interface freeze file.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+///
+/// The frozen contract interface.
+///
+/// The signatures are keyed by selector, which is the full `keccak256` hash of the signature for
+/// events, and its first four bytes for functions and errors.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Interface {
+    /// The function signatures.
+    #[serde(default)]
+    pub functions: BTreeMap<String, String>,
+    /// The event signatures.
+    #[serde(default)]
+    pub events: BTreeMap<String, String>,
+    /// The error signatures.
+    #[serde(default)]
+    pub errors: BTreeMap<String, String>,
+}
+
+impl Interface {
+    ///
+    /// Extracts the interface from the ABI JSON.
+    ///
+    pub fn from_abi(abi: &serde_json::Value) -> Self {
+        let mut interface = Self::default();
+        for entry in abi.as_array().into_iter().flatten() {
+            let (name, inputs) = match (
+                entry.get("name").and_then(|name| name.as_str()),
+                entry.get("inputs").and_then(|inputs| inputs.as_array()),
+            ) {
+                (Some(name), Some(inputs)) => (name, inputs),
+                _ => continue,
+            };
+            let signature = format!("{name}({})", Self::canonical_types(inputs));
+            let hash = era_compiler_common::Hash::keccak256(signature.as_bytes());
+
+            match entry.get("type").and_then(|r#type| r#type.as_str()) {
+                Some("function") => {
+                    interface.functions.insert(
+                        hex::encode(&hash.as_bytes()[..era_compiler_common::BYTE_LENGTH_X32]),
+                        signature,
+                    );
+                }
+                Some("event") => {
+                    interface
+                        .events
+                        .insert(hex::encode(hash.as_bytes()), signature);
+                }
+                Some("error") => {
+                    interface.errors.insert(
+                        hex::encode(&hash.as_bytes()[..era_compiler_common::BYTE_LENGTH_X32]),
+                        signature,
+                    );
+                }
+                _ => continue,
+            }
+        }
+        interface
+    }
+
+    ///
+    /// Returns the function signature hashes in the `solc --hashes` format, one `selector: signature`
+    /// per line, sorted by signature.
+    ///
+    pub fn function_signatures(&self) -> String {
+        let mut functions: Vec<(&String, &String)> = self
+            .functions
+            .iter()
+            .map(|(selector, signature)| (signature, selector))
+            .collect();
+        functions.sort();
+        functions
+            .into_iter()
+            .map(|(signature, selector)| format!("{selector}: {signature}\n"))
+            .collect()
+    }
+
+    ///
+    /// Returns the differences from the `actual` interface.
+    ///
+    /// The removed entries are the frozen ones missing from the `actual` interface,
+    /// and the added entries are the ones not present in the frozen interface.
+    ///
+    pub fn diff(&self, actual: &Self) -> era_solc::StandardJsonOutputErrorInterfaceDiff {
+        let mut diff = era_solc::StandardJsonOutputErrorInterfaceDiff::default();
+        for (kind, expected, actual) in [
+            ("function", &self.functions, &actual.functions),
+            ("event", &self.events, &actual.events),
+            ("error", &self.errors, &actual.errors),
+        ] {
+            for (selector, signature) in expected.iter() {
+                if actual.get(selector) != Some(signature) {
+                    diff.removed
+                        .push(era_solc::StandardJsonOutputErrorInterfaceDiffEntry::new(
+                            kind.to_owned(),
+                            selector.to_owned(),
+                            signature.to_owned(),
+                        ));
+                }
+            }
+            for (selector, signature) in actual.iter() {
+                if expected.get(selector) != Some(signature) {
+                    diff.added
+                        .push(era_solc::StandardJsonOutputErrorInterfaceDiffEntry::new(
+                            kind.to_owned(),
+                            selector.to_owned(),
+                            signature.to_owned(),
+                        ));
+                }
+            }
+        }
+        diff
+    }
+
+    ///
+    /// Returns the comma-separated canonical types of the ABI parameters.
+    ///
+    fn canonical_types(parameters: &[serde_json::Value]) -> String {
+        parameters
+            .iter()
+            .map(|parameter| {
+                let r#type = parameter
+                    .get("type")
+                    .and_then(|r#type| r#type.as_str())
+                    .unwrap_or_default();
+                match r#type.strip_prefix("tuple") {
+                    Some(suffix) => {
+                        let components = parameter
+                            .get("components")
+                            .and_then(|components| components.as_array())
+                            .map(|components| Self::canonical_types(components.as_slice()))
+                            .unwrap_or_default();
+                        format!("({components}){suffix}")
+                    }
+                    None => r#type.to_owned(),
+                }
+            })
+            .collect::<Vec<String>>()
+            .join(",")
+    }
+}
+
+///
+/// The interface freeze file.
+///
+/// Lists the expected interfaces of contracts, so accidental ABI changes are caught at
+/// compile time, e.g.:
+///
+/// ```json
+/// {
+///   "contracts": {
+///     "Test.sol:Test": {
+///       "functions": {
+///         "a9059cbb": "transfer(address,uint256)"
+///       },
+///       "events": {
+///         "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef": "Transfer(address,address,uint256)"
+///       }
+///     }
+///   }
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InterfaceFreeze {
+    /// The frozen interfaces, keyed by contract full path.
+    pub contracts: BTreeMap<String, Interface>,
+}
+
+impl InterfaceFreeze {
+    ///
+    /// Reads the interface freeze file at `path`.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let freeze = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Interface freeze file {path:?} reading: {error}"))?;
+        let mut freeze: Self = serde_json::from_str(freeze.as_str())
+            .map_err(|error| anyhow::anyhow!("Interface freeze file {path:?} parsing: {error}"))?;
+        freeze.contracts = std::mem::take(&mut freeze.contracts)
+            .into_iter()
+            .map(|(full_path, interface)| {
+                (era_solc::path::normalize(full_path.as_str()), interface)
+            })
+            .collect();
+        Ok(freeze)
+    }
+
+    ///
+    /// Checks the contract ABIs, keyed by contract full path, against the frozen interfaces.
+    ///
+    /// Contracts not listed in the freeze file are not checked.
+    ///
+    pub fn check(
+        &self,
+        abi_jsons: &BTreeMap<String, &serde_json::Value>,
+    ) -> Vec<era_solc::StandardJsonOutputError> {
+        let mut errors = Vec::new();
+        for (full_path, expected) in self.contracts.iter() {
+            let path = full_path
+                .rsplit_once(':')
+                .map(|(path, _name)| path)
+                .unwrap_or(full_path.as_str());
+            let source_location = Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                path.to_owned(),
+            ));
+
+            let abi = match abi_jsons.get(full_path.as_str()) {
+                Some(abi) => abi,
+                None => {
+                    errors.push(era_solc::StandardJsonOutputError::new_error(
+                        format!(
+                            "Contract `{full_path}` from the interface freeze file is not found."
+                        ),
+                        source_location,
+                        None,
+                    ));
+                    continue;
+                }
+            };
+            let diff = expected.diff(&Interface::from_abi(abi));
+            if !diff.is_empty() {
+                errors.push(
+                    era_solc::StandardJsonOutputError::new_error(
+                        format!(
+                            "The interface of contract `{full_path}` does not match the interface freeze file:\n{diff}"
+                        ),
+                        source_location,
+                        None,
+                    )
+                    .with_interface_diff(diff),
+                );
+            }
+        }
+        errors
+    }
+
+    ///
+    /// Checks the contract ABIs in the standard JSON output against the freeze file at `path`.
+    ///
+    /// If `remove_abi` is set, the ABIs are removed from the output afterwards, as they have not
+    /// been requested by the user.
+    ///
+    pub fn check_standard_json(
+        path: &Path,
+        solc_output: &mut era_solc::StandardJsonOutput,
+        remove_abi: bool,
+    ) {
+        match Self::try_from_path(path) {
+            Ok(freeze) => {
+                let errors = freeze.check(&solc_output.abi_jsons());
+                solc_output.errors.extend(errors);
+            }
+            Err(error) => solc_output
+                .errors
+                .push(era_solc::StandardJsonOutputError::new_error(
+                    error, None, None,
+                )),
+        }
+        if remove_abi {
+            solc_output.take_abi_jsons();
+        }
+    }
+}
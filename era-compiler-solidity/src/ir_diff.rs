@@ -0,0 +1,62 @@
+//!
+//! The LLVM IR optimization diff.
+//!
+
+use std::path::Path;
+
+/// The suffix of unoptimized LLVM IR files in the debug output directory.
+pub const SUFFIX_UNOPTIMIZED: &str = ".unoptimized.ll";
+
+/// The suffix of optimized LLVM IR files in the debug output directory.
+pub const SUFFIX_OPTIMIZED: &str = ".optimized.ll";
+
+/// The suffix of LLVM IR optimization diff files in the debug output directory.
+pub const SUFFIX_DIFF: &str = ".optimization.diff";
+
+/// The number of unchanged lines surrounding each hunk.
+pub const CONTEXT_RADIUS: usize = 3;
+
+///
+/// Writes a unified diff between the unoptimized and optimized LLVM IR of the contract code
+/// segment to the debug output directory.
+///
+/// The diffed files are the ones dumped by LLVM while building the segment, e.g.
+/// `Test.sol.Test.runtime.unoptimized.ll` and `Test.sol.Test.runtime.optimized.ll`, and the diff
+/// is written next to them, e.g. `Test.sol.Test.runtime.optimization.diff`.
+/// The segment is skipped if LLVM has not dumped both files.
+///
+pub fn write_to_directory(
+    output_directory: &Path,
+    contract_path: &str,
+    code_segment: era_compiler_common::CodeSegment,
+) -> anyhow::Result<()> {
+    let name = format!(
+        "{}.{code_segment}",
+        contract_path.replace('/', "_").replace(':', ".")
+    );
+    let unoptimized_name = format!("{name}{SUFFIX_UNOPTIMIZED}");
+    let optimized_name = format!("{name}{SUFFIX_OPTIMIZED}");
+
+    let unoptimized_path = output_directory.join(unoptimized_name.as_str());
+    let optimized_path = output_directory.join(optimized_name.as_str());
+    if !unoptimized_path.exists() || !optimized_path.exists() {
+        return Ok(());
+    }
+
+    let unoptimized = std::fs::read_to_string(unoptimized_path.as_path())
+        .map_err(|error| anyhow::anyhow!("File {unoptimized_path:?} reading: {error}"))?;
+    let optimized = std::fs::read_to_string(optimized_path.as_path())
+        .map_err(|error| anyhow::anyhow!("File {optimized_path:?} reading: {error}"))?;
+
+    let diff = similar::TextDiff::from_lines(unoptimized.as_str(), optimized.as_str())
+        .unified_diff()
+        .context_radius(CONTEXT_RADIUS)
+        .header(unoptimized_name.as_str(), optimized_name.as_str())
+        .to_string();
+
+    let diff_path = output_directory.join(format!("{name}{SUFFIX_DIFF}"));
+    std::fs::write(diff_path.as_path(), diff)
+        .map_err(|error| anyhow::anyhow!("File {diff_path:?} writing: {error}"))?;
+
+    Ok(())
+}
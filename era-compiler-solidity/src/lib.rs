@@ -9,23 +9,86 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::result_large_err)]
 
+pub mod artifact_directories;
+pub mod artifact_format;
+pub mod ast_json;
+pub mod balance_lowering;
+pub mod build_cache;
+pub mod build_config;
 pub mod build_eravm;
 pub mod build_evm;
+pub mod build_report;
+pub mod bytecode_comparison;
+pub mod bytecode_explanation;
+pub mod call_graph;
 pub mod r#const;
+pub mod contract_filter;
+pub mod contract_selection;
+pub mod debug_assertions;
+pub mod dedup_report;
+pub mod dependency_cycle;
+pub mod dependency_graph;
+pub mod depfile;
+pub mod disassembly;
+pub mod emit;
+pub mod events;
 pub mod evmla;
+pub mod execution_profile;
+pub mod immutables;
+pub mod interface_freeze;
+pub mod ir_diff;
+pub mod library_deployer;
+pub mod limits;
 pub mod linker;
+pub mod lock;
 pub mod missing_libraries;
+pub mod nested_objects;
+pub mod policy;
 pub mod process;
 pub mod project;
+pub mod reachable_selectors;
+pub mod remarks;
+pub mod reports;
+pub mod resource_usage;
+pub mod revert_decoder;
+pub mod server;
+pub mod shuffle;
+pub mod solc_partition;
+pub mod spill_report;
+pub mod standard_json;
+pub mod temp_dir;
+pub mod virtual_file_system;
 pub mod yul;
 
+pub use self::artifact_format::ArtifactFormat;
+pub use self::build_cache::BuildCache;
+pub use self::build_config::BuildConfig;
 pub use self::build_eravm::contract::Contract as EraVMContractBuild;
+pub use self::build_eravm::foundry_artifact::FoundryArtifact;
+pub use self::build_eravm::hardhat_artifact::HardhatArtifact;
 pub use self::build_eravm::Build as EraVMBuild;
 pub use self::build_evm::contract::Contract as EVMContractBuild;
 pub use self::build_evm::Build as EVMBuild;
+pub use self::build_report::BuildReport;
+pub use self::bytecode_comparison::BytecodeComparison;
+pub use self::bytecode_explanation::BytecodeExplanation;
+pub use self::call_graph::CallGraph;
+pub use self::contract_filter::ContractFilter;
+pub use self::dedup_report::DedupReport;
+pub use self::dependency_graph::DependencyGraph;
+pub use self::depfile::Depfile;
+pub use self::disassembly::Disassembly;
+pub use self::emit::Emit;
+pub use self::events::Event;
+pub use self::events::Subscriber as EventSubscriber;
+pub use self::events::Subscribers as EventSubscribers;
+pub use self::execution_profile::ExecutionProfile;
+pub use self::interface_freeze::InterfaceFreeze;
+pub use self::limits::Limits;
 pub use self::linker::input::Input as LinkerInput;
 pub use self::linker::output::Output as LinkerOutput;
 pub use self::linker::Linker;
+pub use self::lock::Lock;
 pub use self::process::input_eravm::Input as EraVMProcessInput;
 pub use self::process::input_evm::Input as EVMProcessInput;
 pub use self::process::output_eravm::Output as EraVMProcessOutput;
@@ -35,11 +98,23 @@ pub use self::process::EXECUTABLE;
 pub use self::project::contract::Contract as ProjectContract;
 pub use self::project::Project;
 pub use self::r#const::*;
+pub use self::reports::Reports;
+pub use self::resource_usage::ResourceMonitor;
+pub use self::resource_usage::ResourceUsage;
+pub use self::resource_usage::SubprocessUsage;
+pub use self::revert_decoder::RevertDecoder;
+pub use self::server::Server;
+pub use self::solc_partition::SolcPartition;
+pub use self::spill_report::SpillReport;
+pub use self::temp_dir::CleanupPolicy as TempDirCleanupPolicy;
+pub use self::temp_dir::TempDir;
+pub use self::virtual_file_system::VirtualFileSystem;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 use std::collections::HashSet;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 
 use rayon::iter::IntoParallelIterator;
@@ -47,6 +122,7 @@ use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::ParallelIterator;
 
 use era_solc::CollectableError;
+use era_solc::WithExitCode;
 
 /// The default error compatible with `solc` standard JSON output.
 pub type Result<T> = std::result::Result<T, era_solc::StandardJsonOutputError>;
@@ -63,31 +139,51 @@ pub fn yul_to_eravm(
     metadata_hash_type: era_compiler_common::HashType,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+    execution_profile: Option<&ExecutionProfile>,
+    call_graph: Option<&Path>,
     output_assembly: bool,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EraVMBuild> {
-    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)?;
+    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
     let solc_version = match solc_path {
         Some(solc_path) => {
             if enable_eravm_extensions {
-                anyhow::bail!("Yul validation cannot be done if EraVM extensions are enabled. Consider compiling without `solc`.")
+                anyhow::bail!(era_solc::ExitCodeError::new(
+                    era_solc::ExitCode::InputError,
+                    "Yul validation cannot be done if EraVM extensions are enabled. Consider compiling without `solc`.".to_owned(),
+                ))
             }
-            let solc_compiler = era_solc::Compiler::try_from_path(solc_path.as_str())?;
+            let solc_compiler = era_solc::Compiler::try_from_path(solc_path.as_str())
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
             solc_compiler.validate_yul_paths(paths, libraries.clone(), messages)?;
             Some(solc_compiler.version)
         }
         None => None,
     };
 
-    let project = Project::try_from_yul_paths(
+    let mut project = Project::try_from_yul_paths(
         paths,
         libraries,
         None,
         solc_version.as_ref(),
+        config,
         debug_config.as_ref(),
-    )?;
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)?;
+    if let Some(execution_profile) = execution_profile {
+        project.apply_execution_profile(execution_profile);
+    }
+    project.set_balance_lowering(balance_lowering);
+    if let Some(call_graph) = call_graph {
+        project.call_graph().write_to_directory(call_graph)?;
+    }
 
     let mut build = project.compile_to_eravm(
         messages,
@@ -96,13 +192,15 @@ pub fn yul_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        None,
+        config,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
 
     let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
     Ok(build)
 }
@@ -118,28 +216,43 @@ pub fn yul_to_evm(
     metadata_hash_type: era_compiler_common::HashType,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    execution_profile: Option<&ExecutionProfile>,
+    call_graph: Option<&Path>,
     threads: Option<usize>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
-    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)?;
+    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
     let _linker_symbols = libraries.as_linker_symbols()?;
 
     let solc_version = match solc_path {
         Some(solc_path) => {
-            let solc_compiler = era_solc::Compiler::try_from_path(solc_path.as_str())?;
+            let solc_compiler = era_solc::Compiler::try_from_path(solc_path.as_str())
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
             solc_compiler.validate_yul_paths(paths, libraries.clone(), messages)?;
             Some(solc_compiler.version)
         }
         None => None,
     };
 
-    let project = Project::try_from_yul_paths(
+    let mut project = Project::try_from_yul_paths(
         paths,
         libraries,
         None,
         solc_version.as_ref(),
+        config,
         debug_config.as_ref(),
-    )?;
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)?;
+    if let Some(execution_profile) = execution_profile {
+        project.apply_execution_profile(execution_profile);
+    }
+    if let Some(call_graph) = call_graph {
+        project.call_graph().write_to_directory(call_graph)?;
+    }
 
     let build = project.compile_to_evm(
         messages,
@@ -147,6 +260,7 @@ pub fn yul_to_evm(
         optimizer_settings,
         llvm_options,
         threads,
+        config,
         debug_config,
     )?;
     Ok(build)
@@ -163,12 +277,15 @@ pub fn llvm_ir_to_eravm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EraVMBuild> {
-    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)?;
+    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
     let linker_symbols = libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_llvm_ir_paths(paths, libraries, None)?;
+    let project = Project::try_from_llvm_ir_paths(paths, libraries, None)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let mut build = project.compile_to_eravm(
         messages,
@@ -177,13 +294,15 @@ pub fn llvm_ir_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        None,
+        config,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
 
     let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
     Ok(build)
 }
@@ -199,11 +318,14 @@ pub fn llvm_ir_to_evm(
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
     threads: Option<usize>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
-    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)?;
+    let libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
-    let project = Project::try_from_llvm_ir_paths(paths, libraries, None)?;
+    let project = Project::try_from_llvm_ir_paths(paths, libraries, None)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let build = project.compile_to_evm(
         messages,
@@ -211,6 +333,7 @@ pub fn llvm_ir_to_evm(
         optimizer_settings,
         llvm_options,
         threads,
+        config,
         debug_config,
     )?;
     Ok(build)
@@ -225,9 +348,11 @@ pub fn eravm_assembly_to_eravm(
     metadata_hash_type: era_compiler_common::HashType,
     llvm_options: Vec<String>,
     output_assembly: bool,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EraVMBuild> {
-    let project = Project::try_from_eravm_assembly_paths(paths, None)?;
+    let project = Project::try_from_eravm_assembly_paths(paths, None)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let optimizer_settings = era_compiler_llvm_context::OptimizerSettings::none();
     let mut build = project.compile_to_eravm(
@@ -237,13 +362,15 @@ pub fn eravm_assembly_to_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        None,
+        config,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
 
     let mut build = build.link(BTreeMap::new());
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
     Ok(build)
 }
@@ -257,9 +384,11 @@ pub fn eravm_assembly_to_evm(
     metadata_hash_type: era_compiler_common::HashType,
     llvm_options: Vec<String>,
     threads: Option<usize>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
-    let project = Project::try_from_eravm_assembly_paths(paths, None)?;
+    let project = Project::try_from_eravm_assembly_paths(paths, None)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let optimizer_settings = era_compiler_llvm_context::OptimizerSettings::none();
     let mut build = project.compile_to_evm(
@@ -268,9 +397,10 @@ pub fn eravm_assembly_to_evm(
         optimizer_settings,
         llvm_options,
         threads,
+        config,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
     Ok(build)
 }
@@ -294,52 +424,101 @@ pub fn standard_output_eravm(
     remappings: BTreeSet<String>,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+    execution_profile: Option<&ExecutionProfile>,
+    contract_filter: Option<&ContractFilter>,
+    reports: &Reports,
     output_assembly: bool,
+    output_ast: bool,
+    output_abi: bool,
     suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
     suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    strict_eravm: bool,
+    ast_cache: Option<PathBuf>,
+    cache_dir: Option<&Path>,
+    depfile: bool,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EraVMBuild> {
-    let solc_version = solc_compiler.version.to_owned();
-    let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_version, codegen);
+    let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, codegen);
 
-    let mut solc_input = era_solc::StandardJsonInput::try_from_solidity_paths(
+    let (solc_input, mut solc_output) = standard_output_solc(
         paths,
         libraries,
-        remappings,
-        era_solc::StandardJsonInputOptimizer::default(),
+        solc_compiler,
+        messages,
         codegen,
+        solc_codegen,
         evm_version,
         enable_eravm_extensions,
-        era_solc::StandardJsonInputSelection::new_required(solc_codegen),
-        era_solc::StandardJsonInputMetadata::new(use_literal_content, metadata_hash_type),
-        llvm_options.clone(),
-        suppressed_errors,
-        suppressed_warnings,
-        false,
-        false,
-    )?;
-
-    let mut solc_output = solc_compiler.standard_json(
-        &mut solc_input,
-        messages,
+        metadata_hash_type,
+        use_literal_content,
         base_path,
         include_paths,
         allow_paths,
+        remappings,
+        llvm_options.clone(),
+        reports,
+        output_abi,
+        suppressed_errors,
+        suppressed_warnings,
+        strict_eravm,
+        ast_cache,
+        config,
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    if config.cache_warming && cache_dir.is_none() {
+        return Ok(EraVMBuild::new(BTreeMap::new(), messages));
+    }
 
     let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
 
-    let project = Project::try_from_solc_output(
+    let settings = BuildCache::settings(
+        &solc_compiler.version,
+        &solc_input.settings,
+        (
+            solc_codegen.to_string(),
+            enable_eravm_extensions,
+            metadata_hash_type,
+            &optimizer_settings,
+            &llvm_options,
+            output_assembly,
+            balance_lowering,
+            execution_profile,
+        ),
+    );
+    let build_cache = cache_dir.map(|cache_dir| {
+        BuildCache::new(
+            cache_dir,
+            settings.as_str(),
+            &solc_output.import_graph,
+            &solc_input.sources,
+        )
+    });
+    let depfile = if depfile && !config.cache_warming {
+        Some(Depfile::try_new(
+            solc_compiler.executable.as_str(),
+            settings.as_str(),
+            &solc_output.import_graph,
+            &solc_output.contracts,
+        )?)
+    } else {
+        None
+    };
+
+    let (mut project, ast_jsons, abi_jsons) = standard_output_project(
         solc_input.settings.libraries,
         solc_codegen,
         &mut solc_output,
         solc_compiler,
+        execution_profile,
+        contract_filter,
+        reports,
+        output_ast,
+        output_abi,
+        config,
         debug_config.as_ref(),
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    project.set_balance_lowering(balance_lowering);
 
     let mut build = project.compile_to_eravm(
         messages,
@@ -348,14 +527,19 @@ pub fn standard_output_eravm(
         optimizer_settings,
         llvm_options,
         output_assembly,
+        build_cache.as_ref(),
+        config,
         debug_config,
     )?;
-    build.take_and_write_warnings();
+    build.take_and_write_warnings(config.sarif.as_ref());
     build.check_errors()?;
+    if config.cache_warming {
+        return Ok(build);
+    }
 
-    let mut build = build.link(linker_symbols);
-    build.take_and_write_warnings();
-    build.check_errors()?;
+    let mut build =
+        link_standard_output_eravm(build, linker_symbols, ast_jsons, abi_jsons, config)?;
+    build.depfile = depfile;
     Ok(build)
 }
 
@@ -377,238 +561,426 @@ pub fn standard_output_evm(
     remappings: BTreeSet<String>,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    execution_profile: Option<&ExecutionProfile>,
+    contract_filter: Option<&ContractFilter>,
+    reports: &Reports,
+    output_ast: bool,
+    output_abi: bool,
+    ast_cache: Option<PathBuf>,
     threads: Option<usize>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<EVMBuild> {
-    let solc_version = solc_compiler.version.to_owned();
-    let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_version, codegen);
+    let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, codegen);
 
-    let mut solc_input = era_solc::StandardJsonInput::try_from_solidity_paths(
+    let (solc_input, mut solc_output) = standard_output_solc(
         paths,
         libraries,
-        remappings,
-        era_solc::StandardJsonInputOptimizer::default(),
+        solc_compiler,
+        messages,
         codegen,
+        solc_codegen,
         evm_version,
         false,
-        era_solc::StandardJsonInputSelection::new_required(solc_codegen),
-        era_solc::StandardJsonInputMetadata::new(use_literal_content, metadata_hash_type),
+        metadata_hash_type,
+        use_literal_content,
+        base_path,
+        include_paths,
+        allow_paths,
+        remappings,
         llvm_options.clone(),
+        reports,
+        output_abi,
         vec![],
         vec![],
         false,
-        false,
+        ast_cache,
+        config,
     )?;
 
-    let mut solc_output = solc_compiler.standard_json(
-        &mut solc_input,
+    let (project, ast_jsons, abi_jsons) = standard_output_project(
+        solc_input.settings.libraries,
+        solc_codegen,
+        &mut solc_output,
+        solc_compiler,
+        execution_profile,
+        contract_filter,
+        reports,
+        output_ast,
+        output_abi,
+        config,
+        debug_config.as_ref(),
+    )?;
+
+    let mut build = project.compile_to_evm(
+        messages,
+        metadata_hash_type,
+        optimizer_settings,
+        llvm_options,
+        threads,
+        config,
+        debug_config,
+    )?;
+    build.ast_jsons = ast_jsons;
+    build.attach_abi_jsons(abi_jsons);
+    Ok(build)
+}
+
+///
+/// Runs the standard output mode for both EraVM and EVM targets.
+///
+/// `solc` is run once, and the project is shared by both targets, so only the LLVM stage is repeated.
+///
+pub fn standard_output_dual(
+    paths: &[PathBuf],
+    libraries: &[String],
+    solc_compiler: &era_solc::Compiler,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    codegen: Option<era_solc::StandardJsonInputCodegen>,
+    evm_version: Option<era_compiler_common::EVMVersion>,
+    metadata_hash_type: era_compiler_common::HashType,
+    use_literal_content: bool,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    remappings: BTreeSet<String>,
+    optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
+    llvm_options: Vec<String>,
+    balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+    execution_profile: Option<&ExecutionProfile>,
+    contract_filter: Option<&ContractFilter>,
+    reports: &Reports,
+    output_assembly: bool,
+    output_ast: bool,
+    output_abi: bool,
+    suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    strict_eravm: bool,
+    ast_cache: Option<PathBuf>,
+    threads: Option<usize>,
+    config: &BuildConfig,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<(EraVMBuild, EVMBuild)> {
+    let solc_codegen = era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, codegen);
+
+    let (solc_input, mut solc_output) = standard_output_solc(
+        paths,
+        libraries,
+        solc_compiler,
         messages,
+        codegen,
+        solc_codegen,
+        evm_version,
+        false,
+        metadata_hash_type,
+        use_literal_content,
         base_path,
         include_paths,
         allow_paths,
+        remappings,
+        llvm_options.clone(),
+        reports,
+        output_abi,
+        suppressed_errors,
+        suppressed_warnings,
+        strict_eravm,
+        ast_cache,
+        config,
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
 
-    let project = Project::try_from_solc_output(
+    let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+
+    let (mut project, ast_jsons, abi_jsons) = standard_output_project(
         solc_input.settings.libraries,
         solc_codegen,
         &mut solc_output,
         solc_compiler,
+        execution_profile,
+        contract_filter,
+        reports,
+        output_ast,
+        output_abi,
+        config,
         debug_config.as_ref(),
     )?;
-    solc_output.take_and_write_warnings();
-    solc_output.check_errors()?;
+    project.set_balance_lowering(balance_lowering);
 
-    let build = project.compile_to_evm(
+    let mut eravm_build = project.clone().compile_to_eravm(
+        messages,
+        false,
+        metadata_hash_type,
+        optimizer_settings.clone(),
+        llvm_options.clone(),
+        output_assembly,
+        None,
+        config,
+        debug_config.clone(),
+    )?;
+    eravm_build.take_and_write_warnings(config.sarif.as_ref());
+    eravm_build.check_errors()?;
+    let eravm_build = link_standard_output_eravm(
+        eravm_build,
+        linker_symbols,
+        ast_jsons.clone(),
+        abi_jsons.clone(),
+        config,
+    )?;
+
+    let mut evm_build = project.compile_to_evm(
         messages,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         threads,
+        config,
         debug_config,
     )?;
-    Ok(build)
+    evm_build.ast_jsons = ast_jsons;
+    evm_build.attach_abi_jsons(abi_jsons);
+
+    Ok((eravm_build, evm_build))
 }
 
 ///
-/// Runs the standard JSON mode for the EraVM target.
+/// Runs `solc` in the standard output mode, and writes the reports derived from its output.
 ///
-pub fn standard_json_eravm(
-    solc_compiler: Option<era_solc::Compiler>,
+/// The reports and the `solc` input echo are not written while warming the cache.
+///
+fn standard_output_solc(
+    paths: &[PathBuf],
+    libraries: &[String],
+    solc_compiler: &era_solc::Compiler,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
     codegen: Option<era_solc::StandardJsonInputCodegen>,
+    solc_codegen: era_solc::StandardJsonInputCodegen,
+    evm_version: Option<era_compiler_common::EVMVersion>,
     enable_eravm_extensions: bool,
-    detect_missing_libraries: bool,
-    json_path: Option<PathBuf>,
-    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    metadata_hash_type: era_compiler_common::HashType,
+    use_literal_content: bool,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
-    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
-) -> anyhow::Result<()> {
-    let mut solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
-    let language = solc_input.language;
-    let prune_output = solc_input.settings.selection_to_prune();
-    let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+    remappings: BTreeSet<String>,
+    llvm_options: Vec<String>,
+    reports: &Reports,
+    output_abi: bool,
+    suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    strict_eravm: bool,
+    ast_cache: Option<PathBuf>,
+    config: &BuildConfig,
+) -> anyhow::Result<(era_solc::StandardJsonInput, era_solc::StandardJsonOutput)> {
+    let mut selection = era_solc::StandardJsonInputSelection::new_required(solc_codegen);
+    if output_abi || reports.interface_freeze.is_some() || reports.revert_decoder.is_some() {
+        selection.extend(era_solc::StandardJsonInputSelection::new(vec![
+            era_solc::StandardJsonInputSelector::ABI,
+        ]));
+    }
 
-    let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
-        solc_input.settings.optimizer.mode,
+    config
+        .check_paths(paths)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
+    let mut solc_input = era_solc::StandardJsonInput::try_from_solidity_paths(
+        paths,
+        config.stdin_name.as_deref(),
+        libraries,
+        remappings,
+        era_solc::StandardJsonInputOptimizer::default(),
+        codegen,
+        evm_version,
+        enable_eravm_extensions,
+        selection,
+        era_solc::StandardJsonInputMetadata::new(use_literal_content, metadata_hash_type),
+        llvm_options,
+        suppressed_errors,
+        suppressed_warnings,
+        false,
+        false,
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)?;
+    config
+        .check_sources(&solc_input.sources)
+        .with_exit_code(era_solc::ExitCode::InputError)?;
+    solc_input.settings.strict_eravm = strict_eravm;
+    solc_input.settings.ast_cache = ast_cache;
+    solc_input.settings.compatibility_report = reports.compatibility_report.is_some();
+    solc_input.settings.revert_sites = reports.revert_decoder.is_some();
+    solc_input.settings.solc_input_echo = reports.solc_input_echo.is_some();
+    config.subscribers.emit(events::Event::SolcStarted);
+    let solc_start_time = std::time::Instant::now();
+    let mut solc_output = solc_compiler.standard_json(
+        &mut solc_input,
+        messages,
+        base_path,
+        include_paths,
+        allow_paths,
     )?;
-    if solc_input
-        .settings
-        .optimizer
-        .fallback_to_optimizing_for_size
+    config.subscribers.emit(events::Event::SolcFinished {
+        duration: solc_start_time.elapsed(),
+    });
+    config
+        .subscribers
+        .emit_warnings(solc_output.errors.as_slice());
+    if let Some(solc_input_echo) = reports
+        .solc_input_echo
+        .as_deref()
+        .filter(|_| !config.cache_warming)
     {
-        optimizer_settings.enable_fallback_to_size();
+        solc_output.take_and_write_solc_input(solc_input_echo)?;
+    }
+    solc_output.take_and_write_warnings(config.sarif.as_ref());
+    solc_output.check_errors()?;
+    if config.cache_warming {
+        return Ok((solc_input, solc_output));
+    }
+
+    if let Some(ref inheritance_graph) = reports.inheritance_graph {
+        solc_output
+            .inheritance_graph
+            .write_to_file(inheritance_graph)?;
     }
-    let llvm_options = solc_input.settings.llvm_options.clone();
+    if let Some(ref import_graph) = reports.import_graph {
+        solc_output.import_graph.write_to_file(import_graph)?;
+    }
+    if let Some(ref compatibility_report) = reports.compatibility_report {
+        solc_output
+            .compatibility_report
+            .write_to_file(compatibility_report)?;
+    }
+    if let Some(ref revert_decoder) = reports.revert_decoder {
+        RevertDecoder::new(&solc_output.abi_jsons(), &solc_output.revert_sites)
+            .write_to_file(revert_decoder)?;
+    }
+    if let Some(ref interface_freeze) = reports.interface_freeze {
+        let errors = interface_freeze.check(&solc_output.abi_jsons());
+        solc_output.errors.extend(errors);
+        solc_output.check_errors()?;
+    }
+    Ok((solc_input, solc_output))
+}
 
-    let codegen = if solc_input.settings.force_evmla {
-        Some(era_solc::StandardJsonInputCodegen::EVMLA)
+///
+/// Builds the project from the `solc` output in the standard output mode, and writes the graphs derived from it.
+///
+/// Returns the project along with the AST and ABI JSONs taken from the `solc` output, if they are requested.
+/// The graphs are not written while warming the cache.
+///
+fn standard_output_project(
+    libraries: era_solc::StandardJsonInputLibraries,
+    solc_codegen: era_solc::StandardJsonInputCodegen,
+    solc_output: &mut era_solc::StandardJsonOutput,
+    solc_compiler: &era_solc::Compiler,
+    execution_profile: Option<&ExecutionProfile>,
+    contract_filter: Option<&ContractFilter>,
+    reports: &Reports,
+    output_ast: bool,
+    output_abi: bool,
+    config: &BuildConfig,
+    debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<(
+    Project,
+    BTreeMap<String, serde_json::Value>,
+    BTreeMap<String, serde_json::Value>,
+)> {
+    let mut project = Project::try_from_solc_output(
+        libraries,
+        solc_codegen,
+        solc_output,
+        solc_compiler,
+        config,
+        debug_config,
+    )?;
+    solc_output.take_and_write_warnings(config.sarif.as_ref());
+    solc_output.check_errors()?;
+    if let Some(dependency_graph) = reports
+        .dependency_graph
+        .as_deref()
+        .filter(|_| !config.cache_warming)
+    {
+        project
+            .dependency_graph(&solc_output.import_graph, &solc_output.linked_libraries)
+            .write_to_directory(dependency_graph)?;
+    }
+    if let Some(contract_filter) = contract_filter {
+        project.apply_contract_filter(contract_filter)?;
+    }
+    if let Some(execution_profile) = execution_profile {
+        project.apply_execution_profile(execution_profile);
+    }
+    if let Some(call_graph) = reports
+        .call_graph
+        .as_deref()
+        .filter(|_| !config.cache_warming)
+    {
+        project.call_graph().write_to_directory(call_graph)?;
+    }
+    let ast_jsons = if output_ast {
+        solc_output.take_ast_jsons()
     } else {
-        codegen
+        BTreeMap::new()
     };
-    let enable_eravm_extensions =
-        solc_input.settings.enable_eravm_extensions || enable_eravm_extensions;
-    let detect_missing_libraries =
-        solc_input.settings.detect_missing_libraries || detect_missing_libraries;
-    let metadata_hash_type = solc_input.settings.metadata.hash_type;
-    let output_assembly = solc_input
-        .settings
-        .output_selection
-        .contains(&era_solc::StandardJsonInputSelector::EraVMAssembly);
-
-    let (mut solc_output, solc_version, project) = match (language, solc_compiler) {
-        (era_solc::StandardJsonInputLanguage::Solidity, solc_compiler) => {
-            let solc_compiler = match solc_compiler {
-                Some(solc_compiler) => solc_compiler,
-                None => era_solc::Compiler::try_from_default()?,
-            };
-
-            let solc_codegen =
-                era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, codegen);
-            solc_input.extend_selection(era_solc::StandardJsonInputSelection::new_required(
-                solc_codegen,
-            ));
-
-            let mut solc_output = solc_compiler.standard_json(
-                &mut solc_input,
-                messages,
-                base_path,
-                include_paths,
-                allow_paths,
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            let project = Project::try_from_solc_output(
-                solc_input.settings.libraries,
-                solc_codegen,
-                &mut solc_output,
-                &solc_compiler,
-                debug_config.as_ref(),
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            (solc_output, Some(solc_compiler.version), project)
-        }
-        (era_solc::StandardJsonInputLanguage::Yul, Some(solc_compiler)) => {
-            let mut solc_output =
-                solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            let project = Project::try_from_yul_sources(
-                solc_input.sources,
-                solc_input.settings.libraries,
-                Some(&mut solc_output),
-                Some(&solc_compiler.version),
-                debug_config.as_ref(),
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            (solc_output, Some(solc_compiler.version), project)
-        }
-        (era_solc::StandardJsonInputLanguage::Yul, None) => {
-            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
-
-            let project = Project::try_from_yul_sources(
-                solc_input.sources,
-                solc_input.settings.libraries,
-                Some(&mut solc_output),
-                None,
-                debug_config.as_ref(),
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            (solc_output, None, project)
-        }
-        (era_solc::StandardJsonInputLanguage::LLVMIR, Some(_)) => {
-            anyhow::bail!("LLVM IR projects cannot be compiled with `solc`.")
-        }
-        (era_solc::StandardJsonInputLanguage::LLVMIR, None) => {
-            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
-
-            let project = Project::try_from_llvm_ir_sources(
-                solc_input.sources,
-                solc_input.settings.libraries,
-                Some(&mut solc_output),
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            (solc_output, None, project)
-        }
-        (era_solc::StandardJsonInputLanguage::EraVMAssembly, Some(_)) => {
-            anyhow::bail!("EraVM assembly projects cannot be compiled with `solc`.")
-        }
-        (era_solc::StandardJsonInputLanguage::EraVMAssembly, None) => {
-            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
-
-            let project = Project::try_from_eravm_assembly_sources(
-                solc_input.sources,
-                Some(&mut solc_output),
-            )?;
-            if solc_output.has_errors() {
-                solc_output.write_and_exit(prune_output);
-            }
-
-            (solc_output, None, project)
-        }
+    let abi_jsons = if output_abi {
+        solc_output.take_abi_jsons()
+    } else {
+        BTreeMap::new()
     };
+    Ok((project, ast_jsons, abi_jsons))
+}
 
-    if detect_missing_libraries {
-        let missing_libraries = project.get_missing_libraries();
-        missing_libraries.write_to_standard_json(&mut solc_output, solc_version.as_ref());
-        solc_output.write_and_exit(prune_output);
-    }
+///
+/// Links the EraVM build in the standard output mode, and attaches the AST and ABI JSONs to it.
+///
+fn link_standard_output_eravm(
+    build: EraVMBuild,
+    linker_symbols: BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ast_jsons: BTreeMap<String, serde_json::Value>,
+    abi_jsons: BTreeMap<String, serde_json::Value>,
+    config: &BuildConfig,
+) -> anyhow::Result<EraVMBuild> {
+    let mut build = build.link(linker_symbols);
+    build.take_and_write_warnings(config.sarif.as_ref());
+    build.check_errors()?;
+    build.ast_jsons = ast_jsons;
+    build.attach_abi_jsons(abi_jsons);
+    Ok(build)
+}
 
-    let build = project.compile_to_eravm(
-        messages,
+///
+/// Runs the standard JSON mode for the EraVM target.
+///
+pub fn standard_json_eravm(
+    solc_compiler: Option<era_solc::Compiler>,
+    codegen: Option<era_solc::StandardJsonInputCodegen>,
+    enable_eravm_extensions: bool,
+    detect_missing_libraries: bool,
+    json_path: Option<PathBuf>,
+    profile: Option<String>,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    base_path: Option<String>,
+    include_paths: Vec<String>,
+    allow_paths: Option<String>,
+    config: &BuildConfig,
+    debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+) -> anyhow::Result<()> {
+    let mut solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
+    solc_input.settings.apply_profile(profile)?;
+    let options = standard_json::Options {
+        codegen,
         enable_eravm_extensions,
-        metadata_hash_type,
-        optimizer_settings,
-        llvm_options,
-        output_assembly,
+        detect_missing_libraries,
+        base_path,
+        include_paths,
+        allow_paths,
+        build: config.to_owned(),
         debug_config,
-    )?;
-    if build.has_errors() {
-        build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
-        solc_output.write_and_exit(prune_output);
+    };
+    let solc_output =
+        standard_json::compile(solc_input, solc_compiler.as_ref(), options, messages)?;
+    if config.cache_warming {
+        messages.extend(solc_output.errors);
+        return Ok(());
     }
-
-    let build = build.link(linker_symbols);
-    build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
-    solc_output.write_and_exit(prune_output);
+    solc_output.write_finalized_and_exit();
 }
 
 ///
@@ -618,16 +990,24 @@ pub fn standard_json_evm(
     solc_compiler: Option<era_solc::Compiler>,
     codegen: Option<era_solc::StandardJsonInputCodegen>,
     json_path: Option<PathBuf>,
+    profile: Option<String>,
     messages: &mut Vec<era_solc::StandardJsonOutputError>,
     base_path: Option<String>,
     include_paths: Vec<String>,
     allow_paths: Option<String>,
     threads: Option<usize>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<()> {
+    let start_time = std::time::Instant::now();
     let mut solc_input = era_solc::StandardJsonInput::try_from(json_path.as_deref())?;
+    solc_input.settings.apply_profile(profile)?;
     let language = solc_input.language;
     let prune_output = solc_input.settings.selection_to_prune();
+    if !config.check_standard_json_sources(&solc_input.sources, messages) {
+        era_solc::StandardJsonOutput::new(&solc_input.sources, messages)
+            .write_and_exit(prune_output);
+    }
 
     let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
         solc_input.settings.optimizer.mode,
@@ -639,15 +1019,49 @@ pub fn standard_json_evm(
     {
         optimizer_settings.enable_fallback_to_size();
     }
-    let llvm_options = solc_input.settings.llvm_options.clone();
+    let mut llvm_options = solc_input.settings.llvm_options.global.clone();
+    if let Some(loop_optimization) = solc_input.settings.optimizer.loop_optimization {
+        llvm_options.extend(loop_optimization.llvm_options());
+    }
+    if let Some(spill_strategy) = solc_input.settings.optimizer.spill_strategy {
+        llvm_options.extend(spill_strategy.llvm_options());
+    }
 
     let metadata_hash_type = solc_input.settings.metadata.hash_type;
 
-    let (mut solc_output, solc_version, project) = match (language, solc_compiler) {
+    let reachable_selectors = std::mem::take(&mut solc_input.settings.reachable_selectors);
+    let immutables = std::mem::take(&mut solc_input.settings.immutables);
+    let contract_llvm_options = std::mem::take(&mut solc_input.settings.llvm_options.contracts);
+    let policy = solc_input.settings.policy.clone();
+    let target_contract = solc_input.settings.contract.take();
+    let output_summary = solc_input.settings.output_summary;
+    let profile = solc_input.settings.profile.take();
+    let contract_filter = ContractFilter::new(
+        std::mem::take(&mut solc_input.settings.only),
+        target_contract.clone(),
+    );
+    let detect_missing_libraries = solc_input.settings.detect_missing_libraries;
+    let output_timings = solc_input.settings.output_timings;
+    let threads = solc_input.settings.parallel.threads().or(threads);
+    if solc_input.settings.library_deployer {
+        messages.push(era_solc::StandardJsonOutputError::new_warning(
+            format!(
+                "The `libraryDeployer` setting is not supported for the {} target yet, and therefore ignored.",
+                era_compiler_common::Target::EVM
+            ),
+            None,
+            None,
+        ));
+    }
+
+    let mut solc_duration = None;
+    let solc_compiler =
+        solc_compiler.map(|solc_compiler| solc_compiler.with_deadline(config.deadline));
+    let (mut solc_output, solc_version, mut project) = match (language, solc_compiler) {
         (era_solc::StandardJsonInputLanguage::Solidity, solc_compiler) => {
             let solc_compiler = match solc_compiler {
                 Some(solc_compiler) => solc_compiler,
-                None => era_solc::Compiler::try_from_default()?,
+                None => era_solc::Compiler::try_from_default()?.with_deadline(config.deadline),
             };
 
             let solc_codegen =
@@ -655,7 +1069,18 @@ pub fn standard_json_evm(
             solc_input.extend_selection(era_solc::StandardJsonInputSelection::new_required(
                 solc_codegen,
             ));
-
+            let interface_freeze = solc_input.settings.interface_freeze.take();
+            let abi_requested = solc_input
+                .settings
+                .output_selection
+                .contains(&era_solc::StandardJsonInputSelector::ABI);
+            if interface_freeze.is_some() {
+                solc_input.extend_selection(era_solc::StandardJsonInputSelection::new(vec![
+                    era_solc::StandardJsonInputSelector::ABI,
+                ]));
+            }
+            config.subscribers.emit(events::Event::SolcStarted);
+            let solc_start_time = std::time::Instant::now();
             let mut solc_output = solc_compiler.standard_json(
                 &mut solc_input,
                 messages,
@@ -663,15 +1088,33 @@ pub fn standard_json_evm(
                 include_paths,
                 allow_paths,
             )?;
+            solc_duration = Some(solc_start_time.elapsed());
+            config.subscribers.emit(events::Event::SolcFinished {
+                duration: solc_start_time.elapsed(),
+            });
+            config
+                .subscribers
+                .emit_warnings(solc_output.errors.as_slice());
             if solc_output.has_errors() {
                 solc_output.write_and_exit(prune_output);
             }
+            if let Some(ref interface_freeze) = interface_freeze {
+                InterfaceFreeze::check_standard_json(
+                    interface_freeze.as_path(),
+                    &mut solc_output,
+                    !abi_requested,
+                );
+                if solc_output.has_errors() {
+                    solc_output.write_and_exit(prune_output);
+                }
+            }
 
             let project = Project::try_from_solc_output(
                 solc_input.settings.libraries,
                 solc_codegen,
                 &mut solc_output,
                 &solc_compiler,
+                config,
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
@@ -681,8 +1124,17 @@ pub fn standard_json_evm(
             (solc_output, Some(solc_compiler.version), project)
         }
         (era_solc::StandardJsonInputLanguage::Yul, Some(solc_compiler)) => {
+            config.subscribers.emit(events::Event::SolcStarted);
+            let solc_start_time = std::time::Instant::now();
             let mut solc_output =
                 solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
+            solc_duration = Some(solc_start_time.elapsed());
+            config.subscribers.emit(events::Event::SolcFinished {
+                duration: solc_start_time.elapsed(),
+            });
+            config
+                .subscribers
+                .emit_warnings(solc_output.errors.as_slice());
             if solc_output.has_errors() {
                 solc_output.write_and_exit(prune_output);
             }
@@ -692,6 +1144,7 @@ pub fn standard_json_evm(
                 solc_input.settings.libraries,
                 Some(&mut solc_output),
                 Some(&solc_compiler.version),
+                config,
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
@@ -702,12 +1155,15 @@ pub fn standard_json_evm(
         }
         (era_solc::StandardJsonInputLanguage::Yul, None) => {
             let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
 
             let project = Project::try_from_yul_sources(
                 solc_input.sources,
                 solc_input.settings.libraries,
                 Some(&mut solc_output),
                 None,
+                config,
                 debug_config.as_ref(),
             )?;
             if solc_output.has_errors() {
@@ -717,10 +1173,15 @@ pub fn standard_json_evm(
             (solc_output, None, project)
         }
         (era_solc::StandardJsonInputLanguage::LLVMIR, Some(_)) => {
-            anyhow::bail!("LLVM IR projects cannot be compiled with `solc`.")
+            anyhow::bail!(era_solc::ExitCodeError::new(
+                era_solc::ExitCode::InputError,
+                "LLVM IR projects cannot be compiled with `solc`.".to_owned(),
+            ))
         }
         (era_solc::StandardJsonInputLanguage::LLVMIR, None) => {
             let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
 
             let project = Project::try_from_llvm_ir_sources(
                 solc_input.sources,
@@ -734,10 +1195,15 @@ pub fn standard_json_evm(
             (solc_output, None, project)
         }
         (era_solc::StandardJsonInputLanguage::EraVMAssembly, Some(_)) => {
-            anyhow::bail!("EraVM assembly projects cannot be compiled with `solc`.")
+            anyhow::bail!(era_solc::ExitCodeError::new(
+                era_solc::ExitCode::InputError,
+                "EraVM assembly projects cannot be compiled with `solc`.".to_owned(),
+            ))
         }
         (era_solc::StandardJsonInputLanguage::EraVMAssembly, None) => {
             let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
 
             let project = Project::try_from_eravm_assembly_sources(
                 solc_input.sources,
@@ -751,21 +1217,81 @@ pub fn standard_json_evm(
         }
     };
 
-    let build = project.compile_to_evm(
+    let missing_libraries = project.get_missing_libraries();
+    if detect_missing_libraries {
+        missing_libraries.write_to_standard_json(&mut solc_output, solc_version.as_ref());
+        solc_output.write_and_exit(prune_output);
+    }
+
+    solc_output.errors.extend(project.check_policy(&policy));
+    if solc_output.has_errors() {
+        solc_output.write_and_exit(prune_output);
+    }
+
+    if let Some(ref contract_filter) = contract_filter {
+        if let Err(error) = project.apply_contract_filter(contract_filter) {
+            solc_output.push_error(None, error);
+            solc_output.write_and_exit(prune_output);
+        }
+        solc_output.retain_contracts(&project.contracts.keys().cloned().collect());
+    }
+    project.apply_output_selection(&solc_input.settings.output_selection);
+    project.apply_reachable_selectors(&reachable_selectors)?;
+    project.apply_immutables(&immutables)?;
+    project.apply_llvm_options(&contract_llvm_options, messages);
+    if let Some(profile) = profile {
+        project.set_profile(profile.as_str());
+    }
+
+    let mut build = project.compile_to_evm(
         messages,
         metadata_hash_type,
         optimizer_settings,
         llvm_options,
         threads,
+        config,
         debug_config,
     )?;
+    let selected_contract = match target_contract {
+        Some(target_contract) => match build.select_contract(target_contract.as_str()) {
+            Ok(full_path) => build
+                .results
+                .get(full_path.as_str())
+                .and_then(|result| result.as_ref().ok())
+                .map(|contract| contract.name.clone()),
+            Err(error) => {
+                solc_output
+                    .errors
+                    .push(era_solc::StandardJsonOutputError::new_error(
+                        error, None, None,
+                    ));
+                solc_output.write_and_exit(prune_output);
+            }
+        },
+        None => None,
+    };
     build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
+    missing_libraries.write_to_standard_json(&mut solc_output, solc_version.as_ref());
+    if let Some(name) = selected_contract {
+        solc_output.retain_contract(
+            name.path.as_str(),
+            name.name.as_deref().unwrap_or(name.path.as_str()),
+        );
+    }
+    if output_summary {
+        solc_output.set_summary(None, solc_duration, start_time.elapsed());
+    }
+    if output_timings {
+        solc_output.set_timings(solc_duration);
+    }
     solc_output.write_and_exit(prune_output);
 }
 
 ///
 /// Runs the combined JSON mode for the EraVM target.
 ///
+/// The temporary directory, if set, is cleaned up according to its policy once the contracts are built.
+///
 pub fn combined_json_eravm(
     format: String,
     paths: &[PathBuf],
@@ -785,8 +1311,13 @@ pub fn combined_json_eravm(
     overwrite: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    balance_lowering: era_solc::StandardJsonInputBalanceLowering,
     suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
     suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+    temp_dir: Option<TempDir>,
+    strict_eravm: bool,
+    ast_cache: Option<PathBuf>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<()> {
     let selector_results = era_solc::CombinedJsonSelector::from_cli(format.as_str());
@@ -839,17 +1370,33 @@ pub fn combined_json_eravm(
         remappings,
         optimizer_settings,
         llvm_options,
+        balance_lowering,
+        None,
+        None,
+        &Reports::default(),
         output_assembly,
+        false,
+        false,
         suppressed_errors,
         suppressed_warnings,
+        strict_eravm,
+        ast_cache,
+        None,
+        false,
+        config,
         debug_config,
     )?;
-    build.write_to_combined_json(&mut combined_json)?;
+    if let Some(temp_dir) = temp_dir {
+        temp_dir.finish(!build.has_errors())?;
+    }
+    build.write_to_combined_json(&mut combined_json, config.sarif.as_ref())?;
 
     match output_directory {
         Some(output_directory) => {
             std::fs::create_dir_all(output_directory.as_path())?;
-            combined_json.write_to_directory(output_directory.as_path(), overwrite)?;
+            combined_json
+                .write_to_directory(output_directory.as_path(), overwrite)
+                .with_exit_code(era_solc::ExitCode::InputError)?;
 
             writeln!(
                 std::io::stderr(),
@@ -866,6 +1413,8 @@ pub fn combined_json_eravm(
 ///
 /// Runs the combined JSON mode for the EVM target.
 ///
+/// The temporary directory, if set, is cleaned up according to its policy once the contracts are built.
+///
 pub fn combined_json_evm(
     format: String,
     paths: &[PathBuf],
@@ -884,7 +1433,10 @@ pub fn combined_json_evm(
     overwrite: bool,
     optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     llvm_options: Vec<String>,
+    ast_cache: Option<PathBuf>,
     threads: Option<usize>,
+    temp_dir: Option<TempDir>,
+    config: &BuildConfig,
     debug_config: Option<era_compiler_llvm_context::DebugConfig>,
 ) -> anyhow::Result<()> {
     let selector_results = era_solc::CombinedJsonSelector::from_cli(format.as_str());
@@ -932,15 +1484,27 @@ pub fn combined_json_evm(
         remappings,
         optimizer_settings,
         llvm_options,
+        None,
+        None,
+        &Reports::default(),
+        false,
+        false,
+        ast_cache,
         threads,
+        config,
         debug_config,
     )?;
-    build.write_to_combined_json(&mut combined_json)?;
+    if let Some(temp_dir) = temp_dir {
+        temp_dir.finish(!build.has_errors())?;
+    }
+    build.write_to_combined_json(&mut combined_json, config.sarif.as_ref())?;
 
     match output_directory {
         Some(output_directory) => {
             std::fs::create_dir_all(output_directory.as_path())?;
-            combined_json.write_to_directory(output_directory.as_path(), overwrite)?;
+            combined_json
+                .write_to_directory(output_directory.as_path(), overwrite)
+                .with_exit_code(era_solc::ExitCode::InputError)?;
 
             writeln!(
                 std::io::stderr(),
@@ -955,17 +1519,14 @@ pub fn combined_json_evm(
 }
 
 ///
-/// Runs the disassembler for EraVM bytecode file and prints the output to stdout.
+/// Runs the disassembler for EraVM bytecode files or hexadecimal strings and prints the output to stdout.
 ///
-pub fn disassemble_eravm(paths: Vec<String>) -> anyhow::Result<()> {
-    let bytecodes = paths
+pub fn disassemble_eravm(inputs: Vec<String>) -> anyhow::Result<()> {
+    let bytecodes = inputs
         .into_par_iter()
-        .map(|path| {
-            let pathbuf = PathBuf::from(path.as_str());
-            let string = std::fs::read_to_string(pathbuf)?;
-            let hexadecimal_string = string.trim().strip_prefix("0x").unwrap_or(string.as_str());
-            let bytecode = hex::decode(hexadecimal_string)?;
-            Ok((path, bytecode))
+        .map(|input| {
+            let bytecode = Disassembly::read_bytecode(input.as_str())?;
+            Ok((input, bytecode))
         })
         .collect::<anyhow::Result<BTreeMap<String, Vec<u8>>>>()?;
 
@@ -975,22 +1536,20 @@ pub fn disassemble_eravm(paths: Vec<String>) -> anyhow::Result<()> {
         &[],
     )?;
 
-    let disassemblies: Vec<(String, String)> = bytecodes
+    let disassemblies: Vec<(String, Disassembly)> = bytecodes
         .into_iter()
-        .map(|(path, bytecode)| {
-            let bytecode_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
-                bytecode.as_slice(),
-                path.as_str(),
-                false,
-            );
-            let disassembly =
-                era_compiler_llvm_context::eravm_disassemble(&target_machine, &bytecode_buffer)?;
-            Ok((path, disassembly))
+        .map(|(input, bytecode)| {
+            let disassembly = Disassembly::try_from_bytecode(&target_machine, bytecode.as_slice())?;
+            Ok((input, disassembly))
         })
-        .collect::<anyhow::Result<Vec<(String, String)>>>()?;
+        .collect::<anyhow::Result<Vec<(String, Disassembly)>>>()?;
 
-    for (path, disassembly) in disassemblies.into_iter() {
-        writeln!(std::io::stderr(), "File `{path}` disassembly:\n\n")?;
+    for (input, disassembly) in disassemblies.into_iter() {
+        if Disassembly::is_hexadecimal_string(input.as_str()) {
+            writeln!(std::io::stderr(), "Bytecode disassembly:\n\n")?;
+        } else {
+            writeln!(std::io::stderr(), "File `{input}` disassembly:\n\n")?;
+        }
         writeln!(std::io::stdout(), "{disassembly}")?;
         writeln!(std::io::stderr(), "\n\n")?;
     }
@@ -1000,7 +1559,15 @@ pub fn disassemble_eravm(paths: Vec<String>) -> anyhow::Result<()> {
 ///
 /// Links EraVM bytecode files.
 ///
-pub fn link_eravm(paths: Vec<String>, libraries: Vec<String>) -> anyhow::Result<()> {
+/// The files are modified in place, unless `output_directory` is set. In the latter case, the
+/// linked and ignored files are written there under their input paths, so the unlinked files
+/// can be linked again with other library addresses, e.g. for another network.
+///
+pub fn link_eravm(
+    paths: Vec<String>,
+    libraries: Vec<String>,
+    output_directory: Option<PathBuf>,
+) -> anyhow::Result<()> {
     let bytecodes = paths
         .into_par_iter()
         .map(|path| {
@@ -1012,14 +1579,48 @@ pub fn link_eravm(paths: Vec<String>, libraries: Vec<String>) -> anyhow::Result<
     let input = LinkerInput::new(bytecodes, libraries);
     let output = Linker::link_eravm(input)?;
 
-    output
-        .linked
-        .par_iter()
-        .map(|(path, contract)| {
-            std::fs::write(path, contract.bytecode.as_bytes())?;
-            Ok(())
-        })
-        .collect::<anyhow::Result<()>>()?;
+    match output_directory {
+        Some(output_directory) => {
+            let bytecodes = output
+                .linked
+                .iter()
+                .map(|(path, contract)| (path, contract.bytecode.as_str()))
+                .chain(
+                    output
+                        .ignored
+                        .iter()
+                        .map(|(path, contract)| (path, contract.bytecode.as_str())),
+                )
+                .collect::<Vec<(&String, &str)>>();
+            bytecodes
+                .into_par_iter()
+                .map(|(path, bytecode)| {
+                    let output_path = output_directory.join(
+                        crate::artifact_directories::ArtifactDirectories::relative_path(
+                            path.as_str(),
+                        ),
+                    );
+                    if let Some(parent) = output_path.parent() {
+                        std::fs::create_dir_all(parent)?;
+                    }
+                    std::fs::write(output_path.as_path(), bytecode.as_bytes()).map_err(
+                        |error| anyhow::anyhow!("File {output_path:?} writing: {error}"),
+                    )?;
+                    Ok(())
+                })
+                .collect::<anyhow::Result<()>>()?;
+        }
+        None => {
+            output
+                .linked
+                .par_iter()
+                .map(|(path, contract)| {
+                    std::fs::write(path, contract.bytecode.as_bytes())?;
+                    Ok(())
+                })
+                .collect::<anyhow::Result<()>>()?;
+        }
+    }
 
     serde_json::to_writer(std::io::stdout(), &output)?;
     std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
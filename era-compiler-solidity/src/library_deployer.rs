@@ -0,0 +1,122 @@
+//!
+//! The library deployer contract.
+//!
+
+use std::collections::BTreeMap;
+
+/// The `Create2Factory` system contract address.
+pub const CREATE2_FACTORY_ADDRESS: u64 = 0x10000;
+
+/// The `CREATE2` address derivation prefix preimage.
+pub const CREATE2_PREFIX_PREIMAGE: &str = "zksyncCreate2";
+
+/// The `Create2Factory` deployment function signature.
+pub const CREATE2_SIGNATURE: &str = "create2(bytes32,bytes32,bytes)";
+
+/// The library deployer source path.
+pub const PATH: &str = "LibraryDeployer.yul";
+
+/// The library deployer Yul object identifier.
+pub const IDENTIFIER: &str = "LibraryDeployer";
+
+///
+/// Returns the address the library with `bytecode_hash` is deployed at by the `Create2Factory`.
+///
+/// Libraries have no constructor arguments and are deployed with the zero salt, so the address
+/// only depends on the bytecode hash and can be linked into the callers at compile time.
+///
+pub fn address(
+    bytecode_hash: &[u8; era_compiler_common::BYTE_LENGTH_FIELD],
+) -> [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS] {
+    let mut sender = [0u8; era_compiler_common::BYTE_LENGTH_FIELD];
+    sender[era_compiler_common::BYTE_LENGTH_FIELD - std::mem::size_of::<u64>()..]
+        .copy_from_slice(CREATE2_FACTORY_ADDRESS.to_be_bytes().as_slice());
+
+    let mut preimage = Vec::with_capacity(era_compiler_common::BYTE_LENGTH_FIELD * 5);
+    preimage.extend_from_slice(
+        era_compiler_common::Hash::keccak256(CREATE2_PREFIX_PREIMAGE.as_bytes()).as_bytes(),
+    );
+    preimage.extend_from_slice(sender.as_slice());
+    preimage.extend_from_slice([0u8; era_compiler_common::BYTE_LENGTH_FIELD].as_slice());
+    preimage.extend_from_slice(bytecode_hash.as_slice());
+    preimage.extend_from_slice(era_compiler_common::Hash::keccak256(&[]).as_bytes());
+
+    era_compiler_common::Hash::keccak256(preimage.as_slice()).as_bytes()
+        [era_compiler_common::BYTE_LENGTH_FIELD - era_compiler_common::BYTE_LENGTH_ETH_ADDRESS..]
+        .try_into()
+        .expect("Always valid")
+}
+
+///
+/// Generates the Yul source code of the library deployer.
+///
+/// `libraries` are the library Yul object identifiers, keyed by library full path.
+/// The constructor deploys each library via the `Create2Factory` unless it has already been
+/// deployed, and the libraries are registered as factory dependencies of the deployer.
+/// In EraVM, `dataoffset` of a dependency object is its bytecode hash.
+///
+pub fn source_code(libraries: &BTreeMap<String, String>) -> String {
+    let prefix = hex::encode(
+        era_compiler_common::Hash::keccak256(CREATE2_PREFIX_PREIMAGE.as_bytes()).as_bytes(),
+    );
+    let empty_input_hash = hex::encode(era_compiler_common::Hash::keccak256(&[]).as_bytes());
+    let selector = hex::encode(
+        &era_compiler_common::Hash::keccak256(CREATE2_SIGNATURE.as_bytes()).as_bytes()
+            [..era_compiler_common::BYTE_LENGTH_X32],
+    );
+
+    let mut deployments = String::new();
+    for (index, (path, identifier)) in libraries.iter().enumerate() {
+        deployments.push_str(
+            format!(
+                r#"
+            // `{path}`
+            let hash_{index} := dataoffset("{identifier}")
+            mstore(0, 0x{prefix})
+            mstore(32, {CREATE2_FACTORY_ADDRESS:#x})
+            mstore(64, 0)
+            mstore(96, hash_{index})
+            mstore(128, 0x{empty_input_hash})
+            if iszero(extcodesize(and(keccak256(0, 160), 0xffffffffffffffffffffffffffffffffffffffff))) {{
+                mstore(0, 0x{selector}{padding})
+                mstore(4, 0)
+                mstore(36, hash_{index})
+                mstore(68, 96)
+                mstore(100, 0)
+                if iszero(call(gas(), {CREATE2_FACTORY_ADDRESS:#x}, 0, 0, 132, 0, 0)) {{
+                    returndatacopy(0, 0, returndatasize())
+                    revert(0, returndatasize())
+                }}
+            }}
+"#,
+                padding = "0".repeat(
+                    (era_compiler_common::BYTE_LENGTH_FIELD
+                        - era_compiler_common::BYTE_LENGTH_X32)
+                        * 2
+                ),
+            )
+            .as_str(),
+        );
+    }
+
+    format!(
+        r#"object "{IDENTIFIER}" {{
+    code {{
+        {{
+{deployments}
+            let size := datasize("{IDENTIFIER}_deployed")
+            codecopy(0, dataoffset("{IDENTIFIER}_deployed"), size)
+            return(0, size)
+        }}
+    }}
+    object "{IDENTIFIER}_deployed" {{
+        code {{
+            {{
+                revert(0, 0)
+            }}
+        }}
+    }}
+}}
+"#
+    )
+}
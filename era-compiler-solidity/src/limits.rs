@@ -0,0 +1,140 @@
+//!
+//! The project size limits.
+//!
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+///
+/// The project size limits.
+///
+/// Bound the worst-case resource consumption of a build, so oversized projects are rejected
+/// before `solc` is spawned or any LLVM work is started.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Limits {
+    /// The maximum number of sources.
+    pub max_sources: usize,
+    /// The maximum number of contracts.
+    pub max_contracts: usize,
+    /// The maximum total size of the sources in bytes.
+    pub max_source_bytes: usize,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            max_sources: Self::DEFAULT_MAX_SOURCES,
+            max_contracts: Self::DEFAULT_MAX_CONTRACTS,
+            max_source_bytes: Self::DEFAULT_MAX_SOURCE_BYTES,
+        }
+    }
+}
+
+impl Limits {
+    /// The default maximum number of sources.
+    pub const DEFAULT_MAX_SOURCES: usize = 20_000;
+
+    /// The default maximum number of contracts.
+    pub const DEFAULT_MAX_CONTRACTS: usize = 20_000;
+
+    /// The default maximum total size of the sources in bytes.
+    pub const DEFAULT_MAX_SOURCE_BYTES: usize = 512 * 1024 * 1024;
+
+    ///
+    /// Checks the number and the total size of the source files at `paths` against the limits.
+    ///
+    /// The sizes are taken from the file system, so oversized projects are rejected before the
+    /// sources are read. The standard input and the files that cannot be accessed are not counted,
+    /// and are checked with [`Self::check_sources`] after reading.
+    ///
+    pub fn check_paths(&self, paths: &[PathBuf]) -> anyhow::Result<()> {
+        self.check_source_count(paths.len())?;
+        let source_bytes = paths
+            .iter()
+            .filter_map(|path| std::fs::metadata(path).ok())
+            .map(|metadata| metadata.len() as usize)
+            .sum();
+        self.check_source_bytes(source_bytes)
+    }
+
+    ///
+    /// Checks the number and the total size of `sources` against the limits.
+    ///
+    /// The size of the sources given by URLs is taken from the file system, as they have not been
+    /// read yet.
+    ///
+    pub fn check_sources(
+        &self,
+        sources: &BTreeMap<String, era_solc::StandardJsonInputSource>,
+    ) -> anyhow::Result<()> {
+        self.check_source_count(sources.len())?;
+        let source_bytes = sources.values().map(Self::source_size).sum();
+        self.check_source_bytes(source_bytes)
+    }
+
+    ///
+    /// Checks the number of `contracts` against the limits.
+    ///
+    pub fn check_contracts(&self, contracts: usize) -> anyhow::Result<()> {
+        if contracts > self.max_contracts {
+            return Err(Self::error(format!(
+                "The number of contracts ({contracts}) exceeds the limit of {}. Please raise it with `--max-contracts`, or disable the limits with `--no-limits`.",
+                self.max_contracts,
+            )));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the number of sources against the limits.
+    ///
+    fn check_source_count(&self, sources: usize) -> anyhow::Result<()> {
+        if sources > self.max_sources {
+            return Err(Self::error(format!(
+                "The number of sources ({sources}) exceeds the limit of {}. Please raise it with `--max-sources`, or disable the limits with `--no-limits`.",
+                self.max_sources,
+            )));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Checks the total size of the sources against the limits.
+    ///
+    fn check_source_bytes(&self, source_bytes: usize) -> anyhow::Result<()> {
+        if source_bytes > self.max_source_bytes {
+            return Err(Self::error(format!(
+                "The total size of the sources ({source_bytes} bytes) exceeds the limit of {} bytes. Please raise it with `--max-source-bytes`, or disable the limits with `--no-limits`.",
+                self.max_source_bytes,
+            )));
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the size of the `source` in bytes.
+    ///
+    /// The sources given by URLs are measured by the first file that exists, which is the one
+    /// `solc` reads.
+    ///
+    fn source_size(source: &era_solc::StandardJsonInputSource) -> usize {
+        if let Some(content) = source.content() {
+            return content.len();
+        }
+        source
+            .urls
+            .iter()
+            .flatten()
+            .find_map(|url| std::fs::metadata(url).ok())
+            .map(|metadata| metadata.len() as usize)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Returns the limit violation error.
+    ///
+    fn error(message: String) -> anyhow::Error {
+        era_solc::ExitCodeError::new(era_solc::ExitCode::ResourceLimit, message).into()
+    }
+}
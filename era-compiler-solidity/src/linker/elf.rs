@@ -0,0 +1,216 @@
+//!
+//! The ELF object relocation reader.
+//!
+
+/// The relocation section type with explicit addends.
+pub const SECTION_TYPE_RELA: u32 = 4;
+
+/// The relocation section type without explicit addends.
+pub const SECTION_TYPE_REL: u32 = 9;
+
+///
+/// The ELF object relocation.
+///
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Relocation {
+    /// The name of the symbol the relocation refers to.
+    pub symbol: String,
+    /// The offset of the relocated field in the object.
+    pub offset: usize,
+}
+
+///
+/// The ELF section header fields used to read relocations.
+///
+#[derive(Debug, Clone, Copy)]
+struct Section {
+    /// The section type.
+    r#type: u32,
+    /// The offset of the section data in the object.
+    offset: usize,
+    /// The size of the section data.
+    size: usize,
+    /// The linked section index: the symbol table of a relocation section, or the string table of a symbol table.
+    link: usize,
+    /// The index of the section a relocation section applies to.
+    info: usize,
+    /// The size of the section entries.
+    entry_size: usize,
+}
+
+///
+/// The ELF object reader.
+///
+struct Reader<'a> {
+    /// The object bytes.
+    object: &'a [u8],
+    /// Whether the object is 64-bit.
+    is_64_bit: bool,
+    /// Whether the object is little-endian.
+    is_little_endian: bool,
+}
+
+///
+/// Returns the relocations of the ELF `object`, with offsets of the relocated fields in the object itself.
+///
+pub fn relocations(object: &[u8]) -> anyhow::Result<Vec<Relocation>> {
+    let reader = Reader::try_new(object)?;
+    let sections = reader.sections()?;
+
+    let mut relocations = Vec::new();
+    for section in sections.iter() {
+        let entry_size = match section.r#type {
+            SECTION_TYPE_REL | SECTION_TYPE_RELA => section.entry_size,
+            _ => continue,
+        };
+        if entry_size == 0 {
+            anyhow::bail!("Relocation section with zero entry size");
+        }
+        let target = sections
+            .get(section.info)
+            .ok_or_else(|| anyhow::anyhow!("Relocation target section is missing"))?;
+        let symbol_table = sections
+            .get(section.link)
+            .ok_or_else(|| anyhow::anyhow!("Relocation symbol table is missing"))?;
+        let string_table = sections
+            .get(symbol_table.link)
+            .ok_or_else(|| anyhow::anyhow!("Symbol string table is missing"))?;
+
+        for index in 0..section.size / entry_size {
+            let entry = section.offset + index * entry_size;
+            let (offset, symbol_index) = if reader.is_64_bit {
+                let info = reader.read(entry + 8, 8)?;
+                (reader.read(entry, 8)?, info >> 32)
+            } else {
+                let info = reader.read(entry + 4, 4)?;
+                (reader.read(entry, 4)?, info >> 8)
+            };
+            let symbol = reader.symbol_name(symbol_table, string_table, symbol_index as usize)?;
+            relocations.push(Relocation {
+                symbol,
+                offset: target.offset + offset as usize,
+            });
+        }
+    }
+    Ok(relocations)
+}
+
+impl<'a> Reader<'a> {
+    ///
+    /// Checks the ELF identification and returns the reader.
+    ///
+    fn try_new(object: &'a [u8]) -> anyhow::Result<Self> {
+        if object.get(..4) != Some(b"\x7fELF".as_slice()) {
+            anyhow::bail!("Not an ELF object");
+        }
+        let is_64_bit = match object.get(4) {
+            Some(1) => false,
+            Some(2) => true,
+            _ => anyhow::bail!("Invalid ELF class"),
+        };
+        let is_little_endian = match object.get(5) {
+            Some(1) => true,
+            Some(2) => false,
+            _ => anyhow::bail!("Invalid ELF data encoding"),
+        };
+        Ok(Self {
+            object,
+            is_64_bit,
+            is_little_endian,
+        })
+    }
+
+    ///
+    /// Reads the section headers.
+    ///
+    fn sections(&self) -> anyhow::Result<Vec<Section>> {
+        let (header_offset, header_size, count) = if self.is_64_bit {
+            (
+                self.read(0x28, 8)?,
+                self.read(0x3A, 2)?,
+                self.read(0x3C, 2)?,
+            )
+        } else {
+            (
+                self.read(0x20, 4)?,
+                self.read(0x2E, 2)?,
+                self.read(0x30, 2)?,
+            )
+        };
+
+        (0..count as usize)
+            .map(|index| {
+                let header = header_offset as usize + index * header_size as usize;
+                Ok(if self.is_64_bit {
+                    Section {
+                        r#type: self.read(header + 4, 4)? as u32,
+                        offset: self.read(header + 24, 8)? as usize,
+                        size: self.read(header + 32, 8)? as usize,
+                        link: self.read(header + 40, 4)? as usize,
+                        info: self.read(header + 44, 4)? as usize,
+                        entry_size: self.read(header + 56, 8)? as usize,
+                    }
+                } else {
+                    Section {
+                        r#type: self.read(header + 4, 4)? as u32,
+                        offset: self.read(header + 16, 4)? as usize,
+                        size: self.read(header + 20, 4)? as usize,
+                        link: self.read(header + 24, 4)? as usize,
+                        info: self.read(header + 28, 4)? as usize,
+                        entry_size: self.read(header + 36, 4)? as usize,
+                    }
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Reads the name of the symbol with `index` in the symbol table.
+    ///
+    fn symbol_name(
+        &self,
+        symbol_table: &Section,
+        string_table: &Section,
+        index: usize,
+    ) -> anyhow::Result<String> {
+        let entry_size = if symbol_table.entry_size == 0 {
+            if self.is_64_bit {
+                24
+            } else {
+                16
+            }
+        } else {
+            symbol_table.entry_size
+        };
+        let name_offset = self.read(symbol_table.offset + index * entry_size, 4)? as usize;
+
+        let start = string_table.offset + name_offset;
+        let name = self
+            .object
+            .get(start..string_table.offset + string_table.size)
+            .ok_or_else(|| anyhow::anyhow!("Symbol name is out of bounds"))?;
+        let end = name
+            .iter()
+            .position(|byte| *byte == 0)
+            .unwrap_or(name.len());
+        Ok(String::from_utf8_lossy(&name[..end]).into_owned())
+    }
+
+    ///
+    /// Reads the unsigned integer of `size` bytes at `offset`.
+    ///
+    fn read(&self, offset: usize, size: usize) -> anyhow::Result<u64> {
+        let bytes = self
+            .object
+            .get(offset..offset + size)
+            .ok_or_else(|| anyhow::anyhow!("ELF object is truncated"))?;
+        let mut buffer = [0u8; 8];
+        if self.is_little_endian {
+            buffer[..size].copy_from_slice(bytes);
+            Ok(u64::from_le_bytes(buffer))
+        } else {
+            buffer[8 - size..].copy_from_slice(bytes);
+            Ok(u64::from_be_bytes(buffer))
+        }
+    }
+}
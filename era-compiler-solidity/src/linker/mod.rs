@@ -2,6 +2,7 @@
 //! The linker.
 //!
 
+pub mod elf;
 pub mod input;
 pub mod output;
 
@@ -13,6 +14,15 @@ use self::output::linked::Linked as OutputLinked;
 use self::output::unlinked::Unlinked as OutputUnlinked;
 use self::output::Output;
 
+/// The `solc` library placeholder prefix.
+pub const SOLC_PLACEHOLDER_PREFIX: &str = "__$";
+
+/// The `solc` library placeholder suffix.
+pub const SOLC_PLACEHOLDER_SUFFIX: &str = "$__";
+
+/// The number of `keccak256` hash hexadecimal characters in the `solc` library placeholder.
+pub const SOLC_PLACEHOLDER_HASH_LENGTH: usize = 34;
+
 ///
 /// The linker.
 ///
@@ -23,28 +33,56 @@ impl Linker {
     ///
     /// Links EraVM bytecode files.
     ///
+    /// Besides the `zksolc` linker symbols, the `solc` library placeholders are accepted, both
+    /// as linker symbols in ELF objects and as `__$<keccak256>$__` substrings of hexadecimal strings.
+    ///
     pub fn link_eravm(input: Input) -> anyhow::Result<Output> {
-        let linker_symbols =
+        let mut linker_symbols =
             era_solc::StandardJsonInputLibraries::try_from(input.libraries.as_slice())?
                 .as_linker_symbols()?;
+        let solc_placeholders: BTreeMap<String, String> = linker_symbols
+            .keys()
+            .map(|path| (Self::solc_placeholder(path.as_str()), path.to_owned()))
+            .collect();
+        for (placeholder, path) in solc_placeholders.iter() {
+            let address = linker_symbols
+                .get(path.as_str())
+                .copied()
+                .expect("Always exists");
+            linker_symbols.insert(placeholder.to_owned(), address);
+        }
+
         let mut output = Output::default();
         let mut unlinked_objects = Vec::new();
         let mut factory_dependencies = BTreeMap::new();
 
-        let bytecode_binary = input
-            .bytecodes
-            .iter()
-            .map(|(path, string)| {
-                let string_stripped = string.strip_prefix("0x").unwrap_or(string.as_str());
-                let bytecode = hex::decode(string_stripped).map_err(|error| {
-                    anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}")
-                })?;
-                Ok((path.to_owned(), bytecode))
-            })
-            .collect::<anyhow::Result<BTreeMap<String, Vec<u8>>>>()?;
+        let mut bytecode_binary = BTreeMap::new();
+        for (path, string) in input.bytecodes.iter() {
+            let string_stripped = string.strip_prefix("0x").unwrap_or(string.as_str());
+            let (string_resolved, resolved_libraries, unresolved_placeholders) =
+                Self::resolve_solc_placeholders(
+                    string_stripped,
+                    &solc_placeholders,
+                    &linker_symbols,
+                );
+            if !unresolved_placeholders.is_empty() {
+                output.unlinked.insert(
+                    path.to_owned(),
+                    OutputUnlinked::new(unresolved_placeholders, vec![]),
+                );
+                continue;
+            }
+            let bytecode = hex::decode(string_resolved.as_str()).map_err(|error| {
+                anyhow::anyhow!("Object `{path}` hexadecimal string decoding: {error}")
+            })?;
+            bytecode_binary.insert(path.to_owned(), (bytecode, resolved_libraries));
+        }
 
         for (path, bytecode_string) in input.bytecodes.into_iter() {
-            let bytecode = bytecode_binary.get(path.as_str()).expect("Always exists");
+            let (bytecode, resolved_libraries) = match bytecode_binary.get(path.as_str()) {
+                Some(bytecode) => bytecode,
+                None => continue,
+            };
             let memory_buffer = inkwell::memory_buffer::MemoryBuffer::create_from_memory_range(
                 bytecode.as_slice(),
                 path.as_str(),
@@ -58,10 +96,22 @@ impl Linker {
 
             let hash = era_compiler_llvm_context::eravm_hash(&memory_buffer)
                 .map_err(|error| anyhow::anyhow!("Object `{path}` bytecode hashing: {error}"))?;
-            output.ignored.insert(
-                path.clone(),
-                OutputIgnored::new(bytecode_string, hex::encode(hash)),
-            );
+            if resolved_libraries.is_empty() {
+                output.ignored.insert(
+                    path.clone(),
+                    OutputIgnored::new(bytecode_string, hex::encode(hash)),
+                );
+            } else {
+                output.linked.insert(
+                    path.clone(),
+                    OutputLinked::new(
+                        hex::encode(bytecode.as_slice()),
+                        hex::encode(hash),
+                        resolved_libraries.to_owned(),
+                        vec![],
+                    ),
+                );
+            }
             factory_dependencies.insert(path, hash);
         }
 
@@ -108,15 +158,84 @@ impl Linker {
             }
         }
 
-        output.unlinked = unlinked_objects
-            .into_iter()
-            .map(|(path, bytecode_buffer)| {
+        output
+            .unlinked
+            .extend(unlinked_objects.into_iter().map(|(path, bytecode_buffer)| {
                 let (library_symbols, factory_dependencies) =
                     bytecode_buffer.get_undefined_references_eravm();
                 let unlinked = OutputUnlinked::new(library_symbols, factory_dependencies);
                 (path, unlinked)
-            })
-            .collect();
+            }));
         Ok(output)
     }
+
+    ///
+    /// Returns the `solc` placeholder of the library with the specified full path.
+    ///
+    /// The placeholder consists of the first 34 hexadecimal characters of the `keccak256` hash
+    /// of the library full path, e.g. `__$5a6b8a0f2c4d39e9e0c1bd5b3f5e6a7c2d$__`.
+    ///
+    pub fn solc_placeholder(path: &str) -> String {
+        let hash = hex::encode(era_compiler_common::Hash::keccak256(path.as_bytes()).as_bytes());
+        format!(
+            "{SOLC_PLACEHOLDER_PREFIX}{}{SOLC_PLACEHOLDER_SUFFIX}",
+            &hash[..SOLC_PLACEHOLDER_HASH_LENGTH]
+        )
+    }
+
+    ///
+    /// Replaces the `solc` placeholders in the hexadecimal bytecode string with library addresses.
+    ///
+    /// Returns the resulting string, the full paths of the resolved libraries, and the unresolved placeholders.
+    ///
+    fn resolve_solc_placeholders(
+        bytecode: &str,
+        solc_placeholders: &BTreeMap<String, String>,
+        linker_symbols: &BTreeMap<String, [u8; era_compiler_common::BYTE_LENGTH_ETH_ADDRESS]>,
+    ) -> (String, Vec<String>, Vec<String>) {
+        let mut result = String::with_capacity(bytecode.len());
+        let mut resolved_libraries = Vec::new();
+        let mut unresolved_placeholders = Vec::new();
+
+        let mut remaining = bytecode;
+        while let Some(start) = remaining.find(SOLC_PLACEHOLDER_PREFIX) {
+            result.push_str(&remaining[..start]);
+            remaining = &remaining[start..];
+
+            let length = SOLC_PLACEHOLDER_PREFIX.len()
+                + SOLC_PLACEHOLDER_HASH_LENGTH
+                + SOLC_PLACEHOLDER_SUFFIX.len();
+            let placeholder = match remaining.get(..length) {
+                Some(placeholder) if placeholder.ends_with(SOLC_PLACEHOLDER_SUFFIX) => placeholder,
+                _ => {
+                    result.push_str(SOLC_PLACEHOLDER_PREFIX);
+                    remaining = &remaining[SOLC_PLACEHOLDER_PREFIX.len()..];
+                    continue;
+                }
+            };
+
+            match solc_placeholders.get(placeholder) {
+                Some(path) => {
+                    let address = linker_symbols.get(path.as_str()).expect("Always exists");
+                    result.push_str(hex::encode(address).as_str());
+                    if !resolved_libraries.contains(path) {
+                        resolved_libraries.push(path.to_owned());
+                    }
+                }
+                None => {
+                    result.push_str(placeholder);
+                    if !unresolved_placeholders
+                        .iter()
+                        .any(|unresolved| unresolved == placeholder)
+                    {
+                        unresolved_placeholders.push(placeholder.to_owned());
+                    }
+                }
+            }
+            remaining = &remaining[length..];
+        }
+        result.push_str(remaining);
+
+        (result, resolved_libraries, unresolved_placeholders)
+    }
 }
@@ -0,0 +1,244 @@
+//!
+//! The build lock file.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The conventional lock file name.
+pub const FILE_NAME: &str = "zksolc.lock";
+
+///
+/// The `solc` executable recorded in the lock file.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Solc {
+    /// The executable path, as it has been specified or found in `PATH`.
+    pub path: String,
+    /// The `keccak256` hash of the executable.
+    pub hash: String,
+}
+
+///
+/// The build lock file.
+///
+/// Records the toolchain, settings, and sources of the last successful build, so that
+/// builds can be checked for reproducibility with `--locked`, e.g.:
+///
+/// ```json
+/// {
+///   "zksolc_version": "1.5.7",
+///   "llvm_revision": "a8f5f9a3d09c2e8d4b8e7a0a0b1c6d4e0f9a1b2c",
+///   "solc": {
+///     "path": "/usr/local/bin/solc",
+///     "hash": "0x5f1c..."
+///   },
+///   "settings_hash": "0x2a9e...",
+///   "sources": {
+///     "contracts/Token.sol": "0x9c3b..."
+///   }
+/// }
+/// ```
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Lock {
+    /// The `zksolc` version.
+    pub zksolc_version: String,
+    /// The LLVM revision.
+    pub llvm_revision: String,
+    /// The `solc` executable, if used.
+    pub solc: Option<Solc>,
+    /// The `keccak256` hash of the compiler settings.
+    pub settings_hash: String,
+    /// The `keccak256` hashes of the source files, keyed by path.
+    pub sources: BTreeMap<String, String>,
+}
+
+impl Lock {
+    ///
+    /// Records the current build.
+    ///
+    /// `sources` are the source hashes reported by `solc` in the contract metadata,
+    /// which also cover the imported files. The input files are hashed here, except for the standard input.
+    ///
+    pub fn new(
+        solc_executable: Option<&str>,
+        settings: &serde_json::Value,
+        input_files: &[PathBuf],
+        mut sources: BTreeMap<String, String>,
+    ) -> anyhow::Result<Self> {
+        let solc = match solc_executable {
+            Some(executable) => {
+                let path = Self::resolve_executable(executable)?;
+                let executable = std::fs::read(path.as_path()).map_err(|error| {
+                    anyhow::anyhow!("`solc` executable {path:?} reading: {error}")
+                })?;
+                Some(Solc {
+                    path: path.to_string_lossy().to_string(),
+                    hash: era_compiler_common::Hash::keccak256(executable.as_slice()).to_string(),
+                })
+            }
+            None => None,
+        };
+
+        let settings = serde_json::to_vec(settings).expect("Always valid");
+
+        for path in input_files
+            .iter()
+            .filter(|path| !era_solc::StandardJsonInputSource::is_stdin(path))
+        {
+            let source = std::fs::read(path)
+                .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+            sources.insert(
+                path.to_string_lossy().to_string(),
+                era_compiler_common::Hash::keccak256(source.as_slice()).to_string(),
+            );
+        }
+
+        Ok(Self {
+            zksolc_version: crate::version(),
+            llvm_revision: inkwell::support::get_commit_id().to_string(),
+            solc,
+            settings_hash: era_compiler_common::Hash::keccak256(settings.as_slice()).to_string(),
+            sources,
+        })
+    }
+
+    ///
+    /// Reads the lock file at `path`.
+    ///
+    pub fn try_from_path(path: &Path) -> anyhow::Result<Self> {
+        let lock = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("Lock file {path:?} reading: {error}"))?;
+        let lock = serde_json::from_str(lock.as_str())
+            .map_err(|error| anyhow::anyhow!("Lock file {path:?} parsing: {error}"))?;
+        Ok(lock)
+    }
+
+    ///
+    /// Returns the source hashes reported by `solc` in the contract metadata JSON.
+    ///
+    pub fn get_solc_source_hashes(metadata_json: &serde_json::Value) -> BTreeMap<String, String> {
+        let source_metadata = match metadata_json.get("source_metadata") {
+            Some(serde_json::Value::String(metadata)) => {
+                match serde_json::from_str::<serde_json::Value>(metadata.as_str()) {
+                    Ok(metadata) => metadata,
+                    Err(_) => return BTreeMap::new(),
+                }
+            }
+            Some(metadata) => metadata.to_owned(),
+            None => return BTreeMap::new(),
+        };
+
+        source_metadata
+            .get("sources")
+            .and_then(|sources| sources.as_object())
+            .map(|sources| {
+                sources
+                    .iter()
+                    .filter_map(|(path, source)| {
+                        let hash = source.get("keccak256")?.as_str()?;
+                        Some((path.to_owned(), hash.to_owned()))
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Writes the lock file to `path`, or checks the build against it if `locked` is set.
+    ///
+    pub fn update(&self, path: &Path, locked: bool) -> anyhow::Result<()> {
+        if locked {
+            let locked = Self::try_from_path(path)?;
+            return self.check(&locked, path);
+        }
+        self.write_to_file(path)
+    }
+
+    ///
+    /// Checks the current build against the locked one, listing all differences on failure.
+    ///
+    pub fn check(&self, locked: &Self, path: &Path) -> anyhow::Result<()> {
+        let mut differences = Vec::new();
+        if self.zksolc_version != locked.zksolc_version {
+            differences.push(format!(
+                "zksolc version: `{}` -> `{}`",
+                locked.zksolc_version, self.zksolc_version
+            ));
+        }
+        if self.llvm_revision != locked.llvm_revision {
+            differences.push(format!(
+                "LLVM revision: `{}` -> `{}`",
+                locked.llvm_revision, self.llvm_revision
+            ));
+        }
+        if self.solc != locked.solc {
+            let format = |solc: Option<&Solc>| match solc {
+                Some(solc) => format!("{} ({})", solc.path, solc.hash),
+                None => "none".to_owned(),
+            };
+            differences.push(format!(
+                "solc: `{}` -> `{}`",
+                format(locked.solc.as_ref()),
+                format(self.solc.as_ref())
+            ));
+        }
+        if self.settings_hash != locked.settings_hash {
+            differences.push("settings".to_owned());
+        }
+        for (source, hash) in locked.sources.iter() {
+            match self.sources.get(source) {
+                Some(current) if current == hash => {}
+                Some(_) => differences.push(format!("source `{source}`: modified")),
+                None => differences.push(format!("source `{source}`: removed")),
+            }
+        }
+        for source in self.sources.keys() {
+            if !locked.sources.contains_key(source) {
+                differences.push(format!("source `{source}`: added"));
+            }
+        }
+
+        if differences.is_empty() {
+            return Ok(());
+        }
+        anyhow::bail!(
+            "The build does not match the lock file {path:?}, which cannot be updated as `--locked` was passed:\n{}",
+            differences
+                .into_iter()
+                .map(|difference| format!("    {difference}"))
+                .collect::<Vec<String>>()
+                .join("\n")
+        );
+    }
+
+    ///
+    /// Writes the lock file to `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Resolves the executable path, looking it up in `PATH` if only a name is specified.
+    ///
+    pub(crate) fn resolve_executable(executable: &str) -> anyhow::Result<PathBuf> {
+        let path = PathBuf::from(executable);
+        if path.components().count() > 1 || path.exists() {
+            return Ok(path);
+        }
+
+        std::env::var_os("PATH")
+            .iter()
+            .flat_map(std::env::split_paths)
+            .map(|directory| directory.join(executable))
+            .find(|path| path.is_file())
+            .ok_or_else(|| anyhow::anyhow!("`solc` executable {executable:?} not found in `PATH`."))
+    }
+}
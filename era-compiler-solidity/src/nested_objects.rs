@@ -0,0 +1,149 @@
+//!
+//! The nested Yul dependency objects.
+//!
+
+use std::collections::BTreeMap;
+
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::literal::Literal;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::yul::parser::dialect::era::EraDialect;
+
+/// The suffix of the runtime code object identifiers.
+pub const RUNTIME_SUFFIX: &str = "_deployed";
+
+///
+/// Checks that the `dataoffset` and `datasize` calls only reference objects, and not data segments.
+///
+/// Data segments cannot be addressed on EraVM, as the bytecode only consists of instructions,
+/// and the factory dependencies are referenced by their hashes.
+///
+pub fn check_data_references(object: &Object<EraDialect>, path: &str) -> anyhow::Result<()> {
+    let mut data_segments = object.data_segments.clone();
+    if let Some(inner_object) = object.inner_object.as_ref() {
+        data_segments.extend(inner_object.data_segments.iter().cloned());
+    }
+
+    let locations = crate::dependency_cycle::get_yul_locations(object);
+    for (identifier, location) in locations.iter() {
+        if data_segments.contains(identifier) {
+            anyhow::bail!(
+                "{path}:{location} Data segment `{identifier}` cannot be referenced with `dataoffset`, `datasize`, or `datacopy`, as EraVM bytecode cannot contain data. Only objects can be referenced."
+            );
+        }
+    }
+    Ok(())
+}
+
+///
+/// Renames the nested objects according to `identifiers`, along with their runtime code objects,
+/// factory dependency entries, and `dataoffset` and `datasize` references.
+///
+/// Used to qualify the nested objects with the path of their source, so equally named nested objects
+/// of different sources do not shadow each other, and to redirect the references to the factory
+/// dependencies removed as duplicates of other ones.
+///
+pub fn qualify(object: &mut Object<EraDialect>, identifiers: &BTreeMap<String, String>) {
+    if identifiers.is_empty() {
+        return;
+    }
+
+    if let Some(identifier) = rename(object.identifier.as_str(), identifiers) {
+        object.identifier = identifier;
+    }
+    object.factory_dependencies = std::mem::take(&mut object.factory_dependencies)
+        .into_iter()
+        .map(|identifier| rename(identifier.as_str(), identifiers).unwrap_or(identifier))
+        .collect();
+    qualify_block(&mut object.code.block, identifiers);
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        qualify(inner_object, identifiers);
+    }
+}
+
+///
+/// Returns the renamed identifier, if the identifier or its runtime code counterpart must be renamed.
+///
+fn rename(identifier: &str, identifiers: &BTreeMap<String, String>) -> Option<String> {
+    if let Some(renamed) = identifiers.get(identifier) {
+        return Some(renamed.to_owned());
+    }
+    let identifier = identifier.strip_suffix(RUNTIME_SUFFIX)?;
+    identifiers
+        .get(identifier)
+        .map(|renamed| format!("{renamed}{RUNTIME_SUFFIX}"))
+}
+
+///
+/// Renames the object references in the block.
+///
+fn qualify_block(block: &mut Block<EraDialect>, identifiers: &BTreeMap<String, String>) {
+    for statement in block.statements.iter_mut() {
+        match statement {
+            Statement::Block(block) => qualify_block(block, identifiers),
+            Statement::Expression(expression) => qualify_expression(expression, identifiers),
+            Statement::FunctionDefinition(function) => {
+                qualify_block(&mut function.body, identifiers)
+            }
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(expression) = declaration.expression.as_mut() {
+                    qualify_expression(expression, identifiers);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                qualify_expression(&mut assignment.initializer, identifiers)
+            }
+            Statement::IfConditional(if_conditional) => {
+                qualify_expression(&mut if_conditional.condition, identifiers);
+                qualify_block(&mut if_conditional.block, identifiers);
+            }
+            Statement::Switch(switch) => {
+                qualify_expression(&mut switch.expression, identifiers);
+                for case in switch.cases.iter_mut() {
+                    qualify_block(&mut case.block, identifiers);
+                }
+                if let Some(default) = switch.default.as_mut() {
+                    qualify_block(default, identifiers);
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                qualify_block(&mut for_loop.initializer, identifiers);
+                qualify_expression(&mut for_loop.condition, identifiers);
+                qualify_block(&mut for_loop.finalizer, identifiers);
+                qualify_block(&mut for_loop.body, identifiers);
+            }
+            _ => {}
+        }
+    }
+}
+
+///
+/// Renames the object references in the expression.
+///
+fn qualify_expression(expression: &mut Expression, identifiers: &BTreeMap<String, String>) {
+    let Expression::FunctionCall(call) = expression else {
+        return;
+    };
+
+    if let FunctionName::DataOffset | FunctionName::DataSize = call.name {
+        if let Some(Expression::Literal(Literal {
+            inner: LexicalLiteral::String(identifier),
+            ..
+        })) = call.arguments.first_mut()
+        {
+            if let Some(renamed) = rename(identifier.inner.as_str(), identifiers) {
+                identifier.inner = renamed;
+            }
+        }
+        return;
+    }
+
+    for argument in call.arguments.iter_mut() {
+        qualify_expression(argument, identifiers);
+    }
+}
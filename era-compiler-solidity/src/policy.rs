@@ -0,0 +1,142 @@
+//!
+//! The organization policy enforcement.
+//!
+
+use era_yul::yul::lexer::token::location::Location;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::function_call::name::Name as FunctionName;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::evmla::assembly::Assembly;
+use crate::yul::parser::dialect::era::EraDialect;
+
+///
+/// Validates the policy rules, returning the forbidden instructions along with the rule identifiers.
+///
+/// The instructions must be named as Yul builtins.
+///
+pub fn forbidden_instructions(
+    policy: &era_solc::StandardJsonInputPolicy,
+) -> anyhow::Result<Vec<(FunctionName, String, String)>> {
+    let mut instructions = Vec::new();
+    for rule in policy.rules.iter() {
+        if rule.id.trim().is_empty() {
+            anyhow::bail!("Policy rules must have non-empty identifiers.");
+        }
+    }
+    for (instruction, rule_id) in policy.forbidden_instructions().into_iter() {
+        let name = FunctionName::from(instruction.to_lowercase().as_str());
+        if let FunctionName::UserDefined(_) = name {
+            anyhow::bail!("Policy rule `{rule_id}` forbids unknown instruction `{instruction}`.");
+        }
+        instructions.push((name, instruction.to_lowercase(), rule_id.to_owned()));
+    }
+    Ok(instructions)
+}
+
+///
+/// Returns the locations of the instruction usages in the Yul object and its inner objects.
+///
+pub fn get_yul_usages(object: &Object<EraDialect>, instruction: &FunctionName) -> Vec<Location> {
+    let mut locations = Vec::new();
+    let mut current = Some(object);
+    while let Some(object) = current {
+        get_block_usages(&object.code.block, instruction, &mut locations);
+        current = object.inner_object.as_deref();
+    }
+    locations
+}
+
+///
+/// Returns the number of the instruction usages in the EVM legacy assembly and its data.
+///
+pub fn get_assembly_usages(assembly: &Assembly, instruction: &str) -> usize {
+    let mut count = assembly
+        .code
+        .as_ref()
+        .map(|code| {
+            code.iter()
+                .filter(|element| element.name.to_string().to_lowercase() == instruction)
+                .count()
+        })
+        .unwrap_or_default();
+    if let Some(data) = assembly.data.as_ref() {
+        for data in data.values() {
+            if let Some(assembly) = data.get_assembly() {
+                count += get_assembly_usages(assembly, instruction);
+            }
+        }
+    }
+    count
+}
+
+///
+/// Collects the instruction usages in the block.
+///
+fn get_block_usages(
+    block: &Block<EraDialect>,
+    instruction: &FunctionName,
+    locations: &mut Vec<Location>,
+) {
+    for statement in block.statements.iter() {
+        match statement {
+            Statement::Block(block) => get_block_usages(block, instruction, locations),
+            Statement::Expression(expression) => {
+                get_expression_usages(expression, instruction, locations)
+            }
+            Statement::FunctionDefinition(function) => {
+                get_block_usages(&function.body, instruction, locations)
+            }
+            Statement::VariableDeclaration(declaration) => {
+                if let Some(expression) = declaration.expression.as_ref() {
+                    get_expression_usages(expression, instruction, locations);
+                }
+            }
+            Statement::Assignment(assignment) => {
+                get_expression_usages(&assignment.initializer, instruction, locations)
+            }
+            Statement::IfConditional(if_conditional) => {
+                get_expression_usages(&if_conditional.condition, instruction, locations);
+                get_block_usages(&if_conditional.block, instruction, locations);
+            }
+            Statement::Switch(switch) => {
+                get_expression_usages(&switch.expression, instruction, locations);
+                for case in switch.cases.iter() {
+                    get_block_usages(&case.block, instruction, locations);
+                }
+                if let Some(default) = switch.default.as_ref() {
+                    get_block_usages(default, instruction, locations);
+                }
+            }
+            Statement::ForLoop(for_loop) => {
+                get_block_usages(&for_loop.initializer, instruction, locations);
+                get_expression_usages(&for_loop.condition, instruction, locations);
+                get_block_usages(&for_loop.finalizer, instruction, locations);
+                get_block_usages(&for_loop.body, instruction, locations);
+            }
+            _ => {}
+        }
+    }
+}
+
+///
+/// Collects the instruction usages in the expression.
+///
+fn get_expression_usages(
+    expression: &Expression,
+    instruction: &FunctionName,
+    locations: &mut Vec<Location>,
+) {
+    let Expression::FunctionCall(call) = expression else {
+        return;
+    };
+
+    if &call.name == instruction {
+        locations.push(call.location);
+    }
+    for argument in call.arguments.iter() {
+        get_expression_usages(argument, instruction, locations);
+    }
+}
@@ -7,6 +7,8 @@
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use crate::process::output_eravm::Output;
+use crate::process::CompilationUnit;
 use crate::project::contract::Contract;
 
 ///
@@ -70,3 +72,29 @@ impl Input {
         }
     }
 }
+
+impl CompilationUnit for Input {
+    type Output = Output;
+
+    fn compile(self) -> crate::Result<Self::Output> {
+        let source_location =
+            era_solc::StandardJsonOutputErrorSourceLocation::new(self.contract.name.path.clone());
+        self.contract
+            .compile_to_eravm(
+                self.solc_version,
+                self.identifier_paths,
+                self.missing_libraries,
+                self.factory_dependencies,
+                self.enable_eravm_extensions,
+                self.metadata_hash_type,
+                self.optimizer_settings,
+                self.llvm_options,
+                self.output_assembly,
+                self.debug_config,
+            )
+            .map(Output::new)
+            .map_err(|error| {
+                era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
+            })
+    }
+}
@@ -33,6 +33,39 @@ impl DependencyData {
             dependencies: BTreeMap::new(),
         }
     }
+
+    ///
+    /// Removes the dependencies whose deploy and runtime bytecode duplicates another dependency.
+    ///
+    /// The dependencies are embedded into the object, so each unique bytecode must only be embedded once.
+    /// Returns the identifiers of the removed dependencies mapped to the identifiers of the retained ones,
+    /// so the references to the former can be redirected, and the size of the removed bytecode in bytes.
+    ///
+    pub fn deduplicate(&mut self) -> (BTreeMap<String, String>, usize) {
+        let mut identifiers = BTreeMap::new();
+        let mut duplicates = Vec::new();
+        let mut unique: Vec<&EVMContractBuild> = Vec::with_capacity(self.dependencies.len());
+        for (key, dependency) in self.dependencies.iter() {
+            match unique.iter().find(|retained| {
+                retained.deploy_build == dependency.deploy_build
+                    && retained.runtime_build == dependency.runtime_build
+            }) {
+                Some(retained) => {
+                    identifiers.insert(dependency.identifier.clone(), retained.identifier.clone());
+                    duplicates.push(key.to_owned());
+                }
+                None => unique.push(dependency),
+            }
+        }
+
+        let mut deduplicated_size = 0;
+        for key in duplicates.into_iter() {
+            if let Some(dependency) = self.dependencies.remove(key.as_str()) {
+                deduplicated_size += dependency.deploy_build.len() + dependency.runtime_build.len();
+            }
+        }
+        (identifiers, deduplicated_size)
+    }
 }
 
 impl era_compiler_llvm_context::Dependency for DependencyData {
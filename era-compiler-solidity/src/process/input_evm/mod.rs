@@ -6,6 +6,8 @@
 
 pub mod dependency_data;
 
+use crate::process::output_evm::Output;
+use crate::process::CompilationUnit;
 use crate::project::contract::Contract;
 
 use self::dependency_data::DependencyData;
@@ -51,3 +53,25 @@ impl Input {
         }
     }
 }
+
+impl CompilationUnit for Input {
+    type Output = Output;
+
+    fn compile(self) -> crate::Result<Self::Output> {
+        let contract = self.contract.expect("Always exists");
+        let source_location =
+            era_solc::StandardJsonOutputErrorSourceLocation::new(contract.name.path.clone());
+        contract
+            .compile_to_evm(
+                self.dependency_data,
+                self.metadata_hash_type,
+                self.optimizer_settings,
+                self.llvm_options,
+                self.debug_config,
+            )
+            .map(Output::new)
+            .map_err(|error| {
+                era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
+            })
+    }
+}
@@ -7,76 +7,50 @@ pub mod input_evm;
 pub mod output_eravm;
 pub mod output_evm;
 
+use std::collections::BTreeMap;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process::Command;
 use std::sync::OnceLock;
 
+use crate::build_config::BuildConfig;
+
 use self::input_eravm::Input as EraVMInput;
 use self::input_evm::Input as EVMInput;
-use self::output_eravm::Output as EraVMOutput;
-use self::output_evm::Output as EVMOutput;
 
 /// The overridden executable name used when the compiler is run as a library.
 pub static EXECUTABLE: OnceLock<PathBuf> = OnceLock::new();
 
+///
+/// The compilation unit, which can be compiled either in the current process or in a subprocess.
+///
+pub trait CompilationUnit: serde::Serialize {
+    /// The compilation unit output.
+    type Output: serde::Serialize + serde::de::DeserializeOwned;
+
+    ///
+    /// Compiles the unit in the current process.
+    ///
+    fn compile(self) -> crate::Result<Self::Output>;
+}
+
 ///
 /// Read input from `stdin`, compile a contract, and write the output to `stdout`.
 ///
 pub fn run(target: era_compiler_common::Target) -> anyhow::Result<()> {
+    let input_json = std::io::read_to_string(std::io::stdin())
+        .map_err(|error| anyhow::anyhow!("Stdin reading error: {error}"))?;
     match target {
         era_compiler_common::Target::EraVM => {
-            let input_json = std::io::read_to_string(std::io::stdin())
-                .map_err(|error| anyhow::anyhow!("Stdin reading error: {error}"))?;
             let input: EraVMInput = era_compiler_common::deserialize_from_str(input_json.as_str())
                 .map_err(|error| anyhow::anyhow!("Stdin parsing error: {error}"))?;
-
-            let source_location = era_solc::StandardJsonOutputErrorSourceLocation::new(
-                input.contract.name.path.clone(),
-            );
-            let result = input
-                .contract
-                .compile_to_eravm(
-                    input.solc_version,
-                    input.identifier_paths,
-                    input.missing_libraries,
-                    input.factory_dependencies,
-                    input.enable_eravm_extensions,
-                    input.metadata_hash_type,
-                    input.optimizer_settings,
-                    input.llvm_options,
-                    input.output_assembly,
-                    input.debug_config,
-                )
-                .map(EraVMOutput::new)
-                .map_err(|error| {
-                    era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
-                });
-            serde_json::to_writer(std::io::stdout(), &result)
+            serde_json::to_writer(std::io::stdout(), &input.compile())
                 .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
         }
         era_compiler_common::Target::EVM => {
-            let input_json = std::io::read_to_string(std::io::stdin())
-                .map_err(|error| anyhow::anyhow!("Stdin reading error: {error}"))?;
             let input: EVMInput = era_compiler_common::deserialize_from_str(input_json.as_str())
                 .map_err(|error| anyhow::anyhow!("Stdin parsing error: {error}"))?;
-
-            let contract = input.contract.expect("Always exists");
-            let source_location =
-                era_solc::StandardJsonOutputErrorSourceLocation::new(contract.name.path.clone());
-            let result = contract
-                .compile_to_evm(
-                    input.dependency_data,
-                    input.metadata_hash_type,
-                    input.optimizer_settings,
-                    input.llvm_options,
-                    input.debug_config,
-                )
-                .map(EVMOutput::new)
-                .map_err(|error| {
-                    era_solc::StandardJsonOutputError::new_error(error, Some(source_location), None)
-                });
-            serde_json::to_writer(std::io::stdout(), &result)
+            serde_json::to_writer(std::io::stdout(), &input.compile())
                 .map_err(|error| anyhow::anyhow!("Stdout writing error: {error}"))?;
         }
     }
@@ -85,13 +59,67 @@ pub fn run(target: era_compiler_common::Target) -> anyhow::Result<()> {
 }
 
 ///
-/// Runs this process recursively to compile a single contract.
+/// Returns the warning listing the completed contracts, if some of `results` have been skipped
+/// due to the build timeout.
 ///
-pub fn call<I, O>(path: &str, input: I, target: era_compiler_common::Target) -> crate::Result<O>
+pub fn build_timeout_warning<C>(
+    results: &BTreeMap<String, Result<C, era_solc::StandardJsonOutputError>>,
+) -> Option<era_solc::StandardJsonOutputError> {
+    if !results.values().any(|result| {
+        result
+            .as_ref()
+            .is_err_and(|error| error.exit_code == Some(era_solc::ExitCode::BuildTimeout))
+    }) {
+        return None;
+    }
+
+    let completed = results
+        .iter()
+        .filter(|(_path, result)| result.is_ok())
+        .map(|(path, _result)| format!("`{path}`"))
+        .collect::<Vec<String>>();
+    Some(era_solc::StandardJsonOutputError::new_warning(
+        format!(
+            "The build timeout has been exceeded. Completed contracts: {}.",
+            if completed.is_empty() {
+                "none".to_owned()
+            } else {
+                completed.join(", ")
+            }
+        ),
+        None,
+        None,
+    ))
+}
+
+///
+/// Compiles a single contract, running this process recursively if `config` requires subprocesses.
+///
+/// If the build deadline has passed, the contract is skipped and reported as an error.
+///
+pub fn call<I>(
+    path: &str,
+    input: I,
+    target: era_compiler_common::Target,
+    config: &BuildConfig,
+) -> crate::Result<I::Output>
 where
-    I: serde::Serialize,
-    O: serde::de::DeserializeOwned,
+    I: CompilationUnit,
 {
+    if config.is_deadline_exceeded() {
+        return Err(era_solc::StandardJsonOutputError::new_error(
+            format!("Contract `{path}` has been skipped, as the build timeout has been exceeded."),
+            Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                path.to_owned(),
+            )),
+            None,
+        )
+        .with_exit_code(era_solc::ExitCode::BuildTimeout));
+    }
+    if !config.subprocesses {
+        return input.compile();
+    }
+
     let executable = EXECUTABLE
         .get()
         .cloned()
@@ -101,6 +129,11 @@ where
     command.stdin(std::process::Stdio::piped());
     command.stdout(std::process::Stdio::piped());
     command.stderr(std::process::Stdio::piped());
+    if let Some(ref temp_dir) = config.temp_dir {
+        for variable in crate::temp_dir::ENVIRONMENT_VARIABLES.into_iter() {
+            command.env(variable, temp_dir.as_os_str());
+        }
+    }
     command.arg("--recursive-process");
     command.arg("--target");
     command.arg(target.to_string());
@@ -129,13 +162,19 @@ where
             String::from_utf8_lossy(result.stdout.as_slice()),
             String::from_utf8_lossy(result.stderr.as_slice()),
         );
+        let exit_code = match result.status.code() {
+            Some(era_solc::ExitCode::PANIC_CODE) => era_solc::ExitCode::InternalError,
+            Some(_) => era_solc::ExitCode::TranslationError,
+            None => exit_code_by_signal(&result.status),
+        };
         return Err(era_solc::StandardJsonOutputError::new_error(
             message,
             Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
                 path.to_owned(),
             )),
             None,
-        ));
+        )
+        .with_exit_code(exit_code));
     }
 
     match era_compiler_common::deserialize_from_slice(result.stdout.as_slice()) {
@@ -149,3 +188,30 @@ where
         }
     }
 }
+
+///
+/// Classifies the failure of a subprocess terminated by a signal.
+///
+/// `SIGKILL` is sent by the out-of-memory killer, and `SIGXCPU` and `SIGXFSZ` are sent when
+/// the CPU time or file size limits are exceeded. Other signals, e.g. `SIGSEGV` or `SIGABRT`,
+/// mean the subprocess has crashed.
+///
+#[cfg(unix)]
+fn exit_code_by_signal(status: &std::process::ExitStatus) -> era_solc::ExitCode {
+    use std::os::unix::process::ExitStatusExt;
+
+    match status.signal() {
+        Some(libc::SIGKILL | libc::SIGXCPU | libc::SIGXFSZ) => era_solc::ExitCode::ResourceLimit,
+        _ => era_solc::ExitCode::InternalError,
+    }
+}
+
+///
+/// Classifies the failure of a subprocess terminated without an exit code.
+///
+/// Only Unix subprocesses can be terminated by a signal, so it is considered a crash.
+///
+#[cfg(not(unix))]
+fn exit_code_by_signal(_status: &std::process::ExitStatus) -> era_solc::ExitCode {
+    era_solc::ExitCode::InternalError
+}
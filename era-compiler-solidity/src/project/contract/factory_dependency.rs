@@ -2,7 +2,7 @@
 //! The factory dependency trait.
 //!
 
-use std::collections::HashSet;
+use std::collections::BTreeSet;
 
 ///
 /// The factory dependency trait.
@@ -11,12 +11,17 @@ pub trait FactoryDependency {
     ///
     /// Returns path references.
     ///
-    fn get_factory_dependencies(&self) -> HashSet<&str>;
+    fn get_factory_dependencies(&self) -> BTreeSet<&str>;
+
+    ///
+    /// Adds a factory dependency by its identifier.
+    ///
+    fn add_factory_dependency(&mut self, identifier: String);
 
     ///
     /// Drains factory dependencies.
     ///
-    fn drain_factory_dependencies(&mut self) -> HashSet<String>;
+    fn drain_factory_dependencies(&mut self) -> BTreeSet<String>;
 
     ///
     /// Whether the dependencies are satisfied.
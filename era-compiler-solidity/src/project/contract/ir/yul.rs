@@ -2,9 +2,12 @@
 //! The contract Yul source code.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
+use era_yul::yul::error::Error as YulError;
 use era_yul::yul::lexer::Lexer;
+use era_yul::yul::parser::error::Error as YulParserError;
 use era_yul::yul::parser::statement::object::Object;
 
 use crate::yul::parser::dialect::era::EraDialect;
@@ -23,9 +26,12 @@ impl Yul {
     ///
     /// Transforms the `solc` standard JSON output contract into a Yul object.
     ///
+    /// The code nested deeper than `max_nesting_depth` is rejected with a resource limit error.
+    ///
     pub fn try_from_source(
         path: &str,
         source_code: &str,
+        max_nesting_depth: usize,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Option<Self>> {
         if source_code.is_empty() {
@@ -36,9 +42,16 @@ impl Yul {
             debug_config.dump_yul(path, None, source_code)?;
         }
 
-        let mut lexer = Lexer::new(source_code.to_owned());
-        let object = Object::parse(&mut lexer, None)
-            .map_err(|error| anyhow::anyhow!("Yul parsing: {error:?}"))?;
+        let mut lexer = Lexer::new(source_code.to_owned()).with_max_depth(max_nesting_depth);
+        let object = Object::parse(&mut lexer, None).map_err(|error| match error {
+            YulError::Parser(ref error @ YulParserError::TooDeeplyNested { .. }) => {
+                anyhow::Error::from(era_solc::ExitCodeError::new(
+                    era_solc::ExitCode::ResourceLimit,
+                    format!("Yul parsing: {error}"),
+                ))
+            }
+            error => anyhow::anyhow!("Yul parsing: {error:?}"),
+        })?;
 
         Ok(Some(Self {
             object: object.wrap(),
@@ -52,12 +65,40 @@ impl Yul {
         self.object.0.inner_object.take().map(|object| *object)
     }
 
+    ///
+    /// Takes the nested factory dependency objects, which are compiled as separate contracts.
+    ///
+    pub fn take_dependency_objects(&mut self) -> Vec<Self> {
+        self.object
+            .0
+            .take_dependency_objects()
+            .into_iter()
+            .map(|object| Self {
+                object: object.wrap(),
+            })
+            .collect()
+    }
+
     ///
     /// Get the list of missing deployable libraries.
     ///
     pub fn get_missing_libraries(&self) -> BTreeSet<String> {
         self.object.0.get_missing_libraries()
     }
+
+    ///
+    /// Lays out the Yul functions and basic blocks according to their call counts.
+    ///
+    pub fn apply_execution_profile(&mut self, call_counts: &BTreeMap<String, u64>) {
+        crate::execution_profile::apply_to_object(&mut self.object.0, call_counts);
+    }
+
+    ///
+    /// Removes the dispatcher cases of selectors missing from `selectors`.
+    ///
+    pub fn apply_reachable_selectors(&mut self, selectors: &BTreeSet<String>) {
+        crate::reachable_selectors::apply_to_object(&mut self.object.0, selectors);
+    }
 }
 
 impl<D> era_compiler_llvm_context::EraVMWriteLLVM<D> for Yul
@@ -2,6 +2,8 @@
 //! The contract metadata.
 //!
 
+use std::collections::BTreeMap;
+
 ///
 /// The contract metadata.
 ///
@@ -22,6 +24,15 @@ pub struct Metadata<'a> {
     pub optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
     /// The LLVM extra arguments.
     pub llvm_options: &'a [String],
+    /// The immutable values propagated at compile time.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub immutables: &'a BTreeMap<String, String>,
+    /// The active compilation profile.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<&'a str>,
+    /// The `BALANCE` and `SELFBALANCE` lowering strategy.
+    #[serde(skip_serializing_if = "era_solc::StandardJsonInputBalanceLowering::is_default")]
+    pub balance_lowering: era_solc::StandardJsonInputBalanceLowering,
 }
 
 impl<'a> Metadata<'a> {
@@ -34,6 +45,9 @@ impl<'a> Metadata<'a> {
         solc_zkvm_edition: Option<semver::Version>,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: &'a [String],
+        immutables: &'a BTreeMap<String, String>,
+        profile: Option<&'a str>,
+        balance_lowering: era_solc::StandardJsonInputBalanceLowering,
     ) -> Self {
         let source_metadata = match source_metadata {
             serde_json::Value::String(inner) => {
@@ -49,6 +63,9 @@ impl<'a> Metadata<'a> {
             zk_version: crate::version().parse().expect("Always valid"),
             optimizer_settings,
             llvm_options,
+            immutables,
+            profile,
+            balance_lowering,
         }
     }
 }
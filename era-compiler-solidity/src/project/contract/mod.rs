@@ -8,12 +8,12 @@ pub mod metadata;
 
 use std::collections::BTreeMap;
 use std::collections::BTreeSet;
-use std::collections::HashSet;
 
 use era_compiler_llvm_context::IContext;
 
 use crate::build_eravm::contract::Contract as EraVMContractBuild;
 use crate::build_evm::contract::Contract as EVMContractBuild;
+use crate::call_graph::ContractCallGraph;
 use crate::process::input_evm::dependency_data::DependencyData as EVMProcessInputDependencyData;
 use crate::yul::parser::wrapper::Wrap;
 
@@ -32,6 +32,18 @@ pub struct Contract {
     pub ir: IR,
     /// The metadata JSON.
     pub source_metadata: serde_json::Value,
+    /// The immutable values propagated at compile time.
+    pub immutables: BTreeMap<String, String>,
+    /// The extra LLVM options applied in addition to the global ones.
+    pub llvm_options: Vec<String>,
+    /// The active compilation profile recorded in the metadata.
+    pub profile: Option<String>,
+    /// The `BALANCE` and `SELFBALANCE` lowering strategy.
+    #[serde(default)]
+    pub balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+    /// Whether to tag the LLVM IR with its provenance and check its consistency.
+    #[serde(default)]
+    pub debug_assertions: bool,
 }
 
 impl Contract {
@@ -47,6 +59,11 @@ impl Contract {
             name,
             ir,
             source_metadata,
+            immutables: BTreeMap::new(),
+            llvm_options: Vec::new(),
+            profile: None,
+            balance_lowering: era_solc::StandardJsonInputBalanceLowering::default(),
+            debug_assertions: false,
         }
     }
 
@@ -87,6 +104,14 @@ impl Contract {
         let llvm = inkwell::context::Context::create();
         let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings);
 
+        let metadata_llvm_options: Vec<String> = llvm_options
+            .iter()
+            .filter(|option| !crate::remarks::is_remarks_option(option.as_str()))
+            .cloned()
+            .collect();
+        let llvm_options =
+            crate::remarks::bind_to_contract(llvm_options, self.name.full_path.as_str());
+
         let metadata = Metadata::new(
             self.source_metadata,
             solc_version
@@ -96,7 +121,10 @@ impl Contract {
                 .as_ref()
                 .map(|version| version.l2_revision.to_owned()),
             optimizer.settings().to_owned(),
-            llvm_options.as_slice(),
+            metadata_llvm_options.as_slice(),
+            &self.immutables,
+            self.profile.as_deref(),
+            self.balance_lowering,
         );
         let metadata_json = serde_json::to_value(&metadata).expect("Always valid");
         let metadata_bytes = serde_json::to_vec(&metadata_json).expect("Always valid");
@@ -110,6 +138,11 @@ impl Contract {
             }
         };
 
+        let debug_output_directory = debug_config
+            .as_ref()
+            .map(|debug_config| debug_config.output_directory.to_owned());
+
+        let is_llvm_ir_dumped = !matches!(self.ir, IR::EraVMAssembly(_));
         let build = match self.ir {
             IR::Yul(mut yul) => {
                 let module = llvm.create_module(self.name.full_path.as_str());
@@ -133,8 +166,14 @@ impl Contract {
                 context.set_yul_data(yul_data);
 
                 yul.declare(&mut context)?;
+                crate::balance_lowering::declare(&mut context, self.balance_lowering)?;
+                if self.debug_assertions {
+                    crate::debug_assertions::enable(&context)?;
+                }
                 yul.into_llvm(&mut context)
+                    .and_then(|()| crate::balance_lowering::finalize(&mut context))
                     .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                crate::debug_assertions::check(&context, "the Yul translation")?;
 
                 context.build(
                     self.name.full_path.as_str(),
@@ -167,9 +206,15 @@ impl Contract {
                 context.set_evmla_data(evmla_data);
 
                 evmla.declare(&mut context)?;
+                crate::balance_lowering::declare(&mut context, self.balance_lowering)?;
+                if self.debug_assertions {
+                    crate::debug_assertions::enable(&context)?;
+                }
                 evmla
                     .into_llvm(&mut context)
+                    .and_then(|()| crate::balance_lowering::finalize(&mut context))
                     .map_err(|error| anyhow::anyhow!("LLVM IR generator: {error}"))?;
+                crate::debug_assertions::check(&context, "the EVM legacy assembly translation")?;
 
                 context.build(
                     self.name.full_path.as_str(),
@@ -232,6 +277,14 @@ impl Contract {
             }
         };
 
+        if let Some(debug_output_directory) = debug_output_directory.filter(|_| is_llvm_ir_dumped) {
+            crate::ir_diff::write_to_directory(
+                debug_output_directory.as_path(),
+                self.name.full_path.as_str(),
+                era_compiler_common::CodeSegment::Runtime,
+            )?;
+        }
+
         Ok(EraVMContractBuild::new(
             self.name,
             build,
@@ -247,7 +300,7 @@ impl Contract {
     ///
     pub fn compile_to_evm(
         self,
-        dependency_data: EVMProcessInputDependencyData,
+        mut dependency_data: EVMProcessInputDependencyData,
         metadata_hash_type: era_compiler_common::HashType,
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
@@ -261,6 +314,14 @@ impl Contract {
 
         let optimizer = era_compiler_llvm_context::Optimizer::new(optimizer_settings);
 
+        let metadata_llvm_options: Vec<String> = llvm_options
+            .iter()
+            .filter(|option| !crate::remarks::is_remarks_option(option.as_str()))
+            .cloned()
+            .collect();
+        let llvm_options =
+            crate::remarks::bind_to_contract(llvm_options, self.name.full_path.as_str());
+
         let metadata = Metadata::new(
             self.source_metadata,
             solc_version
@@ -270,7 +331,10 @@ impl Contract {
                 .as_ref()
                 .map(|version| version.l2_revision.to_owned()),
             optimizer.settings().to_owned(),
-            llvm_options.as_slice(),
+            metadata_llvm_options.as_slice(),
+            &self.immutables,
+            self.profile.as_deref(),
+            era_solc::StandardJsonInputBalanceLowering::default(),
         );
         let metadata_json = serde_json::to_value(&metadata).expect("Always valid");
         let metadata_bytes = serde_json::to_vec(&metadata_json).expect("Always valid");
@@ -284,8 +348,23 @@ impl Contract {
             }
         };
 
-        match self.ir {
+        let full_path = self.name.full_path.clone();
+        let debug_output_directory = debug_config
+            .as_ref()
+            .map(|debug_config| debug_config.output_directory.to_owned());
+
+        let code_segments = match self.ir {
+            IR::LLVMIR(_) => vec![era_compiler_common::CodeSegment::Runtime],
+            _ => vec![
+                era_compiler_common::CodeSegment::Deploy,
+                era_compiler_common::CodeSegment::Runtime,
+            ],
+        };
+        let build: anyhow::Result<EVMContractBuild> = match self.ir {
             IR::Yul(mut deploy_code) => {
+                let (duplicate_identifiers, deduplicated_size) = dependency_data.deduplicate();
+                crate::nested_objects::qualify(&mut deploy_code.object.0, &duplicate_identifiers);
+
                 let mut runtime_code = deploy_code
                     .take_runtime_code()
                     .ok_or_else(|| anyhow::anyhow!("Contract `{identifier}` has no runtime code"))?
@@ -348,14 +427,21 @@ impl Contract {
                     )
                     .map_err(|error| anyhow::anyhow!("linking: {error}"))?;
 
-                Ok(EVMContractBuild::new(
+                let mut build = EVMContractBuild::new(
                     self.name,
                     identifier,
                     deploy_buffer_linked.as_slice().to_owned(),
                     runtime_buffer_linked.as_slice().to_owned(),
                     metadata_hash,
                     metadata_json,
-                ))
+                );
+                build.deployment_payload =
+                    Some(era_solc::StandardJsonOutputContractDeploymentPayload::new(
+                        dependency_data.dependencies.len(),
+                        build.deploy_build.len() + build.runtime_build.len(),
+                        deduplicated_size,
+                    ));
+                Ok(build)
             }
             IR::EVMLA(mut deploy_code) => {
                 let mut runtime_code_assembly = deploy_code.assembly.get_runtime_code()?.to_owned();
@@ -464,7 +550,20 @@ impl Contract {
                 ))
             }
             IR::EraVMAssembly(_) => anyhow::bail!("EraVM assembly cannot be compiled to EVM."),
+        };
+        let build = build?;
+
+        if let Some(debug_output_directory) = debug_output_directory {
+            for code_segment in code_segments.into_iter() {
+                crate::ir_diff::write_to_directory(
+                    debug_output_directory.as_path(),
+                    full_path.as_str(),
+                    code_segment,
+                )?;
+            }
         }
+
+        Ok(build)
     }
 
     ///
@@ -473,10 +572,68 @@ impl Contract {
     pub fn get_missing_libraries(&self) -> BTreeSet<String> {
         self.ir.get_missing_libraries()
     }
+
+    ///
+    /// Lays out the contract functions and basic blocks according to their call counts.
+    ///
+    /// Only Yul contracts are affected, as other IRs either have no function definitions
+    /// or are laid out by their producers.
+    ///
+    pub fn apply_execution_profile(&mut self, call_counts: &BTreeMap<String, u64>) {
+        if let IR::Yul(ref mut yul) = self.ir {
+            yul.apply_execution_profile(call_counts);
+        }
+    }
+
+    ///
+    /// Returns the call graph of the contract.
+    ///
+    /// Only Yul and EVM assembly contracts are supported, as other IRs are not produced from Solidity.
+    ///
+    pub fn call_graph(&self) -> Option<ContractCallGraph> {
+        match self.ir {
+            IR::Yul(ref yul) => Some(ContractCallGraph::from_object(&yul.object.0)),
+            IR::EVMLA(ref evmla) => Some(ContractCallGraph::from_evmla(
+                self.name.full_path.as_str(),
+                &evmla.assembly,
+            )),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Eliminates the functions unreachable from the specified external selectors.
+    ///
+    /// Only Yul contracts are affected, as the EVM assembly dispatcher cannot be reliably
+    /// distinguished from other jump tables.
+    ///
+    pub fn apply_reachable_selectors(&mut self, selectors: &BTreeSet<String>) {
+        if let IR::Yul(ref mut yul) = self.ir {
+            yul.apply_reachable_selectors(selectors);
+        }
+    }
+
+    ///
+    /// Replaces the immutable loads with the known values, so LLVM can constant-fold them.
+    ///
+    /// The values are recorded in the metadata, so the specialized bytecode gets a different hash.
+    ///
+    pub fn apply_immutables(&mut self, immutables: BTreeMap<String, String>) {
+        match self.ir {
+            IR::Yul(ref mut yul) => {
+                crate::immutables::apply_to_object(&mut yul.object.0, &immutables)
+            }
+            IR::EVMLA(ref mut evmla) => {
+                crate::immutables::apply_to_assembly(&mut evmla.assembly, &immutables)
+            }
+            IR::LLVMIR(_) | IR::EraVMAssembly(_) => return,
+        }
+        self.immutables = immutables;
+    }
 }
 
 impl FactoryDependency for Contract {
-    fn get_factory_dependencies(&self) -> HashSet<&str> {
+    fn get_factory_dependencies(&self) -> BTreeSet<&str> {
         match self.ir {
             IR::Yul(ref yul) => yul
                 .object
@@ -491,17 +648,29 @@ impl FactoryDependency for Contract {
                 .iter()
                 .map(|path| path.as_str())
                 .collect(),
-            IR::LLVMIR(_) => HashSet::new(),
-            IR::EraVMAssembly(_) => HashSet::new(),
+            IR::LLVMIR(_) => BTreeSet::new(),
+            IR::EraVMAssembly(_) => BTreeSet::new(),
+        }
+    }
+
+    fn add_factory_dependency(&mut self, identifier: String) {
+        match self.ir {
+            IR::Yul(ref mut yul) => {
+                yul.object.0.factory_dependencies.insert(identifier);
+            }
+            IR::EVMLA(ref mut evm) => {
+                evm.assembly.factory_dependencies.insert(identifier);
+            }
+            IR::LLVMIR(_) | IR::EraVMAssembly(_) => {}
         }
     }
 
-    fn drain_factory_dependencies(&mut self) -> HashSet<String> {
+    fn drain_factory_dependencies(&mut self) -> BTreeSet<String> {
         match self.ir {
-            IR::Yul(ref mut yul) => yul.object.0.factory_dependencies.drain().collect(),
-            IR::EVMLA(ref mut evm) => evm.assembly.factory_dependencies.drain().collect(),
-            IR::LLVMIR(_) => HashSet::new(),
-            IR::EraVMAssembly(_) => HashSet::new(),
+            IR::Yul(ref mut yul) => std::mem::take(&mut yul.object.0.factory_dependencies),
+            IR::EVMLA(ref mut evm) => std::mem::take(&mut evm.assembly.factory_dependencies),
+            IR::LLVMIR(_) => BTreeSet::new(),
+            IR::EraVMAssembly(_) => BTreeSet::new(),
         }
     }
 
@@ -12,15 +12,21 @@ use std::path::PathBuf;
 use rayon::iter::IntoParallelIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::build_cache::BuildCache;
+use crate::build_config::BuildConfig;
 use crate::build_eravm::contract::Contract as EraVMContractBuild;
 use crate::build_eravm::Build as EraVMBuild;
 use crate::build_evm::Build as EVMBuild;
+use crate::call_graph::CallGraph;
+use crate::contract_filter::ContractFilter;
+use crate::dependency_graph::Contract as DependencyGraphContract;
+use crate::dependency_graph::DependencyGraph;
 use crate::evmla::assembly::Assembly;
+use crate::execution_profile::ExecutionProfile;
 use crate::missing_libraries::MissingLibraries;
 use crate::process::input_eravm::Input as EraVMProcessInput;
 use crate::process::input_evm::dependency_data::DependencyData as EVMProcessInputDependencyData;
 use crate::process::input_evm::Input as EVMProcessInput;
-use crate::process::output_eravm::Output as EraVMOutput;
 
 use self::contract::factory_dependency::FactoryDependency;
 use self::contract::ir::eravm_assembly::EraVMAssembly as ContractEraVMAssembly;
@@ -34,7 +40,7 @@ use self::thread_pool_evm::ThreadPool as EVMThreadPool;
 ///
 /// The project representation.
 ///
-#[derive(Debug, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct Project {
     /// The project language.
     pub language: era_solc::StandardJsonInputLanguage,
@@ -80,10 +86,15 @@ impl Project {
         codegen: era_solc::StandardJsonInputCodegen,
         solc_output: &mut era_solc::StandardJsonOutput,
         solc_compiler: &era_solc::Compiler,
+        config: &BuildConfig,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
         if let era_solc::StandardJsonInputCodegen::EVMLA = codegen {
-            Assembly::preprocess_dependencies(&mut solc_output.contracts)?;
+            Assembly::preprocess_dependencies(
+                &mut solc_output.contracts,
+                config.nesting_depth_limit(),
+                config.shuffle_seed,
+            )?;
         }
 
         let solc_version = solc_compiler.version.to_owned();
@@ -98,6 +109,7 @@ impl Project {
                 input_contracts.push((name, contract));
             }
         }
+        crate::shuffle::shuffle(config.shuffle_seed, input_contracts.as_mut_slice());
 
         let results = input_contracts
             .into_par_iter()
@@ -106,9 +118,16 @@ impl Project {
                     era_solc::StandardJsonInputCodegen::Yul => ContractYul::try_from_source(
                         name.full_path.as_str(),
                         contract.ir_optimized.as_str(),
+                        config.nesting_depth_limit(),
                         debug_config,
                     )
-                    .map(|yul| yul.map(ContractIR::from)),
+                    .map(|yul| {
+                        yul.map(|mut yul| {
+                            // The nested objects duplicate the upper-level contracts in the `solc` output.
+                            yul.take_dependency_objects();
+                            ContractIR::from(yul)
+                        })
+                    }),
                     era_solc::StandardJsonInputCodegen::EVMLA => {
                         Ok(ContractEVMLA::try_from_contract(contract).map(ContractIR::from))
                     }
@@ -131,6 +150,17 @@ impl Project {
                 Err(error) => solc_output.push_error(Some(path), error),
             }
         }
+        for (path, dependencies) in solc_output.creation_code_dependencies.iter() {
+            for dependency in dependencies.iter() {
+                let identifier = match contracts.get(dependency) {
+                    Some(dependency) => dependency.identifier().to_owned(),
+                    None => continue,
+                };
+                if let Some(contract) = contracts.get_mut(path) {
+                    contract.add_factory_dependency(identifier);
+                }
+            }
+        }
         Ok(Project::new(
             era_solc::StandardJsonInputLanguage::Solidity,
             Some(solc_version),
@@ -147,16 +177,24 @@ impl Project {
         libraries: era_solc::StandardJsonInputLibraries,
         solc_output: Option<&mut era_solc::StandardJsonOutput>,
         solc_version: Option<&era_solc::Version>,
+        config: &BuildConfig,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
         let sources = paths
             .iter()
             .map(|path| {
                 let source = era_solc::StandardJsonInputSource::from(path.as_path());
-                (path.to_string_lossy().to_string(), source)
+                (era_solc::path::normalize_path(path.as_path()), source)
             })
             .collect::<BTreeMap<String, era_solc::StandardJsonInputSource>>();
-        Self::try_from_yul_sources(sources, libraries, solc_output, solc_version, debug_config)
+        Self::try_from_yul_sources(
+            sources,
+            libraries,
+            solc_output,
+            solc_version,
+            config,
+            debug_config,
+        )
     }
 
     ///
@@ -167,6 +205,7 @@ impl Project {
         libraries: era_solc::StandardJsonInputLibraries,
         mut solc_output: Option<&mut era_solc::StandardJsonOutput>,
         solc_version: Option<&era_solc::Version>,
+        config: &BuildConfig,
         debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<Self> {
         let results = sources
@@ -176,14 +215,24 @@ impl Project {
                     Ok(()) => source.take_content().expect("Always exists"),
                     Err(error) => return Some((path, Err(error))),
                 };
-                let ir = match ContractYul::try_from_source(
+                let mut ir = match ContractYul::try_from_source(
                     path.as_str(),
                     source_code.as_str(),
+                    config.nesting_depth_limit(),
                     debug_config,
                 ) {
                     Ok(ir) => ir?,
                     Err(error) => return Some((path, Err(error))),
                 };
+                let dependency_objects = ir.take_dependency_objects();
+                for object in std::iter::once(&ir).chain(dependency_objects.iter()) {
+                    if let Err(error) = crate::nested_objects::check_data_references(
+                        &object.object.0,
+                        path.as_str(),
+                    ) {
+                        return Some((path, Err(error)));
+                    }
+                }
 
                 let source_hash = era_compiler_common::Hash::keccak256(source_code.as_bytes());
                 let source_metadata = serde_json::json!({
@@ -196,16 +245,26 @@ impl Project {
                     Some(ir.object.0.identifier.clone()),
                 );
                 let full_path = name.full_path.clone();
-                let contract = Contract::new(name, ir.into(), source_metadata);
-                Some((full_path, Ok(contract)))
+                Some((
+                    full_path,
+                    Ok((name, ir, dependency_objects, source_metadata)),
+                ))
             })
-            .collect::<BTreeMap<String, anyhow::Result<Contract>>>();
+            .collect::<BTreeMap<
+                String,
+                anyhow::Result<(
+                    era_compiler_common::ContractName,
+                    ContractYul,
+                    Vec<ContractYul>,
+                    serde_json::Value,
+                )>,
+            >>();
 
-        let mut contracts = BTreeMap::new();
+        let mut sources = BTreeMap::new();
         for (path, result) in results.into_iter() {
             match result {
-                Ok(contract) => {
-                    contracts.insert(path, contract);
+                Ok(source) => {
+                    sources.insert(path, source);
                 }
                 Err(error) => match solc_output {
                     Some(ref mut solc_output) => solc_output.push_error(Some(path), error),
@@ -213,6 +272,61 @@ impl Project {
                 },
             }
         }
+
+        // Hand-written objects may only define their factory dependencies as nested objects,
+        // so those missing from the upper level are compiled as separate contracts. They are
+        // qualified with their source path, so equally named nested objects of different sources
+        // do not shadow each other. The nested objects defined at the upper level are duplicates
+        // in the `solc` output, so references to them are resolved to the upper-level objects.
+        let upper_level_identifiers = sources
+            .values()
+            .map(|(_, ir, _, _)| ir.object.0.identifier.to_owned())
+            .collect::<BTreeSet<String>>();
+        let mut contracts = BTreeMap::new();
+        for (full_path, (name, mut ir, dependency_objects, source_metadata)) in sources.into_iter()
+        {
+            let mut qualified_identifiers = BTreeMap::new();
+            let mut dependencies = Vec::new();
+            for dependency in dependency_objects.into_iter() {
+                let identifier = dependency.object.0.identifier.to_owned();
+                if upper_level_identifiers.contains(identifier.as_str()) {
+                    continue;
+                }
+                let dependency_name = era_compiler_common::ContractName::new(
+                    name.path.clone(),
+                    Some(identifier.clone()),
+                );
+                if qualified_identifiers
+                    .insert(identifier.clone(), dependency_name.full_path.clone())
+                    .is_some()
+                {
+                    let error = anyhow::anyhow!(
+                        "Nested Yul object `{identifier}` is defined more than once in `{}`.",
+                        name.path
+                    );
+                    match solc_output {
+                        Some(ref mut solc_output) => {
+                            solc_output.push_error(Some(name.path.clone()), error)
+                        }
+                        None => anyhow::bail!(error),
+                    }
+                }
+                dependencies.push((dependency_name, dependency));
+            }
+
+            crate::nested_objects::qualify(&mut ir.object.0, &qualified_identifiers);
+            contracts.insert(
+                full_path,
+                Contract::new(name, ir.into(), source_metadata.clone()),
+            );
+            for (dependency_name, mut dependency) in dependencies.into_iter() {
+                crate::nested_objects::qualify(&mut dependency.object.0, &qualified_identifiers);
+                contracts.insert(
+                    dependency_name.full_path.clone(),
+                    Contract::new(dependency_name, dependency.into(), source_metadata.clone()),
+                );
+            }
+        }
         Ok(Self::new(
             era_solc::StandardJsonInputLanguage::Yul,
             solc_version.cloned(),
@@ -233,7 +347,7 @@ impl Project {
             .iter()
             .map(|path| {
                 let source = era_solc::StandardJsonInputSource::from(path.as_path());
-                (path.to_string_lossy().to_string(), source)
+                (era_solc::path::normalize_path(path.as_path()), source)
             })
             .collect::<BTreeMap<String, era_solc::StandardJsonInputSource>>();
         Self::try_from_llvm_ir_sources(sources, libraries, solc_output)
@@ -300,7 +414,7 @@ impl Project {
             .iter()
             .map(|path| {
                 let source = era_solc::StandardJsonInputSource::from(path.as_path());
-                (path.to_string_lossy().to_string(), source)
+                (era_solc::path::normalize_path(path.as_path()), source)
             })
             .collect::<BTreeMap<String, era_solc::StandardJsonInputSource>>();
         Self::try_from_eravm_assembly_sources(sources, solc_output)
@@ -358,6 +472,8 @@ impl Project {
     ///
     /// Compiles all contracts to EraVM, returning their build artifacts.
     ///
+    /// If `build_cache` is set, the unchanged contracts are taken from the cache instead of being recompiled.
+    ///
     pub fn compile_to_eravm(
         self,
         messages: &mut Vec<era_solc::StandardJsonOutputError>,
@@ -366,15 +482,42 @@ impl Project {
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         output_assembly: bool,
+        build_cache: Option<&BuildCache>,
+        config: &BuildConfig,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EraVMBuild> {
-        let results = self.contracts.into_par_iter().map(|(path, mut contract)| {
+        config.check_contracts(self.contracts.len())?;
+        let cycles = self.get_factory_dependency_cycles();
+        let mut contracts = self
+            .contracts
+            .into_iter()
+            .collect::<Vec<(String, Contract)>>();
+        crate::shuffle::shuffle(config.shuffle_seed, contracts.as_mut_slice());
+        let results = contracts.into_par_iter().map(|(path, mut contract)| {
+            if let Some(cycle) = cycles.get(path.as_str()) {
+                let error = era_solc::StandardJsonOutputError::new_error(
+                    cycle,
+                    Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                        contract.name.path.clone(),
+                    )),
+                    None,
+                );
+                return (path, Err(error));
+            }
+            if let Some(build) = build_cache.and_then(|build_cache| build_cache.get(path.as_str())) {
+                config.subscribers.emit(crate::events::Event::CacheHit { path: path.as_str() });
+                return (path, Ok(build));
+            }
+
             let factory_dependencies = contract
                 .drain_factory_dependencies()
                 .into_iter()
                 .map(|identifier| self.identifier_paths.get(identifier.as_str()).cloned().expect("Always exists"))
                 .collect();
             let missing_libraries = contract.get_missing_libraries();
+            contract.debug_assertions = config.debug_assertions;
+            let mut llvm_options = llvm_options.clone();
+            llvm_options.extend(contract.llvm_options.drain(..));
             let input = EraVMProcessInput::new(
                 contract,
                 self.solc_version.clone(),
@@ -384,17 +527,45 @@ impl Project {
                 enable_eravm_extensions,
                 metadata_hash_type,
                 optimizer_settings.clone(),
-                llvm_options.clone(),
+                llvm_options,
                 output_assembly,
                 debug_config.clone(),
             );
-            let result: crate::Result<EraVMOutput> =
-                crate::process::call(path.as_str(), input, era_compiler_common::Target::EraVM);
-            let result = result.map(|output| output.build);
+            config.subscribers.emit(crate::events::Event::ContractStarted {
+                path: path.as_str(),
+                target: era_compiler_common::Target::EraVM,
+            });
+            let start_time = std::time::Instant::now();
+            let result = crate::process::call(
+                path.as_str(),
+                input,
+                era_compiler_common::Target::EraVM,
+                config,
+            );
+            let result = result.map(|output| {
+                let mut build = output.build;
+                build.duration = Some(start_time.elapsed());
+                build
+            });
+            if let Ok(build) = result.as_ref() {
+                config.subscribers.emit(crate::events::Event::BytecodeEmitted {
+                    path: path.as_str(),
+                    size: build.build.bytecode.len(),
+                });
+            }
+            if let (Some(build_cache), Ok(build)) = (build_cache, result.as_ref()) {
+                build_cache.insert(path.as_str(), build);
+            }
             (path, result)
         }).collect::<BTreeMap<String, Result<EraVMContractBuild, era_solc::StandardJsonOutputError>>>();
+        if let Some(build_cache) = build_cache {
+            build_cache.evict();
+        }
 
-        Ok(EraVMBuild::new(results, messages))
+        let mut build = EraVMBuild::new(results, messages);
+        build.shuffle_seed = config.shuffle_seed;
+        config.subscribers.emit_warnings(build.messages.as_slice());
+        Ok(build)
     }
 
     ///
@@ -407,8 +578,11 @@ impl Project {
         optimizer_settings: era_compiler_llvm_context::OptimizerSettings,
         llvm_options: Vec<String>,
         threads: Option<usize>,
+        config: &BuildConfig,
         debug_config: Option<era_compiler_llvm_context::DebugConfig>,
     ) -> anyhow::Result<EVMBuild> {
+        config.check_contracts(self.contracts.len())?;
+        let cycles = self.get_factory_dependency_cycles();
         let dependency_data =
             EVMProcessInputDependencyData::new(self.solc_version, self.identifier_paths);
 
@@ -420,10 +594,415 @@ impl Project {
             llvm_options,
             debug_config,
         );
-        let pool = EVMThreadPool::new(threads, self.contracts, input_template);
+        let mut results = BTreeMap::new();
+        let mut contracts = BTreeMap::new();
+        for (path, contract) in self.contracts.into_iter() {
+            match cycles.get(path.as_str()) {
+                Some(cycle) => {
+                    let error = era_solc::StandardJsonOutputError::new_error(
+                        cycle,
+                        Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                            contract.name.path,
+                        )),
+                        None,
+                    );
+                    results.insert(path, Err(error));
+                }
+                None => {
+                    contracts.insert(path, contract);
+                }
+            }
+        }
+
+        let pool = EVMThreadPool::new(threads, contracts, input_template, config.to_owned());
         pool.start();
-        let results = pool.finish();
-        Ok(EVMBuild::new(results, messages))
+        results.extend(pool.finish());
+        let build = EVMBuild::new(results, messages);
+        config.subscribers.emit_warnings(build.messages.as_slice());
+        Ok(build)
+    }
+
+    ///
+    /// Removes the contracts not matching the `filter`, except the factory dependencies and
+    /// libraries of the matching ones, collected transitively.
+    ///
+    pub fn apply_contract_filter(&mut self, filter: &ContractFilter) -> anyhow::Result<()> {
+        let mut retained: BTreeSet<String> = self
+            .contracts
+            .keys()
+            .filter(|path| filter.is_match(path.as_str()))
+            .cloned()
+            .collect();
+        if retained.is_empty() {
+            anyhow::bail!("No contracts match the `{filter}` allow-list patterns.");
+        }
+        if let Some(ref contract) = filter.contract {
+            let full_path = crate::contract_selection::select(retained.iter(), contract.as_str())?;
+            retained = BTreeSet::from([full_path]);
+        }
+
+        self.retain_with_dependencies(retained);
+        Ok(())
+    }
+
+    ///
+    /// Removes the contracts whose bytecode is not requested by the granular output `selection`,
+    /// except the factory dependencies and libraries of the requested ones, collected transitively.
+    ///
+    /// Selections without entries for specific files or contracts are ignored, so all contracts
+    /// are compiled, as the bytecode has always been emitted regardless of the selection.
+    ///
+    pub fn apply_output_selection(&mut self, selection: &era_solc::StandardJsonInputSelection) {
+        if !selection.is_granular() {
+            return;
+        }
+
+        let retained: BTreeSet<String> = self
+            .contracts
+            .iter()
+            .filter(|(_, contract)| {
+                selection.is_bytecode_requested(
+                    contract.name.path.as_str(),
+                    contract
+                        .name
+                        .name
+                        .as_deref()
+                        .unwrap_or(contract.name.path.as_str()),
+                )
+            })
+            .map(|(path, _)| path.to_owned())
+            .collect();
+        self.retain_with_dependencies(retained);
+    }
+
+    ///
+    /// Removes the contracts except the `retained` ones, and their factory dependencies and
+    /// libraries, collected transitively.
+    ///
+    fn retain_with_dependencies(&mut self, mut retained: BTreeSet<String>) {
+        let mut queue = retained.iter().cloned().collect::<Vec<String>>();
+        while let Some(path) = queue.pop() {
+            let contract = match self.contracts.get(path.as_str()) {
+                Some(contract) => contract,
+                None => continue,
+            };
+            let dependencies = contract
+                .get_factory_dependencies()
+                .into_iter()
+                .filter_map(|identifier| self.identifier_paths.get(identifier).cloned())
+                .chain(contract.get_missing_libraries())
+                .collect::<Vec<String>>();
+            for dependency in dependencies.into_iter() {
+                if self.contracts.contains_key(dependency.as_str())
+                    && retained.insert(dependency.clone())
+                {
+                    queue.push(dependency);
+                }
+            }
+        }
+
+        self.contracts.retain(|path, _| retained.contains(path));
+    }
+
+    ///
+    /// Lays out the functions and basic blocks of the profiled contracts according to the execution profile.
+    ///
+    pub fn apply_execution_profile(&mut self, profile: &ExecutionProfile) {
+        for (path, contract) in self.contracts.iter_mut() {
+            if let Some(call_counts) = profile.get(path.as_str()) {
+                contract.apply_execution_profile(call_counts);
+            }
+        }
+    }
+
+    ///
+    /// Eliminates the functions unreachable from the externally reachable selectors,
+    /// grouped by file and contract name.
+    ///
+    pub fn apply_reachable_selectors(
+        &mut self,
+        reachable_selectors: &BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+    ) -> anyhow::Result<()> {
+        for contract in self.contracts.values_mut() {
+            let name = &contract.name;
+            let selectors =
+                match reachable_selectors
+                    .get(name.path.as_str())
+                    .and_then(|contracts| {
+                        contracts.get(name.name.as_deref().unwrap_or(name.path.as_str()))
+                    }) {
+                    Some(selectors) => crate::reachable_selectors::normalize(selectors)?,
+                    None => continue,
+                };
+            contract.apply_reachable_selectors(&selectors);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Propagates the known immutable values, grouped by file and contract name.
+    ///
+    pub fn apply_immutables(
+        &mut self,
+        immutables: &BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+    ) -> anyhow::Result<()> {
+        for contract in self.contracts.values_mut() {
+            let name = &contract.name;
+            let values = match immutables.get(name.path.as_str()).and_then(|contracts| {
+                contracts.get(name.name.as_deref().unwrap_or(name.path.as_str()))
+            }) {
+                Some(values) => crate::immutables::normalize(values)?,
+                None => continue,
+            };
+            contract.apply_immutables(values);
+        }
+        Ok(())
+    }
+
+    ///
+    /// Sets the extra LLVM options of specific contracts, keyed by contract full path.
+    ///
+    /// The options are applied in addition to the global ones. A warning is emitted for each key
+    /// not matching any contract to compile, as its options would be silently ignored otherwise.
+    ///
+    pub fn apply_llvm_options(
+        &mut self,
+        llvm_options: &BTreeMap<String, Vec<String>>,
+        messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    ) {
+        for (path, options) in llvm_options.iter() {
+            match self.contracts.get_mut(path.as_str()) {
+                Some(contract) => contract.llvm_options = options.to_owned(),
+                None => messages.push(era_solc::StandardJsonOutputError::new_warning(
+                    format!("The `LLVMOptions` key `{path}` does not match any contract to compile, so its options are ignored."),
+                    None,
+                    None,
+                )),
+            }
+        }
+    }
+
+    ///
+    /// Sets the active compilation profile recorded in the metadata of all contracts.
+    ///
+    pub fn set_profile(&mut self, profile: &str) {
+        for contract in self.contracts.values_mut() {
+            contract.profile = Some(profile.to_owned());
+        }
+    }
+
+    ///
+    /// Sets the `BALANCE` and `SELFBALANCE` lowering strategy of all contracts.
+    ///
+    pub fn set_balance_lowering(
+        &mut self,
+        balance_lowering: era_solc::StandardJsonInputBalanceLowering,
+    ) {
+        for contract in self.contracts.values_mut() {
+            contract.balance_lowering = balance_lowering;
+        }
+    }
+
+    ///
+    /// Returns the factory dependency cycle descriptions, keyed by the full path of each contract in a cycle.
+    ///
+    /// Such contracts cannot be compiled, as their bytecode hashes would depend on each other.
+    ///
+    pub fn get_factory_dependency_cycles(&self) -> BTreeMap<String, String> {
+        let dependencies = self
+            .contracts
+            .iter()
+            .map(|(path, contract)| {
+                let locations = match contract.ir {
+                    ContractIR::Yul(ref yul)
+                        if self.language == era_solc::StandardJsonInputLanguage::Yul =>
+                    {
+                        crate::dependency_cycle::get_yul_locations(&yul.object.0)
+                    }
+                    _ => BTreeMap::new(),
+                };
+                let mut edges: Vec<crate::dependency_cycle::Edge> = contract
+                    .get_factory_dependencies()
+                    .into_iter()
+                    .filter_map(|identifier| {
+                        let dependency = self.identifier_paths.get(identifier)?.to_owned();
+                        let location = locations
+                            .get(identifier)
+                            .map(|location| format!("{}:{location}", contract.name.path));
+                        Some((dependency, location))
+                    })
+                    .collect();
+                edges.sort();
+                (path.to_owned(), edges)
+            })
+            .collect();
+
+        let mut cycles = BTreeMap::new();
+        for cycle in crate::dependency_cycle::find(&dependencies).into_iter() {
+            let message = crate::dependency_cycle::format(cycle.as_slice());
+            for (path, _) in cycle.into_iter() {
+                cycles.entry(path).or_insert_with(|| message.clone());
+            }
+        }
+        cycles
+    }
+
+    ///
+    /// Checks the contracts against the instructions forbidden by the organization policy.
+    ///
+    /// Violations are returned as errors, one per contract and instruction.
+    ///
+    pub fn check_policy(
+        &self,
+        policy: &era_solc::StandardJsonInputPolicy,
+    ) -> Vec<era_solc::StandardJsonOutputError> {
+        let instructions = match crate::policy::forbidden_instructions(policy) {
+            Ok(instructions) => instructions,
+            Err(error) => {
+                return vec![era_solc::StandardJsonOutputError::new_error(
+                    error, None, None,
+                )]
+            }
+        };
+
+        let mut errors = Vec::new();
+        for (path, contract) in self.contracts.iter() {
+            for (name, instruction, rule_id) in instructions.iter() {
+                let (count, locations) = match contract.ir {
+                    ContractIR::Yul(ref yul) => {
+                        let usages = crate::policy::get_yul_usages(&yul.object.0, name);
+                        let locations = if self.language == era_solc::StandardJsonInputLanguage::Yul
+                        {
+                            usages
+                                .iter()
+                                .map(|location| format!("\n    {}:{location}", contract.name.path))
+                                .collect::<String>()
+                        } else {
+                            String::new()
+                        };
+                        (usages.len(), locations)
+                    }
+                    ContractIR::EVMLA(ref evmla) => (
+                        crate::policy::get_assembly_usages(&evmla.assembly, instruction.as_str()),
+                        String::new(),
+                    ),
+                    _ => (0, String::new()),
+                };
+                if count == 0 {
+                    continue;
+                }
+
+                errors.push(era_solc::StandardJsonOutputError::new_error(
+                    format!("Policy rule `{rule_id}` forbids instruction `{instruction}`, which is used {count} time(s) in contract `{path}`.{locations}"),
+                    Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                        contract.name.path.clone(),
+                    )),
+                    None,
+                ));
+            }
+        }
+        errors
+    }
+
+    ///
+    /// Returns the call graph of the project.
+    ///
+    pub fn call_graph(&self) -> CallGraph {
+        CallGraph {
+            contracts: self
+                .contracts
+                .iter()
+                .filter_map(|(path, contract)| Some((path.to_owned(), contract.call_graph()?)))
+                .collect(),
+        }
+    }
+
+    ///
+    /// Returns the project dependency graph, combining the source `import_graph` with the factory
+    /// dependencies of the contracts and their `linked_libraries`.
+    ///
+    /// The libraries are taken from the AST, so the ones linked at compile time are included as well.
+    ///
+    pub fn dependency_graph(
+        &self,
+        import_graph: &era_solc::StandardJsonOutputImportGraph,
+        linked_libraries: &era_solc::StandardJsonOutputLinkedLibraries,
+    ) -> DependencyGraph {
+        let contracts = self
+            .contracts
+            .iter()
+            .map(|(path, contract)| {
+                let factory_dependencies = contract
+                    .get_factory_dependencies()
+                    .into_iter()
+                    .filter_map(|identifier| self.identifier_paths.get(identifier).cloned())
+                    .collect();
+                let node = DependencyGraphContract {
+                    source: contract.name.path.to_owned(),
+                    factory_dependencies,
+                    libraries: linked_libraries
+                        .contracts
+                        .get(path.as_str())
+                        .cloned()
+                        .unwrap_or_default(),
+                };
+                (path.to_owned(), node)
+            })
+            .collect();
+        DependencyGraph::new(import_graph, contracts)
+    }
+
+    ///
+    /// Adds the library deployer contract, deploying the missing libraries defined in the project.
+    ///
+    /// Returns the full paths of the libraries to be deployed.
+    ///
+    pub fn add_library_deployer(
+        &mut self,
+        debug_config: Option<&era_compiler_llvm_context::DebugConfig>,
+    ) -> anyhow::Result<BTreeSet<String>> {
+        let libraries = self
+            .get_missing_libraries()
+            .contract_libraries
+            .into_values()
+            .flatten()
+            .filter_map(|path| {
+                let identifier = self.contracts.get(path.as_str())?.identifier().to_owned();
+                Some((path, identifier))
+            })
+            .collect::<BTreeMap<String, String>>();
+        if libraries.is_empty() {
+            return Ok(BTreeSet::new());
+        }
+
+        let source_code = crate::library_deployer::source_code(&libraries);
+        let ir = ContractYul::try_from_source(
+            crate::library_deployer::PATH,
+            source_code.as_str(),
+            era_yul::yul::lexer::Lexer::DEFAULT_MAX_DEPTH,
+            debug_config,
+        )?
+        .expect("Always exists");
+
+        let source_hash = era_compiler_common::Hash::keccak256(source_code.as_bytes());
+        let source_metadata = serde_json::json!({
+            "source_hash": source_hash.to_string(),
+            "solc_version": self.solc_version,
+        });
+
+        let name = era_compiler_common::ContractName::new(
+            crate::library_deployer::PATH.to_owned(),
+            Some(crate::library_deployer::IDENTIFIER.to_owned()),
+        );
+        let full_path = name.full_path.clone();
+        self.identifier_paths.insert(
+            crate::library_deployer::IDENTIFIER.to_owned(),
+            full_path.clone(),
+        );
+        self.contracts
+            .insert(full_path, Contract::new(name, ir.into(), source_metadata));
+
+        Ok(libraries.into_keys().collect())
     }
 
     ///
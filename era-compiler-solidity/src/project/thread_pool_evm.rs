@@ -7,9 +7,9 @@ use std::sync::Arc;
 use std::sync::RwLock;
 use std::time::Duration;
 
+use crate::build_config::BuildConfig;
 use crate::build_evm::contract::Contract as EVMContractBuild;
 use crate::process::input_evm::Input as EVMInput;
-use crate::process::output_evm::Output as EVMOutput;
 use crate::project::contract::factory_dependency::FactoryDependency;
 use crate::project::contract::Contract;
 
@@ -28,6 +28,8 @@ pub struct ThreadPool {
     pub contracts: Arc<RwLock<BTreeMap<String, Contract>>>,
     /// The child process input template.
     pub input_template: EVMInput,
+    /// The build configuration.
+    pub config: BuildConfig,
     /// The thread-safe storage of evaluation results.
     pub results: Arc<RwLock<BTreeMap<String, crate::Result<EVMContractBuild>>>>,
 }
@@ -40,6 +42,7 @@ impl ThreadPool {
         threads: Option<usize>,
         contracts: BTreeMap<String, Contract>,
         input_template: EVMInput,
+        config: BuildConfig,
     ) -> Self {
         let threads = threads.unwrap_or_else(num_cpus::get);
         let inner = rusty_pool::ThreadPool::new(threads, threads, Duration::from_secs(1));
@@ -48,6 +51,7 @@ impl ThreadPool {
             inner,
             contracts: Arc::new(RwLock::new(contracts)),
             input_template,
+            config,
             results: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
@@ -58,7 +62,7 @@ impl ThreadPool {
     /// Checks which contracts have satisfied dependencies and are ready to be compiled.
     ///
     pub fn start(&self) {
-        let contracts_satisfied: Vec<String> = self
+        let mut contracts_satisfied: Vec<String> = self
             .contracts
             .read()
             .expect("Sync")
@@ -78,6 +82,7 @@ impl ThreadPool {
                 }
             })
             .collect();
+        crate::shuffle::shuffle(self.config.shuffle_seed, contracts_satisfied.as_mut_slice());
 
         'outer: for path in contracts_satisfied.into_iter() {
             let contract = match self.contracts.write().expect("Sync").remove(path.as_str()) {
@@ -127,18 +132,42 @@ impl ThreadPool {
         dependencies: BTreeMap<String, EVMContractBuild>,
     ) {
         let mut input = self.input_template.to_owned();
+        input.llvm_options.extend(contract.llvm_options.clone());
         input.contract = Some(contract);
         input.dependency_data.dependencies.extend(dependencies);
 
         let results = self.results.clone();
         let pool = self.to_owned();
         self.inner.evaluate(move || {
-            let result: crate::Result<EVMOutput> =
-                crate::process::call(path.as_str(), input, era_compiler_common::Target::EVM);
-            results
-                .write()
-                .expect("Sync")
-                .insert(path, result.map(|output| output.build));
+            pool.config
+                .subscribers
+                .emit(crate::events::Event::ContractStarted {
+                    path: path.as_str(),
+                    target: era_compiler_common::Target::EVM,
+                });
+            let start_time = std::time::Instant::now();
+            let result = crate::process::call(
+                path.as_str(),
+                input,
+                era_compiler_common::Target::EVM,
+                &pool.config,
+            );
+            if let Ok(output) = result.as_ref() {
+                pool.config
+                    .subscribers
+                    .emit(crate::events::Event::BytecodeEmitted {
+                        path: path.as_str(),
+                        size: output.build.deploy_build.len(),
+                    });
+            }
+            results.write().expect("Sync").insert(
+                path,
+                result.map(|output| {
+                    let mut build = output.build;
+                    build.duration = Some(start_time.elapsed());
+                    build
+                }),
+            );
             pool.start();
         });
     }
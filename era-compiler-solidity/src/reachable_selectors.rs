@@ -0,0 +1,115 @@
+//!
+//! The selector-restricted dead function elimination.
+//!
+
+use std::collections::BTreeSet;
+
+use era_yul::yul::lexer::token::lexeme::literal::integer::Integer as IntegerLiteral;
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::switch::Switch;
+use era_yul::yul::parser::statement::Statement;
+
+use crate::yul::parser::dialect::era::EraDialect;
+
+/// The number of hexadecimal digits in a function selector.
+pub const SELECTOR_HEX_LENGTH: usize = 8;
+
+///
+/// Normalizes the selectors to lowercase hexadecimal strings without the `0x` prefix,
+/// as they are represented in the `methodIdentifiers` output.
+///
+pub fn normalize(selectors: &BTreeSet<String>) -> anyhow::Result<BTreeSet<String>> {
+    selectors
+        .iter()
+        .map(|selector| {
+            let normalized = selector
+                .strip_prefix("0x")
+                .unwrap_or(selector.as_str())
+                .to_lowercase();
+            if normalized.len() != SELECTOR_HEX_LENGTH
+                || !normalized.chars().all(|character| character.is_ascii_hexdigit())
+            {
+                anyhow::bail!(
+                    "Invalid function selector `{selector}`: expected {SELECTOR_HEX_LENGTH} hexadecimal digits"
+                );
+            }
+            Ok(normalized)
+        })
+        .collect()
+}
+
+///
+/// Removes the dispatcher cases of selectors missing from `selectors` from the runtime code.
+///
+/// The removed selectors fall through to the default dispatcher branch, that is, the fallback
+/// function or revert. Functions only reachable from the removed cases become dead and are
+/// eliminated by LLVM, as Yul functions are translated with private linkage.
+///
+pub fn apply_to_object(object: &mut Object<EraDialect>, selectors: &BTreeSet<String>) {
+    if object.identifier.ends_with("_deployed") {
+        apply_to_block(&mut object.code.block, selectors);
+    }
+    if let Some(inner_object) = object.inner_object.as_mut() {
+        apply_to_object(inner_object, selectors);
+    }
+}
+
+///
+/// Removes the unreachable dispatcher cases from the block, skipping function definitions.
+///
+fn apply_to_block(block: &mut Block<EraDialect>, selectors: &BTreeSet<String>) {
+    for statement in block.statements.iter_mut() {
+        match statement {
+            Statement::Block(block) => apply_to_block(block, selectors),
+            Statement::IfConditional(if_conditional) => {
+                apply_to_block(&mut if_conditional.block, selectors)
+            }
+            Statement::Switch(switch) => {
+                if is_dispatcher(switch) {
+                    switch.cases.retain(|case| {
+                        selector(&case.literal.inner)
+                            .map(|selector| selectors.contains(selector.as_str()))
+                            .unwrap_or(true)
+                    });
+                }
+                for case in switch.cases.iter_mut() {
+                    apply_to_block(&mut case.block, selectors);
+                }
+                if let Some(default) = switch.default.as_mut() {
+                    apply_to_block(default, selectors);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+///
+/// Checks whether the switch is a function dispatcher, that is, all its cases are selectors.
+///
+fn is_dispatcher(switch: &Switch<EraDialect>) -> bool {
+    !switch.cases.is_empty()
+        && switch
+            .cases
+            .iter()
+            .all(|case| selector(&case.literal.inner).is_some())
+}
+
+///
+/// Returns the normalized selector if the literal is formatted as one, e.g. `0xa9059cbb`.
+///
+fn selector(literal: &LexicalLiteral) -> Option<String> {
+    match literal {
+        LexicalLiteral::Integer(IntegerLiteral::Hexadecimal { inner }) => {
+            let digits = inner.strip_prefix("0x")?;
+            if digits.len() == SELECTOR_HEX_LENGTH {
+                Some(digits.to_lowercase())
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
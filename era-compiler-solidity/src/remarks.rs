@@ -0,0 +1,121 @@
+//!
+//! The LLVM optimization remarks.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::path::PathBuf;
+
+/// The LLVM option specifying the remarks output file.
+pub const OPTION_OUTPUT: &str = "-pass-remarks-output=";
+
+/// The LLVM options that only affect the remarks output and never the bytecode.
+pub const OPTIONS: [&str; 5] = [
+    "-pass-remarks=",
+    "-pass-remarks-missed=",
+    "-pass-remarks-analysis=",
+    "-pass-remarks-format=",
+    OPTION_OUTPUT,
+];
+
+/// The remarks serialization format.
+pub const FORMAT: &str = "yaml";
+
+/// The per-contract remarks file name prefix used in the temporary directory.
+pub const PARTS_FILE_NAME: &str = "remarks";
+
+///
+/// Returns the LLVM options enabling all remarks to be written to the file at `path`.
+///
+pub fn llvm_options(path: &Path) -> Vec<String> {
+    vec![
+        "-pass-remarks=.*".to_owned(),
+        "-pass-remarks-missed=.*".to_owned(),
+        "-pass-remarks-analysis=.*".to_owned(),
+        format!("-pass-remarks-format={FORMAT}"),
+        format!("{OPTION_OUTPUT}{}", path.to_string_lossy()),
+    ]
+}
+
+///
+/// Checks whether the LLVM option only affects the remarks output.
+///
+/// Such options must not be included into the contract metadata.
+///
+pub fn is_remarks_option(option: &str) -> bool {
+    OPTIONS.iter().any(|prefix| option.starts_with(prefix))
+}
+
+///
+/// Binds the remarks output file to the contract, so parallel compilation processes
+/// do not overwrite each other's remarks.
+///
+pub fn bind_to_contract(llvm_options: Vec<String>, contract_path: &str) -> Vec<String> {
+    llvm_options
+        .into_iter()
+        .map(|option| match option.strip_prefix(OPTION_OUTPUT) {
+            Some(parts_path) => format!(
+                "{OPTION_OUTPUT}{}",
+                part_path(Path::new(parts_path), contract_path).to_string_lossy()
+            ),
+            None => option,
+        })
+        .collect()
+}
+
+///
+/// Returns the path of the remarks file of the contract at `contract_path`, bound to `parts_path`.
+///
+pub fn part_path(parts_path: &Path, contract_path: &str) -> PathBuf {
+    let suffix = contract_path.replace(['/', '\\'], "_").replace(':', ".");
+    let mut part_path = parts_path.as_os_str().to_owned();
+    part_path.push(format!(".{suffix}.{FORMAT}"));
+    PathBuf::from(part_path)
+}
+
+///
+/// Merges the remarks files of the contracts bound to `parts_path` into the file at `path`, removing the former.
+///
+/// `contract_paths` yields the contract paths along with whether the contract has been built successfully.
+/// Only the files written for the specified contracts are touched, so unrelated files in the directory are kept.
+/// YAML remarks are streams of documents, so the merge is a plain concatenation.
+/// The files are merged in alphabetical order of contract paths to keep the output deterministic.
+///
+/// Returns an error if none of the successfully built contracts has its remarks file, which means that
+/// the LLVM build in use does not support the remarks output option.
+///
+pub fn merge<'a>(
+    parts_path: &Path,
+    path: &Path,
+    contract_paths: impl IntoIterator<Item = (&'a str, bool)>,
+) -> anyhow::Result<()> {
+    let contract_paths: BTreeMap<&str, bool> = contract_paths.into_iter().collect();
+
+    let mut remarks = String::new();
+    let mut is_any_built = false;
+    let mut is_any_built_written = false;
+    for (contract_path, is_built) in contract_paths.into_iter() {
+        is_any_built |= is_built;
+
+        let part_path = part_path(parts_path, contract_path);
+        if !part_path.exists() {
+            continue;
+        }
+        let part = std::fs::read_to_string(part_path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {part_path:?} reading: {error}"))?;
+        remarks.push_str(part.as_str());
+        std::fs::remove_file(part_path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {part_path:?} removing: {error}"))?;
+        is_any_built_written |= is_built;
+    }
+    if is_any_built && !is_any_built_written {
+        anyhow::bail!(
+            "LLVM optimization remarks have not been written for any contract. The `{}` option may be not supported by the LLVM build in use.",
+            OPTION_OUTPUT.trim_end_matches('='),
+        );
+    }
+    std::fs::write(path, remarks)
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+//!
+//! The reports written along with the build.
+//!
+
+use std::path::PathBuf;
+
+use crate::interface_freeze::InterfaceFreeze;
+
+///
+/// The reports written along with the build in the basic CLI mode.
+///
+/// Each report is written only if requested, and none of them is written while warming the cache.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Reports {
+    /// The directory the call graphs are written to.
+    pub call_graph: Option<PathBuf>,
+    /// The file the inheritance graph is written to.
+    pub inheritance_graph: Option<PathBuf>,
+    /// The file the import graph is written to.
+    pub import_graph: Option<PathBuf>,
+    /// The directory the dependency graphs are written to.
+    pub dependency_graph: Option<PathBuf>,
+    /// The file the EVM and EraVM compatibility report is written to.
+    pub compatibility_report: Option<PathBuf>,
+    /// The file the revert decoder data is written to.
+    pub revert_decoder: Option<PathBuf>,
+    /// The interface freeze the contract ABIs are checked against.
+    pub interface_freeze: Option<InterfaceFreeze>,
+    /// The file the `solc` standard JSON input is echoed to.
+    pub solc_input_echo: Option<PathBuf>,
+    /// The file the register allocator spill report is written to.
+    pub spill_report: Option<PathBuf>,
+}
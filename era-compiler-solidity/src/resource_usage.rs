@@ -0,0 +1,231 @@
+//!
+//! The resource usage of the compiler run.
+//!
+
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
+use std::sync::Mutex;
+
+use crate::events::Event;
+use crate::events::Subscriber;
+
+///
+/// The resource usage of the compiler run.
+///
+/// The usage of *zksolc* itself, of the `solc` subprocesses, and of the compiler subprocesses
+/// spawned for the contracts is reported separately.
+/// The values that cannot be measured on the host platform are omitted.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceUsage {
+    /// The peak resident set size of the compiler process in kilobytes.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_rss_kb: Option<u64>,
+    /// The user CPU time of the compiler process in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub user_cpu_time_ms: Option<u64>,
+    /// The system CPU time of the compiler process in milliseconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub system_cpu_time_ms: Option<u64>,
+    /// The number of `solc` compilations, excluding the version queries.
+    pub solc_runs: usize,
+    /// The usage of the `solc` subprocesses.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub solc: Option<SubprocessUsage>,
+    /// The usage of the compiler subprocesses spawned for the contracts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub subprocesses: Option<SubprocessUsage>,
+    /// The size of the temporary directory in bytes, only set if the directory is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub temp_disk_usage_bytes: Option<u64>,
+}
+
+///
+/// The resource usage of a group of subprocesses.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubprocessUsage {
+    /// The peak resident set size of the largest subprocess in kilobytes.
+    ///
+    /// The operating system only reports the peak of all subprocesses waited for, so the peak
+    /// is omitted if it cannot be attributed to the group.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub peak_rss_kb: Option<u64>,
+    /// The total user CPU time of the subprocesses in milliseconds.
+    pub user_cpu_time_ms: u64,
+    /// The total system CPU time of the subprocesses in milliseconds.
+    pub system_cpu_time_ms: u64,
+}
+
+///
+/// The event subscriber tracking the `solc` runs, so their resource usage can be told apart
+/// from the usage of the compiler subprocesses.
+///
+#[derive(Debug, Default)]
+pub struct ResourceMonitor {
+    /// The number of finished `solc` compilations.
+    solc_runs: AtomicUsize,
+    /// The usage of the subprocesses at the start of the current `solc` run, and the usage
+    /// accumulated by the finished `solc` runs.
+    solc: Mutex<(Option<SubprocessUsage>, Option<SubprocessUsage>)>,
+}
+
+impl Subscriber for ResourceMonitor {
+    fn on_event(&self, event: &Event) {
+        match event {
+            Event::SolcStarted => {
+                let mut solc = self.solc.lock().expect("Sync");
+                solc.0 = ResourceUsage::children();
+            }
+            Event::SolcFinished { .. } => {
+                self.solc_runs.fetch_add(1, Ordering::Relaxed);
+
+                let mut solc = self.solc.lock().expect("Sync");
+                let (Some(start), Some(end)) = (solc.0.take(), ResourceUsage::children()) else {
+                    return;
+                };
+                let total = solc.1.get_or_insert_with(SubprocessUsage::default);
+                total.user_cpu_time_ms +=
+                    end.user_cpu_time_ms.saturating_sub(start.user_cpu_time_ms);
+                total.system_cpu_time_ms += end
+                    .system_cpu_time_ms
+                    .saturating_sub(start.system_cpu_time_ms);
+                if end.peak_rss_kb > start.peak_rss_kb {
+                    total.peak_rss_kb = total.peak_rss_kb.max(end.peak_rss_kb);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+impl ResourceUsage {
+    ///
+    /// Measures the resource usage of the run so far.
+    ///
+    /// The `solc` usage is taken from the `monitor`, which must be subscribed to the events of
+    /// the build, and the rest of the subprocess usage is attributed to the compiler subprocesses.
+    ///
+    /// The temporary directory is usually removed before the usage is reported, so its size must
+    /// be measured beforehand with [`ResourceUsage::directory_size`].
+    ///
+    pub fn measure(monitor: &ResourceMonitor, temp_disk_usage_bytes: Option<u64>) -> Self {
+        let solc = monitor.solc.lock().expect("Sync").1;
+        #[cfg_attr(not(unix), allow(unused_mut))]
+        let mut usage = Self {
+            solc_runs: monitor.solc_runs.load(Ordering::Relaxed),
+            solc,
+            temp_disk_usage_bytes,
+            ..Self::default()
+        };
+
+        #[cfg(unix)]
+        if let Some(own) = Self::rusage(libc::RUSAGE_SELF) {
+            usage.peak_rss_kb = Some(Self::max_rss_kb(&own));
+            usage.user_cpu_time_ms = Some(Self::milliseconds(own.ru_utime));
+            usage.system_cpu_time_ms = Some(Self::milliseconds(own.ru_stime));
+        }
+
+        if let Some(children) = Self::children() {
+            let solc = solc.unwrap_or_default();
+            usage.subprocesses = Some(SubprocessUsage {
+                peak_rss_kb: children
+                    .peak_rss_kb
+                    .filter(|peak_rss_kb| Some(*peak_rss_kb) > solc.peak_rss_kb),
+                user_cpu_time_ms: children
+                    .user_cpu_time_ms
+                    .saturating_sub(solc.user_cpu_time_ms),
+                system_cpu_time_ms: children
+                    .system_cpu_time_ms
+                    .saturating_sub(solc.system_cpu_time_ms),
+            });
+        }
+
+        usage
+    }
+
+    ///
+    /// Returns the total size of the files in the directory at `path`, including the nested ones.
+    ///
+    /// Entries that cannot be read are skipped.
+    ///
+    pub fn directory_size(path: &Path) -> u64 {
+        let mut size = 0;
+        let mut directories = vec![path.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            let Ok(entries) = std::fs::read_dir(directory.as_path()) else {
+                continue;
+            };
+            for entry in entries.filter_map(Result::ok) {
+                let Ok(metadata) = entry.metadata() else {
+                    continue;
+                };
+                if metadata.is_dir() {
+                    directories.push(entry.path());
+                } else {
+                    size += metadata.len();
+                }
+            }
+        }
+        size
+    }
+
+    ///
+    /// Returns the usage of all subprocesses waited for so far.
+    ///
+    #[cfg(unix)]
+    fn children() -> Option<SubprocessUsage> {
+        Self::rusage(libc::RUSAGE_CHILDREN).map(|children| SubprocessUsage {
+            peak_rss_kb: Some(Self::max_rss_kb(&children)),
+            user_cpu_time_ms: Self::milliseconds(children.ru_utime),
+            system_cpu_time_ms: Self::milliseconds(children.ru_stime),
+        })
+    }
+
+    ///
+    /// Returns the usage of all subprocesses waited for so far.
+    ///
+    #[cfg(not(unix))]
+    fn children() -> Option<SubprocessUsage> {
+        None
+    }
+
+    ///
+    /// Calls `getrusage` for the specified processes.
+    ///
+    #[cfg(unix)]
+    fn rusage(who: libc::c_int) -> Option<libc::rusage> {
+        let mut usage = std::mem::MaybeUninit::<libc::rusage>::uninit();
+        // SAFETY: `getrusage` only writes to the provided buffer, which is large enough.
+        let result = unsafe { libc::getrusage(who, usage.as_mut_ptr()) };
+        if result != 0 {
+            return None;
+        }
+        // SAFETY: the buffer has been initialized by the successful call above.
+        Some(unsafe { usage.assume_init() })
+    }
+
+    ///
+    /// Returns the peak resident set size in kilobytes, which is reported in bytes on macOS.
+    ///
+    #[cfg(unix)]
+    fn max_rss_kb(usage: &libc::rusage) -> u64 {
+        let max_rss = usage.ru_maxrss.max(0) as u64;
+        if cfg!(target_os = "macos") {
+            max_rss / 1024
+        } else {
+            max_rss
+        }
+    }
+
+    ///
+    /// Converts the `timeval` to milliseconds.
+    ///
+    #[cfg(unix)]
+    fn milliseconds(time: libc::timeval) -> u64 {
+        (time.tv_sec.max(0) as u64) * 1000 + (time.tv_usec.max(0) as u64) / 1000
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The revert decoder data.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::interface_freeze::Interface;
+
+///
+/// The custom error decoder data.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Error {
+    /// The error signature, e.g. `Unauthorized(address)`.
+    pub signature: String,
+    /// The locations of the `revert` statements and `require` calls in the `path:line:column` format.
+    pub reverts: Vec<String>,
+}
+
+///
+/// The per-contract revert decoder data.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Contract {
+    /// The custom errors, keyed by selector.
+    pub errors: BTreeMap<String, Error>,
+}
+
+///
+/// The revert decoder data.
+///
+/// Maps the revert data selectors and panic codes to human-readable signatures, so RPC tooling
+/// can decode failures of the deployed contracts, e.g.:
+///
+/// ```json
+/// {
+///   "builtins": {
+///     "08c379a0": "Error(string)",
+///     "4e487b71": "Panic(uint256)"
+///   },
+///   "panics": {
+///     "0x11": "Arithmetic operation overflowed or underflowed outside of an unchecked block."
+///   },
+///   "contracts": {
+///     "Test.sol:Test": {
+///       "errors": {
+///         "8e4a23d6": {
+///           "signature": "Unauthorized(address)",
+///           "reverts": ["Test.sol:12:13"]
+///         }
+///       }
+///     }
+///   }
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RevertDecoder {
+    /// The built-in error signatures, keyed by selector.
+    pub builtins: BTreeMap<String, String>,
+    /// The `Panic(uint256)` code descriptions, keyed by code.
+    pub panics: BTreeMap<String, String>,
+    /// The per-contract data, keyed by contract full path.
+    /// Contracts without custom errors are omitted.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl RevertDecoder {
+    /// The built-in error selectors and signatures.
+    pub const BUILTINS: [(&'static str, &'static str); 2] = [
+        ("08c379a0", "Error(string)"),
+        ("4e487b71", "Panic(uint256)"),
+    ];
+
+    /// The `Panic(uint256)` codes and their descriptions.
+    pub const PANIC_CODES: [(u8, &'static str); 10] = [
+        (0x00, "Generic compiler-inserted panic."),
+        (0x01, "Assertion failed."),
+        (
+            0x11,
+            "Arithmetic operation overflowed or underflowed outside of an unchecked block.",
+        ),
+        (0x12, "Division or modulo by zero."),
+        (0x21, "Value too big or negative converted to an enum type."),
+        (0x22, "Incorrectly encoded storage byte array accessed."),
+        (0x31, "`.pop()` called on an empty array."),
+        (
+            0x32,
+            "Array, `bytesN`, or array slice accessed out of bounds.",
+        ),
+        (
+            0x41,
+            "Too much memory allocated, or an array created that is too large.",
+        ),
+        (
+            0x51,
+            "Zero-initialized variable of internal function type called.",
+        ),
+    ];
+
+    ///
+    /// Builds the decoder data from the contract ABIs, keyed by contract full path, and the revert
+    /// sites extracted from the AST.
+    ///
+    pub fn new(
+        abi_jsons: &BTreeMap<String, &serde_json::Value>,
+        revert_sites: &era_solc::StandardJsonOutputRevertSites,
+    ) -> Self {
+        let contracts = abi_jsons
+            .iter()
+            .filter_map(|(full_path, abi)| {
+                let sites = revert_sites.contracts.get(full_path.as_str());
+                let errors: BTreeMap<String, Error> = Interface::from_abi(abi)
+                    .errors
+                    .into_iter()
+                    .map(|(selector, signature)| {
+                        let reverts = sites
+                            .and_then(|sites| sites.get(selector.as_str()))
+                            .cloned()
+                            .unwrap_or_default();
+                        (selector, Error { signature, reverts })
+                    })
+                    .collect();
+                (!errors.is_empty()).then(|| (full_path.to_owned(), Contract { errors }))
+            })
+            .collect();
+
+        Self {
+            builtins: Self::BUILTINS
+                .iter()
+                .map(|(selector, signature)| (selector.to_string(), signature.to_string()))
+                .collect(),
+            panics: Self::PANIC_CODES
+                .iter()
+                .map(|(code, description)| (format!("0x{code:02x}"), description.to_string()))
+                .collect(),
+            contracts,
+        }
+    }
+
+    ///
+    /// Writes the decoder data to the specified file in JSON format.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+}
@@ -0,0 +1,286 @@
+//!
+//! The JSON-RPC compilation server.
+//!
+
+use std::io::BufRead;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use std::time::Instant;
+
+use crate::standard_json;
+
+///
+/// The JSON-RPC compilation server.
+///
+/// Answers JSON-RPC 2.0 requests written one per line with responses written one per line.
+/// The process is kept alive between the requests, so the LLVM target initialization and the
+/// `solc` discovery are only done once, since their overhead dominates the compilation of small
+/// edits in editor and build tool integrations.
+///
+/// The supported methods are:
+/// - `compile` with the `input` standard JSON, and the optional `solc` executable and `profile`
+/// parameters, returning the standard JSON output
+/// - `version`, returning the `zksolc` version
+/// - `shutdown`, stopping the server
+///
+#[derive(Debug)]
+pub struct Server {
+    /// The default `solc` executable.
+    pub solc: Option<String>,
+    /// The compilation options shared by all requests.
+    pub options: standard_json::Options,
+    /// The build timeout, which is counted from the start of each request.
+    pub build_timeout: Option<Duration>,
+}
+
+///
+/// The JSON-RPC request.
+///
+#[derive(Debug, serde::Deserialize)]
+struct Request {
+    /// The request identifier. Notifications without it are not answered.
+    #[serde(default)]
+    id: Option<serde_json::Value>,
+    /// The method name.
+    method: String,
+    /// The method parameters.
+    #[serde(default)]
+    params: serde_json::Value,
+}
+
+///
+/// The `compile` method parameters.
+///
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct CompileParams {
+    /// The standard JSON input.
+    input: serde_json::Value,
+    /// The `solc` executable overriding the default one.
+    #[serde(default)]
+    solc: Option<String>,
+    /// The settings profile to apply.
+    #[serde(default)]
+    profile: Option<String>,
+}
+
+///
+/// The JSON-RPC error.
+///
+#[derive(Debug)]
+struct ResponseError {
+    /// The error code.
+    code: i64,
+    /// The error message.
+    message: String,
+}
+
+impl Server {
+    /// The JSON-RPC version.
+    pub const JSONRPC_VERSION: &'static str = "2.0";
+
+    /// The JSON-RPC error code of malformed requests.
+    pub const ERROR_CODE_PARSE: i64 = -32700;
+
+    /// The JSON-RPC error code of unknown methods.
+    pub const ERROR_CODE_METHOD_NOT_FOUND: i64 = -32601;
+
+    /// The JSON-RPC error code of invalid method parameters, such as invalid standard JSON input.
+    pub const ERROR_CODE_INVALID_PARAMS: i64 = -32602;
+
+    /// The JSON-RPC error code of compilation environment failures.
+    pub const ERROR_CODE_SERVER: i64 = -32000;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solc: Option<String>,
+        options: standard_json::Options,
+        build_timeout: Option<Duration>,
+    ) -> Self {
+        Self {
+            solc,
+            options,
+            build_timeout,
+        }
+    }
+
+    ///
+    /// Serves the requests from the standard input until it is closed or the server is shut down.
+    ///
+    pub fn run_stdio(&self) -> anyhow::Result<()> {
+        self.serve(std::io::stdin().lock(), std::io::stdout().lock())?;
+        Ok(())
+    }
+
+    ///
+    /// Serves the connections to the Unix socket at `path` one by one, until the server is
+    /// shut down. The socket file is removed afterwards.
+    ///
+    pub fn run_socket(&self, path: &Path) -> anyhow::Result<()> {
+        #[cfg(unix)]
+        {
+            let listener = std::os::unix::net::UnixListener::bind(path)
+                .map_err(|error| anyhow::anyhow!("Socket {path:?} binding: {error}"))?;
+            let result = self.serve_listener(&listener, path);
+            let _ = std::fs::remove_file(path);
+            result
+        }
+        #[cfg(not(unix))]
+        {
+            anyhow::bail!("Server socket {path:?} is only available on Unix-like systems.")
+        }
+    }
+
+    ///
+    /// Serves the connections accepted by the Unix socket `listener` until the server is shut down.
+    ///
+    #[cfg(unix)]
+    fn serve_listener(
+        &self,
+        listener: &std::os::unix::net::UnixListener,
+        path: &Path,
+    ) -> anyhow::Result<()> {
+        for stream in listener.incoming() {
+            let stream =
+                stream.map_err(|error| anyhow::anyhow!("Socket {path:?} accepting: {error}"))?;
+            let reader = std::io::BufReader::new(
+                stream
+                    .try_clone()
+                    .map_err(|error| anyhow::anyhow!("Socket {path:?} reading: {error}"))?,
+            );
+            if self.serve(reader, stream)? {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Answers the requests read from `reader` until it is exhausted or the server is shut down.
+    ///
+    /// Returns whether the server has been shut down.
+    ///
+    pub fn serve(&self, reader: impl BufRead, mut writer: impl Write) -> anyhow::Result<bool> {
+        for line in reader.lines() {
+            let line = line.map_err(|error| anyhow::anyhow!("Request reading: {error}"))?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let (response, is_shutdown) = self.handle(line.as_str());
+            if let Some(response) = response {
+                writeln!(writer, "{response}")
+                    .and_then(|()| writer.flush())
+                    .map_err(|error| anyhow::anyhow!("Response writing: {error}"))?;
+            }
+            if is_shutdown {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    ///
+    /// Handles the request `line`, and returns the response, if any, and whether the server must
+    /// be shut down.
+    ///
+    fn handle(&self, line: &str) -> (Option<serde_json::Value>, bool) {
+        let request: Request = match serde_json::from_str(line) {
+            Ok(request) => request,
+            Err(error) => {
+                let error =
+                    ResponseError::new(Self::ERROR_CODE_PARSE, format!("Request parsing: {error}"));
+                return (
+                    Some(Self::response(serde_json::Value::Null, Err(error))),
+                    false,
+                );
+            }
+        };
+
+        let is_shutdown = request.method == "shutdown";
+        let result = match request.method.as_str() {
+            "compile" => self.compile(request.params),
+            "version" => Ok(serde_json::json!({ "version": crate::version() })),
+            "shutdown" => Ok(serde_json::Value::Null),
+            method => Err(ResponseError::new(
+                Self::ERROR_CODE_METHOD_NOT_FOUND,
+                format!("Method `{method}` not found."),
+            )),
+        };
+        let response = request.id.map(|id| Self::response(id, result));
+        (response, is_shutdown)
+    }
+
+    ///
+    /// Compiles the standard JSON input of the `compile` request, and returns the standard JSON
+    /// output.
+    ///
+    fn compile(&self, params: serde_json::Value) -> Result<serde_json::Value, ResponseError> {
+        let mut options = self.options.clone();
+        options.build.deadline = self
+            .build_timeout
+            .map(|build_timeout| Instant::now() + build_timeout);
+
+        let invalid_params =
+            |error: String| ResponseError::new(Self::ERROR_CODE_INVALID_PARAMS, error);
+
+        let params: CompileParams = serde_json::from_value(params)
+            .map_err(|error| invalid_params(format!("Compilation parameters parsing: {error}")))?;
+        let mut solc_input =
+            era_solc::StandardJsonInput::try_from_str(params.input.to_string().as_str())
+                .map_err(|error| invalid_params(error.to_string()))?;
+        solc_input
+            .settings
+            .apply_profile(params.profile)
+            .map_err(|error| invalid_params(error.to_string()))?;
+
+        let server_error =
+            |error: anyhow::Error| ResponseError::new(Self::ERROR_CODE_SERVER, error.to_string());
+        let solc_compiler = match params.solc.as_deref().or(self.solc.as_deref()) {
+            Some(executable) => {
+                Some(era_solc::Compiler::try_from_path(executable).map_err(server_error)?)
+            }
+            None => None,
+        };
+        let solc_output =
+            standard_json::compile(solc_input, solc_compiler.as_ref(), options, &mut vec![])
+                .map_err(server_error)?;
+        serde_json::to_value(&solc_output)
+            .map_err(|error| server_error(anyhow::anyhow!("Standard JSON output: {error}")))
+    }
+
+    ///
+    /// Returns the response with the `result` of the request `id`.
+    ///
+    fn response(
+        id: serde_json::Value,
+        result: Result<serde_json::Value, ResponseError>,
+    ) -> serde_json::Value {
+        match result {
+            Ok(result) => serde_json::json!({
+                "jsonrpc": Self::JSONRPC_VERSION,
+                "id": id,
+                "result": result,
+            }),
+            Err(error) => serde_json::json!({
+                "jsonrpc": Self::JSONRPC_VERSION,
+                "id": id,
+                "error": {
+                    "code": error.code,
+                    "message": error.message,
+                },
+            }),
+        }
+    }
+}
+
+impl ResponseError {
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(code: i64, message: String) -> Self {
+        Self { code, message }
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The deterministic shuffling of the compilation order.
+//!
+
+///
+/// Permutes the `items` deterministically according to the shuffling `seed`.
+///
+/// Used to randomize the contract scheduling and worker assignment, in order to flush out the
+/// output depending on the compilation order. Does nothing if the seed is not set.
+///
+pub fn shuffle<T>(seed: Option<u64>, items: &mut [T]) {
+    let Some(mut state) = seed else {
+        return;
+    };
+    for index in (1..items.len()).rev() {
+        let other = (next(&mut state) % (index as u64 + 1)) as usize;
+        items.swap(index, other);
+    }
+}
+
+///
+/// Collects the `items` into a vector permuted deterministically according to the shuffling `seed`.
+///
+/// Used to randomize the map iteration orders, in order to flush out the output depending on them.
+/// Keeps the original order if the seed is not set.
+///
+pub fn shuffled<T>(seed: Option<u64>, items: impl IntoIterator<Item = T>) -> Vec<T> {
+    let mut items: Vec<T> = items.into_iter().collect();
+    shuffle(seed, items.as_mut_slice());
+    items
+}
+
+///
+/// Returns the next pseudo-random number of the SplitMix64 generator.
+///
+fn next(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9E3779B97F4A7C15);
+    let mut value = *state;
+    value = (value ^ (value >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94D049BB133111EB);
+    value ^ (value >> 31)
+}
@@ -0,0 +1,244 @@
+//!
+//! The partitioning of sources by their `solc` version pragmas.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::path::PathBuf;
+
+use crate::virtual_file_system::VirtualFileSystem;
+
+///
+/// The input sources compiled with the same `solc` version.
+///
+#[derive(Debug, Clone)]
+pub struct SolcPartition {
+    /// The `solc` compiler.
+    pub solc: era_solc::Compiler,
+    /// The input source paths.
+    pub paths: Vec<PathBuf>,
+}
+
+///
+/// The version requirement of a `pragma solidity` directive.
+///
+/// The version must match any of the alternatives separated by `||`.
+///
+type Requirement = Vec<semver::VersionReq>;
+
+impl SolcPartition {
+    /// The last patch versions of each supported minor `solc` version.
+    pub const LAST_PATCH_VERSIONS: [(u64, u64, u64); 5] =
+        [(0, 4, 26), (0, 5, 17), (0, 6, 12), (0, 7, 6), (0, 8, 28)];
+
+    ///
+    /// Partitions the input `paths` by the `solc` versions satisfying the pragmas of the sources
+    /// and all sources they import.
+    ///
+    /// The highest installed version satisfying the pragmas is preferred. If there is no such
+    /// version, the highest released version is installed. The imports are resolved to source names
+    /// like in [`VirtualFileSystem::source_name`], and only the sources found in the base path,
+    /// the include paths, or the current directory are followed.
+    ///
+    /// The partitions are sorted by `solc` version in ascending order.
+    ///
+    pub fn try_from_paths(
+        paths: &[PathBuf],
+        remappings: &BTreeSet<String>,
+        base_path: Option<&str>,
+        include_paths: &[String],
+        installer: &era_solc::Installer,
+    ) -> anyhow::Result<Vec<Self>> {
+        let remappings = era_solc::StandardJsonInputRemapping::try_from_strings(remappings)?;
+        let installed = installer.installed_versions();
+        let released = Self::released_versions();
+
+        let mut requirements = BTreeMap::new();
+        let mut partitions: BTreeMap<semver::Version, Vec<PathBuf>> = BTreeMap::new();
+        for path in paths.iter() {
+            let mut closure = BTreeSet::new();
+            Self::collect_requirements(
+                era_solc::path::normalize_path(path.as_path()).as_str(),
+                path.as_path(),
+                &remappings,
+                base_path,
+                include_paths,
+                &mut closure,
+                &mut requirements,
+            )?;
+            let is_satisfied = |version: &&semver::Version| {
+                closure
+                    .iter()
+                    .filter_map(|path| requirements.get(path))
+                    .flatten()
+                    .all(|requirement: &Requirement| {
+                        requirement
+                            .iter()
+                            .any(|alternative| alternative.matches(version))
+                    })
+            };
+            let version = installed
+                .iter()
+                .rev()
+                .find(is_satisfied)
+                .or_else(|| released.iter().rev().find(is_satisfied))
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "No supported `solc` version satisfies the pragmas of {path:?} and the sources it imports."
+                    )
+                })?;
+            partitions
+                .entry(version.to_owned())
+                .or_default()
+                .push(path.to_owned());
+        }
+
+        partitions
+            .into_iter()
+            .map(|(version, paths)| {
+                Ok(Self {
+                    solc: installer.resolve(&version)?,
+                    paths,
+                })
+            })
+            .collect()
+    }
+
+    ///
+    /// Collects the requirements of the source `name` at `path` and the sources it imports.
+    ///
+    /// The requirements of each source are keyed by its canonical path.
+    ///
+    fn collect_requirements(
+        name: &str,
+        path: &Path,
+        remappings: &BTreeSet<era_solc::StandardJsonInputRemapping>,
+        base_path: Option<&str>,
+        include_paths: &[String],
+        closure: &mut BTreeSet<PathBuf>,
+        requirements: &mut BTreeMap<PathBuf, Vec<Requirement>>,
+    ) -> anyhow::Result<()> {
+        let path = path
+            .canonicalize()
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        if !closure.insert(path.clone()) {
+            return Ok(());
+        }
+        let source = std::fs::read_to_string(path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+
+        let mut pragmas = Vec::new();
+        for statement in Self::strip_comments(source.as_str()).split(';') {
+            if let Some(pragma) = statement.trim().strip_prefix("pragma solidity") {
+                let requirement = Self::parse_pragma(pragma).map_err(|error| {
+                    anyhow::anyhow!("{path:?}: invalid `pragma solidity{pragma}`: {error}")
+                })?;
+                pragmas.push(requirement);
+            }
+        }
+        requirements.insert(path.clone(), pragmas);
+
+        for import in VirtualFileSystem::imports(source.as_str()).into_iter() {
+            let import = VirtualFileSystem::source_name(name, import.as_str(), remappings);
+            let path = base_path
+                .map(PathBuf::from)
+                .into_iter()
+                .chain(include_paths.iter().map(PathBuf::from))
+                .chain(std::iter::once(PathBuf::new()))
+                .map(|root| root.join(import.as_str()))
+                .find(|path| path.is_file());
+            if let Some(path) = path {
+                Self::collect_requirements(
+                    import.as_str(),
+                    path.as_path(),
+                    remappings,
+                    base_path,
+                    include_paths,
+                    closure,
+                    requirements,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
+    ///
+    /// Parses the version expression of a `pragma solidity` directive.
+    ///
+    /// Unlike `semver`, Solidity treats bare versions as exact ones, and separates comparators
+    /// with spaces instead of commas.
+    ///
+    fn parse_pragma(expression: &str) -> anyhow::Result<Requirement> {
+        expression
+            .split("||")
+            .map(|alternative| {
+                let mut comparators: Vec<String> = Vec::new();
+                let mut operator = String::new();
+                let mut is_hyphen_range = false;
+                for token in alternative.split_whitespace() {
+                    if token == "-" {
+                        if let Some(lower) = comparators.last_mut() {
+                            *lower = format!(">={}", lower.trim_start_matches('='));
+                        }
+                        is_hyphen_range = true;
+                        continue;
+                    }
+                    if token.chars().all(|character| "<>=^~".contains(character)) {
+                        operator.push_str(token);
+                        continue;
+                    }
+                    let token = format!("{}{token}", std::mem::take(&mut operator));
+                    let token = if is_hyphen_range {
+                        is_hyphen_range = false;
+                        format!("<={token}")
+                    } else if token.starts_with(|character: char| character.is_ascii_digit()) {
+                        format!("={token}")
+                    } else {
+                        token
+                    };
+                    comparators.push(token);
+                }
+                semver::VersionReq::parse(comparators.join(", ").as_str())
+                    .map_err(|error| anyhow::anyhow!("{error}"))
+            })
+            .collect()
+    }
+
+    ///
+    /// Removes the line and block comments from the source code.
+    ///
+    pub(crate) fn strip_comments(source: &str) -> String {
+        let mut result = String::with_capacity(source.len());
+        let mut rest = source;
+        while !rest.is_empty() {
+            if let Some(comment) = rest.strip_prefix("//") {
+                rest = comment.find('\n').map_or("", |end| &comment[end..]);
+            } else if let Some(comment) = rest.strip_prefix("/*") {
+                rest = comment.find("*/").map_or("", |end| &comment[end + 2..]);
+                result.push(' ');
+            } else {
+                let character = rest.chars().next().expect("Always exists");
+                result.push(character);
+                rest = &rest[character.len_utf8()..];
+            }
+        }
+        result
+    }
+
+    ///
+    /// Returns all released versions of `solc` supported by `zksolc` in ascending order.
+    ///
+    fn released_versions() -> Vec<semver::Version> {
+        Self::LAST_PATCH_VERSIONS
+            .iter()
+            .flat_map(|(major, minor, last_patch)| {
+                (0..=*last_patch).map(|patch| semver::Version::new(*major, *minor, patch))
+            })
+            .filter(|version| {
+                version >= &era_solc::Compiler::FIRST_SUPPORTED_VERSION
+                    && version <= &era_solc::Compiler::LAST_SUPPORTED_VERSION
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,96 @@
+//!
+//! The per-function register allocator spill report.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// The register allocator pass name in the LLVM remarks.
+pub const REMARK_PASS: &str = "regalloc";
+
+/// The spill statistics remark name.
+pub const REMARK_NAME: &str = "SpillReloadCopies";
+
+/// The string argument distinguishing the function-wide statistics from the per-loop ones.
+pub const REMARK_FUNCTION_SCOPE: &str = "generated in function";
+
+///
+/// The spill statistics of a function.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct FunctionSpills {
+    /// The number of spills.
+    pub spills: u64,
+    /// The number of reloads.
+    pub reloads: u64,
+}
+
+///
+/// The per-function register allocator spill report.
+///
+/// The report is extracted from the LLVM optimization remarks, so functions are identified
+/// by their LLVM names, and the statistics of equally named functions of different contracts
+/// are summed up, e.g. for the `__entry` function.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct SpillReport {
+    /// The spill statistics, grouped by function name.
+    pub functions: BTreeMap<String, FunctionSpills>,
+}
+
+impl SpillReport {
+    ///
+    /// Extracts the report from the YAML remarks file at `path`.
+    ///
+    pub fn try_from_remarks(path: &Path) -> anyhow::Result<Self> {
+        let remarks = std::fs::read_to_string(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+
+        let mut report = Self::default();
+        for remark in remarks.split("\n--- ") {
+            let mut pass = None;
+            let mut name = None;
+            let mut function = None;
+            let mut spills = None;
+            let mut reloads = None;
+            let mut is_function_scope = false;
+            for line in remark.lines() {
+                let line = line.trim_start().trim_start_matches("- ");
+                let Some((key, value)) = line.split_once(':') else {
+                    continue;
+                };
+                let value = value.trim().trim_matches('\'').trim_matches('"');
+                match key {
+                    "Pass" => pass = Some(value),
+                    "Name" => name = Some(value),
+                    "Function" => function = Some(value),
+                    "NumSpills" => spills = value.parse::<u64>().ok(),
+                    "NumReloads" => reloads = value.parse::<u64>().ok(),
+                    "String" if value.trim() == REMARK_FUNCTION_SCOPE => is_function_scope = true,
+                    _ => {}
+                }
+            }
+            if pass != Some(REMARK_PASS) || name != Some(REMARK_NAME) || !is_function_scope {
+                continue;
+            }
+            let Some(function) = function else {
+                continue;
+            };
+
+            let entry = report.functions.entry(function.to_owned()).or_default();
+            entry.spills += spills.unwrap_or_default();
+            entry.reloads += reloads.unwrap_or_default();
+        }
+        Ok(report)
+    }
+
+    ///
+    /// Writes the report to the JSON file at `path`.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        let report = serde_json::to_vec_pretty(self).expect("Always valid");
+        std::fs::write(path, report)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,435 @@
+//!
+//! The standard JSON compilation API.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::build_cache::BuildCache;
+use crate::build_config::BuildConfig;
+use crate::contract_filter::ContractFilter;
+use crate::events;
+use crate::interface_freeze::InterfaceFreeze;
+use crate::project::Project;
+
+///
+/// The standard JSON compilation options, which are not part of the standard JSON input.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    /// The code generator, overridden by `settings.forceEVMLA` of the input.
+    pub codegen: Option<era_solc::StandardJsonInputCodegen>,
+    /// Whether to enable EraVM extensions, in addition to `settings.enableEraVMExtensions`.
+    pub enable_eravm_extensions: bool,
+    /// Whether to detect missing libraries, in addition to `settings.detectMissingLibraries`.
+    pub detect_missing_libraries: bool,
+    /// The `solc` base path.
+    pub base_path: Option<String>,
+    /// The `solc` include paths.
+    pub include_paths: Vec<String>,
+    /// The `solc` allowed paths.
+    pub allow_paths: Option<String>,
+    /// The build configuration.
+    pub build: BuildConfig,
+    /// The debug output config.
+    pub debug_config: Option<era_compiler_llvm_context::DebugConfig>,
+}
+
+///
+/// Compiles the standard JSON `input` for the EraVM target, and returns the standard JSON output.
+///
+/// This is the entry point for embedding the compiler into Rust tooling. The output is the same
+/// as the one printed by `zksolc --standard-json`, so the compilation errors are reported in its
+/// `errors` field, while the returned error is reserved for failures of the environment, such as
+/// a `solc` executable that cannot be run.
+///
+/// Solidity input requires `solc_compiler`, and falls back to the `solc` executable in `${PATH}`.
+/// Yul input is only validated with `solc_compiler` if it is provided. LLVM IR and EraVM assembly
+/// input must be compiled without it. The named settings profile, if any, must be applied in
+/// advance with `era_solc::StandardJsonInputSettings::apply_profile`.
+///
+/// The caller must initialize the EraVM target with `era_compiler_llvm_context::initialize_target`.
+/// The contracts are compiled on the threads of the current process, unless subprocesses are
+/// requested in the build configuration. As LLVM options are global to a process, per-contract
+/// LLVM options are only supported with subprocesses.
+///
+pub fn compile(
+    mut solc_input: era_solc::StandardJsonInput,
+    solc_compiler: Option<&era_solc::Compiler>,
+    options: Options,
+    messages: &mut Vec<era_solc::StandardJsonOutputError>,
+) -> anyhow::Result<era_solc::StandardJsonOutput> {
+    let Options {
+        codegen,
+        enable_eravm_extensions,
+        detect_missing_libraries,
+        base_path,
+        include_paths,
+        allow_paths,
+        build: mut config,
+        debug_config,
+    } = options;
+    config.debug_assertions |= solc_input.settings.debug_assertions;
+
+    let start_time = std::time::Instant::now();
+    let language = solc_input.language;
+    let prune_output = solc_input.settings.selection_to_prune();
+    if !config.check_standard_json_sources(&solc_input.sources, messages) {
+        return Ok(
+            era_solc::StandardJsonOutput::new(&solc_input.sources, messages)
+                .finalize(&prune_output),
+        );
+    }
+    let linker_symbols = solc_input.settings.libraries.as_linker_symbols()?;
+
+    let mut optimizer_settings = era_compiler_llvm_context::OptimizerSettings::try_from_cli(
+        solc_input.settings.optimizer.mode,
+    )?;
+    if solc_input
+        .settings
+        .optimizer
+        .fallback_to_optimizing_for_size
+    {
+        optimizer_settings.enable_fallback_to_size();
+    }
+    let mut llvm_options = solc_input.settings.llvm_options.global.clone();
+    if let Some(loop_optimization) = solc_input.settings.optimizer.loop_optimization {
+        llvm_options.extend(loop_optimization.llvm_options());
+    }
+    if let Some(spill_strategy) = solc_input.settings.optimizer.spill_strategy {
+        llvm_options.extend(spill_strategy.llvm_options());
+    }
+    let balance_lowering = solc_input.settings.optimizer.balance_lowering;
+
+    let codegen = if solc_input.settings.force_evmla {
+        Some(era_solc::StandardJsonInputCodegen::EVMLA)
+    } else {
+        codegen
+    };
+    let enable_eravm_extensions =
+        solc_input.settings.enable_eravm_extensions || enable_eravm_extensions;
+    let detect_missing_libraries =
+        solc_input.settings.detect_missing_libraries || detect_missing_libraries;
+    let library_deployer = solc_input.settings.library_deployer;
+    let metadata_hash_type = solc_input.settings.metadata.hash_type;
+    let output_assembly = [
+        era_solc::StandardJsonInputSelector::EraVMAssembly,
+        era_solc::StandardJsonInputSelector::EraVMAssemblyJSON,
+    ]
+    .iter()
+    .any(|selector| solc_input.settings.output_selection.contains(selector));
+
+    let reachable_selectors = std::mem::take(&mut solc_input.settings.reachable_selectors);
+    let immutables = std::mem::take(&mut solc_input.settings.immutables);
+    let contract_llvm_options = std::mem::take(&mut solc_input.settings.llvm_options.contracts);
+    let policy = solc_input.settings.policy.clone();
+    let target_contract = solc_input.settings.contract.take();
+    let output_summary = solc_input.settings.output_summary;
+    let profile = solc_input.settings.profile.take();
+    let contract_filter = ContractFilter::new(
+        std::mem::take(&mut solc_input.settings.only),
+        target_contract.clone(),
+    );
+    let output_timings = solc_input.settings.output_timings;
+    let parallel = solc_input.settings.parallel.clone();
+
+    let mut solc_duration = None;
+    let mut build_cache = None;
+    let solc_compiler = solc_compiler
+        .cloned()
+        .map(|solc_compiler| solc_compiler.with_deadline(config.deadline));
+    let (mut solc_output, solc_version, mut project) = match (language, solc_compiler.as_ref()) {
+        (era_solc::StandardJsonInputLanguage::Solidity, solc_compiler) => {
+            let default_solc_compiler;
+            let solc_compiler = match solc_compiler {
+                Some(solc_compiler) => solc_compiler,
+                None => {
+                    default_solc_compiler =
+                        era_solc::Compiler::try_from_default()?.with_deadline(config.deadline);
+                    &default_solc_compiler
+                }
+            };
+
+            let solc_codegen =
+                era_solc::StandardJsonInputCodegen::new(&solc_compiler.version, codegen);
+            solc_input.extend_selection(era_solc::StandardJsonInputSelection::new_required(
+                solc_codegen,
+            ));
+            let interface_freeze = solc_input.settings.interface_freeze.take();
+            let abi_requested = solc_input
+                .settings
+                .output_selection
+                .contains(&era_solc::StandardJsonInputSelector::ABI);
+            if interface_freeze.is_some() {
+                solc_input.extend_selection(era_solc::StandardJsonInputSelection::new(vec![
+                    era_solc::StandardJsonInputSelector::ABI,
+                ]));
+            }
+
+            config.subscribers.emit(events::Event::SolcStarted);
+            let solc_start_time = std::time::Instant::now();
+            let mut solc_output = solc_compiler.standard_json(
+                &mut solc_input,
+                messages,
+                base_path,
+                include_paths,
+                allow_paths,
+            )?;
+            solc_duration = Some(solc_start_time.elapsed());
+            config.subscribers.emit(events::Event::SolcFinished {
+                duration: solc_start_time.elapsed(),
+            });
+            config
+                .subscribers
+                .emit_warnings(solc_output.errors.as_slice());
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+            if let Some(ref interface_freeze) = interface_freeze {
+                InterfaceFreeze::check_standard_json(
+                    interface_freeze.as_path(),
+                    &mut solc_output,
+                    !abi_requested,
+                );
+                if solc_output.has_errors() {
+                    return Ok(solc_output.finalize(&prune_output));
+                }
+            }
+
+            if let Some(ref directory) = solc_input.settings.build_cache {
+                let settings = BuildCache::settings(
+                    &solc_compiler.version,
+                    &solc_input.settings,
+                    (
+                        solc_codegen.to_string(),
+                        enable_eravm_extensions,
+                        metadata_hash_type,
+                        &optimizer_settings,
+                        &llvm_options,
+                        &contract_llvm_options,
+                        balance_lowering,
+                        output_assembly,
+                        &reachable_selectors,
+                        &immutables,
+                        &profile,
+                    ),
+                );
+                build_cache = Some(BuildCache::new(
+                    directory.as_path(),
+                    settings.as_str(),
+                    &solc_output.import_graph,
+                    &solc_input.sources,
+                ));
+            }
+
+            let project = Project::try_from_solc_output(
+                solc_input.settings.libraries,
+                solc_codegen,
+                &mut solc_output,
+                solc_compiler,
+                &config,
+                debug_config.as_ref(),
+            )?;
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            (solc_output, Some(solc_compiler.version.clone()), project)
+        }
+        (era_solc::StandardJsonInputLanguage::Yul, Some(solc_compiler)) => {
+            config.subscribers.emit(events::Event::SolcStarted);
+            let solc_start_time = std::time::Instant::now();
+            let mut solc_output =
+                solc_compiler.validate_yul_standard_json(&mut solc_input, messages)?;
+            solc_duration = Some(solc_start_time.elapsed());
+            config.subscribers.emit(events::Event::SolcFinished {
+                duration: solc_start_time.elapsed(),
+            });
+            config
+                .subscribers
+                .emit_warnings(solc_output.errors.as_slice());
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            let project = Project::try_from_yul_sources(
+                solc_input.sources,
+                solc_input.settings.libraries,
+                Some(&mut solc_output),
+                Some(&solc_compiler.version),
+                &config,
+                debug_config.as_ref(),
+            )?;
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            (solc_output, Some(solc_compiler.version.clone()), project)
+        }
+        (era_solc::StandardJsonInputLanguage::Yul, None) => {
+            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
+
+            let project = Project::try_from_yul_sources(
+                solc_input.sources,
+                solc_input.settings.libraries,
+                Some(&mut solc_output),
+                None,
+                &config,
+                debug_config.as_ref(),
+            )?;
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            (solc_output, None, project)
+        }
+        (era_solc::StandardJsonInputLanguage::LLVMIR, Some(_)) => {
+            anyhow::bail!(era_solc::ExitCodeError::new(
+                era_solc::ExitCode::InputError,
+                "LLVM IR projects cannot be compiled with `solc`.".to_owned(),
+            ))
+        }
+        (era_solc::StandardJsonInputLanguage::LLVMIR, None) => {
+            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
+
+            let project = Project::try_from_llvm_ir_sources(
+                solc_input.sources,
+                solc_input.settings.libraries,
+                Some(&mut solc_output),
+            )?;
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            (solc_output, None, project)
+        }
+        (era_solc::StandardJsonInputLanguage::EraVMAssembly, Some(_)) => {
+            anyhow::bail!(era_solc::ExitCodeError::new(
+                era_solc::ExitCode::InputError,
+                "EraVM assembly projects cannot be compiled with `solc`.".to_owned(),
+            ))
+        }
+        (era_solc::StandardJsonInputLanguage::EraVMAssembly, None) => {
+            let mut solc_output = era_solc::StandardJsonOutput::new(&solc_input.sources, messages);
+            solc_output.error_format = solc_input.settings.error_format;
+            solc_output.stream_output = solc_input.settings.stream_output;
+
+            let project = Project::try_from_eravm_assembly_sources(
+                solc_input.sources,
+                Some(&mut solc_output),
+            )?;
+            if solc_output.has_errors() {
+                return Ok(solc_output.finalize(&prune_output));
+            }
+
+            (solc_output, None, project)
+        }
+    };
+
+    if detect_missing_libraries {
+        let missing_libraries = project.get_missing_libraries();
+        missing_libraries.write_to_standard_json(&mut solc_output, solc_version.as_ref());
+        return Ok(solc_output.finalize(&prune_output));
+    }
+
+    solc_output.errors.extend(project.check_policy(&policy));
+    if solc_output.has_errors() {
+        return Ok(solc_output.finalize(&prune_output));
+    }
+
+    if let Some(ref contract_filter) = contract_filter {
+        if let Err(error) = project.apply_contract_filter(contract_filter) {
+            solc_output.push_error(None, error);
+            return Ok(solc_output.finalize(&prune_output));
+        }
+        solc_output.retain_contracts(&project.contracts.keys().cloned().collect());
+    }
+    project.apply_output_selection(&solc_input.settings.output_selection);
+    project.apply_reachable_selectors(&reachable_selectors)?;
+    project.apply_immutables(&immutables)?;
+    if !config.subprocesses && !contract_llvm_options.is_empty() {
+        solc_output.push_error(
+            None,
+            anyhow::anyhow!("Per-contract LLVM options require compiling in subprocesses, as LLVM options are global to a process."),
+        );
+        return Ok(solc_output.finalize(&prune_output));
+    }
+    project.apply_llvm_options(&contract_llvm_options, messages);
+    if let Some(profile) = profile {
+        project.set_profile(profile.as_str());
+    }
+    project.set_balance_lowering(balance_lowering);
+    let deployed_libraries = if library_deployer {
+        project.add_library_deployer(debug_config.as_ref())?
+    } else {
+        BTreeSet::new()
+    };
+
+    if config.cache_warming && build_cache.is_none() {
+        return Ok(solc_output.finalize(&prune_output));
+    }
+
+    let build = parallel.install(|| {
+        project.compile_to_eravm(
+            messages,
+            enable_eravm_extensions,
+            metadata_hash_type,
+            optimizer_settings,
+            llvm_options,
+            output_assembly,
+            build_cache.as_ref(),
+            &config,
+            debug_config,
+        )
+    })??;
+    if build.has_errors() {
+        build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
+        return Ok(solc_output.finalize(&prune_output));
+    }
+    if config.cache_warming {
+        return Ok(solc_output.finalize(&prune_output));
+    }
+
+    let mut build = build
+        .link(linker_symbols.clone())
+        .link_deployed_libraries(linker_symbols, &deployed_libraries);
+    let selected_contract = match target_contract {
+        Some(target_contract) => match build.select_contract(target_contract.as_str()) {
+            Ok(full_path) => build
+                .results
+                .get(full_path.as_str())
+                .and_then(|result| result.as_ref().ok())
+                .map(|contract| contract.name.clone()),
+            Err(error) => {
+                solc_output
+                    .errors
+                    .push(era_solc::StandardJsonOutputError::new_error(
+                        error, None, None,
+                    ));
+                return Ok(solc_output.finalize(&prune_output));
+            }
+        },
+        None => None,
+    };
+    build.write_to_standard_json(&mut solc_output, solc_version.as_ref())?;
+    if let Some(name) = selected_contract {
+        solc_output.retain_contract(
+            name.path.as_str(),
+            name.name.as_deref().unwrap_or(name.path.as_str()),
+        );
+    }
+    if output_summary {
+        solc_output.set_summary(
+            build_cache.as_ref().map(BuildCache::statistics),
+            solc_duration,
+            start_time.elapsed(),
+        );
+    }
+    if output_timings {
+        solc_output.set_timings(solc_duration);
+    }
+    Ok(solc_output.finalize(&prune_output))
+}
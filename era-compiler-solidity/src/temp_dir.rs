@@ -0,0 +1,137 @@
+//!
+//! The temporary directory for intermediate files.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+/// The environment variables pointing subprocesses to the temporary directory.
+pub const ENVIRONMENT_VARIABLES: [&str; 3] = era_solc::Compiler::TEMP_DIR_ENVIRONMENT_VARIABLES;
+
+///
+/// The temporary directory cleanup policy.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CleanupPolicy {
+    /// The directory is removed after the compilation.
+    #[default]
+    AlwaysClean,
+    /// The directory is kept if the compilation has failed.
+    KeepOnError,
+    /// The directory is never removed.
+    KeepAlways,
+}
+
+impl FromStr for CleanupPolicy {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "always-clean" => Ok(Self::AlwaysClean),
+            "keep-on-error" => Ok(Self::KeepOnError),
+            "keep-always" => Ok(Self::KeepAlways),
+            string => anyhow::bail!(
+                "Invalid temporary directory cleanup policy: `{string}`. Available options: {}.",
+                [Self::AlwaysClean, Self::KeepOnError, Self::KeepAlways]
+                    .into_iter()
+                    .map(|policy| policy.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for CleanupPolicy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::AlwaysClean => write!(f, "always-clean"),
+            Self::KeepOnError => write!(f, "keep-on-error"),
+            Self::KeepAlways => write!(f, "keep-always"),
+        }
+    }
+}
+
+///
+/// The temporary directory for intermediate files.
+///
+/// A dedicated directory is created for each compiler run, so concurrent runs do not interfere.
+/// It is removed according to the cleanup policy by [`TempDir::finish`], or when dropped, with
+/// the compilation considered failed in the latter case.
+///
+#[derive(Debug)]
+pub struct TempDir {
+    /// The directory path.
+    path: PathBuf,
+    /// The cleanup policy.
+    policy: CleanupPolicy,
+    /// Whether the compilation has succeeded.
+    is_success: bool,
+}
+
+impl TempDir {
+    /// The directory name prefix.
+    pub const PREFIX: &'static str = "zksolc-";
+
+    ///
+    /// Creates the temporary directory inside `parent`, or the system temporary directory.
+    ///
+    /// The environment of this process is left intact. The subprocesses are pointed to the
+    /// directory via [`BuildConfig::temp_dir`](crate::BuildConfig::temp_dir), and `solc` via
+    /// [`era_solc::Compiler::with_temp_dir`].
+    ///
+    pub fn try_new(parent: Option<&Path>, policy: CleanupPolicy) -> anyhow::Result<Self> {
+        let parent = parent
+            .map(Path::to_path_buf)
+            .unwrap_or_else(std::env::temp_dir);
+        let path = parent.join(format!("{}{}", Self::PREFIX, std::process::id()));
+        std::fs::create_dir_all(path.as_path())
+            .map_err(|error| anyhow::anyhow!("Temporary directory {path:?} creating: {error}"))?;
+
+        Ok(Self {
+            path,
+            policy,
+            is_success: false,
+        })
+    }
+
+    ///
+    /// Returns the directory path.
+    ///
+    pub fn path(&self) -> &Path {
+        self.path.as_path()
+    }
+
+    ///
+    /// Records whether the compilation has succeeded, and applies the cleanup policy.
+    ///
+    pub fn finish(mut self, is_success: bool) -> anyhow::Result<()> {
+        self.is_success = is_success;
+        self.cleanup()
+    }
+
+    ///
+    /// Removes the directory if required by the cleanup policy.
+    ///
+    fn cleanup(&self) -> anyhow::Result<()> {
+        let is_kept = match self.policy {
+            CleanupPolicy::AlwaysClean => false,
+            CleanupPolicy::KeepOnError => !self.is_success,
+            CleanupPolicy::KeepAlways => true,
+        };
+        if is_kept || !self.path.exists() {
+            return Ok(());
+        }
+
+        std::fs::remove_dir_all(self.path.as_path()).map_err(|error| {
+            anyhow::anyhow!("Temporary directory {:?} removing: {error}", self.path)
+        })
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = self.cleanup();
+    }
+}
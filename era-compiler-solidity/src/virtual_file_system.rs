@@ -0,0 +1,201 @@
+//!
+//! The in-memory virtual file system.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::solc_partition::SolcPartition;
+
+///
+/// The in-memory virtual file system.
+///
+/// Holds the Solidity sources of a project keyed by their source names, so the project can be
+/// compiled without touching the disk, e.g. from the unsaved buffers of an IDE or from a test
+/// harness. The imports are resolved against the sources in memory only, so `solc` never has to
+/// fall back to reading files.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct VirtualFileSystem {
+    /// The source code, keyed by source name.
+    pub files: BTreeMap<String, String>,
+}
+
+impl VirtualFileSystem {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(files: BTreeMap<String, String>) -> Self {
+        Self { files }
+    }
+
+    ///
+    /// Returns the standard JSON input with the sources in memory.
+    ///
+    /// The counterpart of [`era_solc::StandardJsonInput::try_from_solidity_paths`], which reads the
+    /// sources from the disk. The library paths are remapped against the sources in memory, and all
+    /// imports and library sources must be resolved in memory.
+    ///
+    pub fn try_into_standard_json_input(
+        self,
+        libraries: &[String],
+        remappings: BTreeSet<String>,
+        optimizer: era_solc::StandardJsonInputOptimizer,
+        codegen: Option<era_solc::StandardJsonInputCodegen>,
+        evm_version: Option<era_compiler_common::EVMVersion>,
+        enable_eravm_extensions: bool,
+        output_selection: era_solc::StandardJsonInputSelection,
+        metadata: era_solc::StandardJsonInputMetadata,
+        llvm_options: Vec<String>,
+        suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+        suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+        detect_missing_libraries: bool,
+        via_ir: bool,
+    ) -> anyhow::Result<era_solc::StandardJsonInput> {
+        let parsed_remappings =
+            era_solc::StandardJsonInputRemapping::try_from_strings(&remappings)?;
+        let mut libraries = era_solc::StandardJsonInputLibraries::try_from(libraries)?;
+        libraries.apply_remappings(&parsed_remappings, |path| self.files.contains_key(path));
+        if let Some(library_file) = libraries
+            .as_inner()
+            .keys()
+            .find(|library_file| !self.files.contains_key(library_file.as_str()))
+        {
+            anyhow::bail!(
+                "Library source `{library_file}` is not found in the virtual file system."
+            );
+        }
+        let sources = self.try_into_sources(&parsed_remappings)?;
+
+        era_solc::StandardJsonInput::try_from_solidity_sources(
+            sources,
+            libraries,
+            remappings,
+            optimizer,
+            codegen,
+            evm_version,
+            enable_eravm_extensions,
+            output_selection,
+            metadata,
+            llvm_options,
+            suppressed_errors,
+            suppressed_warnings,
+            detect_missing_libraries,
+            via_ir,
+        )
+    }
+
+    ///
+    /// Returns the standard JSON input sources, checking that all imports are resolved in memory.
+    ///
+    pub fn try_into_sources(
+        self,
+        remappings: &BTreeSet<era_solc::StandardJsonInputRemapping>,
+    ) -> anyhow::Result<BTreeMap<String, era_solc::StandardJsonInputSource>> {
+        for (name, source) in self.files.iter() {
+            for import in Self::imports(source.as_str()) {
+                if self
+                    .resolve(name.as_str(), import.as_str(), remappings)
+                    .is_none()
+                {
+                    anyhow::bail!(
+                        "Source `{name}` imports `{import}`, which is not found in the virtual file system."
+                    );
+                }
+            }
+        }
+
+        Ok(self
+            .files
+            .into_iter()
+            .map(|(name, source)| (name, era_solc::StandardJsonInputSource::from(source)))
+            .collect())
+    }
+
+    ///
+    /// Resolves the `import` path of the source `name` to the name of a source in memory.
+    ///
+    /// Returns `None` if the source is not in memory.
+    ///
+    pub fn resolve(
+        &self,
+        name: &str,
+        import: &str,
+        remappings: &BTreeSet<era_solc::StandardJsonInputRemapping>,
+    ) -> Option<String> {
+        let import = Self::source_name(name, import, remappings);
+        self.files.contains_key(import.as_str()).then_some(import)
+    }
+
+    ///
+    /// Returns the name of the source imported by the source `name` with the `import` path.
+    ///
+    /// Like in `solc`, relative imports starting with `./` or `../` are resolved against the
+    /// directory of the importing source first, and then the `remappings` are applied.
+    ///
+    pub fn source_name(
+        name: &str,
+        import: &str,
+        remappings: &BTreeSet<era_solc::StandardJsonInputRemapping>,
+    ) -> String {
+        let import = if import.starts_with("./") || import.starts_with("../") {
+            Self::join(name, import)
+        } else {
+            import.to_owned()
+        };
+        era_solc::StandardJsonInputRemapping::apply(remappings, name, import.as_str())
+            .unwrap_or(import)
+    }
+
+    ///
+    /// Returns the paths of the `import` directives of the source code, in order of appearance.
+    ///
+    pub fn imports(source: &str) -> Vec<String> {
+        SolcPartition::strip_comments(source)
+            .split(';')
+            .filter_map(|statement| Self::import_path(statement.trim()))
+            .map(str::to_owned)
+            .collect()
+    }
+
+    ///
+    /// Returns the path of the `statement`, if it is an `import` directive.
+    ///
+    fn import_path(statement: &str) -> Option<&str> {
+        let statement = statement.strip_prefix("import")?;
+        if !statement
+            .starts_with(|character: char| character.is_whitespace() || "\"'{*".contains(character))
+        {
+            return None;
+        }
+        let start = statement.find(['"', '\''])?;
+        let quote = statement[start..].chars().next()?;
+        let length = statement[start + 1..].find(quote)?;
+        Some(&statement[start + 1..start + 1 + length])
+    }
+
+    ///
+    /// Joins the relative `import` path with the directory of the source `name`.
+    ///
+    /// The `.` segments are dropped, and the `..` ones remove the preceding segment. The `..` segments
+    /// leading out of a relative source name are kept, so such sources can still be found on disk.
+    ///
+    fn join(name: &str, import: &str) -> String {
+        let mut segments: Vec<&str> = name.split('/').collect();
+        segments.pop();
+        for segment in import.split('/') {
+            match segment {
+                "." => {}
+                ".." => match segments.last() {
+                    Some(&"") => {}
+                    Some(&"..") | None => segments.push(".."),
+                    Some(_) => {
+                        segments.pop();
+                    }
+                },
+                segment => segments.push(segment),
+            }
+        }
+        segments.join("/")
+    }
+}
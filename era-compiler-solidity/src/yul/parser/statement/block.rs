@@ -39,7 +39,9 @@ where
         }
 
         for function in functions.into_iter() {
+            let location = function.location;
             function.wrap().into_llvm(context)?;
+            crate::debug_assertions::tag_function(context, || format!("yul:function@{location}"))?;
         }
 
         context.set_current_function(current_function.as_str())?;
@@ -49,6 +51,12 @@ where
                 break;
             }
 
+            let provenance = (statement.kind(), statement.location());
+            let marker = crate::debug_assertions::mark(context);
+            let is_terminal = matches!(
+                statement,
+                Statement::Continue(_) | Statement::Break(_) | Statement::Leave(_)
+            );
             match statement {
                 Statement::Block(block) => {
                     block.wrap().into_llvm(context)?;
@@ -63,23 +71,26 @@ where
                 Statement::ForLoop(statement) => statement.wrap().into_llvm(context)?,
                 Statement::Continue(_location) => {
                     context.build_unconditional_branch(context.r#loop().continue_block)?;
-                    break;
                 }
                 Statement::Break(_location) => {
                     context.build_unconditional_branch(context.r#loop().join_block)?;
-                    break;
                 }
                 Statement::Leave(_location) => {
                     context.build_unconditional_branch(
                         context.current_function().borrow().return_block(),
                     )?;
-                    break;
                 }
                 statement => anyhow::bail!(
                     "{} Unexpected local statement: {statement:?}",
                     statement.location(),
                 ),
             }
+            crate::debug_assertions::tag(context, marker, || {
+                format!("yul:{}@{}", provenance.0, provenance.1)
+            })?;
+            if is_terminal {
+                break;
+            }
         }
 
         Ok(())
@@ -918,7 +918,7 @@ impl FunctionCall {
                 let arguments = self.pop_arguments_llvm::<D, 1>(context)?;
 
                 let address = arguments[0].into_int_value();
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                crate::balance_lowering::balance(context, address).map(Some)
             }
             Name::SelfBalance => {
                 let address = context
@@ -926,7 +926,7 @@ impl FunctionCall {
                     .expect("Always exists")
                     .into_int_value();
 
-                era_compiler_llvm_context::eravm_evm_ether_gas::balance(context, address).map(Some)
+                crate::balance_lowering::balance(context, address).map(Some)
             }
 
             Name::GasLimit => {
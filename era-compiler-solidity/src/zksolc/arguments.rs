@@ -9,6 +9,9 @@ use std::path::PathBuf;
 use clap::Parser;
 use path_slash::PathExt;
 
+use crate::command::Command;
+use crate::help::Topic;
+
 ///
 /// Compiles the provided Solidity input files (or use the standard input if no files
 /// are given or "-" is specified as a file name). Outputs the components based on the
@@ -18,8 +21,18 @@ use path_slash::PathExt;
 /// Example: zksolc ERC20.sol -O3 --bin --output-dir "./build/"
 ///
 #[derive(Debug, Parser)]
-#[command(about, long_about = None)]
+#[command(
+    about,
+    long_about = None,
+    after_help = Topic::render_index(),
+    args_conflicts_with_subcommands = true,
+    disable_help_subcommand = true
+)]
 pub struct Arguments {
+    /// The command run instead of the compilation.
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
     /// Print the version and exit.
     #[arg(long)]
     pub version: bool,
@@ -30,200 +43,496 @@ pub struct Arguments {
     /// Yul, LLVM IR, and EraVM Assembly modes currently support only a single file.
     pub inputs: Vec<String>,
 
+    /// Specify the source unit name of the Solidity source read from the standard input passed as `-`.
+    /// The name is used in the contract full paths, libraries, and import resolution instead of `-`.
+    /// Only available in Solidity mode, and not in standard JSON mode.
+    #[arg(long, help_heading = Topic::Input.heading())]
+    pub stdin_name: Option<String>,
+
     /// Set the given path as the root of the source tree instead of the root of the filesystem.
     /// Passed to `solc` without changes.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub base_path: Option<String>,
 
     /// Make an additional source directory available to the default import callback.
     /// Can be used multiple times. Can only be used if the base path has a non-empty value.
     /// Passed to `solc` without changes.
-    #[arg(long, num_args = 1..)]
+    #[arg(long, num_args = 1.., help_heading = Topic::Input.heading())]
     pub include_path: Vec<String>,
 
     /// Allow a given path for imports. A list of paths can be supplied by separating them with a comma.
     /// Passed to `solc` without changes.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub allow_paths: Option<String>,
 
     /// Create one file per component and contract/file at the specified directory, if given.
-    #[arg(short, long)]
+    #[arg(short, long, help_heading = Topic::Output.heading())]
     pub output_dir: Option<PathBuf>,
 
+    /// Write one artifact JSON per contract in the specified format to the output directory, instead of the separate files.
+    /// Available options: `hardhat`, `foundry`.
+    /// Hardhat artifacts are written to `<source name>/<contract name>.json`, where the ZKsync Hardhat plugin expects them.
+    #[arg(long, help_heading = Topic::Output.heading())]
+    pub format: Option<era_compiler_solidity::ArtifactFormat>,
+
     /// Overwrite existing files (used together with -o).
-    #[arg(long = "overwrite")]
+    #[arg(long = "overwrite", help_heading = Topic::Output.heading())]
     pub overwrite: bool,
 
     /// Set the optimization parameter -O[0 | 1 | 2 | 3 | s | z].
     /// Use `3` for best performance and `z` for minimal size.
-    #[arg(short = 'O', long)]
+    #[arg(short = 'O', long, help_heading = Topic::Optimizer.heading())]
     pub optimization: Option<char>,
 
     /// Try to recompile with -Oz if the bytecode is too large.
-    #[arg(long = "fallback-Oz")]
+    #[arg(long = "fallback-Oz", help_heading = Topic::Optimizer.heading())]
     pub fallback_to_optimizing_for_size: bool,
 
     /// Pass arbitrary space-separated options to LLVM.
     /// The argument must be a single-quoted string following a `=` separator.
     /// Example: `--llvm-options='-eravm-jump-table-density-threshold=10'`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Optimizer.heading())]
     pub llvm_options: Option<String>,
 
     /// Specify the path to a `solc` executable.
     /// Solidity mode: if not provided, `solc` is also searched in `${PATH}`.
     /// Yul mode: `solc` is optional for additional Yul validation, as `zksolc` has limited Yul verification capabilities.
     /// LLVM IR and EraVM assembly modes: `solc` is unused.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub solc: Option<String>,
 
+    /// Specify the version of `solc` to use if `--solc` is not provided and `solc` is not found in `${PATH}`.
+    /// The version is taken from the installation directory, and downloaded there if it is missing.
+    /// Defaults to the latest supported version.
+    #[arg(long, help_heading = Topic::Solc.heading())]
+    pub solc_version: Option<semver::Version>,
+
+    /// Forbid downloading `solc` if it is not installed.
+    #[arg(long, help_heading = Topic::Solc.heading())]
+    pub offline: bool,
+
+    /// Partition the input files by their `pragma solidity` directives, and compile each partition with the highest `solc` version satisfying them.
+    /// The `solc` executables are taken from the installation directory, and downloaded there if they are missing.
+    /// Only available for the EraVM target in Solidity mode, and in basic CLI mode.
+    #[arg(long, help_heading = Topic::Solc.heading())]
+    pub solc_per_pragma: bool,
+
     /// EVM version `solc` will produce Yul or EVM assembly for.
     /// The default is chosen by `solc`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub evm_version: Option<era_compiler_common::EVMVersion>,
 
     /// Specify addresses of deployable libraries. Syntax: `<libraryFullPath1>=<address1> ... <libraryFullPathN>=<addressN>`.
     /// Addresses are interpreted as hexadecimal strings prefixed with `0x`.
-    #[arg(short, long, num_args = 1..)]
+    #[arg(short, long, num_args = 1.., help_heading = Topic::Input.heading())]
     pub libraries: Vec<String>,
 
     /// Output a single JSON document containing the specified information.
     /// Available arguments: `abi`, `hashes`, `metadata`, `devdoc`, `userdoc`, `storage-layout`, `ast`, `asm`, `bin`, `bin-runtime`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub combined_json: Option<String>,
 
     /// Switch to standard JSON input/output mode. Read from stdin or specified file, write the result to stdout.
     /// This is the default used by the Hardhat plugin.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub standard_json: Option<Option<String>>,
 
+    /// Switch to server mode, answering JSON-RPC compilation requests written one per line to stdin.
+    /// The process is kept alive between the requests, so the LLVM and `solc` initialization is only done once.
+    /// The requests carry standard JSON input, and the responses carry standard JSON output.
+    #[arg(long, help_heading = Topic::Input.heading())]
+    pub server: bool,
+
+    /// Listen on the Unix socket at the specified path in server mode, instead of stdin.
+    /// The connections are served one by one, and the socket file is removed on shutdown.
+    #[arg(long, help_heading = Topic::Input.heading())]
+    pub server_socket: Option<PathBuf>,
+
+    /// Select the named compilation profile from `settings.profiles` of the standard JSON input.
+    /// Overrides `settings.profile`, and is recorded in the metadata.
+    /// Only available in standard JSON mode.
+    #[arg(long, help_heading = Topic::Input.heading())]
+    pub profile: Option<String>,
+
     /// Specify the target machine.
     /// Available arguments: `eravm`, `evm`.
+    /// Both targets can be specified as `eravm,evm` to compile them in a single run in Solidity mode.
     /// The default is `eravm`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub target: Option<String>,
 
     /// Sets the number of threads, where each thread compiles its own translation unit in a child process.
-    #[arg(short, long)]
+    #[arg(short, long, help_heading = Topic::Build.heading())]
     pub threads: Option<usize>,
 
+    /// Sets the whole build timeout in seconds.
+    /// Once it is exceeded, no more contracts are compiled, the skipped ones are reported as errors, and the compiler exits with code 6.
+    /// The contracts being compiled at that moment are allowed to finish.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub build_timeout: Option<u64>,
+
+    /// Sets the maximum number of sources, rejecting larger projects before `solc` is spawned.
+    /// The default is 20000.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub max_sources: Option<usize>,
+
+    /// Sets the maximum number of contracts, rejecting larger projects before any of them is compiled.
+    /// The default is 20000.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub max_contracts: Option<usize>,
+
+    /// Sets the maximum total size of the sources in bytes, rejecting larger projects before `solc` is spawned.
+    /// The default is 536870912, that is 512 MiB.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub max_source_bytes: Option<usize>,
+
+    /// Disables the limits of the number of sources and contracts, and of the total size of the sources.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub no_limits: bool,
+
+    /// Sets the maximum nesting depth of Yul blocks, function calls, and objects, and of EVM legacy assembly
+    /// sub-assemblies, rejecting deeper inputs instead of overflowing the stack.
+    /// The default is 256. Unaffected by `--no-limits`.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub max_nesting_depth: Option<usize>,
+
+    /// Deterministically permutes the contract scheduling, worker assignment, and map iteration orders with the specified seed.
+    /// Used to flush out the output depending on the compilation order, since the output must stay the same with any seed.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub shuffle_seed: Option<u64>,
+
+    /// Watch the inputs, base path, and include paths, and recompile on each change.
+    /// The current directory is watched if none of them are specified.
+    /// Best combined with `--cache-dir`, so only the contracts affected by the changes are recompiled.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub watch: bool,
+
     /// Switch to Yul mode.
     /// Only one input Yul file is allowed.
     /// Cannot be used with combined and standard JSON modes.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub yul: bool,
 
     /// Switch to LLVM IR mode.
     /// Only one input LLVM IR file is allowed.
     /// Cannot be used with combined and standard JSON modes.
     /// Use this mode at your own risk, as LLVM IR input validation is not implemented.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub llvm_ir: bool,
 
     /// Switch to EraVM assembly mode.
     /// Only one input EraVM assembly file is allowed.
     /// Cannot be used with combined and standard JSON modes.
     /// Use this mode at your own risk, as EraVM assembly input validation is not implemented.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub eravm_assembly: bool,
 
-    /// Specify the bytecode file to disassemble.
+    /// Disassemble the bytecode files or `0x`-prefixed hexadecimal strings passed as inputs.
     /// Two file types are allowed: raw binary bytecode (*.zbin), and hexadecimal string (*.hex).
     /// Cannot be used with combined and standard JSON modes.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub disassemble: bool,
 
     /// Specify the bytecode file to link.
-    /// In default mode, input bytecode files and `--libraries` are required, and the input files are modified in place.
+    /// In default mode, input bytecode files and `--libraries` are required, and the input files are modified in place,
+    /// unless `--output-dir` is specified. The latter allows linking the same unlinked bytecode for several networks.
     /// In standard JSON mode, the result of linking is returned via stdout in a JSON.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Input.heading())]
     pub link: bool,
 
+    /// Compile and emit the artifacts of the specified contract only.
+    /// The contract is specified as `<path>:<name>` or `<name>`, and must match exactly one contract.
+    /// Its factory dependencies and libraries are compiled as well.
+    /// Cannot be used with combined and standard JSON modes.
+    #[arg(long, help_heading = Topic::Output.heading())]
+    pub contract: Option<String>,
+
+    /// Compile and emit only the contracts matching the specified glob patterns, e.g. `contracts/Router.sol:*`.
+    /// The patterns are matched against the contract full paths, where `*` matches any sequence of characters.
+    /// The factory dependencies and libraries of the matching contracts are compiled as well.
+    /// Takes one pattern and can be used multiple times.
+    #[arg(long, action = clap::ArgAction::Append, help_heading = Topic::Output.heading())]
+    pub only: Vec<String>,
+
     /// Specify the `solc` codegen.
     /// Available options: `evmla`, `yul`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub codegen: Option<era_solc::StandardJsonInputCodegen>,
 
     /// Enable EraVM extensions.
     /// In this mode, calls to addresses `0xFFFF` and below are substituted by special EraVM instructions.
     /// In the Yul mode, the `verbatim_*` instruction family becomes available.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::EraVM.heading())]
     pub enable_eravm_extensions: bool,
 
     /// Set the metadata hash type.
     /// Available types: `none`, `keccak256`, `ipfs`.
     /// The default is `keccak256`.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Output.heading())]
     pub metadata_hash: Option<era_compiler_common::HashType>,
 
     /// Sets the literal content flag for contract metadata.
     /// If enabled, the metadata will contain the literal content of the source files.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub metadata_literal: bool,
 
     /// Output assembly of the compiled contracts.
-    #[arg(long = "asm")]
+    #[arg(long = "asm", help_heading = Topic::Output.heading())]
     pub output_assembly: bool,
 
     /// Output metadata of the compiled project.
-    #[arg(long = "metadata")]
+    #[arg(long = "metadata", help_heading = Topic::Output.heading())]
     pub output_metadata: bool,
 
     /// Output bytecode of the compiled contracts.
-    #[arg(long = "bin")]
+    #[arg(long = "bin", help_heading = Topic::Output.heading())]
     pub output_binary: bool,
 
+    /// Select the artifacts to output as a comma-separated list.
+    /// Available options: `bytecode`, `abi`, `metadata`, `asm`, `hashes`.
+    /// Can be combined with `--bin`, `--metadata`, and `--asm`.
+    #[arg(long, value_delimiter = ',', help_heading = Topic::Output.heading())]
+    pub output_selection: Option<Vec<era_solc::StandardJsonInputSelector>>,
+
+    /// Output the ABI of the compiled contracts.
+    /// Only available via `--output-selection`.
+    #[arg(skip)]
+    pub output_abi: bool,
+
+    /// Output the function signature hashes of the compiled contracts, computed from their ABIs.
+    /// Only available via `--output-selection`.
+    #[arg(skip)]
+    pub output_hashes: bool,
+
+    /// Output the AST of the source files in the compact JSON format.
+    /// Only available in Solidity mode.
+    #[arg(long = "ast-json", help_heading = Topic::Output.heading())]
+    pub output_ast_json: bool,
+
     /// Suppress specified errors.
-    /// Available arguments: `sendtransfer`.
-    #[arg(long, num_args = 1..)]
+    /// Available arguments: `sendtransfer`, `assemblycreate`.
+    #[arg(long, num_args = 1.., help_heading = Topic::EraVM.heading())]
     pub suppress_errors: Option<Vec<String>>,
 
     /// Suppress specified warnings.
-    /// Available arguments: `txorigin`.
-    #[arg(long, num_args = 1..)]
+    /// Available arguments: `txorigin`, `blockrandomness`, `assemblyinitcode`, `creationcode`,
+    /// `precompilecall`, `abicoderv1`.
+    #[arg(long, num_args = 1.., help_heading = Topic::EraVM.heading())]
     pub suppress_warnings: Option<Vec<String>>,
 
+    /// Report all EraVM-specific warnings as errors with their stable codes.
+    /// Errors and warnings cannot be suppressed in this mode.
+    /// Only available for the EraVM target in Solidity mode.
+    #[arg(long, help_heading = Topic::EraVM.heading())]
+    pub strict_eravm: bool,
+
+    /// Set the format of the errors and warnings printed to stderr.
+    /// Available options: `human`, `sarif`.
+    /// The `sarif` format prints a single SARIF 2.1.0 log, e.g. for GitHub code scanning.
+    /// The default is `human`.
+    #[arg(long, help_heading = Topic::Output.heading())]
+    pub error_format: Option<era_solc::StandardJsonInputErrorFormat>,
+
+    /// Cache the results of the AST checks in the specified directory, keyed by source content hash.
+    /// Unchanged sources skip the checks on rebuilds.
+    /// The cache is invalidated if the `solc` version, suppressed errors and warnings, policy, or strict EraVM mode change.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub ast_cache: Option<PathBuf>,
+
+    /// Reuse the contract builds cached in the specified directory, keyed by the content hashes of the sources they depend on.
+    /// Only the contracts affected by source changes are recompiled on rebuilds.
+    /// The cache is invalidated if the compiler versions or the settings affecting the builds change.
+    /// Only available for the EraVM target in Solidity mode.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub cache_dir: Option<PathBuf>,
+
+    /// Write the contract-level dependency manifest in the Make depfile format to the specified file.
+    /// The artifact files of each contract depend on the sources it imports, the `solc` executable, and the settings stamp file written next to the manifest.
+    /// Requires `--output-dir`. Only available for the EraVM target in Solidity mode, and in basic CLI mode.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub depfile: Option<PathBuf>,
+
     /// Dump all IRs to files in the specified directory.
     /// Only for testing and debugging.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Debug.heading())]
     pub debug_output_dir: Option<PathBuf>,
 
+    /// Write LLVM optimization remarks to the specified file in YAML format.
+    /// The remarks show which functions were inlined, which loops were unrolled, and why other candidates were rejected.
+    /// Only available in Solidity, Yul, and LLVM IR modes.
+    #[arg(long, help_heading = Topic::Debug.heading())]
+    pub remarks_file: Option<PathBuf>,
+
+    /// Tag each LLVM IR instruction with the Yul statement or EVM legacy assembly instruction it was generated from,
+    /// and verify the LLVM IR after the translation, reporting the failures as compilation errors.
+    /// The tags are visible in the IR dumped with `--debug-output-dir`.
+    /// Only available in Solidity and Yul modes.
+    #[arg(long, help_heading = Topic::Debug.heading())]
+    pub debug_assertions: bool,
+
+    /// Place intermediate files into a dedicated subdirectory of the specified directory.
+    /// The subprocesses, including `solc`, are pointed to the subdirectory via the `TMPDIR`, `TMP`, and `TEMP` variables.
+    /// Defaults to the system temporary directory if only `--temp-dir-policy` is specified.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub temp_dir: Option<PathBuf>,
+
+    /// Set the temporary directory cleanup policy.
+    /// Available options: `always-clean`, `keep-on-error`, `keep-always`.
+    /// Defaults to `always-clean`.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub temp_dir_policy: Option<era_compiler_solidity::TempDirCleanupPolicy>,
+
+    /// Lay out functions and basic blocks according to the execution profile at the specified path.
+    /// The profile is a JSON file with Yul function call counts, usually exported from a node or a test runner.
+    /// Frequently called functions are placed first, and never called ones are moved to the end and marked as cold.
+    /// The cases of `switch` statements, such as the function dispatcher, are reordered the same way.
+    /// Only available in Solidity and Yul modes.
+    #[arg(long, help_heading = Topic::Optimizer.heading())]
+    pub execution_profile: Option<PathBuf>,
+
+    /// Write the call graphs of the project and each contract to the specified directory in JSON and DOT formats.
+    /// The graphs include internal, external, library, and system contract simulation calls, and are built before optimization.
+    /// Only available in Solidity and Yul modes with the Yul codegen.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub call_graph: Option<PathBuf>,
+
+    /// Write the inheritance graph with the C3-linearized inheritance chain of each contract to the specified JSON file.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub inheritance_graph: Option<PathBuf>,
+
+    /// Write the source import graph to the specified JSON file.
+    /// The imported paths are resolved with remappings, base path, and include paths applied.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub import_graph: Option<PathBuf>,
+
+    /// Write the additional project-wide outputs to the output directory.
+    /// Available options: `dep-graph`, the dependency graph of the project in JSON and DOT formats, combining the source imports with the contract factory dependencies deployed with `CREATE`, and the linked libraries.
+    /// Only available in Solidity mode.
+    #[arg(long, value_delimiter = ',', help_heading = Topic::Reports.heading())]
+    pub emit: Vec<era_compiler_solidity::Emit>,
+
+    /// Write the report of the constructs behaving differently on EVM and EraVM to the specified JSON file.
+    /// The report lists the gas, address derivation, code introspection, account abstraction, block property,
+    /// and value transfer constructs with their locations, grouped by contract.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub compatibility_report: Option<PathBuf>,
+
+    /// Write the revert decoder data to the specified JSON file.
+    /// The data maps the custom error selectors and panic codes to human-readable signatures and the locations
+    /// of their `revert` statements, grouped by contract, so RPC tooling can decode failures of deployed contracts.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub revert_decoder: Option<PathBuf>,
+
+    /// Check the contract ABIs against the interface freeze file at the specified path.
+    /// The file lists the expected function, event, and error selectors of contracts, and any mismatch is reported as an error.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub interface_freeze: Option<PathBuf>,
+
+    /// Write the exact standard JSON input passed to `solc` to the specified JSON file.
+    /// The input includes the output selection, remappings, and other settings rewritten by zksolc,
+    /// so the `solc` step can be audited and reproduced independently.
+    /// Only available in Solidity mode.
+    #[arg(long, help_heading = Topic::Solc.heading())]
+    pub solc_input_echo: Option<PathBuf>,
+
+    /// Write the lock file recording the toolchain, settings, and source hashes of a successful build to the specified path.
+    /// The conventional file name is `zksolc.lock`.
+    /// Not available in standard JSON and combined JSON modes.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub lock_file: Option<PathBuf>,
+
+    /// Fail if the build does not match the lock file instead of updating it.
+    /// The lock file is specified with `--lock-file`, and defaults to `zksolc.lock` in the current directory.
+    #[arg(long, help_heading = Topic::Build.heading())]
+    pub locked: bool,
+
+    /// Sign the emitted bytecode and metadata with the secp256k1 private key at the specified path.
+    /// The key file must contain the key in hexadecimal format.
+    /// The signatures are written to the build report in the output directory, along with the key identifier,
+    /// which is the Ethereum address of the key.
+    /// Only available for the EraVM target with `--output-dir`.
+    #[arg(long, help_heading = Topic::Output.heading())]
+    pub signing_key: Option<PathBuf>,
+
+    /// Write the build report to the output directory, including the resource usage of the run.
+    /// The report is also written with `--signing-key` and `--balance-lowering`.
+    /// Only available for the EraVM target with `--output-dir`.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub build_report: bool,
+
+    /// Set the LLVM loop unrolling and rotation aggressiveness.
+    /// Available options: `off`, `conservative`, `aggressive`.
+    /// By default, the LLVM optimizer decides according to the optimization mode.
+    #[arg(long, help_heading = Topic::Optimizer.heading())]
+    pub loop_optimization: Option<era_solc::StandardJsonInputLoopOptimization>,
+
+    /// Set the LLVM register allocator spill strategy for functions exceeding the register availability.
+    /// Available options: `size`, `speed`, `hybrid`, named after the LLVM `-split-spill-mode` values they select.
+    /// By default, the LLVM register allocator decides on its own.
+    #[arg(long, help_heading = Topic::Optimizer.heading())]
+    pub spill_strategy: Option<era_solc::StandardJsonInputSpillStrategy>,
+
+    /// Set the `BALANCE` and `SELFBALANCE` lowering strategy.
+    /// Available options: `inline`, `shared`.
+    /// `inline` emits the system contract call sequence at every balance query, while `shared`
+    /// calls a single helper routine, trading the call overhead for a smaller bytecode.
+    /// Only available for the EraVM target.
+    #[arg(long, help_heading = Topic::EraVM.heading())]
+    pub balance_lowering: Option<era_solc::StandardJsonInputBalanceLowering>,
+
+    /// Write the per-function spill and reload counts to the specified JSON file.
+    /// The report is extracted from the LLVM optimization remarks, so it requires `--remarks-file`.
+    #[arg(long, help_heading = Topic::Reports.heading())]
+    pub spill_report: Option<PathBuf>,
+
     /// Set the verify-each option in LLVM.
     /// Only for testing and debugging.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Debug.heading())]
     pub llvm_verify_each: bool,
 
     /// Set the debug-logging option in LLVM.
     /// Only for testing and debugging.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Debug.heading())]
     pub llvm_debug_logging: bool,
 
     /// Run this process recursively and provide JSON input to compile a single contract.
     /// Only for usage from within the compiler.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Debug.heading())]
     pub recursive_process: bool,
 
     /// Switch to missing deployable libraries detection mode.
     /// Only available for standard JSON input/output mode.
     /// Contracts are not compiled in this mode, and all compilation artifacts are not included.
     /// Deprecated: missing libraries are now always returned in standard JSON output.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::EraVM.heading())]
     pub detect_missing_libraries: bool,
 
     /// Forcibly switch to EVM legacy assembly codegen.
     /// It is useful for older revisions of `solc` 0.8, where Yul was considered highly experimental
     /// and contained more bugs than today.
     /// Deprecated: use `--codegen` instead.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub force_evmla: bool,
 
     /// Deprecated: use `--enable-eravm-extensions` instead.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::EraVM.heading())]
     pub system_mode: bool,
 
     /// Deprecated.
     /// The `solc` optimizer is not used by `zksolc` anymore.
-    #[arg(long)]
+    #[arg(long, help_heading = Topic::Solc.heading())]
     pub disable_solc_optimizer: bool,
+
+    /// Whether only the phases populating the caches are run.
+    /// Only available via `zksolc cache warm`.
+    #[arg(skip)]
+    pub cache_warming: bool,
 }
 
 impl Arguments {
@@ -232,6 +541,9 @@ impl Arguments {
     ///
     pub fn validate(&self) -> Vec<era_solc::StandardJsonOutputError> {
         let mut messages = vec![];
+        let emit_dependency_graph = self
+            .emit
+            .contains(&era_compiler_solidity::Emit::DependencyGraph);
 
         if self.system_mode {
             messages.push(era_solc::StandardJsonOutputError::new_warning(
@@ -280,13 +592,14 @@ impl Arguments {
             self.link,
             self.combined_json.is_some(),
             self.standard_json.is_some(),
+            self.server,
         ]
         .iter()
         .filter(|&&x| x)
         .count();
         if modes_count > 1 + ((self.link && self.standard_json.is_some()) as usize) {
             messages.push(era_solc::StandardJsonOutputError::new_error(
-                "Only one mode is allowed at the same time: Yul, LLVM IR, EraVM Assembly, disassembler, combined JSON, standard JSON. Only linker can be used with `--standard-json`.", None, None));
+                "Only one mode is allowed at the same time: Yul, LLVM IR, EraVM Assembly, disassembler, combined JSON, standard JSON, server. Only linker can be used with `--standard-json`.", None, None));
         }
 
         if self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link {
@@ -355,7 +668,65 @@ impl Arguments {
             ));
         }
 
+        if (self.eravm_assembly || self.disassemble || self.link) && self.remarks_file.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "LLVM optimization remarks are only available in Solidity, Yul, and LLVM IR modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.remarks_file.is_some() && self.cache_dir.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "LLVM optimization remarks file cannot be used with the build cache, as cached contracts are not compiled.",
+                None,
+                None,
+            ));
+        }
+
+        if self.spill_report.is_some() && self.remarks_file.is_none() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Spill report requires the LLVM optimization remarks file to be specified with `--remarks-file`.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+            && self.execution_profile.is_some()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Execution profile is only available in Solidity and Yul modes.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+            && self.call_graph.is_some()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Call graph is only available in Solidity and Yul modes.",
+                None,
+                None,
+            ));
+        }
+
         if self.eravm_assembly {
+            if self.loop_optimization.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Loop optimizations are not supported in EraVM assembly mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.spill_strategy.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Spill strategies are not supported in EraVM assembly mode.",
+                    None,
+                    None,
+                ));
+            }
             if self.optimization.is_some() {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
                     "LLVM optimizations are not supported in EraVM assembly mode.",
@@ -387,18 +758,27 @@ impl Arguments {
         linker_default_arguments_count += match self.standard_json {
             Some(Some(_)) => 2,
             Some(None) => 1,
-            _ => self.inputs.len() + ((!self.libraries.is_empty()) as usize) + self.libraries.len(),
+            _ => {
+                self.inputs.len()
+                    + ((!self.libraries.is_empty()) as usize)
+                    + self.libraries.len()
+                    + (self.output_dir.is_some() as usize) * 2
+            }
         };
         if self.link && std::env::args().count() > linker_default_arguments_count {
             messages.push(era_solc::StandardJsonOutputError::new_error(
-                "Error: No other options except bytecode files, `--libraries`, `--standard-json`, `--target` are allowed in linker mode.",
+                "Error: No other options except bytecode files, `--libraries`, `--output-dir`, `--standard-json`, `--target` are allowed in linker mode.",
                 None,
                 None,
             ));
         }
 
         if self.combined_json.is_some()
-            && (self.output_assembly || self.output_metadata || self.output_binary)
+            && (self.output_assembly
+                || self.output_metadata
+                || self.output_binary
+                || self.output_ast_json
+                || self.output_selection.is_some())
         {
             messages.push(era_solc::StandardJsonOutputError::new_error(
                 "Cannot output data outside of JSON in combined JSON mode.",
@@ -407,62 +787,623 @@ impl Arguments {
             ));
         }
 
-        if self.standard_json.is_none() && self.detect_missing_libraries {
+        if self.combined_json.is_some() && self.remarks_file.is_some() {
             messages.push(era_solc::StandardJsonOutputError::new_error(
-                "Missing deployable libraries detection mode is only supported in standard JSON mode.", None, None
+                "LLVM optimization remarks file cannot be used in combined JSON mode.",
+                None,
+                None,
             ));
         }
 
-        if self.standard_json.is_some() {
-            if self.output_assembly || self.output_metadata || self.output_binary {
-                messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Cannot output data outside of JSON in standard JSON mode.",
-                    None,
-                    None,
-                ));
-            }
+        if self.combined_json.is_some() && self.execution_profile.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Execution profile cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
 
-            if !self.inputs.is_empty() {
-                messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Input files must be passed via standard JSON input.",
-                    None,
-                    None,
-                ));
-            }
-            if !self.libraries.is_empty() {
-                messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Libraries must be passed via standard JSON input.",
-                    None,
-                    None,
-                ));
-            }
+        if self.combined_json.is_some() && self.call_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Call graph cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
 
-            if self.codegen.is_some() {
+        if self.combined_json.is_some() && self.inheritance_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Inheritance graph cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && self.import_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Import graph cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+        if self.combined_json.is_some() && emit_dependency_graph {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency graph cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+        if self.combined_json.is_some() && self.compatibility_report.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Compatibility report cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+        if self.combined_json.is_some() && self.revert_decoder.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Revert decoder cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.yul || self.llvm_ir || self.eravm_assembly)
+            && self.output_selection.as_ref().is_some_and(|selection| {
+                selection.contains(&era_solc::StandardJsonInputSelector::ABI)
+            })
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "ABI output is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if (self.yul || self.llvm_ir || self.eravm_assembly)
+            && self.output_selection.as_ref().is_some_and(|selection| {
+                selection.contains(&era_solc::StandardJsonInputSelector::MethodIdentifiers)
+            })
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Function signature hashes output is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && self.output_ast_json {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "AST output is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && !self.only.is_empty() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Contract allow-list is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.llvm_ir || self.eravm_assembly) && self.balance_lowering.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Balance lowering strategy is only available in Solidity and Yul modes.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && self.inheritance_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Inheritance graph is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && self.import_graph.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Import graph is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && emit_dependency_graph {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency graph is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if emit_dependency_graph
+            && self.output_dir.is_none()
+            && self.combined_json.is_none()
+            && self.standard_json.is_none()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency graph requires `--output-dir` to be written to.",
+                None,
+                None,
+            ));
+        }
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && self.compatibility_report.is_some()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Compatibility report is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if (self.yul || self.llvm_ir || self.eravm_assembly) && self.revert_decoder.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Revert decoder is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        let is_multi_target = self
+            .target
+            .as_ref()
+            .is_some_and(|target| target.contains(','));
+        if is_multi_target
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Multiple targets are only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if is_multi_target && self.combined_json.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Multiple targets cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+        if is_multi_target && (self.enable_eravm_extensions || self.system_mode) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "EraVM extensions cannot be used with multiple targets.",
+                None,
+                None,
+            ));
+        }
+
+        if self.debug_assertions
+            && (self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Debug assertions are only available in Solidity and Yul modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.strict_eravm {
+            if self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Codegen must be passed via standard JSON input.",
+                    "Strict EraVM mode is only available in Solidity mode.",
                     None,
                     None,
                 ));
             }
-            if self.evm_version.is_some() {
+            if self.suppress_errors.is_some() || self.suppress_warnings.is_some() {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "EVM version must be passed via standard JSON input.",
+                    "Errors and warnings cannot be suppressed in strict EraVM mode.",
                     None,
                     None,
                 ));
             }
+        }
 
-            if self.output_dir.is_some() {
-                messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Output directory cannot be used in standard JSON mode.",
-                    None,
-                    None,
-                ));
-            }
-            if self.overwrite {
-                messages.push(era_solc::StandardJsonOutputError::new_error(
-                    "Overwriting flag cannot be used in standard JSON mode.",
-                    None,
+        if self.interface_freeze.is_some()
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Interface freeze is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if self.combined_json.is_some() && self.interface_freeze.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Interface freeze cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.solc_input_echo.is_some()
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Solc input echo is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if self.cache_dir.is_some()
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Build cache is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.depfile.is_some()
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency manifest is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+        if self.depfile.is_some() && (self.combined_json.is_some() || self.standard_json.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency manifest is only available in basic CLI mode.",
+                None,
+                None,
+            ));
+        }
+        if self.depfile.is_some()
+            && self.output_dir.is_none()
+            && self.combined_json.is_none()
+            && self.standard_json.is_none()
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Dependency manifest requires `--output-dir`, as its targets are the written artifact files.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && self.cache_dir.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Build cache cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && self.solc_input_echo.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Solc input echo cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.ast_cache.is_some()
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "AST cache is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.no_limits
+            && (self.max_sources.is_some()
+                || self.max_contracts.is_some()
+                || self.max_source_bytes.is_some())
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Limits cannot be set together with `--no-limits`.",
+                None,
+                None,
+            ));
+        }
+        if self.max_nesting_depth == Some(0) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "The maximum nesting depth must be positive.",
+                None,
+                None,
+            ));
+        }
+
+        if let Some(ref stdin_name) = self.stdin_name {
+            if self.yul
+                || self.llvm_ir
+                || self.eravm_assembly
+                || self.disassemble
+                || self.link
+                || self.standard_json.is_some()
+            {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Standard input name is only available in Solidity mode, and not in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            let input_files: Vec<PathBuf> = self
+                .inputs
+                .iter()
+                .filter(|input| !input.contains('='))
+                .filter_map(|input| Self::input_file(input).ok())
+                .collect();
+            let stdin_count = input_files
+                .iter()
+                .filter(|path| era_solc::StandardJsonInputSource::is_stdin(path))
+                .count();
+            if stdin_count != 1 {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Standard input name requires the standard input `-` to be passed once among the input files.",
+                    None,
+                    None,
+                ));
+            }
+            if input_files
+                .iter()
+                .any(|path| era_solc::StandardJsonInputSource::name(path, None) == *stdin_name)
+            {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    format!("Standard input name `{stdin_name}` clashes with an input file."),
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        if self.solc_version.is_some() && self.solc.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "`solc` version cannot be specified together with the `solc` executable.",
+                None,
+                None,
+            ));
+        }
+        if self.solc_per_pragma && (self.solc.is_some() || self.solc_version.is_some()) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "`solc` per pragma resolution cannot be used together with a specific `solc` executable or version.",
+                None,
+                None,
+            ));
+        }
+        if self.solc_per_pragma && (self.combined_json.is_some() || self.standard_json.is_some()) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "`solc` per pragma resolution is only available in basic CLI mode.",
+                None,
+                None,
+            ));
+        }
+        if self.solc_per_pragma {
+            let unsupported_options = [
+                ("--call-graph", self.call_graph.is_some()),
+                ("--inheritance-graph", self.inheritance_graph.is_some()),
+                ("--import-graph", self.import_graph.is_some()),
+                ("--emit dep-graph", emit_dependency_graph),
+                (
+                    "--compatibility-report",
+                    self.compatibility_report.is_some(),
+                ),
+                ("--revert-decoder", self.revert_decoder.is_some()),
+                ("--interface-freeze", self.interface_freeze.is_some()),
+                ("--only", !self.only.is_empty()),
+                ("--solc-input-echo", self.solc_input_echo.is_some()),
+                ("--depfile", self.depfile.is_some()),
+                ("--lock-file", self.lock_file.is_some()),
+                ("--locked", self.locked),
+            ]
+            .into_iter()
+            .filter_map(|(option, is_set)| is_set.then_some(format!("`{option}`")))
+            .collect::<Vec<String>>();
+            if !unsupported_options.is_empty() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    format!(
+                        "`solc` per pragma resolution cannot be used together with {}, as each partition is compiled by a separate `solc` invocation.",
+                        unsupported_options.join(", ")
+                    ),
+                    None,
+                    None,
+                ));
+            }
+        }
+        if (self.solc_version.is_some() || self.offline || self.solc_per_pragma)
+            && (self.yul || self.llvm_ir || self.eravm_assembly || self.disassemble || self.link)
+        {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "`solc` installation is only available in Solidity mode.",
+                None,
+                None,
+            ));
+        }
+
+        if let Some(format) = self.format {
+            if self.output_dir.is_none() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    format!("Artifact format `{format}` requires `--output-dir`."),
+                    None,
+                    None,
+                ));
+            }
+            if self.combined_json.is_some()
+                || self.standard_json.is_some()
+                || self.disassemble
+                || self.link
+                || self.server
+            {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Artifact format is only available in basic CLI mode.",
+                    None,
+                    None,
+                ));
+            }
+        }
+
+        if self.server_socket.is_some() && !self.server {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Server socket requires `--server`.",
+                None,
+                None,
+            ));
+        }
+        if self.server && (self.temp_dir.is_some() || self.temp_dir_policy.is_some()) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Temporary directory options cannot be used in server mode. Please set the `TMPDIR` environment variable instead.",
+                None,
+                None,
+            ));
+        }
+        if self.server && !self.inputs.is_empty() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Server mode does not accept input files. Please send them in standard JSON compilation requests.",
+                None,
+                None,
+            ));
+        }
+
+        if self.watch && (self.disassemble || self.link || self.server) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Watch mode is only available in compilation modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.watch && self.standard_json == Some(None) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Watch mode requires the standard JSON input file path.",
+                None,
+                None,
+            ));
+        }
+
+        if (self.disassemble || self.link) && (self.lock_file.is_some() || self.locked) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Lock file is only available in compilation modes.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && (self.lock_file.is_some() || self.locked) {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Lock file cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.signing_key.is_some() && self.combined_json.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Artifact signing cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        } else if self.signing_key.is_some() && self.output_dir.is_none() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Artifact signing is only available with `--output-dir`.",
+                None,
+                None,
+            ));
+        }
+
+        if self.build_report && self.combined_json.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Build report cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        } else if self.build_report && self.output_dir.is_none() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Build report is only available with `--output-dir`.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && self.contract.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Contract selection cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.combined_json.is_some() && !self.only.is_empty() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Contract allow-list cannot be used in combined JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.standard_json.is_none() && self.profile.is_some() {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Compilation profiles are only supported in standard JSON mode.",
+                None,
+                None,
+            ));
+        }
+
+        if self.standard_json.is_none() && self.detect_missing_libraries {
+            messages.push(era_solc::StandardJsonOutputError::new_error(
+                "Missing deployable libraries detection mode is only supported in standard JSON mode.", None, None
+            ));
+        }
+
+        if self.standard_json.is_some() {
+            if self.output_assembly
+                || self.output_metadata
+                || self.output_binary
+                || self.output_ast_json
+                || self.output_selection.is_some()
+            {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Cannot output data outside of JSON in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+
+            if !self.inputs.is_empty() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Input files must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.libraries.is_empty() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Libraries must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+
+            if self.codegen.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Codegen must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+            if self.evm_version.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "EVM version must be passed via standard JSON input.",
+                    None,
+                    None,
+                ));
+            }
+
+            if self.output_dir.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Output directory cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.overwrite {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Overwriting flag cannot be used in standard JSON mode.",
+                    None,
                     None,
                 ));
             }
@@ -480,6 +1421,27 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.loop_optimization.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Loop optimization mode must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.spill_strategy.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Spill strategy must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.balance_lowering.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Balance lowering strategy must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
             if self.llvm_options.is_some() {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
                     "LLVM options must be specified in standard JSON input settings.",
@@ -487,6 +1449,108 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.error_format.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Error format must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.temp_dir.is_some() || self.temp_dir_policy.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Temporary directory options cannot be used in standard JSON mode. Please set the `TMPDIR` environment variable instead.",
+                    None,
+                    None,
+                ));
+            }
+            if self.remarks_file.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "LLVM optimization remarks file cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.execution_profile.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Execution profile cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.call_graph.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Call graph cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.inheritance_graph.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Inheritance graph cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.import_graph.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Import graph cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if emit_dependency_graph {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Dependency graph cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.compatibility_report.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Compatibility report cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.revert_decoder.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Revert decoder cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self
+                .target
+                .as_ref()
+                .is_some_and(|target| target.contains(','))
+            {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Multiple targets cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.lock_file.is_some() || self.locked {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Lock file cannot be used in standard JSON mode.",
+                    None,
+                    None,
+                ));
+            }
+            if self.contract.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Contract selection must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if !self.only.is_empty() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Contract allow-list must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
             if self.metadata_hash.is_some() {
                 messages.push(era_solc::StandardJsonOutputError::new_error(
                     "Metadata hash mode must be specified in standard JSON input settings.",
@@ -516,6 +1580,41 @@ impl Arguments {
                     None,
                 ));
             }
+            if self.strict_eravm {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Strict EraVM mode must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.ast_cache.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "AST cache must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.cache_dir.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Build cache must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.interface_freeze.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Interface freeze file must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
+            if self.solc_input_echo.is_some() {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    "Solc input echo must be specified in standard JSON input settings.",
+                    None,
+                    None,
+                ));
+            }
 
             if self.enable_eravm_extensions || self.system_mode {
                 messages.push(era_solc::StandardJsonOutputError::new_warning(
@@ -537,6 +1636,76 @@ impl Arguments {
         messages
     }
 
+    ///
+    /// Sets the output flags requested with `--output-selection`.
+    ///
+    pub fn apply_output_selection(&mut self) {
+        if self.format.is_some() {
+            self.output_abi = true;
+            self.output_binary = true;
+        }
+        for selector in self.output_selection.take().unwrap_or_default().into_iter() {
+            match selector {
+                era_solc::StandardJsonInputSelector::EVM => self.output_binary = true,
+                era_solc::StandardJsonInputSelector::ABI => self.output_abi = true,
+                era_solc::StandardJsonInputSelector::MethodIdentifiers => self.output_hashes = true,
+                era_solc::StandardJsonInputSelector::Metadata => self.output_metadata = true,
+                era_solc::StandardJsonInputSelector::EraVMAssembly => self.output_assembly = true,
+                _ => {}
+            }
+        }
+    }
+
+    ///
+    /// Returns the settings affecting the build, which are hashed into the lock file.
+    ///
+    /// Output paths and selections are not included, as they do not affect the bytecode.
+    ///
+    pub fn lock_settings(&self) -> serde_json::Value {
+        let mode = if self.yul {
+            "yul"
+        } else if self.llvm_ir {
+            "llvm-ir"
+        } else if self.eravm_assembly {
+            "eravm-assembly"
+        } else {
+            "solidity"
+        };
+        let remappings: BTreeSet<&String> = self
+            .inputs
+            .iter()
+            .filter(|input| input.contains('='))
+            .collect();
+
+        serde_json::json!({
+            "target": self.target,
+            "mode": mode,
+            "optimization": self.optimization.map(|mode| mode.to_string()),
+            "fallbackToOptimizingForSize": self.fallback_to_optimizing_for_size,
+            "llvmOptions": self.llvm_options,
+            "loopOptimization": self.loop_optimization,
+            "spillStrategy": self.spill_strategy,
+            "balanceLowering": self.balance_lowering,
+            "evmVersion": self.evm_version.as_ref().map(|version| version.to_string()),
+            "codegen": self.codegen.as_ref().map(|codegen| codegen.to_string()),
+            "forceEVMLA": self.force_evmla,
+            "disableSolcOptimizer": self.disable_solc_optimizer,
+            "detectMissingLibraries": self.detect_missing_libraries,
+            "enableEraVMExtensions": self.enable_eravm_extensions || self.system_mode,
+            "metadataHash": self.metadata_hash.as_ref().map(|hash_type| hash_type.to_string()),
+            "metadataLiteral": self.metadata_literal,
+            "libraries": self.libraries,
+            "remappings": remappings,
+            "basePath": self.base_path,
+            "includePaths": self.include_path,
+            "allowPaths": self.allow_paths,
+            "executionProfile": self.execution_profile,
+            "suppressErrors": self.suppress_errors,
+            "suppressWarnings": self.suppress_warnings,
+            "strictEraVM": self.strict_eravm,
+        })
+    }
+
     ///
     /// Returns remappings from input paths.
     ///
@@ -548,32 +1717,33 @@ impl Arguments {
 
         for input in self.inputs.iter() {
             if input.contains('=') {
-                let mut parts = Vec::with_capacity(2);
-                for path in input.trim().split('=') {
-                    let path = PathBuf::from(path);
-                    parts.push(
-                        Self::path_to_posix(path.as_path())?
-                            .to_string_lossy()
-                            .to_string(),
-                    );
-                }
-                if parts.len() != 2 {
-                    anyhow::bail!(
-                        "Invalid remapping `{}`: expected two parts separated by '='.",
-                        input
-                    );
-                }
-                remappings.insert(parts.join("="));
+                let remapping: era_solc::StandardJsonInputRemapping = input.trim().parse()?;
+                let [context, prefix, target] =
+                    [remapping.context, remapping.prefix, remapping.target].map(|path| {
+                        Self::path_to_posix(Path::new(path.as_str()))
+                            .map(|path| path.to_string_lossy().to_string())
+                    });
+                let remapping = era_solc::StandardJsonInputRemapping {
+                    context: context?,
+                    prefix: prefix?,
+                    target: target?,
+                };
+                remappings.insert(remapping.to_string());
             } else {
-                let path = PathBuf::from(input.trim());
-                let path = Self::path_to_posix(path.as_path())?;
-                input_files.push(path);
+                input_files.push(Self::input_file(input)?);
             }
         }
 
         Ok((input_files, remappings))
     }
 
+    ///
+    /// Returns the path of the input file, as it is passed to the source loader.
+    ///
+    fn input_file(input: &str) -> anyhow::Result<PathBuf> {
+        Self::path_to_posix(Path::new(input.trim()))
+    }
+
     ///
     /// Normalizes an input path by converting it to POSIX format.
     ///
@@ -582,7 +1752,7 @@ impl Arguments {
             .to_slash()
             .ok_or_else(|| anyhow::anyhow!("Input path {:?} POSIX conversion error.", path))?
             .to_string();
-        let path = PathBuf::from(path.as_str());
+        let path = PathBuf::from(era_solc::path::normalize(path.as_str()));
         Ok(path)
     }
 }
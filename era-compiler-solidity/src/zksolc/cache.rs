@@ -0,0 +1,140 @@
+//!
+//! The `zksolc cache` commands.
+//!
+
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+
+use clap::Parser;
+
+use crate::arguments::Arguments;
+
+///
+/// The `zksolc cache` commands.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Cache {
+    /// Prime the caches without emitting any artifacts.
+    ///
+    /// If <INPUT> is a standard JSON input file, the caches specified in its settings are primed.
+    /// If <INPUT> is a directory, all Solidity sources in it are compiled in basic CLI mode, and
+    /// either `--cache-dir` or `--ast-cache` must be passed among the options.
+    /// Only the phases populating the caches are run. Only the EraVM target is supported.
+    Warm(Warm),
+}
+
+impl Cache {
+    ///
+    /// Runs the selected cache command.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        match self {
+            Self::Warm(warm) => warm.run(),
+        }
+    }
+}
+
+///
+/// The `zksolc cache warm <INPUT> [OPTIONS]...` command.
+///
+/// Primes the caches by running only the compilation phases populating them, so CI can
+/// populate shared caches on merge, and subsequent builds become mostly cache hits.
+///
+#[derive(Debug, clap::Args)]
+pub struct Warm {
+    /// The standard JSON input file, or the directory with Solidity sources.
+    pub input: PathBuf,
+    /// The options passed to the compilation, such as `--cache-dir` or `--solc`.
+    #[arg(trailing_var_arg = true, allow_hyphen_values = true)]
+    pub arguments: Vec<OsString>,
+}
+
+impl Warm {
+    ///
+    /// Runs the cacheable compilation phases in-process, skipping linking, reports, and artifacts.
+    ///
+    /// The diagnostics are written to `stderr`.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let mut command_line = vec![OsString::from("zksolc")];
+        let is_standard_json = self.input.is_file();
+        let sources = if is_standard_json {
+            let solc_input = era_solc::StandardJsonInput::try_from(Some(self.input.as_path()))?;
+            if solc_input.settings.build_cache.is_none() && solc_input.settings.ast_cache.is_none()
+            {
+                anyhow::bail!(
+                    "Standard JSON input {:?} does not specify any caches. Set `settings.buildCache` or `settings.astCache`.",
+                    self.input
+                );
+            }
+            command_line.push(OsString::from("--standard-json"));
+            command_line.push(self.input.clone().into_os_string());
+            solc_input.sources.len()
+        } else {
+            let paths = Self::solidity_paths(self.input.as_path())?;
+            if paths.is_empty() {
+                anyhow::bail!(
+                    "Directory {:?} does not contain any Solidity sources.",
+                    self.input
+                );
+            }
+            command_line.extend(paths.iter().map(|path| path.as_os_str().to_owned()));
+            paths.len()
+        };
+        command_line.extend(self.arguments);
+
+        let mut arguments = Arguments::try_parse_from(command_line)?;
+        if !is_standard_json && arguments.cache_dir.is_none() && arguments.ast_cache.is_none() {
+            anyhow::bail!("Either `--cache-dir` or `--ast-cache` must be passed to warm the caches of a directory.");
+        }
+        arguments.cache_warming = true;
+
+        let mut messages = arguments.validate();
+        if messages.iter().all(|message| message.severity != "error") {
+            if let Err(error) = crate::main_inner(arguments, &mut messages, None) {
+                messages.push(era_solc::StandardJsonOutputError::new_error(
+                    error, None, None,
+                ));
+            }
+        }
+        for message in messages.iter() {
+            writeln!(std::io::stderr(), "{message}")?;
+        }
+        if messages.iter().any(|message| message.severity == "error") {
+            anyhow::bail!("Cache warming failed.");
+        }
+
+        writeln!(
+            std::io::stderr(),
+            "Caches warmed with {sources} source{}.",
+            if sources == 1 { "" } else { "s" }
+        )?;
+        Ok(())
+    }
+
+    ///
+    /// Collects the Solidity sources in the `directory` and its subdirectories, in sorted order.
+    ///
+    fn solidity_paths(directory: &Path) -> anyhow::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
+        let mut directories = vec![directory.to_path_buf()];
+        while let Some(directory) = directories.pop() {
+            let entries = std::fs::read_dir(directory.as_path())
+                .map_err(|error| anyhow::anyhow!("Directory {directory:?} reading: {error}"))?;
+            for entry in entries {
+                let path = entry
+                    .map_err(|error| anyhow::anyhow!("Directory {directory:?} reading: {error}"))?
+                    .path();
+                if path.is_dir() {
+                    directories.push(path);
+                } else if path.extension().and_then(|extension| extension.to_str()) == Some("sol") {
+                    paths.push(path);
+                }
+            }
+        }
+        paths.sort();
+        Ok(paths)
+    }
+}
@@ -0,0 +1,73 @@
+//!
+//! The `zksolc` commands run instead of the compilation.
+//!
+
+use crate::cache::Cache;
+use crate::compare::Compare;
+use crate::dedup_report::DedupReport;
+use crate::disassemble::Disassemble;
+use crate::explain_bytecode::ExplainBytecode;
+use crate::help::Help;
+use crate::link::Link;
+use crate::solc::Solc;
+
+///
+/// The `zksolc` commands run instead of the compilation.
+///
+/// As the commands take precedence over the input paths, an input file named after a command
+/// must be passed with a path prefix, e.g. `./cache`.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Command {
+    /// Print the options and examples of a help topic, or the list of topics.
+    Help(Help),
+    /// Manage the caches.
+    #[command(subcommand)]
+    Cache(Cache),
+    /// Manage the `solc` installations.
+    #[command(subcommand)]
+    Solc(Solc),
+    /// Compare two bytecodes, ignoring their metadata trailers.
+    ///
+    /// Each input is either a file with raw binary bytecode or a hexadecimal string, or a
+    /// hexadecimal string passed directly. Exits with code 0 if the code is the same, and with
+    /// code 1 otherwise.
+    Compare(Compare),
+    /// Explain an EraVM bytecode offset.
+    ///
+    /// Maps the offset, e.g. taken from a revert trace, to the instruction, the function
+    /// containing it, and the Solidity declaration the function is generated from.
+    ExplainBytecode(ExplainBytecode),
+    /// Disassemble EraVM bytecode into EraVM assembly with instruction offsets.
+    ///
+    /// Each input is either a file with raw binary bytecode or a hexadecimal string, or a
+    /// hexadecimal string passed directly.
+    Disassemble(Disassemble),
+    /// Link unlinked EraVM bytecode files with the library addresses.
+    ///
+    /// Prints the linked bytecode and the bytecode hashes in JSON, in the same format as
+    /// `--link` does.
+    Link(Link),
+    /// Report the duplicate functions and near-duplicate contracts across a build in JSON.
+    ///
+    /// The report is printed to stdout, unless a file is specified with `--output`.
+    DedupReport(DedupReport),
+}
+
+impl Command {
+    ///
+    /// Runs the command.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        match self {
+            Self::Help(help) => help.run(),
+            Self::Cache(cache) => cache.run(),
+            Self::Solc(solc) => solc.run(),
+            Self::Compare(compare) => compare.run(),
+            Self::ExplainBytecode(explain_bytecode) => explain_bytecode.run(),
+            Self::Disassemble(disassemble) => disassemble.run(),
+            Self::Link(link) => link.run(),
+            Self::DedupReport(dedup_report) => dedup_report.run(),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//!
+//! The `zksolc compare` command.
+//!
+
+use std::io::Write;
+
+///
+/// The `zksolc compare <LEFT> <RIGHT>` command.
+///
+/// Compares two bytecodes, ignoring their metadata trailers, so differences in the metadata
+/// alone are not mistaken for code changes.
+///
+#[derive(Debug, clap::Args)]
+pub struct Compare {
+    /// The first bytecode file or hexadecimal string.
+    pub left: String,
+    /// The second bytecode file or hexadecimal string.
+    pub right: String,
+    /// The metadata hash type the bytecodes have been compiled with.
+    /// Available types: `none`, `keccak256`, `ipfs`.
+    /// The default is `keccak256`, the same as the compilation default.
+    #[arg(long, default_value_t = era_compiler_common::HashType::Keccak256)]
+    pub metadata_hash: era_compiler_common::HashType,
+}
+
+impl Compare {
+    ///
+    /// Compares the bytecodes and prints the result.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let left = era_compiler_solidity::Disassembly::read_bytecode(self.left.as_str())?;
+        let right = era_compiler_solidity::Disassembly::read_bytecode(self.right.as_str())?;
+
+        let comparison = era_compiler_solidity::BytecodeComparison::new(
+            left.as_slice(),
+            right.as_slice(),
+            self.metadata_hash,
+        );
+        writeln!(std::io::stdout(), "{comparison}")?;
+        if !comparison.is_equivalent() {
+            std::process::exit(era_compiler_common::EXIT_CODE_FAILURE);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,40 @@
+//!
+//! The `zksolc dedup-report` command.
+//!
+
+use std::io::Write;
+use std::path::PathBuf;
+
+///
+/// The `zksolc dedup-report <INPUT> [--output <PATH>]` command.
+///
+/// Reports the duplicate functions and near-duplicate contracts across a build, with the
+/// estimated savings of their consolidation.
+///
+#[derive(Debug, clap::Args)]
+pub struct DedupReport {
+    /// The standard JSON output with `eravm.assemblyJson` or `eravm.assembly` selected.
+    pub input: PathBuf,
+    /// The report file. If not specified, the report is printed to stdout.
+    #[arg(long)]
+    pub output: Option<PathBuf>,
+}
+
+impl DedupReport {
+    ///
+    /// Builds the report and writes it to the file or stdout.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let report =
+            era_compiler_solidity::DedupReport::try_from_standard_json(self.input.as_path())?;
+        match self.output {
+            Some(path) => report.write_to_file(path.as_path())?,
+            None => writeln!(
+                std::io::stdout(),
+                "{}",
+                serde_json::to_string_pretty(&report).expect("Always valid")
+            )?,
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+//!
+//! The `zksolc disassemble` command.
+//!
+
+use era_solc::WithExitCode;
+
+///
+/// The `zksolc disassemble <INPUTS>...` command.
+///
+/// Decodes EraVM bytecode into EraVM assembly with instruction offsets, e.g. to inspect
+/// contracts deployed on-chain.
+///
+#[derive(Debug, clap::Args)]
+pub struct Disassemble {
+    /// The bytecode files or hexadecimal strings.
+    #[arg(required = true)]
+    pub inputs: Vec<String>,
+}
+
+impl Disassemble {
+    ///
+    /// Disassembles the bytecodes and prints the result.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        era_compiler_solidity::disassemble_eravm(self.inputs)
+            .with_exit_code(era_solc::ExitCode::InputError)
+    }
+}
@@ -0,0 +1,41 @@
+//!
+//! The `zksolc explain-bytecode` command.
+//!
+
+use std::io::Write;
+use std::path::PathBuf;
+
+///
+/// The `zksolc explain-bytecode <OUTPUT> <OFFSET> [--contract <CONTRACT>]` command.
+///
+/// Maps an EraVM bytecode offset, e.g. taken from a revert trace, to the instruction, the function
+/// containing it, and the Solidity declaration the function is generated from.
+///
+#[derive(Debug, clap::Args)]
+pub struct ExplainBytecode {
+    /// The standard JSON output with `eravm.assemblyJson` or `eravm.assembly` selected, and `ast`
+    /// selected for Solidity declarations to be resolved.
+    pub output: PathBuf,
+    /// The bytecode offset in bytes, in decimal or `0x`-prefixed hexadecimal format.
+    #[arg(value_parser = era_compiler_solidity::BytecodeExplanation::parse_offset)]
+    pub offset: usize,
+    /// The contract to explain the offset in, specified as `<path>:<name>` or `<name>`.
+    /// Required if the output contains several contracts.
+    #[arg(long)]
+    pub contract: Option<String>,
+}
+
+impl ExplainBytecode {
+    ///
+    /// Explains the offset and prints the result.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let explanation = era_compiler_solidity::BytecodeExplanation::try_from_standard_json(
+            self.output.as_path(),
+            self.offset,
+            self.contract.as_deref(),
+        )?;
+        write!(std::io::stdout(), "{explanation}")?;
+        Ok(())
+    }
+}
@@ -0,0 +1,257 @@
+//!
+//! The `zksolc help <topic>` pages.
+//!
+
+use std::io::Write;
+use std::str::FromStr;
+
+use clap::CommandFactory;
+
+use crate::arguments::Arguments;
+
+///
+/// The help topic, grouping the options by area.
+///
+/// The topic pages are generated from the argument definitions, so each option is documented once.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Topic {
+    /// Input languages, I/O modes, and integrated tools.
+    Input,
+    /// Output artifacts and their formats.
+    Output,
+    /// Options passed to `solc`.
+    Solc,
+    /// LLVM optimizer options.
+    Optimizer,
+    /// EraVM-specific options and diagnostics.
+    EraVM,
+    /// Reports and analysis artifacts.
+    Reports,
+    /// Build orchestration, caching, and reproducibility options.
+    Build,
+    /// Debugging and testing options.
+    Debug,
+}
+
+///
+/// The `zksolc help [TOPIC]` command.
+///
+#[derive(Debug, clap::Args)]
+pub struct Help {
+    /// The topic to print the options and examples of. If not specified, the topics are listed.
+    #[arg(value_parser = Topic::from_str)]
+    pub topic: Option<Topic>,
+}
+
+impl Help {
+    ///
+    /// Prints the topic page, or the list of topics.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let page = match self.topic {
+            Some(topic) => topic.render(),
+            None => Topic::render_index(),
+        };
+        writeln!(std::io::stdout(), "{page}")?;
+        Ok(())
+    }
+}
+
+impl Topic {
+    /// The command printing the topic pages, e.g. `zksolc help optimizer`.
+    pub const COMMAND: &'static str = "help";
+
+    /// All topics in the order they are listed.
+    pub const ALL: [Self; 8] = [
+        Self::Input,
+        Self::Output,
+        Self::Solc,
+        Self::Optimizer,
+        Self::EraVM,
+        Self::Reports,
+        Self::Build,
+        Self::Debug,
+    ];
+
+    ///
+    /// Returns the heading of the topic options in `--help`.
+    ///
+    pub fn heading(&self) -> &'static str {
+        match self {
+            Self::Input => "Input Modes",
+            Self::Output => "Output",
+            Self::Solc => "solc Settings",
+            Self::Optimizer => "Optimizer",
+            Self::EraVM => "EraVM",
+            Self::Reports => "Reports",
+            Self::Build => "Build",
+            Self::Debug => "Debugging",
+        }
+    }
+
+    ///
+    /// Returns the one-line description of the topic.
+    ///
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Input => {
+                "Input languages, standard and combined JSON modes, and integrated tools."
+            }
+            Self::Output => "Output artifacts, their selection and formats.",
+            Self::Solc => "Options passed to `solc` or controlling its invocation.",
+            Self::Optimizer => "LLVM optimizer options.",
+            Self::EraVM => "EraVM-specific features and diagnostics.",
+            Self::Reports => "Reports and analysis artifacts written alongside the build.",
+            Self::Build => "Parallelism, caching, and reproducibility of the build.",
+            Self::Debug => "Options for debugging the compiler and its output.",
+        }
+    }
+
+    ///
+    /// Returns the usage examples of the topic.
+    ///
+    pub fn examples(&self) -> &'static [&'static str] {
+        match self {
+            Self::Input => &[
+                "zksolc --standard-json './input.json'",
+                "zksolc './Simple.yul' --yul --bin",
+                "zksolc './Simple.sol' --combined-json 'abi,bin'",
+                "cat './Simple.sol' | zksolc - --stdin-name 'Simple.sol' --bin",
+                "zksolc --server --solc './solc-0.8.28'",
+            ],
+            Self::Output => &[
+                "zksolc './Simple.sol' --bin --asm --metadata",
+                "zksolc './Simple.sol' --output-selection 'bytecode,abi' --output-dir './build/'",
+            ],
+            Self::Solc => &[
+                "zksolc './Simple.sol' --bin --solc './solc-0.8.28' --codegen 'evmla'",
+                "zksolc './Simple.sol' --bin --evm-version 'cancun'",
+                "zksolc solc install '0.8.28'",
+                "zksolc './Simple.sol' --bin --solc-version '0.8.28' --offline",
+            ],
+            Self::Optimizer => &[
+                "zksolc './Simple.sol' --bin -O3",
+                "zksolc './Simple.sol' --bin -Oz --loop-optimization 'off'",
+            ],
+            Self::EraVM => &[
+                "zksolc './Simple.sol' --bin --suppress-warnings 'txorigin'",
+                "zksolc './Simple.sol' --bin --strict-eravm",
+            ],
+            Self::Reports => &[
+                "zksolc './Simple.sol' --bin --compatibility-report './compatibility.json'",
+                "zksolc './Simple.sol' --bin --inheritance-graph './inheritance.json'",
+                "zksolc './Simple.sol' --bin --output-dir './build' --emit 'dep-graph'",
+                "zksolc compare './baseline.zbin' './Simple.zbin'",
+                "zksolc dedup-report './output.json' --output './dedup.json'",
+            ],
+            Self::Build => &[
+                "zksolc './Simple.sol' --bin --threads 4",
+                "zksolc './Simple.sol' --bin --lock-file './zksolc.lock' --locked",
+                "zksolc './Simple.sol' --bin --cache-dir './cache/build'",
+                "zksolc cache warm './src/' --cache-dir './cache/build'",
+                "zksolc './Simple.sol' --bin --output-dir './build' --depfile './deps.d'",
+            ],
+            Self::Debug => &[
+                "zksolc './Simple.sol' --bin --debug-output-dir './debug/'",
+                "zksolc './Simple.sol' --bin --remarks-file './remarks.yaml'",
+            ],
+        }
+    }
+
+    ///
+    /// Renders the topic page with the topic options and examples.
+    ///
+    pub fn render(&self) -> String {
+        let arguments = Arguments::command();
+        let mut command = clap::Command::new(format!("zksolc {} {self}", Self::COMMAND))
+            .about(self.description())
+            .override_usage("zksolc [OPTIONS] [INPUTS]...")
+            .disable_help_flag(true)
+            .disable_version_flag(true)
+            .after_help(format!(
+                "Examples:\n{}",
+                Self::format_examples(self.examples())
+            ));
+        for argument in arguments.get_arguments() {
+            if argument.is_hide_set() || argument.get_help_heading() != Some(self.heading()) {
+                continue;
+            }
+            command = command.arg(argument.clone().help_heading(None::<&str>));
+        }
+        command.render_long_help().to_string()
+    }
+
+    ///
+    /// Renders the list of topics with an example of each.
+    ///
+    /// Appended to `--help`, and printed by `zksolc help` without a topic.
+    ///
+    pub fn render_index() -> String {
+        let topics = Self::ALL
+            .iter()
+            .map(|topic| format!("  {topic:<12}{}", topic.description()))
+            .collect::<Vec<String>>()
+            .join("\n");
+        let examples = Self::ALL
+            .iter()
+            .map(|topic| topic.examples()[0])
+            .collect::<Vec<&str>>();
+        format!(
+            "Topics:\n{topics}\n\nRun `zksolc {} <TOPIC>` for the options and examples of a topic.\n\nExamples:\n{}",
+            Self::COMMAND,
+            Self::format_examples(examples.as_slice()),
+        )
+    }
+
+    ///
+    /// Formats the examples as an indented list.
+    ///
+    fn format_examples(examples: &[&str]) -> String {
+        examples
+            .iter()
+            .map(|example| format!("  {example}"))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl FromStr for Topic {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "input" => Ok(Self::Input),
+            "output" => Ok(Self::Output),
+            "solc" => Ok(Self::Solc),
+            "optimizer" => Ok(Self::Optimizer),
+            "eravm" => Ok(Self::EraVM),
+            "reports" => Ok(Self::Reports),
+            "build" => Ok(Self::Build),
+            "debug" => Ok(Self::Debug),
+            topic => Err(anyhow::anyhow!(
+                "Invalid help topic `{topic}`. Available topics: {}.",
+                Self::ALL
+                    .iter()
+                    .map(|topic| format!("`{topic}`"))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for Topic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Input => write!(f, "input"),
+            Self::Output => write!(f, "output"),
+            Self::Solc => write!(f, "solc"),
+            Self::Optimizer => write!(f, "optimizer"),
+            Self::EraVM => write!(f, "eravm"),
+            Self::Reports => write!(f, "reports"),
+            Self::Build => write!(f, "build"),
+            Self::Debug => write!(f, "debug"),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+//!
+//! The `zksolc link` command.
+//!
+
+use std::path::PathBuf;
+
+use era_solc::WithExitCode;
+
+///
+/// The `zksolc link <INPUTS>... --libraries <LIBRARIES>... [--output-dir <OUTPUT_DIR>]` command.
+///
+/// Links previously emitted unlinked EraVM bytecode files with the library addresses, without
+/// recompiling, so the same build can be linked for each network at deploy time.
+///
+#[derive(Debug, clap::Args)]
+pub struct Link {
+    /// The unlinked bytecode files.
+    #[arg(required = true)]
+    pub inputs: Vec<String>,
+    /// The library addresses. Syntax: `<libraryFullPath1>=<address1> ... <libraryFullPathN>=<addressN>`.
+    /// Addresses are interpreted as hexadecimal strings prefixed with `0x`.
+    #[arg(short, long, num_args = 1.., required = true)]
+    pub libraries: Vec<String>,
+    /// The directory to write the linked files to, under their input paths.
+    /// If not specified, the input files are modified in place.
+    #[arg(short, long)]
+    pub output_dir: Option<PathBuf>,
+}
+
+impl Link {
+    ///
+    /// Links the bytecode files and prints the linked bytecode with the bytecode hashes.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        era_compiler_solidity::link_eravm(self.inputs, self.libraries, self.output_dir)
+            .with_exit_code(era_solc::ExitCode::InputError)
+    }
+}
@@ -3,14 +3,31 @@
 //!
 
 pub mod arguments;
+pub mod cache;
+pub mod command;
+pub mod compare;
+pub mod dedup_report;
+pub mod disassemble;
+pub mod explain_bytecode;
+pub mod help;
+pub mod link;
+pub mod solc;
+pub mod watch;
 
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
 
 use clap::Parser;
+use era_solc::CollectableError;
+use era_solc::WithExitCode;
 
 use self::arguments::Arguments;
+use self::watch::Watch;
 
 /// The rayon worker stack size.
 const RAYON_WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
@@ -23,11 +40,21 @@ static GLOBAL: mimalloc::MiMalloc = mimalloc::MiMalloc;
 /// The application entry point.
 ///
 fn main() -> anyhow::Result<()> {
-    let arguments = Arguments::try_parse()?;
+    let mut arguments = Arguments::try_parse()?;
+    if let Some(command) = arguments.command.take() {
+        return command.run();
+    }
+
     let is_standard_json = arguments.standard_json.is_some();
+    let sarif = (!is_standard_json
+        && arguments.error_format == Some(era_solc::StandardJsonInputErrorFormat::Sarif))
+    .then(era_solc::StandardJsonOutputErrorSarifDeferred::default);
     let mut messages = arguments.validate();
+    let mut exit_code = era_solc::ExitCode::InputError;
     if messages.iter().all(|error| error.severity != "error") {
-        if !is_standard_json {
+        if let Some(ref sarif) = sarif {
+            sarif.defer(messages.drain(..).collect());
+        } else if !is_standard_json {
             std::io::stderr()
                 .write_all(
                     messages
@@ -39,11 +66,24 @@ fn main() -> anyhow::Result<()> {
                 )
                 .expect("Stderr writing error");
         }
-        if let Err(error) = main_inner(arguments, &mut messages) {
-            messages.push(era_solc::StandardJsonOutputError::new_error(
-                error, None, None,
-            ));
-        }
+        exit_code = match main_inner(arguments, &mut messages, sarif.clone()) {
+            Ok(()) => era_solc::ExitCode::Success,
+            Err(error) => {
+                let exit_code = era_solc::ExitCode::from(&error);
+                let errors = error
+                    .downcast_ref::<era_solc::ExitCodeError>()
+                    .map(|error| error.errors.clone())
+                    .unwrap_or_default();
+                if sarif.is_some() && !errors.is_empty() {
+                    messages.extend(errors);
+                } else {
+                    messages.push(era_solc::StandardJsonOutputError::new_error(
+                        error, None, None,
+                    ));
+                }
+                exit_code
+            }
+        };
     }
 
     if is_standard_json {
@@ -51,11 +91,17 @@ fn main() -> anyhow::Result<()> {
         output.write_and_exit(era_solc::StandardJsonInputSelection::default());
     }
 
-    let exit_code = if messages.iter().any(|error| error.severity == "error") {
-        era_compiler_common::EXIT_CODE_FAILURE
-    } else {
-        era_compiler_common::EXIT_CODE_SUCCESS
-    };
+    if exit_code == era_solc::ExitCode::Success {
+        exit_code = messages
+            .iter()
+            .map(era_solc::StandardJsonOutputError::exit_code)
+            .max()
+            .unwrap_or(era_solc::ExitCode::Success);
+    }
+    if let Some(sarif) = sarif {
+        sarif.write(messages);
+        exit_code.exit();
+    }
     std::io::stderr()
         .write_all(
             messages
@@ -66,15 +112,16 @@ fn main() -> anyhow::Result<()> {
                 .as_bytes(),
         )
         .expect("Stderr writing error");
-    std::process::exit(exit_code);
+    exit_code.exit();
 }
 
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
 fn main_inner(
-    arguments: Arguments,
+    mut arguments: Arguments,
     messages: &mut Vec<era_solc::StandardJsonOutputError>,
+    sarif: Option<era_solc::StandardJsonOutputErrorSarifDeferred>,
 ) -> anyhow::Result<()> {
     if arguments.version {
         writeln!(
@@ -87,12 +134,37 @@ fn main_inner(
         return Ok(());
     }
 
-    let target = match arguments.target {
-        Some(ref target) => era_compiler_common::Target::from_str(target.as_str())?,
-        None => era_compiler_common::Target::EraVM,
+    if arguments.watch {
+        return Watch::new(&arguments)?.run();
+    }
+
+    arguments.apply_output_selection();
+
+    let mut targets = Vec::with_capacity(2);
+    match arguments.target {
+        Some(ref target) => {
+            for target in target.split(',') {
+                targets.push(
+                    era_compiler_common::Target::from_str(target.trim())
+                        .with_exit_code(era_solc::ExitCode::InputError)?,
+                );
+            }
+        }
+        None => targets.push(era_compiler_common::Target::EraVM),
+    }
+    let (target, is_dual_target) = match targets.as_slice() {
+        [target] => (*target, false),
+        [era_compiler_common::Target::EraVM, era_compiler_common::Target::EVM]
+        | [era_compiler_common::Target::EVM, era_compiler_common::Target::EraVM] => {
+            (era_compiler_common::Target::EraVM, true)
+        }
+        _ => anyhow::bail!(input_error(
+            "Only `eravm` and `evm` can be specified together as targets."
+        )),
     };
-    if let era_compiler_common::Target::EVM = target {
-        messages.push(era_solc::StandardJsonOutputError::new_warning("EVM target is under development and not fully functional yet. It must only be used for research and development purposes.", None, None))
+    if is_dual_target || matches!(target, era_compiler_common::Target::EVM) {
+        messages.push(era_solc::StandardJsonOutputError::new_warning("EVM target is under development and not fully functional yet. It must only be used for research and development purposes.", None, None));
+        check_eravm_only_arguments(&arguments)?;
     }
 
     let mut thread_pool_builder = rayon::ThreadPoolBuilder::new();
@@ -105,16 +177,109 @@ fn main_inner(
         .expect("Thread pool configuration failure");
 
     inkwell::support::enable_llvm_pretty_stack_trace();
-    era_compiler_llvm_context::initialize_target(target);
+    for target in targets.into_iter() {
+        era_compiler_llvm_context::initialize_target(target);
+    }
 
     if arguments.recursive_process {
         return era_compiler_solidity::run_recursive(target);
     }
 
-    let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
+    let temp_dir = if arguments.temp_dir.is_some() || arguments.temp_dir_policy.is_some() {
+        Some(
+            era_compiler_solidity::TempDir::try_new(
+                arguments.temp_dir.as_deref(),
+                arguments.temp_dir_policy.unwrap_or_default(),
+            )
+            .with_exit_code(era_solc::ExitCode::InputError)?,
+        )
+    } else {
+        None
+    };
+
+    let resource_monitor = Arc::new(era_compiler_solidity::ResourceMonitor::default());
+    let mut subscribers = era_compiler_solidity::EventSubscribers::default();
+    subscribers.subscribe(resource_monitor.clone());
+    let config = era_compiler_solidity::BuildConfig {
+        limits: if arguments.no_limits {
+            None
+        } else {
+            let limits = era_compiler_solidity::Limits::default();
+            Some(era_compiler_solidity::Limits {
+                max_sources: arguments.max_sources.unwrap_or(limits.max_sources),
+                max_contracts: arguments.max_contracts.unwrap_or(limits.max_contracts),
+                max_source_bytes: arguments
+                    .max_source_bytes
+                    .unwrap_or(limits.max_source_bytes),
+            })
+        },
+        deadline: arguments
+            .build_timeout
+            .map(|build_timeout| Instant::now() + Duration::from_secs(build_timeout)),
+        shuffle_seed: arguments.shuffle_seed,
+        debug_assertions: arguments.debug_assertions,
+        subprocesses: true,
+        subscribers,
+        sarif,
+        stdin_name: arguments
+            .stdin_name
+            .as_deref()
+            .map(era_solc::path::normalize),
+        cache_warming: arguments.cache_warming,
+        temp_dir: temp_dir.as_ref().map(|temp_dir| temp_dir.path().to_owned()),
+        max_nesting_depth: arguments.max_nesting_depth,
+    };
+
+    if arguments.solc.is_none()
+        && !arguments.solc_per_pragma
+        && !(arguments.yul
+            || arguments.llvm_ir
+            || arguments.eravm_assembly
+            || arguments.disassemble
+            || arguments.link)
+        && !era_solc::Compiler::is_default_in_path()
+    {
+        let version = arguments
+            .solc_version
+            .clone()
+            .unwrap_or(era_solc::Compiler::LAST_SUPPORTED_VERSION);
+        let solc = era_solc::Installer::try_new(arguments.offline)
+            .and_then(|installer| installer.resolve(&version))
+            .with_exit_code(era_solc::ExitCode::InputError);
+        match solc {
+            Ok(solc) => arguments.solc = Some(solc.executable),
+            // The standard JSON input may not require `solc`, which is checked later.
+            Err(_) if arguments.standard_json.is_some() || arguments.server => {}
+            Err(error) => return Err(error),
+        }
+    }
+
+    let lock_path = match arguments.lock_file {
+        Some(ref path) => Some(path.to_owned()),
+        None if arguments.locked => Some(PathBuf::from(era_compiler_solidity::lock::FILE_NAME)),
+        None => None,
+    };
+    let lock_settings = arguments.lock_settings();
+    let lock_solc = if arguments.llvm_ir || arguments.eravm_assembly {
+        None
+    } else if arguments.yul {
+        arguments.solc.clone()
+    } else {
+        Some(
+            arguments
+                .solc
+                .clone()
+                .unwrap_or_else(|| era_solc::Compiler::DEFAULT_EXECUTABLE_NAME.to_owned()),
+        )
+    };
+
+    let (input_files, remappings) = arguments
+        .split_input_files_and_remappings()
+        .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let mut optimizer_settings = match arguments.optimization {
-        Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)?,
+        Some(mode) => era_compiler_llvm_context::OptimizerSettings::try_from_cli(mode)
+            .with_exit_code(era_solc::ExitCode::InputError)?,
         None => era_compiler_llvm_context::OptimizerSettings::cycles(),
     };
     if arguments.fallback_to_optimizing_for_size {
@@ -123,7 +288,7 @@ fn main_inner(
     optimizer_settings.is_verify_each_enabled = arguments.llvm_verify_each;
     optimizer_settings.is_debug_logging_enabled = arguments.llvm_debug_logging;
 
-    let llvm_options: Vec<String> = arguments
+    let mut llvm_options: Vec<String> = arguments
         .llvm_options
         .as_ref()
         .map(|options| {
@@ -133,17 +298,77 @@ fn main_inner(
                 .collect()
         })
         .unwrap_or_default();
+    if let Some(loop_optimization) = arguments.loop_optimization {
+        llvm_options.extend(loop_optimization.llvm_options());
+    }
+    if let Some(spill_strategy) = arguments.spill_strategy {
+        llvm_options.extend(spill_strategy.llvm_options());
+    }
+    let balance_lowering = arguments.balance_lowering.unwrap_or_default();
+    let remarks_parts_path = arguments
+        .remarks_file
+        .as_ref()
+        .map(|remarks_file| match temp_dir {
+            Some(ref temp_dir) => temp_dir
+                .path()
+                .join(era_compiler_solidity::remarks::PARTS_FILE_NAME),
+            None => remarks_file.to_owned(),
+        });
+    if let Some(ref remarks_parts_path) = remarks_parts_path {
+        llvm_options.extend(era_compiler_solidity::remarks::llvm_options(
+            remarks_parts_path.as_path(),
+        ));
+    }
+
+    let execution_profile = match arguments.execution_profile {
+        Some(ref path) => Some(
+            era_compiler_solidity::ExecutionProfile::try_from_path(path.as_path())
+                .with_exit_code(era_solc::ExitCode::InputError)?,
+        ),
+        None => None,
+    };
+
+    let interface_freeze = match arguments.interface_freeze {
+        Some(ref path) => Some(
+            era_compiler_solidity::InterfaceFreeze::try_from_path(path.as_path())
+                .with_exit_code(era_solc::ExitCode::InputError)?,
+        ),
+        None => None,
+    };
+    let reports = era_compiler_solidity::Reports {
+        call_graph: arguments.call_graph.clone(),
+        inheritance_graph: arguments.inheritance_graph.clone(),
+        import_graph: arguments.import_graph.clone(),
+        dependency_graph: arguments
+            .emit
+            .contains(&era_compiler_solidity::Emit::DependencyGraph)
+            .then(|| arguments.output_dir.clone())
+            .flatten(),
+        compatibility_report: arguments.compatibility_report.clone(),
+        revert_decoder: arguments.revert_decoder.clone(),
+        interface_freeze,
+        solc_input_echo: arguments.solc_input_echo.clone(),
+        spill_report: arguments.spill_report.clone(),
+    };
+
+    let contract_filter = era_compiler_solidity::ContractFilter::new(
+        arguments.only.clone(),
+        arguments.contract.clone(),
+    );
 
     let suppressed_errors = era_solc::StandardJsonInputErrorType::try_from_strings(
         arguments.suppress_errors.unwrap_or_default().as_slice(),
-    )?;
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)?;
     let suppressed_warnings = era_solc::StandardJsonInputWarningType::try_from_strings(
         arguments.suppress_warnings.unwrap_or_default().as_slice(),
-    )?;
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)?;
 
     let debug_config = match arguments.debug_output_dir {
         Some(ref debug_output_directory) => {
-            std::fs::create_dir_all(debug_output_directory.as_path())?;
+            std::fs::create_dir_all(debug_output_directory.as_path())
+                .with_exit_code(era_solc::ExitCode::InputError)?;
             Some(era_compiler_llvm_context::DebugConfig::new(
                 debug_output_directory.to_owned(),
             ))
@@ -157,9 +382,157 @@ fn main_inner(
         .metadata_hash
         .unwrap_or(era_compiler_common::HashType::Keccak256);
 
+    if is_dual_target {
+        if arguments.server {
+            anyhow::bail!(input_error(
+                "Server mode is only available for the EraVM target."
+            ));
+        }
+
+        let solc_compiler = era_solc::Compiler::try_from_path(
+            arguments
+                .solc
+                .as_deref()
+                .unwrap_or(era_solc::Compiler::DEFAULT_EXECUTABLE_NAME),
+        )
+        .with_exit_code(era_solc::ExitCode::InputError)?
+        .with_temp_dir(config.temp_dir.as_deref())
+        .with_deadline(config.deadline);
+        let (mut eravm_build, mut evm_build) = era_compiler_solidity::standard_output_dual(
+            input_files.as_slice(),
+            arguments.libraries.as_slice(),
+            &solc_compiler,
+            messages,
+            arguments.codegen,
+            arguments.evm_version,
+            metadata_hash_type,
+            arguments.metadata_literal,
+            arguments.base_path,
+            arguments.include_path,
+            arguments.allow_paths,
+            remappings,
+            optimizer_settings,
+            llvm_options,
+            balance_lowering,
+            execution_profile.as_ref(),
+            contract_filter.as_ref(),
+            &reports,
+            arguments.output_assembly,
+            arguments.output_ast_json,
+            arguments.output_abi || arguments.output_hashes,
+            suppressed_errors,
+            suppressed_warnings,
+            arguments.strict_eravm,
+            arguments.ast_cache,
+            arguments.threads,
+            &config,
+            debug_config,
+        )?;
+
+        merge_remarks(
+            remarks_parts_path.as_deref(),
+            arguments.remarks_file.as_deref(),
+            reports.spill_report.as_deref(),
+            eravm_build
+                .results
+                .iter()
+                .map(|(path, result)| (path.as_str(), result.is_ok()))
+                .chain(
+                    evm_build
+                        .results
+                        .iter()
+                        .map(|(path, result)| (path.as_str(), result.is_ok())),
+                ),
+        )?;
+        if let Some(temp_dir) = temp_dir {
+            temp_dir.finish(!eravm_build.has_errors() && !evm_build.has_errors())?;
+        }
+
+        if let Some(ref lock_path) = lock_path {
+            update_lock_file(
+                lock_path.as_path(),
+                arguments.locked,
+                lock_solc.as_deref(),
+                &lock_settings,
+                input_files.as_slice(),
+                eravm_build
+                    .results
+                    .values()
+                    .map(|result| result.as_ref().ok().map(|contract| &contract.metadata_json))
+                    .chain(evm_build.results.values().map(|result| {
+                        result.as_ref().ok().map(|contract| &contract.metadata_json)
+                    })),
+            )?;
+        }
+
+        if let Some(ref contract) = arguments.contract {
+            eravm_build
+                .select_contract(contract.as_str())
+                .with_exit_code(era_solc::ExitCode::InputError)?;
+            evm_build
+                .select_contract(contract.as_str())
+                .with_exit_code(era_solc::ExitCode::InputError)?;
+        }
+
+        if let Some(output_directory) = arguments.output_dir {
+            eravm_build
+                .write_to_directory(
+                    &output_directory.join(era_compiler_common::Target::EraVM.to_string()),
+                    arguments.output_metadata,
+                    arguments.output_binary,
+                    arguments.output_abi,
+                    arguments.output_hashes,
+                    arguments.overwrite,
+                    config.sarif.as_ref(),
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?;
+            evm_build
+                .write_to_directory(
+                    &output_directory.join(era_compiler_common::Target::EVM.to_string()),
+                    arguments.output_metadata,
+                    arguments.output_assembly,
+                    arguments.output_binary,
+                    arguments.output_abi,
+                    arguments.output_hashes,
+                    arguments.overwrite,
+                    config.sarif.as_ref(),
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?;
+        } else {
+            writeln!(
+                std::io::stdout(),
+                "\n======= Target: {} =======",
+                era_compiler_common::Target::EraVM
+            )?;
+            eravm_build.write_to_terminal(
+                arguments.output_metadata,
+                arguments.output_assembly,
+                arguments.output_binary,
+                arguments.output_abi,
+                arguments.output_hashes,
+                config.sarif.as_ref(),
+            )?;
+            writeln!(
+                std::io::stdout(),
+                "\n======= Target: {} =======",
+                era_compiler_common::Target::EVM
+            )?;
+            evm_build.write_to_terminal(
+                arguments.output_metadata,
+                arguments.output_assembly,
+                arguments.output_binary,
+                arguments.output_abi,
+                arguments.output_hashes,
+                config.sarif.as_ref(),
+            )?;
+        }
+
+        return Ok(());
+    }
+
     match target {
         era_compiler_common::Target::EraVM => {
-            let build = if arguments.yul {
+            let mut build = if arguments.yul {
                 era_compiler_solidity::yul_to_eravm(
                     input_files.as_slice(),
                     arguments.libraries.as_slice(),
@@ -169,7 +542,11 @@ fn main_inner(
                     metadata_hash_type,
                     optimizer_settings,
                     llvm_options,
+                    balance_lowering,
+                    execution_profile.as_ref(),
+                    reports.call_graph.as_deref(),
                     arguments.output_assembly,
+                    &config,
                     debug_config,
                 )
             } else if arguments.llvm_ir {
@@ -181,6 +558,7 @@ fn main_inner(
                     optimizer_settings,
                     llvm_options,
                     arguments.output_assembly,
+                    &config,
                     debug_config,
                 )
             } else if arguments.eravm_assembly {
@@ -190,20 +568,50 @@ fn main_inner(
                     metadata_hash_type,
                     llvm_options,
                     arguments.output_assembly,
+                    &config,
                     debug_config,
                 )
             } else if arguments.disassemble {
-                return era_compiler_solidity::disassemble_eravm(arguments.inputs);
+                return era_compiler_solidity::disassemble_eravm(arguments.inputs)
+                    .with_exit_code(era_solc::ExitCode::InputError);
             } else if arguments.link {
                 return match arguments.standard_json {
                     Some(path) => era_compiler_solidity::link_eravm_json(path),
-                    None => {
-                        era_compiler_solidity::link_eravm(arguments.inputs, arguments.libraries)
-                    }
+                    None => era_compiler_solidity::link_eravm(
+                        arguments.inputs,
+                        arguments.libraries,
+                        arguments.output_dir,
+                    ),
+                }
+                .with_exit_code(era_solc::ExitCode::InputError);
+            } else if arguments.server {
+                let server = era_compiler_solidity::Server::new(
+                    arguments.solc,
+                    era_compiler_solidity::standard_json::Options {
+                        codegen: arguments.codegen,
+                        enable_eravm_extensions,
+                        detect_missing_libraries: arguments.detect_missing_libraries,
+                        base_path: arguments.base_path,
+                        include_paths: arguments.include_path,
+                        allow_paths: arguments.allow_paths,
+                        build: era_compiler_solidity::BuildConfig {
+                            deadline: None,
+                            ..config
+                        },
+                        debug_config,
+                    },
+                    arguments.build_timeout.map(Duration::from_secs),
+                );
+                return match arguments.server_socket {
+                    Some(path) => server.run_socket(path.as_path()),
+                    None => server.run_stdio(),
                 };
             } else if let Some(standard_json) = arguments.standard_json {
                 let solc_compiler = match arguments.solc.as_deref() {
-                    Some(executable) => Some(era_solc::Compiler::try_from_path(executable)?),
+                    Some(executable) => Some(
+                        era_solc::Compiler::try_from_path(executable)
+                            .with_exit_code(era_solc::ExitCode::InputError)?,
+                    ),
                     None => None,
                 };
                 return era_compiler_solidity::standard_json_eravm(
@@ -212,10 +620,12 @@ fn main_inner(
                     enable_eravm_extensions,
                     arguments.detect_missing_libraries,
                     standard_json.map(PathBuf::from),
+                    arguments.profile,
                     messages,
                     arguments.base_path,
                     arguments.include_path,
                     arguments.allow_paths,
+                    &config,
                     debug_config,
                 );
             } else if let Some(format) = arguments.combined_json {
@@ -224,7 +634,10 @@ fn main_inner(
                         .solc
                         .as_deref()
                         .unwrap_or(era_solc::Compiler::DEFAULT_EXECUTABLE_NAME),
-                )?;
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
                 return era_compiler_solidity::combined_json_eravm(
                     format,
                     input_files.as_slice(),
@@ -244,17 +657,78 @@ fn main_inner(
                     arguments.overwrite,
                     optimizer_settings,
                     llvm_options,
+                    balance_lowering,
                     suppressed_errors,
                     suppressed_warnings,
+                    temp_dir,
+                    arguments.strict_eravm,
+                    arguments.ast_cache,
+                    &config,
                     debug_config,
                 );
+            } else if arguments.solc_per_pragma {
+                let installer = era_solc::Installer::try_new(arguments.offline)
+                    .with_exit_code(era_solc::ExitCode::InputError)?;
+                let partitions = era_compiler_solidity::SolcPartition::try_from_paths(
+                    input_files.as_slice(),
+                    &remappings,
+                    arguments.base_path.as_deref(),
+                    arguments.include_path.as_slice(),
+                    &installer,
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?;
+                let mut builds = Vec::with_capacity(partitions.len());
+                for partition in partitions.into_iter() {
+                    let build = era_compiler_solidity::standard_output_eravm(
+                        partition.paths.as_slice(),
+                        arguments.libraries.as_slice(),
+                        &partition
+                            .solc
+                            .with_temp_dir(config.temp_dir.as_deref())
+                            .with_deadline(config.deadline),
+                        messages,
+                        arguments.codegen,
+                        arguments.evm_version,
+                        enable_eravm_extensions,
+                        metadata_hash_type,
+                        arguments.metadata_literal,
+                        arguments.base_path.clone(),
+                        arguments.include_path.clone(),
+                        arguments.allow_paths.clone(),
+                        remappings.clone(),
+                        optimizer_settings.clone(),
+                        llvm_options.clone(),
+                        balance_lowering,
+                        execution_profile.as_ref(),
+                        // The reports, the contract filter, and the dependency manifest are
+                        // rejected in `Arguments::validate`, as they cannot span the partitions.
+                        None,
+                        &era_compiler_solidity::Reports::default(),
+                        arguments.output_assembly,
+                        arguments.output_ast_json,
+                        arguments.output_abi || arguments.output_hashes,
+                        suppressed_errors.clone(),
+                        suppressed_warnings.clone(),
+                        arguments.strict_eravm,
+                        arguments.ast_cache.clone(),
+                        arguments.cache_dir.as_deref(),
+                        false,
+                        &config,
+                        debug_config.clone(),
+                    )?;
+                    builds.push(build);
+                }
+                Ok(era_compiler_solidity::EraVMBuild::merge(builds))
             } else {
                 let solc_compiler = era_solc::Compiler::try_from_path(
                     arguments
                         .solc
                         .as_deref()
                         .unwrap_or(era_solc::Compiler::DEFAULT_EXECUTABLE_NAME),
-                )?;
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
                 era_compiler_solidity::standard_output_eravm(
                     input_files.as_slice(),
                     arguments.libraries.as_slice(),
@@ -271,30 +745,144 @@ fn main_inner(
                     remappings,
                     optimizer_settings,
                     llvm_options,
+                    balance_lowering,
+                    execution_profile.as_ref(),
+                    contract_filter.as_ref(),
+                    &reports,
                     arguments.output_assembly,
+                    arguments.output_ast_json,
+                    arguments.output_abi || arguments.output_hashes,
                     suppressed_errors,
                     suppressed_warnings,
+                    arguments.strict_eravm,
+                    arguments.ast_cache,
+                    arguments.cache_dir.as_deref(),
+                    arguments.depfile.is_some(),
+                    &config,
                     debug_config,
                 )
             }?;
 
-            if let Some(output_directory) = arguments.output_dir {
-                build.write_to_directory(
-                    &output_directory,
-                    arguments.output_metadata,
-                    arguments.output_binary,
-                    arguments.overwrite,
+            merge_remarks(
+                remarks_parts_path.as_deref(),
+                arguments.remarks_file.as_deref(),
+                reports.spill_report.as_deref(),
+                build
+                    .results
+                    .iter()
+                    .map(|(path, result)| (path.as_str(), result.is_ok())),
+            )?;
+            let temp_disk_usage = temp_dir.as_ref().map(|temp_dir| {
+                era_compiler_solidity::ResourceUsage::directory_size(temp_dir.path())
+            });
+            if let Some(temp_dir) = temp_dir {
+                temp_dir.finish(!build.has_errors())?;
+            }
+            if arguments.cache_warming {
+                return Ok(());
+            }
+
+            if let Some(ref lock_path) = lock_path {
+                update_lock_file(
+                    lock_path.as_path(),
+                    arguments.locked,
+                    lock_solc.as_deref(),
+                    &lock_settings,
+                    input_files.as_slice(),
+                    build
+                        .results
+                        .values()
+                        .map(|result| result.as_ref().ok().map(|contract| &contract.metadata_json)),
                 )?;
+            }
+
+            if let Some(ref contract) = arguments.contract {
+                build
+                    .select_contract(contract.as_str())
+                    .with_exit_code(era_solc::ExitCode::InputError)?;
+            }
+
+            if let Some(output_directory) = arguments.output_dir {
+                let build_report = (arguments.build_report
+                    || arguments.signing_key.is_some()
+                    || arguments.balance_lowering.is_some())
+                .then(|| {
+                    era_compiler_solidity::BuildReport::new(
+                        &build,
+                        era_compiler_solidity::ResourceUsage::measure(
+                            resource_monitor.as_ref(),
+                            temp_disk_usage,
+                        ),
+                    )
+                });
+                let depfile = build.depfile.take();
+                let artifact_paths = if let Some(format) = arguments.format {
+                    build
+                        .write_artifacts(
+                            &output_directory,
+                            format,
+                            arguments.overwrite,
+                            config.sarif.as_ref(),
+                        )
+                        .with_exit_code(era_solc::ExitCode::InputError)?
+                } else {
+                    build
+                        .write_to_directory(
+                            &output_directory,
+                            arguments.output_metadata,
+                            arguments.output_binary,
+                            arguments.output_abi,
+                            arguments.output_hashes,
+                            arguments.overwrite,
+                            config.sarif.as_ref(),
+                        )
+                        .with_exit_code(era_solc::ExitCode::InputError)?
+                };
+                if let (Some(depfile), Some(depfile_path)) = (depfile, arguments.depfile.as_ref()) {
+                    depfile
+                        .write_to_file(depfile_path.as_path(), &artifact_paths)
+                        .with_exit_code(era_solc::ExitCode::InputError)?;
+                }
+                if let Some(mut build_report) = build_report {
+                    if let Some(ref signing_key) = arguments.signing_key {
+                        build_report = build_report
+                            .try_sign(
+                                signing_key.as_path(),
+                                output_directory.as_path(),
+                                &artifact_paths,
+                            )
+                            .with_exit_code(era_solc::ExitCode::InputError)?;
+                    }
+                    build_report.write_to_file(
+                        output_directory
+                            .join(era_compiler_solidity::BuildReport::FILE_NAME)
+                            .as_path(),
+                    )?;
+                }
             } else {
                 build.write_to_terminal(
                     arguments.output_metadata,
                     arguments.output_assembly,
                     arguments.output_binary,
+                    arguments.output_abi,
+                    arguments.output_hashes,
+                    config.sarif.as_ref(),
                 )?;
             }
         }
         era_compiler_common::Target::EVM => {
-            let build = if arguments.yul {
+            if arguments.strict_eravm {
+                anyhow::bail!(input_error(
+                    "Strict EraVM mode is only available for the EraVM target."
+                ));
+            }
+            if arguments.balance_lowering.is_some() {
+                anyhow::bail!(input_error(
+                    "Balance lowering strategies are only available for the EraVM target."
+                ));
+            }
+
+            let mut build = if arguments.yul {
                 era_compiler_solidity::yul_to_evm(
                     input_files.as_slice(),
                     arguments.libraries.as_slice(),
@@ -303,7 +891,10 @@ fn main_inner(
                     metadata_hash_type,
                     optimizer_settings,
                     llvm_options,
+                    execution_profile.as_ref(),
+                    reports.call_graph.as_deref(),
                     arguments.threads,
+                    &config,
                     debug_config,
                 )
             } else if arguments.llvm_ir {
@@ -315,6 +906,7 @@ fn main_inner(
                     optimizer_settings,
                     llvm_options,
                     arguments.threads,
+                    &config,
                     debug_config,
                 )
             } else if arguments.eravm_assembly {
@@ -324,26 +916,38 @@ fn main_inner(
                     metadata_hash_type,
                     llvm_options,
                     arguments.threads,
+                    &config,
                     debug_config,
                 )
             } else if arguments.disassemble {
-                anyhow::bail!("The EVM target does not support disassembling yet.");
+                anyhow::bail!(input_error(
+                    "The EVM target does not support disassembling yet."
+                ));
             } else if arguments.link {
-                anyhow::bail!("The EVM target does not support linking yet.");
+                anyhow::bail!(input_error("The EVM target does not support linking yet."));
+            } else if arguments.server {
+                anyhow::bail!(input_error(
+                    "The EVM target does not support server mode yet."
+                ));
             } else if let Some(standard_json) = arguments.standard_json {
                 let solc_compiler = match arguments.solc.as_deref() {
-                    Some(executable) => Some(era_solc::Compiler::try_from_path(executable)?),
+                    Some(executable) => Some(
+                        era_solc::Compiler::try_from_path(executable)
+                            .with_exit_code(era_solc::ExitCode::InputError)?,
+                    ),
                     None => None,
                 };
                 return era_compiler_solidity::standard_json_evm(
                     solc_compiler,
                     arguments.codegen,
                     standard_json.map(PathBuf::from),
+                    arguments.profile,
                     messages,
                     arguments.base_path,
                     arguments.include_path,
                     arguments.allow_paths,
                     arguments.threads,
+                    &config,
                     debug_config,
                 );
             } else if let Some(format) = arguments.combined_json {
@@ -352,7 +956,10 @@ fn main_inner(
                         .solc
                         .as_deref()
                         .unwrap_or(era_solc::Compiler::DEFAULT_EXECUTABLE_NAME),
-                )?;
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
                 return era_compiler_solidity::combined_json_evm(
                     format,
                     input_files.as_slice(),
@@ -371,7 +978,10 @@ fn main_inner(
                     arguments.overwrite,
                     optimizer_settings,
                     llvm_options,
+                    arguments.ast_cache,
                     arguments.threads,
+                    temp_dir,
+                    &config,
                     debug_config,
                 );
             } else {
@@ -380,7 +990,10 @@ fn main_inner(
                         .solc
                         .as_deref()
                         .unwrap_or(era_solc::Compiler::DEFAULT_EXECUTABLE_NAME),
-                )?;
+                )
+                .with_exit_code(era_solc::ExitCode::InputError)?
+                .with_temp_dir(config.temp_dir.as_deref())
+                .with_deadline(config.deadline);
                 era_compiler_solidity::standard_output_evm(
                     input_files.as_slice(),
                     arguments.libraries.as_slice(),
@@ -396,24 +1009,72 @@ fn main_inner(
                     remappings,
                     optimizer_settings,
                     llvm_options,
+                    execution_profile.as_ref(),
+                    contract_filter.as_ref(),
+                    &reports,
+                    arguments.output_ast_json,
+                    arguments.output_abi || arguments.output_hashes,
+                    arguments.ast_cache,
                     arguments.threads,
+                    &config,
                     debug_config,
                 )
             }?;
 
-            if let Some(output_directory) = arguments.output_dir {
-                build.write_to_directory(
-                    &output_directory,
-                    arguments.output_metadata,
-                    arguments.output_assembly,
-                    arguments.output_binary,
-                    arguments.overwrite,
+            merge_remarks(
+                remarks_parts_path.as_deref(),
+                arguments.remarks_file.as_deref(),
+                reports.spill_report.as_deref(),
+                build
+                    .results
+                    .iter()
+                    .map(|(path, result)| (path.as_str(), result.is_ok())),
+            )?;
+            if let Some(temp_dir) = temp_dir {
+                temp_dir.finish(!build.has_errors())?;
+            }
+
+            if let Some(ref lock_path) = lock_path {
+                update_lock_file(
+                    lock_path.as_path(),
+                    arguments.locked,
+                    lock_solc.as_deref(),
+                    &lock_settings,
+                    input_files.as_slice(),
+                    build
+                        .results
+                        .values()
+                        .map(|result| result.as_ref().ok().map(|contract| &contract.metadata_json)),
                 )?;
+            }
+
+            if let Some(ref contract) = arguments.contract {
+                build
+                    .select_contract(contract.as_str())
+                    .with_exit_code(era_solc::ExitCode::InputError)?;
+            }
+
+            if let Some(output_directory) = arguments.output_dir {
+                build
+                    .write_to_directory(
+                        &output_directory,
+                        arguments.output_metadata,
+                        arguments.output_assembly,
+                        arguments.output_binary,
+                        arguments.output_abi,
+                        arguments.output_hashes,
+                        arguments.overwrite,
+                        config.sarif.as_ref(),
+                    )
+                    .with_exit_code(era_solc::ExitCode::InputError)?;
             } else {
                 build.write_to_terminal(
                     arguments.output_metadata,
                     arguments.output_assembly,
                     arguments.output_binary,
+                    arguments.output_abi,
+                    arguments.output_hashes,
+                    config.sarif.as_ref(),
                 )?;
             }
         }
@@ -421,3 +1082,104 @@ fn main_inner(
 
     Ok(())
 }
+
+///
+/// Checks that no options only available for the EraVM target are specified for the EVM target.
+///
+fn check_eravm_only_arguments(arguments: &Arguments) -> anyhow::Result<()> {
+    if arguments.cache_warming {
+        anyhow::bail!(input_error(
+            "Cache warming is only available for the EraVM target."
+        ));
+    }
+    if arguments.signing_key.is_some() {
+        anyhow::bail!(input_error(
+            "The EVM target does not support artifact signing yet."
+        ));
+    }
+    if arguments.build_report {
+        anyhow::bail!(input_error(
+            "Build report is only available for the EraVM target."
+        ));
+    }
+    if arguments.cache_dir.is_some() {
+        anyhow::bail!(input_error(
+            "Build cache is only available for the EraVM target."
+        ));
+    }
+    if arguments.depfile.is_some() {
+        anyhow::bail!(input_error(
+            "Dependency manifest is only available for the EraVM target."
+        ));
+    }
+    if arguments.solc_per_pragma {
+        anyhow::bail!(input_error(
+            "`solc` per pragma resolution is only available for the EraVM target."
+        ));
+    }
+    if arguments.debug_assertions {
+        anyhow::bail!(input_error(
+            "Debug assertions are only available for the EraVM target."
+        ));
+    }
+    if arguments.format.is_some() {
+        anyhow::bail!(input_error(
+            "Artifact formats are only available for the EraVM target."
+        ));
+    }
+    Ok(())
+}
+
+///
+/// Merges the remarks of the built contracts into the remarks file, and writes the spill report from it.
+///
+/// Does nothing if the remarks file has not been requested.
+///
+fn merge_remarks<'a>(
+    remarks_parts_path: Option<&Path>,
+    remarks_file: Option<&Path>,
+    spill_report: Option<&Path>,
+    contract_paths: impl IntoIterator<Item = (&'a str, bool)>,
+) -> anyhow::Result<()> {
+    let (Some(remarks_parts_path), Some(remarks_file)) = (remarks_parts_path, remarks_file) else {
+        return Ok(());
+    };
+    era_compiler_solidity::remarks::merge(remarks_parts_path, remarks_file, contract_paths)?;
+    if let Some(spill_report) = spill_report {
+        era_compiler_solidity::SpillReport::try_from_remarks(remarks_file)?
+            .write_to_file(spill_report)?;
+    }
+    Ok(())
+}
+
+///
+/// Writes the lock file, or checks the build against it if `locked` is set.
+///
+/// Does nothing if some contracts have failed to build, which is denoted by `None` in `metadata_jsons`.
+///
+fn update_lock_file<'a>(
+    lock_path: &Path,
+    locked: bool,
+    lock_solc: Option<&str>,
+    lock_settings: &serde_json::Value,
+    input_files: &[PathBuf],
+    metadata_jsons: impl Iterator<Item = Option<&'a serde_json::Value>>,
+) -> anyhow::Result<()> {
+    let Some(metadata_jsons) = metadata_jsons.collect::<Option<Vec<&serde_json::Value>>>() else {
+        return Ok(());
+    };
+    let sources = metadata_jsons
+        .into_iter()
+        .flat_map(era_compiler_solidity::Lock::get_solc_source_hashes)
+        .collect();
+    era_compiler_solidity::Lock::new(lock_solc, lock_settings, input_files, sources)?
+        .update(lock_path, locked)
+        .with_exit_code(era_solc::ExitCode::InputError)
+}
+
+///
+/// Creates an error about invalid arguments, input files, or settings.
+///
+fn input_error(message: &str) -> era_solc::ExitCodeError {
+    era_solc::ExitCodeError::new(era_solc::ExitCode::InputError, message.to_owned())
+}
@@ -0,0 +1,62 @@
+//!
+//! The `zksolc solc` commands.
+//!
+
+use std::io::Write;
+
+///
+/// The `zksolc solc` commands.
+///
+#[derive(Debug, clap::Subcommand)]
+pub enum Solc {
+    /// Download the ZKsync fork of `solc` into the installation directory.
+    ///
+    /// The installation directory is taken from the `ZKSOLC_SOLC_DIR` environment variable, and
+    /// defaults to `zksolc/solc` in the user cache directory. The download is always verified
+    /// against the SHA-256 and Keccak-256 checksums of the release build list before it is executed.
+    Install(Install),
+}
+
+impl Solc {
+    ///
+    /// Runs the selected `solc` command.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        match self {
+            Self::Install(install) => install.run(),
+        }
+    }
+}
+
+///
+/// The `zksolc solc install <VERSION> [--sha256 <CHECKSUM>]` command.
+///
+/// Downloads the ZKsync fork of `solc` into the local installation directory, where it is found
+/// automatically if `--solc` is not provided and `solc` is not in `${PATH}`.
+///
+#[derive(Debug, clap::Args)]
+pub struct Install {
+    /// The `solc` version to install.
+    #[arg(value_parser = semver::Version::parse)]
+    pub version: semver::Version,
+    /// The expected SHA-256 checksum of the executable. The download is rejected if it does not match.
+    #[arg(long)]
+    pub sha256: Option<String>,
+}
+
+impl Install {
+    ///
+    /// Downloads and installs the executable.
+    ///
+    pub fn run(self) -> anyhow::Result<()> {
+        let installer = era_solc::Installer::try_new(false)?;
+        let compiler = installer.install(&self.version, self.sha256.as_deref())?;
+        writeln!(
+            std::io::stderr(),
+            "Installed `solc` v{} to {:?}.",
+            compiler.version.long,
+            compiler.executable
+        )?;
+        Ok(())
+    }
+}
@@ -0,0 +1,278 @@
+//!
+//! The watch mode recompiling the project on file changes.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::ffi::OsString;
+use std::io::Write;
+use std::path::PathBuf;
+use std::time::Duration;
+use std::time::Instant;
+use std::time::SystemTime;
+
+use crate::arguments::Arguments;
+
+///
+/// The watch mode recompiling the project on file changes.
+///
+/// Each compilation is run in a child process with the same arguments except `--watch`, so the
+/// diagnostics and outputs are the same as the ones of a regular run.
+///
+/// In Solidity mode, the child process also writes the import graph, so the imported sources
+/// outside of the watched paths are watched as well.
+///
+#[derive(Debug)]
+pub struct Watch {
+    /// The watched files and directories.
+    pub paths: Vec<PathBuf>,
+    /// The directories written by the compilation, which are never watched.
+    pub excluded_paths: Vec<PathBuf>,
+    /// The import graph written by each compilation, if the imports are watched.
+    pub import_graph_path: Option<PathBuf>,
+    /// The directories the imported source paths are resolved against.
+    pub import_roots: Vec<PathBuf>,
+    /// The imported sources of the last compilation.
+    pub imports: BTreeSet<PathBuf>,
+    /// The arguments of each compilation.
+    pub arguments: Vec<OsString>,
+}
+
+impl Watch {
+    /// The interval between the checks for changes.
+    pub const POLLING_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// The extensions of the watched files in the watched directories.
+    pub const EXTENSIONS: [&'static str; 6] = ["sol", "yul", "ll", "zasm", "json", "hex"];
+
+    /// The file name suffix of the import graph written by the child process.
+    pub const IMPORT_GRAPH_FILE_NAME: &'static str = "imports.json";
+
+    ///
+    /// Collects the watched paths from the parsed `arguments`.
+    ///
+    /// The inputs, the existing remapping targets, the base path, and the include paths are watched.
+    /// If none of them are specified, the current directory is watched as the project root.
+    /// The output and cache directories are excluded, so the outputs do not trigger recompilation.
+    ///
+    pub fn new(arguments: &Arguments) -> anyhow::Result<Self> {
+        let mut paths = Vec::new();
+        match arguments.standard_json {
+            Some(Some(ref path)) => paths.push(PathBuf::from(path)),
+            Some(None) => anyhow::bail!("Watch mode requires the standard JSON input file path."),
+            None => {
+                let (input_files, remappings) = arguments.split_input_files_and_remappings()?;
+                paths.extend(input_files);
+                paths.extend(
+                    era_solc::StandardJsonInputRemapping::try_from_strings(&remappings)?
+                        .into_iter()
+                        .map(|remapping| PathBuf::from(remapping.target))
+                        .filter(|path| path.exists()),
+                );
+            }
+        }
+        paths.extend(arguments.base_path.iter().map(PathBuf::from));
+        paths.extend(arguments.include_path.iter().map(PathBuf::from));
+        if paths.is_empty() {
+            paths.push(
+                std::env::current_dir()
+                    .map_err(|error| anyhow::anyhow!("Current directory getting: {error}"))?,
+            );
+        }
+
+        let excluded_paths = arguments
+            .output_dir
+            .iter()
+            .chain(arguments.cache_dir.iter())
+            .cloned()
+            .collect();
+
+        let mut child_arguments: Vec<OsString> = std::env::args_os()
+            .skip(1)
+            .filter(|argument| argument.to_str() != Some("--watch"))
+            .collect();
+        let is_solidity_mode = !arguments.yul
+            && !arguments.llvm_ir
+            && !arguments.eravm_assembly
+            && !arguments.solc_per_pragma
+            && arguments.standard_json.is_none()
+            && arguments.combined_json.is_none();
+        let import_graph_path = if !is_solidity_mode {
+            None
+        } else if let Some(ref path) = arguments.import_graph {
+            Some(path.to_owned())
+        } else {
+            let path = std::env::temp_dir().join(format!(
+                "zksolc-watch-{}.{}",
+                std::process::id(),
+                Self::IMPORT_GRAPH_FILE_NAME
+            ));
+            child_arguments.push(OsString::from("--import-graph"));
+            child_arguments.push(path.clone().into_os_string());
+            Some(path)
+        };
+        let import_roots = std::iter::once(PathBuf::new())
+            .chain(arguments.base_path.iter().map(PathBuf::from))
+            .chain(arguments.include_path.iter().map(PathBuf::from))
+            .collect();
+
+        Ok(Self {
+            paths,
+            excluded_paths,
+            import_graph_path,
+            import_roots,
+            imports: BTreeSet::new(),
+            arguments: child_arguments,
+        })
+    }
+
+    ///
+    /// Compiles the project, and recompiles it on each change of the watched files.
+    ///
+    /// Only returns on errors, and is expected to be stopped with Ctrl+C.
+    ///
+    pub fn run(mut self) -> anyhow::Result<()> {
+        let executable = std::env::current_exe()
+            .map_err(|error| anyhow::anyhow!("Current executable path getting: {error}"))?;
+
+        writeln!(
+            std::io::stderr(),
+            "Watching {} for changes. Press Ctrl+C to stop.",
+            self.paths
+                .iter()
+                .map(|path| format!("{path:?}"))
+                .collect::<Vec<String>>()
+                .join(", "),
+        )?;
+        loop {
+            let start_time = Instant::now();
+            let status = std::process::Command::new(executable.as_path())
+                .args(self.arguments.as_slice())
+                .status()
+                .map_err(|error| anyhow::anyhow!("Compiler subprocess running: {error}"))?;
+            writeln!(
+                std::io::stderr(),
+                "\nCompilation finished with exit code {} in {} ms. Waiting for changes...",
+                status.code().unwrap_or(-1),
+                start_time.elapsed().as_millis(),
+            )?;
+
+            // The fingerprint is taken after the compilation, so its own outputs are not
+            // considered changes.
+            self.update_imports();
+            let mut fingerprint = self.fingerprint();
+
+            let changed = loop {
+                std::thread::sleep(Self::POLLING_INTERVAL);
+                let new_fingerprint = self.fingerprint();
+                let changed = new_fingerprint
+                    .iter()
+                    .filter(|(path, modified)| fingerprint.get(*path) != Some(*modified))
+                    .map(|(path, _)| path.to_owned())
+                    .chain(
+                        fingerprint
+                            .keys()
+                            .filter(|path| !new_fingerprint.contains_key(*path))
+                            .cloned(),
+                    )
+                    .collect::<Vec<PathBuf>>();
+                fingerprint = new_fingerprint;
+                if !changed.is_empty() {
+                    break changed;
+                }
+            };
+            writeln!(
+                std::io::stderr(),
+                "\nChanges detected in {:?}{}, recompiling...",
+                changed[0],
+                match changed.len() {
+                    1 => String::new(),
+                    count => format!(" and {} more", count - 1),
+                },
+            )?;
+        }
+    }
+
+    ///
+    /// Reads the imported sources from the import graph written by the last compilation.
+    ///
+    /// The source paths are resolved against the current directory, the base path, and the
+    /// include paths, like `solc` does. If the graph cannot be read, e.g. because the last
+    /// compilation has failed early, the previous imports are kept.
+    ///
+    fn update_imports(&mut self) {
+        let Some(ref import_graph_path) = self.import_graph_path else {
+            return;
+        };
+        let Ok(import_graph) = std::fs::read(import_graph_path.as_path()) else {
+            return;
+        };
+        let Ok(import_graph) = serde_json::from_slice::<era_solc::StandardJsonOutputImportGraph>(
+            import_graph.as_slice(),
+        ) else {
+            return;
+        };
+
+        self.imports = import_graph
+            .sources
+            .keys()
+            .filter_map(|path| {
+                self.import_roots
+                    .iter()
+                    .map(|root| root.join(path.as_str()))
+                    .find(|path| path.is_file())
+            })
+            .collect();
+    }
+
+    ///
+    /// Returns the modification times and sizes of the watched files.
+    ///
+    /// The directories are traversed recursively, collecting the files with the watched extensions,
+    /// except the excluded directories. The imported sources are watched wherever they are located.
+    /// Files that cannot be read are skipped, and reported as changed once they become readable.
+    ///
+    fn fingerprint(&self) -> BTreeMap<PathBuf, (SystemTime, u64)> {
+        let excluded_paths = self
+            .excluded_paths
+            .iter()
+            .filter_map(|path| std::fs::canonicalize(path).ok())
+            .collect::<BTreeSet<PathBuf>>();
+
+        let mut fingerprint = BTreeMap::new();
+        let mut paths = self.paths.clone();
+        paths.extend(self.imports.iter().cloned());
+        while let Some(path) = paths.pop() {
+            let Ok(metadata) = std::fs::metadata(path.as_path()) else {
+                continue;
+            };
+            if metadata.is_dir() {
+                if std::fs::canonicalize(path.as_path())
+                    .is_ok_and(|path| excluded_paths.contains(&path))
+                {
+                    continue;
+                }
+                let Ok(entries) = std::fs::read_dir(path.as_path()) else {
+                    continue;
+                };
+                paths.extend(
+                    entries
+                        .filter_map(Result::ok)
+                        .map(|entry| entry.path())
+                        .filter(|path| {
+                            path.is_dir()
+                                || path
+                                    .extension()
+                                    .and_then(|extension| extension.to_str())
+                                    .is_some_and(|extension| Self::EXTENSIONS.contains(&extension))
+                        }),
+                );
+                continue;
+            }
+            if let Ok(modified) = metadata.modified() {
+                fingerprint.insert(path, (modified, metadata.len()));
+            }
+        }
+        fingerprint
+    }
+}
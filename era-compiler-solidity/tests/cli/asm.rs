@@ -67,7 +67,8 @@ fn invalid_input(target: Target) -> anyhow::Result<()> {
         .status
         .code()
         .expect("No exit code.");
-    solc_result.code(result_exit_code);
+    assert_eq!(result_exit_code, era_solc::ExitCode::SolcError.code());
+    solc_result.failure();
 
     Ok(())
 }
@@ -0,0 +1,176 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--ast-cache",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    let entries = std::fs::read_dir(tmp_dir.path())?.count();
+    assert!(entries > 0, "The AST cache is empty");
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(tmp_dir.path())?.count(),
+        entries,
+        "The AST cache has been invalidated"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cached_messages() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--ast-cache",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    for _ in 0..2 {
+        let result = crate::cli::execute_zksolc(args)?;
+        result
+            .success()
+            .stderr(predicate::str::contains("You are checking for 'tx.origin'"));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn import_invalidation() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_cache")?;
+    let cache_dir = tmp_dir.path().join("cache");
+    let importing_path = tmp_dir.path().join("A.sol");
+    let imported_path = tmp_dir.path().join("B.sol");
+    std::fs::write(
+        importing_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\nimport \"./B.sol\";\ncontract A is B {}\n",
+    )?;
+    std::fs::write(
+        imported_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\ncontract B {}\n",
+    )?;
+
+    let args = &[
+        "--bin",
+        importing_path.to_str().unwrap(),
+        "--ast-cache",
+        cache_dir.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+    assert_eq!(std::fs::read_dir(cache_dir.as_path())?.count(), 2);
+
+    std::fs::write(
+        imported_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\ncontract B { uint256 value; }\n",
+    )?;
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+    assert_eq!(
+        std::fs::read_dir(cache_dir.as_path())?.count(),
+        4,
+        "The importing source entry has not been invalidated"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn suppressed_warnings_invalidation() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_cache")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--ast-cache",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("You are checking for 'tx.origin'"));
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--ast-cache",
+        tmp_dir.path().to_str().unwrap(),
+        "--suppress-warnings",
+        "txorigin",
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("You are checking for 'tx.origin'").not());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--ast-cache",
+        "ast_cache",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "AST cache is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--ast-cache",
+        "ast_cache",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "AST cache must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
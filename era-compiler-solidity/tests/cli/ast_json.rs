@@ -0,0 +1,132 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--ast-json"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("JSON AST (compact format):"))
+        .stdout(predicate::str::contains("\"nodeType\":\"SourceUnit\""));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_dir(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_json_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--ast-json",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let ast_path = tmp_dir
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_NAME)
+        .join(format!(
+            "{}_json.ast",
+            crate::common::TEST_SOLIDITY_CONTRACT_NAME
+        ));
+    let ast: serde_json::Value = serde_json::from_str(std::fs::read_to_string(ast_path)?.as_str())?;
+    assert_eq!(
+        ast.get("nodeType").and_then(|node| node.as_str()),
+        Some("SourceUnit")
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_dir_duplicate_names(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("ast_json_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+        "--ast-json",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    for path in [
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+    ] {
+        let ast_path = tmp_dir.path().join(path).join(format!(
+            "{}_json.ast",
+            crate::common::TEST_SOLIDITY_CONTRACT_NAME
+        ));
+        let ast: serde_json::Value =
+            serde_json::from_str(std::fs::read_to_string(ast_path)?.as_str())?;
+        assert_eq!(
+            ast.get("nodeType").and_then(|node| node.as_str()),
+            Some("SourceUnit")
+        );
+        assert_eq!(
+            ast.get("absolutePath").and_then(|path| path.as_str()),
+            Some(path)
+        );
+    }
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn yul(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[crate::common::TEST_YUL_CONTRACT_PATH, "--yul", "--ast-json"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "AST output is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_AST_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"ast\":{"))
+        .stdout(predicate::str::contains("\"nodeType\":\"SourceUnit\""));
+
+    Ok(())
+}
@@ -0,0 +1,118 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--build-timeout",
+        "3600",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM, crate::common::TEST_LLVM_IR_CONTRACT_PATH)]
+#[test_case(Target::EVM, crate::common::TEST_LLVM_IR_CONTRACT_EVM_PATH)]
+fn exceeded(target: Target, path: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[path, "--llvm-ir", "--bin", "--build-timeout", "0"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let status_code = result
+        .failure()
+        .stderr(
+            predicate::str::contains("has been skipped, as the build timeout has been exceeded")
+                .and(predicate::str::contains("Completed contracts: none.")),
+        )
+        .get_output()
+        .status
+        .code()
+        .expect("No exit code.");
+    assert_eq!(status_code, era_solc::ExitCode::BuildTimeout.code());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn exceeded_solc(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--build-timeout",
+        "0",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let status_code = result
+        .failure()
+        .stderr(
+            predicate::str::contains(
+                "subprocess has been killed, as the build timeout has been exceeded.",
+            )
+            .and(predicate::str::contains("has been skipped").not()),
+        )
+        .get_output()
+        .status
+        .code()
+        .expect("No exit code.");
+    assert_eq!(status_code, era_solc::ExitCode::BuildTimeout.code());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn exceeded_standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--build-timeout",
+        "0",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let errors: Vec<&str> = output["errors"]
+        .as_array()
+        .expect("The messages are missing")
+        .iter()
+        .filter(|error| error["severity"] == "error")
+        .filter_map(|error| error["message"].as_str())
+        .collect();
+    assert_eq!(errors.len(), 1, "Unexpected errors: {errors:?}");
+    assert!(
+        errors[0].ends_with("subprocess has been killed, as the build timeout has been exceeded."),
+        "Unexpected error: {}",
+        errors[0]
+    );
+    assert_eq!(
+        output["contracts"]
+            .as_object()
+            .map(serde_json::Map::len)
+            .unwrap_or_default(),
+        0
+    );
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn solidity(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("call_graph")?;
+
+    let args = &[
+        "--bin",
+        "--codegen",
+        "yul",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--call-graph",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let call_graph = std::fs::read_to_string(tmp_dir.path().join("call_graph.json"))?;
+    assert!(call_graph.contains("\"internal\""));
+    assert!(tmp_dir.path().join("call_graph.dot").exists());
+    assert!(tmp_dir
+        .path()
+        .join("Test.sol")
+        .join("Test.call_graph.json")
+        .exists());
+
+    Ok(())
+}
+
+#[test]
+fn evmla() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("call_graph")?;
+
+    let args = &[
+        "--bin",
+        "--codegen",
+        "evmla",
+        crate::common::TEST_SOLIDITY_CONTRACT_RECURSION_PATH,
+        "--call-graph",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let call_graph: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(
+            tmp_dir
+                .path()
+                .join("Recursion.sol")
+                .join("Recursion.call_graph.json"),
+        )?
+        .as_str(),
+    )?;
+    let edges = call_graph["edges"].as_array().expect("Always exists");
+    let runtime_object = format!(
+        "{}:Recursion_deployed",
+        crate::common::TEST_SOLIDITY_CONTRACT_RECURSION_PATH
+    );
+    assert!(
+        edges
+            .iter()
+            .any(|edge| edge["object"] == runtime_object.as_str()
+                && edge["kind"] == "internal"
+                && edge["caller"]
+                    .as_str()
+                    .is_some_and(|caller| caller.starts_with("tag_"))
+                && edge["caller"] == edge["callee"]),
+        "The recursive call of `fib` is missing: {edges:?}"
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn same_file_name(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("call_graph")?;
+
+    let args = &[
+        "--bin",
+        "--codegen",
+        "yul",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+        "--call-graph",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let call_graph = std::fs::read_to_string(
+        tmp_dir
+            .path()
+            .join(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+            .join("Test.call_graph.json"),
+    )?;
+    assert!(!call_graph.contains("fun_set_"));
+    let call_graph_duplicate = std::fs::read_to_string(
+        tmp_dir
+            .path()
+            .join(crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH)
+            .join("Test.call_graph.json"),
+    )?;
+    assert!(call_graph_duplicate.contains("fun_set_"));
+    assert!(!tmp_dir.path().join("Test.sol").exists());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn yul(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("call_graph")?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--call-graph",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    assert!(tmp_dir.path().join("call_graph.json").exists());
+    assert!(tmp_dir.path().join("call_graph.dot").exists());
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--call-graph",
+        "call_graph",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Call graph is only available in Solidity and Yul modes.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--call-graph",
+        "call_graph",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Call graph cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--call-graph",
+        "call_graph",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Call graph cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
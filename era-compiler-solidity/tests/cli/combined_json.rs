@@ -68,9 +68,10 @@ fn all_yul(target: Target) -> anyhow::Result<()> {
             .status
             .code()
             .expect("No exit code.");
+        assert_eq!(status_code, era_solc::ExitCode::SolcError.code());
 
         let solc_result = crate::cli::execute_solc(args)?;
-        solc_result.code(status_code);
+        solc_result.failure();
     }
 
     Ok(())
@@ -105,6 +106,82 @@ fn two_files(target: Target) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn duplicate_names(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+        "--combined-json",
+        "bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let contracts = output["contracts"].as_object().expect("Always exists");
+    assert_eq!(contracts.len(), 2);
+    let bytecode =
+        &contracts[format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str()]["bin"];
+    let duplicate_bytecode = &contracts[format!(
+        "{}:Test",
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH
+    )
+    .as_str()]["bin"];
+    assert!(bytecode.is_string());
+    assert!(duplicate_bytecode.is_string());
+    assert_ne!(bytecode, duplicate_bytecode);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn solc_compatible(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "abi,bin,bin-runtime,hashes,metadata",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let solc_result = crate::cli::execute_solc(args)?;
+    let solc_output: serde_json::Value =
+        serde_json::from_slice(solc_result.success().get_output().stdout.as_slice())?;
+
+    let contracts = output["contracts"].as_object().expect("Always exists");
+    let solc_contracts = solc_output["contracts"].as_object().expect("Always exists");
+    assert_eq!(
+        contracts.keys().collect::<Vec<&String>>(),
+        solc_contracts.keys().collect::<Vec<&String>>()
+    );
+    for (full_path, solc_contract) in solc_contracts.iter() {
+        let contract = &contracts[full_path.as_str()];
+        for key in solc_contract.as_object().expect("Always exists").keys() {
+            assert!(
+                contract.get(key).is_some(),
+                "Contract `{full_path}` is missing the `{key}` field"
+            );
+        }
+        assert_eq!(contract["abi"], solc_contract["abi"]);
+        assert_eq!(contract["hashes"], solc_contract["hashes"]);
+        assert!(contract["metadata"].is_string());
+        assert!(contract["bin"].is_string());
+        assert!(contract["bin-runtime"].is_string());
+    }
+    assert!(output["version"].is_string());
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn invalid_path(target: Target) -> anyhow::Result<()> {
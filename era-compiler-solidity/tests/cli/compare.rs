@@ -0,0 +1,150 @@
+//!
+//! CLI tests for the `zksolc compare` command.
+//!
+
+use predicates::prelude::*;
+
+/// The test bytecode with the `solc` version 0.8.23 in the metadata trailer.
+const BYTECODE_V0_8_23: &str = "0x0000008003000039000000400030043fa164736f6c6343000817000a";
+
+/// The test bytecode with the `solc` version 0.8.24 in the metadata trailer.
+const BYTECODE_V0_8_24: &str = "0x0000008003000039000000400030043fa164736f6c6343000818000a";
+
+/// The test bytecode with different code.
+const BYTECODE_DIFFERENT: &str = "0x0000008103000039000000400030043fa164736f6c6343000817000a";
+
+/// The test bytecode with a raw `keccak256` hash in the metadata trailer.
+const BYTECODE_KECCAK256: &str = "0x0000008003000039000000400030043f0000000000000000000000000000000a00000000000000000000000000000000000000000000000000000000000000001c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8";
+
+/// The test bytecode with another raw `keccak256` hash in the metadata trailer.
+const BYTECODE_KECCAK256_OTHER: &str = "0x0000008003000039000000400030043f0000000000000000000000000000000a0000000000000000000000000000000000000000000000000000000000000000b10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0c00";
+
+#[test]
+fn identical() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "compare",
+        "--metadata-hash",
+        "none",
+        BYTECODE_V0_8_23,
+        BYTECODE_V0_8_23,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Bytecodes are identical."));
+
+    Ok(())
+}
+
+#[test]
+fn equivalent_modulo_metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "compare",
+        "--metadata-hash",
+        "none",
+        BYTECODE_V0_8_23,
+        BYTECODE_V0_8_24,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Bytecodes are equivalent modulo metadata.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn different() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "compare",
+        "--metadata-hash",
+        "none",
+        BYTECODE_V0_8_23,
+        BYTECODE_DIFFERENT,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .code(era_compiler_common::EXIT_CODE_FAILURE)
+        .stdout(predicate::str::contains(
+            "Bytecodes differ at code offset 0x3.",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn equivalent_modulo_metadata_keccak256() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["compare", BYTECODE_KECCAK256, BYTECODE_KECCAK256_OTHER];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::eq("Bytecodes are equivalent modulo metadata.\n"));
+
+    Ok(())
+}
+
+#[test]
+fn different_keccak256_as_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "compare",
+        "--metadata-hash",
+        "none",
+        BYTECODE_KECCAK256,
+        BYTECODE_KECCAK256_OTHER,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .code(era_compiler_common::EXIT_CODE_FAILURE)
+        .stdout(predicate::eq("Bytecodes differ at code offset 0x40.\n"));
+
+    Ok(())
+}
+
+#[test]
+fn file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "compare",
+        crate::common::TEST_DISASSEMBLER_BYTECODE_PATH,
+        crate::common::TEST_DISASSEMBLER_BYTECODE_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Bytecodes are identical."));
+
+    Ok(())
+}
+
+#[test]
+fn missing_input() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["compare", BYTECODE_V0_8_23];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "the following required arguments were not provided",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("compatibility_report")?;
+    let report_path = tmp_dir.path().join("compatibility.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_COMPATIBILITY_PATH,
+        "--compatibility-report",
+        report_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let report: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(report_path)?.as_str())?;
+    let contract = &report["contracts"][format!(
+        "{}:Compatibility",
+        crate::common::TEST_SOLIDITY_CONTRACT_COMPATIBILITY_PATH
+    )];
+    assert_eq!(contract["summary"]["address-derivation"], 1);
+    assert_eq!(contract["summary"]["code-introspection"], 1);
+    assert_eq!(contract["summary"]["gas"], 1);
+    assert_eq!(contract["summary"]["block-info"], 2);
+    assert_eq!(contract["summary"]["account-abstraction"], 1);
+    assert_eq!(contract["findings"][0]["construct"], "new Child");
+    assert_eq!(
+        contract["findings"][0]["location"],
+        format!(
+            "{}:9:24",
+            crate::common::TEST_SOLIDITY_CONTRACT_COMPATIBILITY_PATH
+        )
+    );
+    assert!(report["kinds"]["address-derivation"].is_string());
+    assert!(report["contracts"][format!(
+        "{}:Child",
+        crate::common::TEST_SOLIDITY_CONTRACT_COMPATIBILITY_PATH
+    )]
+    .is_null());
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--compatibility-report",
+        "compatibility.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Compatibility report is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--compatibility-report",
+        "compatibility.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Compatibility report cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--compatibility-report",
+        "compatibility.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Compatibility report cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,153 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--contract",
+        "Callable",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Callable.sol:Callable"))
+        .stdout(predicate::str::contains("Main.sol:Main").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn full_path(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--contract",
+        "caller/Main.sol:Main",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Main.sol:Main"))
+        .stdout(predicate::str::contains("Callable.sol:Callable").not());
+
+    Ok(())
+}
+
+#[test]
+fn other_contracts_not_compiled() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = tempfile::TempDir::with_prefix("contract")?;
+    let source_path = tmp_dir.path().join("Contracts.sol");
+    std::fs::write(
+        source_path.as_path(),
+        r#"
+// SPDX-License-Identifier: Unlicensed
+pragma solidity >=0.8.0;
+
+contract Selected {}
+
+contract Unsupported {
+    function f(address target) public returns (bool success) {
+        assembly {
+            success := callcode(gas(), target, 0, 0, 0, 0, 0)
+        }
+    }
+}
+"#,
+    )?;
+
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--contract",
+        "Selected",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contracts.sol:Selected"))
+        .stdout(predicate::str::contains("Unsupported").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn ambiguous(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH,
+        "--contract",
+        "Test",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Contract `Test` is ambiguous"))
+        .stderr(predicate::str::contains("Test.sol:Test"))
+        .stderr(predicate::str::contains("InterfaceEmptyYul.sol:Test"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn not_found(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--contract",
+        "Missing",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Contract `Missing` not found"))
+        .stderr(predicate::str::contains("Test.sol:Test"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--contract",
+        "Test",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Contract selection must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
@@ -2,6 +2,8 @@
 //! CLI tests for the eponymous option.
 //!
 
+use std::collections::BTreeSet;
+
 use era_compiler_common::Target;
 use tempfile::TempDir;
 use test_case::test_case;
@@ -45,3 +47,58 @@ fn standard_json(target: Target) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_case(Target::EraVM, &["runtime"])]
+#[test_case(Target::EVM, &["deploy", "runtime"])]
+fn optimization_diff(target: Target, code_segments: &[&str]) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_debug = TempDir::with_prefix("debug_output")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-output-dir",
+        tmp_dir_debug.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let prefix = format!(
+        "{}.Test",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH.replace('/', "_")
+    );
+    let diff_names: BTreeSet<String> = std::fs::read_dir(tmp_dir_debug.path())?
+        .filter_map(|entry| entry.ok())
+        .map(|entry| entry.file_name().to_string_lossy().to_string())
+        .filter(|name| name.ends_with(era_compiler_solidity::ir_diff::SUFFIX_DIFF))
+        .collect();
+    let expected_names: BTreeSet<String> = code_segments
+        .iter()
+        .map(|code_segment| {
+            format!(
+                "{prefix}.{code_segment}{}",
+                era_compiler_solidity::ir_diff::SUFFIX_DIFF
+            )
+        })
+        .collect();
+    assert_eq!(diff_names, expected_names);
+
+    for code_segment in code_segments.iter() {
+        let diff = std::fs::read_to_string(tmp_dir_debug.path().join(format!(
+            "{prefix}.{code_segment}{}",
+            era_compiler_solidity::ir_diff::SUFFIX_DIFF
+        )))?;
+        assert!(diff.starts_with(
+            format!(
+                "--- {prefix}.{code_segment}{}\n+++ {prefix}.{code_segment}{}\n",
+                era_compiler_solidity::ir_diff::SUFFIX_UNOPTIMIZED,
+                era_compiler_solidity::ir_diff::SUFFIX_OPTIMIZED,
+            )
+            .as_str()
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,120 @@
+//!
+//! CLI tests for the `zksolc dedup-report` command.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Compiles the test contracts into a standard JSON output file in `tmp_dir`.
+///
+fn compile(tmp_dir: &TempDir) -> anyhow::Result<PathBuf> {
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_DEDUP_REPORT_PATH,
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result.success().get_output().stdout.clone();
+
+    let path = tmp_dir.path().join("output.json");
+    std::fs::write(path.as_path(), stdout)?;
+    Ok(path)
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("dedup_report")?;
+    let path = compile(&tmp_dir)?;
+
+    let args = &["dedup-report", path.to_str().expect("Always valid")];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result.success().get_output().stdout.clone();
+
+    let report: era_compiler_solidity::DedupReport = serde_json::from_slice(stdout.as_slice())?;
+    assert!(report.functions.iter().any(|functions| {
+        functions.occurrences.len() == 2
+            && functions.occurrences[0].contract == "First.sol:First"
+            && functions.occurrences[1].contract == "Second.sol:Second"
+    }));
+    assert_eq!(report.contracts.len(), 1);
+    assert_eq!(
+        report.contracts[0].contracts,
+        ["First.sol:First".to_owned(), "Second.sol:Second".to_owned()]
+    );
+    assert!(report.estimated_savings > 0);
+
+    Ok(())
+}
+
+#[test]
+fn output() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("dedup_report")?;
+    let path = compile(&tmp_dir)?;
+    let report_path = tmp_dir.path().join("dedup_report.json");
+
+    let args = &[
+        "dedup-report",
+        path.to_str().expect("Always valid"),
+        "--output",
+        report_path.to_str().expect("Always valid"),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::is_empty());
+
+    let report: era_compiler_solidity::DedupReport =
+        serde_json::from_str(std::fs::read_to_string(report_path)?.as_str())?;
+    assert_eq!(report.contracts.len(), 1);
+
+    Ok(())
+}
+
+#[test]
+fn no_assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "dedup-report",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "No contracts with EraVM assembly found",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn excess_arguments() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["dedup-report", "output.json", "--bin"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unexpected argument '--bin' found",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_input() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["dedup-report"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "the following required arguments were not provided",
+    ));
+
+    Ok(())
+}
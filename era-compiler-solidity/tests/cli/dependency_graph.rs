@@ -0,0 +1,259 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("dependency_graph")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let main_path = format!(
+        "{}:Main",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+    let callable_path = format!(
+        "{}:Callable",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+    );
+
+    let graph: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(tmp_dir.path().join("dependency_graph.json"))?.as_str(),
+    )?;
+    assert_eq!(
+        graph["sources"][crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH]["imports"][0],
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+    );
+    let main = &graph["contracts"][main_path.as_str()];
+    assert_eq!(
+        main["source"],
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+    assert_eq!(main["factoryDependencies"][0], callable_path.as_str());
+
+    let dot = std::fs::read_to_string(tmp_dir.path().join("dependency_graph.dot"))?;
+    assert!(dot.starts_with("digraph \"dependency_graph\" {"));
+    assert!(
+        dot.contains(format!("{main_path:?} -> {callable_path:?} [label=\"creates\"];").as_str())
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM, false)]
+#[test_case(Target::EraVM, true)]
+#[test_case(Target::EVM, false)]
+#[test_case(Target::EVM, true)]
+fn libraries(target: Target, is_linked: bool) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("dependency_graph")?;
+    let output_dir = tmp_dir.path().join("build");
+    let library_path = tmp_dir.path().join("Library.sol");
+    let main_path = tmp_dir.path().join("Main.sol");
+    std::fs::write(
+        library_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Linked { function square(uint256 x) public pure returns (uint256) { return x * x; } }\nlibrary Inlined { function twice(uint256 x) internal pure returns (uint256) { return Linked.square(x) * 2; } }\n",
+    )?;
+    std::fs::write(
+        main_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Library.sol\";\ncontract Main { function main(uint256 x) external pure returns (uint256) { return Inlined.twice(x); } }\n",
+    )?;
+    let library_full_path = format!("{}:Linked", library_path.to_str().unwrap());
+    let library_argument =
+        format!("{library_full_path}=0xF9702469Dfb84A9aC171E284F71615bd3D3f1EdC");
+
+    let mut args = vec![
+        main_path.to_str().unwrap(),
+        library_path.to_str().unwrap(),
+        "--bin",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--emit",
+        "dep-graph",
+    ];
+    if is_linked {
+        args.extend(["--libraries", library_argument.as_str()]);
+    }
+
+    let result = crate::cli::execute_zksolc_with_target(args.as_slice(), target)?;
+    result.success();
+
+    let graph: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(output_dir.join("dependency_graph.json"))?.as_str(),
+    )?;
+    let main_full_path = format!("{}:Main", main_path.to_str().unwrap());
+    assert_eq!(
+        graph["contracts"][main_full_path.as_str()]["libraries"],
+        serde_json::json!([library_full_path]),
+        "The library called via an internal library function is missing"
+    );
+    let inlined_full_path = format!("{}:Inlined", library_path.to_str().unwrap());
+    assert_eq!(
+        graph["contracts"][inlined_full_path.as_str()]["libraries"],
+        serde_json::json!([library_full_path]),
+    );
+    assert_eq!(
+        graph["contracts"][library_full_path.as_str()]["libraries"],
+        serde_json::json!([]),
+        "The library is linked to itself"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn dot_quoting() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("dependency_graph")?;
+    let output_dir = tmp_dir.path().join("build");
+    let source_path = tmp_dir.path().join("Контракт.sol");
+    std::fs::write(
+        source_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Test { function main() external pure returns (uint256) { return 42; } }\n",
+    )?;
+
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--bin",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let dot = std::fs::read_to_string(output_dir.join("dependency_graph.dot"))?;
+    assert!(
+        dot.contains(
+            format!(
+                "\"{}:Test\" [shape=ellipse];",
+                source_path.to_str().unwrap()
+            )
+            .as_str()
+        ),
+        "The non-ASCII path is escaped"
+    );
+    assert!(!dot.contains("\\u{"), "The Rust escapes are not valid DOT");
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency graph requires `--output-dir` to be written to.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--emit",
+        "unknown",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid output to emit: `unknown`. Available options: dep-graph.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency graph is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency graph cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--emit",
+        "dep-graph",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Dependency graph cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,75 @@
+//!
+//! CLI tests for the `zksolc disassemble` command.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn hexadecimal_string() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["disassemble", "0x0000008003000039000000400030043f"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Bytecode disassembly:"))
+        .stdout(predicate::str::contains(
+            "0: 00 00 00 80 03 00 00 39       add     128, r0, r3",
+        ))
+        .stdout(predicate::str::contains(
+            "8: 00 00 00 40 00 30 04 3f       stm.h   64, r3",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "disassemble",
+        crate::common::TEST_DISASSEMBLER_BYTECODE_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "File `{}` disassembly:",
+            crate::common::TEST_DISASSEMBLER_BYTECODE_PATH
+        )))
+        .stdout(predicate::str::contains("stm.h   64, r3"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_path() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["disassemble", "anyarg"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .code(era_solc::ExitCode::InputError.code())
+        .stderr(predicate::str::contains("File \"anyarg\" reading"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_inputs() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["disassemble"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "the following required arguments were not provided",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case("inline")]
+#[test_case("shared")]
+fn default(strategy: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        strategy,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--balance-lowering",
+        "shared",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn bytecode() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let compile = |strategy: &str| -> anyhow::Result<(String, String)> {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+            "--balance-lowering",
+            strategy,
+            "--asm",
+            "--bin",
+        ];
+
+        let result = crate::cli::execute_zksolc(args)?;
+        let stdout = String::from_utf8(result.success().get_output().stdout.clone())?;
+        let (assembly, binary) = stdout.split_once("Binary:\n").expect("Always exists");
+        let binary = binary.lines().next().expect("Always exists").to_owned();
+        Ok((assembly.to_owned(), binary))
+    };
+
+    let (inline_assembly, inline_binary) = compile("inline")?;
+    let (shared_assembly, shared_binary) = compile("shared")?;
+    assert_ne!(inline_binary, shared_binary);
+    assert!(!inline_assembly.contains(era_compiler_solidity::balance_lowering::FUNCTION_NAME));
+    assert!(shared_assembly.contains(era_compiler_solidity::balance_lowering::FUNCTION_NAME));
+
+    Ok(())
+}
+
+#[test]
+fn metadata() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        "shared",
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""balance_lowering":"shared""#));
+
+    Ok(())
+}
+
+#[test]
+fn metadata_default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        "inline",
+        "--metadata",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("balance_lowering").not());
+
+    Ok(())
+}
+
+#[test]
+fn build_report() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        "shared",
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    let contract = build_report
+        .contracts
+        .get(
+            format!(
+                "{}:Balance",
+                crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH
+            )
+            .as_str(),
+        )
+        .expect("Always exists");
+    assert_eq!(
+        contract.balance_lowering,
+        era_solc::StandardJsonInputBalanceLowering::Shared
+    );
+    assert!(build_report.key_id.is_none());
+    assert!(build_report.signature.is_none());
+    assert!(contract.artifacts.is_empty());
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        "outlined",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid balance lowering strategy",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--balance-lowering",
+        "shared",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Balance lowering strategy is only available in Solidity and Yul modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--balance-lowering",
+        "shared",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Balance lowering strategy must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_BALANCE_PATH,
+        "--balance-lowering",
+        "shared",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Balance lowering strategies are only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
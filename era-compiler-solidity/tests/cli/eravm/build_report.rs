@@ -0,0 +1,98 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--build-report",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    assert!(build_report.key_id.is_none());
+    assert!(build_report.signature.is_none());
+
+    let resource_usage = build_report.resource_usage.expect("Always exists");
+    assert_eq!(resource_usage.solc_runs, 1);
+    assert!(resource_usage.temp_disk_usage_bytes.is_none());
+    #[cfg(unix)]
+    {
+        assert!(resource_usage.peak_rss_kb.is_some());
+        let solc = resource_usage.solc.expect("Always exists");
+        assert!(solc.peak_rss_kb.is_some());
+        assert!(resource_usage.subprocesses.is_some());
+    }
+
+    Ok(())
+}
+
+#[test]
+fn temp_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+    let temp_dir = tmp_dir_zksolc.path().join("temp");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--build-report",
+        "--temp-dir",
+        temp_dir.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    let resource_usage = build_report.resource_usage.expect("Always exists");
+    assert!(resource_usage.temp_disk_usage_bytes.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn missing_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--build-report",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build report is only available with `--output-dir`.",
+    ));
+
+    Ok(())
+}
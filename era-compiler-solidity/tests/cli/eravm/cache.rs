@@ -0,0 +1,139 @@
+//!
+//! CLI tests for the `zksolc cache` commands.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn warm_directory() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("cache_warm")?;
+    let sources_path = tmp_dir.path().join("src");
+    let cache_path = tmp_dir.path().join("cache");
+    std::fs::create_dir_all(sources_path.join("nested"))?;
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        sources_path.join("nested").join("Test.sol"),
+    )?;
+
+    let args = &[
+        "cache",
+        "warm",
+        sources_path.to_str().unwrap(),
+        "--cache-dir",
+        cache_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Caches warmed with 1 source."));
+    let entries = std::fs::read_dir(cache_path.as_path())?.count();
+    assert!(entries > 0, "No contracts have been cached");
+
+    let source_path = sources_path.join("nested").join("Test.sol");
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--bin",
+        "--cache-dir",
+        cache_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        entries,
+        "The warmed contracts have been recompiled"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn warm_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("cache_warm")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let cache_path = tmp_dir.path().join("cache");
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            "Test.sol": {
+                "content": std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH)?,
+            },
+        },
+        "settings": {
+            "outputSelection": {
+                "*": {
+                    "*": ["abi"],
+                },
+            },
+            "buildCache": cache_path,
+        },
+    });
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &["cache", "warm", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::contains("Caches warmed with 1 source."));
+    assert!(
+        std::fs::read_dir(cache_path.as_path())?.count() > 0,
+        "No contracts have been cached"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn warm_standard_json_without_caches() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "cache",
+        "warm",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("does not specify any caches"));
+
+    Ok(())
+}
+
+#[test]
+fn warm_directory_without_caches() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["cache", "warm", "tests/data/contracts/solidity"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Either `--cache-dir` or `--ast-cache` must be passed to warm the caches of a directory.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_command() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["cache", "cool"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("unrecognized subcommand 'cool'"));
+
+    Ok(())
+}
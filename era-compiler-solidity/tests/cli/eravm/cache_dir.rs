@@ -0,0 +1,211 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("cache_dir")?;
+    let cache_path = tmp_dir.path().join("cache");
+    let leaf_path = tmp_dir.path().join("Leaf.sol");
+    let root_path = tmp_dir.path().join("Root.sol");
+    let other_path = tmp_dir.path().join("Other.sol");
+    std::fs::write(
+        root_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Leaf.sol\";\ncontract Root { function main() external pure returns (uint256) { return Leaf.get(); } }\n",
+    )?;
+    std::fs::write(
+        other_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other { function main() external pure returns (uint256) { return 42; } }\n",
+    )?;
+
+    let args = &[
+        leaf_path.to_str().unwrap(),
+        root_path.to_str().unwrap(),
+        other_path.to_str().unwrap(),
+        "--bin",
+        "--cache-dir",
+        cache_path.to_str().unwrap(),
+    ];
+
+    std::fs::write(
+        leaf_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 1; } }\n",
+    )?;
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        3,
+        "Not all contracts have been cached"
+    );
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        3,
+        "The unchanged contracts have been recompiled"
+    );
+
+    std::fs::write(
+        leaf_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 2; } }\n",
+    )?;
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        5,
+        "Only the edited source and its dependents must be recompiled"
+    );
+
+    Ok(())
+}
+
+#[test_case(&["-Oz"] ; "optimization")]
+#[test_case(&["--evm-version", "paris"] ; "evm_version")]
+#[test_case(&["--metadata-literal"] ; "metadata_literal")]
+#[test_case(&["--metadata-hash", "none"] ; "metadata_hash")]
+fn settings_invalidation(settings: &[&str]) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("cache_dir")?;
+    let cache_path = tmp_dir.path().join("cache");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        cache_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    let entries = std::fs::read_dir(cache_path.as_path())?.count();
+
+    let mut args = args.to_vec();
+    args.extend_from_slice(settings);
+    let result = crate::cli::execute_zksolc(args.as_slice())?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        entries * 2,
+        "Changing the settings must invalidate the cache"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cached_output() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("cache_dir")?;
+    let cache_path = tmp_dir.path().join("cache");
+
+    let args = &[crate::common::TEST_SOLIDITY_CONTRACT_PATH, "--bin"];
+    let result = crate::cli::execute_zksolc(args)?;
+    let uncached_stdout = result.success().get_output().stdout.to_owned();
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--cache-dir",
+        cache_path.to_str().unwrap(),
+    ];
+    for _ in 0..2 {
+        let result = crate::cli::execute_zksolc(args)?;
+        let cached_stdout = result.success().get_output().stdout.to_owned();
+        assert_eq!(
+            String::from_utf8_lossy(cached_stdout.as_slice()),
+            String::from_utf8_lossy(uncached_stdout.as_slice()),
+            "The cached build differs from the uncached one"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--cache-dir",
+        "cache",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build cache is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--cache-dir",
+        "cache",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build cache cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--cache-dir",
+        "cache",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Build cache must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--cache-dir",
+        "cache",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Build cache is only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,175 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-assertions",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test]
+fn evmla() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--codegen",
+        "evmla",
+        "--debug-assertions",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--debug-assertions",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test]
+fn debug_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("debug_assertions")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-assertions",
+        "--debug-output-dir",
+        tmp_dir.path().to_str().unwrap(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let is_tagged = std::fs::read_dir(tmp_dir.path())?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("ll"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .any(|ir| {
+            ir.contains(era_compiler_solidity::debug_assertions::PROVENANCE_METADATA_KIND)
+                && ir.contains("yul:")
+        });
+    assert!(is_tagged, "The LLVM IR has not been tagged with provenance");
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("debug_assertions")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let debug_output_dir = tmp_dir.path().join("debug");
+
+    let input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            "Test.sol": {
+                "content": "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Test { function main(uint256 x) external pure returns (uint256) { return x + 1; } }\n",
+            },
+        },
+        "settings": {
+            "outputSelection": {
+                "*": {
+                    "*": ["evm.bytecode"],
+                },
+            },
+            "debugAssertions": true,
+        },
+    });
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &[
+        "--standard-json",
+        input_path.to_str().unwrap(),
+        "--debug-output-dir",
+        debug_output_dir.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    let is_tagged = std::fs::read_dir(debug_output_dir.as_path())?
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| path.extension().and_then(|extension| extension.to_str()) == Some("ll"))
+        .filter_map(|path| std::fs::read_to_string(path).ok())
+        .any(|ir| ir.contains(era_compiler_solidity::debug_assertions::PROVENANCE_METADATA_KIND));
+    assert!(is_tagged, "The LLVM IR has not been tagged with provenance");
+
+    Ok(())
+}
+
+#[test]
+fn llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--debug-assertions",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Debug assertions are only available in Solidity and Yul modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--debug-assertions",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Debug assertions are only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
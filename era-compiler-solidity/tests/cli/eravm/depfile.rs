@@ -0,0 +1,262 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("depfile")?;
+    let depfile_path = tmp_dir.path().join("deps.d");
+    let output_dir = tmp_dir.path().join("build");
+    let leaf_path = tmp_dir.path().join("Leaf.sol");
+    let root_path = tmp_dir.path().join("Root.sol");
+    std::fs::write(
+        leaf_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 1; } }\n",
+    )?;
+    std::fs::write(
+        root_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Leaf.sol\";\ncontract Root { function main() external pure returns (uint256) { return Leaf.get(); } }\n",
+    )?;
+
+    let args = &[
+        leaf_path.to_str().unwrap(),
+        root_path.to_str().unwrap(),
+        "--bin",
+        "--abi",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--depfile",
+        depfile_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let depfile = std::fs::read_to_string(depfile_path.as_path())?;
+    let rules = depfile
+        .lines()
+        .map(|line| {
+            line.split_once(": ")
+                .expect("The rule separator is missing")
+        })
+        .collect::<Vec<(&str, &str)>>();
+    assert_eq!(rules.len(), 2, "Each contract must have a rule");
+    for (targets, _prerequisites) in rules.iter() {
+        for target in targets.split(' ') {
+            assert!(
+                std::path::Path::new(target).is_file(),
+                "The target {target} is not a written artifact"
+            );
+        }
+    }
+
+    let root_binary_path = output_dir.join("Root.sol").join(format!(
+        "Root.{}",
+        era_compiler_common::EXTENSION_ERAVM_BINARY
+    ));
+    let root_abi_path = output_dir
+        .join("Root.sol")
+        .join(format!("Root.{}", era_compiler_solidity::EXTENSION_ABI));
+    let (root_targets, root_prerequisites) = rules
+        .iter()
+        .find(|(targets, _)| targets.contains(root_binary_path.to_str().unwrap()))
+        .expect("The contract rule is missing");
+    assert!(
+        root_targets.contains(root_abi_path.to_str().unwrap()),
+        "The ABI artifact is not a target"
+    );
+    assert!(
+        root_prerequisites.contains(leaf_path.to_str().unwrap()),
+        "The imported source is missing"
+    );
+    assert!(
+        root_prerequisites.contains("solc"),
+        "The `solc` executable is missing"
+    );
+    assert!(
+        root_prerequisites.ends_with("deps.d.settings"),
+        "The settings stamp file is missing"
+    );
+    let (_, leaf_prerequisites) = rules
+        .iter()
+        .find(|(targets, _)| targets.contains("Leaf.sol"))
+        .expect("The library rule is missing");
+    assert!(
+        !leaf_prerequisites.contains(root_path.to_str().unwrap()),
+        "The importing source is a dependency"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn escaping() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("depfile")?;
+    let depfile_path = tmp_dir.path().join("deps.d");
+    let output_dir = tmp_dir.path().join("build #1");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--depfile",
+        depfile_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let depfile = std::fs::read_to_string(depfile_path.as_path())?;
+    assert!(
+        depfile.contains("build\\ \\#1"),
+        "The space and the hash sign are not escaped"
+    );
+    assert!(
+        !depfile.contains("\\:"),
+        "The colon is escaped in violation of the make rules"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--depfile",
+        "deps.d",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency manifest requires `--output-dir`",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn settings_stamp() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("depfile")?;
+    let depfile_path = tmp_dir.path().join("deps.d");
+    let settings_path = tmp_dir.path().join("deps.d.settings");
+    let output_dir = tmp_dir.path().join("build");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--overwrite",
+        "--depfile",
+        depfile_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+    let settings_hash = std::fs::read_to_string(settings_path.as_path())?;
+    let modified = std::fs::metadata(settings_path.as_path())?.modified()?;
+
+    std::thread::sleep(std::time::Duration::from_millis(100));
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+    assert_eq!(
+        std::fs::metadata(settings_path.as_path())?.modified()?,
+        modified,
+        "The settings stamp file has been rewritten with the same settings"
+    );
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "-Oz",
+        "--output-dir",
+        output_dir.to_str().unwrap(),
+        "--overwrite",
+        "--depfile",
+        depfile_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+    assert_ne!(
+        std::fs::read_to_string(settings_path.as_path())?,
+        settings_hash,
+        "The settings stamp file has not been updated"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--depfile",
+        "deps.d",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency manifest is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--depfile",
+        "deps.d",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency manifest is only available in basic CLI mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--depfile",
+        "deps.d",
+        "--bin",
+        "--output-dir",
+        "build",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Dependency manifest is only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
@@ -22,6 +22,22 @@ fn default() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn hexadecimal_string() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--disassemble", "0x0000008003000039000000400030043f"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Bytecode disassembly:"))
+        .stdout(predicate::str::contains("00 00 00 40 00 30 04 3f"))
+        .stdout(predicate::str::contains("stm.h"));
+
+    Ok(())
+}
+
 #[test]
 fn invalid_path() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -29,7 +45,9 @@ fn invalid_path() -> anyhow::Result<()> {
     let args = &["--disassemble", "anyarg"];
 
     let result = crate::cli::execute_zksolc(args)?;
-    result.failure();
+    result
+        .failure()
+        .stderr(predicate::str::contains("File \"anyarg\" reading"));
 
     Ok(())
 }
@@ -2,8 +2,11 @@
 //! CLI tests for the eponymous option.
 //!
 
+use std::collections::BTreeMap;
+
 use era_compiler_common::Target;
 use predicates::prelude::*;
+use tempfile::TempDir;
 
 #[test]
 fn with_libraries() -> anyhow::Result<()> {
@@ -37,6 +40,41 @@ fn with_libraries() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn with_libraries_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("link_output")?;
+    let unlinked = std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?;
+
+    let args = &[
+        "--link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries",
+        crate::common::LIBRARY_LINKER,
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "\"linked\":{\"tests/data/bytecodes/linker.zbin\":",
+    ));
+
+    let linked = std::fs::read_to_string(
+        tmp_dir
+            .path()
+            .join(crate::common::TEST_LINKER_BYTECODE_PATH),
+    )?;
+    assert_ne!(linked, unlinked);
+    assert_eq!(
+        std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?,
+        unlinked
+    );
+
+    Ok(())
+}
+
 #[test]
 fn without_libraries() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -79,7 +117,7 @@ fn with_libraries_excess_args() -> anyhow::Result<()> {
 
     let result = crate::cli::execute_zksolc(args)?;
     result.failure().stderr(predicate::str::contains(
-        "Error: No other options except bytecode files, `--libraries`, `--standard-json`, `--target` are allowed in linker mode.",
+        "Error: No other options except bytecode files, `--libraries`, `--output-dir`, `--standard-json`, `--target` are allowed in linker mode.",
     ));
 
     Ok(())
@@ -264,3 +302,89 @@ fn unsupported_evm() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn command() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    era_compiler_llvm_context::initialize_target(Target::EraVM);
+
+    let tmp_dir = TempDir::with_prefix("link_command")?;
+    let unlinked = std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?;
+
+    let args = &[
+        "link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries",
+        crate::common::LIBRARY_LINKER,
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result.success().get_output().stdout.to_owned();
+    let output: serde_json::Value = serde_json::from_slice(stdout.as_slice())?;
+    let linked = &output["linked"][crate::common::TEST_LINKER_BYTECODE_PATH];
+
+    let expected =
+        era_compiler_solidity::Linker::link_eravm(era_compiler_solidity::LinkerInput::new(
+            BTreeMap::from([(
+                crate::common::TEST_LINKER_BYTECODE_PATH.to_owned(),
+                unlinked.clone(),
+            )]),
+            vec![crate::common::LIBRARY_LINKER.to_owned()],
+        ))?;
+    let expected = expected
+        .linked
+        .get(crate::common::TEST_LINKER_BYTECODE_PATH)
+        .expect("Always exists");
+    assert_eq!(linked["bytecode"], expected.bytecode.as_str());
+    assert_eq!(linked["hash"], expected.hash.as_str());
+
+    let written = std::fs::read_to_string(
+        tmp_dir
+            .path()
+            .join(crate::common::TEST_LINKER_BYTECODE_PATH),
+    )?;
+    assert_eq!(written, expected.bytecode);
+    assert_ne!(written, unlinked);
+    assert_eq!(
+        std::fs::read_to_string(crate::common::TEST_LINKER_BYTECODE_PATH)?,
+        unlinked
+    );
+
+    Ok(())
+}
+
+#[test]
+fn command_libraries_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["link", crate::common::TEST_LINKER_BYTECODE_PATH];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("--libraries <LIBRARIES>..."));
+
+    Ok(())
+}
+
+#[test]
+fn command_excess_args() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "link",
+        crate::common::TEST_LINKER_BYTECODE_PATH,
+        "--libraries",
+        crate::common::LIBRARY_LINKER,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unexpected argument '--bin' found",
+    ));
+
+    Ok(())
+}
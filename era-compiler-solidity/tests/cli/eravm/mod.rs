@@ -2,13 +2,21 @@
 //! The EraVM CLI/e2e tests entry module.
 //!
 
+mod balance_lowering;
+mod build_report;
+mod cache;
+mod cache_dir;
+mod debug_assertions;
+mod depfile;
 mod detect_missing_libraries;
 mod disassemble;
 mod enable_eravm_extensions;
 mod eravm_assembly;
 mod link;
 mod output_dir;
+mod signing_key;
 mod standard_json;
+mod strict_eravm;
 mod suppress_errors;
 mod suppress_warnings;
 mod yul;
@@ -0,0 +1,176 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--metadata",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--signing-key",
+        crate::common::TEST_SIGNING_KEY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    assert_eq!(
+        build_report.key_id.as_deref(),
+        Some(crate::common::TEST_SIGNING_KEY_ID)
+    );
+    assert!(build_report.signature.is_some());
+
+    let contract = build_report
+        .contracts
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists");
+    assert_eq!(contract.artifacts.len(), 2);
+    for (name, signature) in contract.artifacts.iter() {
+        let contents = std::fs::read(tmp_dir_zksolc.path().join(name))?;
+        assert_eq!(
+            signature.hash,
+            era_compiler_common::Hash::keccak256(contents.as_slice()).to_string()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn all_artifacts() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--asm",
+        "--abi",
+        "--hashes",
+        "--metadata",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--signing-key",
+        crate::common::TEST_SIGNING_KEY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    let contract = build_report
+        .contracts
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists");
+    assert_eq!(contract.artifacts.len(), 5);
+
+    assert!(build_report.resource_usage.is_some());
+
+    Ok(())
+}
+
+#[test]
+fn missing_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--signing-key",
+        crate::common::TEST_SIGNING_KEY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifact signing is only available with `--output-dir`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_key() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--signing-key",
+        crate::common::TEST_BROKEN_INPUT_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Signing key file"));
+
+    Ok(())
+}
+
+#[test]
+fn format() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--format",
+        "hardhat",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+        "--signing-key",
+        crate::common::TEST_SIGNING_KEY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let build_report_path = tmp_dir_zksolc
+        .path()
+        .join(era_compiler_solidity::BuildReport::FILE_NAME);
+    let build_report: era_compiler_solidity::BuildReport =
+        serde_json::from_str(std::fs::read_to_string(build_report_path)?.as_str())?;
+    let contract = build_report
+        .contracts
+        .get(format!("{}:Test", crate::common::TEST_SOLIDITY_CONTRACT_PATH).as_str())
+        .expect("Always exists");
+    let (name, signature) = contract.artifacts.iter().next().expect("Always exists");
+    assert_eq!(
+        name.as_str(),
+        format!("{}/Test.json", crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+    );
+    let contents = std::fs::read(tmp_dir_zksolc.path().join(name))?;
+    assert_eq!(
+        signature.hash,
+        era_compiler_common::Hash::keccak256(contents.as_slice()).to_string()
+    );
+
+    Ok(())
+}
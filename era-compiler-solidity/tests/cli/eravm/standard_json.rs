@@ -61,6 +61,29 @@ fn eravm_assembly() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn eravm_assembly_contract_structure() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_ERAVM_ASSEMBLY_STANDARD_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EraVM)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let contract = output["contracts"]["Test"]
+        .as_object()
+        .and_then(|contracts| contracts.values().next())
+        .expect("Always exists");
+    assert!(contract["eravm"]["bytecode"].is_string());
+    assert!(contract["hash"].is_string());
+    assert!(contract["factoryDependencies"].is_object());
+
+    Ok(())
+}
+
 #[test]
 fn eravm_assembly_solc() -> anyhow::Result<()> {
     crate::common::setup()?;
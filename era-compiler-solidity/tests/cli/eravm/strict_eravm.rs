@@ -0,0 +1,118 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--strict-eravm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "Strict EraVM mode forbids `txorigin`",
+        ))
+        .stderr(predicate::str::contains("You may disable this warning").not());
+
+    Ok(())
+}
+
+#[test]
+fn clean() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--strict-eravm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn suppress_warnings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let warning_type = era_solc::StandardJsonInputWarningType::TxOrigin.to_string();
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--strict-eravm",
+        "--suppress-warnings",
+        warning_type.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Errors and warnings cannot be suppressed in strict EraVM mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--strict-eravm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Strict EraVM mode is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--strict-eravm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Strict EraVM mode must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_setting() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_STRICT_ERAVM_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Strict EraVM mode forbids `txorigin`",
+    ));
+
+    Ok(())
+}
@@ -27,6 +27,49 @@ fn default(error_type: StandardJsonInputErrorType) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test]
+fn selfdestruct() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_SELFDESTRUCT_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "You are using 'selfdestruct', which is not supported by EraVM",
+        ))
+        .stderr(predicate::str::contains(format!(
+            "--> {}:7:9:",
+            crate::common::TEST_SOLIDITY_CONTRACT_SELFDESTRUCT_PATH
+        )))
+        .stderr(predicate::str::contains("The `SELFDESTRUCT` instruction is not supported").not());
+
+    Ok(())
+}
+
+#[test]
+fn selfdestruct_unsuppressible() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_SELFDESTRUCT_PATH,
+        "--suppress-errors",
+        "selfdestruct",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid suppressed error type: selfdestruct",
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn standard_json() -> anyhow::Result<()> {
     crate::common::setup()?;
@@ -2,24 +2,66 @@
 //! CLI tests for the eponymous option.
 //!
 
+use era_solc::StandardJsonInputWarningType;
 use predicates::prelude::*;
-
-#[test]
-fn default() -> anyhow::Result<()> {
+use test_case::test_case;
+
+#[test_case(
+    StandardJsonInputWarningType::TxOrigin,
+    crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+    "You are checking for 'tx.origin'"
+)]
+#[test_case(
+    StandardJsonInputWarningType::BlockRandomness,
+    crate::common::TEST_SOLIDITY_CONTRACT_BLOCK_RANDOMNESS_PATH,
+    "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'"
+)]
+#[test_case(
+    StandardJsonInputWarningType::AssemblyInitCode,
+    crate::common::TEST_SOLIDITY_CONTRACT_ASSEMBLY_INIT_CODE_PATH,
+    "You are passing init code assembled in memory"
+)]
+#[test_case(
+    StandardJsonInputWarningType::CreationCode,
+    crate::common::TEST_SOLIDITY_CONTRACT_CREATION_CODE_PATH,
+    "You are using 'type(T).creationCode', whose value differs on EraVM"
+)]
+#[test_case(
+    StandardJsonInputWarningType::PrecompileCall,
+    crate::common::TEST_SOLIDITY_CONTRACT_PRECOMPILE_CALL_PATH,
+    "You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile"
+)]
+#[test_case(
+    StandardJsonInputWarningType::AbiCoderV1,
+    crate::common::TEST_SOLIDITY_CONTRACT_ABI_CODER_V1_PATH,
+    "This source is compiled with ABI coder v1"
+)]
+fn default(
+    warning_type: StandardJsonInputWarningType,
+    path: &str,
+    warning: &str,
+) -> anyhow::Result<()> {
     crate::common::setup()?;
 
-    let warning_type = era_solc::StandardJsonInputWarningType::TxOrigin.to_string();
-    let args = &[
-        "--bin",
-        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
-        "--suppress-warnings",
-        warning_type.as_str(),
-    ];
+    let warning_type = warning_type.to_string();
 
-    let result = crate::cli::execute_zksolc(args)?;
+    // The init code warning is only reported if the assembly `create` error is suppressed,
+    // as the latter takes precedence otherwise.
+    let mut args = vec!["--bin", path, "--suppress-errors", "assemblycreate"];
+
+    let result = crate::cli::execute_zksolc(args.as_slice())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains(warning));
+
+    args.extend(["--suppress-warnings", warning_type.as_str()]);
+
+    let result = crate::cli::execute_zksolc(args.as_slice())?;
     result
         .success()
-        .stdout(predicate::str::contains("Binary:\n"));
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains(warning).not());
 
     Ok(())
 }
@@ -27,3 +27,68 @@ fn solc_enable_eravm_extensions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn nested_dependency_objects() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_DEPLOYER_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EraVM)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "======= tests/data/contracts/yul/Deployer.yul:Deployer =======",
+        ))
+        .stdout(predicate::str::contains(
+            "======= tests/data/contracts/yul/Deployer.yul:Child =======",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn nested_dependency_objects_equally_named() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_DEPLOYER_PATH,
+        crate::common::TEST_YUL_CONTRACT_SECOND_DEPLOYER_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EraVM)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "======= tests/data/contracts/yul/Deployer.yul:Child =======",
+        ))
+        .stdout(predicate::str::contains(
+            "======= tests/data/contracts/yul/SecondDeployer.yul:Child =======",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn data_segment_reference() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_DATA_REFERENCE_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EraVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Data segment `Seed` cannot be referenced",
+    ));
+
+    Ok(())
+}
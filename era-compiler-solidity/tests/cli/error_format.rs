@@ -0,0 +1,131 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn sarif() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--error-format",
+        "sarif",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains(r#""version":"2.1.0""#))
+        .stderr(predicate::str::contains(r#""ruleId":"txorigin""#))
+        .stderr(predicate::str::contains(r#""level":"warning""#))
+        .stderr(predicate::str::contains(r#""startLine":7"#))
+        .stderr(predicate::str::contains(r#""charOffset":"#))
+        .stderr(predicate::str::contains(r#""byteOffset":"#))
+        .stderr(predicate::str::contains("Warning:").not());
+
+    Ok(())
+}
+
+#[test]
+fn sarif_errors() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--strict-eravm",
+        "--error-format",
+        "sarif",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(r#""version":"2.1.0""#))
+        .stderr(predicate::str::contains(r#""level":"error""#))
+        .stderr(predicate::str::contains(
+            "Strict EraVM mode forbids `txorigin`",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn human() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH,
+        "--error-format",
+        "human",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Warning:"))
+        .stderr(predicate::str::contains(r#""version":"2.1.0""#).not());
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--error-format",
+        "xml",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid error format: `xml`"));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--error-format",
+        "sarif",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Error format must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_sarif() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SARIF_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""sarif":{"#))
+        .stdout(predicate::str::contains(r#""ruleId":"sendtransfer""#));
+
+    Ok(())
+}
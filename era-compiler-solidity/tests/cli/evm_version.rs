@@ -27,6 +27,47 @@ fn default(target: Target) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn builtin_unavailable(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--evm-version",
+        "shanghai",
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_MCOPY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "The `mcopy` builtin is only available since the `cancun` EVM version, but `shanghai` is configured.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn builtin_available(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let evm_version = era_compiler_common::EVMVersion::Cancun.to_string();
+    let args = &[
+        "--evm-version",
+        evm_version.as_str(),
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_MCOPY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn yul(target: Target) -> anyhow::Result<()> {
@@ -0,0 +1,127 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("execution_profile")?;
+    let profile_path = tmp_dir.path().join("profile.json");
+    std::fs::write(
+        profile_path.as_path(),
+        format!(
+            r#"{{ "contracts": {{ "{}:Test": {{ "fun_main": 1000, "unused": 0 }} }} }}"#,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        ),
+    )?;
+
+    let args = &[
+        "--bin",
+        "--codegen",
+        "yul",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--execution-profile",
+        profile_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn yul(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("execution_profile")?;
+    let profile_path = tmp_dir.path().join("profile.json");
+    std::fs::write(
+        profile_path.as_path(),
+        format!(
+            r#"{{ "contracts": {{ "{}:Test": {{ "abi_encode_uint256": 1000, "allocate_memory": 0 }} }} }}"#,
+            crate::common::TEST_YUL_CONTRACT_PATH
+        ),
+    )?;
+
+    let args = &[
+        "--yul",
+        "--bin",
+        "-O",
+        "0",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let default_output = result.success().get_output().stdout.clone();
+
+    let args = &[
+        "--yul",
+        "--bin",
+        "-O",
+        "0",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--execution-profile",
+        profile_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let profiled_output = result
+        .success()
+        .stdout(predicate::str::contains("Binary:"))
+        .get_output()
+        .stdout
+        .clone();
+
+    assert_ne!(
+        profiled_output, default_output,
+        "The execution profile has not changed the layout"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--execution-profile",
+        crate::common::TEST_BROKEN_INPUT_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Execution profile"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--execution-profile",
+        "profile.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Execution profile cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,185 @@
+//!
+//! CLI tests for the `zksolc explain-bytecode` command.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Compiles the test contract into a standard JSON output file in `tmp_dir`.
+///
+/// Returns the output path and the first instruction of the `main` function.
+///
+fn compile(
+    tmp_dir: &TempDir,
+) -> anyhow::Result<(
+    PathBuf,
+    era_solc::StandardJsonOutputContractEraVMAssemblyInstruction,
+)> {
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_EXPLAIN_BYTECODE_PATH,
+    ];
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result.success().get_output().stdout.clone();
+
+    let output: serde_json::Value = serde_json::from_slice(stdout.as_slice())?;
+    let assembly: era_solc::StandardJsonOutputContractEraVMAssembly = serde_json::from_value(
+        output["contracts"]["Test.sol"]["Test"]["eravm"]["assemblyJson"].to_owned(),
+    )?;
+    let instruction = assembly
+        .instructions
+        .into_iter()
+        .find(|instruction| {
+            instruction
+                .function
+                .as_deref()
+                .is_some_and(|function| function.starts_with("fun_main_"))
+        })
+        .expect("Always exists");
+
+    let path = tmp_dir.path().join("output.json");
+    std::fs::write(path.as_path(), stdout)?;
+    Ok((path, instruction))
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("explain_bytecode")?;
+    let (path, instruction) = compile(&tmp_dir)?;
+
+    let offset = format!("{:#x}", instruction.offset + 4);
+    let args = &[
+        "explain-bytecode",
+        path.to_str().expect("Always valid"),
+        offset.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract: Test.sol:Test"))
+        .stdout(predicate::str::contains(format!(
+            "Offset: {offset} (instruction at {:#x})",
+            instruction.offset
+        )))
+        .stdout(predicate::str::contains(format!(
+            "Instruction: {instruction}"
+        )))
+        .stdout(predicate::str::contains(format!(
+            "Function: {}",
+            instruction.function.as_deref().expect("Always exists")
+        )))
+        .stdout(predicate::str::contains("Declaration: function main"));
+
+    Ok(())
+}
+
+#[test]
+fn decimal() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("explain_bytecode")?;
+    let (path, instruction) = compile(&tmp_dir)?;
+
+    let offset = instruction.offset.to_string();
+    let args = &[
+        "explain-bytecode",
+        path.to_str().expect("Always valid"),
+        offset.as_str(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(format!(
+        "Instruction: {instruction}"
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn contract() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("explain_bytecode")?;
+    let (path, instruction) = compile(&tmp_dir)?;
+
+    let offset = instruction.offset.to_string();
+    let args = &[
+        "explain-bytecode",
+        path.to_str().expect("Always valid"),
+        offset.as_str(),
+        "--contract",
+        "Test",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Declaration: function main"));
+
+    Ok(())
+}
+
+#[test]
+fn out_of_code() -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let tmp_dir = TempDir::with_prefix("explain_bytecode")?;
+    let (path, _instruction) = compile(&tmp_dir)?;
+
+    let args = &[
+        "explain-bytecode",
+        path.to_str().expect("Always valid"),
+        "0x100000",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Offset 1048576 is out of the code of contract `Test.sol:Test`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_offset() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["explain-bytecode", "output.json", "0xZZ"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid bytecode offset `0xZZ`"));
+
+    Ok(())
+}
+
+#[test]
+fn excess_arguments() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["explain-bytecode", "output.json", "0x28", "--bin"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unexpected argument '--bin' found",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_offset() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["explain-bytecode", "output.json"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "the following required arguments were not provided",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,204 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn hardhat() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("format")?;
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let source = "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Child { function get() external pure returns (uint256) { return 42; } }\ncontract Parent { function create() external returns (address) { return address(new Child()); } }\n";
+    let source_path = tmp_dir.path().join("Factory.sol");
+    std::fs::write(source_path.as_path(), source)?;
+
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--output-dir",
+        tmp_dir_path,
+        "--format",
+        "hardhat",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let source_directory = tmp_dir.path().join(
+        era_compiler_solidity::artifact_directories::ArtifactDirectories::relative_path(
+            era_solc::path::normalize_path(source_path.as_path()).as_str(),
+        ),
+    );
+    let child: serde_json::Value =
+        serde_json::from_slice(std::fs::read(source_directory.join("Child.json"))?.as_slice())?;
+    let parent: era_compiler_solidity::HardhatArtifact =
+        serde_json::from_slice(std::fs::read(source_directory.join("Parent.json"))?.as_slice())?;
+
+    assert_eq!(
+        child["_format"],
+        era_compiler_solidity::HardhatArtifact::FORMAT
+    );
+    assert_eq!(child["contractName"], "Child");
+    assert!(child["bytecode"]
+        .as_str()
+        .expect("The bytecode is missing")
+        .starts_with("0x"));
+    assert_eq!(child["bytecode"], child["deployedBytecode"]);
+    assert!(child["abi"].as_array().is_some_and(|abi| !abi.is_empty()));
+
+    assert_eq!(parent.contract_name, "Parent");
+    assert_eq!(
+        parent.factory_deps.values().collect::<Vec<&String>>(),
+        vec![&format!("{}:Child", parent.source_name)]
+    );
+
+    Ok(())
+}
+
+#[test]
+fn foundry() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("format")?;
+    let tmp_dir_path = tmp_dir.path().to_str().unwrap();
+    let source = "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Child { function get() external pure returns (uint256) { return 42; } }\ncontract Parent { function create() external returns (address) { return address(new Child()); } }\n";
+    let source_path = tmp_dir.path().join("Factory.sol");
+    std::fs::write(source_path.as_path(), source)?;
+
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--output-dir",
+        tmp_dir_path,
+        "--format",
+        "foundry",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let child: serde_json::Value = serde_json::from_slice(
+        std::fs::read(tmp_dir.path().join("Factory.sol").join("Child.json"))?.as_slice(),
+    )?;
+    let parent: era_compiler_solidity::FoundryArtifact = serde_json::from_slice(
+        std::fs::read(tmp_dir.path().join("Factory.sol").join("Parent.json"))?.as_slice(),
+    )?;
+
+    assert!(child["bytecode"]["object"]
+        .as_str()
+        .expect("The bytecode object is missing")
+        .starts_with("0x"));
+    assert_eq!(
+        child["bytecode"]["object"],
+        child["deployedBytecode"]["object"]
+    );
+    assert_eq!(child["methodIdentifiers"]["get()"], "6d4ce63c");
+    assert!(child["abi"].as_array().is_some_and(|abi| !abi.is_empty()));
+    assert_eq!(
+        child["rawMetadata"],
+        serde_json::Value::String(child["metadata"].to_string())
+    );
+
+    assert_eq!(
+        parent
+            .method_identifiers
+            .get("create()")
+            .map(String::as_str),
+        Some("efc81a8c")
+    );
+    assert_eq!(parent.factory_dependencies.len(), 1);
+    assert!(parent
+        .factory_dependencies
+        .values()
+        .all(|path| path.ends_with("Factory.sol:Child")));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--format",
+        "truffle",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid artifact format: `truffle`. Available options: hardhat, foundry.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn output_dir_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--format",
+        "foundry",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifact format `foundry` requires `--output-dir`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("format")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+        "--format",
+        "hardhat",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifact format is only available in basic CLI mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("format")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+        "--format",
+        "foundry",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "Artifact formats are only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
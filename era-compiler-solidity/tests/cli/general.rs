@@ -123,3 +123,74 @@ fn same_output_directory_and_terminal(
 
     Ok(())
 }
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn source_utf8_bom(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("source_encoding")?;
+    let source_path = tmp_dir.path().join("Test.sol");
+    let mut source = vec![0xEF, 0xBB, 0xBF];
+    source.extend(std::fs::read(crate::common::TEST_SOLIDITY_CONTRACT_PATH)?);
+    std::fs::write(source_path.as_path(), source)?;
+
+    let args = &[source_path.to_str().unwrap(), "--bin"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn source_utf16_bom(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("source_encoding")?;
+    let source_path = tmp_dir.path().join("Test.sol");
+    let mut source = vec![0xFF, 0xFE];
+    source.extend(
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_CONTRACT_PATH)?
+            .encode_utf16()
+            .flat_map(u16::to_le_bytes),
+    );
+    std::fs::write(source_path.as_path(), source)?;
+
+    let args = &[source_path.to_str().unwrap(), "--bin"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stderr(predicate::str::contains(
+            "has been transcoded from UTF-16LE to UTF-8",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn source_non_utf8() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("source_encoding")?;
+    let source_path = tmp_dir.path().join("Test.sol");
+    let mut source = b"// SPDX-License-Identifier: MIT\n// Caf".to_vec();
+    source.push(0xE9);
+    source.extend(std::fs::read(crate::common::TEST_SOLIDITY_CONTRACT_PATH)?);
+    std::fs::write(source_path.as_path(), source)?;
+
+    let args = &[source_path.to_str().unwrap(), "--bin"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(
+        predicate::str::contains("is not valid UTF-8: invalid byte 0xe9 at offset 38 (line 2)")
+            .and(predicate::str::contains("Windows-1252")),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,88 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--help"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Input Modes:"))
+        .stdout(predicate::str::contains("Optimizer:"))
+        .stdout(predicate::str::contains("Topics:"))
+        .stdout(predicate::str::contains("zksolc help <TOPIC>"));
+
+    Ok(())
+}
+
+#[test]
+fn topics() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["help"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Topics:"))
+        .stdout(predicate::str::contains("Examples:"));
+
+    Ok(())
+}
+
+#[test_case("input", "--standard-json")]
+#[test_case("output", "--output-dir")]
+#[test_case("solc", "--codegen")]
+#[test_case("optimizer", "--optimization")]
+#[test_case("eravm", "--suppress-warnings")]
+#[test_case("reports", "--compatibility-report")]
+#[test_case("build", "--threads")]
+#[test_case("debug", "--debug-output-dir")]
+fn topic(topic: &str, option: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["help", topic];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(option))
+        .stdout(predicate::str::contains("Examples:"));
+
+    Ok(())
+}
+
+#[test]
+fn topic_excludes_other_options() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["help", "optimizer"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("--standard-json").not());
+
+    Ok(())
+}
+
+#[test]
+fn topic_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["help", "mega-ultra-topic"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid help topic `mega-ultra-topic`",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,134 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("import_graph")?;
+    let graph_path = tmp_dir.path().join("imports.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--import-graph",
+        graph_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let graph: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(graph_path)?.as_str())?;
+    let main = &graph["sources"][crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH];
+    assert_eq!(
+        main["imports"][0]["path"],
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+    );
+    assert_eq!(main["imports"][0]["file"], "./Callable.sol");
+    assert_eq!(
+        graph["sources"][crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH]["dependents"]
+            [0],
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn remappings(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("import_graph")?;
+    let graph_path = tmp_dir.path().join("imports.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_REMAPPED_MAIN_PATH,
+        "caller/=tests/data/contracts/solidity/caller/",
+        "--import-graph",
+        graph_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let graph: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(graph_path)?.as_str())?;
+    let main = &graph["sources"][crate::common::TEST_SOLIDITY_CONTRACT_REMAPPED_MAIN_PATH];
+    assert_eq!(
+        main["imports"][0]["path"],
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH
+    );
+    assert_eq!(main["imports"][0]["file"], "caller/Callable.sol");
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--import-graph",
+        "imports.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Import graph is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--import-graph",
+        "imports.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Import graph cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--import-graph",
+        "imports.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Import graph cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,113 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("inheritance_graph")?;
+    let graph_path = tmp_dir.path().join("inheritance.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_INHERITANCE_PATH,
+        "--inheritance-graph",
+        graph_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let graph: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(graph_path)?.as_str())?;
+    let path = crate::common::TEST_SOLIDITY_CONTRACT_INHERITANCE_PATH;
+    let full_path = |name: &str| serde_json::Value::String(format!("{path}:{name}"));
+    let diamond = &graph["contracts"][format!("{path}:Diamond")];
+    assert_eq!(
+        diamond["bases"],
+        serde_json::Value::Array(vec![full_path("Left"), full_path("Right")])
+    );
+    assert_eq!(
+        diamond["linearization"],
+        serde_json::Value::Array(vec![
+            full_path("Diamond"),
+            full_path("Right"),
+            full_path("Left"),
+            full_path("Base"),
+            full_path("IBase"),
+        ])
+    );
+    assert_eq!(
+        graph["contracts"][format!("{path}:Base")]["abstract"],
+        serde_json::Value::Bool(true)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--inheritance-graph",
+        "inheritance.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Inheritance graph is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--inheritance-graph",
+        "inheritance.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Inheritance graph cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--inheritance-graph",
+        "inheritance.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Inheritance graph cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
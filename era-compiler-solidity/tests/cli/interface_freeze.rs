@@ -0,0 +1,158 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("interface_freeze")?;
+    let freeze_path = tmp_dir.path().join("freeze.json");
+    std::fs::write(
+        freeze_path.as_path(),
+        format!(
+            r#"{{ "contracts": {{ "{}:Test": {{}} }} }}"#,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        ),
+    )?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--interface-freeze",
+        freeze_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn mismatch(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("interface_freeze")?;
+    let freeze_path = tmp_dir.path().join("freeze.json");
+    std::fs::write(
+        freeze_path.as_path(),
+        format!(
+            r#"{{ "contracts": {{ "{}:Test": {{ "functions": {{ "a9059cbb": "transfer(address,uint256)" }} }} }} }}"#,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        ),
+    )?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--interface-freeze",
+        freeze_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "does not match the interface freeze file",
+        ))
+        .stderr(predicate::str::contains(
+            "- function 0xa9059cbb transfer(address,uint256)",
+        ));
+
+    Ok(())
+}
+
+#[test]
+fn missing_contract() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("interface_freeze")?;
+    let freeze_path = tmp_dir.path().join("freeze.json");
+    std::fs::write(
+        freeze_path.as_path(),
+        format!(
+            r#"{{ "contracts": {{ "{}:Missing": {{}} }} }}"#,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH
+        ),
+    )?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--interface-freeze",
+        freeze_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "from the interface freeze file is not found.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--interface-freeze",
+        crate::common::TEST_BROKEN_INPUT_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Interface freeze file"));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--interface-freeze",
+        "freeze.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Interface freeze is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--interface-freeze",
+        "freeze.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Interface freeze file must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+//!
+//! CLI tests for the project size limits.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--max-sources",
+        "1",
+        "--max-contracts",
+        "1",
+        "--max-source-bytes",
+        "1048576",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn max_sources() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+        "--max-sources",
+        "1",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(4).stderr(predicate::str::contains(
+        "The number of sources (2) exceeds the limit of 1.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn max_contracts() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--max-contracts",
+        "0",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(4).stderr(predicate::str::contains(
+        "The number of contracts (1) exceeds the limit of 0.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn max_source_bytes() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--max-source-bytes",
+        "1",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(4).stderr(predicate::str::contains(
+        "bytes) exceeds the limit of 1 bytes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn no_limits() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--no-limits",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn no_limits_with_limit() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--no-limits",
+        "--max-contracts",
+        "0",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Limits cannot be set together with `--no-limits`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--max-sources",
+        "0",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "The number of sources (1) exceeds the limit of 0.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_urls() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_LLVM_IR_STANDARD_JSON_PATH,
+        "--max-source-bytes",
+        "1",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "bytes) exceeds the limit of 1 bytes.",
+    ));
+
+    Ok(())
+}
@@ -20,6 +20,21 @@ fn default(target: Target, path: &str) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM, crate::common::TEST_LLVM_IR_CONTRACT_PATH)]
+#[test_case(Target::EVM, crate::common::TEST_LLVM_IR_CONTRACT_EVM_PATH)]
+fn bin(target: Target, path: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+    let args = &[path, "--llvm-ir", "--bin"];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(format!("======= {path} =======")))
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn invalid_input_text(target: Target) -> anyhow::Result<()> {
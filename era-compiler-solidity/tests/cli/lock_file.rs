@@ -0,0 +1,169 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("lock_file")?;
+    let lock_path = tmp_dir.path().join("zksolc.lock");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let lock: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(lock_path)?.as_str())?;
+    assert!(lock["solc"]["hash"].is_string());
+    assert!(lock["settings_hash"].is_string());
+    assert!(lock["sources"][crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH].is_string());
+    assert!(
+        lock["sources"][crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH].is_string()
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn locked(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("lock_file")?;
+    let lock_path = tmp_dir.path().join("zksolc.lock");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+        "--locked",
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM, "-Oz")]
+#[test_case(Target::EVM, "-Oz")]
+#[test_case(Target::EraVM, "--disable-solc-optimizer")]
+fn locked_settings_changed(target: Target, option: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("lock_file")?;
+    let lock_path = tmp_dir.path().join("zksolc.lock");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        option,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+        "--locked",
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains(
+            "The build does not match the lock file",
+        ))
+        .stderr(predicate::str::contains("settings"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn locked_missing(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("lock_file")?;
+    let lock_path = tmp_dir.path().join("zksolc.lock");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--lock-file",
+        lock_path.to_str().unwrap(),
+        "--locked",
+    ];
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Lock file"))
+        .stderr(predicate::str::contains("reading"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--lock-file",
+        "zksolc.lock",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Lock file cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--locked",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Lock file cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case(Target::EraVM, "off")]
+#[test_case(Target::EraVM, "conservative")]
+#[test_case(Target::EraVM, "aggressive")]
+#[test_case(Target::EVM, "off")]
+#[test_case(Target::EVM, "conservative")]
+#[test_case(Target::EVM, "aggressive")]
+fn default(target: Target, mode: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--loop-optimization",
+        mode,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--loop-optimization",
+        "maximum",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid loop optimization mode"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--loop-optimization",
+        "off",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Loop optimization mode must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
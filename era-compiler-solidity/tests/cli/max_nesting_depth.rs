@@ -0,0 +1,86 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--max-nesting-depth",
+        "3",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(4).stderr(predicate::str::contains(
+        "The input is too deeply nested: the maximum nesting depth is 3",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn evmla() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--codegen",
+        "evmla",
+        "--max-nesting-depth",
+        "1",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(4).stderr(predicate::str::contains(
+        "The legacy assembly of contract `tests/data/contracts/solidity/Test.sol:Test` is too deeply nested: the maximum nesting depth is 1.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn sufficient() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--codegen",
+        "evmla",
+        "--max-nesting-depth",
+        "2",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn zero() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--max-nesting-depth",
+        "0",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().code(1).stderr(predicate::str::contains(
+        "The maximum nesting depth must be positive.",
+    ));
+
+    Ok(())
+}
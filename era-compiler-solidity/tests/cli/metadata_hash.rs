@@ -91,3 +91,35 @@ fn standard_json_ipfs(target: Target) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json_bytecode_hash(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(2);
+    for path in [
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_BYTECODE_HASH_NONE_PATH,
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_BYTECODE_HASH_IPFS_PATH,
+    ] {
+        let args = &["--standard-json", path];
+
+        let result = crate::cli::execute_zksolc_with_target(args, target)?;
+        let output: serde_json::Value = serde_json::from_slice(
+            result
+                .success()
+                .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+                .get_output()
+                .stdout
+                .as_slice(),
+        )?;
+        outputs.push(output["contracts"]["Test.sol"]["Test"]["evm"]["bytecode"]["object"].clone());
+    }
+    assert!(outputs[0].is_string(), "The bytecode is missing");
+    assert_ne!(
+        outputs[0], outputs[1],
+        "The metadata hash type has not been applied"
+    );
+
+    Ok(())
+}
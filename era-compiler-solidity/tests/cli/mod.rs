@@ -2,6 +2,7 @@
 //! The CLI/e2e tests entry module.
 //!
 
+use std::path::Path;
 use std::path::PathBuf;
 use std::process::Command;
 
@@ -10,34 +11,70 @@ use assert_cmd::cargo::CommandCargoExt;
 
 mod allow_paths;
 mod asm;
+mod ast_cache;
+mod ast_json;
 mod base_path;
 mod bin;
+mod build_timeout;
+mod call_graph;
 mod codegen;
 mod combined_json;
+mod compare;
+mod compatibility_report;
+mod contract;
 mod debug_output_dir;
+mod dedup_report;
+mod dependency_graph;
 mod disable_solc_optimizer;
+mod disassemble;
 mod eravm;
+mod error_format;
 mod evm_version;
+mod execution_profile;
+mod explain_bytecode;
 mod fallback_oz;
 mod force_evmla;
+mod format;
 mod general;
+mod help;
+mod import_graph;
 mod include_path;
+mod inheritance_graph;
+mod interface_freeze;
 mod libraries;
+mod limits;
 mod llvm_ir;
 mod llvm_options;
+mod lock_file;
+mod loop_optimization;
+mod max_nesting_depth;
 mod metadata;
 mod metadata_hash;
 mod metadata_literal;
+mod only;
 mod optimization;
 mod output_dir;
+mod output_selection;
 mod overwrite;
+mod profile;
 mod recursive_process;
 mod remappings;
+mod remarks_file;
+mod revert_decoder;
+mod server;
+mod shuffle_seed;
 mod solc;
+mod solc_input_echo;
+mod solc_install;
+mod solc_per_pragma;
+mod spill_strategy;
 mod standard_json;
+mod stdin_name;
 mod target;
+mod temp_dir;
 mod threads;
 mod version;
+mod watch;
 mod yul;
 
 ///
@@ -72,6 +109,40 @@ pub fn execute_zksolc_with_target(
         .assert())
 }
 
+///
+/// Execute zksolc with the given arguments and the file at `stdin_path` piped to its standard input.
+///
+pub fn execute_zksolc_with_stdin(
+    args: &[&str],
+    stdin_path: &str,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    let mut cmd = assert_cmd::Command::cargo_bin(era_compiler_solidity::DEFAULT_EXECUTABLE_NAME)?;
+    Ok(cmd
+        .env(
+            "PATH",
+            std::fs::canonicalize(PathBuf::from(crate::common::SOLC_DOWNLOAD_DIRECTORY))?,
+        )
+        .args(args)
+        .pipe_stdin(stdin_path)?
+        .assert())
+}
+
+///
+/// Execute zksolc without `solc` in `${PATH}`, and with the `solc` installation directory at `solc_dir`.
+///
+pub fn execute_zksolc_with_solc_dir(
+    args: &[&str],
+    solc_dir: &Path,
+) -> anyhow::Result<assert_cmd::assert::Assert> {
+    let empty_dir = tempfile::TempDir::with_prefix("empty_path")?;
+    let mut cmd = Command::cargo_bin(era_compiler_solidity::DEFAULT_EXECUTABLE_NAME)?;
+    Ok(cmd
+        .env("PATH", empty_dir.path())
+        .env(era_solc::Installer::DIRECTORY_VARIABLE, solc_dir)
+        .args(args)
+        .assert())
+}
+
 ///
 /// Execute solc with the given arguments and return the result.
 ///
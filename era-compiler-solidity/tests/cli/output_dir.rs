@@ -173,6 +173,47 @@ fn weird_path_characters(target: Target) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM, era_compiler_common::EXTENSION_ERAVM_BINARY)]
+#[test_case(Target::EVM, era_compiler_common::EXTENSION_EVM_BINARY)]
+fn duplicate_names(target: Target, extension: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir_zksolc = TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+        "--bin",
+        "--output-dir",
+        tmp_dir_zksolc.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stderr(predicate::str::contains(format!(
+            "Sources `{}`, `{}` share the file name `Test.sol`",
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH,
+        )))
+        .stderr(predicate::str::contains("Compiler run successful"));
+
+    let output_file = tmp_dir_zksolc
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_PATH)
+        .join(format!("Test.{extension}"));
+    let duplicate_output_file = tmp_dir_zksolc
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH)
+        .join(format!("Test.{extension}"));
+    assert_ne!(
+        std::fs::read(output_file)?,
+        std::fs::read(duplicate_output_file)?
+    );
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn combined_json(target: Target) -> anyhow::Result<()> {
@@ -0,0 +1,168 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--output-selection",
+        "bytecode,abi,metadata",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"))
+        .stdout(predicate::str::contains("ABI:\n"))
+        .stdout(predicate::str::contains("Metadata:\n"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_dir(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("output_selection")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--output-selection",
+        "abi",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let contract_dir = tmp_dir
+        .path()
+        .join(crate::common::TEST_SOLIDITY_CONTRACT_NAME);
+    assert!(contract_dir.join("Test.abi").exists());
+    assert!(!contract_dir
+        .join(crate::common::SOLIDITY_BIN_OUTPUT_NAME_ERAVM)
+        .exists());
+    assert!(!contract_dir
+        .join(crate::common::SOLIDITY_BIN_OUTPUT_NAME_EVM)
+        .exists());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn hashes(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH,
+        "--output-selection",
+        "hashes",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "Function signatures:\nb69ef8a8: balance()\n2e1a7d4d: withdraw(uint256)\n",
+        ))
+        .stdout(predicate::str::contains("ABI:\n").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn hashes_output_dir(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("output_selection")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH,
+        "--output-selection",
+        "hashes",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let contract_dir = tmp_dir.path().join("RevertDecoder.sol");
+    assert_eq!(
+        std::fs::read_to_string(contract_dir.join("RevertDecoder.signatures"))?,
+        "b69ef8a8: balance()\n2e1a7d4d: withdraw(uint256)\n"
+    );
+    assert!(!contract_dir.join("RevertDecoder.abi").exists());
+
+    Ok(())
+}
+
+#[test]
+fn hashes_yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--output-selection",
+        "hashes",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Function signature hashes output is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--output-selection",
+        "bytecode,storage",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid output selection: `storage`",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--output-selection",
+        "bytecode",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Cannot output data outside of JSON in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 use era_compiler_common::Target;
 use predicates::prelude::*;
+use tempfile::TempDir;
 use test_case::test_case;
 
 #[test_case(Target::EraVM)]
@@ -44,6 +45,83 @@ fn excess_equals_sign(target: Target) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn empty_prefix(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "context:=./path/to/",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid remapping `context:=./path/to/`: the prefix is empty.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn libraries(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("remappings")?;
+    let library_path = tmp_dir.path().join("Math.sol");
+    let contract_path = tmp_dir.path().join("Calculator.sol");
+    std::fs::write(
+        library_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Math { function square(uint256 x) public pure returns (uint256) { return x * x; } }\n",
+    )?;
+    std::fs::write(
+        contract_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"math/Math.sol\";\ncontract Calculator { function main() external pure returns (uint256) { return Math.square(4); } }\n",
+    )?;
+    let remapping = format!("math/={}/", tmp_dir.path().to_str().unwrap());
+
+    let args = &[
+        contract_path.to_str().unwrap(),
+        remapping.as_str(),
+        "--allow-paths",
+        tmp_dir.path().to_str().unwrap(),
+        "--libraries",
+        "math/Math.sol:Math=0x1234567890123456789012345678901234567890",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json_empty_prefix(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("remappings")?;
+    let input_path = tmp_dir.path().join("input.json");
+    std::fs::write(
+        input_path.as_path(),
+        r#"{"language":"Solidity","sources":{"Test.sol":{"content":"contract Test {}"}},"settings":{"remappings":["=./path/to/"]}}"#,
+    )?;
+
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Invalid remapping `=./path/to/`: the prefix is empty.",
+    ));
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn standard_json(target: Target) -> anyhow::Result<()> {
@@ -0,0 +1,113 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("remarks")?;
+    let remarks_file = tmp_dir.path().join("remarks.yaml");
+    let unrelated_file = tmp_dir.path().join("remarks.yaml.notes.yaml");
+    std::fs::write(unrelated_file.as_path(), "notes")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--remarks-file",
+        remarks_file.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let remarks = std::fs::read_to_string(remarks_file.as_path())?;
+    assert!(
+        remarks.starts_with("--- !"),
+        "Remarks are not a YAML document stream: {remarks}"
+    );
+    assert!(
+        remarks.contains("\nPass:") && remarks.contains("\nFunction:"),
+        "Remarks have no pass or function: {remarks}"
+    );
+    assert_eq!(
+        std::fs::read_to_string(unrelated_file.as_path())?,
+        "notes",
+        "Unrelated file has been modified"
+    );
+    assert_eq!(
+        std::fs::read_dir(tmp_dir.path())?.count(),
+        2,
+        "Per-contract remarks files are not merged"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn cache_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("remarks")?;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--remarks-file",
+        "remarks.yaml",
+        "--cache-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM optimization remarks file cannot be used with the build cache",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--remarks-file",
+        "remarks.yaml",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "LLVM optimization remarks file cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn eravm_assembly() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--eravm-assembly",
+        crate::common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH,
+        "--remarks-file",
+        "remarks.yaml",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "LLVM optimization remarks are only available in Solidity, Yul, and LLVM IR modes.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,128 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("revert_decoder")?;
+    let decoder_path = tmp_dir.path().join("revert_decoder.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH,
+        "--revert-decoder",
+        decoder_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let decoder: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(decoder_path)?.as_str())?;
+    assert_eq!(decoder["builtins"]["08c379a0"], "Error(string)");
+    assert_eq!(decoder["builtins"]["4e487b71"], "Panic(uint256)");
+    assert!(decoder["panics"]["0x11"].is_string());
+
+    let errors = decoder["contracts"][format!(
+        "{}:RevertDecoder",
+        crate::common::TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH
+    )]["errors"]
+        .as_object()
+        .expect("Always exists");
+    assert_eq!(errors["8e4a23d6"]["signature"], "Unauthorized(address)");
+    assert_eq!(
+        errors["cf479181"]["signature"],
+        "InsufficientBalance(uint256,uint256)"
+    );
+
+    let expected = [
+        ("Unauthorized(address)", 31, 13),
+        ("InsufficientBalance(uint256,uint256)", 45, 13),
+        ("ZeroAmount()", 11, 9),
+        ("Overdrawn(uint256,uint256)", 20, 13),
+    ];
+    for (signature, line, column) in expected.into_iter() {
+        let error = errors
+            .values()
+            .find(|error| error["signature"] == signature)
+            .unwrap_or_else(|| panic!("Error `{signature}` is missing"));
+        assert_eq!(
+            error["reverts"],
+            serde_json::json!([format!(
+                "{}:{line}:{column}",
+                crate::common::TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH
+            )]),
+            "Error `{signature}` has unexpected revert sites"
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--revert-decoder",
+        "revert_decoder.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Revert decoder is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--revert-decoder",
+        "revert_decoder.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.failure().stderr(predicate::str::contains(
+        "Revert decoder cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--revert-decoder",
+        "revert_decoder.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Revert decoder cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::path::PathBuf;
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+///
+/// Writes the JSON-RPC `requests` one per line to a file in `tmp_dir`, and returns its path.
+///
+fn write_requests(tmp_dir: &TempDir, requests: &[serde_json::Value]) -> anyhow::Result<PathBuf> {
+    let path = tmp_dir.path().join("requests.jsonl");
+    let requests: Vec<String> = requests.iter().map(|request| request.to_string()).collect();
+    std::fs::write(path.as_path(), requests.join("\n"))?;
+    Ok(path)
+}
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("server")?;
+    let input: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(crate::common::TEST_YUL_STANDARD_JSON_SOLC_PATH)?.as_str(),
+    )?;
+    let requests_path = write_requests(
+        &tmp_dir,
+        &[
+            serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "version" }),
+            serde_json::json!({ "jsonrpc": "2.0", "id": 2, "method": "compile", "params": { "input": input } }),
+            serde_json::json!({ "jsonrpc": "2.0", "id": 3, "method": "compile", "params": { "input": input } }),
+            serde_json::json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+            serde_json::json!({ "jsonrpc": "2.0", "id": 5, "method": "version" }),
+        ],
+    )?;
+
+    let args = &["--server"];
+
+    let result = crate::cli::execute_zksolc_with_stdin(args, requests_path.to_str().unwrap())?;
+    let result = result.success();
+    let stdout = String::from_utf8_lossy(result.get_output().stdout.as_slice()).into_owned();
+    let responses = stdout
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<serde_json::Value>, _>>()?;
+
+    assert_eq!(responses.len(), 4, "The server has not been shut down");
+    assert_eq!(
+        responses[0]["result"]["version"],
+        era_compiler_solidity::version()
+    );
+    for response in responses[1..=2].iter() {
+        assert!(
+            !response["result"]["contracts"]["Test"]["Return"]["evm"]["bytecode"]["object"]
+                .as_str()
+                .expect("The bytecode is missing")
+                .is_empty()
+        );
+    }
+    assert_eq!(responses[3]["result"], serde_json::Value::Null);
+
+    Ok(())
+}
+
+#[test]
+fn invalid_requests() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("server")?;
+    let requests_path = tmp_dir.path().join("requests.jsonl");
+    std::fs::write(
+        requests_path.as_path(),
+        [
+            "{ invalid",
+            r#"{ "jsonrpc": "2.0", "id": 1, "method": "unknown" }"#,
+            r#"{ "jsonrpc": "2.0", "id": 2, "method": "compile", "params": { "input": {} } }"#,
+        ]
+        .join("\n"),
+    )?;
+
+    let args = &["--server"];
+
+    let result = crate::cli::execute_zksolc_with_stdin(args, requests_path.to_str().unwrap())?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""code":-32700"#))
+        .stdout(predicate::str::contains("Method `unknown` not found."))
+        .stdout(predicate::str::contains(r#""code":-32602"#));
+
+    Ok(())
+}
+
+#[test]
+fn input_files() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--server", crate::common::TEST_SOLIDITY_CONTRACT_PATH];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Server mode does not accept input files.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn socket_without_server() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--server-socket", "zksolc.sock"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Server socket requires `--server`.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--server", "--standard-json"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Only one mode is allowed at the same time",
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,101 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn deterministic(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(3);
+    for shuffle_seed in [None, Some("1"), Some("18446744073709551615")] {
+        let mut args = vec![
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            "--metadata",
+        ];
+        if let Some(shuffle_seed) = shuffle_seed {
+            args.extend(["--shuffle-seed", shuffle_seed]);
+        }
+
+        let result = crate::cli::execute_zksolc_with_target(args.as_slice(), target)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+    assert_eq!(outputs[0], outputs[2]);
+
+    Ok(())
+}
+
+#[test]
+fn deterministic_evmla() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(3);
+    for shuffle_seed in ["1", "2", "3"] {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--codegen",
+            "evmla",
+            "--bin",
+            "--shuffle-seed",
+            shuffle_seed,
+        ];
+
+        let result = crate::cli::execute_zksolc(args)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+    assert_eq!(outputs[0], outputs[2]);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn deterministic_standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(2);
+    for shuffle_seed in ["1", "2"] {
+        let args = &[
+            "--standard-json",
+            crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+            "--shuffle-seed",
+            shuffle_seed,
+        ];
+
+        let result = crate::cli::execute_zksolc_with_target(args, target)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--shuffle-seed",
+        "seed",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("--shuffle-seed"));
+
+    Ok(())
+}
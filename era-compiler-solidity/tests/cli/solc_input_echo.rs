@@ -0,0 +1,161 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_input_echo")?;
+    let echo_path = tmp_dir.path().join("solc_input.json");
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc-input-echo",
+        echo_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    let echo = std::fs::read_to_string(echo_path.as_path())?;
+    assert!(
+        echo.starts_with(r#"{"language":"Solidity","sources":{"#),
+        "The echo is not in the order written to solc: {echo}"
+    );
+    let echo: serde_json::Value = serde_json::from_str(echo.as_str())?;
+    assert!(echo["settings"]["outputSelection"].is_object());
+    assert!(echo["settings"].get("solcInputEcho").is_none());
+
+    Ok(())
+}
+
+#[test]
+fn reproducible() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_input_echo")?;
+    let echo_path = tmp_dir.path().join("solc_input.json");
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--bin",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc",
+        solc_compiler.as_str(),
+        "--solc-input-echo",
+        echo_path.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    let output = std::process::Command::new(solc_compiler.as_str())
+        .arg("--standard-json")
+        .arg(echo_path.as_path())
+        .output()?;
+    assert!(output.status.success());
+    let output: serde_json::Value = serde_json::from_slice(output.stdout.as_slice())?;
+    let errors: Vec<&serde_json::Value> = output["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter(|error| error["severity"] == "error")
+                .collect()
+        })
+        .unwrap_or_default();
+    assert_eq!(errors, Vec::<&serde_json::Value>::new());
+    assert!(
+        output["contracts"][crate::common::TEST_SOLIDITY_CONTRACT_PATH]
+            .as_object()
+            .is_some_and(|contracts| !contracts.is_empty())
+    );
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--yul",
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--solc-input-echo",
+        "solc_input.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Solc input echo is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--solc-input-echo",
+        "solc_input.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Solc input echo cannot be used in combined JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--solc-input-echo",
+        "solc_input.json",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Solc input echo must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_setting() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SOLC_INPUT_ECHO_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""solcInput":{"#))
+        .stdout(predicate::str::contains("solcInputEcho").not());
+
+    Ok(())
+}
@@ -0,0 +1,170 @@
+//!
+//! CLI tests for the `zksolc solc` commands and the automatic `solc` resolution.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn install() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_install")?;
+    let version = era_solc::Compiler::LAST_SUPPORTED_VERSION.to_string();
+
+    let args = &["solc", "install", version.as_str()];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result.success().stderr(predicate::str::contains(format!(
+        "Installed `solc` v{version}"
+    )));
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc-version",
+        version.as_str(),
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result.success().stdout(predicate::str::contains("Binary:"));
+
+    Ok(())
+}
+
+#[test]
+fn install_checksum_mismatch() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_install")?;
+    let version = era_solc::Compiler::LAST_SUPPORTED_VERSION.to_string();
+
+    let args = &["solc", "install", version.as_str(), "--sha256", "00"];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("checksum mismatch"));
+    assert_eq!(
+        std::fs::read_dir(tmp_dir.path())?.count(),
+        0,
+        "The rejected download has been installed"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn install_unsupported_version() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_install")?;
+
+    let args = &["solc", "install", "0.4.11"];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("`solc` v0.4.11 is not supported."));
+
+    Ok(())
+}
+
+#[test]
+fn invalid_command() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["solc", "uninstall", "0.8.28"];
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "unrecognized subcommand 'uninstall'",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn offline_missing() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_install")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result.failure().stderr(predicate::str::contains(
+        "cannot be downloaded in offline mode",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn offline_modified() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_install")?;
+    let version = era_solc::Compiler::LAST_SUPPORTED_VERSION.to_string();
+    let executable_path = tmp_dir
+        .path()
+        .join(format!("solc-{version}{}", std::env::consts::EXE_SUFFIX));
+    std::fs::copy(
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable,
+        executable_path.as_path(),
+    )?;
+    let mut checksum_path = executable_path.into_os_string();
+    checksum_path.push(".sha256");
+    std::fs::write(checksum_path, "00")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result.failure().stderr(predicate::str::contains(
+        "has been modified since its installation",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn solc_version_with_solc() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc",
+        "solc",
+        "--solc-version",
+        "0.8.28",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "`solc` version cannot be specified together with the `solc` executable.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn offline_llvm_ir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--llvm-ir",
+        crate::common::TEST_LLVM_IR_CONTRACT_PATH,
+        "--offline",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "`solc` installation is only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
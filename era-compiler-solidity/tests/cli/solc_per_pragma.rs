@@ -0,0 +1,189 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_per_pragma")?;
+    let solc_dir = tmp_dir.path().join("solc");
+    let legacy_path = tmp_dir.path().join("Legacy.sol");
+    let modern_path = tmp_dir.path().join("Modern.sol");
+    std::fs::write(
+        legacy_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity 0.7.6;\ncontract Legacy { function main() external pure returns (uint256) { return 1; } }\n",
+    )?;
+    std::fs::write(
+        modern_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0 <0.9.0;\ncontract Modern { function main() external pure returns (uint256) { return 2; } }\n",
+    )?;
+
+    for version in ["0.7.6", "0.8.28"] {
+        let args = &["solc", "install", version];
+        let result = crate::cli::execute_zksolc_with_solc_dir(args, solc_dir.as_path())?;
+        result.success();
+    }
+
+    let args = &[
+        legacy_path.to_str().unwrap(),
+        modern_path.to_str().unwrap(),
+        "--solc-per-pragma",
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, solc_dir.as_path())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Legacy.sol:Legacy"))
+        .stdout(predicate::str::contains("Modern.sol:Modern"));
+
+    Ok(())
+}
+
+#[test]
+fn imports() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_per_pragma")?;
+    let solc_dir = tmp_dir.path().join("solc");
+    let leaf_path = tmp_dir.path().join("Leaf.sol");
+    let root_path = tmp_dir.path().join("Root.sol");
+    std::fs::write(
+        leaf_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity 0.7.6;\nlibrary Leaf { function get() internal pure returns (uint256) { return 1; } }\n",
+    )?;
+    std::fs::write(
+        root_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.7.0;\nimport \"./Leaf.sol\";\ncontract Root { function main() external pure returns (uint256) { return Leaf.get(); } }\n",
+    )?;
+
+    let args = &["solc", "install", "0.7.6"];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, solc_dir.as_path())?;
+    result.success();
+
+    let args = &[
+        root_path.to_str().unwrap(),
+        "--solc-per-pragma",
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, solc_dir.as_path())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Root.sol:Root"));
+
+    Ok(())
+}
+
+#[test]
+fn unsatisfiable() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("solc_per_pragma")?;
+    let source_path = tmp_dir.path().join("Future.sol");
+    std::fs::write(
+        source_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity ^0.9.0;\ncontract Future {}\n",
+    )?;
+
+    let args = &[
+        source_path.to_str().unwrap(),
+        "--solc-per-pragma",
+        "--offline",
+        "--bin",
+    ];
+    let result = crate::cli::execute_zksolc_with_solc_dir(args, tmp_dir.path())?;
+    result.failure().stderr(predicate::str::contains(
+        "No supported `solc` version satisfies the pragmas",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn solc() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc",
+        "solc",
+        "--solc-per-pragma",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "`solc` per pragma resolution cannot be used together with a specific `solc` executable or version.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn combined_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--solc-per-pragma",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "`solc` per pragma resolution is only available in basic CLI mode",
+    ));
+
+    Ok(())
+}
+
+#[test_case("--call-graph", "call_graph")]
+#[test_case("--import-graph", "imports.json")]
+#[test_case("--emit", "dep-graph")]
+#[test_case("--revert-decoder", "decoder.json")]
+#[test_case("--solc-input-echo", "input.json")]
+#[test_case("--only", "Test")]
+fn unsupported_option(option: &str, value: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--solc-per-pragma",
+        option,
+        value,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(format!(
+        "`solc` per pragma resolution cannot be used together with `{option}"
+    )));
+
+    Ok(())
+}
+
+#[test]
+fn unsupported_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--solc-per-pragma",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result.failure().stderr(predicate::str::contains(
+        "`solc` per pragma resolution is only available for the EraVM target.",
+    ));
+
+    Ok(())
+}
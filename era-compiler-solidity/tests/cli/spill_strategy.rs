@@ -0,0 +1,122 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM, "size")]
+#[test_case(Target::EraVM, "speed")]
+#[test_case(Target::EraVM, "hybrid")]
+#[test_case(Target::EVM, "size")]
+#[test_case(Target::EVM, "speed")]
+#[test_case(Target::EVM, "hybrid")]
+fn default(target: Target, strategy: &str) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--spill-strategy",
+        strategy,
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Binary:\n"));
+
+    Ok(())
+}
+
+#[test]
+fn invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--spill-strategy",
+        "registers",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Invalid spill strategy"));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn report(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("spill_report")?;
+    let remarks_file = tmp_dir.path().join("remarks.yaml");
+    let spill_report = tmp_dir.path().join("spills.json");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--remarks-file",
+        remarks_file.to_str().unwrap(),
+        "--spill-report",
+        spill_report.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    let report: era_compiler_solidity::SpillReport =
+        serde_json::from_str(std::fs::read_to_string(spill_report)?.as_str())?;
+    let expected = era_compiler_solidity::SpillReport::try_from_remarks(remarks_file.as_path())?;
+    assert_eq!(
+        report.functions, expected.functions,
+        "The report must match the merged remarks file"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn report_without_remarks_file() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--spill-report",
+        "spills.json",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Spill report requires the LLVM optimization remarks file to be specified with `--remarks-file`.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--spill-strategy",
+        "hybrid",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Spill strategy must be specified in standard JSON input settings.",
+    ));
+
+    Ok(())
+}
@@ -4,6 +4,7 @@
 
 use era_compiler_common::Target;
 use predicates::prelude::*;
+use tempfile::TempDir;
 use test_case::test_case;
 
 #[test_case(Target::EraVM)]
@@ -162,6 +163,130 @@ fn suppressed_messages(target: Target) -> anyhow::Result<()> {
     Ok(())
 }
 
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn suppressions(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_JSON_CONTRACT_PATH_SUPPRESSIONS,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""file":"New.sol""#))
+        .stdout(predicate::str::contains(r#""file":"Legacy.sol""#).not());
+
+    Ok(())
+}
+
+#[test]
+fn suppressions_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_JSON_CONTRACT_PATH_SUPPRESSIONS_INVALID,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Invalid suppressed error or warning type: unknown",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn severity_promoted(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_PROMOTED_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .code(era_solc::ExitCode::TranslationError.code())
+        .stdout(predicate::str::contains(r#""errorCode":"txorigin""#))
+        .stdout(predicate::str::contains(r#""severity":"error""#))
+        .stdout(predicate::str::contains("bytecode").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn severity_demoted(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_DEMOTED_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(r#""errorCode":"sendtransfer""#))
+        .stdout(predicate::str::contains(r#""severity":"warning""#))
+        .stdout(predicate::str::contains("bytecode"));
+
+    Ok(())
+}
+
+#[test]
+fn severity_invalid() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Invalid diagnostic code in severity overrides: unknown",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn stream_output(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_STREAM_OUTPUT_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.to_owned();
+    let lines = String::from_utf8(output)?
+        .lines()
+        .map(serde_json::from_str)
+        .collect::<Result<Vec<serde_json::Value>, _>>()?;
+
+    let contracts = lines
+        .iter()
+        .filter_map(|line| line.get("name").and_then(|name| name.as_str()))
+        .collect::<Vec<&str>>();
+    assert_eq!(contracts, vec!["A", "B"]);
+    assert!(lines
+        .last()
+        .and_then(|line| line.get("zk_version"))
+        .is_some());
+
+    Ok(())
+}
+
 #[test_case(Target::EraVM)]
 #[test_case(Target::EVM)]
 fn recursion(target: Target) -> anyhow::Result<()> {
@@ -448,3 +573,1051 @@ fn yul_solc(target: Target) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+fn compile_modified_input(
+    path: &str,
+    target: Target,
+    modify: impl FnOnce(&mut serde_json::Value),
+) -> anyhow::Result<serde_json::Value> {
+    let tmp_dir = TempDir::with_prefix("modified_input")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let mut input: serde_json::Value =
+        serde_json::from_str(std::fs::read_to_string(path)?.as_str())?;
+    modify(&mut input);
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let errors: Vec<&serde_json::Value> = output["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter(|error| error["severity"] == "error")
+                .collect()
+        })
+        .unwrap_or_default();
+    assert!(errors.is_empty(), "Unexpected errors: {errors:?}");
+    Ok(output)
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn reachable_selectors(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let bytecode = |output: &serde_json::Value| -> String {
+        output["contracts"]["Default.yul"]["Test"]["evm"]["bytecode"]["object"]
+            .as_str()
+            .expect("The bytecode is missing")
+            .to_owned()
+    };
+
+    let restricted = compile_modified_input(
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_REACHABLE_SELECTORS_PATH,
+        target,
+        |input| {
+            input["settings"]["outputSelection"]["*"]["*"] = serde_json::json!(["evm.bytecode"])
+        },
+    )?;
+    let unrestricted = compile_modified_input(
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_REACHABLE_SELECTORS_PATH,
+        target,
+        |input| {
+            input["settings"]["outputSelection"]["*"]["*"] = serde_json::json!(["evm.bytecode"]);
+            input["settings"]
+                .as_object_mut()
+                .expect("Always exists")
+                .remove("reachableSelectors");
+        },
+    )?;
+
+    let restricted = bytecode(&restricted);
+    let unrestricted = bytecode(&unrestricted);
+    assert!(!restricted.is_empty());
+    assert!(
+        restricted.len() < unrestricted.len(),
+        "The restricted bytecode of {} characters is not smaller than the unrestricted one of {} characters",
+        restricted.len(),
+        unrestricted.len(),
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn reachable_selectors_invalid(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_REACHABLE_SELECTORS_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Invalid function selector `0x3df4`",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn immutables(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let contract = |output: &serde_json::Value| -> anyhow::Result<(String, serde_json::Value)> {
+        let contract = &output["contracts"]["Test.yul"]["Test"];
+        let bytecode = contract["evm"]["bytecode"]["object"]
+            .as_str()
+            .expect("The bytecode is missing")
+            .to_owned();
+        let metadata = match contract["metadata"] {
+            serde_json::Value::String(ref metadata) => serde_json::from_str(metadata.as_str())?,
+            ref metadata => metadata.to_owned(),
+        };
+        Ok((bytecode, metadata))
+    };
+
+    let propagated = compile_modified_input(
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_IMMUTABLES_PATH,
+        target,
+        |input| {
+            input["settings"]["outputSelection"]["*"]["*"] =
+                serde_json::json!(["metadata", "evm.bytecode"])
+        },
+    )?;
+    let loaded = compile_modified_input(
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_IMMUTABLES_PATH,
+        target,
+        |input| {
+            input["settings"]["outputSelection"]["*"]["*"] =
+                serde_json::json!(["metadata", "evm.bytecode"]);
+            input["settings"]
+                .as_object_mut()
+                .expect("Always exists")
+                .remove("immutables");
+        },
+    )?;
+
+    let (propagated_bytecode, propagated_metadata) = contract(&propagated)?;
+    let (loaded_bytecode, loaded_metadata) = contract(&loaded)?;
+    assert_eq!(
+        propagated_metadata["immutables"],
+        serde_json::json!({ "7": "0x000000000000000000000000000000000000cafe" })
+    );
+    assert!(loaded_metadata.get("immutables").is_none());
+    assert!(!propagated_bytecode.is_empty());
+    assert_ne!(propagated_bytecode, loaded_bytecode);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn llvm_options_per_contract(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LLVM_OPTIONS_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("bytecode"))
+        .stdout(predicate::str::contains("LLVMOptions").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn llvm_options_per_contract_unknown(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("llvm_options")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let mut input: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(
+            crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LLVM_OPTIONS_PATH,
+        )?
+        .as_str(),
+    )?;
+    input["settings"]["LLVMOptions"]["A:Missing"] = serde_json::json!(["-disable-early-taildup"]);
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let warnings: Vec<&str> = output["errors"]
+        .as_array()
+        .expect("The messages are missing")
+        .iter()
+        .filter(|error| error["severity"] == "warning")
+        .filter_map(|error| error["message"].as_str())
+        .filter(|message| message.contains("LLVMOptions"))
+        .collect();
+    assert_eq!(
+        warnings,
+        vec!["The `LLVMOptions` key `A:Missing` does not match any contract to compile, so its options are ignored."]
+    );
+    assert!(output["contracts"]["A"]["C"]["abi"].is_array());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn policy_instruction(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_POLICY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Policy rule `SEC-001` forbids instruction `selfdestruct`",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn policy_instruction_invalid(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_POLICY_INVALID_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Policy rule `SEC-001` forbids unknown instruction `teleport`",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn factory_dependency_cycle(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_YUL_STANDARD_JSON_ZKSOLC_CYCLE_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.to_owned();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+
+    let errors = output["errors"]
+        .as_array()
+        .expect("Always exists")
+        .iter()
+        .filter(|error| {
+            error["message"]
+                .as_str()
+                .is_some_and(|message| message.starts_with("Cyclic factory dependency"))
+        })
+        .collect::<Vec<&serde_json::Value>>();
+    let mut files = errors
+        .iter()
+        .filter_map(|error| error["sourceLocation"]["file"].as_str())
+        .collect::<Vec<&str>>();
+    files.sort();
+    assert_eq!(files, vec!["First.yul", "Second.yul"]);
+    for error in errors.into_iter() {
+        assert_eq!(error["severity"], "error");
+        assert!(error["message"]
+            .as_str()
+            .is_some_and(|message| message.contains(
+                "\n    First.yul:First\n      --> deploys the next contract at First.yul:12:"
+            )));
+    }
+
+    Ok(())
+}
+
+#[test]
+fn policy_warning() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_POLICY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Policy rule `SEC-002` forbids `txorigin` warnings",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn detect_missing_libraries(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_DETECT_MISSING_LIBRARIES_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("missingLibraries"))
+        .stdout(predicate::str::contains("Test.sol:Library"));
+
+    Ok(())
+}
+
+#[test]
+fn library_deployer() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LIBRARY_DEPLOYER_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value = serde_json::from_slice(
+        result
+            .success()
+            .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+            .get_output()
+            .stdout
+            .as_slice(),
+    )?;
+    let contracts = &output["contracts"];
+
+    let library_hash = contracts["Test.sol"]["Library"]["hash"]
+        .as_str()
+        .expect("Always exists");
+    assert_eq!(
+        contracts["LibraryDeployer.yul"]["LibraryDeployer"]["factoryDependencies"][library_hash],
+        "Test.sol:Library"
+    );
+
+    let library_address = era_compiler_solidity::library_deployer::address(
+        &hex::decode(library_hash)?.try_into().expect("Always valid"),
+    );
+    assert_eq!(
+        contracts["Test.sol"]["Test"]["objectFormat"],
+        serde_json::to_value(era_compiler_common::ObjectFormat::Raw)?
+    );
+    assert!(contracts["Test.sol"]["Test"]["evm"]["bytecode"]["object"]
+        .as_str()
+        .expect("Always exists")
+        .contains(hex::encode(library_address).as_str()));
+
+    Ok(())
+}
+
+#[test]
+fn library_deployer_evm() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LIBRARY_DEPLOYER_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, Target::EVM)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "The `libraryDeployer` setting is not supported for the {} target yet, and therefore ignored.",
+            Target::EVM
+        )))
+        .stdout(predicate::str::contains("LibraryDeployer.yul").not());
+
+    Ok(())
+}
+
+#[test_case(
+    Target::EraVM,
+    crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_STORAGE_LAYOUT_PATH,
+    &["value", "owner", "balances"],
+    None
+)]
+#[test_case(
+    Target::EVM,
+    crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_STORAGE_LAYOUT_PATH,
+    &["value", "owner", "balances"],
+    None
+)]
+#[test_case(
+    Target::EraVM,
+    crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_TRANSIENT_STORAGE_LAYOUT_PATH,
+    &["value"],
+    Some(&["locked"])
+)]
+#[test_case(
+    Target::EVM,
+    crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_TRANSIENT_STORAGE_LAYOUT_PATH,
+    &["value"],
+    Some(&["locked"])
+)]
+fn storage_layout(
+    target: Target,
+    path: &str,
+    storage_labels: &[&str],
+    transient_storage_labels: Option<&[&str]>,
+) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &["--solc", solc_compiler.as_str(), "--standard-json", path];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let errors: Vec<&serde_json::Value> = output["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter(|error| error["severity"] == "error")
+                .collect()
+        })
+        .unwrap_or_default();
+    assert_eq!(errors, Vec::<&serde_json::Value>::new());
+
+    let labels = |layout: &serde_json::Value| -> Vec<String> {
+        layout["storage"]
+            .as_array()
+            .expect("The storage layout is missing")
+            .iter()
+            .map(|slot| slot["label"].as_str().expect("Always exists").to_owned())
+            .collect()
+    };
+    let contract = &output["contracts"]["Storage.sol"]["Storage"];
+    assert_eq!(labels(&contract["storageLayout"]), storage_labels);
+    match transient_storage_labels {
+        Some(transient_storage_labels) => assert_eq!(
+            labels(&contract["transientStorageLayout"]),
+            transient_storage_labels
+        ),
+        None => assert!(contract.get("transientStorageLayout").is_none()),
+    }
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn natspec(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_NATSPEC_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"userdoc\":{"))
+        .stdout(predicate::str::contains("Returns the answer."))
+        .stdout(predicate::str::contains("\"devdoc\":{"))
+        .stdout(predicate::str::contains("Always returns 42."))
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn assembly_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ASSEMBLY_JSON_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains(
+            "\"assemblyJson\":{\"instructions\":[",
+        ))
+        .stdout(predicate::str::contains("\"assembly\":").not())
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not());
+
+    Ok(())
+}
+
+#[test]
+fn eravm_settings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ERAVM_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+
+    let errors: Vec<&serde_json::Value> = output["errors"]
+        .as_array()
+        .map(|errors| {
+            errors
+                .iter()
+                .filter(|error| error["severity"] == "error")
+                .collect()
+        })
+        .unwrap_or_default();
+    assert_eq!(errors, Vec::<&serde_json::Value>::new());
+
+    let contract = &output["contracts"]["Test.sol"]["Test"];
+    assert!(contract["eravm"]["assembly"]
+        .as_str()
+        .is_some_and(|assembly| !assembly.is_empty()));
+    let bytecode = contract["evm"]["bytecode"]["object"]
+        .as_str()
+        .expect("The bytecode is missing");
+    let bytecode = hex::decode(bytecode)?;
+    assert_eq!(
+        era_compiler_solidity::BytecodeComparison::split_metadata(
+            bytecode.as_slice(),
+            era_compiler_common::HashType::None
+        )
+        .1
+        .len(),
+        0,
+        "The bytecode has a metadata trailer despite the `none` hash type"
+    );
+    assert_eq!(bytecode.len() % 64, 32);
+
+    Ok(())
+}
+
+#[test]
+fn build_cache() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("build_cache")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let cache_path = tmp_dir.path().join("cache");
+    let ast_cache_path = tmp_dir.path().join("ast_cache");
+
+    let write_input = |leaf: &str| -> anyhow::Result<()> {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {
+                "Leaf.sol": {
+                    "content": leaf,
+                },
+                "Root.sol": {
+                    "content": "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"./Leaf.sol\";\ncontract Root { function main() external pure returns (uint256) { return Leaf.get(); } }\n",
+                },
+                "Other.sol": {
+                    "content": "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Other { function main() external pure returns (uint256) { return 42; } }\n",
+                },
+            },
+            "settings": {
+                "optimizer": {
+                    "mode": "3",
+                },
+                "outputSelection": {
+                    "*": {
+                        "*": ["abi"],
+                    },
+                },
+                "buildCache": cache_path,
+                "astCache": ast_cache_path,
+                "outputSummary": true,
+            },
+        });
+        std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+        Ok(())
+    };
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+    let get_summary = |stdout: &[u8]| -> anyhow::Result<era_solc::StandardJsonOutputSummary> {
+        let output: era_solc::StandardJsonOutput = serde_json::from_slice(stdout)?;
+        Ok(output.summary.expect("Always exists"))
+    };
+
+    write_input("// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 1; } }\n")?;
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        3,
+        "Not all contracts have been cached"
+    );
+    let summary = get_summary(stdout.as_slice())?;
+    assert_eq!(
+        summary.ast_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(0, 3))
+    );
+    assert_eq!(
+        summary.build_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(0, 3))
+    );
+
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        3,
+        "The unchanged contracts have been recompiled"
+    );
+    let summary = get_summary(stdout.as_slice())?;
+    assert_eq!(
+        summary.ast_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(3, 0))
+    );
+    assert_eq!(
+        summary.build_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(3, 0))
+    );
+
+    write_input("// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 2; } }\n")?;
+    let result = crate::cli::execute_zksolc(args)?;
+    let stdout = result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+        .get_output()
+        .stdout
+        .clone();
+    assert_eq!(
+        std::fs::read_dir(cache_path.as_path())?.count(),
+        5,
+        "Only the edited source and its dependents must be recompiled"
+    );
+    let summary = get_summary(stdout.as_slice())?;
+    assert_eq!(
+        summary.ast_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(1, 2))
+    );
+    assert_eq!(
+        summary.build_cache,
+        Some(era_solc::StandardJsonOutputSummaryCache::new(1, 2))
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn interface_freeze(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("interface_freeze")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let freeze_path = tmp_dir.path().join("freeze.json");
+    std::fs::write(
+        freeze_path.as_path(),
+        r#"{ "contracts": { "Test.sol:Test": { "functions": { "dffeadd0": "main()" } } } }"#,
+    )?;
+
+    let write_input = |source: &str| -> anyhow::Result<()> {
+        let input = serde_json::json!({
+            "language": "Solidity",
+            "sources": {
+                "Test.sol": {
+                    "content": source,
+                },
+            },
+            "settings": {
+                "outputSelection": {
+                    "*": {
+                        "*": ["evm.methodIdentifiers"],
+                    },
+                },
+                "interfaceFreeze": freeze_path,
+            },
+        });
+        std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+        Ok(())
+    };
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    write_input("// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Test { function main() external pure returns (uint256) { return 42; } }\n")?;
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"severity\":\"error\"").not())
+        .stdout(predicate::str::contains("\"abi\"").not());
+
+    write_input("// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\ncontract Test { function main(uint256 x) external pure returns (uint256) { return x; } }\n")?;
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let error = output["errors"]
+        .as_array()
+        .into_iter()
+        .flatten()
+        .find(|error| {
+            error["message"]
+                .as_str()
+                .is_some_and(|message| message.contains("does not match the interface freeze file"))
+        })
+        .expect("Always exists");
+    assert_eq!(
+        error["interfaceDiff"],
+        serde_json::json!({
+            "removed": [
+                { "kind": "function", "selector": "dffeadd0", "signature": "main()" },
+            ],
+            "added": [
+                { "kind": "function", "selector": "ab3ae255", "signature": "main(uint256)" },
+            ],
+        })
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn summary(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SUMMARY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let stdout = result.success().get_output().stdout.clone();
+    let output: era_solc::StandardJsonOutput = serde_json::from_slice(stdout.as_slice())?;
+    let summary = output.summary.expect("Always exists");
+
+    assert_eq!(summary.total_contracts, 1);
+    assert_eq!(
+        summary.contracts.keys().collect::<Vec<&String>>(),
+        vec!["A:C"]
+    );
+    assert!(summary.contracts["A:C"].size > 0);
+    assert_eq!(summary.errors, 0);
+    assert_eq!(
+        summary.warnings,
+        output
+            .errors
+            .iter()
+            .filter(|message| message.severity == "warning")
+            .count()
+    );
+    assert!(summary.ast_cache.is_none());
+    assert!(summary.build_cache.is_none());
+    assert!(summary.solc_duration_ms.is_some());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn summary_not_requested(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"summary\"").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_timings(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_TIMINGS_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"timings\":{\"solcDurationMs\":"))
+        .stdout(predicate::str::contains(
+            "\"sources\":{\"A\":{\"analysisDurationMs\":",
+        ))
+        .stdout(predicate::str::contains(
+            "\"contracts\":{\"A:C\":{\"backendDurationMs\":",
+        ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_timings_not_requested(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("\"timings\"").not());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn parallel(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let mut outputs = Vec::with_capacity(2);
+    for path in [
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PARALLEL_PATH,
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PARALLEL_DISABLED_PATH,
+    ] {
+        let args = &["--solc", solc_compiler.as_str(), "--standard-json", path];
+
+        let result = crate::cli::execute_zksolc_with_target(args, target)?;
+        let output: serde_json::Value =
+            serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+        let errors: Vec<&serde_json::Value> = output["errors"]
+            .as_array()
+            .map(|errors| {
+                errors
+                    .iter()
+                    .filter(|error| error["severity"] == "error")
+                    .collect()
+            })
+            .unwrap_or_default();
+        assert_eq!(errors, Vec::<&serde_json::Value>::new());
+        for (source, contract) in [("A", "C"), ("B", "D")] {
+            assert!(
+                output["contracts"][source][contract]["evm"]["bytecode"]["object"]
+                    .as_str()
+                    .is_some_and(|bytecode| !bytecode.is_empty()),
+                "The bytecode of `{source}:{contract}` is missing"
+            );
+        }
+        outputs.push(output["contracts"].clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn parallel_zero_threads(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("parallel")?;
+    let input_path = tmp_dir.path().join("input.json");
+    let mut input: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PARALLEL_PATH)?
+            .as_str(),
+    )?;
+    input["settings"]["parallel"]["threads"] = serde_json::json!(0);
+    std::fs::write(input_path.as_path(), serde_json::to_vec(&input)?)?;
+
+    let args = &["--standard-json", input_path.to_str().unwrap()];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output: serde_json::Value =
+        serde_json::from_slice(result.success().get_output().stdout.as_slice())?;
+    let errors: Vec<&str> = output["errors"]
+        .as_array()
+        .expect("The messages are missing")
+        .iter()
+        .filter(|error| error["severity"] == "error")
+        .filter_map(|error| error["message"].as_str())
+        .collect();
+    assert_eq!(errors.len(), 1, "Unexpected errors: {errors:?}");
+    assert!(
+        errors[0].contains("invalid value: integer `0`, expected a nonzero usize"),
+        "Unexpected error: {}",
+        errors[0]
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn only(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ONLY_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.clone();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contracts = output["contracts"]["A"]
+        .as_object()
+        .expect("Always exists")
+        .keys()
+        .cloned()
+        .collect::<Vec<String>>();
+    assert_eq!(contracts, vec!["C".to_owned(), "D".to_owned()]);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_selection_granular(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_GRANULAR_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.clone();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contracts = &output["contracts"]["A"];
+    assert!(contracts["C"]["evm"]["bytecode"]["object"].is_string());
+    assert!(contracts["D"]["evm"]["bytecode"]["object"].is_string());
+    assert!(contracts["E"]["abi"].is_array());
+    assert!(contracts["E"]["evm"]["bytecode"].is_null());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_selection_sub_selectors(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_SUB_SELECTORS_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.clone();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    assert!(
+        output["errors"].as_array().map_or(true, |errors| errors
+            .iter()
+            .all(|error| error["severity"] != "error")),
+        "{output}"
+    );
+    let contracts = &output["contracts"]["A"];
+    assert!(contracts["C"]["evm"]["bytecode"]["object"].is_string());
+    assert!(contracts["D"]["evm"]["bytecode"]["object"].is_string());
+    assert!(contracts["E"]["abi"].is_array());
+    assert!(contracts["E"]["evm"]["bytecode"].is_null());
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn output_selection_wildcard(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)?.executable;
+
+    let args = &[
+        "--solc",
+        solc_compiler.as_str(),
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_WILDCARD_PATH,
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    let output = result.success().get_output().stdout.clone();
+    let output: serde_json::Value = serde_json::from_slice(output.as_slice())?;
+    let contracts = &output["contracts"]["A"];
+    for name in ["C", "D", "E"] {
+        assert!(contracts[name]["abi"].is_array());
+        assert!(contracts[name]["evm"]["bytecode"]["object"].is_string());
+    }
+
+    Ok(())
+}
@@ -0,0 +1,154 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["-", "--stdin-name", "Contract.sol", "--bin"];
+
+    let result =
+        crate::cli::execute_zksolc_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract.sol:Test"));
+
+    Ok(())
+}
+
+#[test]
+fn padded_path() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[" - ", "--stdin-name", "Contract.sol", "--bin"];
+
+    let result =
+        crate::cli::execute_zksolc_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Contract.sol:Test"));
+
+    Ok(())
+}
+
+#[test]
+fn libraries() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("stdin_name")?;
+    let source_path = tmp_dir.path().join("source.sol");
+    std::fs::write(
+        source_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Math { function square(uint256 x) public pure returns (uint256) { return x * x; } }\ncontract Calculator { function main() external pure returns (uint256) { return Math.square(4); } }\n",
+    )?;
+
+    let args = &[
+        "-",
+        "--stdin-name",
+        "Calculator.sol",
+        "--libraries",
+        "Calculator.sol:Math=0x1234567890123456789012345678901234567890",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_stdin(args, source_path.to_str().unwrap())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Calculator.sol:Calculator"));
+
+    Ok(())
+}
+
+#[test]
+fn remappings() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("stdin_name")?;
+    let library_path = tmp_dir.path().join("Leaf.sol");
+    let source_path = tmp_dir.path().join("source.sol");
+    std::fs::write(
+        library_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nlibrary Leaf { function get() internal pure returns (uint256) { return 1; } }\n",
+    )?;
+    std::fs::write(
+        source_path.as_path(),
+        "// SPDX-License-Identifier: Unlicensed\npragma solidity >=0.8.0;\nimport \"leaf/Leaf.sol\";\ncontract Root { function main() external pure returns (uint256) { return Leaf.get(); } }\n",
+    )?;
+    let remapping = format!("leaf/={}/", tmp_dir.path().to_str().unwrap());
+
+    let args = &[
+        "-",
+        remapping.as_str(),
+        "--stdin-name",
+        "Root.sol",
+        "--allow-paths",
+        tmp_dir.path().to_str().unwrap(),
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_stdin(args, source_path.to_str().unwrap())?;
+    result
+        .success()
+        .stdout(predicate::str::contains("Root.sol:Root"));
+
+    Ok(())
+}
+
+#[test]
+fn missing_stdin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--stdin-name",
+        "Contract.sol",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Standard input name requires the standard input `-` to be passed once among the input files.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn clash() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "-",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--stdin-name",
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+    ];
+
+    let result =
+        crate::cli::execute_zksolc_with_stdin(args, crate::common::TEST_SOLIDITY_CONTRACT_PATH)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("clashes with an input file."));
+
+    Ok(())
+}
+
+#[test]
+fn yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--yul", "-", "--stdin-name", "Contract.yul", "--bin"];
+
+    let result =
+        crate::cli::execute_zksolc_with_stdin(args, crate::common::TEST_YUL_CONTRACT_PATH)?;
+    result.failure().stderr(predicate::str::contains(
+        "Standard input name is only available in Solidity mode, and not in standard JSON mode.",
+    ));
+
+    Ok(())
+}
@@ -17,3 +17,105 @@ fn warning_evm() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn dual() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--target",
+        "eravm,evm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result
+        .success()
+        .stdout(predicate::str::contains("======= Target: eravm ======="))
+        .stdout(predicate::str::contains("======= Target: evm ======="));
+
+    Ok(())
+}
+
+#[test]
+fn dual_output_dir() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = tempfile::TempDir::with_prefix("zksolc_output")?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--target",
+        "eravm,evm",
+        "--output-dir",
+        tmp_dir.path().to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success();
+
+    assert!(tmp_dir.path().join("eravm").is_dir());
+    assert!(tmp_dir.path().join("evm").is_dir());
+
+    Ok(())
+}
+
+#[test]
+fn dual_invalid_target() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--target",
+        "eravm,eravm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Only `eravm` and `evm` can be specified together as targets.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn dual_yul() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_PATH,
+        "--yul",
+        "--bin",
+        "--target",
+        "eravm,evm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Multiple targets are only available in Solidity mode.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn dual_standard_json() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--target",
+        "eravm,evm",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Multiple targets cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
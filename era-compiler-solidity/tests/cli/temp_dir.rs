@@ -0,0 +1,151 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use era_compiler_common::Target;
+use predicates::prelude::*;
+use tempfile::TempDir;
+use test_case::test_case;
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn default(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("temp_dir")?;
+    let remarks_file = tmp_dir.path().join("remarks.yaml");
+    let temp_dir = tmp_dir.path().join("temp");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--remarks-file",
+        remarks_file.to_str().unwrap(),
+        "--temp-dir",
+        temp_dir.to_str().unwrap(),
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    assert!(remarks_file.exists(), "Remarks file not found");
+    assert_eq!(
+        std::fs::read_dir(temp_dir.as_path())?.count(),
+        0,
+        "Temporary directory is not cleaned up"
+    );
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn keep_always(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("temp_dir")?;
+    let temp_dir = tmp_dir.path().join("temp");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--temp-dir",
+        temp_dir.to_str().unwrap(),
+        "--temp-dir-policy",
+        "keep-always",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    assert_eq!(
+        std::fs::read_dir(temp_dir.as_path())?.count(),
+        1,
+        "Temporary directory is not kept"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn invalid_policy() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--bin",
+        "--temp-dir-policy",
+        "never",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Invalid temporary directory cleanup policy",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--standard-json",
+        crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+        "--temp-dir",
+        "temp",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success().stdout(predicate::str::contains(
+        "Temporary directory options cannot be used in standard JSON mode.",
+    ));
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn combined_json_keep_on_error(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("temp_dir")?;
+    let temp_dir = tmp_dir.path().join("temp");
+
+    let args = &[
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        "--combined-json",
+        "bin",
+        "--temp-dir",
+        temp_dir.to_str().unwrap(),
+        "--temp-dir-policy",
+        "keep-on-error",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result.success();
+
+    assert_eq!(
+        std::fs::read_dir(temp_dir.as_path())?.count(),
+        0,
+        "Temporary directory is not cleaned up"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn server() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--server", "--temp-dir", "temp"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Temporary directory options cannot be used in server mode.",
+    ));
+
+    Ok(())
+}
@@ -35,3 +35,50 @@ fn standard_json(target: Target) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn deterministic(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(2);
+    for threads in ["1", "4"] {
+        let args = &[
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+            crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+            "--bin",
+            "--metadata",
+            "--threads",
+            threads,
+        ];
+
+        let result = crate::cli::execute_zksolc_with_target(args, target)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    Ok(())
+}
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn deterministic_standard_json(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let mut outputs = Vec::with_capacity(2);
+    for threads in ["1", "4"] {
+        let args = &[
+            "--standard-json",
+            crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH,
+            "--threads",
+            threads,
+        ];
+
+        let result = crate::cli::execute_zksolc_with_target(args, target)?;
+        outputs.push(result.success().get_output().stdout.clone());
+    }
+    assert_eq!(outputs[0], outputs[1]);
+
+    Ok(())
+}
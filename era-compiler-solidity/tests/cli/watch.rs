@@ -0,0 +1,193 @@
+//!
+//! CLI tests for the eponymous option.
+//!
+
+use std::io::BufRead;
+use std::path::PathBuf;
+use std::process::Child;
+use std::process::Command;
+use std::process::Stdio;
+use std::sync::mpsc::Receiver;
+use std::time::Duration;
+use std::time::Instant;
+
+use assert_cmd::cargo::CommandCargoExt;
+use predicates::prelude::*;
+use tempfile::TempDir;
+
+/// The timeout of each compilation in the tests.
+const TIMEOUT: Duration = Duration::from_secs(120);
+
+#[test]
+fn default() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("watch")?;
+    let source_path = tmp_dir.path().join("Test.sol");
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        source_path.as_path(),
+    )?;
+
+    let (mut process, receiver) = spawn(&[source_path.to_str().unwrap(), "--bin", "--watch"])?;
+
+    let result = wait_for(&receiver, "Waiting for changes").and_then(|()| {
+        let mut source = std::fs::read_to_string(source_path.as_path())?;
+        source.push_str("\n// Edited.\n");
+        std::fs::write(source_path.as_path(), source)?;
+        wait_for(&receiver, "Changes detected")?;
+        wait_for(&receiver, "Waiting for changes")
+    });
+    process.kill()?;
+    process.wait()?;
+    result
+}
+
+#[test]
+fn output_dir_not_watched() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("watch")?;
+    let source_path = tmp_dir.path().join("Test.sol");
+    std::fs::copy(
+        crate::common::TEST_SOLIDITY_CONTRACT_PATH,
+        source_path.as_path(),
+    )?;
+    let output_path = tmp_dir.path().join("out");
+
+    let (mut process, receiver) = spawn(&[
+        source_path.to_str().unwrap(),
+        "--base-path",
+        tmp_dir.path().to_str().unwrap(),
+        "--output-dir",
+        output_path.to_str().unwrap(),
+        "--overwrite",
+        "--bin",
+        "--watch",
+    ])?;
+
+    let result = wait_for(&receiver, "Waiting for changes").and_then(|()| {
+        match wait_for_within(&receiver, "Changes detected", Duration::from_secs(5)) {
+            Ok(()) => anyhow::bail!("The outputs have triggered a recompilation"),
+            Err(_) => Ok(()),
+        }
+    });
+    process.kill()?;
+    process.wait()?;
+    result
+}
+
+#[test]
+fn imports_watched() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let tmp_dir = TempDir::with_prefix("watch")?;
+    let source_path = tmp_dir.path().join("Main.sol");
+    let library_path = tmp_dir.path().join("Library.sol");
+    std::fs::write(
+        source_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\nimport \"./Library.sol\";\ncontract Main {}\n",
+    )?;
+    std::fs::write(
+        library_path.as_path(),
+        "// SPDX-License-Identifier: MIT\npragma solidity >=0.4.16;\nlibrary Library {}\n",
+    )?;
+
+    let (mut process, receiver) = spawn(&[source_path.to_str().unwrap(), "--bin", "--watch"])?;
+
+    let result = wait_for(&receiver, "Waiting for changes").and_then(|()| {
+        let mut source = std::fs::read_to_string(library_path.as_path())?;
+        source.push_str("\n// Edited.\n");
+        std::fs::write(library_path.as_path(), source)?;
+        wait_for(&receiver, "Changes detected")
+    });
+    process.kill()?;
+    process.wait()?;
+    result
+}
+
+#[test]
+fn disassemble() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        "--disassemble",
+        crate::common::TEST_DISASSEMBLER_BYTECODE_PATH,
+        "--watch",
+    ];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.failure().stderr(predicate::str::contains(
+        "Watch mode is only available in compilation modes.",
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn standard_json_stdin() -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &["--standard-json", "--watch"];
+
+    let result = crate::cli::execute_zksolc(args)?;
+    result.success().stdout(predicate::str::contains(
+        "Watch mode requires the standard JSON input file path.",
+    ));
+
+    Ok(())
+}
+
+///
+/// Spawns the compiler in watch mode with `args`, and returns the process and its `stderr` lines.
+///
+fn spawn(args: &[&str]) -> anyhow::Result<(Child, Receiver<String>)> {
+    let mut process = Command::cargo_bin(era_compiler_solidity::DEFAULT_EXECUTABLE_NAME)?
+        .env(
+            "PATH",
+            std::fs::canonicalize(PathBuf::from(crate::common::SOLC_DOWNLOAD_DIRECTORY))?,
+        )
+        .args(args)
+        .stdout(Stdio::null())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    let stderr = process.stderr.take().expect("Always exists");
+    let (sender, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        for line in std::io::BufReader::new(stderr).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            if sender.send(line).is_err() {
+                break;
+            }
+        }
+    });
+    Ok((process, receiver))
+}
+
+///
+/// Waits for a line containing the `pattern` in the watch mode output.
+///
+fn wait_for(receiver: &Receiver<String>, pattern: &str) -> anyhow::Result<()> {
+    wait_for_within(receiver, pattern, TIMEOUT)
+}
+
+///
+/// Waits for a line containing the `pattern` in the watch mode output for at most `timeout`.
+///
+fn wait_for_within(
+    receiver: &Receiver<String>,
+    pattern: &str,
+    timeout: Duration,
+) -> anyhow::Result<()> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        let line = receiver
+            .recv_timeout(deadline.saturating_duration_since(Instant::now()))
+            .map_err(|error| anyhow::anyhow!("Waiting for `{pattern}`: {error}"))?;
+        if line.contains(pattern) {
+            return Ok(());
+        }
+    }
+}
@@ -177,3 +177,32 @@ fn through_solc_invalid_zksolc_error(target: Target) -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test_case(Target::EraVM)]
+#[test_case(Target::EVM)]
+fn factory_dependency_cycle(target: Target) -> anyhow::Result<()> {
+    crate::common::setup()?;
+
+    let args = &[
+        crate::common::TEST_YUL_CONTRACT_CYCLE_FIRST_PATH,
+        crate::common::TEST_YUL_CONTRACT_CYCLE_SECOND_PATH,
+        "--yul",
+        "--bin",
+    ];
+
+    let result = crate::cli::execute_zksolc_with_target(args, target)?;
+    result
+        .failure()
+        .stderr(predicate::str::contains("Cyclic factory dependency"))
+        .stderr(predicate::str::contains(format!(
+            "    {}:First\n      --> deploys the next contract at {}:12:",
+            crate::common::TEST_YUL_CONTRACT_CYCLE_FIRST_PATH,
+            crate::common::TEST_YUL_CONTRACT_CYCLE_FIRST_PATH,
+        )))
+        .stderr(predicate::str::contains(format!(
+            "    {}:Second",
+            crate::common::TEST_YUL_CONTRACT_CYCLE_SECOND_PATH,
+        )));
+
+    Ok(())
+}
@@ -27,6 +27,10 @@ pub const TEST_SOLIDITY_CONTRACT_NAME: &str = "Test.sol";
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_PATH: &str = "tests/data/contracts/solidity/Test.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_DUPLICATE_NAME_PATH: &str =
+    "tests/data/contracts/solidity/duplicate/Test.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_GREETER_PATH: &str = "tests/data/contracts/solidity/Greeter.sol";
 
@@ -38,6 +42,10 @@ pub const TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH: &str =
     "tests/data/contracts/solidity/caller/Callable.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_REMAPPED_MAIN_PATH: &str =
+    "tests/data/contracts/solidity/remapped/Main.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH: &str =
     "tests/data/contracts/solidity/SimpleContract.sol";
@@ -54,10 +62,60 @@ pub const TEST_SOLIDITY_CONTRACT_LINKER_MIXED_DEPS_MULTI_LEVEL_PATH: &str =
 pub const TEST_SOLIDITY_CONTRACT_OPTIMIZED_PATH: &str =
     "tests/data/contracts/solidity/Optimized.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_RECURSION_PATH: &str =
+    "tests/data/contracts/solidity/Recursion.sol";
+
 /// A test input file.
 pub const TEST_SOLIDITY_CONTRACT_INTERFACE_EMPTY_YUL_PATH: &str =
     "tests/data/contracts/solidity/InterfaceEmptyYul.sol";
 
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_INHERITANCE_PATH: &str =
+    "tests/data/contracts/solidity/Inheritance.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_COMPATIBILITY_PATH: &str =
+    "tests/data/contracts/solidity/Compatibility.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_REVERT_DECODER_PATH: &str =
+    "tests/data/contracts/solidity/RevertDecoder.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_TX_ORIGIN_PATH: &str =
+    "tests/data/contracts/solidity/TxOrigin.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_BLOCK_RANDOMNESS_PATH: &str =
+    "tests/data/contracts/solidity/BlockRandomness.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_ASSEMBLY_INIT_CODE_PATH: &str =
+    "tests/data/contracts/solidity/AssemblyInitCode.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_CREATION_CODE_PATH: &str =
+    "tests/data/contracts/solidity/CreationCode.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_PRECOMPILE_CALL_PATH: &str =
+    "tests/data/contracts/solidity/PrecompileCall.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_ABI_CODER_V1_PATH: &str =
+    "tests/data/contracts/solidity/AbiCoderV1.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_SELFDESTRUCT_PATH: &str =
+    "tests/data/contracts/solidity/SelfDestruct.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_BALANCE_PATH: &str = "tests/data/contracts/solidity/Balance.sol";
+
+/// A test input file.
+pub const TEST_SOLIDITY_CONTRACT_MCOPY_PATH: &str = "tests/data/contracts/solidity/MCopy.sol";
+
 /// A test input file.
 pub const SOLIDITY_BIN_OUTPUT_NAME_ERAVM: &str = "Test.zbin";
 
@@ -73,6 +131,23 @@ pub const SOLIDITY_ASM_OUTPUT_NAME_EVM: &str = "Test.asm";
 /// A test input file.
 pub const TEST_YUL_CONTRACT_PATH: &str = "tests/data/contracts/yul/Default.yul";
 
+/// A test input file.
+pub const TEST_YUL_CONTRACT_CYCLE_FIRST_PATH: &str = "tests/data/contracts/yul/cycle/First.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_CYCLE_SECOND_PATH: &str = "tests/data/contracts/yul/cycle/Second.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DEPLOYER_PATH: &str = "tests/data/contracts/yul/Deployer.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_SECOND_DEPLOYER_PATH: &str =
+    "tests/data/contracts/yul/SecondDeployer.yul";
+
+/// A test input file.
+pub const TEST_YUL_CONTRACT_DATA_REFERENCE_PATH: &str =
+    "tests/data/contracts/yul/DataReference.yul";
+
 /// A test input file.
 pub const TEST_LLVM_IR_CONTRACT_PATH: &str = "tests/data/contracts/llvm_ir/Test.ll";
 
@@ -105,6 +180,22 @@ pub const TEST_SOLIDITY_STANDARD_JSON_INVALID_UTF8_PATH: &str =
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH: &str =
     "tests/data/standard_json_input/solidity_solc.json";
 
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_AST_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_ast.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_NATSPEC_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_natspec.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_STORAGE_LAYOUT_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_storage_layout.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_TRANSIENT_STORAGE_LAYOUT_PATH: &str =
+    "tests/data/standard_json_input/solidity_solc_transient_storage_layout.json";
+
 /// A test input file.
 pub const TEST_SOLIDITY_STANDARD_JSON_SOLC_EMPTY_SOURCES_PATH: &str =
     "tests/data/standard_json_input/solidity_solc_empty_sources.json";
@@ -132,6 +223,134 @@ pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_FORCE_EVMLA: &str =
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_SOLC_PATH: &str = "tests/data/standard_json_input/yul_solc.json";
 
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_REACHABLE_SELECTORS_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_reachable_selectors.json";
+
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_REACHABLE_SELECTORS_INVALID_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_reachable_selectors_invalid.json";
+
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_IMMUTABLES_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_immutables.json";
+
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_POLICY_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_policy.json";
+
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_POLICY_INVALID_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_policy_invalid.json";
+
+/// A test input file.
+pub const TEST_YUL_STANDARD_JSON_ZKSOLC_CYCLE_PATH: &str =
+    "tests/data/standard_json_input/yul_zksolc_cycle.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_POLICY_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_policy.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LLVM_OPTIONS_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_llvm_options.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SARIF_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_sarif.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SOLC_INPUT_ECHO_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_solc_input_echo.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_TIMINGS_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_output_timings.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PARALLEL_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_parallel.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PARALLEL_DISABLED_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_parallel_disabled.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ONLY_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_only.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_GRANULAR_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_output_selection_granular.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_SUB_SELECTORS_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_output_selection_sub_selectors.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SELECTION_WILDCARD_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_output_selection_wildcard.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_PROFILES_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_profiles.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_PROMOTED_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_severity_promoted.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_DEMOTED_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_severity_demoted.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_STREAM_OUTPUT_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_stream_output.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_SEVERITY_INVALID_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_severity_invalid.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_DETECT_MISSING_LIBRARIES_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_detect_missing_libraries.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_LIBRARY_DEPLOYER_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_library_deployer.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ASSEMBLY_JSON_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_assembly_json.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_EXPLAIN_BYTECODE_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_explain_bytecode.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_DEDUP_REPORT_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_dedup_report.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_BYTECODE_HASH_NONE_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_bytecode_hash_none.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_BYTECODE_HASH_IPFS_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_bytecode_hash_ipfs.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ERAVM_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_eravm.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_STRICT_ERAVM_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_strict_eravm.json";
+
+/// A test input file.
+pub const TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_OUTPUT_SUMMARY_PATH: &str =
+    "tests/data/standard_json_input/solidity_zksolc_output_summary.json";
+
 /// A test input file.
 pub const TEST_YUL_STANDARD_JSON_SOLC_INVALID_PATH: &str =
     "tests/data/standard_json_input/yul_solc_urls_invalid.json";
@@ -184,6 +403,14 @@ pub const TEST_JSON_CONTRACT_PATH_SUPPRESSED_ERRORS_INVALID: &str =
 pub const TEST_JSON_CONTRACT_PATH_SUPPRESSED_WARNINGS_INVALID: &str =
     "tests/data/standard_json_input/suppressed_warnings_invalid.json";
 
+/// A test input file.
+pub const TEST_JSON_CONTRACT_PATH_SUPPRESSIONS: &str =
+    "tests/data/standard_json_input/suppressions.json";
+
+/// A test input file.
+pub const TEST_JSON_CONTRACT_PATH_SUPPRESSIONS_INVALID: &str =
+    "tests/data/standard_json_input/suppressions_invalid.json";
+
 /// A test input file.
 pub const TEST_DISASSEMBLER_BYTECODE_PATH: &str = "tests/data/bytecodes/disassembler.zbin";
 
@@ -220,6 +447,12 @@ pub const TEST_LINKER_STANDARD_JSON_INPUT_LINKER_ERROR_PATH: &str =
 /// The broken input file path.
 pub const TEST_BROKEN_INPUT_PATH: &str = "tests/data/broken.bad";
 
+/// A test input file.
+pub const TEST_SIGNING_KEY_PATH: &str = "tests/data/keys/signing.key";
+
+/// The Ethereum address of the key at `TEST_SIGNING_KEY_PATH`.
+pub const TEST_SIGNING_KEY_ID: &str = "0x2c7536e3605d9c16a7a3d7b1898e529396a65c23";
+
 /// A non-existent path.
 pub const TEST_NON_EXISTENT_PATH: &str = "tests/data/non_existent";
 
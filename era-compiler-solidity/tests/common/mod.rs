@@ -46,6 +46,17 @@ pub fn setup() -> anyhow::Result<()> {
     Ok(())
 }
 
+///
+/// Returns the build configuration of the tests, which compiles each contract in a subprocess,
+/// like the compiler executable does.
+///
+pub fn build_config() -> era_compiler_solidity::BuildConfig {
+    era_compiler_solidity::BuildConfig {
+        subprocesses: true,
+        ..Default::default()
+    }
+}
+
 ///
 /// Downloads the necessary compiler executables.
 ///
@@ -149,6 +160,7 @@ pub fn build_solidity_standard_json(
         solc_codegen,
         &mut solc_output,
         &solc_compiler,
+        &build_config(),
         None,
     )?;
     solc_output.check_errors()?;
@@ -161,6 +173,8 @@ pub fn build_solidity_standard_json(
         vec![],
         false,
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
@@ -206,6 +220,7 @@ pub fn build_solidity_combined_json(
         solc_codegen,
         &mut solc_output,
         &solc_compiler,
+        &build_config(),
         None,
     )?;
     solc_output.check_errors()?;
@@ -218,12 +233,14 @@ pub fn build_solidity_combined_json(
         vec![],
         selectors.contains(&era_solc::CombinedJsonSelector::Assembly),
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
     let mut combined_json =
         solc_compiler.combined_json(paths.as_slice(), selectors.into_iter().collect())?;
-    build.write_to_combined_json(&mut combined_json)?;
+    build.write_to_combined_json(&mut combined_json, None)?;
     Ok(combined_json)
 }
 
@@ -272,6 +289,7 @@ pub fn build_solidity_and_detect_missing_libraries(
         solc_codegen,
         &mut solc_output,
         &solc_compiler,
+        &build_config(),
         None,
     )?;
 
@@ -306,6 +324,7 @@ pub fn build_yul(
         era_solc::StandardJsonInputLibraries::default(),
         Some(&mut solc_output),
         None,
+        &build_config(),
         None,
     )?;
     let build = project.compile_to_eravm(
@@ -316,6 +335,8 @@ pub fn build_yul(
         vec![],
         false,
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
@@ -361,6 +382,7 @@ pub fn build_yul_standard_json(
         era_solc::StandardJsonInputLibraries::default(),
         Some(&mut solc_output),
         solc_version,
+        &build_config(),
         None,
     )?;
     let build = project.compile_to_eravm(
@@ -371,6 +393,8 @@ pub fn build_yul_standard_json(
         vec![],
         false,
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
@@ -410,6 +434,8 @@ pub fn build_llvm_ir_standard_json(
         vec![],
         false,
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
@@ -445,6 +471,8 @@ pub fn build_eravm_assembly_standard_json(
         vec![],
         false,
         None,
+        &build_config(),
+        None,
     )?;
     build.check_errors()?;
 
@@ -468,6 +496,32 @@ pub fn check_solidity_message(
     suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
     suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
 ) -> anyhow::Result<bool> {
+    let messages = get_solidity_messages(
+        source_code,
+        libraries,
+        solc_version,
+        solc_codegen,
+        suppressed_errors,
+        suppressed_warnings,
+    )?;
+    let contains_warning = messages
+        .iter()
+        .any(|error| error.formatted_message.contains(warning_substring));
+
+    Ok(contains_warning)
+}
+
+///
+/// Returns the errors and warnings of the built Solidity project.
+///
+pub fn get_solidity_messages(
+    source_code: &str,
+    libraries: era_solc::StandardJsonInputLibraries,
+    solc_version: &semver::Version,
+    solc_codegen: era_solc::StandardJsonInputCodegen,
+    suppressed_errors: Vec<era_solc::StandardJsonInputErrorType>,
+    suppressed_warnings: Vec<era_solc::StandardJsonInputWarningType>,
+) -> anyhow::Result<Vec<era_solc::StandardJsonOutputError>> {
     self::setup()?;
 
     let solc_compiler = get_solc_compiler(solc_version)?;
@@ -497,10 +551,5 @@ pub fn check_solidity_message(
 
     let solc_output =
         solc_compiler.standard_json(&mut solc_input, &mut vec![], None, vec![], None)?;
-    let contains_warning = solc_output
-        .errors
-        .iter()
-        .any(|error| error.formatted_message.contains(warning_substring));
-
-    Ok(contains_warning)
+    Ok(solc_output.errors)
 }
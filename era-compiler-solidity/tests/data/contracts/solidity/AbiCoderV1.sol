@@ -0,0 +1,10 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+pragma abicoder v1;
+
+contract AbiCoderV1 {
+    function identity(uint8 value) external pure returns (uint8) {
+        return value;
+    }
+}
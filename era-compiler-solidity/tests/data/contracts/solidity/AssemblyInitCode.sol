@@ -0,0 +1,14 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract AssemblyInitCode {
+    function deploy(bytes32 code, uint256 argument) external returns (address result) {
+        assembly {
+            let pointer := mload(0x40)
+            mstore(pointer, code)
+            mstore(add(pointer, 0x20), argument)
+            result := create(0, pointer, 0x40)
+        }
+    }
+}
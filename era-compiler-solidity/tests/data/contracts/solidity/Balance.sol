@@ -0,0 +1,17 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Balance {
+    function own() external view returns (uint256) {
+        return address(this).balance;
+    }
+
+    function of(address account) external view returns (uint256) {
+        return account.balance;
+    }
+
+    function sum(address a, address b) external view returns (uint256) {
+        return a.balance + b.balance;
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract BlockRandomness {
+    function random() external view returns (bytes32) {
+        return blockhash(block.number - 1);
+    }
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Child {}
+
+contract Compatibility {
+    function deploy(bytes32 salt) external returns (address) {
+        return address(new Child{salt: salt}());
+    }
+
+    function codeSize(address target) external view returns (uint256 size) {
+        assembly {
+            size := extcodesize(target)
+        }
+    }
+
+    function remainingGas() external view returns (uint256) {
+        return gasleft();
+    }
+
+    function recentBlock() external view returns (bytes32) {
+        return blockhash(block.number - 1);
+    }
+
+    function signer(bytes32 hash, uint8 v, bytes32 r, bytes32 s) external pure returns (address) {
+        return ecrecover(hash, v, r, s);
+    }
+}
@@ -0,0 +1,11 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract Deployed {}
+
+contract CreationCode {
+    function codeHash() external pure returns (bytes32) {
+        return keccak256(type(Deployed).creationCode);
+    }
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.6.0;
+
+interface IBase {
+    function value() external view returns (uint256);
+}
+
+abstract contract Base is IBase {
+    function value() public view virtual override returns (uint256) {
+        return 1;
+    }
+}
+
+contract Left is Base {
+    function value() public view virtual override returns (uint256) {
+        return 2;
+    }
+}
+
+contract Right is Base {
+    function value() public view virtual override returns (uint256) {
+        return 3;
+    }
+}
+
+contract Diamond is Left, Right {
+    function value() public view override(Left, Right) returns (uint256) {
+        return super.value();
+    }
+}
@@ -0,0 +1,13 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract MCopy {
+    function copy() external pure returns (uint256 result) {
+        assembly {
+            mstore(0, 42)
+            mcopy(32, 0, 32)
+            result := mload(32)
+        }
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract PrecompileCall {
+    function hash(bytes memory input) external view returns (bool success, bytes memory output) {
+        (success, output) = address(0x02).staticcall(input);
+    }
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.4;
+
+error Unauthorized(address caller);
+
+error ZeroAmount();
+
+function checkAmount(uint256 amount) pure {
+    if (amount == 0) {
+        revert ZeroAmount();
+    }
+}
+
+library Balances {
+    error Overdrawn(uint256 balance, uint256 amount);
+
+    function subtract(uint256 balance, uint256 amount) internal pure returns (uint256) {
+        if (amount > balance) {
+            revert Overdrawn(balance, amount);
+        }
+        return balance - amount;
+    }
+}
+
+abstract contract Owned {
+    address internal owner = msg.sender;
+
+    function checkOwner() internal view {
+        if (msg.sender != owner) {
+            revert Unauthorized(msg.sender);
+        }
+    }
+}
+
+contract RevertDecoder is Owned {
+    error InsufficientBalance(uint256 available, uint256 required);
+
+    uint256 public balance;
+
+    function withdraw(uint256 amount) external {
+        checkOwner();
+        checkAmount(amount);
+        if (amount > balance) {
+            revert InsufficientBalance(balance, amount);
+        }
+        balance = Balances.subtract(balance, amount);
+    }
+}
@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract SelfDestruct {
+    function destroy() external {
+        selfdestruct(payable(msg.sender));
+    }
+}
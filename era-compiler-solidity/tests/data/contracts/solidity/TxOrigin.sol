@@ -0,0 +1,9 @@
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.8.0;
+
+contract TxOrigin {
+    function isOrigin() external view returns (bool) {
+        return msg.sender == tx.origin;
+    }
+}
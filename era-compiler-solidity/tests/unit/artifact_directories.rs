@@ -0,0 +1,50 @@
+//!
+//! Unit tests for the per-source artifact directories.
+//!
+
+use std::path::PathBuf;
+
+use era_compiler_solidity::artifact_directories::ArtifactDirectories;
+
+#[test]
+fn relative_path_normal() {
+    assert_eq!(
+        ArtifactDirectories::relative_path("./contracts/Token.sol"),
+        PathBuf::from("contracts/Token.sol")
+    );
+}
+
+#[test]
+fn relative_path_parent() {
+    assert_eq!(
+        ArtifactDirectories::relative_path("../contracts/Token.sol"),
+        PathBuf::from("__parent__/contracts/Token.sol")
+    );
+    assert_ne!(
+        ArtifactDirectories::relative_path("../contracts/Token.sol"),
+        ArtifactDirectories::relative_path("contracts/Token.sol")
+    );
+}
+
+#[cfg(unix)]
+#[test]
+fn relative_path_root() {
+    assert_eq!(
+        ArtifactDirectories::relative_path("/contracts/Token.sol"),
+        PathBuf::from("__root__/contracts/Token.sol")
+    );
+}
+
+#[test]
+fn colliding_file_names() {
+    let directories = ArtifactDirectories::new(["Token.sol", "../Token.sol"]);
+
+    assert_eq!(
+        directories.get("Token.sol"),
+        PathBuf::from("Token.sol").as_path()
+    );
+    assert_eq!(
+        directories.get("../Token.sol"),
+        PathBuf::from("__parent__/Token.sol").as_path()
+    );
+}
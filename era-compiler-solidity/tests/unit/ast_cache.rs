@@ -0,0 +1,50 @@
+//!
+//! Unit tests for the AST check result cache.
+//!
+
+use era_solc::standard_json::output::ast_cache::Message;
+use tempfile::TempDir;
+
+#[test]
+fn message_fields_restored() {
+    let mut warning = era_solc::StandardJsonOutputError::new_warning(
+        "You are checking for 'tx.origin'",
+        Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+            "A.sol".to_owned(),
+        )),
+        None,
+    )
+    .with_exit_code(era_solc::ExitCode::SolcError);
+    warning.warning_type = Some(era_solc::StandardJsonInputWarningType::TxOrigin);
+
+    let entry = serde_json::to_string(&Message::from(warning)).expect("Always valid");
+    let message: Message = serde_json::from_str(entry.as_str()).expect("Always valid");
+    let warning = era_solc::StandardJsonOutputError::from(message);
+
+    assert_eq!(warning.severity, "warning");
+    assert_eq!(warning.exit_code, Some(era_solc::ExitCode::SolcError));
+    assert_eq!(
+        warning.warning_type,
+        Some(era_solc::StandardJsonInputWarningType::TxOrigin)
+    );
+}
+
+#[test]
+fn eviction() -> anyhow::Result<()> {
+    let tmp_dir = TempDir::with_prefix("ast_cache")?;
+    let directory = era_solc::CacheDirectory::new(tmp_dir.path(), 2);
+
+    for key in ["a", "b", "c"] {
+        directory.insert(key, &key);
+        std::thread::sleep(std::time::Duration::from_millis(10));
+    }
+    assert_eq!(directory.get::<String>("a").as_deref(), Some("a"));
+    directory.evict();
+
+    assert_eq!(std::fs::read_dir(tmp_dir.path())?.count(), 2);
+    assert_eq!(directory.get::<String>("a").as_deref(), Some("a"));
+    assert_eq!(directory.get::<String>("b"), None);
+    assert_eq!(directory.get::<String>("c").as_deref(), Some("c"));
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+//!
+//! Unit tests for the metadata-aware bytecode comparison.
+//!
+
+use era_compiler_common::HashType;
+use era_compiler_solidity::BytecodeComparison;
+
+/// The code of the test bytecodes.
+const CODE: &str = "0000008003000039000000400030043f";
+
+/// The metadata trailer with the `solc` version 0.8.23, followed by its length.
+const TRAILER_V0_8_23: &str = "a164736f6c6343000817000a";
+
+/// The metadata trailer with the `solc` version 0.8.24, followed by its length.
+const TRAILER_V0_8_24: &str = "a164736f6c6343000818000a";
+
+/// The word-aligned EraVM code of the test bytecodes.
+const CODE_WORD: &str = "0000008003000039000000400030043f0000000000000000000000000000000a";
+
+/// The zero word padding the EraVM bytecode to an odd number of words.
+const PADDING_WORD: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// The raw `keccak256` metadata hash trailer.
+const KECCAK256_TRAILER: &str = "1c8aff950685c2ed4bc3174f3472287b56d9517b9c948127319a09a7a36deac8";
+
+/// Another raw `keccak256` metadata hash trailer, ending with a zero byte.
+const KECCAK256_TRAILER_OTHER: &str =
+    "b10e2d527612073b26eecdfd717e6a320cf44b4afac2b0732d9fcbe2b7fa0c00";
+
+fn bytecode(parts: &[&str]) -> Vec<u8> {
+    hex::decode(parts.concat()).expect("Always valid")
+}
+
+#[test]
+fn identical() {
+    let left = bytecode(&[CODE, TRAILER_V0_8_23]);
+
+    assert_eq!(
+        BytecodeComparison::new(left.as_slice(), left.as_slice(), HashType::None),
+        BytecodeComparison::Identical
+    );
+}
+
+#[test]
+fn equivalent_modulo_metadata() {
+    let left = bytecode(&[CODE, TRAILER_V0_8_23, "0000000000"]);
+    let right = bytecode(&[CODE, TRAILER_V0_8_24]);
+
+    let comparison = BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::None);
+    assert_eq!(comparison, BytecodeComparison::EquivalentModuloMetadata);
+    assert!(comparison.is_equivalent());
+}
+
+#[test]
+fn different() {
+    let left = bytecode(&[CODE, TRAILER_V0_8_23]);
+    let right = bytecode(&["0000008103000039000000400030043f", TRAILER_V0_8_23]);
+
+    let comparison = BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::None);
+    assert_eq!(comparison, BytecodeComparison::Different { offset: 3 });
+    assert!(!comparison.is_equivalent());
+}
+
+#[test]
+fn different_length() {
+    let left = bytecode(&[CODE, TRAILER_V0_8_23]);
+    let right = bytecode(&[CODE, "00000001", TRAILER_V0_8_24]);
+
+    assert_eq!(
+        BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::None),
+        BytecodeComparison::Different { offset: 16 }
+    );
+}
+
+#[test]
+fn split_metadata() {
+    let bytecode = bytecode(&[CODE, TRAILER_V0_8_23, "000000"]);
+
+    let (code, trailer) = BytecodeComparison::split_metadata(bytecode.as_slice(), HashType::None);
+    assert_eq!(hex::encode(code), CODE);
+    assert_eq!(hex::encode(trailer), format!("{TRAILER_V0_8_23}000000"));
+}
+
+#[test]
+fn split_metadata_missing() {
+    let bytecode = bytecode(&[CODE]);
+
+    let (code, trailer) = BytecodeComparison::split_metadata(bytecode.as_slice(), HashType::None);
+    assert_eq!(hex::encode(code), CODE);
+    assert!(trailer.is_empty());
+}
+
+#[test]
+fn equivalent_modulo_metadata_keccak256() {
+    let left = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER]);
+    let right = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER_OTHER]);
+
+    let comparison =
+        BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::Keccak256);
+    assert_eq!(comparison, BytecodeComparison::EquivalentModuloMetadata);
+    assert!(comparison.is_equivalent());
+}
+
+#[test]
+fn different_keccak256() {
+    let left = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER]);
+    let right = bytecode(&[
+        "0000008103000039000000400030043f0000000000000000000000000000000a",
+        PADDING_WORD,
+        KECCAK256_TRAILER_OTHER,
+    ]);
+
+    assert_eq!(
+        BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::Keccak256),
+        BytecodeComparison::Different { offset: 3 }
+    );
+}
+
+#[test]
+fn different_keccak256_as_code() {
+    let left = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER]);
+    let right = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER_OTHER]);
+
+    assert_eq!(
+        BytecodeComparison::new(left.as_slice(), right.as_slice(), HashType::None),
+        BytecodeComparison::Different { offset: 64 }
+    );
+}
+
+#[test]
+fn split_metadata_keccak256() {
+    let bytecode = bytecode(&[CODE_WORD, PADDING_WORD, KECCAK256_TRAILER_OTHER]);
+
+    let (code, trailer) =
+        BytecodeComparison::split_metadata(bytecode.as_slice(), HashType::Keccak256);
+    assert_eq!(hex::encode(code), format!("{CODE_WORD}{PADDING_WORD}"));
+    assert_eq!(hex::encode(trailer), KECCAK256_TRAILER_OTHER);
+}
@@ -0,0 +1,131 @@
+//!
+//! Unit tests for the bytecode deduplication report.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// Builds the assembly with the `functions` of `size` instructions, where `seed` makes the instructions unique.
+///
+fn assembly(
+    functions: &[&str],
+    size: usize,
+    seed: usize,
+) -> era_solc::StandardJsonOutputContractEraVMAssembly {
+    let mut text = String::from("\t.text\n");
+    for (index, function) in functions.iter().enumerate() {
+        text.push_str(format!("{function}:\n").as_str());
+        for instruction in 0..size {
+            text.push_str(
+                format!(
+                    "\tadd\t{}, r1, r1\n",
+                    seed * 1_000_000 + index * 1_000 + instruction
+                )
+                .as_str(),
+            );
+        }
+        text.push_str(format!("\tjump\t@.BB{seed}_{index}\n").as_str());
+    }
+    era_solc::StandardJsonOutputContractEraVMAssembly::from_text(text.as_str())
+}
+
+#[test]
+fn duplicate_functions() {
+    let mut assemblies = BTreeMap::new();
+    assemblies.insert("A.sol:A".to_owned(), assembly(&["fun_a_1"], 16, 0));
+    assemblies.insert("B.sol:B".to_owned(), assembly(&["fun_b_2"], 16, 0));
+
+    let report = era_compiler_solidity::DedupReport::new(&assemblies);
+
+    assert_eq!(report.functions.len(), 1);
+    let functions = &report.functions[0];
+    assert_eq!(
+        functions.size,
+        17 * era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE
+    );
+    assert_eq!(functions.estimated_savings, functions.size);
+    assert_eq!(functions.occurrences.len(), 2);
+    assert_eq!(functions.occurrences[0].contract, "A.sol:A");
+    assert_eq!(functions.occurrences[0].function, "fun_a_1");
+    assert_eq!(functions.occurrences[1].contract, "B.sol:B");
+    assert_eq!(functions.occurrences[1].function, "fun_b_2");
+    assert_eq!(report.estimated_savings, functions.size);
+}
+
+#[test]
+fn short_functions() {
+    let size = era_compiler_solidity::dedup_report::MIN_FUNCTION_SIZE - 2;
+
+    let mut assemblies = BTreeMap::new();
+    assemblies.insert("A.sol:A".to_owned(), assembly(&["fun_a_1"], size, 0));
+    assemblies.insert("B.sol:B".to_owned(), assembly(&["fun_b_2"], size, 0));
+
+    let report = era_compiler_solidity::DedupReport::new(&assemblies);
+
+    assert!(report.functions.is_empty());
+    assert_eq!(report.estimated_savings, 0);
+}
+
+#[test]
+fn similar_contracts() {
+    let mut assemblies = BTreeMap::new();
+    assemblies.insert(
+        "A.sol:A".to_owned(),
+        assembly(&["fun_a_1", "fun_a_2"], 32, 0),
+    );
+    assemblies.insert(
+        "B.sol:B".to_owned(),
+        assembly(&["fun_b_3", "fun_b_4"], 32, 0),
+    );
+    assemblies.insert(
+        "C.sol:C".to_owned(),
+        assembly(&["fun_c_5", "fun_c_6"], 32, 1),
+    );
+
+    let report = era_compiler_solidity::DedupReport::new(&assemblies);
+
+    assert_eq!(report.contracts.len(), 1);
+    let contracts = &report.contracts[0];
+    assert_eq!(
+        contracts.contracts,
+        ["A.sol:A".to_owned(), "B.sol:B".to_owned()]
+    );
+    assert_eq!(contracts.similarity, 1.0);
+    assert_eq!(
+        contracts.estimated_savings,
+        66 * era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE
+    );
+}
+
+#[test]
+fn similar_contracts_partially() {
+    let mut assemblies = BTreeMap::new();
+    assemblies.insert(
+        "A.sol:A".to_owned(),
+        assembly(&["fun_a_1", "fun_a_2"], 32, 0),
+    );
+    assemblies.insert(
+        "B.sol:B".to_owned(),
+        assembly(&["fun_b_3", "fun_b_4", "fun_b_5"], 32, 0),
+    );
+
+    let report = era_compiler_solidity::DedupReport::new(&assemblies);
+
+    assert!(report.contracts.is_empty());
+}
+
+#[test]
+fn different_contracts() {
+    let mut assemblies = BTreeMap::new();
+    for index in 0..16 {
+        assemblies.insert(
+            format!("C{index}.sol:C{index}"),
+            assembly(&["fun_main_1"], 32, index),
+        );
+    }
+
+    let report = era_compiler_solidity::DedupReport::new(&assemblies);
+
+    assert!(report.functions.is_empty());
+    assert!(report.contracts.is_empty());
+}
@@ -0,0 +1,91 @@
+//!
+//! Unit tests for the factory dependency cycle detection.
+//!
+
+use std::collections::BTreeMap;
+
+use era_compiler_solidity::dependency_cycle::Edge;
+
+fn dependencies(edges: &[(&str, &str)]) -> BTreeMap<String, Vec<Edge>> {
+    let mut dependencies: BTreeMap<String, Vec<Edge>> = BTreeMap::new();
+    for (path, dependency) in edges.iter() {
+        dependencies
+            .entry((*path).to_owned())
+            .or_default()
+            .push(((*dependency).to_owned(), Some(format!("{path}.sol:1:1"))));
+    }
+    dependencies
+}
+
+fn paths(cycles: Vec<Vec<Edge>>) -> Vec<Vec<String>> {
+    cycles
+        .into_iter()
+        .map(|cycle| cycle.into_iter().map(|(path, _)| path).collect())
+        .collect()
+}
+
+#[test]
+fn none() {
+    let dependencies = dependencies(&[("A", "B"), ("B", "C"), ("A", "C")]);
+
+    assert!(era_compiler_solidity::dependency_cycle::find(&dependencies).is_empty());
+}
+
+#[test]
+fn self_reference() {
+    let dependencies = dependencies(&[("A", "A")]);
+
+    assert_eq!(
+        era_compiler_solidity::dependency_cycle::find(&dependencies),
+        vec![vec![("A".to_owned(), Some("A.sol:1:1".to_owned()))]]
+    );
+}
+
+#[test]
+fn rotated_to_smallest() {
+    let dependencies = dependencies(&[("C", "A"), ("A", "B"), ("B", "C")]);
+
+    assert_eq!(
+        era_compiler_solidity::dependency_cycle::find(&dependencies),
+        vec![vec![
+            ("A".to_owned(), Some("A.sol:1:1".to_owned())),
+            ("B".to_owned(), Some("B.sol:1:1".to_owned())),
+            ("C".to_owned(), Some("C.sol:1:1".to_owned())),
+        ]]
+    );
+}
+
+#[test]
+fn shared_contracts() {
+    let dependencies = dependencies(&[("A", "B"), ("A", "C"), ("B", "C"), ("C", "A")]);
+
+    assert_eq!(
+        paths(era_compiler_solidity::dependency_cycle::find(&dependencies)),
+        vec![vec!["A", "B", "C"], vec!["A", "C"]]
+    );
+}
+
+#[test]
+fn all_elementary() {
+    let dependencies = dependencies(&[
+        ("A", "B"),
+        ("B", "A"),
+        ("B", "C"),
+        ("C", "B"),
+        ("C", "A"),
+        ("A", "C"),
+        ("D", "D"),
+    ]);
+
+    assert_eq!(
+        paths(era_compiler_solidity::dependency_cycle::find(&dependencies)),
+        vec![
+            vec!["A", "B"],
+            vec!["A", "B", "C"],
+            vec!["A", "C"],
+            vec!["A", "C", "B"],
+            vec!["B", "C"],
+            vec!["D"],
+        ]
+    );
+}
@@ -0,0 +1,103 @@
+//!
+//! Unit tests for the EraVM bytecode disassembly.
+//!
+
+///
+/// Creates the EraVM target machine for disassembling.
+///
+fn target_machine() -> era_compiler_llvm_context::TargetMachine {
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+    era_compiler_llvm_context::TargetMachine::new(
+        era_compiler_common::Target::EraVM,
+        &era_compiler_llvm_context::OptimizerSettings::cycles(),
+        &[],
+    )
+    .expect("Target machine creation failure")
+}
+
+#[test]
+fn default() {
+    crate::common::setup().expect("Setup failure");
+    let target_machine = target_machine();
+
+    let bytecode = era_compiler_solidity::Disassembly::read_bytecode(
+        crate::common::TEST_DISASSEMBLER_BYTECODE_PATH,
+    )
+    .expect("Bytecode reading failure");
+    let disassembly =
+        era_compiler_solidity::Disassembly::try_from_bytecode(&target_machine, bytecode.as_slice())
+            .expect("Disassembly failure");
+    let assembly = disassembly.assembly();
+
+    assert!(!assembly.instructions.is_empty());
+    assert!(assembly
+        .instructions
+        .windows(2)
+        .all(|pair| pair[0].offset < pair[1].offset));
+}
+
+#[test]
+fn hexadecimal_string() {
+    crate::common::setup().expect("Setup failure");
+    let target_machine = target_machine();
+
+    let bytecode =
+        era_compiler_solidity::Disassembly::read_bytecode("0x0000008003000039000000400030043f")
+            .expect("Bytecode reading failure");
+    let disassembly =
+        era_compiler_solidity::Disassembly::try_from_bytecode(&target_machine, bytecode.as_slice())
+            .expect("Disassembly failure");
+    let assembly = disassembly.assembly();
+
+    assert_eq!(assembly.instructions.len(), 2);
+    assert_eq!(assembly.instructions[0].mnemonic, "add");
+    assert_eq!(assembly.instructions[1].offset, 8);
+    assert_eq!(assembly.instructions[1].mnemonic, "stm.h");
+}
+
+#[test]
+fn assembly() {
+    let disassembly = era_compiler_solidity::Disassembly {
+        text: r#"
+__entry:
+       0: 00 00 00 80 03 00 00 39      add     128, r0, r3  ; comment
+.BB0_1:
+      10: 00 00 00 40 00 30 04 3f      stm.h   64, r3
+"#
+        .to_owned(),
+    };
+    let assembly = disassembly.assembly();
+
+    assert_eq!(assembly.instructions.len(), 2);
+    assert_eq!(assembly.instructions[0].offset, 0);
+    assert_eq!(assembly.instructions[0].mnemonic, "add");
+    assert_eq!(
+        assembly.instructions[0].operands,
+        vec!["128".to_owned(), "r0".to_owned(), "r3".to_owned()]
+    );
+    assert_eq!(
+        assembly.instructions[0].function.as_deref(),
+        Some("__entry")
+    );
+    assert_eq!(assembly.instructions[1].offset, 0x10);
+    assert_eq!(assembly.instructions[1].mnemonic, "stm.h");
+    assert_eq!(assembly.labels.get(".BB0_1"), Some(&1));
+}
+
+#[test]
+fn missing_file() {
+    let result = era_compiler_solidity::Disassembly::read_bytecode("anyarg");
+    assert!(result
+        .expect_err("Always fails")
+        .to_string()
+        .contains("File \"anyarg\" reading"));
+}
+
+#[test]
+fn invalid_hexadecimal_string() {
+    let result = era_compiler_solidity::Disassembly::read_bytecode("0xZZ");
+    assert!(result
+        .expect_err("Always fails")
+        .to_string()
+        .contains("Hexadecimal bytecode string `0xZZ` decoding"));
+}
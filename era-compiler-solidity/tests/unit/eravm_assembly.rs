@@ -0,0 +1,61 @@
+//!
+//! Unit tests for the EraVM assembly JSON representation.
+//!
+
+#[test]
+fn from_text() {
+    let assembly = std::fs::read_to_string(crate::common::TEST_ERAVM_ASSEMBLY_CONTRACT_PATH)
+        .expect("Test file reading error");
+    let assembly = era_solc::StandardJsonOutputContractEraVMAssembly::from_text(assembly.as_str());
+
+    let first = assembly
+        .instructions
+        .first()
+        .expect("The instructions are missing");
+    assert_eq!(first.offset, 0);
+    assert_eq!(first.mnemonic, "sub.s!");
+    assert_eq!(first.operands, vec!["0", "r2", "r1"]);
+    assert_eq!(first.function.as_deref(), Some("__entry"));
+
+    let index = *assembly
+        .labels
+        .get(".BB0_2")
+        .expect("The `.BB0_2` label is missing");
+    let instruction = &assembly.instructions[index];
+    assert_eq!(
+        instruction.offset,
+        index * era_solc::StandardJsonOutputContractEraVMAssembly::INSTRUCTION_SIZE
+    );
+    assert_eq!(instruction.mnemonic, "add");
+    assert_eq!(instruction.operands, vec!["42", "r0", "r1"]);
+
+    assert_eq!(assembly.constants.len(), 2);
+    assert_eq!(assembly.constants[0].label, "CPI0_0");
+    assert_eq!(
+        assembly.constants[0].value,
+        "2535301200456458802993406410752"
+    );
+}
+
+#[test]
+fn source_locations() {
+    let assembly = era_solc::StandardJsonOutputContractEraVMAssembly::from_text(
+        r#"
+        .text
+        .file   1 "Test.sol"
+__entry:
+        .loc    1 5 9
+        add     42, r0, r1
+        retl    r1, @DEFAULT_FAR_RETURN
+"#,
+    );
+
+    let location = assembly.instructions[0]
+        .source_location
+        .as_ref()
+        .expect("The source location is missing");
+    assert_eq!(location.file, "Test.sol");
+    assert_eq!(location.line, 5);
+    assert_eq!(location.column, 9);
+    assert_eq!(assembly.instructions[1].offset, 8);
+}
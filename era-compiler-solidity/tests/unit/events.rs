@@ -0,0 +1,98 @@
+//!
+//! Unit tests for the compiler events.
+//!
+
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use era_solc::CollectableError;
+
+/// The path of the test source.
+const SOURCE_PATH: &str = "events.sol";
+
+///
+/// The subscriber collecting the events of the test source.
+///
+#[derive(Default)]
+struct Collector {
+    /// The collected event names.
+    events: Mutex<Vec<&'static str>>,
+}
+
+impl era_compiler_solidity::EventSubscriber for Collector {
+    fn on_event(&self, event: &era_compiler_solidity::Event) {
+        let name = match event {
+            era_compiler_solidity::Event::ContractStarted { path, .. }
+                if path.starts_with(SOURCE_PATH) =>
+            {
+                "ContractStarted"
+            }
+            era_compiler_solidity::Event::BytecodeEmitted { path, size }
+                if path.starts_with(SOURCE_PATH) && *size > 0 =>
+            {
+                "BytecodeEmitted"
+            }
+            _ => return,
+        };
+        self.events.lock().expect("Sync").push(name);
+    }
+}
+
+///
+/// Compiles the test source with the build configuration `config`.
+///
+fn compile(config: era_compiler_solidity::BuildConfig) {
+    crate::common::setup().expect("Test setup failure");
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+    let solc_input = serde_json::json!({
+        "language": "Solidity",
+        "sources": {
+            SOURCE_PATH: {
+                "content": "contract Events { function f() public pure returns (uint256) { return 42; } }",
+            },
+        },
+        "settings": {
+            "outputSelection": { "*": { "*": ["evm.bytecode"] } },
+        },
+    });
+    let solc_input = era_solc::StandardJsonInput::try_from_str(solc_input.to_string().as_str())
+        .expect("Standard JSON parsing error");
+    let solc_output = era_compiler_solidity::standard_json::compile(
+        solc_input,
+        Some(&solc_compiler),
+        era_compiler_solidity::standard_json::Options {
+            build: config,
+            ..Default::default()
+        },
+        &mut vec![],
+    )
+    .expect("Build failure");
+    solc_output.check_errors().expect("Build failure");
+}
+
+#[test]
+fn contract_events() {
+    let collector = Arc::new(Collector::default());
+    let mut config = crate::common::build_config();
+    config.subscribers.subscribe(collector.clone());
+    compile(config);
+
+    assert_eq!(
+        collector.events.lock().expect("Sync").as_slice(),
+        &["ContractStarted", "BytecodeEmitted"]
+    );
+}
+
+#[test]
+fn other_build_events() {
+    let collector = Arc::new(Collector::default());
+    let mut config = crate::common::build_config();
+    config.subscribers.subscribe(collector.clone());
+    compile(crate::common::build_config());
+
+    assert!(collector.events.lock().expect("Sync").is_empty());
+}
@@ -0,0 +1,29 @@
+//!
+//! Unit tests for the EVM bytecode opcodes.
+//!
+
+use era_compiler_solidity::build_evm::opcodes::opcodes;
+
+#[test]
+fn default() {
+    let bytecode = hex::decode("6080604052348015600e575f80fd5b50").expect("Always valid");
+
+    assert_eq!(
+        opcodes(bytecode.as_slice()),
+        "PUSH1 0x80 PUSH1 0x40 MSTORE CALLVALUE DUP1 ISZERO PUSH1 0x0E JUMPI PUSH0 DUP1 REVERT JUMPDEST POP"
+    );
+}
+
+#[test]
+fn push_truncated() {
+    let bytecode = hex::decode("61ff").expect("Always valid");
+
+    assert_eq!(opcodes(bytecode.as_slice()), "PUSH2 0xFF");
+}
+
+#[test]
+fn unassigned() {
+    let bytecode = hex::decode("ef00fe").expect("Always valid");
+
+    assert_eq!(opcodes(bytecode.as_slice()), "0xEF STOP INVALID");
+}
@@ -0,0 +1,185 @@
+//!
+//! Unit tests for the profile-guided code layout.
+//!
+
+use std::collections::BTreeMap;
+
+use era_yul::yul::lexer::Lexer;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use era_compiler_solidity::yul::parser::dialect::era::EraDialect;
+
+const SOURCE: &str = r#"
+object "Test" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                switch shr(224, calldataload(0))
+                case 0x01 { fail() }
+                case 0x02 { return(0, 0) }
+                case 0x03 { mstore(0, hot(1)) }
+                case 0x04 { let value := warm(1) mstore(0, value) }
+                case 0x05 { if hot(0) { fail() } }
+                default { fail() }
+            }
+            function fail()
+            {
+                revert(0, 0)
+            }
+            function hot(value) -> result
+            {
+                result := add(value, 1)
+            }
+            function unprofiled(value) -> result
+            {
+                for { let i := 0 } lt(i, value) { i := add(i, 1) }
+                {
+                    switch i
+                    case 0 { fail() }
+                    case 1 { result := hot(i) }
+                }
+            }
+            function warm(value) -> result
+            {
+                result := mul(value, 2)
+            }
+        }
+    }
+}
+"#;
+
+fn apply(call_counts: &[(&str, u64)]) -> Object<EraDialect> {
+    let mut object = Object::<EraDialect>::parse(&mut Lexer::new(SOURCE.to_owned()), None)
+        .expect("Always valid");
+    let call_counts: BTreeMap<String, u64> = call_counts
+        .iter()
+        .map(|(name, call_count)| ((*name).to_owned(), *call_count))
+        .collect();
+    era_compiler_solidity::execution_profile::apply_to_object(&mut object, &call_counts);
+    object
+}
+
+fn runtime_block(object: &Object<EraDialect>) -> &Block<EraDialect> {
+    &object
+        .inner_object
+        .as_deref()
+        .expect("Always exists")
+        .code
+        .block
+}
+
+fn functions(block: &Block<EraDialect>) -> Vec<(String, bool)> {
+    block
+        .statements
+        .iter()
+        .filter_map(|statement| match statement {
+            Statement::FunctionDefinition(function) => Some((
+                function.identifier.to_owned(),
+                function
+                    .attributes
+                    .contains(&era_compiler_llvm_context::Attribute::Cold),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn switch_cases(block: &Block<EraDialect>, cases: &mut Vec<Vec<String>>) {
+    for statement in block.statements.iter() {
+        match statement {
+            Statement::Block(block) => switch_cases(block, cases),
+            Statement::FunctionDefinition(function) => switch_cases(&function.body, cases),
+            Statement::ForLoop(for_loop) => switch_cases(&for_loop.body, cases),
+            Statement::Switch(switch) => {
+                cases.push(
+                    switch
+                        .cases
+                        .iter()
+                        .map(|case| case.literal.inner.to_string())
+                        .collect(),
+                );
+            }
+            _ => {}
+        }
+    }
+}
+
+fn runtime_switch_cases(object: &Object<EraDialect>) -> Vec<Vec<String>> {
+    let mut cases = Vec::new();
+    switch_cases(runtime_block(object), &mut cases);
+    cases
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|value| (*value).to_owned()).collect()
+}
+
+#[test]
+fn functions_layout() {
+    let object = apply(&[("fail", 0), ("hot", 1000), ("warm", 10)]);
+
+    assert_eq!(
+        functions(runtime_block(&object)),
+        vec![
+            ("hot".to_owned(), false),
+            ("warm".to_owned(), false),
+            ("unprofiled".to_owned(), false),
+            ("fail".to_owned(), true),
+        ]
+    );
+}
+
+#[test]
+fn switch_cases_layout() {
+    let object = apply(&[("fail", 0), ("hot", 1000), ("warm", 10)]);
+
+    assert_eq!(
+        runtime_switch_cases(&object),
+        vec![
+            strings(&["0x03", "0x05", "0x04", "0x02", "0x01"]),
+            strings(&["1", "0"]),
+        ]
+    );
+}
+
+#[test]
+fn switch_cases_warm_first() {
+    let object = apply(&[("hot", 10), ("warm", 1000)]);
+
+    assert_eq!(
+        runtime_switch_cases(&object),
+        vec![
+            strings(&["0x04", "0x03", "0x05", "0x01", "0x02"]),
+            strings(&["1", "0"]),
+        ]
+    );
+}
+
+#[test]
+fn empty() {
+    let object = apply(&[]);
+
+    assert_eq!(
+        functions(runtime_block(&object)),
+        vec![
+            ("fail".to_owned(), false),
+            ("hot".to_owned(), false),
+            ("unprofiled".to_owned(), false),
+            ("warm".to_owned(), false),
+        ]
+    );
+    assert_eq!(
+        runtime_switch_cases(&object),
+        vec![
+            strings(&["0x01", "0x02", "0x03", "0x04", "0x05"]),
+            strings(&["0", "1"]),
+        ]
+    );
+}
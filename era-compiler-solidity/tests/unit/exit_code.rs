@@ -0,0 +1,60 @@
+//!
+//! Unit tests for the process exit codes.
+//!
+
+use anyhow::Context;
+use era_solc::WithExitCode;
+
+#[test]
+fn internal_error_outranks_build_timeout() {
+    let exit_codes = [
+        era_solc::ExitCode::InputError,
+        era_solc::ExitCode::InternalError,
+        era_solc::ExitCode::BuildTimeout,
+        era_solc::ExitCode::ResourceLimit,
+    ];
+
+    assert_eq!(
+        exit_codes.into_iter().max(),
+        Some(era_solc::ExitCode::InternalError)
+    );
+}
+
+#[test]
+fn with_exit_code_keeps_causes() {
+    let error = Err::<(), anyhow::Error>(anyhow::anyhow!("No such file or directory"))
+        .context("File \"A.sol\" reading")
+        .with_exit_code(era_solc::ExitCode::InputError)
+        .expect_err("Always fails");
+
+    assert_eq!(
+        era_solc::ExitCode::from(&error),
+        era_solc::ExitCode::InputError
+    );
+    assert_eq!(
+        error
+            .chain()
+            .map(|cause| cause.to_string())
+            .collect::<Vec<String>>(),
+        vec![
+            "File \"A.sol\" reading".to_owned(),
+            "No such file or directory".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn with_exit_code_keeps_classified() {
+    let error = Err::<(), anyhow::Error>(
+        era_solc::ExitCodeError::new(era_solc::ExitCode::SolcError, "Parser error".to_owned())
+            .into(),
+    )
+    .with_exit_code(era_solc::ExitCode::InputError)
+    .expect_err("Always fails");
+
+    assert_eq!(
+        era_solc::ExitCode::from(&error),
+        era_solc::ExitCode::SolcError
+    );
+    assert_eq!(error.to_string(), "Parser error");
+}
@@ -2,6 +2,7 @@
 //! Unit tests for factory dependencies.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
 
 use test_case::test_case;
@@ -76,3 +77,142 @@ fn default(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen
         "Expected 0 factory dependencies"
     );
 }
+
+pub const CREATION_CODE_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract Child {}
+
+contract Base {
+    function code() public pure returns (bytes memory) {
+        return type(Child).creationCode;
+    }
+}
+
+contract Derived is Base {}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn creation_code(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    let mut sources = BTreeMap::new();
+    sources.insert("test.sol".to_owned(), CREATION_CODE_TEST_SOURCE.to_owned());
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::Ipfs,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Build failure");
+
+    let file = output.contracts.get("test.sol").expect("Missing file");
+    for name in ["Base", "Derived"] {
+        assert!(
+            file.get(name)
+                .expect("Missing contract")
+                .factory_dependencies
+                .values()
+                .any(|path| path == "test.sol:Child"),
+            "Expected `Child` in the factory dependencies of `{name}`"
+        );
+    }
+}
+
+#[test]
+fn deployment_payload() {
+    let sources = crate::common::read_sources(&[
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH,
+        crate::common::TEST_SOLIDITY_CONTRACT_CALLER_CALLABLE_PATH,
+    ]);
+
+    let output = crate::common::build_solidity_standard_json(
+        sources,
+        era_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::Ipfs,
+        BTreeSet::new(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        era_compiler_llvm_context::OptimizerSettings::cycles(),
+    )
+    .expect("Build failure");
+
+    let deployment_payload = output
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_CALLER_MAIN_PATH)
+        .expect("Missing file")
+        .get("Main")
+        .expect("Missing contract")
+        .eravm
+        .as_ref()
+        .expect("Missing EraVM data")
+        .deployment_payload
+        .clone()
+        .expect("Missing deployment payload");
+    assert_eq!(
+        deployment_payload.factory_dependencies, 1,
+        "Expected 1 unique factory dependency"
+    );
+    assert_eq!(
+        deployment_payload.deduplicated_size, 0,
+        "Expected no duplicate factory dependencies"
+    );
+}
+
+#[test]
+fn deduplicate_evm() {
+    let mut dependency_data =
+        era_compiler_solidity::process::input_evm::dependency_data::DependencyData::new(
+            None,
+            BTreeMap::new(),
+        );
+    for (path, name, identifier, runtime_build) in [
+        ("A.sol", "Proxy", "A_Proxy_1", vec![0x01; 32]),
+        ("B.sol", "Proxy", "B_Proxy_2", vec![0x01; 32]),
+        ("C.sol", "Other", "C_Other_3", vec![0x02; 32]),
+    ] {
+        let dependency = era_compiler_solidity::EVMContractBuild::new(
+            era_compiler_common::ContractName::new(path.to_owned(), Some(name.to_owned())),
+            identifier.to_owned(),
+            vec![0x00; 16],
+            runtime_build,
+            None,
+            serde_json::Value::Null,
+        );
+        dependency_data
+            .dependencies
+            .insert(identifier.to_owned(), dependency);
+    }
+
+    let (identifiers, deduplicated_size) = dependency_data.deduplicate();
+    assert_eq!(
+        identifiers,
+        BTreeMap::from([("B_Proxy_2".to_owned(), "A_Proxy_1".to_owned())]),
+        "The duplicate must be redirected to the first dependency"
+    );
+    assert_eq!(deduplicated_size, 48);
+    assert_eq!(
+        dependency_data
+            .dependencies
+            .keys()
+            .map(|key| key.as_str())
+            .collect::<Vec<&str>>(),
+        vec!["A_Proxy_1", "C_Other_3"],
+        "The duplicate must not be embedded"
+    );
+}
@@ -0,0 +1,157 @@
+//!
+//! Unit tests for the compile-time immutable value propagation.
+//!
+
+use std::collections::BTreeMap;
+
+use era_yul::yul::lexer::token::lexeme::literal::Literal as LexicalLiteral;
+use era_yul::yul::lexer::Lexer;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::expression::Expression;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use era_compiler_solidity::yul::parser::dialect::era::EraDialect;
+
+const SOURCE: &str = r#"
+object "Test" {
+    code {
+        {
+            setimmutable(0, "7", 0x01)
+            return(0, 0)
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                mstore(0, loadimmutable("7"))
+                mstore(32, add(loadimmutable("8"), 1))
+                return(0, 64)
+            }
+        }
+    }
+}
+"#;
+
+const VALUE: &str = "0x000000000000000000000000000000000000cafe";
+
+fn apply(values: &[(&str, &str)]) -> Object<EraDialect> {
+    let mut object = Object::<EraDialect>::parse(&mut Lexer::new(SOURCE.to_owned()), None)
+        .expect("Always valid");
+    let values: BTreeMap<String, String> = values
+        .iter()
+        .map(|(key, value)| ((*key).to_owned(), (*value).to_owned()))
+        .collect();
+    era_compiler_solidity::immutables::apply_to_object(&mut object, &values);
+    object
+}
+
+fn render(expression: &Expression) -> String {
+    match expression {
+        Expression::FunctionCall(call) => format!(
+            "{:?}({})",
+            call.name,
+            call.arguments
+                .iter()
+                .map(render)
+                .collect::<Vec<String>>()
+                .join(", ")
+        ),
+        Expression::Identifier(identifier) => identifier.inner.to_owned(),
+        Expression::Literal(literal) => match literal.inner {
+            LexicalLiteral::String(ref inner) => format!("\"{inner}\""),
+            ref inner => inner.to_string(),
+        },
+    }
+}
+
+fn statements(block: &Block<EraDialect>) -> Vec<String> {
+    block
+        .statements
+        .iter()
+        .flat_map(|statement| match statement {
+            Statement::Block(block) => statements(block),
+            Statement::Expression(expression) => vec![render(expression)],
+            _ => vec![],
+        })
+        .collect()
+}
+
+fn runtime_statements(object: &Object<EraDialect>) -> Vec<String> {
+    statements(
+        &object
+            .inner_object
+            .as_deref()
+            .expect("Always exists")
+            .code
+            .block,
+    )
+}
+
+#[test]
+fn default() {
+    let object = apply(&[("7", VALUE)]);
+
+    assert_eq!(
+        runtime_statements(&object),
+        vec![
+            format!("MStore(0, {VALUE})"),
+            "MStore(32, Add(LoadImmutable(\"8\"), 1))".to_owned(),
+            "Return(0, 64)".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn deploy_code_unchanged() {
+    let object = apply(&[("7", VALUE)]);
+
+    assert_eq!(
+        statements(&object.code.block),
+        vec![
+            "SetImmutable(0, \"7\", 0x01)".to_owned(),
+            "Return(0, 0)".to_owned(),
+        ]
+    );
+}
+
+#[test]
+fn normalize() {
+    let values = BTreeMap::from([
+        ("7".to_owned(), "0xCAFE".to_owned()),
+        ("8".to_owned(), "beef".to_owned()),
+    ]);
+
+    assert_eq!(
+        era_compiler_solidity::immutables::normalize(&values).expect("Always valid"),
+        BTreeMap::from([
+            ("7".to_owned(), "0xcafe".to_owned()),
+            ("8".to_owned(), "0xbeef".to_owned()),
+        ])
+    );
+}
+
+#[test]
+fn normalize_too_long() {
+    let value = format!("0x{}", "f".repeat(65));
+    let values = BTreeMap::from([("7".to_owned(), value.clone())]);
+
+    assert_eq!(
+        era_compiler_solidity::immutables::normalize(&values)
+            .expect_err("Always invalid")
+            .to_string(),
+        format!("Invalid value `{value}` of immutable `7`: expected up to 64 hexadecimal digits")
+    );
+}
+
+#[test]
+fn normalize_empty() {
+    let values = BTreeMap::from([("7".to_owned(), "0x".to_owned())]);
+
+    assert_eq!(
+        era_compiler_solidity::immutables::normalize(&values)
+            .expect_err("Always invalid")
+            .to_string(),
+        "Invalid value `0x` of immutable `7`: expected up to 64 hexadecimal digits"
+    );
+}
@@ -119,3 +119,25 @@ fn specified(version: semver::Version, codegen: era_solc::StandardJsonInputCodeg
         "The list of missing libraries must be empty"
     );
 }
+
+#[test_case(
+    "C:/contracts/MiniMath.sol:MiniMath=0x1234567890abcdef1234567890abcdef12345678",
+    "C:/contracts/MiniMath.sol"
+)]
+#[test_case(
+    r"\\?\C:/contracts/MiniMath.sol:MiniMath=0x1234567890abcdef1234567890abcdef12345678",
+    "C:/contracts/MiniMath.sol"
+)]
+fn windows_path(library: &str, expected_file: &str) {
+    let libraries = era_solc::StandardJsonInputLibraries::try_from(&[library.to_owned()][..])
+        .expect("Parsing failure");
+
+    let contracts = libraries
+        .as_inner()
+        .get(expected_file)
+        .expect("Missing library file");
+    assert_eq!(
+        contracts.get("MiniMath").map(String::as_str),
+        Some("0x1234567890abcdef1234567890abcdef12345678"),
+    );
+}
@@ -661,3 +661,255 @@ fn libraries_not_passed_post_compile_time_complex(
     );
     assert!(!output.unlinked.is_empty(), "No unlinked objects found");
 }
+
+#[test]
+fn solc_placeholder_resolved() {
+    let library = "tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary";
+    let address = "1234567890abcdef1234567890abcdef12345678";
+
+    let bytecode = std::fs::read_to_string(crate::common::TEST_DISASSEMBLER_BYTECODE_PATH)
+        .expect("Always valid");
+    let bytecode = bytecode.trim().trim_start_matches("0x");
+    let placeholder = era_compiler_solidity::Linker::solc_placeholder(library);
+    let bytecode_unlinked = format!("{}{placeholder}{}", &bytecode[..64], &bytecode[104..]);
+
+    let bytecodes = BTreeMap::from([("bytecode".to_owned(), bytecode_unlinked)]);
+    let input =
+        era_compiler_solidity::LinkerInput::new(bytecodes, vec![format!("{library}=0x{address}")]);
+    let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
+
+    let linked = output
+        .linked
+        .get("bytecode")
+        .expect("Missing linked object");
+    assert_eq!(
+        linked.bytecode,
+        format!("{}{address}{}", &bytecode[..64], &bytecode[104..])
+    );
+    assert_eq!(linked.linker_symbols, vec![library.to_owned()]);
+}
+
+#[test]
+fn solc_placeholder_unresolved() {
+    let library = "tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary";
+
+    let bytecode = std::fs::read_to_string(crate::common::TEST_DISASSEMBLER_BYTECODE_PATH)
+        .expect("Always valid");
+    let bytecode = bytecode.trim().trim_start_matches("0x");
+    let placeholder = era_compiler_solidity::Linker::solc_placeholder(library);
+    let bytecode_unlinked = format!("{}{placeholder}{}", &bytecode[..64], &bytecode[104..]);
+
+    let bytecodes = BTreeMap::from([("bytecode".to_owned(), bytecode_unlinked)]);
+    let input = era_compiler_solidity::LinkerInput::new(bytecodes, vec![]);
+    let output = era_compiler_solidity::Linker::link_eravm(input).expect("Linker failed");
+
+    let unlinked = output
+        .unlinked
+        .get("bytecode")
+        .expect("Missing unlinked object");
+    assert_eq!(unlinked.linker_symbols, vec![placeholder]);
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn library_not_passed_link_references(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        era_solc::StandardJsonInputLibraries::default(),
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        &version,
+        codegen,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let bytecode = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("SimpleContract")
+        .expect("Missing contract")
+        .evm
+        .as_ref()
+        .expect("Missing EVM data")
+        .bytecode
+        .as_ref()
+        .expect("Missing bytecode");
+
+    let link_references = bytecode
+        .link_references
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing link references")
+        .get("SimpleLibrary")
+        .expect("The library link reference is missing");
+    assert!(
+        !link_references.is_empty(),
+        "The library link reference offsets are missing"
+    );
+    let object = hex::decode(bytecode.object.as_str()).expect("Invalid bytecode");
+    assert!(
+        link_references
+            .iter()
+            .all(|reference| reference.start + reference.length <= object.len()),
+        "The library link reference offsets are out of the object"
+    );
+    assert_eq!(
+        link_references
+            .iter()
+            .map(|reference| reference.length)
+            .sum::<usize>()
+            % era_compiler_common::BYTE_LENGTH_ETH_ADDRESS,
+        0,
+        "The library link references do not cover whole addresses"
+    );
+    assert!(bytecode.hash.is_none(), "The unlinked bytecode has a hash");
+    assert!(
+        bytecode.opcodes.is_none(),
+        "The unlinked bytecode has opcodes"
+    );
+    assert_eq!(bytecode.to_bytes().expect("Always valid"), object);
+}
+
+#[test]
+fn library_passed_bytecode() {
+    let libraries =
+        vec!["tests/data/contracts/solidity/SimpleContract.sol:SimpleLibrary=0x1234567890abcdef1234567890abcdef12345678".to_owned()];
+    let libraries =
+        era_solc::StandardJsonInputLibraries::try_from(libraries.as_slice()).expect("Always valid");
+    let sources =
+        crate::common::read_sources(&[crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH]);
+
+    let build = crate::common::build_solidity_standard_json(
+        sources,
+        libraries,
+        era_compiler_common::HashType::None,
+        BTreeSet::new(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        era_compiler_llvm_context::OptimizerSettings::none(),
+    )
+    .expect("Build failure");
+    let contract = build
+        .contracts
+        .get(crate::common::TEST_SOLIDITY_CONTRACT_SIMPLE_CONTRACT_PATH)
+        .expect("Missing file")
+        .get("SimpleContract")
+        .expect("Missing contract");
+    let bytecode = contract
+        .evm
+        .as_ref()
+        .expect("Missing EVM data")
+        .bytecode
+        .as_ref()
+        .expect("Missing bytecode");
+
+    assert!(bytecode.link_references.is_empty());
+    assert!(
+        bytecode.hash.is_some(),
+        "The linked bytecode hash is missing"
+    );
+    assert_eq!(bytecode.hash, contract.hash);
+    assert_eq!(
+        bytecode.raw.as_deref(),
+        Some(
+            hex::decode(bytecode.object.as_str())
+                .expect("Invalid bytecode")
+                .as_slice()
+        ),
+    );
+    let opcodes = bytecode.opcodes.as_deref().expect("Missing opcodes");
+    assert!(opcodes.lines().count() > 1);
+    assert!(opcodes.lines().all(|opcode| !opcode.trim().is_empty()));
+}
+
+///
+/// Builds a 32-bit little-endian ELF object with a code section of 16 bytes, whose fields at
+/// offsets 4 and 8 are relocated against the `Library.0` and `Library.1` symbols.
+///
+fn get_elf_object() -> Vec<u8> {
+    const HEADER_SIZE: usize = 52;
+    const CODE_OFFSET: usize = HEADER_SIZE;
+    const STRINGS_OFFSET: usize = CODE_OFFSET + 16;
+    const STRINGS: &[u8] = b"\0Library.0\0Library.1\0";
+    const SYMBOLS_OFFSET: usize = STRINGS_OFFSET + 24;
+    const RELOCATIONS_OFFSET: usize = SYMBOLS_OFFSET + 3 * 16;
+    const SECTIONS_OFFSET: usize = RELOCATIONS_OFFSET + 2 * 12;
+
+    let mut object = vec![0u8; SECTIONS_OFFSET];
+    object[..6].copy_from_slice(b"\x7fELF\x01\x01");
+    object[0x20..0x24].copy_from_slice(&(SECTIONS_OFFSET as u32).to_le_bytes());
+    object[0x2E..0x30].copy_from_slice(&40u16.to_le_bytes());
+    object[0x30..0x32].copy_from_slice(&5u16.to_le_bytes());
+    object[STRINGS_OFFSET..STRINGS_OFFSET + STRINGS.len()].copy_from_slice(STRINGS);
+    for (index, name) in [1u32, 11u32].into_iter().enumerate() {
+        let symbol = SYMBOLS_OFFSET + (index + 1) * 16;
+        object[symbol..symbol + 4].copy_from_slice(&name.to_le_bytes());
+    }
+    for (index, offset) in [4u32, 8u32].into_iter().enumerate() {
+        let relocation = RELOCATIONS_OFFSET + index * 12;
+        object[relocation..relocation + 4].copy_from_slice(&offset.to_le_bytes());
+        object[relocation + 4..relocation + 8]
+            .copy_from_slice(&(((index as u32 + 1) << 8) | 1).to_le_bytes());
+    }
+
+    // The null, code, string table, symbol table, and relocation sections:
+    // type, offset, size, link, info, entry size.
+    let sections: [[u32; 6]; 5] = [
+        [0, 0, 0, 0, 0, 0],
+        [1, CODE_OFFSET as u32, 16, 0, 0, 0],
+        [3, STRINGS_OFFSET as u32, STRINGS.len() as u32, 0, 0, 0],
+        [2, SYMBOLS_OFFSET as u32, 3 * 16, 2, 1, 16],
+        [4, RELOCATIONS_OFFSET as u32, 2 * 12, 3, 1, 12],
+    ];
+    for [r#type, offset, size, link, info, entry_size] in sections.into_iter() {
+        let mut header = [0u8; 40];
+        header[4..8].copy_from_slice(&r#type.to_le_bytes());
+        header[16..20].copy_from_slice(&offset.to_le_bytes());
+        header[20..24].copy_from_slice(&size.to_le_bytes());
+        header[24..28].copy_from_slice(&link.to_le_bytes());
+        header[28..32].copy_from_slice(&info.to_le_bytes());
+        header[36..40].copy_from_slice(&entry_size.to_le_bytes());
+        object.extend_from_slice(header.as_slice());
+    }
+    object
+}
+
+#[test]
+fn elf_relocations() {
+    let object = get_elf_object();
+
+    let relocations =
+        era_compiler_solidity::linker::elf::relocations(object.as_slice()).expect("Always valid");
+    assert_eq!(
+        relocations,
+        vec![
+            era_compiler_solidity::linker::elf::Relocation {
+                symbol: "Library.0".to_owned(),
+                offset: 56,
+            },
+            era_compiler_solidity::linker::elf::Relocation {
+                symbol: "Library.1".to_owned(),
+                offset: 60,
+            },
+        ]
+    );
+}
+
+#[test]
+fn elf_relocations_truncated() {
+    let object = get_elf_object();
+
+    assert!(era_compiler_solidity::linker::elf::relocations(&object[..100]).is_err());
+}
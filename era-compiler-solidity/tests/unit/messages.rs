@@ -554,6 +554,294 @@ fn assembly_create2_suppressed(
     .expect("Test failure"));
 }
 
+pub const SELFDESTRUCT_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract SelfDestructExample {
+    function destroy() public {
+        selfdestruct(payable(msg.sender));
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn selfdestruct(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        SELFDESTRUCT_TEST_SOURCE,
+        "You are using 'selfdestruct'",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const SELFDESTRUCT_ASSEMBLY_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract SelfDestructExample {
+    function destroy() public {
+        assembly {
+            selfdestruct(caller())
+        }
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn selfdestruct_assembly(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        SELFDESTRUCT_ASSEMBLY_TEST_SOURCE,
+        "You are using 'selfdestruct'",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const ASSEMBLY_INIT_CODE_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.5.0;
+
+contract AssemblyInitCode {
+    function main(bytes32 code, uint256 argument) external returns (address result) {
+        assembly {
+            let pointer := mload(0x40)
+            mstore(pointer, code)
+            mstore(add(pointer, 0x20), argument)
+            result := create(0, pointer, 0x40)
+        }
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn assembly_init_code(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(crate::common::check_solidity_message(
+        ASSEMBLY_INIT_CODE_SOURCE,
+        "You are passing init code assembled in memory",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![era_solc::StandardJsonInputErrorType::AssemblyCreate],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn assembly_init_code_suppressed(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(!crate::common::check_solidity_message(
+        ASSEMBLY_INIT_CODE_SOURCE,
+        "You are passing init code assembled in memory",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![era_solc::StandardJsonInputErrorType::AssemblyCreate],
+        vec![era_solc::StandardJsonInputWarningType::AssemblyInitCode],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn assembly_init_code_calldata(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(!crate::common::check_solidity_message(
+        ASSEMBLY_CREATE_SOURCE_05_06,
+        "You are passing init code assembled in memory",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![era_solc::StandardJsonInputErrorType::AssemblyCreate],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const ASSEMBLY_INIT_CODE_CREATION_CODE_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.5.0;
+
+contract A {}
+
+contract AssemblyInitCodeCreationCode {
+    function main(bytes32 salt) external returns (address result) {
+        bytes memory code = type(A).creationCode;
+        assembly {
+            mstore(0x00, salt)
+            result := create2(0, add(code, 0x20), mload(code), mload(0x00))
+        }
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn assembly_init_code_creation_code(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(!crate::common::check_solidity_message(
+        ASSEMBLY_INIT_CODE_CREATION_CODE_SOURCE,
+        "You are passing init code assembled in memory",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![era_solc::StandardJsonInputErrorType::AssemblyCreate],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn assembly_init_code_assembly_create(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    assert!(!crate::common::check_solidity_message(
+        ASSEMBLY_INIT_CODE_SOURCE,
+        "You are passing init code assembled in memory",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
 pub const RUNTIME_CODE_SOURCE: &str = r#"
 // SPDX-License-Identifier: Unlicensed
 
@@ -561,21 +849,570 @@ pragma solidity >=0.5.3;
 
 contract A {}
 
-contract Test {
-    function main() public pure returns(bytes memory) {
-        return type(A).runtimeCode;
+contract Test {
+    function main() public pure returns(bytes memory) {
+        return type(A).runtimeCode;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn runtime_code(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(crate::common::check_solidity_message(
+        RUNTIME_CODE_SOURCE,
+        "Deploy and runtime code are merged in EraVM",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const CREATION_CODE_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract A {}
+
+contract Test {
+    function main() public pure returns(bytes32) {
+        return keccak256(type(A).creationCode);
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn creation_code(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        CREATION_CODE_SOURCE,
+        "You are using 'type(T).creationCode', whose value differs on EraVM",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn creation_code_suppressed(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(!crate::common::check_solidity_message(
+        CREATION_CODE_SOURCE,
+        "You are using 'type(T).creationCode', whose value differs on EraVM",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![era_solc::StandardJsonInputWarningType::CreationCode],
+    )
+    .expect("Test failure"));
+}
+
+pub const PRECOMPILE_CALL_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract PrecompileCallExample {
+    function main(bytes memory input) public view returns (bool success, bytes memory output) {
+        (success, output) = address(0x02).staticcall(input);
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn precompile_call(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        PRECOMPILE_CALL_TEST_SOURCE,
+        "You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn precompile_call_suppressed(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    assert!(!crate::common::check_solidity_message(
+        PRECOMPILE_CALL_TEST_SOURCE,
+        "You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![era_solc::StandardJsonInputWarningType::PrecompileCall],
+    )
+    .expect("Test failure"));
+}
+
+pub const PRECOMPILE_CALL_ASSEMBLY_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract PrecompileCallExample {
+    function main(bytes32 hash, uint8 v, bytes32 r, bytes32 s) public view returns (address signer) {
+        assembly {
+            let pointer := mload(0x40)
+            mstore(pointer, hash)
+            mstore(add(pointer, 0x20), v)
+            mstore(add(pointer, 0x40), r)
+            mstore(add(pointer, 0x60), s)
+            if iszero(staticcall(gas(), 1, pointer, 0x80, pointer, 0x20)) {
+                revert(0, 0)
+            }
+            signer := mload(pointer)
+        }
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn precompile_call_assembly(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        PRECOMPILE_CALL_ASSEMBLY_TEST_SOURCE,
+        "You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const PRECOMPILE_BUILTIN_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract PrecompileBuiltinExample {
+    function main(bytes memory input) public pure returns (bytes32) {
+        return sha256(input);
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn precompile_builtin(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(!crate::common::check_solidity_message(
+        PRECOMPILE_BUILTIN_TEST_SOURCE,
+        "You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const ABI_CODER_V1_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+pragma abicoder v1;
+
+contract AbiCoderV1Example {
+    function main(uint8 value) public pure returns (uint8) {
+        return value;
+    }
+}
+"#;
+
+pub const ABI_CODER_DEFAULT_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+
+contract AbiCoderDefaultExample {
+    function main(uint8 value) public pure returns (uint8) {
+        return value;
+    }
+}
+"#;
+
+pub const ABI_CODER_V2_TEST_SOURCE: &str = r#"
+// SPDX-License-Identifier: Unlicensed
+
+pragma solidity >=0.7.0;
+pragma experimental ABIEncoderV2;
+
+contract AbiCoderV2Example {
+    function main(uint8 value) public pure returns (uint8) {
+        return value;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn abi_coder_v1(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        ABI_CODER_V1_TEST_SOURCE,
+        "This source is compiled with ABI coder v1",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn abi_coder_v1_suppressed(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(!crate::common::check_solidity_message(
+        ABI_CODER_V1_TEST_SOURCE,
+        "This source is compiled with ABI coder v1",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![era_solc::StandardJsonInputWarningType::AbiCoderV1],
+    )
+    .expect("Test failure"));
+}
+
+#[test]
+fn abi_coder_v1_default() {
+    assert!(crate::common::check_solidity_message(
+        ABI_CODER_DEFAULT_TEST_SOURCE,
+        "This source is compiled with ABI coder v1",
+        era_solc::StandardJsonInputLibraries::default(),
+        &semver::Version::new(0, 7, 6),
+        era_solc::StandardJsonInputCodegen::EVMLA,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn abi_coder_v2_default(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(!crate::common::check_solidity_message(
+        ABI_CODER_DEFAULT_TEST_SOURCE,
+        "This source is compiled with ABI coder v1",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test]
+fn abi_coder_v2_experimental() {
+    assert!(!crate::common::check_solidity_message(
+        ABI_CODER_V2_TEST_SOURCE,
+        "This source is compiled with ABI coder v1",
+        era_solc::StandardJsonInputLibraries::default(),
+        &semver::Version::new(0, 7, 6),
+        era_solc::StandardJsonInputCodegen::EVMLA,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const TX_ORIGIN_TEST_SOURCE: &str = r#"
+contract TxOriginExample {
+    function main() private {
+        address txOrigin = tx.origin;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 4, 26),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn tx_origin(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(crate::common::check_solidity_message(
+        TX_ORIGIN_TEST_SOURCE,
+        "You are checking for 'tx.origin', which might lead to",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    semver::Version::new(0, 4, 26),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn tx_origin_suppressed(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(!crate::common::check_solidity_message(
+        TX_ORIGIN_TEST_SOURCE,
+        "You are checking for 'tx.origin', which might lead to",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![era_solc::StandardJsonInputWarningType::TxOrigin],
+    )
+    .expect("Test failure"));
+}
+
+pub const TX_ORIGIN_DISABLED_INLINE_TEST_SOURCE: &str = r#"
+contract TxOriginExample {
+    function main() private {
+        // zksolc-disable-next-line tx-origin
+        address txOrigin = tx.origin;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn tx_origin_disabled_inline(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    assert!(!crate::common::check_solidity_message(
+        TX_ORIGIN_DISABLED_INLINE_TEST_SOURCE,
+        "You are checking for 'tx.origin', which might lead to",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const TX_ORIGIN_DISABLED_INLINE_OTHER_CODE_TEST_SOURCE: &str = r#"
+contract TxOriginExample {
+    function main() private {
+        // zksolc-disable-next-line sendtransfer
+        address txOrigin = tx.origin;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn tx_origin_disabled_inline_other_code(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    assert!(crate::common::check_solidity_message(
+        TX_ORIGIN_DISABLED_INLINE_OTHER_CODE_TEST_SOURCE,
+        "You are checking for 'tx.origin', which might lead to",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const TX_ORIGIN_DISABLED_INLINE_PREVIOUS_LINE_TEST_SOURCE: &str = r#"
+contract TxOriginExample {
+    function main() private {
+        // zksolc-disable-next-line txorigin
+        address sender = msg.sender;
+        address txOrigin = tx.origin;
     }
 }
 "#;
 
-#[test_case(
-    semver::Version::new(0, 5, 17),
-    era_solc::StandardJsonInputCodegen::EVMLA
-)]
-#[test_case(
-    semver::Version::new(0, 6, 12),
-    era_solc::StandardJsonInputCodegen::EVMLA
-)]
 #[test_case(
     semver::Version::new(0, 7, 6),
     era_solc::StandardJsonInputCodegen::EVMLA
@@ -588,14 +1425,13 @@ contract Test {
     era_solc::Compiler::LAST_SUPPORTED_VERSION,
     era_solc::StandardJsonInputCodegen::Yul
 )]
-fn runtime_code(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
-    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
-        return;
-    }
-
+fn tx_origin_disabled_inline_previous_line(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
     assert!(crate::common::check_solidity_message(
-        RUNTIME_CODE_SOURCE,
-        "Deploy and runtime code are merged in EraVM",
+        TX_ORIGIN_DISABLED_INLINE_PREVIOUS_LINE_TEST_SOURCE,
+        "You are checking for 'tx.origin', which might lead to",
         era_solc::StandardJsonInputLibraries::default(),
         &version,
         codegen,
@@ -605,10 +1441,12 @@ fn runtime_code(version: semver::Version, codegen: era_solc::StandardJsonInputCo
     .expect("Test failure"));
 }
 
-pub const TX_ORIGIN_TEST_SOURCE: &str = r#"
+pub const TX_ORIGIN_ASSEMBLY_TEST_SOURCE: &str = r#"
 contract TxOriginExample {
     function main() private {
-        address txOrigin = tx.origin;
+        assembly {
+            let txOrigin := origin()
+        }
     }
 }
 "#;
@@ -637,13 +1475,13 @@ contract TxOriginExample {
     era_solc::Compiler::LAST_SUPPORTED_VERSION,
     era_solc::StandardJsonInputCodegen::Yul
 )]
-fn tx_origin(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+fn tx_origin_assembly(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
     if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
         return;
     }
 
     assert!(crate::common::check_solidity_message(
-        TX_ORIGIN_TEST_SOURCE,
+        TX_ORIGIN_ASSEMBLY_TEST_SOURCE,
         "You are checking for 'tx.origin', which might lead to",
         era_solc::StandardJsonInputLibraries::default(),
         &version,
@@ -678,14 +1516,17 @@ fn tx_origin(version: semver::Version, codegen: era_solc::StandardJsonInputCodeg
     era_solc::Compiler::LAST_SUPPORTED_VERSION,
     era_solc::StandardJsonInputCodegen::Yul
 )]
-fn tx_origin_suppressed(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+fn tx_origin_assembly_suppressed(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
     if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
         return;
     }
 
     assert!(!crate::common::check_solidity_message(
-        TX_ORIGIN_TEST_SOURCE,
-        "You are checking for 'tx.origin', which might lead to",
+        TX_ORIGIN_ASSEMBLY_TEST_SOURCE,
+        "You are checking for 'tx.origin' in your code, which might lead to",
         era_solc::StandardJsonInputLibraries::default(),
         &version,
         codegen,
@@ -695,12 +1536,10 @@ fn tx_origin_suppressed(version: semver::Version, codegen: era_solc::StandardJso
     .expect("Test failure"));
 }
 
-pub const TX_ORIGIN_ASSEMBLY_TEST_SOURCE: &str = r#"
-contract TxOriginExample {
-    function main() private {
-        assembly {
-            let txOrigin := origin()
-        }
+pub const BLOCK_HASH_TEST_SOURCE: &str = r#"
+contract BlockHashExample {
+    function main() private view returns (bytes32) {
+        return blockhash(block.number - 1);
     }
 }
 "#;
@@ -729,14 +1568,14 @@ contract TxOriginExample {
     era_solc::Compiler::LAST_SUPPORTED_VERSION,
     era_solc::StandardJsonInputCodegen::Yul
 )]
-fn tx_origin_assembly(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+fn block_hash(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
     if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
         return;
     }
 
     assert!(crate::common::check_solidity_message(
-        TX_ORIGIN_ASSEMBLY_TEST_SOURCE,
-        "You are checking for 'tx.origin', which might lead to",
+        BLOCK_HASH_TEST_SOURCE,
+        "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'",
         era_solc::StandardJsonInputLibraries::default(),
         &version,
         codegen,
@@ -770,22 +1609,398 @@ fn tx_origin_assembly(version: semver::Version, codegen: era_solc::StandardJsonI
     era_solc::Compiler::LAST_SUPPORTED_VERSION,
     era_solc::StandardJsonInputCodegen::Yul
 )]
-fn tx_origin_assembly_suppressed(
-    version: semver::Version,
-    codegen: era_solc::StandardJsonInputCodegen,
-) {
+fn block_hash_suppressed(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
     if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
         return;
     }
 
     assert!(!crate::common::check_solidity_message(
-        TX_ORIGIN_ASSEMBLY_TEST_SOURCE,
-        "You are checking for 'tx.origin' in your code, which might lead to",
+        BLOCK_HASH_TEST_SOURCE,
+        "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'",
         era_solc::StandardJsonInputLibraries::default(),
         &version,
         codegen,
         vec![],
-        vec![era_solc::StandardJsonInputWarningType::TxOrigin],
+        vec![era_solc::StandardJsonInputWarningType::BlockRandomness],
+    )
+    .expect("Test failure"));
+}
+
+pub const BLOCK_DIFFICULTY_TEST_SOURCE: &str = r#"
+contract BlockDifficultyExample {
+    function main() private view returns (uint256) {
+        return block.difficulty;
+    }
+}
+"#;
+
+#[test_case(
+    semver::Version::new(0, 4, 26),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 5, 17),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 6, 12),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    semver::Version::new(0, 7, 6),
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn block_difficulty(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
+        return;
+    }
+
+    assert!(crate::common::check_solidity_message(
+        BLOCK_DIFFICULTY_TEST_SOURCE,
+        "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+pub const BLOCK_PREVRANDAO_TEST_SOURCE: &str = r#"
+contract BlockPrevrandaoExample {
+    function main() private view returns (uint256) {
+        return block.prevrandao;
+    }
+}
+"#;
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn block_prevrandao(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen) {
+    assert!(crate::common::check_solidity_message(
+        BLOCK_PREVRANDAO_TEST_SOURCE,
+        "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::EVMLA
+)]
+#[test_case(
+    era_solc::Compiler::LAST_SUPPORTED_VERSION,
+    era_solc::StandardJsonInputCodegen::Yul
+)]
+fn block_prevrandao_suppressed(
+    version: semver::Version,
+    codegen: era_solc::StandardJsonInputCodegen,
+) {
+    assert!(!crate::common::check_solidity_message(
+        BLOCK_PREVRANDAO_TEST_SOURCE,
+        "You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty'",
+        era_solc::StandardJsonInputLibraries::default(),
+        &version,
+        codegen,
+        vec![],
+        vec![era_solc::StandardJsonInputWarningType::BlockRandomness],
+    )
+    .expect("Test failure"));
+}
+
+pub const CONSOLIDATED_REPORT_TEST_SOURCE: &str = r#"
+contract ConsolidatedExample {
+    function a() public view returns (address) {
+        return tx.origin;
+    }
+
+    function b() public view returns (address) {
+        return tx.origin;
+    }
+}
+"#;
+
+#[test]
+fn consolidated_report() {
+    assert!(crate::common::check_solidity_message(
+        CONSOLIDATED_REPORT_TEST_SOURCE,
+        "`test.sol:ConsolidatedExample` uses 1 unsupported feature(s)",
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+    assert!(crate::common::check_solidity_message(
+        CONSOLIDATED_REPORT_TEST_SOURCE,
+        "Found 2 usage(s):\n    --> test.sol:4",
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure"));
+}
+
+#[test]
+fn formatted_message_excerpt() {
+    assert!(crate::common::check_solidity_message(
+        SEND_TEST_SOURCE_08,
+        " --> test.sol:5:16:\n  |\n5 |         return payable(r).send(msg.value);\n  |                ^",
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
     )
     .expect("Test failure"));
 }
+
+#[test]
+fn suggested_fix_tx_origin() {
+    let messages = crate::common::get_solidity_messages(
+        TX_ORIGIN_TEST_SOURCE,
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+
+    let suggested_fix = messages
+        .iter()
+        .flat_map(|message| message.suggested_fixes.iter())
+        .next()
+        .expect("Missing suggested fix");
+    let span =
+        suggested_fix.source_location.start as usize..suggested_fix.source_location.end as usize;
+    assert_eq!(&TX_ORIGIN_TEST_SOURCE[span], "tx.origin");
+    assert_eq!(suggested_fix.replacement, "msg.sender");
+}
+
+#[test]
+fn suggested_fix_tx_origin_assembly() {
+    let messages = crate::common::get_solidity_messages(
+        TX_ORIGIN_ASSEMBLY_TEST_SOURCE,
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+
+    let suggested_fix = messages
+        .iter()
+        .flat_map(|message| message.suggested_fixes.iter())
+        .next()
+        .expect("Missing suggested fix");
+    let span =
+        suggested_fix.source_location.start as usize..suggested_fix.source_location.end as usize;
+    assert_eq!(&TX_ORIGIN_ASSEMBLY_TEST_SOURCE[span], "origin()");
+    assert_eq!(suggested_fix.replacement, "caller()");
+}
+
+#[test]
+fn suggested_fix_transfer() {
+    let messages = crate::common::get_solidity_messages(
+        TRANSFER_TEST_SOURCE_08,
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+
+    let suggested_fix = messages
+        .iter()
+        .flat_map(|message| message.suggested_fixes.iter())
+        .next()
+        .expect("Missing suggested fix");
+    let span =
+        suggested_fix.source_location.start as usize..suggested_fix.source_location.end as usize;
+    assert_eq!(
+        &TRANSFER_TEST_SOURCE_08[span],
+        "payable(r).transfer(msg.value);"
+    );
+    assert_eq!(
+        suggested_fix.replacement,
+        r#"{ (bool success, ) = payable(r).call{value: msg.value}(""); require(success, "Transfer failed"); }"#
+    );
+}
+
+pub const TRANSFER_TEST_SOURCE_UNBRACED_IF: &str = r#"
+contract TransferExample {
+    function s(bool c) public payable {
+        address r = address(0);
+        if (c) payable(r).transfer(msg.value);
+    }
+}
+"#;
+
+pub const TRANSFER_TEST_SOURCE_UNBRACED_IF_FIXED: &str = r#"
+contract TransferExample {
+    function s(bool c) public payable {
+        address r = address(0);
+        if (c) { (bool success, ) = payable(r).call{value: msg.value}(""); require(success, "Transfer failed"); }
+    }
+}
+"#;
+
+pub const TRANSFER_TEST_SOURCE_SAME_SCOPE: &str = r#"
+contract TransferExample {
+    function s() public payable {
+        address r = address(0);
+        payable(r).transfer(msg.value);
+        payable(r).transfer(0);
+    }
+}
+"#;
+
+pub const TRANSFER_TEST_SOURCE_SAME_SCOPE_FIXED: &str = r#"
+contract TransferExample {
+    function s() public payable {
+        address r = address(0);
+        { (bool success, ) = payable(r).call{value: msg.value}(""); require(success, "Transfer failed"); }
+        { (bool success, ) = payable(r).call{value: 0}(""); require(success, "Transfer failed"); }
+    }
+}
+"#;
+
+#[test_case(
+    TRANSFER_TEST_SOURCE_UNBRACED_IF,
+    TRANSFER_TEST_SOURCE_UNBRACED_IF_FIXED,
+    1
+)]
+#[test_case(
+    TRANSFER_TEST_SOURCE_SAME_SCOPE,
+    TRANSFER_TEST_SOURCE_SAME_SCOPE_FIXED,
+    2
+)]
+fn suggested_fix_transfer_applied(source_code: &str, expected: &str, expected_fixes: usize) {
+    let messages = crate::common::get_solidity_messages(
+        source_code,
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+
+    let mut suggested_fixes: Vec<&era_solc::StandardJsonOutputErrorSuggestedFix> = messages
+        .iter()
+        .flat_map(|message| message.suggested_fixes.iter())
+        .collect();
+    assert_eq!(suggested_fixes.len(), expected_fixes);
+
+    suggested_fixes
+        .sort_by_key(|suggested_fix| std::cmp::Reverse(suggested_fix.source_location.start));
+    let mut fixed = source_code.to_owned();
+    for suggested_fix in suggested_fixes.into_iter() {
+        let span = suggested_fix.source_location.start as usize
+            ..suggested_fix.source_location.end as usize;
+        fixed.replace_range(span, suggested_fix.replacement.as_str());
+    }
+    assert_eq!(fixed, expected);
+
+    let messages = crate::common::get_solidity_messages(
+        fixed.as_str(),
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+    let errors: Vec<&str> = messages
+        .iter()
+        .filter(|message| message.severity == "error")
+        .map(|message| message.message.as_str())
+        .collect();
+    assert_eq!(errors, Vec::<&str>::new());
+}
+
+#[test]
+fn suggested_fix_send_none() {
+    let messages = crate::common::get_solidity_messages(
+        SEND_TEST_SOURCE_08,
+        era_solc::StandardJsonInputLibraries::default(),
+        &era_solc::Compiler::LAST_SUPPORTED_VERSION,
+        era_solc::StandardJsonInputCodegen::Yul,
+        vec![],
+        vec![],
+    )
+    .expect("Test failure");
+
+    let send_message = messages
+        .iter()
+        .find(|message| {
+            message
+                .message
+                .contains("You are using '<address payable>.send/transfer(<X>)' without providing")
+        })
+        .expect("Missing send message");
+    assert!(send_message.suggested_fixes.is_empty());
+}
+
+#[test]
+fn consolidated_report_codes() {
+    let messages = ["A.sol", "B.sol"]
+        .into_iter()
+        .map(|path| {
+            let mut message = era_solc::StandardJsonOutputError::new_warning(
+                "tx.origin",
+                Some(era_solc::StandardJsonOutputErrorSourceLocation::new(
+                    path.to_owned(),
+                )),
+                None,
+            );
+            message.error_code = Some(era_solc::StandardJsonOutputError::CODE_TX_ORIGIN.to_owned());
+            message.warning_type = Some(era_solc::StandardJsonInputWarningType::TxOrigin);
+            message
+        })
+        .collect();
+
+    let reports = era_solc::StandardJsonOutputError::new_unsupported_feature_reports(
+        "A.sol:Test",
+        messages,
+        &std::collections::BTreeMap::new(),
+    );
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(
+        report.error_code.as_deref(),
+        Some(era_solc::StandardJsonOutputError::CODE_TX_ORIGIN)
+    );
+    assert_eq!(
+        report.warning_type,
+        Some(era_solc::StandardJsonInputWarningType::TxOrigin)
+    );
+    assert_eq!(report.unsupported_features.len(), 1);
+    assert_eq!(report.unsupported_features[0].locations.len(), 2);
+}
@@ -2,13 +2,34 @@
 //! The unit tests entry module.
 //!
 
+mod artifact_directories;
+mod ast_cache;
+mod bytecode_comparison;
 mod combined_json;
+mod dedup_report;
+mod dependency_cycle;
+mod disassembly;
+mod eravm_assembly;
+mod events;
+mod evm_opcodes;
+mod execution_profile;
+mod exit_code;
 mod factory_dependency;
+mod immutables;
 mod ir_artifacts;
 mod libraries;
 mod linker;
 mod messages;
 mod optimizer;
+mod output_selection;
+mod reachable_selectors;
 mod remappings;
+mod sarif;
+mod server;
+mod solc_build_list;
+mod spill_report;
 mod standard_json;
+mod statistics;
+mod temp_dir;
 mod unsupported_instructions;
+mod virtual_file_system;
@@ -0,0 +1,57 @@
+//!
+//! Unit tests for the standard JSON output selection.
+//!
+
+#[test]
+fn sub_selectors() {
+    let json = serde_json::json!({
+        "*": {
+            "*": ["abi"]
+        },
+        "A.sol": {
+            "C": ["evm.bytecode.object", "evm.gasEstimates"]
+        }
+    });
+    let selection: era_solc::StandardJsonInputSelection =
+        serde_json::from_value(json.clone()).expect("Always valid");
+
+    assert!(selection.is_granular());
+    assert!(selection.is_bytecode_requested("A.sol", "C"));
+    assert!(!selection.is_bytecode_requested("A.sol", "D"));
+    assert!(!selection.is_bytecode_requested("B.sol", "C"));
+    assert_eq!(
+        serde_json::to_value(&selection).expect("Always valid"),
+        json,
+        "The selectors unknown to zksolc must be passed to solc unchanged"
+    );
+}
+
+#[test]
+fn contract_wildcard() {
+    let selection: era_solc::StandardJsonInputSelection =
+        serde_json::from_value(serde_json::json!({
+            "A.sol": {
+                "C": ["*"],
+                "D": ["abi", "evm.deployedBytecode.object"],
+                "E": ["abi", "evm.gasEstimates"]
+            }
+        }))
+        .expect("Always valid");
+
+    assert!(selection.is_bytecode_requested("A.sol", "C"));
+    assert!(selection.is_bytecode_requested("A.sol", "D"));
+    assert!(!selection.is_bytecode_requested("A.sol", "E"));
+}
+
+#[test]
+fn wildcard_only() {
+    let selection: era_solc::StandardJsonInputSelection =
+        serde_json::from_value(serde_json::json!({
+            "*": {
+                "*": ["abi"]
+            }
+        }))
+        .expect("Always valid");
+
+    assert!(!selection.is_granular());
+}
@@ -0,0 +1,157 @@
+//!
+//! Unit tests for the selector-restricted dead function elimination.
+//!
+
+use std::collections::BTreeSet;
+
+use era_yul::yul::lexer::Lexer;
+use era_yul::yul::parser::statement::block::Block;
+use era_yul::yul::parser::statement::object::Object;
+use era_yul::yul::parser::statement::Statement;
+
+use era_compiler_solidity::yul::parser::dialect::era::EraDialect;
+
+const SOURCE: &str = r#"
+object "Test" {
+    code {
+        {
+            switch shr(224, calldataload(0))
+            case 0x3df4ddf4 { return(0, 0) }
+            case 0x5a8ac02d { return(0, 0) }
+        }
+    }
+    object "Test_deployed" {
+        code {
+            {
+                if iszero(lt(calldatasize(), 4))
+                {
+                    switch shr(224, calldataload(0))
+                    case 0x3df4ddf4 { return(0, 0) }
+                    case 0x5A8AC02D { return(0, 0) }
+                    case 0x70a08231 { mstore(0, select(calldataload(4))) }
+                    default { revert(0, 0) }
+                }
+                revert(0, 0)
+            }
+            function select(value) -> result
+            {
+                switch value
+                case 0 { result := 1 }
+                case 1 { result := 2 }
+            }
+        }
+    }
+}
+"#;
+
+fn apply(selectors: &[&str]) -> Object<EraDialect> {
+    let mut object = Object::<EraDialect>::parse(&mut Lexer::new(SOURCE.to_owned()), None)
+        .expect("Always valid");
+    let selectors: BTreeSet<String> = selectors
+        .iter()
+        .map(|selector| (*selector).to_owned())
+        .collect();
+    era_compiler_solidity::reachable_selectors::apply_to_object(&mut object, &selectors);
+    object
+}
+
+fn switch_cases(block: &Block<EraDialect>, cases: &mut Vec<Vec<String>>) {
+    for statement in block.statements.iter() {
+        match statement {
+            Statement::Block(block) => switch_cases(block, cases),
+            Statement::FunctionDefinition(function) => switch_cases(&function.body, cases),
+            Statement::IfConditional(if_conditional) => switch_cases(&if_conditional.block, cases),
+            Statement::Switch(switch) => {
+                cases.push(
+                    switch
+                        .cases
+                        .iter()
+                        .map(|case| case.literal.inner.to_string())
+                        .collect(),
+                );
+                for case in switch.cases.iter() {
+                    switch_cases(&case.block, cases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+fn deploy_cases(object: &Object<EraDialect>) -> Vec<Vec<String>> {
+    let mut cases = Vec::new();
+    switch_cases(&object.code.block, &mut cases);
+    cases
+}
+
+fn runtime_cases(object: &Object<EraDialect>) -> Vec<Vec<String>> {
+    deploy_cases(object.inner_object.as_deref().expect("Always exists"))
+}
+
+#[test]
+fn default() {
+    let object = apply(&["3df4ddf4"]);
+
+    assert_eq!(
+        runtime_cases(&object),
+        vec![
+            vec!["0x3df4ddf4".to_owned()],
+            vec!["0".to_owned(), "1".to_owned()],
+        ]
+    );
+}
+
+#[test]
+fn uppercase_literal() {
+    let object = apply(&["5a8ac02d", "70a08231"]);
+
+    assert_eq!(
+        runtime_cases(&object),
+        vec![
+            vec!["0x5A8AC02D".to_owned(), "0x70a08231".to_owned()],
+            vec!["0".to_owned(), "1".to_owned()],
+        ]
+    );
+}
+
+#[test]
+fn empty() {
+    let object = apply(&[]);
+
+    assert_eq!(
+        runtime_cases(&object),
+        vec![Vec::<String>::new(), vec!["0".to_owned(), "1".to_owned()]]
+    );
+}
+
+#[test]
+fn deploy_code_unchanged() {
+    let object = apply(&[]);
+
+    assert_eq!(
+        deploy_cases(&object),
+        vec![vec!["0x3df4ddf4".to_owned(), "0x5a8ac02d".to_owned()]]
+    );
+}
+
+#[test]
+fn normalize() {
+    let selectors = BTreeSet::from(["0x3DF4DDF4".to_owned(), "5a8ac02d".to_owned()]);
+
+    assert_eq!(
+        era_compiler_solidity::reachable_selectors::normalize(&selectors).expect("Always valid"),
+        BTreeSet::from(["3df4ddf4".to_owned(), "5a8ac02d".to_owned()])
+    );
+}
+
+#[test]
+fn normalize_invalid() {
+    let selectors = BTreeSet::from(["0x3df4".to_owned()]);
+
+    assert_eq!(
+        era_compiler_solidity::reachable_selectors::normalize(&selectors)
+            .expect_err("Always invalid")
+            .to_string(),
+        "Invalid function selector `0x3df4`: expected 8 hexadecimal digits"
+    );
+}
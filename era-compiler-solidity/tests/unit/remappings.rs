@@ -54,3 +54,85 @@ fn default(version: semver::Version, codegen: era_solc::StandardJsonInputCodegen
     )
     .expect("Test failure");
 }
+
+#[test_case(
+    "@openzeppelin/=lib/openzeppelin/",
+    "",
+    "@openzeppelin/",
+    "lib/openzeppelin/"
+)]
+#[test_case("context:@oz/=lib/oz/", "context", "@oz/", "lib/oz/")]
+#[test_case("C:/lib/oz/=D:/vendor/oz/", "", "C:/lib/oz/", "D:/vendor/oz/")]
+#[test_case(r"C:\lib\oz\=D:\vendor\oz\", "", r"C:\lib\oz\", r"D:\vendor\oz\")]
+#[test_case("C:/project:@oz/=C:/lib/oz/", "C:/project", "@oz/", "C:/lib/oz/")]
+#[test_case("context:C:/lib/oz/=lib/oz/", "context", "C:/lib/oz/", "lib/oz/")]
+fn parse(remapping: &str, context: &str, prefix: &str, target: &str) {
+    let remapping: era_solc::StandardJsonInputRemapping = remapping.parse().expect("Always valid");
+    assert_eq!(remapping.context, context);
+    assert_eq!(remapping.prefix, prefix);
+    assert_eq!(remapping.target, target);
+}
+
+#[test]
+fn source_paths() {
+    let input = era_solc::StandardJsonInput::try_from_str(
+        r#"{
+            "language": "Solidity",
+            "sources": {
+                "Main.sol": { "content": "import \"math/Math.sol\"; contract Main {}" },
+                "math/Local.sol": { "content": "contract Local {}" }
+            },
+            "settings": {
+                "remappings": ["math/=lib/math/"],
+                "libraries": {
+                    "math/Math.sol": { "Math": "0x1234567890123456789012345678901234567890" }
+                },
+                "immutables": {
+                    "math/Math.sol": { "Math": { "scale": "0x02" } },
+                    "math/Local.sol": { "Local": { "scale": "0x03" } }
+                },
+                "contract": "math/Math.sol:Math"
+            }
+        }"#,
+    )
+    .expect("Always valid");
+
+    assert_eq!(
+        input.settings.remappings,
+        BTreeSet::from(["math/=lib/math/".to_owned()])
+    );
+    assert_eq!(
+        input
+            .settings
+            .libraries
+            .as_inner()
+            .keys()
+            .collect::<Vec<&String>>(),
+        vec!["lib/math/Math.sol"]
+    );
+    assert_eq!(
+        input.settings.immutables.keys().collect::<Vec<&String>>(),
+        vec!["lib/math/Math.sol", "math/Local.sol"]
+    );
+    assert_eq!(
+        input.settings.contract.as_deref(),
+        Some("lib/math/Math.sol:Math")
+    );
+}
+
+#[test]
+fn source_paths_invalid() {
+    let error = era_solc::StandardJsonInput::try_from_str(
+        r#"{
+            "language": "Solidity",
+            "sources": { "Main.sol": { "content": "contract Main {}" } },
+            "settings": { "remappings": ["=lib/math/"] }
+        }"#,
+    )
+    .expect_err("Always invalid");
+
+    assert_eq!(
+        error.to_string(),
+        "Invalid remapping `=lib/math/`: the prefix is empty."
+    );
+}
@@ -0,0 +1,59 @@
+//!
+//! Unit tests for the SARIF log.
+//!
+
+#[test]
+fn region() {
+    let source_code = "// ©\ncontract Test {\n    // ½ ½\n    address x = tx.origin;\n}\n";
+    let start = source_code.find("tx.origin").expect("Always exists");
+    let source_location = era_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+        "Test.sol".to_owned(),
+        start as isize,
+        (start + "tx.origin".len()) as isize,
+    );
+
+    let region = era_solc::StandardJsonOutputErrorSarifRegion::try_from_source_location(
+        &source_location,
+        Some(source_code),
+    )
+    .expect("Always exists");
+    assert_eq!(region.start_line, Some(4));
+    assert_eq!(region.start_column, Some(17));
+    assert_eq!(region.byte_offset, start);
+    assert_eq!(region.char_offset, Some(start - 3));
+    assert_eq!(region.byte_length, 9);
+    assert_eq!(region.char_length, Some(9));
+}
+
+#[test]
+fn region_without_source_code() {
+    let source_location = era_solc::StandardJsonOutputErrorSourceLocation::new_with_offsets(
+        "Test.sol".to_owned(),
+        10,
+        15,
+    );
+
+    let region = era_solc::StandardJsonOutputErrorSarifRegion::try_from_source_location(
+        &source_location,
+        None,
+    )
+    .expect("Always exists");
+    assert_eq!(region.start_line, None);
+    assert_eq!(region.char_offset, None);
+    assert_eq!(region.byte_offset, 10);
+    assert_eq!(region.byte_length, 5);
+}
+
+#[test]
+fn region_invalid() {
+    let source_location =
+        era_solc::StandardJsonOutputErrorSourceLocation::new("Test.sol".to_owned());
+
+    assert!(
+        era_solc::StandardJsonOutputErrorSarifRegion::try_from_source_location(
+            &source_location,
+            Some("contract Test {}"),
+        )
+        .is_none()
+    );
+}
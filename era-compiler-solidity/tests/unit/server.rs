@@ -0,0 +1,43 @@
+//!
+//! Unit tests for the compilation server.
+//!
+
+use std::time::Duration;
+
+#[test]
+fn build_timeout_per_request() {
+    crate::common::setup().expect("Test setup failure");
+
+    let input: serde_json::Value = serde_json::from_str(
+        std::fs::read_to_string(crate::common::TEST_YUL_STANDARD_JSON_SOLC_PATH)
+            .expect("Standard JSON reading error")
+            .as_str(),
+    )
+    .expect("Standard JSON parsing error");
+    let server = era_compiler_solidity::Server::new(
+        None,
+        era_compiler_solidity::standard_json::Options {
+            build: crate::common::build_config(),
+            ..Default::default()
+        },
+        Some(Duration::from_secs(1)),
+    );
+
+    // The timeout must not be counted from the server start.
+    std::thread::sleep(Duration::from_secs(2));
+
+    let request = serde_json::json!({ "jsonrpc": "2.0", "id": 1, "method": "compile", "params": { "input": input } });
+    let mut responses = Vec::new();
+    server
+        .serve(request.to_string().as_bytes(), &mut responses)
+        .expect("Server failure");
+    let response: serde_json::Value =
+        serde_json::from_slice(responses.as_slice()).expect("Response parsing error");
+
+    assert!(
+        !response["result"]["contracts"]["Test"]["Return"]["evm"]["bytecode"]["object"]
+            .as_str()
+            .expect("The bytecode is missing")
+            .is_empty()
+    );
+}
@@ -0,0 +1,72 @@
+//!
+//! Unit tests for the `solc` release build list verification.
+//!
+
+/// The SHA-256 checksum of `abc`.
+const SHA256_ABC: &str = "0xba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad";
+
+/// The Keccak-256 checksum of `abc`.
+const KECCAK256_ABC: &str = "0x4e03657aea45a94fc7d47ba826c8d667c0d1e6e33a64a036ec44f58fa12d6c45";
+
+///
+/// Returns the build list with a single `solc-linux-amd64-0.8.28-1.0.1` build.
+///
+fn build_list(sha256: &str, keccak256: &str) -> era_solc::installer::build_list::BuildList {
+    serde_json::from_value(serde_json::json!({
+        "builds": [
+            {
+                "path": "solc-linux-amd64-0.8.28-1.0.1",
+                "version": "0.8.28",
+                "longVersion": "0.8.28+commit.7893614a",
+                "sha256": sha256,
+                "keccak256": keccak256,
+                "urls": []
+            }
+        ],
+        "releases": {
+            "0.8.28": "solc-linux-amd64-0.8.28-1.0.1"
+        },
+        "latestRelease": "0.8.28"
+    }))
+    .expect("Always valid")
+}
+
+#[test]
+fn default() {
+    let list = build_list(SHA256_ABC, KECCAK256_ABC);
+    let build = list
+        .get("solc-linux-amd64-0.8.28-1.0.1")
+        .expect("Always exists");
+    assert_eq!(build.version, semver::Version::new(0, 8, 28));
+    build.verify(b"abc").expect("Verification failure");
+}
+
+#[test]
+fn missing_build() {
+    let list = build_list(SHA256_ABC, KECCAK256_ABC);
+    assert!(list.get("solc-macosx-arm64-0.8.28-1.0.1").is_none());
+}
+
+#[test]
+fn sha256_mismatch() {
+    let list = build_list(SHA256_ABC, KECCAK256_ABC);
+    let build = list
+        .get("solc-linux-amd64-0.8.28-1.0.1")
+        .expect("Always exists");
+    let error = build
+        .verify(b"abd")
+        .expect_err("Tampered download accepted");
+    assert!(error.to_string().contains("SHA-256 checksum mismatch"));
+}
+
+#[test]
+fn keccak256_mismatch() {
+    let list = build_list(SHA256_ABC, "0x00");
+    let build = list
+        .get("solc-linux-amd64-0.8.28-1.0.1")
+        .expect("Always exists");
+    let error = build
+        .verify(b"abc")
+        .expect_err("Tampered download accepted");
+    assert!(error.to_string().contains("Keccak-256 checksum mismatch"));
+}
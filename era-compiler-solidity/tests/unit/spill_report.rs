@@ -0,0 +1,82 @@
+//!
+//! Unit tests for the register allocator spill report.
+//!
+
+use tempfile::TempDir;
+
+/// The LLVM optimization remarks with function-wide and per-loop spill statistics.
+pub const REMARKS: &str = r#"--- !Missed
+Pass:            regalloc
+Name:            SpillReloadCopies
+Function:        fun_transfer_42
+Args:
+  - NumSpills:       '3'
+  - String:          ' spills '
+  - NumReloads:      '5'
+  - String:          ' reloads '
+  - String:          generated in function
+...
+--- !Missed
+Pass:            regalloc
+Name:            SpillReloadCopies
+Function:        fun_transfer_42
+Args:
+  - NumSpills:       '2'
+  - String:          ' spills '
+  - NumReloads:      '2'
+  - String:          ' reloads '
+  - String:          generated in loop
+...
+--- !Missed
+Pass:            regalloc
+Name:            SpillReloadCopies
+Function:        __entry
+Args:
+  - NumSpills:       '1'
+  - String:          ' spills '
+  - String:          generated in function
+...
+--- !Missed
+Pass:            regalloc
+Name:            SpillReloadCopies
+Function:        __entry
+Args:
+  - NumReloads:      '4'
+  - String:          ' reloads '
+  - String:          generated in function
+...
+--- !Analysis
+Pass:            asm-printer
+Name:            InstructionCount
+Function:        fun_transfer_42
+Args:
+  - NumInstructions: '120'
+...
+"#;
+
+#[test]
+fn default() {
+    let tmp_dir = TempDir::with_prefix("spill_report").expect("Always valid");
+    let remarks_path = tmp_dir.path().join("remarks.yaml");
+    std::fs::write(remarks_path.as_path(), REMARKS).expect("Always valid");
+
+    let report = era_compiler_solidity::SpillReport::try_from_remarks(remarks_path.as_path())
+        .expect("Always valid");
+    assert_eq!(report.functions.len(), 2);
+    assert_eq!(
+        report.functions.get("fun_transfer_42"),
+        Some(&era_compiler_solidity::spill_report::FunctionSpills {
+            spills: 3,
+            reloads: 5,
+        }),
+        "Per-loop statistics must not be counted"
+    );
+    assert_eq!(
+        report.functions.get("__entry"),
+        Some(&era_compiler_solidity::spill_report::FunctionSpills {
+            spills: 1,
+            reloads: 4,
+        }),
+        "Statistics of equally named functions must be summed up"
+    );
+}
@@ -2,8 +2,12 @@
 //! Unit tests for standard JSON for all supported languages.
 //!
 
+use std::num::NonZeroUsize;
 use std::path::PathBuf;
 
+use era_solc::CollectableError;
+use test_case::test_case;
+
 #[test]
 fn standard_json_yul_solc() {
     let solc_input = era_solc::StandardJsonInput::try_from(Some(
@@ -267,3 +271,200 @@ fn standard_json_eravm_assembly_urls() {
         .object
         .is_empty())
 }
+
+#[test]
+fn eravm_settings() {
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(
+        PathBuf::from(crate::common::TEST_SOLIDITY_STANDARD_JSON_ZKSOLC_ERAVM_PATH).as_path(),
+    ))
+    .expect("Standard JSON reading error");
+
+    assert!(!solc_input.settings.enable_eravm_extensions);
+    assert!(matches!(
+        solc_input.settings.metadata.hash_type,
+        era_compiler_common::HashType::None
+    ));
+    assert!(solc_input
+        .settings
+        .output_selection
+        .contains(&era_solc::StandardJsonInputSelector::EraVMAssembly));
+    assert!(
+        solc_input
+            .settings
+            .optimizer
+            .fallback_to_optimizing_for_size
+    );
+}
+
+#[test]
+fn eravm_settings_bytecode_padding_disabled() {
+    let error = era_solc::StandardJsonInput::try_from_str(
+        r#"{
+            "language": "Solidity",
+            "sources": { "Test.sol": { "content": "contract Test {}" } },
+            "settings": { "eravm": { "bytecodePadding": false } }
+        }"#,
+    )
+    .expect_err("Always invalid");
+
+    assert_eq!(
+        error.to_string(),
+        "The `eravm.bytecodePadding` setting cannot be disabled, as EraVM requires the bytecode size to be an odd number of 32-byte words."
+    );
+}
+
+#[test_case(r#"{}"#, None ; "default")]
+#[test_case(r#"{ "threads": 4 }"#, Some(4) ; "threads")]
+#[test_case(r#"{ "enabled": false }"#, Some(1) ; "disabled")]
+#[test_case(r#"{ "enabled": false, "threads": 4 }"#, Some(1) ; "disabled_threads")]
+fn parallel_threads(parallel: &str, expected: Option<usize>) {
+    let parallel: era_solc::StandardJsonInputParallel =
+        serde_json::from_str(parallel).expect("Always valid");
+
+    assert_eq!(parallel.threads(), expected);
+}
+
+#[test]
+fn parallel_threads_zero() {
+    let error = era_solc::StandardJsonInput::try_from_str(
+        r#"{
+            "language": "Solidity",
+            "sources": { "Test.sol": { "content": "contract Test {}" } },
+            "settings": { "parallel": { "threads": 0 } }
+        }"#,
+    )
+    .expect_err("Always invalid");
+
+    assert!(
+        error
+            .to_string()
+            .contains("invalid value: integer `0`, expected a nonzero usize"),
+        "Unexpected error: {error}"
+    );
+}
+
+#[test_case(true, 3, 3 ; "enabled")]
+#[test_case(false, 3, 1 ; "disabled")]
+fn parallel_install(enabled: bool, threads: usize, expected: usize) {
+    let parallel = era_solc::StandardJsonInputParallel {
+        enabled,
+        threads: NonZeroUsize::new(threads),
+    };
+
+    let threads = parallel
+        .install(rayon::current_num_threads)
+        .expect("Always valid");
+    assert_eq!(threads, expected);
+}
+
+#[test]
+fn compile_solidity() {
+    crate::common::setup().expect("Setup failure");
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(
+        PathBuf::from(crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH).as_path(),
+    ))
+    .expect("Standard JSON reading error");
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let solc_output = era_compiler_solidity::standard_json::compile(
+        solc_input,
+        Some(&solc_compiler),
+        era_compiler_solidity::standard_json::Options::default(),
+        &mut vec![],
+    )
+    .expect("Test failure");
+
+    assert!(!solc_output
+        .contracts
+        .get("A")
+        .expect("The `A` source is missing")
+        .get("C")
+        .expect("The `C` contract is missing")
+        .evm
+        .as_ref()
+        .expect("The `evm` field is missing")
+        .bytecode
+        .as_ref()
+        .expect("The `bytecode` field is missing")
+        .object
+        .is_empty())
+}
+
+#[test]
+fn compile_solidity_invalid() {
+    crate::common::setup().expect("Setup failure");
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+
+    let solc_input = era_solc::StandardJsonInput::try_from(Some(
+        PathBuf::from(crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_INVALID_PATH).as_path(),
+    ))
+    .expect("Standard JSON reading error");
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let solc_output = era_compiler_solidity::standard_json::compile(
+        solc_input,
+        Some(&solc_compiler),
+        era_compiler_solidity::standard_json::Options::default(),
+        &mut vec![],
+    )
+    .expect("Test failure");
+
+    assert!(solc_output.has_errors());
+    assert!(solc_output.contracts.is_empty());
+}
+
+#[test]
+fn compile_solidity_in_process_matches_subprocesses() {
+    crate::common::setup().expect("Setup failure");
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let bytecodes = [false, true].map(|subprocesses| {
+        let solc_input = era_solc::StandardJsonInput::try_from(Some(
+            PathBuf::from(crate::common::TEST_SOLIDITY_STANDARD_JSON_SOLC_PATH).as_path(),
+        ))
+        .expect("Standard JSON reading error");
+        let options = era_compiler_solidity::standard_json::Options {
+            build: era_compiler_solidity::BuildConfig {
+                subprocesses,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let solc_output = era_compiler_solidity::standard_json::compile(
+            solc_input,
+            Some(&solc_compiler),
+            options,
+            &mut vec![],
+        )
+        .expect("Test failure");
+        assert!(!solc_output.has_errors());
+
+        solc_output
+            .contracts
+            .get("A")
+            .expect("The `A` source is missing")
+            .get("C")
+            .expect("The `C` contract is missing")
+            .evm
+            .as_ref()
+            .expect("The `evm` field is missing")
+            .bytecode
+            .as_ref()
+            .expect("The `bytecode` field is missing")
+            .object
+            .clone()
+    });
+
+    assert!(!bytecodes[0].is_empty());
+    assert_eq!(bytecodes[0], bytecodes[1]);
+}
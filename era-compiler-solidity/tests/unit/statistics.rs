@@ -0,0 +1,156 @@
+//!
+//! Unit tests for the output message statistics.
+//!
+
+///
+/// Returns a message with the `code` located in the source at `path`.
+///
+fn message(is_error: bool, code: &str, path: Option<&str>) -> era_solc::StandardJsonOutputError {
+    let source_location =
+        path.map(|path| era_solc::StandardJsonOutputErrorSourceLocation::new(path.to_owned()));
+    let mut message = if is_error {
+        era_solc::StandardJsonOutputError::new_error("Test", source_location, None)
+    } else {
+        era_solc::StandardJsonOutputError::new_warning("Test", source_location, None)
+    };
+    message.error_code = Some(code.to_owned());
+    message
+}
+
+#[test]
+fn default() {
+    let messages = vec![
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_TX_ORIGIN,
+            Some("A.sol"),
+        ),
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_TX_ORIGIN,
+            Some("B.sol"),
+        ),
+        message(
+            true,
+            era_solc::StandardJsonOutputError::CODE_SEND_TRANSFER,
+            Some("A.sol"),
+        ),
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_ABI_CODER_V1,
+            None,
+        ),
+        era_solc::StandardJsonOutputError::new("Info", "Test", None, None),
+    ];
+
+    let statistics = era_solc::StandardJsonOutputStatistics::new(messages.as_slice());
+    assert_eq!(statistics.total.errors, 1);
+    assert_eq!(statistics.total.warnings, 3);
+    assert_eq!(
+        statistics.codes[era_solc::StandardJsonOutputError::CODE_TX_ORIGIN].warnings,
+        2
+    );
+    assert_eq!(
+        statistics.codes[era_solc::StandardJsonOutputError::CODE_SEND_TRANSFER].errors,
+        1
+    );
+    assert_eq!(statistics.codes.len(), 3);
+    assert_eq!(
+        statistics.sources["A.sol"],
+        era_solc::StandardJsonOutputStatisticsCounts {
+            errors: 1,
+            warnings: 1,
+        }
+    );
+    assert_eq!(statistics.sources["B.sol"].warnings, 1);
+    assert_eq!(statistics.sources.len(), 2);
+}
+
+#[test]
+fn trend() {
+    let previous = era_solc::StandardJsonOutputStatistics::new(&[
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_TX_ORIGIN,
+            Some("A.sol"),
+        ),
+        message(
+            true,
+            era_solc::StandardJsonOutputError::CODE_SEND_TRANSFER,
+            Some("B.sol"),
+        ),
+    ]);
+    let current = era_solc::StandardJsonOutputStatistics::new(&[
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_TX_ORIGIN,
+            Some("A.sol"),
+        ),
+        message(
+            false,
+            era_solc::StandardJsonOutputError::CODE_CREATION_CODE,
+            Some("C.sol"),
+        ),
+    ]);
+
+    let trend = current.trend(&previous);
+    assert_eq!(
+        trend.total,
+        era_solc::StandardJsonOutputStatisticsDelta {
+            errors: -1,
+            warnings: 1,
+        }
+    );
+    assert!(!trend
+        .codes
+        .contains_key(era_solc::StandardJsonOutputError::CODE_TX_ORIGIN));
+    assert_eq!(
+        trend.codes[era_solc::StandardJsonOutputError::CODE_SEND_TRANSFER].errors,
+        -1
+    );
+    assert_eq!(
+        trend.codes[era_solc::StandardJsonOutputError::CODE_CREATION_CODE].warnings,
+        1
+    );
+    assert!(!trend.sources.contains_key("A.sol"));
+    assert_eq!(trend.sources["B.sol"].errors, -1);
+    assert_eq!(trend.sources["C.sol"].warnings, 1);
+    assert!(current.trend(&current).total.is_zero());
+}
+
+#[test]
+fn consolidated_report() {
+    let mut tx_origin = message(
+        false,
+        era_solc::StandardJsonOutputError::CODE_TX_ORIGIN,
+        Some("A.sol"),
+    );
+    tx_origin.warning_type = Some(era_solc::StandardJsonInputWarningType::TxOrigin);
+    let mut creation_code = message(
+        false,
+        era_solc::StandardJsonOutputError::CODE_CREATION_CODE,
+        Some("B.sol"),
+    );
+    creation_code.warning_type = Some(era_solc::StandardJsonInputWarningType::CreationCode);
+    let reports = era_solc::StandardJsonOutputError::new_unsupported_feature_reports(
+        "A.sol:Test",
+        vec![tx_origin.clone(), tx_origin, creation_code],
+        &std::collections::BTreeMap::new(),
+    );
+    assert_eq!(reports.len(), 1);
+    assert!(reports[0].error_code.is_none());
+
+    let statistics = era_solc::StandardJsonOutputStatistics::new(reports.as_slice());
+    assert_eq!(statistics.total.warnings, 3);
+    assert_eq!(
+        statistics.codes[era_solc::StandardJsonOutputError::CODE_TX_ORIGIN].warnings,
+        2
+    );
+    assert_eq!(
+        statistics.codes[era_solc::StandardJsonOutputError::CODE_CREATION_CODE].warnings,
+        1
+    );
+    assert!(!statistics.codes.contains_key("Warning"));
+    assert_eq!(statistics.sources["A.sol"].warnings, 2);
+    assert_eq!(statistics.sources["B.sol"].warnings, 1);
+}
@@ -0,0 +1,65 @@
+//!
+//! Unit tests for the temporary directory.
+//!
+
+use era_compiler_solidity::TempDir;
+use era_compiler_solidity::TempDirCleanupPolicy;
+
+#[test]
+fn environment_intact() -> anyhow::Result<()> {
+    let parent = tempfile::TempDir::with_prefix("temp_dir")?;
+    let environment_before: Vec<Option<std::ffi::OsString>> =
+        era_compiler_solidity::temp_dir::ENVIRONMENT_VARIABLES
+            .into_iter()
+            .map(std::env::var_os)
+            .collect();
+
+    let temp_dir = TempDir::try_new(Some(parent.path()), TempDirCleanupPolicy::AlwaysClean)?;
+    assert!(temp_dir.path().starts_with(parent.path()));
+
+    let environment_after: Vec<Option<std::ffi::OsString>> =
+        era_compiler_solidity::temp_dir::ENVIRONMENT_VARIABLES
+            .into_iter()
+            .map(std::env::var_os)
+            .collect();
+    assert_eq!(environment_after, environment_before);
+
+    temp_dir.finish(true)?;
+    Ok(())
+}
+
+#[test]
+fn keep_on_error_success() -> anyhow::Result<()> {
+    let parent = tempfile::TempDir::with_prefix("temp_dir")?;
+
+    let temp_dir = TempDir::try_new(Some(parent.path()), TempDirCleanupPolicy::KeepOnError)?;
+    let path = temp_dir.path().to_owned();
+    temp_dir.finish(true)?;
+
+    assert!(!path.exists(), "Temporary directory is not cleaned up");
+    Ok(())
+}
+
+#[test]
+fn keep_on_error_failure() -> anyhow::Result<()> {
+    let parent = tempfile::TempDir::with_prefix("temp_dir")?;
+
+    let temp_dir = TempDir::try_new(Some(parent.path()), TempDirCleanupPolicy::KeepOnError)?;
+    let path = temp_dir.path().to_owned();
+    temp_dir.finish(false)?;
+
+    assert!(path.exists(), "Temporary directory is not kept");
+    Ok(())
+}
+
+#[test]
+fn keep_on_error_dropped() -> anyhow::Result<()> {
+    let parent = tempfile::TempDir::with_prefix("temp_dir")?;
+
+    let temp_dir = TempDir::try_new(Some(parent.path()), TempDirCleanupPolicy::KeepOnError)?;
+    let path = temp_dir.path().to_owned();
+    drop(temp_dir);
+
+    assert!(path.exists(), "Temporary directory is not kept");
+    Ok(())
+}
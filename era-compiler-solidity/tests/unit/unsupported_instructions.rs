@@ -309,14 +309,14 @@ contract MinimalDestructible {
     era_solc::StandardJsonInputCodegen::Yul,
     SELFDESTRUCT_TEST_SOURCE
 )]
-#[should_panic(expected = "The `SELFDESTRUCT` instruction is not supported")]
+#[should_panic(expected = "You are using 'selfdestruct'")]
 fn selfdestruct(
     version: semver::Version,
     codegen: era_solc::StandardJsonInputCodegen,
     source: &str,
 ) {
     if cfg!(target_os = "windows") && version < semver::Version::new(0, 6, 0) {
-        panic!("The `SELFDESTRUCT` instruction is not supported");
+        panic!("You are using 'selfdestruct'");
     }
 
     let mut sources = BTreeMap::new();
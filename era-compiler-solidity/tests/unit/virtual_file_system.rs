@@ -0,0 +1,210 @@
+//!
+//! Unit tests for the in-memory virtual file system.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use era_solc::CollectableError;
+
+#[test]
+fn resolve() {
+    let file_system = era_compiler_solidity::VirtualFileSystem::new(BTreeMap::from([
+        ("contracts/A.sol".to_owned(), String::new()),
+        ("contracts/lib/B.sol".to_owned(), String::new()),
+        ("deps/oz/C.sol".to_owned(), String::new()),
+    ]));
+    let remappings = BTreeSet::from(["@oz/=deps/oz/".parse().expect("Always valid")]);
+
+    assert_eq!(
+        file_system.resolve("contracts/A.sol", "./lib/B.sol", &remappings),
+        Some("contracts/lib/B.sol".to_owned())
+    );
+    assert_eq!(
+        file_system.resolve("contracts/lib/B.sol", "../A.sol", &remappings),
+        Some("contracts/A.sol".to_owned())
+    );
+    assert_eq!(
+        file_system.resolve("contracts/A.sol", "@oz/C.sol", &remappings),
+        Some("deps/oz/C.sol".to_owned())
+    );
+    assert_eq!(
+        file_system.resolve("contracts/A.sol", "./C.sol", &remappings),
+        None
+    );
+}
+
+#[test]
+fn source_name_outside() {
+    let remappings = BTreeSet::new();
+
+    assert_eq!(
+        era_compiler_solidity::VirtualFileSystem::source_name("A.sol", "../lib/B.sol", &remappings),
+        "../lib/B.sol"
+    );
+    assert_eq!(
+        era_compiler_solidity::VirtualFileSystem::source_name(
+            "/project/A.sol",
+            "../../B.sol",
+            &remappings
+        ),
+        "/B.sol"
+    );
+}
+
+#[test]
+fn imports() {
+    let source = r#"
+// import "./Commented.sol";
+/* import "./Block.sol"; */
+import "./A.sol";
+import {B} from './B.sol';
+import * as C from "./C.sol";
+import"./D.sol";
+contract important { string constant imported = "./E.sol"; }
+"#;
+
+    assert_eq!(
+        era_compiler_solidity::VirtualFileSystem::imports(source),
+        vec!["./A.sol", "./B.sol", "./C.sol", "./D.sol"]
+    );
+}
+
+#[test]
+fn missing_import() {
+    let file_system = era_compiler_solidity::VirtualFileSystem::new(BTreeMap::from([(
+        "A.sol".to_owned(),
+        r#"import "./B.sol"; contract A {}"#.to_owned(),
+    )]));
+
+    let error = file_system
+        .try_into_sources(&BTreeSet::new())
+        .expect_err("Test failure");
+    assert_eq!(
+        error.to_string(),
+        "Source `A.sol` imports `./B.sol`, which is not found in the virtual file system."
+    );
+}
+
+#[test]
+fn missing_library() {
+    let file_system = era_compiler_solidity::VirtualFileSystem::new(BTreeMap::from([(
+        "A.sol".to_owned(),
+        "contract A {}".to_owned(),
+    )]));
+
+    let error = file_system
+        .try_into_standard_json_input(
+            &["lib/L.sol:L=0x0000000000000000000000000000000000001234".to_owned()],
+            BTreeSet::new(),
+            era_solc::StandardJsonInputOptimizer::default(),
+            None,
+            None,
+            false,
+            era_solc::StandardJsonInputSelection::default(),
+            era_solc::StandardJsonInputMetadata::default(),
+            vec![],
+            vec![],
+            vec![],
+            false,
+            false,
+        )
+        .expect_err("Test failure");
+    assert_eq!(
+        error.to_string(),
+        "Library source `lib/L.sol` is not found in the virtual file system."
+    );
+}
+
+#[test]
+fn compile() {
+    crate::common::setup().expect("Setup failure");
+    era_compiler_llvm_context::initialize_target(era_compiler_common::Target::EraVM);
+
+    let file_system = era_compiler_solidity::VirtualFileSystem::new(BTreeMap::from([
+        (
+            "virtual/contracts/A.sol".to_owned(),
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.4.16;
+
+import "./lib/B.sol";
+
+contract A is B {}
+"#
+            .to_owned(),
+        ),
+        (
+            "virtual/contracts/lib/B.sol".to_owned(),
+            r#"
+// SPDX-License-Identifier: MIT
+pragma solidity >=0.4.16;
+
+contract B {}
+"#
+            .to_owned(),
+        ),
+    ]));
+    for name in file_system.files.keys() {
+        assert!(
+            !std::path::Path::new(name).exists(),
+            "The source `{name}` must not exist on disk"
+        );
+    }
+
+    let solc_input = file_system
+        .try_into_standard_json_input(
+            &[],
+            BTreeSet::new(),
+            era_solc::StandardJsonInputOptimizer::default(),
+            Some(era_solc::StandardJsonInputCodegen::Yul),
+            None,
+            false,
+            era_solc::StandardJsonInputSelection::new_required(
+                era_solc::StandardJsonInputCodegen::Yul,
+            ),
+            era_solc::StandardJsonInputMetadata::default(),
+            vec![],
+            vec![],
+            vec![],
+            false,
+            false,
+        )
+        .expect("Test failure");
+    assert_eq!(
+        solc_input.sources.keys().collect::<Vec<&String>>(),
+        vec!["virtual/contracts/A.sol", "virtual/contracts/lib/B.sol"]
+    );
+    let solc_compiler =
+        crate::common::get_solc_compiler(&era_solc::Compiler::LAST_SUPPORTED_VERSION)
+            .expect("`solc` initialization error");
+
+    let solc_output = era_compiler_solidity::standard_json::compile(
+        solc_input,
+        Some(&solc_compiler),
+        era_compiler_solidity::standard_json::Options::default(),
+        &mut vec![],
+    )
+    .expect("Test failure");
+
+    assert!(!solc_output.has_errors());
+    for (path, name) in [
+        ("virtual/contracts/A.sol", "A"),
+        ("virtual/contracts/lib/B.sol", "B"),
+    ] {
+        assert!(!solc_output
+            .contracts
+            .get(path)
+            .unwrap_or_else(|| panic!("The `{path}` source is missing"))
+            .get(name)
+            .unwrap_or_else(|| panic!("The `{name}` contract is missing"))
+            .evm
+            .as_ref()
+            .expect("The `evm` field is missing")
+            .bytecode
+            .as_ref()
+            .expect("The `bytecode` field is missing")
+            .object
+            .is_empty());
+    }
+}
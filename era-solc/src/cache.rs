@@ -0,0 +1,120 @@
+//!
+//! The on-disk cache directory.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::SystemTime;
+
+/// The default maximum number of entries kept in a cache directory.
+pub const MAX_ENTRIES_DEFAULT: usize = 4096;
+
+/// The cache entry file extension.
+pub const ENTRY_EXTENSION: &str = "json";
+
+///
+/// The on-disk cache directory, shared by the compilation caches.
+///
+/// Entries are JSON files named after their keys. All operations are best-effort, so I/O errors
+/// are ignored, and the cached work is simply repeated on the next run.
+///
+#[derive(Debug, Clone)]
+pub struct Directory {
+    /// The cache directory path.
+    path: PathBuf,
+    /// The maximum number of entries kept after eviction.
+    max_entries: usize,
+}
+
+impl Directory {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(path: &Path, max_entries: usize) -> Self {
+        Self {
+            path: path.to_owned(),
+            max_entries,
+        }
+    }
+
+    ///
+    /// Returns the entry with `key`, if it exists and is valid.
+    ///
+    /// The entry modification time is updated, so recently used entries survive the eviction.
+    ///
+    pub fn get<T>(&self, key: &str) -> Option<T>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let entry_path = self.entry_path(key);
+        let entry = std::fs::read(entry_path.as_path()).ok()?;
+        let entry = serde_json::from_slice(entry.as_slice()).ok()?;
+        if let Ok(file) = std::fs::File::options()
+            .write(true)
+            .open(entry_path.as_path())
+        {
+            let _ = file.set_modified(SystemTime::now());
+        }
+        Some(entry)
+    }
+
+    ///
+    /// Writes the entry with `key`.
+    ///
+    /// The entry is written to a temporary file first, so concurrent runs never observe
+    /// a partially written entry.
+    ///
+    pub fn insert<T>(&self, key: &str, entry: &T)
+    where
+        T: serde::Serialize,
+    {
+        if std::fs::create_dir_all(self.path.as_path()).is_err() {
+            return;
+        }
+        let entry = serde_json::to_vec(entry).expect("Always valid");
+        let entry_path = self.entry_path(key);
+        let temporary_path = entry_path.with_extension(format!("{}.tmp", std::process::id()));
+        if std::fs::write(temporary_path.as_path(), entry).is_err()
+            || std::fs::rename(temporary_path.as_path(), entry_path).is_err()
+        {
+            let _ = std::fs::remove_file(temporary_path);
+        }
+    }
+
+    ///
+    /// Removes the least recently used entries exceeding the maximum number of entries.
+    ///
+    pub fn evict(&self) {
+        let Ok(entries) = std::fs::read_dir(self.path.as_path()) else {
+            return;
+        };
+        let mut entries: Vec<(SystemTime, PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.extension()
+                    .is_some_and(|extension| extension == ENTRY_EXTENSION)
+            })
+            .filter_map(|path| {
+                let modified = std::fs::metadata(path.as_path()).ok()?.modified().ok()?;
+                Some((modified, path))
+            })
+            .collect();
+        if entries.len() <= self.max_entries {
+            return;
+        }
+
+        entries.sort();
+        let excess = entries.len() - self.max_entries;
+        for (_modified, path) in entries.into_iter().take(excess) {
+            let _ = std::fs::remove_file(path);
+        }
+    }
+
+    ///
+    /// Returns the path of the entry file.
+    ///
+    fn entry_path(&self, key: &str) -> PathBuf {
+        self.path.join(format!("{key}.{ENTRY_EXTENSION}"))
+    }
+}
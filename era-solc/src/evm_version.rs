@@ -0,0 +1,66 @@
+//!
+//! The EVM version availability of the assembly builtins.
+//!
+
+/// The EVM versions known to `solc`, from the oldest to the newest.
+pub const EVM_VERSIONS: [&str; 13] = [
+    "homestead",
+    "tangerineWhistle",
+    "spuriousDragon",
+    "byzantium",
+    "constantinople",
+    "petersburg",
+    "istanbul",
+    "berlin",
+    "london",
+    "paris",
+    "shanghai",
+    "cancun",
+    "prague",
+];
+
+/// The assembly builtins introduced after the first EVM version, with the version introducing them.
+pub const GATED_BUILTINS: [(&str, &str); 18] = [
+    ("returndatasize", "byzantium"),
+    ("returndatacopy", "byzantium"),
+    ("staticcall", "byzantium"),
+    ("revert", "byzantium"),
+    ("shl", "constantinople"),
+    ("shr", "constantinople"),
+    ("sar", "constantinople"),
+    ("create2", "constantinople"),
+    ("extcodehash", "constantinople"),
+    ("chainid", "istanbul"),
+    ("selfbalance", "istanbul"),
+    ("basefee", "london"),
+    ("prevrandao", "paris"),
+    ("mcopy", "cancun"),
+    ("tload", "cancun"),
+    ("tstore", "cancun"),
+    ("blobhash", "cancun"),
+    ("blobbasefee", "cancun"),
+];
+
+///
+/// Returns the EVM version introducing the `builtin`, if it is not available since the first one.
+///
+pub fn builtin_minimum_version(builtin: &str) -> Option<&'static str> {
+    GATED_BUILTINS
+        .iter()
+        .find(|(name, _)| *name == builtin)
+        .map(|(_, version)| *version)
+}
+
+///
+/// Whether the EVM version `version` is older than `other`.
+///
+/// Returns `None` if any of the versions is unknown, so newer versions are never rejected.
+///
+pub fn is_older(version: &str, other: &str) -> Option<bool> {
+    let position = |version: &str| {
+        EVM_VERSIONS
+            .iter()
+            .position(|known| known.eq_ignore_ascii_case(version))
+    };
+    Some(position(version)? < position(other)?)
+}
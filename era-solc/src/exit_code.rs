@@ -0,0 +1,168 @@
+//!
+//! The process exit code.
+//!
+
+use crate::standard_json::output::error::Error;
+
+///
+/// The process exit code.
+///
+/// The variants are ordered by priority, so the most severe failure class wins
+/// if several classes of errors have been collected. Internal compiler errors are the most
+/// severe ones, so they outrank the build timeout despite its greater numeric code.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+pub enum ExitCode {
+    /// The compilation has succeeded.
+    Success,
+    /// The arguments, input files, or settings are invalid.
+    InputError,
+    /// `solc` has reported compilation errors.
+    SolcError,
+    /// The translation to the target bytecode has failed.
+    TranslationError,
+    /// A resource limit has been violated, e.g. a compilation subprocess has been killed.
+    ResourceLimit,
+    /// The build timeout has been exceeded, so `solc` has been killed, or some contracts have been skipped.
+    BuildTimeout,
+    /// An internal compiler error has occurred, e.g. a panic.
+    InternalError,
+}
+
+impl ExitCode {
+    /// The exit code of a panicking Rust process.
+    pub const PANIC_CODE: i32 = 101;
+
+    ///
+    /// Returns the numeric process exit code.
+    ///
+    pub fn code(&self) -> i32 {
+        match self {
+            Self::Success => era_compiler_common::EXIT_CODE_SUCCESS,
+            Self::InputError => era_compiler_common::EXIT_CODE_FAILURE,
+            Self::SolcError => 2,
+            Self::TranslationError => 3,
+            Self::ResourceLimit => 4,
+            Self::BuildTimeout => 6,
+            Self::InternalError => 5,
+        }
+    }
+
+    ///
+    /// Exits the process with the code.
+    ///
+    pub fn exit(&self) -> ! {
+        std::process::exit(self.code())
+    }
+}
+
+impl From<&anyhow::Error> for ExitCode {
+    ///
+    /// Returns the exit code attached to the `error` or any of its causes.
+    ///
+    /// The expected failures are classified where they are raised, so an error without
+    /// the exit code is considered an internal one.
+    ///
+    fn from(error: &anyhow::Error) -> Self {
+        error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ExitCodeError>())
+            .map(|error| error.exit_code)
+            .unwrap_or(Self::InternalError)
+    }
+}
+
+///
+/// Attaches the exit code to the errors not classified yet.
+///
+pub trait WithExitCode<T> {
+    ///
+    /// Attaches the `exit_code` to the error, unless it already has one.
+    ///
+    fn with_exit_code(self, exit_code: ExitCode) -> anyhow::Result<T>;
+}
+
+impl<T, E> WithExitCode<T> for Result<T, E>
+where
+    E: Into<anyhow::Error>,
+{
+    fn with_exit_code(self, exit_code: ExitCode) -> anyhow::Result<T> {
+        self.map_err(|error| {
+            let error = error.into();
+            if error
+                .chain()
+                .any(|cause| cause.downcast_ref::<ExitCodeError>().is_some())
+            {
+                return error;
+            }
+            ExitCodeError::new(exit_code, error.to_string())
+                .with_source(error)
+                .into()
+        })
+    }
+}
+
+///
+/// The error bearing the exit code of its failure class.
+///
+/// Allows the exit code to survive the `anyhow` error conversions on the way to the entry point.
+/// Errors without it are considered internal errors.
+///
+#[derive(Debug)]
+pub struct ExitCodeError {
+    /// The exit code.
+    pub exit_code: ExitCode,
+    /// The error message.
+    pub message: String,
+    /// The errors the message consists of, if they are known.
+    pub errors: Vec<Error>,
+    /// The original error, if the exit code has been attached to an existing one.
+    ///
+    /// The error is transparent, so its message is the one of the original error,
+    /// and the causes of the original error are its causes.
+    pub source: Option<anyhow::Error>,
+}
+
+impl ExitCodeError {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(exit_code: ExitCode, message: String) -> Self {
+        Self {
+            exit_code,
+            message,
+            errors: Vec::new(),
+            source: None,
+        }
+    }
+
+    ///
+    /// Sets the original error, keeping its chain of causes.
+    ///
+    pub fn with_source(mut self, source: anyhow::Error) -> Self {
+        self.source = Some(source);
+        self
+    }
+
+    ///
+    /// Sets the errors the message consists of.
+    ///
+    pub fn with_errors(mut self, errors: Vec<Error>) -> Self {
+        self.errors = errors;
+        self
+    }
+}
+
+impl std::fmt::Display for ExitCodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ExitCodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        self.source.as_ref().and_then(|source| source.source())
+    }
+}
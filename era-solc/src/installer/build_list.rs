@@ -0,0 +1,65 @@
+//!
+//! The `solc` release build list.
+//!
+
+use sha2::Digest;
+
+///
+/// The `solc` release build list, in the format of the `list.json` files of `solc-bin`.
+///
+/// Only the fields used for the download verification are parsed.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct BuildList {
+    /// The release builds.
+    pub builds: Vec<Build>,
+}
+
+///
+/// The `solc` release build.
+///
+#[derive(Debug, Clone, serde::Deserialize)]
+pub struct Build {
+    /// The executable file name.
+    pub path: String,
+    /// The `solc` version.
+    pub version: semver::Version,
+    /// The SHA-256 checksum of the executable, prefixed with `0x`.
+    pub sha256: String,
+    /// The Keccak-256 checksum of the executable, prefixed with `0x`.
+    pub keccak256: String,
+}
+
+impl BuildList {
+    ///
+    /// Returns the build with the executable file name `path`.
+    ///
+    pub fn get(&self, path: &str) -> Option<&Build> {
+        self.builds.iter().find(|build| build.path == path)
+    }
+}
+
+impl Build {
+    ///
+    /// Checks the executable `bytes` against the checksums of the build.
+    ///
+    /// Both checksums must be present and match.
+    ///
+    pub fn verify(&self, bytes: &[u8]) -> anyhow::Result<()> {
+        let sha256 = hex::encode(sha2::Sha256::digest(bytes));
+        let keccak256 = hex::encode(era_compiler_common::Hash::keccak256(bytes).as_bytes());
+        for (name, expected, actual) in [
+            ("SHA-256", self.sha256.as_str(), sha256.as_str()),
+            ("Keccak-256", self.keccak256.as_str(), keccak256.as_str()),
+        ] {
+            let expected = expected.strip_prefix("0x").unwrap_or(expected);
+            if !expected.eq_ignore_ascii_case(actual) {
+                anyhow::bail!(
+                    "`{}` {name} checksum mismatch: the build list has {expected}, downloaded {actual}.",
+                    self.path
+                );
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,339 @@
+//!
+//! The `solc` executable installer.
+//!
+
+pub mod build_list;
+
+use std::io::Write;
+use std::path::Path;
+use std::path::PathBuf;
+use std::time::Duration;
+
+use sha2::Digest;
+
+use crate::solc::Compiler;
+
+use self::build_list::BuildList;
+
+///
+/// The `solc` executable installer.
+///
+/// Downloads the ZKsync fork of `solc` into a local directory, and resolves the installed
+/// executables by version. Each download is verified against the checksums of the release build
+/// list before it is executed, and the verified SHA-256 checksum is stored next to the executable
+/// to detect its modification.
+///
+#[derive(Debug, Clone)]
+pub struct Installer {
+    /// The directory with the installed executables.
+    pub directory: PathBuf,
+    /// Whether the downloads are forbidden.
+    pub is_offline: bool,
+}
+
+impl Installer {
+    /// The environment variable overriding the installation directory.
+    pub const DIRECTORY_VARIABLE: &'static str = "ZKSOLC_SOLC_DIR";
+
+    /// The ZKsync revision of the installed executables.
+    pub const REVISION: semver::Version = semver::Version::new(1, 0, 1);
+
+    /// The release download URL prefix of the ZKsync fork of `solc`.
+    pub const DOWNLOAD_URL: &'static str =
+        "https://github.com/matter-labs/era-solidity/releases/download";
+
+    /// The release build list file name.
+    pub const BUILD_LIST_FILE_NAME: &'static str = "list.json";
+
+    /// The download timeout.
+    pub const DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(300);
+
+    ///
+    /// A shortcut constructor with the default installation directory.
+    ///
+    /// The directory is taken from the `ZKSOLC_SOLC_DIR` environment variable, and defaults to
+    /// the `zksolc/solc` subdirectory of the user cache directory.
+    ///
+    pub fn try_new(is_offline: bool) -> anyhow::Result<Self> {
+        let directory = match std::env::var_os(Self::DIRECTORY_VARIABLE) {
+            Some(directory) => PathBuf::from(directory),
+            None => Self::cache_directory()
+                .ok_or_else(|| {
+                    anyhow::anyhow!(
+                        "The user cache directory cannot be determined. Please set the `{}` environment variable.",
+                        Self::DIRECTORY_VARIABLE
+                    )
+                })?
+                .join("zksolc")
+                .join(Compiler::DEFAULT_EXECUTABLE_NAME),
+        };
+        Ok(Self {
+            directory,
+            is_offline,
+        })
+    }
+
+    ///
+    /// Downloads and installs the `solc` executable of the specified `version`.
+    ///
+    /// The download is rejected if its SHA-256 or Keccak-256 checksum does not match the release
+    /// build list, or its SHA-256 checksum does not match `sha256`, if the latter is provided.
+    /// The verified executable is also checked to report the requested version.
+    /// An existing installation of the same version is replaced.
+    ///
+    pub fn install(
+        &self,
+        version: &semver::Version,
+        sha256: Option<&str>,
+    ) -> anyhow::Result<Compiler> {
+        if self.is_offline {
+            anyhow::bail!("`solc` v{version} cannot be downloaded in offline mode.");
+        }
+        Self::check_version(version)?;
+
+        let client = reqwest::blocking::ClientBuilder::new()
+            .connect_timeout(Duration::from_secs(60))
+            .timeout(Self::DOWNLOAD_TIMEOUT)
+            .build()
+            .map_err(|error| anyhow::anyhow!("HTTP client initialization: {error}"))?;
+        let release_url = format!(
+            "{}/{version}-{revision}",
+            Self::DOWNLOAD_URL,
+            revision = Self::REVISION,
+        );
+
+        let list_url = format!("{release_url}/{}", Self::BUILD_LIST_FILE_NAME);
+        let list: BuildList = serde_json::from_slice(
+            Self::download(&client, list_url.as_str())
+                .map_err(|error| {
+                    anyhow::anyhow!("`solc` v{version} build list downloading: {error}")
+                })?
+                .as_slice(),
+        )
+        .map_err(|error| anyhow::anyhow!("`solc` v{version} build list parsing: {error}"))?;
+        let download_name = format!(
+            "{name}-{platform}-{version}-{revision}{suffix}",
+            name = Compiler::DEFAULT_EXECUTABLE_NAME,
+            platform = Self::platform()?,
+            revision = Self::REVISION,
+            suffix = std::env::consts::EXE_SUFFIX,
+        );
+        let build = list
+            .get(download_name.as_str())
+            .filter(|build| &build.version == version)
+            .ok_or_else(|| {
+                anyhow::anyhow!("`solc` v{version} build `{download_name}` is missing from the build list at {list_url}.")
+            })?;
+
+        let url = format!("{release_url}/{download_name}");
+        let bytes = Self::download(&client, url.as_str())
+            .map_err(|error| anyhow::anyhow!("`solc` v{version} downloading: {error}"))?;
+        build
+            .verify(bytes.as_slice())
+            .map_err(|error| anyhow::anyhow!("`solc` v{version} verification: {error}"))?;
+        let checksum = hex::encode(sha2::Sha256::digest(bytes.as_slice()));
+        if let Some(expected) = sha256 {
+            if !checksum.eq_ignore_ascii_case(expected) {
+                anyhow::bail!(
+                    "`solc` v{version} checksum mismatch: expected {expected}, downloaded {checksum}."
+                );
+            }
+        }
+
+        std::fs::create_dir_all(self.directory.as_path())
+            .map_err(|error| anyhow::anyhow!("Directory {:?} creating: {error}", self.directory))?;
+        let download_path = self
+            .directory
+            .join(format!(".{}.download", Self::file_name(version)));
+        Self::write_executable(download_path.as_path(), bytes.as_slice())?;
+        let validation = Compiler::try_from_path(download_path.to_string_lossy().as_ref())
+            .and_then(|compiler| {
+                if &compiler.version.default != version {
+                    anyhow::bail!(
+                        "`solc` v{version} download reports version {}.",
+                        compiler.version.default
+                    );
+                }
+                Ok(())
+            });
+        if let Err(error) = validation {
+            let _ = std::fs::remove_file(download_path.as_path());
+            return Err(error);
+        }
+
+        let path = self.path(version);
+        std::fs::rename(download_path.as_path(), path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+        let checksum_path = Self::checksum_path(path.as_path());
+        std::fs::write(checksum_path.as_path(), checksum)
+            .map_err(|error| anyhow::anyhow!("File {checksum_path:?} writing: {error}"))?;
+        Compiler::try_from_path(path.to_string_lossy().as_ref())
+    }
+
+    ///
+    /// Returns the installed `solc` executable of the specified `version`, installing it if it is
+    /// missing and downloads are allowed.
+    ///
+    pub fn resolve(&self, version: &semver::Version) -> anyhow::Result<Compiler> {
+        let path = self.path(version);
+        if !path.exists() {
+            if self.is_offline {
+                anyhow::bail!(
+                    "`solc` v{version} is not installed in {:?}, and cannot be downloaded in offline mode. Please install it with `zksolc solc install {version}`.",
+                    self.directory
+                );
+            }
+            return self.install(version, None);
+        }
+
+        let checksum_path = Self::checksum_path(path.as_path());
+        let expected = std::fs::read_to_string(checksum_path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {checksum_path:?} reading: {error}"))?;
+        let bytes = std::fs::read(path.as_path())
+            .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+        let checksum = hex::encode(sha2::Sha256::digest(bytes.as_slice()));
+        if checksum != expected.trim() {
+            anyhow::bail!(
+                "`solc` v{version} at {path:?} has been modified since its installation. Please reinstall it with `zksolc solc install {version}`."
+            );
+        }
+        Compiler::try_from_path(path.to_string_lossy().as_ref())
+    }
+
+    ///
+    /// Returns the versions of the installed executables in ascending order.
+    ///
+    /// The executables without a checksum file, e.g. interrupted downloads, are skipped.
+    ///
+    pub fn installed_versions(&self) -> Vec<semver::Version> {
+        let Ok(entries) = std::fs::read_dir(self.directory.as_path()) else {
+            return vec![];
+        };
+        let prefix = format!("{}-", Compiler::DEFAULT_EXECUTABLE_NAME);
+        let mut versions: Vec<semver::Version> = entries
+            .filter_map(Result::ok)
+            .filter_map(|entry| {
+                let file_name = entry.file_name().into_string().ok()?;
+                let version = file_name
+                    .strip_prefix(prefix.as_str())?
+                    .strip_suffix(std::env::consts::EXE_SUFFIX)?;
+                let version = semver::Version::parse(version).ok()?;
+                Self::checksum_path(entry.path().as_path())
+                    .exists()
+                    .then_some(version)
+            })
+            .collect();
+        versions.sort();
+        versions
+    }
+
+    ///
+    /// Returns the path to the installed executable of the specified `version`.
+    ///
+    pub fn path(&self, version: &semver::Version) -> PathBuf {
+        self.directory.join(Self::file_name(version))
+    }
+
+    ///
+    /// Returns the file name of the installed executable of the specified `version`.
+    ///
+    fn file_name(version: &semver::Version) -> String {
+        format!(
+            "{}-{version}{}",
+            Compiler::DEFAULT_EXECUTABLE_NAME,
+            std::env::consts::EXE_SUFFIX
+        )
+    }
+
+    ///
+    /// Returns the path to the checksum file of the executable at `path`.
+    ///
+    fn checksum_path(path: &Path) -> PathBuf {
+        let mut file_name = path.file_name().expect("Always exists").to_owned();
+        file_name.push(".sha256");
+        path.with_file_name(file_name)
+    }
+
+    ///
+    /// Checks if the `version` is supported by the current version of `zksolc`.
+    ///
+    fn check_version(version: &semver::Version) -> anyhow::Result<()> {
+        if version < &Compiler::FIRST_SUPPORTED_VERSION
+            || version > &Compiler::LAST_SUPPORTED_VERSION
+        {
+            anyhow::bail!(
+                "`solc` v{version} is not supported. The supported versions are from {} to {}.",
+                Compiler::FIRST_SUPPORTED_VERSION,
+                Compiler::LAST_SUPPORTED_VERSION,
+            );
+        }
+        Ok(())
+    }
+
+    ///
+    /// Returns the platform name used in the release executable names.
+    ///
+    fn platform() -> anyhow::Result<&'static str> {
+        Ok(
+            match (std::env::consts::OS, std::env::consts::ARCH) {
+                ("linux", "x86_64") => "linux-amd64",
+                ("linux", "aarch64") => "linux-arm64",
+                ("macos", "x86_64") => "macosx-amd64",
+                ("macos", "aarch64") => "macosx-arm64",
+                ("windows", "x86_64") => "windows-amd64",
+                (os, arch) => anyhow::bail!(
+                    "`solc` executables are not available for {os}-{arch}. Please provide one explicitly with the `--solc` option."
+                ),
+            },
+        )
+    }
+
+    ///
+    /// Returns the user cache directory.
+    ///
+    fn cache_directory() -> Option<PathBuf> {
+        if cfg!(target_os = "windows") {
+            return std::env::var_os("LOCALAPPDATA").map(PathBuf::from);
+        }
+        if cfg!(target_os = "macos") {
+            return std::env::var_os("HOME")
+                .map(|home| PathBuf::from(home).join("Library").join("Caches"));
+        }
+        std::env::var_os("XDG_CACHE_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".cache")))
+    }
+
+    ///
+    /// Downloads the file at `url`.
+    ///
+    fn download(client: &reqwest::blocking::Client, url: &str) -> anyhow::Result<Vec<u8>> {
+        client
+            .get(url)
+            .send()
+            .and_then(|response| response.error_for_status())
+            .and_then(|response| response.bytes())
+            .map(|bytes| bytes.to_vec())
+            .map_err(|error| anyhow::anyhow!("{url}: {error}"))
+    }
+
+    ///
+    /// Writes the executable `bytes` to `path`, and makes the file executable.
+    ///
+    fn write_executable(path: &Path, bytes: &[u8]) -> anyhow::Result<()> {
+        let mut file = std::fs::File::create(path)
+            .map_err(|error| anyhow::anyhow!("File {path:?} creating: {error}"))?;
+        file.write_all(bytes)
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            file.set_permissions(std::fs::Permissions::from_mode(0o755))
+                .map_err(|error| anyhow::anyhow!("File {path:?} permissions setting: {error}"))?;
+        }
+
+        Ok(())
+    }
+}
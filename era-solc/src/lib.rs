@@ -9,37 +9,85 @@
 #![allow(clippy::should_implement_trait)]
 #![allow(clippy::result_large_err)]
 
+pub mod cache;
 pub mod combined_json;
+pub mod evm_version;
+pub mod exit_code;
+pub mod installer;
+pub mod path;
 pub mod solc;
 pub mod standard_json;
 pub mod version;
 
+pub use self::cache::Directory as CacheDirectory;
 pub use self::combined_json::contract::Contract as CombinedJsonContract;
 pub use self::combined_json::selector::Selector as CombinedJsonSelector;
 pub use self::combined_json::CombinedJson;
+pub use self::exit_code::ExitCode;
+pub use self::exit_code::ExitCodeError;
+pub use self::exit_code::WithExitCode;
+pub use self::installer::Installer;
 pub use self::solc::Compiler;
 pub use self::standard_json::input::language::Language as StandardJsonInputLanguage;
+pub use self::standard_json::input::settings::balance_lowering::BalanceLowering as StandardJsonInputBalanceLowering;
 pub use self::standard_json::input::settings::codegen::Codegen as StandardJsonInputCodegen;
+pub use self::standard_json::input::settings::diagnostics::Diagnostics as StandardJsonInputDiagnostics;
+pub use self::standard_json::input::settings::diagnostics::Severity as StandardJsonInputSeverity;
+pub use self::standard_json::input::settings::eravm::EraVM as StandardJsonInputEraVM;
+pub use self::standard_json::input::settings::error_format::ErrorFormat as StandardJsonInputErrorFormat;
 pub use self::standard_json::input::settings::error_type::ErrorType as StandardJsonInputErrorType;
 pub use self::standard_json::input::settings::libraries::Libraries as StandardJsonInputLibraries;
+pub use self::standard_json::input::settings::llvm_options::LLVMOptions as StandardJsonInputLLVMOptions;
+pub use self::standard_json::input::settings::loop_optimization::LoopOptimization as StandardJsonInputLoopOptimization;
 pub use self::standard_json::input::settings::metadata::Metadata as StandardJsonInputMetadata;
 pub use self::standard_json::input::settings::optimizer::Optimizer as StandardJsonInputOptimizer;
+pub use self::standard_json::input::settings::parallel::Parallel as StandardJsonInputParallel;
+pub use self::standard_json::input::settings::policy::Policy as StandardJsonInputPolicy;
+pub use self::standard_json::input::settings::policy::Rule as StandardJsonInputPolicyRule;
+pub use self::standard_json::input::settings::profile::Profile as StandardJsonInputProfile;
+pub use self::standard_json::input::settings::remapping::Remapping as StandardJsonInputRemapping;
 pub use self::standard_json::input::settings::selection::file::File as StandardJsonInputSelectionFile;
 pub use self::standard_json::input::settings::selection::selector::Selector as StandardJsonInputSelector;
 pub use self::standard_json::input::settings::selection::Selection as StandardJsonInputSelection;
+pub use self::standard_json::input::settings::spill_strategy::SpillStrategy as StandardJsonInputSpillStrategy;
+pub use self::standard_json::input::settings::suppressions::Suppressions as StandardJsonInputSuppressions;
 pub use self::standard_json::input::settings::warning_type::WarningType as StandardJsonInputWarningType;
 pub use self::standard_json::input::settings::Settings as StandardJsonInputSettings;
 pub use self::standard_json::input::source::Source as StandardJsonInputSource;
 pub use self::standard_json::input::Input as StandardJsonInput;
+pub use self::standard_json::output::compatibility_report::CompatibilityReport as StandardJsonOutputCompatibilityReport;
+pub use self::standard_json::output::contract::deployment_payload::DeploymentPayload as StandardJsonOutputContractDeploymentPayload;
+pub use self::standard_json::output::contract::eravm::assembly::Assembly as StandardJsonOutputContractEraVMAssembly;
+pub use self::standard_json::output::contract::eravm::assembly::Instruction as StandardJsonOutputContractEraVMAssemblyInstruction;
 pub use self::standard_json::output::contract::eravm::EraVM as StandardJsonOutputContractEraVM;
+pub use self::standard_json::output::contract::evm::bytecode::link_reference::LinkReference as StandardJsonOutputContractEVMBytecodeLinkReference;
 pub use self::standard_json::output::contract::evm::bytecode::Bytecode as StandardJsonOutputContractEVMBytecode;
 pub use self::standard_json::output::contract::evm::extra_metadata::recursive_function::RecursiveFunction as StandardJsonOutputContractEVMExtraMetadataRecursiveFunction;
 pub use self::standard_json::output::contract::evm::extra_metadata::ExtraMetadata as StandardJsonOutputContractEVMExtraMetadata;
 pub use self::standard_json::output::contract::evm::EVM as StandardJsonOutputContractEVM;
 pub use self::standard_json::output::contract::Contract as StandardJsonOutputContract;
 pub use self::standard_json::output::error::collectable::Collectable as CollectableError;
+pub use self::standard_json::output::error::interface_diff::Entry as StandardJsonOutputErrorInterfaceDiffEntry;
+pub use self::standard_json::output::error::interface_diff::InterfaceDiff as StandardJsonOutputErrorInterfaceDiff;
+pub use self::standard_json::output::error::sarif::Deferred as StandardJsonOutputErrorSarifDeferred;
+pub use self::standard_json::output::error::sarif::Region as StandardJsonOutputErrorSarifRegion;
+pub use self::standard_json::output::error::sarif::Sarif as StandardJsonOutputErrorSarif;
 pub use self::standard_json::output::error::source_location::SourceLocation as StandardJsonOutputErrorSourceLocation;
+pub use self::standard_json::output::error::suggested_fix::SuggestedFix as StandardJsonOutputErrorSuggestedFix;
+pub use self::standard_json::output::error::unsupported_feature::UnsupportedFeature as StandardJsonOutputErrorUnsupportedFeature;
 pub use self::standard_json::output::error::Error as StandardJsonOutputError;
+pub use self::standard_json::output::import_graph::ImportGraph as StandardJsonOutputImportGraph;
+pub use self::standard_json::output::inheritance_graph::InheritanceGraph as StandardJsonOutputInheritanceGraph;
+pub use self::standard_json::output::linked_libraries::LinkedLibraries as StandardJsonOutputLinkedLibraries;
+pub use self::standard_json::output::revert_sites::RevertSites as StandardJsonOutputRevertSites;
+pub use self::standard_json::output::statistics::Counts as StandardJsonOutputStatisticsCounts;
+pub use self::standard_json::output::statistics::Delta as StandardJsonOutputStatisticsDelta;
+pub use self::standard_json::output::statistics::Statistics as StandardJsonOutputStatistics;
+pub use self::standard_json::output::statistics::Trend as StandardJsonOutputStatisticsTrend;
+pub use self::standard_json::output::summary::cache::Cache as StandardJsonOutputSummaryCache;
+pub use self::standard_json::output::summary::contract::Contract as StandardJsonOutputSummaryContract;
+pub use self::standard_json::output::summary::Summary as StandardJsonOutputSummary;
+pub use self::standard_json::output::timings::Timings as StandardJsonOutputTimings;
 pub use self::standard_json::output::Output as StandardJsonOutput;
 pub use self::version::Version;
 
@@ -0,0 +1,45 @@
+//!
+//! The source path normalization.
+//!
+
+use std::path::Path;
+
+/// The Windows verbatim path prefix used for long paths, e.g. `\\?\C:\contracts`.
+pub const VERBATIM_PREFIX: &str = r"\\?\";
+
+/// The Windows verbatim UNC path prefix used for long network paths, e.g. `\\?\UNC\server\share`.
+pub const VERBATIM_UNC_PREFIX: &str = r"\\?\UNC\";
+
+///
+/// Normalizes the source path, so it is represented equally on all host systems.
+///
+/// The Windows verbatim prefixes of long paths are removed, with verbatim UNC paths turned into
+/// ordinary ones, and separators are converted to forward slashes. As a result, the source keys of
+/// standard JSON input and output, and the metadata hashes depending on them, match between hosts.
+///
+/// Backslashes are only treated as separators on Windows, as they are valid file name characters elsewhere.
+///
+pub fn normalize(path: &str) -> String {
+    let path = if let Some(path) = path.strip_prefix(VERBATIM_UNC_PREFIX) {
+        format!(r"\\{path}")
+    } else if let Some(path) = path.strip_prefix(VERBATIM_PREFIX) {
+        path.to_owned()
+    } else {
+        path.to_owned()
+    };
+
+    if cfg!(windows) {
+        path.replace('\\', "/")
+    } else {
+        path
+    }
+}
+
+///
+/// Normalizes the file system source path.
+///
+/// See [`normalize`] for the details.
+///
+pub fn normalize_path(path: &Path) -> String {
+    normalize(path.to_string_lossy().as_ref())
+}
@@ -4,17 +4,24 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::io::Read;
 use std::io::Write;
+use std::path::Path;
 use std::path::PathBuf;
 use std::sync::OnceLock;
 use std::sync::RwLock;
+use std::time::Duration;
+use std::time::Instant;
 
 use crate::combined_json::selector::Selector as CombinedJsonSelector;
 use crate::combined_json::CombinedJson;
+use crate::exit_code::ExitCode;
+use crate::exit_code::ExitCodeError;
 use crate::standard_json::input::settings::libraries::Libraries as StandardJsonInputSettingsLibraries;
 use crate::standard_json::input::settings::optimizer::Optimizer as StandardJsonInputSettingsOptimizer;
 use crate::standard_json::input::settings::selection::Selection as StandardJsonInputSettingsSelection;
 use crate::standard_json::input::Input as StandardJsonInput;
+use crate::standard_json::output::error::collectable::Collectable as CollectableError;
 use crate::standard_json::output::error::Error as StandardJsonOutputError;
 use crate::standard_json::output::Output as StandardJsonOutput;
 use crate::version::Version;
@@ -28,12 +35,22 @@ pub struct Compiler {
     pub executable: String,
     /// The `solc` compiler version.
     pub version: Version,
+    /// The directory `solc` is pointed to for its intermediate files, if set.
+    pub temp_dir: Option<PathBuf>,
+    /// The build deadline, after which the `solc` subprocess is killed, if set.
+    pub deadline: Option<Instant>,
 }
 
 impl Compiler {
     /// The default executable name.
     pub const DEFAULT_EXECUTABLE_NAME: &'static str = "solc";
 
+    /// The environment variables pointing subprocesses to the temporary directory.
+    pub const TEMP_DIR_ENVIRONMENT_VARIABLES: [&'static str; 3] = ["TMPDIR", "TMP", "TEMP"];
+
+    /// The interval of checking whether the subprocess has finished before the deadline.
+    const DEADLINE_POLLING_INTERVAL: Duration = Duration::from_millis(10);
+
     /// The first version of `solc` with the support of standard JSON interface.
     pub const FIRST_SUPPORTED_VERSION: semver::Version = semver::Version::new(0, 4, 12);
 
@@ -67,18 +84,36 @@ impl Compiler {
         let mut executables = Self::executables().write().expect("Sync");
 
         if let Err(error) = which::which(executable) {
-            anyhow::bail!("The `{executable}` executable not found: {error}. Please add it to ${{PATH}} or provide it explicitly with the `--solc` option.");
+            anyhow::bail!("The `{executable}` executable not found: {error}. Please add it to ${{PATH}}, provide it explicitly with the `--solc` option, or install it with `zksolc solc install <version>`.");
         }
         let version = Self::parse_version(executable)?;
         let compiler = Self {
             executable: executable.to_owned(),
             version,
+            temp_dir: None,
+            deadline: None,
         };
 
         executables.insert(executable.to_owned(), compiler.clone());
         Ok(compiler)
     }
 
+    ///
+    /// Points `solc` to `temp_dir` for its intermediate files, instead of the system temporary directory.
+    ///
+    pub fn with_temp_dir(mut self, temp_dir: Option<&Path>) -> Self {
+        self.temp_dir = temp_dir.map(Path::to_path_buf);
+        self
+    }
+
+    ///
+    /// Makes `solc` killed once `deadline` has passed, so it is covered by the build timeout.
+    ///
+    pub fn with_deadline(mut self, deadline: Option<Instant>) -> Self {
+        self.deadline = deadline;
+        self
+    }
+
     ///
     /// Initializes the Solidity compiler with the default executable name.
     ///
@@ -86,6 +121,13 @@ impl Compiler {
         Self::try_from_path(Self::DEFAULT_EXECUTABLE_NAME)
     }
 
+    ///
+    /// Whether the executable with the default name is found in `${PATH}`.
+    ///
+    pub fn is_default_in_path() -> bool {
+        which::which(Self::DEFAULT_EXECUTABLE_NAME).is_ok()
+    }
+
     ///
     /// The Solidity `--standard-json` mirror.
     ///
@@ -101,6 +143,7 @@ impl Compiler {
         command.stdin(std::process::Stdio::piped());
         command.stdout(std::process::Stdio::piped());
         command.stderr(std::process::Stdio::piped());
+        self.set_temp_dir(&mut command);
         command.arg("--standard-json");
         if let Some(base_path) = base_path {
             command.arg("--base-path");
@@ -127,9 +170,7 @@ impl Compiler {
             anyhow::anyhow!("{} subprocess stdin writing: {error:?}", self.executable)
         })?;
 
-        let result = process.wait_with_output().map_err(|error| {
-            anyhow::anyhow!("{} subprocess output reading: {error:?}", self.executable)
-        })?;
+        let result = self.wait_with_deadline(process)?;
         if !result.status.success() {
             anyhow::bail!(
                 "{} subprocess failed with exit code {:?}:\n{}\n{}",
@@ -152,6 +193,19 @@ impl Compiler {
                 );
             }
         };
+        solc_output.error_format = input.settings.error_format;
+        for error in solc_output.errors.iter_mut() {
+            error.resolve_region(&input.sources);
+        }
+        solc_output.stream_output = input.settings.stream_output;
+        if input.settings.solc_input_echo {
+            let solc_input = String::from_utf8(stdin_input).expect("Always valid");
+            let solc_input =
+                serde_json::value::RawValue::from_string(solc_input).map_err(|error| {
+                    anyhow::anyhow!("{} standard JSON input echoing: {error}", self.executable)
+                })?;
+            solc_output.solc_input = Some(solc_input);
+        }
 
         if input.settings.force_evmla {
             messages.push(StandardJsonOutputError::new_warning(
@@ -167,6 +221,15 @@ impl Compiler {
                 None,
             ));
         }
+        for (path, source) in input.sources.iter() {
+            if let Some(encoding) = source.encoding {
+                messages.push(StandardJsonOutputError::new_warning(
+                    format!("Source `{path}` has been transcoded from {encoding} to UTF-8. Please re-save it as UTF-8."),
+                    None,
+                    None,
+                ));
+            }
+        }
         if !input.suppressed_errors.is_empty() {
             messages.push(StandardJsonOutputError::new_warning(
                 "`suppressedErrors` at the root of standard JSON input is deprecated. Please move them to `settings`.",
@@ -201,7 +264,19 @@ impl Compiler {
             &self.version,
             suppressed_errors.as_slice(),
             suppressed_warnings.as_slice(),
+            &input.settings.suppressions,
+            &input.settings.diagnostics,
+            &input.settings.policy,
+            input.settings.strict_eravm,
+            input.settings.compatibility_report,
+            input.settings.revert_sites,
+            input.settings.ast_cache.as_deref(),
         )?;
+        if let Some(evm_version) = input.settings.evm_version.as_ref() {
+            if solc_output.has_errors() {
+                solc_output.check_evm_version_builtins(&input.sources, evm_version);
+            }
+        }
         solc_output.remove_evm_artifacts();
 
         Ok(solc_output)
@@ -225,6 +300,7 @@ impl Compiler {
         let mut command = std::process::Command::new(executable.as_str());
         command.stdout(std::process::Stdio::piped());
         command.stderr(std::process::Stdio::piped());
+        self.set_temp_dir(&mut command);
         command.args(paths);
         command.arg("--combined-json");
         command.arg(
@@ -239,18 +315,17 @@ impl Compiler {
             .spawn()
             .map_err(|error| anyhow::anyhow!("{} subprocess spawning: {:?}", executable, error))?;
 
-        let result = process.wait_with_output().map_err(|error| {
-            anyhow::anyhow!("{} subprocess output reading: {error:?}", self.executable)
-        })?;
+        let result = self.wait_with_deadline(process)?;
 
         if !result.status.success() {
-            anyhow::bail!(
+            let message = format!(
                 "{} subprocess failed with exit code {:?}:\n{}\n{}",
                 self.executable,
                 result.status.code(),
                 String::from_utf8_lossy(result.stdout.as_slice()),
                 String::from_utf8_lossy(result.stderr.as_slice()),
             );
+            return Err(ExitCodeError::new(ExitCode::SolcError, message).into());
         }
 
         era_compiler_common::deserialize_from_slice::<CombinedJson>(result.stdout.as_slice())
@@ -294,6 +369,88 @@ impl Compiler {
         Ok(solc_output)
     }
 
+    ///
+    /// Points the `solc` subprocess `command` to the temporary directory, if it is set.
+    ///
+    fn set_temp_dir(&self, command: &mut std::process::Command) {
+        if let Some(ref temp_dir) = self.temp_dir {
+            for variable in Self::TEMP_DIR_ENVIRONMENT_VARIABLES.into_iter() {
+                command.env(variable, temp_dir.as_os_str());
+            }
+        }
+    }
+
+    ///
+    /// Waits for the `solc` subprocess to finish, and collects its output.
+    ///
+    /// If the deadline passes before that, the subprocess is killed, and the build timeout
+    /// error is returned.
+    ///
+    fn wait_with_deadline(
+        &self,
+        mut process: std::process::Child,
+    ) -> anyhow::Result<std::process::Output> {
+        let Some(deadline) = self.deadline else {
+            return process.wait_with_output().map_err(|error| {
+                anyhow::anyhow!("{} subprocess output reading: {error:?}", self.executable)
+            });
+        };
+
+        drop(process.stdin.take());
+        let stdout = Self::read_pipe(process.stdout.take());
+        let stderr = Self::read_pipe(process.stderr.take());
+
+        let status = loop {
+            let status = process.try_wait().map_err(|error| {
+                anyhow::anyhow!("{} subprocess waiting: {error:?}", self.executable)
+            })?;
+            if let Some(status) = status {
+                break status;
+            }
+            if Instant::now() >= deadline {
+                let _ = process.kill();
+                let _ = process.wait();
+                return Err(ExitCodeError::new(
+                    ExitCode::BuildTimeout,
+                    format!(
+                        "{} subprocess has been killed, as the build timeout has been exceeded.",
+                        self.executable
+                    ),
+                )
+                .into());
+            }
+            std::thread::sleep(Self::DEADLINE_POLLING_INTERVAL);
+        };
+
+        let output = |reader: std::thread::JoinHandle<std::io::Result<Vec<u8>>>| {
+            reader.join().expect("Thread panicked").map_err(|error| {
+                anyhow::anyhow!("{} subprocess output reading: {error:?}", self.executable)
+            })
+        };
+        Ok(std::process::Output {
+            status,
+            stdout: output(stdout)?,
+            stderr: output(stderr)?,
+        })
+    }
+
+    ///
+    /// Reads the subprocess `pipe` to the end in a separate thread, so the subprocess is not
+    /// blocked on a full pipe while it is being waited for.
+    ///
+    fn read_pipe<R>(pipe: Option<R>) -> std::thread::JoinHandle<std::io::Result<Vec<u8>>>
+    where
+        R: Read + Send + 'static,
+    {
+        std::thread::spawn(move || {
+            let mut buffer = Vec::new();
+            if let Some(mut pipe) = pipe {
+                pipe.read_to_end(&mut buffer)?;
+            }
+            Ok(buffer)
+        })
+    }
+
     ///
     /// Returns the global shared array of `solc` executables.
     ///
@@ -20,6 +20,7 @@ use crate::standard_json::input::settings::error_type::ErrorType as StandardJson
 use crate::standard_json::input::settings::libraries::Libraries as StandardJsonInputSettingsLibraries;
 use crate::standard_json::input::settings::metadata::Metadata as StandardJsonInputSettingsMetadata;
 use crate::standard_json::input::settings::optimizer::Optimizer as StandardJsonInputSettingsOptimizer;
+use crate::standard_json::input::settings::remapping::Remapping as StandardJsonInputSettingsRemapping;
 use crate::standard_json::input::settings::selection::Selection as StandardJsonInputSettingsSelection;
 use crate::standard_json::input::settings::warning_type::WarningType as StandardJsonInputSettingsWarningType;
 
@@ -61,15 +62,36 @@ impl Input {
             None => std::io::read_to_string(std::io::stdin())
                 .map_err(|error| anyhow::anyhow!("Standard JSON reading from stdin: {error}")),
         }?;
-        era_compiler_common::deserialize_from_str::<Self>(input_json.as_str())
-            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))
+        Self::try_from_str(input_json.as_str())
+    }
+
+    ///
+    /// A shortcut constructor from the input JSON string.
+    ///
+    pub fn try_from_str(input_json: &str) -> anyhow::Result<Self> {
+        let input_json = input_json.strip_prefix(Source::BOM).unwrap_or(input_json);
+        let mut input = era_compiler_common::deserialize_from_str::<Self>(input_json)
+            .map_err(|error| anyhow::anyhow!("Standard JSON parsing: {error}"))?;
+        input.normalize_paths();
+        let sources = &input.sources;
+        input
+            .settings
+            .apply_remappings(|path| sources.contains_key(path))?;
+        input.settings.apply_eravm()?;
+        for source in input.sources.values_mut() {
+            source.strip_bom();
+        }
+        Ok(input)
     }
 
     ///
     /// A shortcut constructor from Solidity source paths.
     ///
+    /// The source read from the standard input is named `stdin_name` if it is specified.
+    ///
     pub fn try_from_solidity_paths(
         paths: &[PathBuf],
+        stdin_name: Option<&str>,
         libraries: &[String],
         remappings: BTreeSet<String>,
         optimizer: StandardJsonInputSettingsOptimizer,
@@ -84,17 +106,26 @@ impl Input {
         detect_missing_libraries: bool,
         via_ir: bool,
     ) -> anyhow::Result<Self> {
-        let mut paths: BTreeSet<PathBuf> = paths.iter().cloned().collect();
-        let libraries = StandardJsonInputSettingsLibraries::try_from(libraries)?;
+        let mut paths: BTreeMap<String, PathBuf> = paths
+            .iter()
+            .map(|path| (Source::name(path.as_path(), stdin_name), path.to_owned()))
+            .collect();
+        let mut libraries = StandardJsonInputSettingsLibraries::try_from(libraries)?;
+        libraries.apply_remappings(
+            &StandardJsonInputSettingsRemapping::try_from_strings(&remappings)?,
+            |path| paths.contains_key(path),
+        );
         for library_file in libraries.as_inner().keys() {
-            paths.insert(PathBuf::from(library_file));
+            if !paths.contains_key(library_file) {
+                paths.insert(library_file.to_owned(), PathBuf::from(library_file));
+            }
         }
 
         let sources = paths
             .into_par_iter()
-            .map(|path| {
+            .map(|(name, path)| {
                 let source = Source::try_read(path.as_path())?;
-                Ok((path.to_string_lossy().to_string(), source))
+                Ok((name, source))
             })
             .collect::<anyhow::Result<BTreeMap<String, Source>>>()?;
 
@@ -135,24 +166,27 @@ impl Input {
         detect_missing_libraries: bool,
         via_ir: bool,
     ) -> anyhow::Result<Self> {
+        let mut settings = Settings::new(
+            optimizer,
+            libraries,
+            remappings,
+            codegen,
+            evm_version,
+            enable_eravm_extensions,
+            output_selection,
+            metadata,
+            llvm_options,
+            suppressed_errors.clone(),
+            suppressed_warnings.clone(),
+            detect_missing_libraries,
+            via_ir,
+        );
+        settings.apply_remappings(|path| sources.contains_key(path))?;
+
         Ok(Self {
             language: Language::Solidity,
             sources,
-            settings: Settings::new(
-                optimizer,
-                libraries,
-                remappings,
-                codegen,
-                evm_version,
-                enable_eravm_extensions,
-                output_selection,
-                metadata,
-                llvm_options,
-                suppressed_errors.clone(),
-                suppressed_warnings.clone(),
-                detect_missing_libraries,
-                via_ir,
-            ),
+            settings,
             suppressed_errors,
             suppressed_warnings,
         })
@@ -205,7 +239,7 @@ impl Input {
             .iter()
             .map(|path| {
                 (
-                    path.to_string_lossy().to_string(),
+                    crate::path::normalize_path(path.as_path()),
                     Source::from(path.as_path()),
                 )
             })
@@ -213,6 +247,17 @@ impl Input {
         Self::from_yul_sources(sources, libraries, optimizer, llvm_options)
     }
 
+    ///
+    /// Normalizes the source paths used as keys, so they are represented equally on all host systems.
+    ///
+    pub fn normalize_paths(&mut self) {
+        self.sources = std::mem::take(&mut self.sources)
+            .into_iter()
+            .map(|(path, source)| (crate::path::normalize(path.as_str()), source))
+            .collect();
+        self.settings.normalize_paths();
+    }
+
     ///
     /// Extends the output selection with another one.
     ///
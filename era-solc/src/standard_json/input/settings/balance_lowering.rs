@@ -0,0 +1,60 @@
+//!
+//! The `BALANCE` and `SELFBALANCE` lowering strategy.
+//!
+
+use std::str::FromStr;
+
+///
+/// The `BALANCE` and `SELFBALANCE` lowering strategy.
+///
+/// On EraVM, balance queries are far calls to the base token system contract, so the choice
+/// trades the bytecode size of contracts with many balance queries against the call overhead.
+///
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum BalanceLowering {
+    /// The system contract call sequence is emitted at every balance query.
+    #[default]
+    Inline,
+    /// The system contract call sequence is emitted once in a helper routine called by every balance query.
+    Shared,
+}
+
+impl BalanceLowering {
+    ///
+    /// Whether the strategy is the default one.
+    ///
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl FromStr for BalanceLowering {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "inline" => Ok(Self::Inline),
+            "shared" => Ok(Self::Shared),
+            string => anyhow::bail!(
+                "Invalid balance lowering strategy: `{string}`. Available options: {}.",
+                [Self::Inline, Self::Shared]
+                    .into_iter()
+                    .map(|strategy| strategy.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for BalanceLowering {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Inline => write!(f, "inline"),
+            Self::Shared => write!(f, "shared"),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//!
+//! The `solc --standard-json` input settings diagnostics.
+//!
+
+use std::collections::BTreeMap;
+use std::str::FromStr;
+
+use crate::standard_json::input::settings::error_type::ErrorType;
+use crate::standard_json::input::settings::warning_type::WarningType;
+
+///
+/// The diagnostic severity.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// The diagnostic fails the compilation.
+    Error,
+    /// The diagnostic is only reported.
+    Warning,
+}
+
+impl Severity {
+    ///
+    /// Returns the message type of the severity.
+    ///
+    pub fn as_type(&self) -> &'static str {
+        match self {
+            Self::Error => "Error",
+            Self::Warning => "Warning",
+        }
+    }
+}
+
+///
+/// The `solc --standard-json` input settings diagnostics.
+///
+/// The severity of the built-in checks can be overridden by their stable codes, e.g.:
+///
+/// ```json
+/// {
+///   "severity": {
+///     "txorigin": "error",
+///     "sendtransfer": "warning"
+///   }
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "DiagnosticsFormat")]
+pub struct Diagnostics {
+    /// The severity overrides, keyed by the stable code of the check.
+    pub severity: BTreeMap<String, Severity>,
+}
+
+impl Diagnostics {
+    ///
+    /// Returns the overridden severity of the check with the stable `code`.
+    ///
+    pub fn severity(&self, code: &str) -> Option<Severity> {
+        self.severity.get(code).copied()
+    }
+}
+
+///
+/// The accepted format of the diagnostics settings.
+///
+#[derive(serde::Deserialize)]
+struct DiagnosticsFormat {
+    /// The severity overrides, keyed by the stable code of the check.
+    #[serde(default)]
+    severity: BTreeMap<String, Severity>,
+}
+
+impl TryFrom<DiagnosticsFormat> for Diagnostics {
+    type Error = anyhow::Error;
+
+    fn try_from(format: DiagnosticsFormat) -> Result<Self, Self::Error> {
+        for code in format.severity.keys() {
+            if ErrorType::from_str(code).is_err() && WarningType::from_str(code).is_err() {
+                anyhow::bail!("Invalid diagnostic code in severity overrides: {code}");
+            }
+        }
+        Ok(Self {
+            severity: format.severity,
+        })
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The `solc --standard-json` input settings EraVM namespace.
+//!
+
+///
+/// The `solc --standard-json` input settings EraVM namespace.
+///
+/// Groups the EraVM-specific settings in one place. Each field that is set overrides its
+/// counterpart scattered across the other settings, so JSON-driven tooling can configure
+/// everything the command line interface can.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraVM {
+    /// Whether to enable EraVM extensions.
+    /// Overrides `settings.enableEraVMExtensions`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enable_extensions: Option<bool>,
+    /// The metadata hash type appended to the bytecode.
+    /// Overrides `settings.metadata.hashType`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_type: Option<era_compiler_common::HashType>,
+    /// Whether to pad the bytecode with zeros before the metadata hash to an odd number of words.
+    /// EraVM requires the padding, so it can only be set to `true`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytecode_padding: Option<bool>,
+    /// Whether to emit the EraVM assembly of all contracts.
+    /// Extends `settings.outputSelection` with `eravm.assembly`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emit_assembly: Option<bool>,
+    /// Whether to try to recompile with optimizations for size if the bytecode is too large.
+    /// Overrides `settings.optimizer.fallbackToOptimizingForSize`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_fallback: Option<bool>,
+}
@@ -0,0 +1,48 @@
+//!
+//! The diagnostics output format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The diagnostics output format.
+///
+#[derive(
+    Debug, Default, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum ErrorFormat {
+    /// The human-readable format, as it is printed by `solc`.
+    #[default]
+    Human,
+    /// The SARIF 2.1.0 log, e.g. for GitHub code scanning.
+    Sarif,
+}
+
+impl FromStr for ErrorFormat {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "human" => Ok(Self::Human),
+            "sarif" => Ok(Self::Sarif),
+            string => anyhow::bail!(
+                "Invalid error format: `{string}`. Available options: {}.",
+                [Self::Human, Self::Sarif]
+                    .into_iter()
+                    .map(|format| format.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for ErrorFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Human => write!(f, "human"),
+            Self::Sarif => write!(f, "sarif"),
+        }
+    }
+}
@@ -3,6 +3,9 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::standard_json::input::settings::remapping::Remapping;
 
 ///
 /// The Solidity libraries.
@@ -51,6 +54,37 @@ impl Libraries {
         self.inner.is_empty()
     }
 
+    ///
+    /// Normalizes the library source paths, so they are represented equally on all host systems.
+    ///
+    pub fn normalize_paths(&mut self) {
+        self.inner = std::mem::take(&mut self.inner)
+            .into_iter()
+            .map(|(path, contracts)| (crate::path::normalize(path.as_str()), contracts))
+            .collect();
+    }
+
+    ///
+    /// Remaps the library source paths with the import `remappings`.
+    ///
+    /// See [`Remapping::remap_source_path`] for the details.
+    ///
+    pub fn apply_remappings(
+        &mut self,
+        remappings: &BTreeSet<Remapping>,
+        is_source: impl Fn(&str) -> bool,
+    ) {
+        if remappings.is_empty() {
+            return;
+        }
+        let mut inner: BTreeMap<String, BTreeMap<String, String>> = BTreeMap::new();
+        for (path, contracts) in std::mem::take(&mut self.inner).into_iter() {
+            let path = Remapping::remap_source_path(remappings, path, &is_source);
+            inner.entry(path).or_default().extend(contracts);
+        }
+        self.inner = inner;
+    }
+
     ///
     /// Returns a reference to the inner value.
     ///
@@ -78,22 +112,23 @@ impl TryFrom<&[String]> for Libraries {
     fn try_from(arguments: &[String]) -> Result<Self, Self::Error> {
         let mut libraries = BTreeMap::new();
         for (index, library) in arguments.iter().enumerate() {
-            let mut path_and_address = library.split('=');
-            let path = path_and_address
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("Library #{index} path is missing."))?;
-            let mut file_and_contract = path.split(':');
-            let file = file_and_contract
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("Library `{path}` file name is missing."))?;
-            let contract = file_and_contract
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("Library `{path}` contract name is missing."))?;
-            let address = path_and_address
-                .next()
-                .ok_or_else(|| anyhow::anyhow!("Library `{path}` address is missing."))?;
+            let (path, address) = match library.rsplit_once('=') {
+                Some((path, address)) => (path, Some(address)),
+                None if library.is_empty() => {
+                    anyhow::bail!("Library #{index} path is missing.")
+                }
+                None => (library.as_str(), None),
+            };
+            // The contract name is separated by the last colon, as Windows paths may contain a drive letter.
+            let (file, contract) = match path.rsplit_once(':') {
+                Some((file, contract)) if !file.is_empty() => (file, contract),
+                Some(_) => anyhow::bail!("Library `{path}` file name is missing."),
+                None => anyhow::bail!("Library `{path}` contract name is missing."),
+            };
+            let address =
+                address.ok_or_else(|| anyhow::anyhow!("Library `{path}` address is missing."))?;
             libraries
-                .entry(file.to_owned())
+                .entry(crate::path::normalize(file))
                 .or_insert_with(BTreeMap::new)
                 .insert(contract.to_owned(), address.to_owned());
         }
@@ -0,0 +1,72 @@
+//!
+//! The `solc --standard-json` input settings extra LLVM options.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The `solc --standard-json` input settings extra LLVM options.
+///
+/// Specified either as a list of options applied to all contracts, or as a map of contract
+/// full paths, e.g. `Test.sol:Test`, to the options applied to them in addition to the global
+/// ones under the `*` key:
+///
+/// ```json
+/// {
+///   "*": ["-eravm-jump-table-density-threshold=10"],
+///   "Test.sol:Test": ["-unroll-threshold=0"]
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Deserialize)]
+#[serde(from = "LLVMOptionsFormat")]
+pub struct LLVMOptions {
+    /// The options applied to all contracts.
+    pub global: Vec<String>,
+    /// The options applied to specific contracts, keyed by contract full path.
+    pub contracts: BTreeMap<String, Vec<String>>,
+}
+
+impl LLVMOptions {
+    /// The map key of the options applied to all contracts.
+    pub const GLOBAL_KEY: &'static str = "*";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(global: Vec<String>) -> Self {
+        Self {
+            global,
+            contracts: BTreeMap::new(),
+        }
+    }
+}
+
+///
+/// The accepted formats of the extra LLVM options.
+///
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum LLVMOptionsFormat {
+    /// The options applied to all contracts.
+    Global(Vec<String>),
+    /// The options keyed by contract full path or [`LLVMOptions::GLOBAL_KEY`].
+    PerContract(BTreeMap<String, Vec<String>>),
+}
+
+impl From<LLVMOptionsFormat> for LLVMOptions {
+    fn from(format: LLVMOptionsFormat) -> Self {
+        match format {
+            LLVMOptionsFormat::Global(global) => Self::new(global),
+            LLVMOptionsFormat::PerContract(mut contracts) => Self {
+                global: contracts.remove(Self::GLOBAL_KEY).unwrap_or_default(),
+                contracts: contracts
+                    .into_iter()
+                    .map(|(full_path, options)| {
+                        (crate::path::normalize(full_path.as_str()), options)
+                    })
+                    .collect(),
+            },
+        }
+    }
+}
@@ -0,0 +1,71 @@
+//!
+//! The LLVM loop optimization mode.
+//!
+
+use std::str::FromStr;
+
+///
+/// The LLVM loop optimization mode.
+///
+/// Controls the aggressiveness of loop unrolling and rotation, which frequently bloat
+/// the bytecode past the size limit for loops whose cost is amortized anyway.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LoopOptimization {
+    /// Loop unrolling and rotation are disabled.
+    Off,
+    /// Only small loops are unrolled and rotated.
+    Conservative,
+    /// Loops are unrolled partially and at runtime, and large loop headers are rotated.
+    Aggressive,
+}
+
+impl LoopOptimization {
+    ///
+    /// Returns the LLVM options implementing the mode.
+    ///
+    pub fn llvm_options(&self) -> Vec<String> {
+        let options: &[&str] = match self {
+            Self::Off => &["-unroll-threshold=0", "-rotation-max-header-size=0"],
+            Self::Conservative => &["-unroll-threshold=50", "-rotation-max-header-size=8"],
+            Self::Aggressive => &[
+                "-unroll-threshold=1000",
+                "-unroll-allow-partial",
+                "-unroll-runtime",
+                "-rotation-max-header-size=32",
+            ],
+        };
+        options.iter().map(|option| option.to_string()).collect()
+    }
+}
+
+impl FromStr for LoopOptimization {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "off" => Ok(Self::Off),
+            "conservative" => Ok(Self::Conservative),
+            "aggressive" => Ok(Self::Aggressive),
+            string => anyhow::bail!(
+                "Invalid loop optimization mode: `{string}`. Available options: {}.",
+                [Self::Off, Self::Conservative, Self::Aggressive]
+                    .into_iter()
+                    .map(|mode| mode.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for LoopOptimization {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Off => write!(f, "off"),
+            Self::Conservative => write!(f, "conservative"),
+            Self::Aggressive => write!(f, "aggressive"),
+        }
+    }
+}
@@ -13,7 +13,12 @@ pub struct Metadata {
     pub use_literal_content: bool,
 
     /// The metadata hash type.
-    #[serde(default = "Metadata::default_hash_type", skip_serializing)]
+    /// Also accepted as `bytecodeHash` for compatibility with `solc`.
+    #[serde(
+        default = "Metadata::default_hash_type",
+        alias = "bytecodeHash",
+        skip_serializing
+    )]
     pub hash_type: era_compiler_common::HashType,
 }
 
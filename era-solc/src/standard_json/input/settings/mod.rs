@@ -2,22 +2,47 @@
 //! The `solc --standard-json` input settings.
 //!
 
+pub mod balance_lowering;
 pub mod codegen;
+pub mod diagnostics;
+pub mod eravm;
+pub mod error_format;
 pub mod error_type;
 pub mod libraries;
+pub mod llvm_options;
+pub mod loop_optimization;
 pub mod metadata;
 pub mod optimizer;
+pub mod parallel;
+pub mod policy;
+pub mod profile;
+pub mod remapping;
 pub mod selection;
+pub mod spill_strategy;
+pub mod suppressions;
 pub mod warning_type;
 
+use std::collections::BTreeMap;
 use std::collections::BTreeSet;
+use std::path::PathBuf;
+use std::str::FromStr;
 
 use self::codegen::Codegen;
+use self::diagnostics::Diagnostics;
+use self::eravm::EraVM;
+use self::error_format::ErrorFormat;
 use self::error_type::ErrorType;
 use self::libraries::Libraries;
+use self::llvm_options::LLVMOptions;
 use self::metadata::Metadata;
 use self::optimizer::Optimizer;
+use self::parallel::Parallel;
+use self::policy::Policy;
+use self::profile::Profile;
+use self::remapping::Remapping;
+use self::selection::selector::Selector;
 use self::selection::Selection;
+use self::suppressions::Suppressions;
 use self::warning_type::WarningType;
 
 ///
@@ -56,21 +81,110 @@ pub struct Settings {
     /// Whether to enable EraVM extensions.
     #[serde(default, rename = "enableEraVMExtensions", skip_serializing)]
     pub enable_eravm_extensions: bool,
+    /// The EraVM-specific settings, overriding their counterparts in the other settings.
+    #[serde(default, rename = "eravm", skip_serializing)]
+    pub eravm: EraVM,
 
-    /// The extra LLVM options.
+    /// The named compilation profiles.
+    #[serde(default, skip_serializing)]
+    pub profiles: BTreeMap<String, Profile>,
+    /// The active compilation profile from `profiles`, recorded in the metadata.
+    #[serde(default, skip_serializing)]
+    pub profile: Option<String>,
+
+    /// The extra LLVM options, applied either to all contracts or to specific ones.
     #[serde(default, rename = "LLVMOptions", skip_serializing)]
-    pub llvm_options: Vec<String>,
+    pub llvm_options: LLVMOptions,
+    /// The format of the diagnostics, additionally written to the output.
+    #[serde(default, skip_serializing)]
+    pub error_format: ErrorFormat,
+    /// Whether to write the output as newline-delimited JSON, one contract per line.
+    /// Reduces the peak memory usage for large projects.
+    #[serde(default, rename = "streamOutput", skip_serializing)]
+    pub stream_output: bool,
     /// The suppressed errors.
     #[serde(default, skip_serializing)]
     pub suppressed_errors: Vec<ErrorType>,
     /// The suppressed warnings.
     #[serde(default, skip_serializing)]
     pub suppressed_warnings: Vec<WarningType>,
+    /// The diagnostics settings, e.g. the severity overrides of the built-in checks.
+    #[serde(default, skip_serializing)]
+    pub diagnostics: Diagnostics,
+    /// The errors and warnings suppressed in specific sources only, keyed by source path.
+    /// Allows silencing known legacy sources without hiding issues in the others.
+    #[serde(default, skip_serializing)]
+    pub suppressions: BTreeMap<String, Suppressions>,
+    /// The externally reachable function selectors, grouped by file and contract name.
+    /// Functions unreachable from them are eliminated from the deployed code.
+    #[serde(default, skip_serializing)]
+    pub reachable_selectors: BTreeMap<String, BTreeMap<String, BTreeSet<String>>>,
+    /// The known immutable values, grouped by file and contract name.
+    /// The values are constant-folded into the code and recorded in the metadata.
+    #[serde(default, skip_serializing)]
+    pub immutables: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>>,
+    /// The contract to compile and emit artifacts for, specified as `<path>:<name>` or `<name>`.
+    /// Its factory dependencies and libraries are compiled as well.
+    /// All contracts are compiled and emitted if unset.
+    #[serde(default, skip_serializing)]
+    pub contract: Option<String>,
+    /// The glob patterns of the contract full paths to compile and emit artifacts for.
+    /// The factory dependencies and libraries of the matching contracts are compiled as well.
+    #[serde(default, skip_serializing)]
+    pub only: Vec<String>,
+    /// The organization policy with the forbidden instructions and warnings.
+    #[serde(default, skip_serializing)]
+    pub policy: Policy,
+    /// Whether to report all EraVM-specific warnings as errors, ignoring the suppressed errors and warnings.
+    #[serde(default, rename = "strictEraVM", skip_serializing)]
+    pub strict_eravm: bool,
+    /// Whether to output the project-level summary of the contracts, messages, caches, and durations.
+    #[serde(default, rename = "outputSummary", skip_serializing)]
+    pub output_summary: bool,
+    /// The directory to cache the results of the AST checks in, keyed by source content hash.
+    /// Unchanged sources skip the checks on rebuilds.
+    #[serde(default, rename = "astCache", skip_serializing)]
+    pub ast_cache: Option<PathBuf>,
+    /// The directory to cache the EraVM contract builds in, keyed by the content hash of their
+    /// sources and all sources imported by them. Only the contracts affected by changes are recompiled.
+    #[serde(default, rename = "buildCache", skip_serializing)]
+    pub build_cache: Option<PathBuf>,
+    /// Whether to extract the EVM and EraVM semantic difference report from the AST.
+    /// Only set by the command line interface.
+    #[serde(skip)]
+    pub compatibility_report: bool,
+    /// Whether to extract the custom error revert sites from the AST.
+    /// Only set by the command line interface.
+    #[serde(skip)]
+    pub revert_sites: bool,
+    /// The interface freeze file to check the contract ABIs against.
+    #[serde(default, rename = "interfaceFreeze", skip_serializing)]
+    pub interface_freeze: Option<PathBuf>,
+    /// Whether to echo the exact input passed to `solc` after the zksolc rewriting in the output.
+    /// Allows auditing and reproducing the `solc` step independently.
+    #[serde(default, rename = "solcInputEcho", skip_serializing)]
+    pub solc_input_echo: bool,
+    /// Whether to output the time spent in `solc` and the zksolc AST analysis per source,
+    /// and in the zksolc backend per contract.
+    #[serde(default, rename = "outputTimings", skip_serializing)]
+    pub output_timings: bool,
+    /// Whether to tag the generated LLVM IR with the provenance of each instruction, and verify it
+    /// after the translation. Only supported by the EraVM target.
+    #[serde(default, rename = "debugAssertions", skip_serializing)]
+    pub debug_assertions: bool,
+    /// The parallel compilation settings.
+    #[serde(default, skip_serializing)]
+    pub parallel: Parallel,
 
-    /// Whether to enable the missing libraries detection mode.
-    /// Deprecated in favor of post-compile-time linking.
+    /// Whether to only detect the missing deployable libraries, skipping the compilation.
+    /// The result is written to the `missingLibraries` field of each contract.
     #[serde(default, rename = "detectMissingLibraries", skip_serializing)]
     pub detect_missing_libraries: bool,
+    /// Whether to emit the library deployer contract.
+    /// The deployer deploys the missing libraries defined in the project via the `Create2Factory`,
+    /// and their callers are linked against the deterministic library addresses.
+    #[serde(default, rename = "libraryDeployer", skip_serializing)]
+    pub library_deployer: bool,
     /// Whether to add the Yul step to compilation via EVM assembly.
     /// Only used from era-compiler-tester to allow running additional tests.
     #[serde(
@@ -114,14 +228,38 @@ impl Settings {
             evm_version,
             force_evmla: false,
             enable_eravm_extensions,
+            eravm: EraVM::default(),
+            profiles: BTreeMap::new(),
+            profile: None,
 
             output_selection,
             metadata,
-            llvm_options,
+            llvm_options: LLVMOptions::new(llvm_options),
+            error_format: ErrorFormat::default(),
+            stream_output: false,
             suppressed_errors,
             suppressed_warnings,
+            diagnostics: Diagnostics::default(),
+            suppressions: BTreeMap::new(),
+            reachable_selectors: BTreeMap::new(),
+            immutables: BTreeMap::new(),
+            contract: None,
+            only: Vec::new(),
+            policy: Policy::default(),
+            strict_eravm: false,
+            output_summary: false,
+            ast_cache: None,
+            build_cache: None,
+            compatibility_report: false,
+            revert_sites: false,
+            interface_freeze: None,
+            solc_input_echo: false,
+            output_timings: false,
+            debug_assertions: false,
+            parallel: Parallel::default(),
 
             detect_missing_libraries,
+            library_deployer: false,
             via_ir: if via_ir { Some(true) } else { None },
         }
     }
@@ -133,6 +271,147 @@ impl Settings {
         self.output_selection.extend(selection);
     }
 
+    ///
+    /// Applies the settings of the `eravm` namespace to their counterparts in the other settings.
+    ///
+    pub fn apply_eravm(&mut self) -> anyhow::Result<()> {
+        if let Some(false) = self.eravm.bytecode_padding {
+            anyhow::bail!(
+                "The `eravm.bytecodePadding` setting cannot be disabled, as EraVM requires the bytecode size to be an odd number of 32-byte words."
+            );
+        }
+        if let Some(enable_extensions) = self.eravm.enable_extensions {
+            self.enable_eravm_extensions = enable_extensions;
+        }
+        if let Some(hash_type) = self.eravm.hash_type {
+            self.metadata.hash_type = hash_type;
+        }
+        if let Some(true) = self.eravm.emit_assembly {
+            self.output_selection
+                .extend(Selection::new(vec![Selector::EraVMAssembly]));
+        }
+        if let Some(size_fallback) = self.eravm.size_fallback {
+            self.optimizer.fallback_to_optimizing_for_size = size_fallback;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Applies the active compilation profile, overriding its counterparts in the other settings.
+    ///
+    /// The `profile` argument, if set, takes precedence over `settings.profile`.
+    ///
+    pub fn apply_profile(&mut self, profile: Option<String>) -> anyhow::Result<()> {
+        if let Some(profile) = profile {
+            self.profile = Some(profile);
+        }
+        let Some(name) = self.profile.as_deref() else {
+            return Ok(());
+        };
+        let profile = self.profiles.get(name).cloned().ok_or_else(|| {
+            anyhow::anyhow!("Profile `{name}` is not found in `settings.profiles`.")
+        })?;
+
+        if let Some(mode) = profile.mode {
+            self.optimizer.mode = mode;
+        }
+        if let Some(size_fallback) = profile.size_fallback {
+            self.optimizer.fallback_to_optimizing_for_size = size_fallback;
+        }
+        if let Some(loop_optimization) = profile.loop_optimization {
+            self.optimizer.loop_optimization = Some(loop_optimization);
+        }
+        if let Some(hash_type) = profile.hash_type {
+            self.metadata.hash_type = hash_type;
+        }
+        if let Some(use_literal_content) = profile.use_literal_content {
+            self.metadata.use_literal_content = use_literal_content;
+        }
+        if let Some(true) = profile.emit_assembly {
+            self.output_selection.extend(Selection::new(vec![
+                selection::selector::Selector::EraVMAssembly,
+            ]));
+        }
+        if let Some(llvm_options) = profile.llvm_options {
+            self.llvm_options.global = llvm_options;
+        }
+        Ok(())
+    }
+
+    ///
+    /// Normalizes the source paths used as keys and in remappings,
+    /// so they are represented equally on all host systems.
+    ///
+    pub fn normalize_paths(&mut self) {
+        self.libraries.normalize_paths();
+        self.remappings = std::mem::take(&mut self.remappings)
+            .into_iter()
+            .map(|remapping| match Remapping::from_str(remapping.as_str()) {
+                Ok(parsed) => parsed.normalize_paths().to_string(),
+                Err(_) => remapping,
+            })
+            .collect();
+        self.suppressions = std::mem::take(&mut self.suppressions)
+            .into_iter()
+            .map(|(path, suppressions)| (crate::path::normalize(path.as_str()), suppressions))
+            .collect();
+        self.reachable_selectors = std::mem::take(&mut self.reachable_selectors)
+            .into_iter()
+            .map(|(path, contracts)| (crate::path::normalize(path.as_str()), contracts))
+            .collect();
+        self.immutables = std::mem::take(&mut self.immutables)
+            .into_iter()
+            .map(|(path, contracts)| (crate::path::normalize(path.as_str()), contracts))
+            .collect();
+        self.contract = self
+            .contract
+            .take()
+            .map(|contract| crate::path::normalize(contract.as_str()));
+    }
+
+    ///
+    /// Validates the remappings and applies them to the source paths specified by the user,
+    /// so the paths match the source names used by `solc` in the error messages and metadata.
+    ///
+    /// See [`Remapping::remap_source_path`] for the details.
+    ///
+    pub fn apply_remappings(&mut self, is_source: impl Fn(&str) -> bool) -> anyhow::Result<()> {
+        let remappings = Remapping::try_from_strings(&self.remappings)?;
+        if remappings.is_empty() {
+            return Ok(());
+        }
+        let remap = |path: String| Remapping::remap_source_path(&remappings, path, &is_source);
+
+        self.libraries.apply_remappings(&remappings, &is_source);
+        self.suppressions = std::mem::take(&mut self.suppressions)
+            .into_iter()
+            .map(|(path, suppressions)| (remap(path), suppressions))
+            .collect();
+        let mut reachable_selectors: BTreeMap<String, BTreeMap<String, BTreeSet<String>>> =
+            BTreeMap::new();
+        for (path, contracts) in std::mem::take(&mut self.reachable_selectors).into_iter() {
+            reachable_selectors
+                .entry(remap(path))
+                .or_default()
+                .extend(contracts);
+        }
+        self.reachable_selectors = reachable_selectors;
+        let mut immutables: BTreeMap<String, BTreeMap<String, BTreeMap<String, String>>> =
+            BTreeMap::new();
+        for (path, contracts) in std::mem::take(&mut self.immutables).into_iter() {
+            immutables.entry(remap(path)).or_default().extend(contracts);
+        }
+        self.immutables = immutables;
+        self.contract = self
+            .contract
+            .take()
+            .map(|contract| match contract.rsplit_once(':') {
+                Some((path, name)) => format!("{}:{name}", remap(path.to_owned())),
+                None => contract,
+            });
+        Ok(())
+    }
+
     ///
     /// Returns flags that are going to be automatically added by the compiler,
     /// but were not explicitly requested by the user.
@@ -2,6 +2,10 @@
 //! The `solc --standard-json` input settings optimizer.
 //!
 
+use crate::standard_json::input::settings::balance_lowering::BalanceLowering;
+use crate::standard_json::input::settings::loop_optimization::LoopOptimization;
+use crate::standard_json::input::settings::spill_strategy::SpillStrategy;
+
 ///
 /// The `solc --standard-json` input settings optimizer.
 ///
@@ -14,6 +18,15 @@ pub struct Optimizer {
     /// Whether to try to recompile with -Oz if the bytecode is too large.
     #[serde(default, skip_serializing)]
     pub fallback_to_optimizing_for_size: bool,
+    /// The LLVM loop optimization mode.
+    #[serde(default, skip_serializing)]
+    pub loop_optimization: Option<LoopOptimization>,
+    /// The LLVM register allocator spill strategy.
+    #[serde(default, skip_serializing)]
+    pub spill_strategy: Option<SpillStrategy>,
+    /// The `BALANCE` and `SELFBALANCE` lowering strategy.
+    #[serde(default, skip_serializing)]
+    pub balance_lowering: BalanceLowering,
 
     /// Enable the solc optimizer.
     /// Always `true` in order to allow library inlining.
@@ -23,7 +36,13 @@ pub struct Optimizer {
 
 impl Default for Optimizer {
     fn default() -> Self {
-        Self::new(Self::default_mode(), false)
+        Self::new(
+            Self::default_mode(),
+            false,
+            None,
+            None,
+            BalanceLowering::default(),
+        )
     }
 }
 
@@ -31,10 +50,19 @@ impl Optimizer {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(mode: char, fallback_to_optimizing_for_size: bool) -> Self {
+    pub fn new(
+        mode: char,
+        fallback_to_optimizing_for_size: bool,
+        loop_optimization: Option<LoopOptimization>,
+        spill_strategy: Option<SpillStrategy>,
+        balance_lowering: BalanceLowering,
+    ) -> Self {
         Self {
             mode,
             fallback_to_optimizing_for_size,
+            loop_optimization,
+            spill_strategy,
+            balance_lowering,
 
             enabled: Self::default_enabled(),
         }
@@ -0,0 +1,76 @@
+//!
+//! The parallel compilation settings.
+//!
+
+use std::num::NonZeroUsize;
+
+///
+/// The parallel compilation settings.
+///
+/// Independent contracts are lowered through LLVM concurrently, each in its own thread.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Parallel {
+    /// Whether to compile the contracts in parallel.
+    /// If disabled, the contracts are compiled one by one.
+    #[serde(default = "Parallel::default_enabled")]
+    pub enabled: bool,
+    /// The number of threads, which must be positive.
+    /// If unset, the `--threads` option or the number of logical CPUs is used.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub threads: Option<NonZeroUsize>,
+}
+
+impl Default for Parallel {
+    fn default() -> Self {
+        Self {
+            enabled: Self::default_enabled(),
+            threads: None,
+        }
+    }
+}
+
+impl Parallel {
+    /// The stack size of the worker threads, which must fit the deep recursion of the LLVM passes.
+    pub const WORKER_STACK_SIZE: usize = 16 * 1024 * 1024;
+
+    ///
+    /// Returns the number of threads overriding the default thread pool, if any.
+    ///
+    pub fn threads(&self) -> Option<usize> {
+        if self.enabled {
+            self.threads.map(NonZeroUsize::get)
+        } else {
+            Some(1)
+        }
+    }
+
+    ///
+    /// Runs the `operation` in a thread pool with the configured number of threads.
+    ///
+    /// If the number of threads is not configured, the `operation` is run in the default thread pool.
+    ///
+    pub fn install<R, F>(&self, operation: F) -> anyhow::Result<R>
+    where
+        R: Send,
+        F: FnOnce() -> R + Send,
+    {
+        let Some(threads) = self.threads() else {
+            return Ok(operation());
+        };
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .stack_size(Self::WORKER_STACK_SIZE)
+            .build()
+            .map_err(|error| anyhow::anyhow!("Thread pool configuration: {error}"))?;
+        Ok(pool.install(operation))
+    }
+
+    ///
+    /// The default value of the `enabled` flag.
+    ///
+    fn default_enabled() -> bool {
+        true
+    }
+}
@@ -0,0 +1,67 @@
+//!
+//! The organization policy.
+//!
+
+use std::collections::BTreeSet;
+
+use crate::standard_json::input::settings::warning_type::WarningType;
+
+///
+/// The organization policy rule.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Rule {
+    /// The rule identifier, reported with each violation.
+    pub id: String,
+    /// The forbidden instructions, named as Yul builtins, e.g. `selfdestruct` or `delegatecall`.
+    #[serde(default)]
+    pub instructions: BTreeSet<String>,
+    /// The forbidden warning types, which are reported as errors instead.
+    #[serde(default)]
+    pub warnings: Vec<WarningType>,
+}
+
+///
+/// The organization policy.
+///
+/// The policy is enforced by the compiler, so the violations cannot be suppressed.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Policy {
+    /// The policy rules.
+    #[serde(default)]
+    pub rules: Vec<Rule>,
+}
+
+impl Policy {
+    ///
+    /// Whether the policy has no rules.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    ///
+    /// Returns the identifier of the first rule forbidding the warning type.
+    ///
+    pub fn forbids_warning(&self, warning_type: WarningType) -> Option<&str> {
+        self.rules
+            .iter()
+            .find(|rule| rule.warnings.contains(&warning_type))
+            .map(|rule| rule.id.as_str())
+    }
+
+    ///
+    /// Returns the forbidden instructions along with the identifiers of the rules forbidding them.
+    ///
+    pub fn forbidden_instructions(&self) -> Vec<(&str, &str)> {
+        self.rules
+            .iter()
+            .flat_map(|rule| {
+                rule.instructions
+                    .iter()
+                    .map(move |instruction| (instruction.as_str(), rule.id.as_str()))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,56 @@
+//!
+//! The `solc --standard-json` input settings named compilation profile.
+//!
+
+use crate::standard_json::input::settings::loop_optimization::LoopOptimization;
+
+///
+/// The `solc --standard-json` input settings named compilation profile.
+///
+/// Profiles are declared in `settings.profiles`, and the active one is selected with
+/// `settings.profile` or the `--profile` option. Each field that is set overrides its
+/// counterpart in the other settings, e.g.:
+///
+/// ```json
+/// {
+///   "dev": { "mode": "1", "hashType": "none", "emitAssembly": true },
+///   "production": { "mode": "z", "sizeFallback": true, "hashType": "ipfs" }
+/// }
+/// ```
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Profile {
+    /// The optimization mode.
+    /// Overrides `settings.optimizer.mode`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<char>,
+    /// Whether to try to recompile with optimizations for size if the bytecode is too large.
+    /// Overrides `settings.optimizer.fallbackToOptimizingForSize`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_fallback: Option<bool>,
+    /// The LLVM loop optimization mode.
+    /// Overrides `settings.optimizer.loopOptimization`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub loop_optimization: Option<LoopOptimization>,
+    /// The metadata hash type appended to the bytecode.
+    /// Overrides `settings.metadata.hashType`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash_type: Option<era_compiler_common::HashType>,
+    /// Whether to use literal content in the metadata.
+    /// Overrides `settings.metadata.useLiteralContent`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub use_literal_content: Option<bool>,
+    /// Whether to emit the EraVM assembly of all contracts, which is useful for debugging.
+    /// Extends `settings.outputSelection` with `eravm.assembly`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub emit_assembly: Option<bool>,
+    /// The extra LLVM options applied to all contracts, e.g. the debugging ones.
+    /// Overrides the global `settings.LLVMOptions`.
+    #[serde(
+        default,
+        rename = "LLVMOptions",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub llvm_options: Option<Vec<String>>,
+}
@@ -0,0 +1,158 @@
+//!
+//! The import remapping.
+//!
+
+use std::collections::BTreeSet;
+use std::str::FromStr;
+
+///
+/// The import remapping of the `[context:]prefix=target` format.
+///
+/// Imports starting with `prefix` in sources whose names start with `context` have the prefix
+/// replaced with `target`, e.g. `@openzeppelin/=lib/openzeppelin/`. An empty context matches all
+/// sources.
+///
+/// The remappings are passed around as strings in the public interfaces, and are only parsed
+/// where they are validated or applied.
+///
+#[derive(
+    Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, serde::Serialize, serde::Deserialize,
+)]
+#[serde(try_from = "String", into = "String")]
+pub struct Remapping {
+    /// The source name prefix of the importing sources.
+    pub context: String,
+    /// The replaced prefix of the import paths.
+    pub prefix: String,
+    /// The replacement of the prefix.
+    pub target: String,
+}
+
+impl Remapping {
+    ///
+    /// Remaps the `import` path of the source `name` with the best matching remapping.
+    ///
+    /// Like in `solc`, the remapping with the longest context wins, and then the one with the
+    /// longest prefix. Returns `None` if no remapping matches.
+    ///
+    pub fn apply<'a>(
+        remappings: impl IntoIterator<Item = &'a Self>,
+        name: &str,
+        import: &str,
+    ) -> Option<String> {
+        remappings
+            .into_iter()
+            .filter(|remapping| {
+                name.starts_with(remapping.context.as_str())
+                    && import.starts_with(remapping.prefix.as_str())
+            })
+            .max_by_key(|remapping| (remapping.context.len(), remapping.prefix.len()))
+            .map(|remapping| format!("{}{}", remapping.target, &import[remapping.prefix.len()..]))
+    }
+
+    ///
+    /// Parses and validates the remapping strings.
+    ///
+    pub fn try_from_strings(remappings: &BTreeSet<String>) -> anyhow::Result<BTreeSet<Self>> {
+        remappings
+            .iter()
+            .map(|remapping| Self::from_str(remapping.as_str()))
+            .collect()
+    }
+
+    ///
+    /// Remaps the source `path` specified by the user, so it matches the source name assigned by
+    /// `solc` to the imported source.
+    ///
+    /// Only the context-free remappings are applied. The paths of the input sources are left
+    /// intact, as `solc` does not remap them.
+    ///
+    pub fn remap_source_path(
+        remappings: &BTreeSet<Self>,
+        path: String,
+        is_source: impl Fn(&str) -> bool,
+    ) -> String {
+        if is_source(path.as_str()) {
+            return path;
+        }
+        Self::apply(remappings, "", path.as_str()).unwrap_or(path)
+    }
+
+    ///
+    /// Normalizes the remapping paths, so they are represented equally on all host systems.
+    ///
+    pub fn normalize_paths(self) -> Self {
+        Self {
+            context: crate::path::normalize(self.context.as_str()),
+            prefix: crate::path::normalize(self.prefix.as_str()),
+            target: crate::path::normalize(self.target.as_str()),
+        }
+    }
+
+    ///
+    /// Returns the index of the colon separating the context from the prefix.
+    ///
+    /// The colons of Windows drive letters, such as in `C:/project:@oz/=C:/lib/oz/`, are skipped.
+    ///
+    fn context_separator(source: &str) -> Option<usize> {
+        let bytes = source.as_bytes();
+        source
+            .match_indices(':')
+            .map(|(index, _)| index)
+            .find(|&index| {
+                let is_drive_letter = index >= 1
+                    && bytes[index - 1].is_ascii_alphabetic()
+                    && (index == 1 || bytes[index - 2] == b':')
+                    && matches!(bytes.get(index + 1), Some(b'/') | Some(b'\\'));
+                !is_drive_letter
+            })
+    }
+}
+
+impl FromStr for Remapping {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        let (source, target) = match string.split('=').collect::<Vec<&str>>().as_slice() {
+            [source, target] => (*source, *target),
+            _ => {
+                anyhow::bail!("Invalid remapping `{string}`: expected two parts separated by '='.")
+            }
+        };
+        let (context, prefix) = match Self::context_separator(source) {
+            Some(index) => (&source[..index], &source[index + 1..]),
+            None => ("", source),
+        };
+        if prefix.is_empty() {
+            anyhow::bail!("Invalid remapping `{string}`: the prefix is empty.");
+        }
+        Ok(Self {
+            context: context.to_owned(),
+            prefix: prefix.to_owned(),
+            target: target.to_owned(),
+        })
+    }
+}
+
+impl TryFrom<String> for Remapping {
+    type Error = anyhow::Error;
+
+    fn try_from(string: String) -> Result<Self, Self::Error> {
+        Self::from_str(string.as_str())
+    }
+}
+
+impl From<Remapping> for String {
+    fn from(remapping: Remapping) -> Self {
+        remapping.to_string()
+    }
+}
+
+impl std::fmt::Display for Remapping {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        if !self.context.is_empty() {
+            write!(f, "{}:", self.context)?;
+        }
+        write!(f, "{}={}", self.prefix, self.target)
+    }
+}
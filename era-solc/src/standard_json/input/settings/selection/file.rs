@@ -2,6 +2,7 @@
 //! The `solc --standard-json` output file selection.
 //!
 
+use std::collections::BTreeMap;
 use std::collections::HashSet;
 
 use crate::standard_json::input::settings::selection::selector::Selector;
@@ -17,6 +18,9 @@ pub struct File {
     /// The per-contract output selections.
     #[serde(default, rename = "*", skip_serializing_if = "HashSet::is_empty")]
     pub per_contract: HashSet<Selector>,
+    /// The output selections of specific contracts, keyed by contract name.
+    #[serde(flatten)]
+    pub contracts: BTreeMap<String, HashSet<Selector>>,
 }
 
 impl File {
@@ -39,6 +43,7 @@ impl File {
         Self {
             per_file,
             per_contract,
+            contracts: BTreeMap::new(),
         }
     }
 
@@ -48,6 +53,9 @@ impl File {
     pub fn extend(&mut self, other: Self) -> &mut Self {
         self.per_file.extend(other.per_file);
         self.per_contract.extend(other.per_contract);
+        for (name, flags) in other.contracts.into_iter() {
+            self.contracts.entry(name).or_default().extend(flags);
+        }
         self
     }
 
@@ -64,6 +72,9 @@ impl File {
             Selector::Metadata,
             Selector::Yul,
             Selector::EVMLA,
+            Selector::EVMBytecodeRaw,
+            Selector::EraVMAssembly,
+            Selector::EraVMAssemblyJSON,
         ];
 
         let mut unset_per_file = HashSet::with_capacity(required_per_file.len());
@@ -82,6 +93,7 @@ impl File {
         Self {
             per_file: unset_per_file,
             per_contract: unset_per_contract,
+            contracts: BTreeMap::new(),
         }
     }
 
@@ -95,10 +107,21 @@ impl File {
         }
     }
 
+    ///
+    /// Whether the code generation output is requested for the contract `name`, either for all
+    /// contracts or for the contract specifically.
+    ///
+    pub fn is_bytecode_requested(&self, name: &str) -> bool {
+        self.per_contract
+            .iter()
+            .chain(self.contracts.get(name).into_iter().flatten())
+            .any(Selector::is_bytecode)
+    }
+
     ///
     /// Checks whether the selection is empty.
     ///
     pub fn is_empty(&self) -> bool {
-        self.per_file.is_empty() && self.per_contract.is_empty()
+        self.per_file.is_empty() && self.per_contract.is_empty() && self.contracts.is_empty()
     }
 }
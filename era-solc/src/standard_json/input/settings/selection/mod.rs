@@ -5,6 +5,8 @@
 pub mod file;
 pub mod selector;
 
+use std::collections::BTreeMap;
+
 use crate::standard_json::input::settings::codegen::Codegen as StandardJsonInputSettingsCodegen;
 
 use self::file::File as FileSelection;
@@ -18,6 +20,12 @@ pub struct Selection {
     /// Only the 'all' wildcard is available for robustness reasons.
     #[serde(default, rename = "*", skip_serializing_if = "FileSelection::is_empty")]
     pub all: FileSelection,
+    /// The output selections of specific files, keyed by source name.
+    ///
+    /// They are passed to `solc` as is, and only used by `zksolc` to skip the code generation
+    /// for contracts whose bytecode has not been requested.
+    #[serde(flatten)]
+    pub files: BTreeMap<String, FileSelection>,
 }
 
 impl Selection {
@@ -27,6 +35,7 @@ impl Selection {
     pub fn new(flags: Vec<Selector>) -> Self {
         Self {
             all: FileSelection::new(flags),
+            files: BTreeMap::new(),
         }
     }
 
@@ -54,6 +63,9 @@ impl Selection {
     ///
     pub fn extend(&mut self, other: Self) -> &mut Self {
         self.all.extend(other.all);
+        for (path, selection) in other.files.into_iter() {
+            self.files.entry(path).or_default().extend(selection);
+        }
         self
     }
 
@@ -66,6 +78,7 @@ impl Selection {
     pub fn selection_to_prune(&self) -> Self {
         Self {
             all: self.all.selection_to_prune(),
+            files: BTreeMap::new(),
         }
     }
 
@@ -75,4 +88,25 @@ impl Selection {
     pub fn contains(&self, flag: &Selector) -> bool {
         self.all.contains(flag)
     }
+
+    ///
+    /// Whether the selection is granular, that is, it has entries for specific files or contracts.
+    ///
+    /// Selections with the wildcards only are not granular, so all contracts are compiled even if
+    /// no bytecode is selected, as the bytecode of all contracts has always been emitted in this case.
+    ///
+    pub fn is_granular(&self) -> bool {
+        !self.files.is_empty() || !self.all.contracts.is_empty()
+    }
+
+    ///
+    /// Whether the code generation output is requested for the contract `name` in the file `path`.
+    ///
+    pub fn is_bytecode_requested(&self, path: &str, name: &str) -> bool {
+        self.all.is_bytecode_requested(name)
+            || self
+                .files
+                .get(path)
+                .is_some_and(|selection| selection.is_bytecode_requested(name))
+    }
 }
@@ -2,47 +2,134 @@
 //! The `solc --standard-json` expected output selector.
 //!
 
+use std::str::FromStr;
+
 use crate::standard_json::input::settings::codegen::Codegen as StandardJsonInputSettingsCodegen;
 
 ///
 /// The `solc --standard-json` expected output selector.
 ///
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+/// The selectors not used by `zksolc` are kept as is, and passed to `solc` unchanged.
+///
+#[derive(Debug, Clone, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum Selector {
     /// The ABI JSON.
-    #[serde(rename = "abi")]
     ABI,
     /// The metadata.
-    #[serde(rename = "metadata")]
     Metadata,
     /// The developer documentation.
-    #[serde(rename = "devdoc")]
     Devdoc,
     /// The user documentation.
-    #[serde(rename = "userdoc")]
     Userdoc,
     /// The storage layout.
-    #[serde(rename = "storageLayout")]
     StorageLayout,
+    /// The transient storage layout.
+    TransientStorageLayout,
     /// The AST JSON.
-    #[serde(rename = "ast")]
     AST,
     /// The Yul IR.
-    #[serde(rename = "irOptimized")]
     Yul,
     /// The EVM bytecode.
-    #[serde(rename = "evm")]
     EVM,
+    /// The EVM deploy bytecode.
+    EVMBytecode,
+    /// The EVM runtime bytecode.
+    EVMDeployedBytecode,
+    /// The raw bytes of the bytecode, only emitted if requested explicitly.
+    EVMBytecodeRaw,
     /// The EVM legacy assembly JSON.
-    #[serde(rename = "evm.legacyAssembly")]
     EVMLA,
     /// The function signature hashes JSON.
-    #[serde(rename = "evm.methodIdentifiers")]
     MethodIdentifiers,
 
     /// The EraVM assembly.
-    #[serde(rename = "eravm.assembly")]
     EraVMAssembly,
+    /// The EraVM assembly in the structured JSON form.
+    EraVMAssemblyJSON,
+
+    /// Any other selector, e.g. a sub-selector such as `evm.bytecode.object`, or the `*` wildcard.
+    Other(String),
+}
+
+impl Selector {
+    /// The standard JSON names of the selectors known to `zksolc`.
+    pub const NAMES: [(&'static str, Self); 16] = [
+        ("abi", Self::ABI),
+        ("metadata", Self::Metadata),
+        ("devdoc", Self::Devdoc),
+        ("userdoc", Self::Userdoc),
+        ("storageLayout", Self::StorageLayout),
+        ("transientStorageLayout", Self::TransientStorageLayout),
+        ("ast", Self::AST),
+        ("irOptimized", Self::Yul),
+        ("evm", Self::EVM),
+        ("evm.bytecode", Self::EVMBytecode),
+        ("evm.deployedBytecode", Self::EVMDeployedBytecode),
+        ("evm.bytecode.raw", Self::EVMBytecodeRaw),
+        ("evm.legacyAssembly", Self::EVMLA),
+        ("evm.methodIdentifiers", Self::MethodIdentifiers),
+        ("eravm.assembly", Self::EraVMAssembly),
+        ("eravm.assemblyJson", Self::EraVMAssemblyJSON),
+    ];
+
+    /// The wildcard selecting all outputs.
+    pub const WILDCARD: &'static str = "*";
+
+    /// The selectors available in the CLI output selection, along with their CLI names.
+    pub const CLI_NAMES: [(&'static str, Self); 5] = [
+        ("bytecode", Self::EVM),
+        ("abi", Self::ABI),
+        ("metadata", Self::Metadata),
+        ("asm", Self::EraVMAssembly),
+        ("hashes", Self::MethodIdentifiers),
+    ];
+
+    ///
+    /// Whether the selector requests the output of the code generation, that is, the bytecode or
+    /// the assembly.
+    ///
+    /// The sub-selectors of the bytecode selectors, e.g. `evm.bytecode.object`, and the wildcard
+    /// are also taken into account.
+    ///
+    pub fn is_bytecode(&self) -> bool {
+        match self {
+            Self::EVM
+            | Self::EVMBytecode
+            | Self::EVMDeployedBytecode
+            | Self::EVMBytecodeRaw
+            | Self::EraVMAssembly
+            | Self::EraVMAssemblyJSON => true,
+            Self::Other(selector) => {
+                selector == Self::WILDCARD
+                    || ["evm.bytecode.", "evm.deployedBytecode.", "eravm."]
+                        .iter()
+                        .any(|prefix| selector.starts_with(prefix))
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for Selector {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        Self::CLI_NAMES
+            .iter()
+            .find(|(name, _)| *name == string)
+            .map(|(_, selector)| selector.to_owned())
+            .ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Invalid output selection: `{string}`. Available options: {}.",
+                    Self::CLI_NAMES
+                        .iter()
+                        .map(|(name, _)| *name)
+                        .collect::<Vec<&str>>()
+                        .join(", ")
+                )
+            })
+    }
 }
 
 impl From<StandardJsonInputSettingsCodegen> for Selector {
@@ -53,3 +140,26 @@ impl From<StandardJsonInputSettingsCodegen> for Selector {
         }
     }
 }
+
+impl From<String> for Selector {
+    fn from(string: String) -> Self {
+        Self::NAMES
+            .iter()
+            .find(|(name, _)| *name == string.as_str())
+            .map(|(_, selector)| selector.to_owned())
+            .unwrap_or(Self::Other(string))
+    }
+}
+
+impl From<Selector> for String {
+    fn from(selector: Selector) -> Self {
+        match selector {
+            Selector::Other(string) => string,
+            selector => Selector::NAMES
+                .iter()
+                .find(|(_, known)| known == &selector)
+                .map(|(name, _)| (*name).to_owned())
+                .expect("Always exists"),
+        }
+    }
+}
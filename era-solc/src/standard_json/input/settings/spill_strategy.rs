@@ -0,0 +1,67 @@
+//!
+//! The LLVM register allocator spill strategy.
+//!
+
+use std::str::FromStr;
+
+///
+/// The LLVM register allocator spill strategy.
+///
+/// Controls how the live ranges of functions exceeding the register availability are split
+/// before spilling, which may help pathological functions that either bloat the bytecode or run out of stack.
+/// The values are always spilled to stack frame slots, as the backends have no other spill location.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SpillStrategy {
+    /// The split live ranges are spilled with the back-copies hoisted, minimizing the number of copies.
+    Size,
+    /// The split live ranges are spilled next to their uses, minimizing the number of executed copies.
+    Speed,
+    /// The split live ranges are partitioned, and spilling is deferred until live range splitting fails.
+    Hybrid,
+}
+
+impl SpillStrategy {
+    ///
+    /// Returns the LLVM options implementing the strategy.
+    ///
+    pub fn llvm_options(&self) -> Vec<String> {
+        let options: &[&str] = match self {
+            Self::Size => &["-split-spill-mode=size"],
+            Self::Speed => &["-split-spill-mode=speed"],
+            Self::Hybrid => &["-split-spill-mode=default", "-enable-deferred-spilling"],
+        };
+        options.iter().map(|option| option.to_string()).collect()
+    }
+}
+
+impl FromStr for SpillStrategy {
+    type Err = anyhow::Error;
+
+    fn from_str(string: &str) -> Result<Self, Self::Err> {
+        match string {
+            "size" => Ok(Self::Size),
+            "speed" => Ok(Self::Speed),
+            "hybrid" => Ok(Self::Hybrid),
+            string => anyhow::bail!(
+                "Invalid spill strategy: `{string}`. Available options: {}.",
+                [Self::Size, Self::Speed, Self::Hybrid]
+                    .into_iter()
+                    .map(|strategy| strategy.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
+
+impl std::fmt::Display for SpillStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Size => write!(f, "size"),
+            Self::Speed => write!(f, "speed"),
+            Self::Hybrid => write!(f, "hybrid"),
+        }
+    }
+}
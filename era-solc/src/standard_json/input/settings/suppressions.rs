@@ -0,0 +1,75 @@
+//!
+//! The `solc --standard-json` input settings per-source suppressions.
+//!
+
+use std::str::FromStr;
+
+use crate::standard_json::input::settings::error_type::ErrorType;
+use crate::standard_json::input::settings::policy::Policy;
+use crate::standard_json::input::settings::warning_type::WarningType;
+
+///
+/// The errors and warnings suppressed in a single source.
+///
+/// Specified as a list of error and warning types, e.g. `["txorigin", "sendtransfer"]`.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "Vec<String>", into = "Vec<String>")]
+pub struct Suppressions {
+    /// The suppressed errors.
+    pub errors: Vec<ErrorType>,
+    /// The suppressed warnings.
+    pub warnings: Vec<WarningType>,
+}
+
+impl Suppressions {
+    ///
+    /// Returns the suppressions without the warnings forbidden by the `policy`,
+    /// as the latter cannot be suppressed.
+    ///
+    pub fn without_forbidden(&self, policy: &Policy) -> Self {
+        Self {
+            errors: self.errors.clone(),
+            warnings: self
+                .warnings
+                .iter()
+                .copied()
+                .filter(|warning_type| policy.forbids_warning(*warning_type).is_none())
+                .collect(),
+        }
+    }
+}
+
+impl TryFrom<Vec<String>> for Suppressions {
+    type Error = anyhow::Error;
+
+    fn try_from(types: Vec<String>) -> Result<Self, Self::Error> {
+        let mut suppressions = Self::default();
+        for r#type in types.into_iter() {
+            if let Ok(error_type) = ErrorType::from_str(r#type.as_str()) {
+                suppressions.errors.push(error_type);
+            } else if let Ok(warning_type) = WarningType::from_str(r#type.as_str()) {
+                suppressions.warnings.push(warning_type);
+            } else {
+                anyhow::bail!("Invalid suppressed error or warning type: {type}");
+            }
+        }
+        Ok(suppressions)
+    }
+}
+
+impl From<Suppressions> for Vec<String> {
+    fn from(suppressions: Suppressions) -> Self {
+        suppressions
+            .errors
+            .iter()
+            .map(|error_type| error_type.to_string())
+            .chain(
+                suppressions
+                    .warnings
+                    .iter()
+                    .map(|warning_type| warning_type.to_string()),
+            )
+            .collect()
+    }
+}
@@ -12,6 +12,16 @@ use std::str::FromStr;
 pub enum WarningType {
     /// The eponymous feature.
     TxOrigin,
+    /// The eponymous feature.
+    BlockRandomness,
+    /// The eponymous feature.
+    AssemblyInitCode,
+    /// The eponymous feature.
+    CreationCode,
+    /// The eponymous feature.
+    PrecompileCall,
+    /// The eponymous feature.
+    AbiCoderV1,
 }
 
 impl WarningType {
@@ -32,6 +42,11 @@ impl FromStr for WarningType {
     fn from_str(string: &str) -> Result<Self, Self::Err> {
         match string {
             "txorigin" => Ok(Self::TxOrigin),
+            "blockrandomness" => Ok(Self::BlockRandomness),
+            "assemblyinitcode" => Ok(Self::AssemblyInitCode),
+            "creationcode" => Ok(Self::CreationCode),
+            "precompilecall" => Ok(Self::PrecompileCall),
+            "abicoderv1" => Ok(Self::AbiCoderV1),
             r#type => Err(anyhow::anyhow!("Invalid suppressed warning type: {type}")),
         }
     }
@@ -41,6 +56,11 @@ impl std::fmt::Display for WarningType {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
             Self::TxOrigin => write!(f, "txorigin"),
+            Self::BlockRandomness => write!(f, "blockrandomness"),
+            Self::AssemblyInitCode => write!(f, "assemblyinitcode"),
+            Self::CreationCode => write!(f, "creationcode"),
+            Self::PrecompileCall => write!(f, "precompilecall"),
+            Self::AbiCoderV1 => write!(f, "abicoderv1"),
         }
     }
 }
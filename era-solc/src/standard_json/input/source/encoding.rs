@@ -0,0 +1,105 @@
+//!
+//! The source code encoding.
+//!
+
+/// The UTF-8 byte order mark.
+pub const BOM_UTF8: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
+/// The UTF-16 little-endian byte order mark.
+pub const BOM_UTF16_LE: [u8; 2] = [0xFF, 0xFE];
+
+/// The UTF-16 big-endian byte order mark.
+pub const BOM_UTF16_BE: [u8; 2] = [0xFE, 0xFF];
+
+///
+/// The source code encoding transcoded to UTF-8.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// UTF-16 little-endian with the byte order mark.
+    Utf16LE,
+    /// UTF-16 big-endian with the byte order mark.
+    Utf16BE,
+}
+
+impl Encoding {
+    ///
+    /// Decodes the source code, stripping the UTF-8 byte order mark.
+    ///
+    /// UTF-16 sources with the byte order mark are unambiguous, so they are transcoded to UTF-8,
+    /// and the original encoding is returned to be reported. Other encodings cannot be reliably
+    /// told apart, so such sources are rejected with the offset of the first invalid byte.
+    ///
+    pub fn decode(bytes: Vec<u8>, name: &str) -> anyhow::Result<(String, Option<Self>)> {
+        if let Some(bytes) = bytes.strip_prefix(BOM_UTF16_LE.as_slice()) {
+            return Self::decode_utf16(bytes, name, u16::from_le_bytes)
+                .map(|source| (source, Some(Self::Utf16LE)));
+        }
+        if let Some(bytes) = bytes.strip_prefix(BOM_UTF16_BE.as_slice()) {
+            return Self::decode_utf16(bytes, name, u16::from_be_bytes)
+                .map(|source| (source, Some(Self::Utf16BE)));
+        }
+
+        let offset = if bytes.starts_with(BOM_UTF8.as_slice()) {
+            BOM_UTF8.len()
+        } else {
+            0
+        };
+        match std::str::from_utf8(&bytes[offset..]) {
+            Ok(source) => Ok((source.to_owned(), None)),
+            Err(error) => {
+                let position = offset + error.valid_up_to();
+                let line = bytes[..position]
+                    .iter()
+                    .filter(|byte| **byte == b'\n')
+                    .count()
+                    + 1;
+                let hint = if bytes.contains(&0) {
+                    "The file looks like UTF-16 without the byte order mark."
+                } else {
+                    "The file looks like a legacy single-byte encoding, e.g. Windows-1252."
+                };
+                anyhow::bail!(
+                    "{name} is not valid UTF-8: invalid byte 0x{:02x} at offset {position} (line {line}). {hint} Please re-save it as UTF-8.",
+                    bytes[position],
+                )
+            }
+        }
+    }
+
+    ///
+    /// Decodes the UTF-16 source code without the byte order mark.
+    ///
+    fn decode_utf16(
+        bytes: &[u8],
+        name: &str,
+        from_bytes: fn([u8; 2]) -> u16,
+    ) -> anyhow::Result<String> {
+        if bytes.len() % 2 != 0 {
+            anyhow::bail!("{name} is not valid UTF-16: odd number of bytes.");
+        }
+        let units = bytes
+            .chunks_exact(2)
+            .map(|chunk| from_bytes([chunk[0], chunk[1]]))
+            .collect::<Vec<u16>>();
+        char::decode_utf16(units.iter().copied())
+            .map(|character| {
+                character.map_err(|error| {
+                    anyhow::anyhow!(
+                        "{name} is not valid UTF-16: unpaired surrogate 0x{:04x}.",
+                        error.unpaired_surrogate()
+                    )
+                })
+            })
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Utf16LE => write!(f, "UTF-16LE"),
+            Self::Utf16BE => write!(f, "UTF-16BE"),
+        }
+    }
+}
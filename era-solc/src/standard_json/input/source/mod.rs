@@ -2,9 +2,14 @@
 //! The `solc --standard-json` input source.
 //!
 
+pub mod encoding;
+
+use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 
+use self::encoding::Encoding;
+
 ///
 /// The `solc --standard-json` input source.
 ///
@@ -17,24 +22,59 @@ pub struct Source {
     /// The source file URLs.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub urls: Option<Vec<String>>,
+
+    /// The original encoding, if the source code has been transcoded to UTF-8.
+    #[serde(skip)]
+    pub encoding: Option<Encoding>,
 }
 
 impl Source {
+    /// The byte order mark, as it appears in decoded strings.
+    pub const BOM: char = '\u{feff}';
+
+    /// The path denoting the standard input.
+    pub const STDIN_PATH: &'static str = "-";
+
+    ///
+    /// Whether the `path` denotes the standard input.
+    ///
+    pub fn is_stdin(path: &Path) -> bool {
+        path.as_os_str() == Self::STDIN_PATH
+    }
+
+    ///
+    /// Returns the source unit name of the source at `path`.
+    ///
+    /// The source read from the standard input is named `stdin_name` if it is specified.
+    ///
+    pub fn name(path: &Path, stdin_name: Option<&str>) -> String {
+        match stdin_name {
+            Some(name) if Self::is_stdin(path) => name.to_owned(),
+            _ => crate::path::normalize_path(path),
+        }
+    }
+
     ///
     /// Reads the source from the file system.
     ///
     pub fn try_read(path: &Path) -> anyhow::Result<Self> {
-        let content = if path.to_string_lossy() == "-" {
-            std::io::read_to_string(std::io::stdin())
-                .map_err(|error| anyhow::anyhow!("<stdin> reading: {error}"))
+        let (name, bytes) = if Self::is_stdin(path) {
+            let mut bytes = Vec::new();
+            std::io::stdin()
+                .read_to_end(&mut bytes)
+                .map_err(|error| anyhow::anyhow!("<stdin> reading: {error}"))?;
+            ("<stdin>".to_owned(), bytes)
         } else {
-            std::fs::read_to_string(path)
-                .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))
-        }?;
+            let bytes = std::fs::read(path)
+                .map_err(|error| anyhow::anyhow!("File {path:?} reading: {error}"))?;
+            (format!("File {path:?}"), bytes)
+        };
+        let (content, encoding) = Encoding::decode(bytes, name.as_str())?;
 
         Ok(Self {
             content: Some(content),
             urls: None,
+            encoding,
         })
     }
 
@@ -75,6 +115,17 @@ impl Source {
         }
     }
 
+    ///
+    /// Strips the byte order mark from the source code passed as a string.
+    ///
+    pub fn strip_bom(&mut self) {
+        if let Some(content) = self.content.as_mut() {
+            if content.starts_with(Self::BOM) {
+                content.remove(0);
+            }
+        }
+    }
+
     ///
     /// Takes ownership of the source code and returns it.
     ///
@@ -95,6 +146,7 @@ impl From<String> for Source {
         Self {
             content: Some(content),
             urls: None,
+            encoding: None,
         }
     }
 }
@@ -104,6 +156,7 @@ impl From<&Path> for Source {
         Self {
             content: None,
             urls: Some(vec![path.to_string_lossy().to_string()]),
+            encoding: None,
         }
     }
 }
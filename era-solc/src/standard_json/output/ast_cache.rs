@@ -0,0 +1,172 @@
+//!
+//! The AST check result cache.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::cache::Directory as CacheDirectory;
+use crate::exit_code::ExitCode;
+use crate::standard_json::input::settings::diagnostics::Diagnostics as StandardJsonInputSettingsDiagnostics;
+use crate::standard_json::input::settings::error_type::ErrorType as StandardJsonInputSettingsErrorType;
+use crate::standard_json::input::settings::policy::Policy as StandardJsonInputSettingsPolicy;
+use crate::standard_json::input::settings::suppressions::Suppressions as StandardJsonInputSettingsSuppressions;
+use crate::standard_json::input::settings::warning_type::WarningType as StandardJsonInputSettingsWarningType;
+use crate::standard_json::input::source::Source as StandardJSONInputSource;
+use crate::standard_json::output::compatibility_report::Finding as CompatibilityReportFinding;
+use crate::standard_json::output::error::sarif::Region as StandardJsonOutputErrorSarifRegion;
+use crate::standard_json::output::error::Error as StandardJsonOutputError;
+use crate::standard_json::output::import_graph::ImportGraph;
+use crate::version::Version;
+
+///
+/// The AST check result cache.
+///
+/// The results are stored as JSON files named after the hash of the source path and the content
+/// of the source and all sources it imports, directly or transitively. Therefore, editing a source
+/// invalidates the results of the sources depending on it, whose diagnostics may refer to it.
+/// The hash also covers the compiler versions and the settings affecting the checks,
+/// so changing any of them invalidates the whole cache.
+///
+/// Only the results independent of the AST node IDs are cached, as the IDs are assigned by `solc`
+/// across all sources of the compilation and change whenever any source changes.
+///
+#[derive(Debug, Clone)]
+pub struct AstCache {
+    /// The cache directory.
+    directory: CacheDirectory,
+    /// The serialized compiler versions and settings affecting the checks.
+    settings: String,
+}
+
+///
+/// The cached AST check results of a source.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+    /// The errors and warnings.
+    pub messages: Vec<Message>,
+    /// The compatibility report findings, grouped by unit.
+    pub findings: Vec<(String, Vec<CompatibilityReportFinding>)>,
+}
+
+///
+/// The cached error or warning.
+///
+/// Includes the fields not serialized to the standard JSON output.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Message {
+    /// The error or warning.
+    #[serde(flatten)]
+    pub error: StandardJsonOutputError,
+    /// The exit code overriding the one derived from the error type.
+    pub exit_code: Option<ExitCode>,
+    /// The suppressible warning type, if the message is such a warning.
+    pub warning_type: Option<StandardJsonInputSettingsWarningType>,
+    /// The SARIF region of the source location.
+    #[serde(default)]
+    pub region: Option<StandardJsonOutputErrorSarifRegion>,
+    /// Whether the message has been promoted to an error by a severity override.
+    #[serde(default)]
+    pub is_promoted: bool,
+}
+
+impl From<StandardJsonOutputError> for Message {
+    fn from(error: StandardJsonOutputError) -> Self {
+        Self {
+            exit_code: error.exit_code,
+            warning_type: error.warning_type,
+            region: error.region.clone(),
+            is_promoted: error.is_promoted,
+            error,
+        }
+    }
+}
+
+impl From<Message> for StandardJsonOutputError {
+    fn from(message: Message) -> Self {
+        let mut error = message.error;
+        error.exit_code = message.exit_code;
+        error.warning_type = message.warning_type;
+        error.region = message.region;
+        error.is_promoted = message.is_promoted;
+        error
+    }
+}
+
+impl AstCache {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        directory: &Path,
+        version: &Version,
+        suppressed_errors: &[StandardJsonInputSettingsErrorType],
+        suppressed_warnings: &[StandardJsonInputSettingsWarningType],
+        suppressions: &BTreeMap<String, StandardJsonInputSettingsSuppressions>,
+        diagnostics: &StandardJsonInputSettingsDiagnostics,
+        policy: &StandardJsonInputSettingsPolicy,
+        strict_eravm: bool,
+        compatibility_report: bool,
+    ) -> Self {
+        let settings = serde_json::to_string(&(
+            crate::version(),
+            version.long.as_str(),
+            suppressed_errors,
+            suppressed_warnings,
+            suppressions,
+            diagnostics,
+            policy,
+            strict_eravm,
+            compatibility_report,
+        ))
+        .expect("Always valid");
+
+        Self {
+            directory: CacheDirectory::new(directory, crate::cache::MAX_ENTRIES_DEFAULT),
+            settings,
+        }
+    }
+
+    ///
+    /// Returns the cache key of the source at `path`, if the content of the source and
+    /// all sources it imports is available.
+    ///
+    pub fn key(
+        &self,
+        path: &str,
+        import_graph: &ImportGraph,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<String> {
+        let mut preimage = format!("{}\0{path}", self.settings);
+        for path in import_graph.closure(path).into_iter() {
+            let content = sources.get(path.as_str())?.content()?;
+            preimage.push_str(format!("\0{path}\0{content}").as_str());
+        }
+        Some(hex::encode(
+            era_compiler_common::Hash::keccak256(preimage.as_bytes()).as_bytes(),
+        ))
+    }
+
+    ///
+    /// Returns the cached results, if the entry exists and is valid.
+    ///
+    pub fn get(&self, key: &str) -> Option<Entry> {
+        self.directory.get(key)
+    }
+
+    ///
+    /// Caches the results.
+    ///
+    pub fn insert(&self, key: &str, entry: &Entry) {
+        self.directory.insert(key, entry);
+    }
+
+    ///
+    /// Removes the least recently used entries exceeding the cache size limit.
+    ///
+    pub fn evict(&self) {
+        self.directory.evict();
+    }
+}
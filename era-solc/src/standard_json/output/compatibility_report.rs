@@ -0,0 +1,333 @@
+//!
+//! The EVM and EraVM semantic difference report extracted from the `solc` AST.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::standard_json::input::source::Source as StandardJsonInputSource;
+use crate::standard_json::output::error::mapped_location::MappedLocation;
+use crate::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The kind of the construct behaving differently on EVM and EraVM.
+///
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum Kind {
+    /// Gas-dependent logic.
+    Gas,
+    /// Contract deployment and address derivation.
+    AddressDerivation,
+    /// Code size, hash, and copy introspection.
+    CodeIntrospection,
+    /// Transaction origin and signature checks.
+    AccountAbstraction,
+    /// Block properties.
+    BlockInfo,
+    /// Gas-limited value transfers.
+    ValueTransfer,
+}
+
+impl Kind {
+    ///
+    /// Returns the description of the difference.
+    ///
+    pub fn description(&self) -> &'static str {
+        match self {
+            Self::Gas => "EraVM gas costs differ from the EVM ones and include pubdata costs, so gas-dependent logic and hardcoded gas amounts may behave differently.",
+            Self::AddressDerivation => "Contracts are deployed by the ContractDeployer system contract, so CREATE and CREATE2 addresses are derived from different preimages than on EVM.",
+            Self::CodeIntrospection => "EraVM bytecode differs from the EVM one, so code sizes, hashes, and copies do not match the EVM values, and creation code cannot be used as a deployable blob.",
+            Self::AccountAbstraction => "With native account abstraction, transactions may be initiated by smart contract accounts, so `tx.origin` and ECDSA signature checks may not identify the account.",
+            Self::BlockInfo => "Block properties follow the L2 block and batch semantics, and some of them, e.g. `coinbase` and `difficulty`, return constant values.",
+            Self::ValueTransfer => "The fixed gas stipend of `send` and `transfer` may be insufficient on EraVM, depending on pubdata costs.",
+        }
+    }
+}
+
+///
+/// The construct behaving differently on EVM and EraVM.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Finding {
+    /// The difference kind.
+    pub kind: Kind,
+    /// The construct as it is written in the source code, e.g. `tx.origin` or `assembly extcodesize`.
+    pub construct: String,
+    /// The location in the `path:line:column` format.
+    pub location: String,
+}
+
+impl Finding {
+    ///
+    /// Extracts the finding from an AST node, if it is a construct behaving differently.
+    ///
+    pub fn try_from_node(
+        node: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Option<Self> {
+        let node = node.as_object()?;
+        let (kind, construct) = match node.get("nodeType")?.as_str()? {
+            "MemberAccess" => {
+                let member = node.get("memberName")?.as_str()?;
+                let expression = node.get("expression")?.as_object()?;
+                let type_identifier = expression
+                    .get("typeDescriptions")
+                    .and_then(|descriptions| descriptions.get("typeIdentifier"))
+                    .and_then(|identifier| identifier.as_str())
+                    .unwrap_or_default();
+                let name = match expression.get("nodeType").and_then(|kind| kind.as_str()) {
+                    Some("Identifier") => expression.get("name").and_then(|name| name.as_str()),
+                    _ => None,
+                };
+                let kind = match (name, member) {
+                    (Some("tx"), "origin") => Kind::AccountAbstraction,
+                    (Some("tx"), "gasprice") | (Some("msg"), "gas") => Kind::Gas,
+                    (
+                        Some("block"),
+                        "number" | "timestamp" | "coinbase" | "difficulty" | "prevrandao"
+                        | "basefee" | "gaslimit" | "blockhash",
+                    ) => Kind::BlockInfo,
+                    _ if type_identifier.starts_with("t_address")
+                        && ["code", "codehash"].contains(&member) =>
+                    {
+                        return Self::new(
+                            Kind::CodeIntrospection,
+                            format!("<address>.{member}"),
+                            node,
+                            id_paths,
+                            sources,
+                        );
+                    }
+                    _ if type_identifier.starts_with("t_address")
+                        && ["send", "transfer"].contains(&member) =>
+                    {
+                        return Self::new(
+                            Kind::ValueTransfer,
+                            format!("<address>.{member}"),
+                            node,
+                            id_paths,
+                            sources,
+                        );
+                    }
+                    _ if type_identifier.starts_with("t_magic_meta_type")
+                        && ["creationCode", "runtimeCode"].contains(&member) =>
+                    {
+                        return Self::new(
+                            Kind::CodeIntrospection,
+                            format!("type(<contract>).{member}"),
+                            node,
+                            id_paths,
+                            sources,
+                        );
+                    }
+                    _ => return None,
+                };
+                (kind, format!("{}.{member}", name?))
+            }
+            "Identifier" => {
+                let is_builtin = node
+                    .get("referencedDeclaration")
+                    .and_then(|declaration| declaration.as_i64())
+                    .is_some_and(|declaration| declaration < 0);
+                if !is_builtin {
+                    return None;
+                }
+                let name = node.get("name")?.as_str()?;
+                let kind = match name {
+                    "gasleft" => Kind::Gas,
+                    "blockhash" => Kind::BlockInfo,
+                    "ecrecover" => Kind::AccountAbstraction,
+                    _ => return None,
+                };
+                (kind, format!("{name}()"))
+            }
+            "FunctionCallOptions" => {
+                let names = node.get("names")?.as_array()?;
+                if !names.iter().any(|name| name.as_str() == Some("gas")) {
+                    return None;
+                }
+                (Kind::Gas, "{gas: <amount>}".to_owned())
+            }
+            "NewExpression" => {
+                let type_name = node.get("typeName")?.as_object()?;
+                if type_name.get("nodeType")?.as_str()? != "UserDefinedTypeName" {
+                    return None;
+                }
+                let name = type_name
+                    .get("pathNode")
+                    .and_then(|path| path.get("name"))
+                    .or_else(|| type_name.get("name"))
+                    .and_then(|name| name.as_str())
+                    .unwrap_or("<contract>");
+                (Kind::AddressDerivation, format!("new {name}"))
+            }
+            "YulFunctionCall" => {
+                let name = node.get("functionName")?.get("name")?.as_str()?;
+                let kind = match name {
+                    "gas" | "gasprice" | "gaslimit" => Kind::Gas,
+                    "create" | "create2" => Kind::AddressDerivation,
+                    "extcodesize" | "extcodecopy" | "extcodehash" | "codesize" | "codecopy" => {
+                        Kind::CodeIntrospection
+                    }
+                    "origin" => Kind::AccountAbstraction,
+                    "blockhash" | "coinbase" | "difficulty" | "prevrandao" | "basefee"
+                    | "number" | "timestamp" => Kind::BlockInfo,
+                    _ => return None,
+                };
+                (kind, format!("assembly {name}"))
+            }
+            _ => return None,
+        };
+
+        Self::new(kind, construct, node, id_paths, sources)
+    }
+
+    ///
+    /// A shortcut constructor, resolving the location of the AST node.
+    ///
+    fn new(
+        kind: Kind,
+        construct: String,
+        node: &serde_json::Map<String, serde_json::Value>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Option<Self> {
+        let source_location = SourceLocation::try_from_ast(node.get("src")?.as_str()?, id_paths)?;
+        let source_code = sources
+            .get(source_location.file.as_str())
+            .and_then(|source| source.content());
+        let location = MappedLocation::try_from_source_location(&source_location, source_code)
+            .to_short_string();
+
+        Some(Self {
+            kind,
+            construct,
+            location,
+        })
+    }
+}
+
+///
+/// The per-contract compatibility report.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Contract {
+    /// The number of findings of each kind.
+    pub summary: BTreeMap<Kind, usize>,
+    /// The findings.
+    pub findings: Vec<Finding>,
+}
+
+///
+/// The EVM and EraVM semantic difference report.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct CompatibilityReport {
+    /// The descriptions of the difference kinds found in the project.
+    pub kinds: BTreeMap<Kind, String>,
+    /// The per-contract reports, keyed by contract full path.
+    /// Constructs used outside of contracts, e.g. in free functions, are reported per source file.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl CompatibilityReport {
+    ///
+    /// Collects the findings in the top-level AST nodes of a source file, keyed by contract full path.
+    ///
+    pub fn get_findings(
+        path: &str,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Vec<(String, Vec<Finding>)> {
+        let nodes = match ast.get("nodes").and_then(|nodes| nodes.as_array()) {
+            Some(nodes) => nodes.as_slice(),
+            None => std::slice::from_ref(ast),
+        };
+
+        let mut units: Vec<(String, Vec<Finding>)> = Vec::new();
+        for node in nodes.iter() {
+            let mut findings = Vec::new();
+            Self::collect(node, id_paths, sources, &mut findings);
+            if findings.is_empty() {
+                continue;
+            }
+
+            let unit = match (
+                node.get("nodeType")
+                    .and_then(|node_type| node_type.as_str()),
+                node.get("name").and_then(|name| name.as_str()),
+            ) {
+                (Some("ContractDefinition"), Some(name)) => format!("{path}:{name}"),
+                _ => path.to_owned(),
+            };
+            match units.iter_mut().find(|(name, _)| name == &unit) {
+                Some((_, unit_findings)) => unit_findings.extend(findings),
+                None => units.push((unit, findings)),
+            }
+        }
+        units
+    }
+
+    ///
+    /// Builds the report from the findings collected from all sources.
+    ///
+    pub fn new(units: Vec<(String, Vec<Finding>)>) -> Self {
+        let mut report = Self::default();
+        for (unit, findings) in units.into_iter() {
+            let contract = report.contracts.entry(unit).or_default();
+            for finding in findings.into_iter() {
+                *contract.summary.entry(finding.kind).or_default() += 1;
+                report
+                    .kinds
+                    .entry(finding.kind)
+                    .or_insert_with(|| finding.kind.description().to_owned());
+                contract.findings.push(finding);
+            }
+        }
+        report
+    }
+
+    ///
+    /// Writes the report to the specified file in JSON format.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Collects the findings in the AST node and its children.
+    ///
+    fn collect(
+        node: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+        findings: &mut Vec<Finding>,
+    ) {
+        if let Some(finding) = Finding::try_from_node(node, id_paths, sources) {
+            findings.push(finding);
+        }
+
+        match node {
+            serde_json::Value::Array(array) => {
+                for element in array.iter() {
+                    Self::collect(element, id_paths, sources, findings);
+                }
+            }
+            serde_json::Value::Object(object) => {
+                for (_key, value) in object.iter() {
+                    Self::collect(value, id_paths, sources, findings);
+                }
+            }
+            _ => {}
+        }
+    }
+}
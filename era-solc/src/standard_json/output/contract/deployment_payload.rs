@@ -0,0 +1,37 @@
+//!
+//! The `solc --standard-json` output contract deployment payload.
+//!
+
+///
+/// The `solc --standard-json` output contract deployment payload.
+///
+/// On EraVM, the payload consists of the contract bytecode and all its factory dependencies, including
+/// transitive ones. Factory dependencies are published by their bytecode hashes, so identical
+/// ones, e.g. the same proxy deployed by several contracts, are only published once.
+///
+/// On EVM, the factory dependencies are embedded into the contract bytecode, so the dependencies
+/// with identical bytecode are embedded once, and the references to them are redirected to that copy.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeploymentPayload {
+    /// The number of unique factory dependencies, including transitive ones on EraVM.
+    pub factory_dependencies: usize,
+    /// The size of the contract bytecode and its unique factory dependencies in bytes.
+    pub size: usize,
+    /// The size of duplicate factory dependencies that are not published or embedded again, in bytes.
+    pub deduplicated_size: usize,
+}
+
+impl DeploymentPayload {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(factory_dependencies: usize, size: usize, deduplicated_size: usize) -> Self {
+        Self {
+            factory_dependencies,
+            size,
+            deduplicated_size,
+        }
+    }
+}
@@ -0,0 +1,202 @@
+//!
+//! The `solc --standard-json` output contract EraVM assembly JSON.
+//!
+
+use std::collections::BTreeMap;
+
+///
+/// The EraVM assembly instruction source location.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct SourceLocation {
+    /// The source file path.
+    pub file: String,
+    /// The one-based line number.
+    pub line: usize,
+    /// The one-based column number.
+    pub column: usize,
+}
+
+///
+/// The EraVM assembly instruction.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Instruction {
+    /// The instruction offset in the bytecode in bytes.
+    pub offset: usize,
+    /// The instruction mnemonic with modifiers, e.g. `sub.s!`.
+    pub mnemonic: String,
+    /// The instruction operands, as they are written in the assembly.
+    pub operands: Vec<String>,
+    /// The name of the function containing the instruction.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<String>,
+    /// The source location from the last `.loc` directive.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_location: Option<SourceLocation>,
+}
+
+///
+/// The EraVM assembly constant pool entry.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Constant {
+    /// The label the constant is referenced by, e.g. `CPI0_0`.
+    pub label: String,
+    /// The constant value, as it is written in the assembly.
+    pub value: String,
+}
+
+///
+/// The `solc --standard-json` output contract EraVM assembly JSON.
+///
+/// The structured form of the text assembly, so analysis tools do not have to parse the latter.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Assembly {
+    /// The code section instructions.
+    pub instructions: Vec<Instruction>,
+    /// The code section labels, mapped to the index of the instruction they point to.
+    pub labels: BTreeMap<String, usize>,
+    /// The constant pool from the data sections.
+    pub constants: Vec<Constant>,
+}
+
+impl Assembly {
+    /// The EraVM instruction size in bytes.
+    pub const INSTRUCTION_SIZE: usize = 8;
+
+    ///
+    /// Parses the text assembly.
+    ///
+    /// Labels not starting with `.` are treated as function symbols. The `.file` and `.loc`
+    /// directives are used to resolve source locations, and the other directives are skipped
+    /// as they do not occupy space in the bytecode.
+    ///
+    /// The disassembler output is also accepted, where instructions are prefixed with their
+    /// hexadecimal offset and encoding, e.g. `8: 00 00 00 40 00 30 04 3f  stm.h 64, r3`.
+    ///
+    pub fn from_text(assembly: &str) -> Self {
+        let mut result = Self::default();
+        let mut files = BTreeMap::new();
+        let mut function = None;
+        let mut source_location = None;
+        let mut is_code = true;
+        let mut constant_label: Option<String> = None;
+
+        for line in assembly.lines() {
+            let line = line.split(';').next().expect("Always exists").trim();
+            if line.is_empty() {
+                continue;
+            }
+            if [".rodata", ".data", ".section"]
+                .iter()
+                .any(|section| line.starts_with(section))
+            {
+                is_code = false;
+                continue;
+            }
+            if line.starts_with(".text") {
+                is_code = true;
+                continue;
+            }
+            if let Some(label) = line.strip_suffix(':') {
+                if is_code {
+                    if !label.starts_with('.') {
+                        function = Some(label.to_owned());
+                    }
+                    result
+                        .labels
+                        .insert(label.to_owned(), result.instructions.len());
+                } else {
+                    constant_label = Some(label.to_owned());
+                }
+                continue;
+            }
+
+            let (offset, line) = match Self::split_encoding(line) {
+                Some((offset, instruction)) => (offset, instruction),
+                None => (result.instructions.len() * Self::INSTRUCTION_SIZE, line),
+            };
+            let (mnemonic, operands) = line.split_once(char::is_whitespace).unwrap_or((line, ""));
+            let operands = operands.trim();
+
+            match mnemonic {
+                ".file" => {
+                    if let Some((Ok(number), path)) = operands
+                        .split_once(char::is_whitespace)
+                        .map(|(number, path)| (number.parse::<usize>(), path))
+                    {
+                        files.insert(number, path.trim().trim_matches('"').to_owned());
+                    }
+                }
+                ".loc" => {
+                    let numbers = operands
+                        .split_whitespace()
+                        .take(3)
+                        .map(|number| number.parse::<usize>().ok())
+                        .collect::<Option<Vec<usize>>>();
+                    source_location = numbers.and_then(|numbers| {
+                        Some(SourceLocation {
+                            file: files.get(numbers.first()?)?.to_owned(),
+                            line: *numbers.get(1)?,
+                            column: numbers.get(2).copied().unwrap_or_default(),
+                        })
+                    });
+                }
+                ".cell" if !is_code => result.constants.push(Constant {
+                    label: constant_label.clone().unwrap_or_default(),
+                    value: operands.to_owned(),
+                }),
+                directive if directive.starts_with('.') => {}
+                _ if !is_code => {}
+                mnemonic => result.instructions.push(Instruction {
+                    offset,
+                    mnemonic: mnemonic.to_owned(),
+                    operands: operands
+                        .split(',')
+                        .map(|operand| operand.trim().to_owned())
+                        .filter(|operand| !operand.is_empty())
+                        .collect(),
+                    function: function.clone(),
+                    source_location: source_location.clone(),
+                }),
+            }
+        }
+
+        result
+    }
+
+    ///
+    /// Splits the disassembler prefix with the instruction offset and encoding off the `line`.
+    ///
+    /// Returns `None` if the line is not prefixed, e.g. if it is taken from the compiler assembly.
+    ///
+    fn split_encoding(line: &str) -> Option<(usize, &str)> {
+        let (offset, mut instruction) = line.split_once(':')?;
+        let offset = usize::from_str_radix(offset.trim(), 16).ok()?;
+        for _ in 0..Self::INSTRUCTION_SIZE {
+            let remainder = instruction.trim_start();
+            match remainder.split_once(char::is_whitespace) {
+                Some((byte, rest))
+                    if byte.len() == 2 && byte.chars().all(|char| char.is_ascii_hexdigit()) =>
+                {
+                    instruction = rest;
+                }
+                _ => break,
+            }
+        }
+        Some((offset, instruction.trim()))
+    }
+}
+
+impl std::fmt::Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.mnemonic)?;
+        if !self.operands.is_empty() {
+            write!(f, " {}", self.operands.join(", "))?;
+        }
+        Ok(())
+    }
+}
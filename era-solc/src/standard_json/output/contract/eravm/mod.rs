@@ -0,0 +1,54 @@
+//!
+//! The `solc --standard-json` output contract EraVM data.
+//!
+
+pub mod assembly;
+
+use crate::standard_json::output::contract::deployment_payload::DeploymentPayload;
+
+use self::assembly::Assembly;
+
+///
+/// The `solc --standard-json` output contract EraVM data.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EraVM {
+    /// The contract bytecode.
+    pub bytecode: String,
+    /// The contract text assembly.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assembly: Option<String>,
+    /// The contract assembly in the structured JSON form.
+    /// Only parsed from the text assembly when the output is finalized, if selected.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub assembly_json: Option<Assembly>,
+    /// The deployment payload summary.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_payload: Option<DeploymentPayload>,
+}
+
+impl EraVM {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        bytecode: String,
+        assembly: Option<String>,
+        deployment_payload: Option<DeploymentPayload>,
+    ) -> Self {
+        Self {
+            bytecode,
+            assembly,
+            assembly_json: None,
+            deployment_payload,
+        }
+    }
+
+    ///
+    /// Parses the text assembly into the structured JSON form.
+    ///
+    pub fn set_assembly_json(&mut self) {
+        self.assembly_json = self.assembly.as_deref().map(Assembly::from_text);
+    }
+}
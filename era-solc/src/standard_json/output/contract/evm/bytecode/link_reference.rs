@@ -0,0 +1,15 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode link reference.
+//!
+
+///
+/// The `solc --standard-json` output contract EVM bytecode link reference.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LinkReference {
+    /// The byte offset of the library address placeholder.
+    pub start: usize,
+    /// The byte length of the library address placeholder.
+    pub length: usize,
+}
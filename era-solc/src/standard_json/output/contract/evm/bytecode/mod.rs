@@ -0,0 +1,78 @@
+//!
+//! The `solc --standard-json` output contract EVM bytecode.
+//!
+
+pub mod link_reference;
+
+use std::collections::BTreeMap;
+
+use self::link_reference::LinkReference;
+
+///
+/// The `solc --standard-json` output contract EVM bytecode.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Bytecode {
+    /// The bytecode object.
+    pub object: String,
+    /// The unlinked library references, keyed by source file and library name.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub link_references: BTreeMap<String, BTreeMap<String, Vec<LinkReference>>>,
+    /// The bytecode instructions, one per line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub opcodes: Option<String>,
+    /// The EraVM bytecode hash.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+
+    /// The raw bytecode bytes, only emitted if `evm.bytecode.raw` is selected.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub raw: Option<Vec<u8>>,
+}
+
+impl Bytecode {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(bytecode: Vec<u8>, opcodes: Option<String>, hash: Option<String>) -> Self {
+        Self {
+            object: hex::encode(bytecode.as_slice()),
+            link_references: BTreeMap::new(),
+            opcodes,
+            hash,
+            raw: Some(bytecode),
+        }
+    }
+
+    ///
+    /// Adds the link references of the unlinked libraries, keyed by library full path.
+    ///
+    /// Unlinked EraVM bytecode is an ELF object where libraries are resolved via relocations,
+    /// so the offsets point at the relocated library address fields in the object.
+    ///
+    pub fn with_unlinked_libraries(
+        mut self,
+        libraries: BTreeMap<String, Vec<LinkReference>>,
+    ) -> Self {
+        for (library, references) in libraries.into_iter() {
+            let (file, name) = library.rsplit_once(':').unwrap_or(("", library.as_str()));
+            self.link_references
+                .entry(file.to_owned())
+                .or_default()
+                .insert(name.to_owned(), references);
+        }
+        self
+    }
+
+    ///
+    /// Returns the raw bytecode, decoding the bytecode object if it is not available.
+    ///
+    pub fn to_bytes(&self) -> anyhow::Result<Vec<u8>> {
+        match self.raw {
+            Some(ref raw) => Ok(raw.to_owned()),
+            None => hex::decode(self.object.as_str())
+                .map_err(|error| anyhow::anyhow!("Bytecode object decoding: {error}")),
+        }
+    }
+}
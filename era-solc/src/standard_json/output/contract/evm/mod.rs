@@ -7,6 +7,8 @@ pub mod extra_metadata;
 
 use std::collections::BTreeMap;
 
+use crate::standard_json::output::contract::deployment_payload::DeploymentPayload;
+
 use self::bytecode::Bytecode;
 use self::extra_metadata::ExtraMetadata;
 
@@ -19,6 +21,9 @@ pub struct EVM {
     /// The contract bytecode.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub bytecode: Option<Bytecode>,
+    /// The contract runtime bytecode, only set for the EVM target.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub deployed_bytecode: Option<Bytecode>,
     /// The contract EVM legacy assembly code.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub legacy_assembly: serde_json::Value,
@@ -32,24 +37,26 @@ pub struct EVM {
     /// The extra EVMLA metadata.
     #[serde(default, skip_serializing_if = "Option::is_none")]
     pub extra_metadata: Option<ExtraMetadata>,
+    /// The EVM deployment payload summary.
+    #[serde(default, skip_serializing_if = "Option::is_none", skip_deserializing)]
+    pub deployment_payload: Option<DeploymentPayload>,
 }
 
 impl EVM {
     ///
     /// Sets the EraVM assembly and bytecode.
     ///
-    pub fn modify_eravm(&mut self, bytecode: String, assembly: Option<String>) {
-        self.bytecode = Some(Bytecode::new(bytecode));
+    pub fn modify_eravm(&mut self, bytecode: Bytecode, assembly: Option<String>) {
+        self.bytecode = Some(bytecode);
         self.assembly = assembly;
     }
 
     ///
-    /// Sets the EVM and deploy and runtime bytecode.
+    /// Sets the EVM deploy and runtime bytecode.
     ///
-    pub fn modify_evm(&mut self, deploy_bytecode: String, runtime_bytecode: String) {
-        let mut bytecode = deploy_bytecode;
-        bytecode.push_str(runtime_bytecode.as_str());
-        self.bytecode = Some(Bytecode::new(bytecode));
+    pub fn modify_evm(&mut self, bytecode: Bytecode, deployed_bytecode: Bytecode) {
+        self.bytecode = Some(bytecode);
+        self.deployed_bytecode = Some(deployed_bytecode);
     }
 
     ///
@@ -57,9 +64,11 @@ impl EVM {
     ///
     pub fn is_empty(&self) -> bool {
         self.bytecode.is_none()
+            && self.deployed_bytecode.is_none()
             && self.legacy_assembly.is_null()
             && self.method_identifiers.is_empty()
             && self.assembly.is_none()
             && self.extra_metadata.is_none()
+            && self.deployment_payload.is_none()
     }
 }
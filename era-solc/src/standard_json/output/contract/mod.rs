@@ -2,6 +2,7 @@
 //! The `solc --standard-json` output contract.
 //!
 
+pub mod deployment_payload;
 pub mod eravm;
 pub mod evm;
 
@@ -23,7 +24,7 @@ pub struct Contract {
     /// The contract storage layout.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub storage_layout: serde_json::Value,
-    /// The contract storage layout.
+    /// The contract transient storage layout.
     #[serde(default, skip_serializing_if = "serde_json::Value::is_null")]
     pub transient_storage_layout: serde_json::Value,
     /// The contract metadata.
@@ -4,6 +4,9 @@
 
 use std::io::Write;
 
+use crate::exit_code::ExitCode;
+use crate::exit_code::ExitCodeError;
+use crate::standard_json::output::error::sarif::Deferred as SarifDeferred;
 use crate::standard_json::output::error::Error;
 
 ///
@@ -30,32 +33,53 @@ pub trait Collectable {
         !self.errors().is_empty()
     }
 
+    ///
+    /// Returns the exit code of the most severe failure class among the errors.
+    ///
+    fn exit_code(&self) -> ExitCode {
+        self.errors()
+            .into_iter()
+            .map(Error::exit_code)
+            .max()
+            .unwrap_or(ExitCode::Success)
+    }
+
     ///
     /// Collects errors into one message and bails, if there is at least one error.
     ///
+    /// The error bears the exit code of the most severe failure class, and the errors themselves.
+    ///
     fn check_errors(&self) -> anyhow::Result<()> {
         if !self.has_errors() {
             return Ok(());
         }
 
-        anyhow::bail!(
-            "{}",
-            self.errors()
-                .iter()
-                .map(|error| error.to_string())
-                .collect::<Vec<String>>()
-                .join("\n")
-        );
+        let message = self
+            .errors()
+            .iter()
+            .map(|error| error.to_string())
+            .collect::<Vec<String>>()
+            .join("\n");
+        let errors = self.errors().into_iter().cloned().collect();
+        Err(ExitCodeError::new(self.exit_code(), message)
+            .with_errors(errors)
+            .into())
     }
 
     ///
     /// Checks for errors, exiting the application if there is at least one error.
     ///
-    fn exit_on_error(&self) {
+    /// If `sarif` is set, the errors are written to the SARIF log along with the deferred ones.
+    ///
+    fn exit_on_error(&self, sarif: Option<&SarifDeferred>) {
         if !self.has_errors() {
             return;
         }
 
+        if let Some(sarif) = sarif {
+            sarif.write(self.errors().into_iter().cloned().collect());
+            self.exit_code().exit();
+        }
         std::io::stderr()
             .write_all(
                 self.errors()
@@ -66,17 +90,23 @@ pub trait Collectable {
                     .as_bytes(),
             )
             .expect("Stderr writing error");
-        std::process::exit(era_compiler_common::EXIT_CODE_FAILURE);
+        self.exit_code().exit();
     }
 
     ///
     /// Removes warnings from the list of messages and prints them to stderr.
     ///
-    fn take_and_write_warnings(&mut self) {
+    /// If `sarif` is set, the warnings are deferred until the SARIF log is written.
+    ///
+    fn take_and_write_warnings(&mut self, sarif: Option<&SarifDeferred>) {
         let warnings = self.take_warnings();
         if warnings.is_empty() {
             return;
         }
+        if let Some(sarif) = sarif {
+            sarif.defer(warnings);
+            return;
+        }
         writeln!(
             std::io::stderr(),
             "{}",
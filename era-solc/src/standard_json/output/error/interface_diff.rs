@@ -0,0 +1,74 @@
+//!
+//! The `solc --standard-json` output error interface diff.
+//!
+
+///
+/// The `solc --standard-json` output error interface diff.
+///
+/// Lists the differences of a contract interface from its frozen counterpart, so CI tooling
+/// can process them without parsing the message.
+///
+#[derive(Debug, Default, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceDiff {
+    /// The frozen entries missing from the actual interface.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<Entry>,
+    /// The actual entries not present in the frozen interface.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub added: Vec<Entry>,
+}
+
+///
+/// The interface diff entry.
+///
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Entry {
+    /// The entry kind: `function`, `event`, or `error`.
+    pub kind: String,
+    /// The hexadecimal selector without the `0x` prefix.
+    pub selector: String,
+    /// The canonical signature.
+    pub signature: String,
+}
+
+impl InterfaceDiff {
+    ///
+    /// Whether the interfaces are equal.
+    ///
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty()
+    }
+}
+
+impl Entry {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(kind: String, selector: String, signature: String) -> Self {
+        Self {
+            kind,
+            selector,
+            signature,
+        }
+    }
+}
+
+impl std::fmt::Display for InterfaceDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for entry in self.removed.iter() {
+            writeln!(f, "- {entry}")?;
+        }
+        for entry in self.added.iter() {
+            writeln!(f, "+ {entry}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for Entry {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{} 0x{} {}", self.kind, self.selector, self.signature)
+    }
+}
@@ -8,6 +8,7 @@ use crate::standard_json::output::error::source_location::SourceLocation;
 /// The mapped error location.
 ///
 /// It can be resolved from `solc` AST error location if the source code is provided.
+/// It is printed in the `solc` format, with a source code excerpt if the line is known.
 ///
 #[derive(Debug)]
 pub struct MappedLocation<'a> {
@@ -59,6 +60,8 @@ impl<'a> MappedLocation<'a> {
     ///
     /// A shortcut constructor from `solc` AST source location.
     ///
+    /// The line and column numbers are one-based, as they are printed by `solc`.
+    ///
     pub fn try_from_source_location(
         source_location: &SourceLocation,
         source_code: Option<&'a str>,
@@ -67,63 +70,80 @@ impl<'a> MappedLocation<'a> {
             Some(source_code) => source_code,
             None => return Self::new(source_location.file.to_owned()),
         };
-        if source_location.start <= 0 || source_location.end <= 0 {
+        if source_location.start < 0 || source_location.end < source_location.start {
             return Self::new(source_location.file.to_owned());
         }
         let start = source_location.start as usize;
         let end = source_location.end as usize;
 
-        let mut cursor = 1;
-        for (line, source_line) in source_code.lines().enumerate() {
-            let cursor_next = cursor + source_line.len() + 1;
+        let mut line_start = 0;
+        for (line, source_line) in source_code.split('\n').enumerate() {
+            let line_end = line_start + source_line.len();
 
-            if cursor <= start && start <= cursor_next {
-                let line = line + 1;
-                let column = start - cursor;
-                let length = end - start;
+            if start <= line_end {
+                let source_line = source_line.strip_suffix('\r').unwrap_or(source_line);
                 return Self::new_with_location(
                     source_location.file.to_owned(),
-                    line,
-                    column,
-                    length,
+                    line + 1,
+                    start - line_start + 1,
+                    end - start,
                     Some(source_line),
                 );
             }
 
-            cursor = cursor_next;
+            line_start = line_end + 1;
         }
 
         Self::new(source_location.file.to_owned())
     }
-}
 
-impl<'a> std::fmt::Display for MappedLocation<'a> {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let mut path = self.path.clone();
+    ///
+    /// Returns the location in the `path:line:column` format, omitting the unknown parts.
+    ///
+    pub fn to_short_string(&self) -> String {
+        let mut location = self.path.clone();
         if let Some(line) = self.line {
-            path.push(':');
-            path.push_str(line.to_string().as_str());
+            location.push_str(format!(":{line}").as_str());
             if let Some(column) = self.column {
-                path.push(':');
-                path.push_str(column.to_string().as_str());
-                if let (Some(source_code_line), Some(length)) = (self.source_code_line, self.length)
-                {
-                    let line_number_length = line.to_string().len();
-                    writeln!(f, "{} --> {path}", " ".repeat(line_number_length))?;
-                    writeln!(f, " {} |", " ".repeat(line_number_length))?;
-                    writeln!(f, " {line} | {source_code_line}")?;
-                    writeln!(
-                        f,
-                        " {} | {} {}",
-                        " ".repeat(line_number_length),
-                        " ".repeat(column),
-                        "^".repeat(std::cmp::min(length, source_code_line.len() - column))
-                    )?;
-                }
+                location.push_str(format!(":{column}").as_str());
             }
-        } else {
-            writeln!(f, "--> {path}")?;
         }
+        location
+    }
+}
+
+impl<'a> std::fmt::Display for MappedLocation<'a> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (line, column) = match (self.line, self.column) {
+            (Some(line), Some(column)) => (line, column),
+            _ => return writeln!(f, "--> {}", self.to_short_string()),
+        };
+
+        let line_number = line.to_string();
+        let padding = " ".repeat(line_number.len());
+        writeln!(f, "{padding}--> {}:{line}:{column}:", self.path)?;
+
+        let source_code_line = match self.source_code_line {
+            Some(source_code_line) => source_code_line,
+            None => return Ok(()),
+        };
+        let column = std::cmp::min(column.saturating_sub(1), source_code_line.len());
+        let prefix: String = source_code_line
+            .get(..column)
+            .unwrap_or_default()
+            .chars()
+            .map(|character| if character == '\t' { '\t' } else { ' ' })
+            .collect();
+        let length = self.length.unwrap_or_default();
+        let marker = if column + length > source_code_line.len() {
+            "^ (Relevant source part starts here and spans across multiple lines).".to_owned()
+        } else {
+            "^".repeat(std::cmp::max(length, 1))
+        };
+
+        writeln!(f, "{padding} |")?;
+        writeln!(f, "{line_number} | {source_code_line}")?;
+        writeln!(f, "{padding} | {prefix}{marker}")?;
         Ok(())
     }
 }
@@ -3,15 +3,27 @@
 //!
 
 pub mod collectable;
+pub mod interface_diff;
 pub mod mapped_location;
+pub mod sarif;
 pub mod source_location;
+pub mod suggested_fix;
+pub mod unsupported_feature;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
 
+use crate::exit_code::ExitCode;
+use crate::standard_json::input::settings::diagnostics::Diagnostics;
+use crate::standard_json::input::settings::warning_type::WarningType;
 use crate::standard_json::input::source::Source as StandardJsonInputSource;
 
+use self::interface_diff::InterfaceDiff;
 use self::mapped_location::MappedLocation;
+use self::sarif::Region;
 use self::source_location::SourceLocation;
+use self::suggested_fix::SuggestedFix;
+use self::unsupported_feature::UnsupportedFeature;
 
 ///
 /// The `solc --standard-json` output error.
@@ -35,12 +47,77 @@ pub struct Error {
     pub source_location: Option<SourceLocation>,
     /// The error type.
     pub r#type: String,
+    /// The mechanical fixes of the reported issues.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub suggested_fixes: Vec<SuggestedFix>,
+    /// The differences of the contract interface from the interface freeze file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub interface_diff: Option<InterfaceDiff>,
+    /// The features listed in the consolidated unsupported feature report.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub unsupported_features: Vec<UnsupportedFeature>,
+
+    /// The exit code overriding the one derived from the error type.
+    #[serde(skip)]
+    pub exit_code: Option<ExitCode>,
+    /// The suppressible warning type, if the message is such a warning.
+    #[serde(skip)]
+    pub warning_type: Option<WarningType>,
+    /// The SARIF region of the source location, resolved while the source code is available.
+    #[serde(skip)]
+    pub region: Option<Region>,
+    /// Whether the message has been promoted to an error by a severity override.
+    #[serde(skip)]
+    pub is_promoted: bool,
 }
 
 impl Error {
     /// The list of ignored `solc` warnings that are strictly EVM-related.
     pub const IGNORED_WARNING_CODES: [&'static str; 5] = ["1699", "3860", "5159", "5574", "6417"];
 
+    /// The `solc` error types signaling an internal compiler error.
+    pub const INTERNAL_ERROR_TYPES: [&'static str; 3] =
+        ["InternalCompilerError", "CompilerError", "Exception"];
+
+    /// The `solc` error types signaling invalid input.
+    pub const INPUT_ERROR_TYPES: [&'static str; 2] = ["JSONError", "IOError"];
+
+    /// The error type used by `zksolc`.
+    pub const ZKSOLC_ERROR_TYPE: &'static str = "Error";
+
+    /// The stable code of the `tx.origin` and `origin` usage warning.
+    pub const CODE_TX_ORIGIN: &'static str = "txorigin";
+
+    /// The stable code of the `blockhash` and `block.prevrandao` usage warning.
+    pub const CODE_BLOCK_RANDOMNESS: &'static str = "blockrandomness";
+
+    /// The stable code of the init code assembled in memory for `create` and `create2` warning.
+    pub const CODE_ASSEMBLY_INIT_CODE: &'static str = "assemblyinitcode";
+
+    /// The stable code of the low-level precompile call warning.
+    pub const CODE_PRECOMPILE_CALL: &'static str = "precompilecall";
+
+    /// The stable code of the ABI coder v1 warning.
+    pub const CODE_ABI_CODER_V1: &'static str = "abicoderv1";
+
+    /// The stable code of the `send` and `transfer` usage error.
+    pub const CODE_SEND_TRANSFER: &'static str = "sendtransfer";
+
+    /// The stable code of the `create` and `create2` in assembly blocks usage error.
+    pub const CODE_ASSEMBLY_CREATE: &'static str = "assemblycreate";
+
+    /// The stable code of the `selfdestruct` usage error.
+    pub const CODE_SELFDESTRUCT: &'static str = "selfdestruct";
+
+    /// The stable code of the creation code usage warning.
+    pub const CODE_CREATION_CODE: &'static str = "creationcode";
+
+    /// The stable code of the runtime code usage error.
+    pub const CODE_RUNTIME_CODE: &'static str = "runtimecode";
+
+    /// The stable code of the builtin unavailable in the configured EVM version error.
+    pub const CODE_EVM_VERSION_BUILTIN: &'static str = "evmversionbuiltin";
+
     ///
     /// A shortcut constructor.
     ///
@@ -62,6 +139,7 @@ impl Error {
             format!("{}: {}", r#type, message_trimmed)
         };
         formatted_message.push('\n');
+        let mut region = None;
         if let Some(ref source_location) = source_location {
             let source_code = sources.and_then(|sources| {
                 sources
@@ -72,6 +150,7 @@ impl Error {
                 MappedLocation::try_from_source_location(source_location, source_code);
             formatted_message.push_str(mapped_location.to_string().as_str());
             formatted_message.push('\n');
+            region = Region::try_from_source_location(source_location, source_code);
         }
 
         Self {
@@ -82,6 +161,14 @@ impl Error {
             severity: r#type.to_lowercase(),
             source_location,
             r#type: r#type.to_owned(),
+            suggested_fixes: Vec::new(),
+            interface_diff: None,
+            unsupported_features: Vec::new(),
+
+            exit_code: None,
+            warning_type: None,
+            region,
+            is_promoted: false,
         }
     }
 
@@ -113,6 +200,240 @@ impl Error {
         Self::new("Warning", message, source_location, sources)
     }
 
+    ///
+    /// Overrides the exit code derived from the error type.
+    ///
+    pub fn with_exit_code(mut self, exit_code: ExitCode) -> Self {
+        self.exit_code = Some(exit_code);
+        self
+    }
+
+    ///
+    /// Resolves the SARIF region of the source location against the `sources`.
+    ///
+    /// Used for the messages produced by `solc`, whose line and column are only a part of
+    /// the formatted message.
+    ///
+    pub fn resolve_region(&mut self, sources: &BTreeMap<String, StandardJsonInputSource>) {
+        let Some(ref source_location) = self.source_location else {
+            return;
+        };
+        let source_code = sources
+            .get(source_location.file.as_str())
+            .and_then(|source| source.content());
+        self.region = Region::try_from_source_location(source_location, source_code);
+    }
+
+    ///
+    /// Attaches the suggested fix of the reported issue, if a mechanical fix exists.
+    ///
+    pub fn with_suggested_fix(mut self, suggested_fix: Option<SuggestedFix>) -> Self {
+        self.suggested_fixes.extend(suggested_fix);
+        self
+    }
+
+    ///
+    /// Attaches the structured differences of the contract interface from the interface freeze file.
+    ///
+    pub fn with_interface_diff(mut self, interface_diff: InterfaceDiff) -> Self {
+        self.interface_diff = Some(interface_diff);
+        self
+    }
+
+    ///
+    /// Applies the severity override of the check the message is reported by, if any.
+    ///
+    /// Promoted warnings fail the compilation, and demoted errors are only reported.
+    /// In standard JSON mode, promoted warnings also make the process exit with a non-zero code.
+    ///
+    pub fn with_severity(
+        self,
+        diagnostics: &Diagnostics,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let severity = match self
+            .error_code
+            .as_deref()
+            .and_then(|code| diagnostics.severity(code))
+        {
+            Some(severity) if severity.as_type() != self.r#type => severity,
+            _ => return self,
+        };
+        let mut message = Self::new(
+            severity.as_type(),
+            self.message,
+            self.source_location,
+            Some(sources),
+        );
+        message.error_code = self.error_code;
+        message.suggested_fixes = self.suggested_fixes;
+        message.exit_code = self.exit_code;
+        message.warning_type = self.warning_type;
+        message.is_promoted = message.severity == "error";
+        message
+    }
+
+    ///
+    /// Converts the warning into an error reporting the violation of the policy rule forbidding it.
+    ///
+    /// The suppression hints are dropped, as policy violations cannot be suppressed.
+    ///
+    pub fn into_policy_violation(
+        self,
+        rule_id: &str,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let description = self.description();
+        let message = match self.warning_type {
+            Some(warning_type) => {
+                format!("Policy rule `{rule_id}` forbids `{warning_type}` warnings:\n{description}")
+            }
+            None => format!("Policy rule `{rule_id}` violation:\n{description}"),
+        };
+        let mut error = Self::new_error(message, self.source_location, Some(sources));
+        error.suggested_fixes = self.suggested_fixes;
+        error
+    }
+
+    ///
+    /// Converts the EraVM-specific message into an error reporting the violation of the strict EraVM mode.
+    ///
+    /// The stable code is preserved, and the suppression hints are dropped, as strict mode violations
+    /// cannot be suppressed.
+    ///
+    pub fn into_strict_violation(
+        self,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let code = self.error_code.as_deref().unwrap_or_default();
+        let message = format!(
+            "Strict EraVM mode forbids `{code}`:\n{}",
+            self.description()
+        );
+        let mut error = Self::new_error(message, self.source_location, Some(sources));
+        error.error_code = self.error_code;
+        error.suggested_fixes = self.suggested_fixes;
+        error
+    }
+
+    ///
+    /// Returns the message without the suppression hints.
+    ///
+    fn description(&self) -> &str {
+        self.message
+            .split("\nYou may disable this")
+            .next()
+            .unwrap_or_default()
+            .trim()
+    }
+
+    ///
+    /// Returns the exit code of the error failure class.
+    ///
+    /// Errors reported by `zksolc` are translation errors unless overridden,
+    /// and the rest are classified according to the `solc` error type.
+    ///
+    pub fn exit_code(&self) -> ExitCode {
+        if self.severity != "error" {
+            return ExitCode::Success;
+        }
+        if let Some(exit_code) = self.exit_code {
+            return exit_code;
+        }
+
+        match self.r#type.as_str() {
+            Self::ZKSOLC_ERROR_TYPE => ExitCode::TranslationError,
+            r#type if Self::INTERNAL_ERROR_TYPES.contains(&r#type) => ExitCode::InternalError,
+            r#type if Self::INPUT_ERROR_TYPES.contains(&r#type) => ExitCode::InputError,
+            _ => ExitCode::SolcError,
+        }
+    }
+
+    ///
+    /// Consolidates the unsupported feature messages of a contract into one report per severity.
+    ///
+    /// Each distinct feature is described once, followed by the number and locations of its usages.
+    /// The features are also listed in the structured form along with their error codes and warning types.
+    /// The report points to the first usage of the first feature, and keeps the error code and
+    /// the warning type if they are shared by all the consolidated messages.
+    ///
+    pub fn new_unsupported_feature_reports(
+        contract: &str,
+        messages: Vec<Self>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Vec<Self> {
+        let mut reports = Vec::with_capacity(2);
+        for r#type in ["Error", "Warning"] {
+            let mut features: Vec<UnsupportedFeature> = Vec::new();
+            let mut suggested_fixes = Vec::new();
+            for message in messages.iter().filter(|message| message.r#type == r#type) {
+                let index = match features.iter().position(|feature| {
+                    feature.message == message.message
+                        && feature.error_code == message.error_code
+                        && feature.warning_type == message.warning_type
+                }) {
+                    Some(index) => index,
+                    None => {
+                        features.push(UnsupportedFeature {
+                            message: message.message.to_owned(),
+                            error_code: message.error_code.to_owned(),
+                            warning_type: message.warning_type,
+                            locations: Vec::new(),
+                        });
+                        features.len() - 1
+                    }
+                };
+                features[index]
+                    .locations
+                    .extend(message.source_location.iter().cloned());
+                suggested_fixes.extend(message.suggested_fixes.iter().cloned());
+            }
+            if features.is_empty() {
+                continue;
+            }
+
+            let mut report = format!(
+                "`{contract}` uses {} unsupported feature(s):\n",
+                features.len()
+            );
+            for (index, feature) in features.iter().enumerate() {
+                report.push_str(format!("\n[{}] {}\n", index + 1, feature.message.trim()).as_str());
+                report.push_str(format!("Found {} usage(s):\n", feature.locations.len()).as_str());
+                for location in feature.locations.iter() {
+                    let source_code = sources
+                        .get(location.file.as_str())
+                        .and_then(|source| source.content());
+                    let location = MappedLocation::try_from_source_location(location, source_code);
+                    report.push_str(format!("    --> {}\n", location.to_short_string()).as_str());
+                }
+            }
+
+            let source_location = features
+                .first()
+                .and_then(|feature| feature.locations.first())
+                .cloned();
+            let mut report = Self::new(r#type, report, source_location, Some(sources));
+            let mut error_codes = features
+                .iter()
+                .map(|feature| feature.error_code.as_deref())
+                .collect::<BTreeSet<Option<&str>>>();
+            if error_codes.len() == 1 {
+                report.error_code = error_codes.pop_first().flatten().map(str::to_owned);
+            }
+            let warning_type = features.first().and_then(|feature| feature.warning_type);
+            if features
+                .iter()
+                .all(|feature| feature.warning_type == warning_type)
+            {
+                report.warning_type = warning_type;
+            }
+            report.suggested_fixes = suggested_fixes;
+            report.unsupported_features = features;
+            reports.push(report);
+        }
+        reports
+    }
+
     ///
     /// Returns the `origin` instruction usage warning.
     ///
@@ -133,11 +454,159 @@ You may disable this warning with:
     b. `--suppress-warnings txorigin` in the CLI.
 "#;
 
-        Self::new_warning(
+        let mut warning = Self::new_warning(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        warning.error_code = Some(Self::CODE_TX_ORIGIN.to_owned());
+        warning.warning_type = Some(WarningType::TxOrigin);
+        warning
+    }
+
+    ///
+    /// Returns the error of the `builtin` unavailable in the `configured` EVM version.
+    ///
+    pub fn error_evm_version_builtin(
+        builtin: &str,
+        required: &str,
+        configured: &str,
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = format!(
+            "The `{builtin}` builtin is only available since the `{required}` EVM version, but `{configured}` is configured. Please set `evmVersion` to `{required}` or newer, or avoid using `{builtin}`."
+        );
+
+        let mut error = Self::new_error(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        error.error_code = Some(Self::CODE_EVM_VERSION_BUILTIN.to_owned());
+        error
+    }
+
+    ///
+    /// Returns the `blockhash` and `block.prevrandao` usage warning.
+    ///
+    pub fn warning_block_randomness(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+You are relying on 'blockhash', 'block.prevrandao', or 'block.difficulty', which behave differently on ZKsync Era.
+'block.prevrandao' and 'block.difficulty' return a constant value, and 'blockhash' only returns the hashes
+of the recent blocks and is known to the operator in advance, so none of them are a source of randomness.
+Please use a verifiable randomness source, e.g. an oracle, instead.
+Learn more at https://docs.zksync.io/zksync-protocol/differences/evm-instructions
+
+You may disable this warning with:
+    a. `suppressedWarnings = ["blockrandomness"]` in standard JSON.
+    b. `--suppress-warnings blockrandomness` in the CLI.
+"#;
+
+        let mut warning = Self::new_warning(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        warning.error_code = Some(Self::CODE_BLOCK_RANDOMNESS.to_owned());
+        warning.warning_type = Some(WarningType::BlockRandomness);
+        warning
+    }
+
+    ///
+    /// Returns the init code assembled in memory for `create` and `create2` warning.
+    ///
+    pub fn warning_assembly_init_code(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+You are passing init code assembled in memory with 'mstore' to 'create'/'create2' in an assembly block.
+EraVM deploys contracts by their bytecode hashes, so concatenating bytecode and constructor arguments
+in memory cannot work. Please use the `new` operator in Solidity, or call the `ContractDeployer`
+system contract with the bytecode hash of a contract known to the compiler and the constructor
+arguments passed separately.
+Learn more about the deployment on EraVM at https://docs.zksync.io/zksync-protocol/differences/evm-instructions#create-create2
+
+You may disable this warning with:
+    a. `suppressedWarnings = ["assemblyinitcode"]` in standard JSON.
+    b. `--suppress-warnings assemblyinitcode` in the CLI.
+"#;
+
+        let mut warning = Self::new_warning(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        warning.error_code = Some(Self::CODE_ASSEMBLY_INIT_CODE.to_owned());
+        warning.warning_type = Some(WarningType::AssemblyInitCode);
+        warning
+    }
+
+    ///
+    /// Returns the low-level precompile call warning.
+    ///
+    pub fn warning_precompile_call(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+You are calling the 'ecrecover', 'sha256', or 'ripemd160' precompile with 'staticcall' to its hardcoded address.
+Precompiles on ZKsync Era are system contracts, whose addresses, gas costs, and availability may
+differ from the EVM ones, so raw calls relying on the EVM precompile layout may behave differently.
+Please call the 'ecrecover', 'sha256', and 'ripemd160' builtin functions directly instead.
+Learn more about precompiles at https://docs.zksync.io/zksync-protocol/differences/pre-compiles
+
+You may disable this warning with:
+    a. `suppressedWarnings = ["precompilecall"]` in standard JSON.
+    b. `--suppress-warnings precompilecall` in the CLI.
+"#;
+
+        let mut warning = Self::new_warning(
             message,
             node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
             Some(sources),
-        )
+        );
+        warning.error_code = Some(Self::CODE_PRECOMPILE_CALL.to_owned());
+        warning.warning_type = Some(WarningType::PrecompileCall);
+        warning
+    }
+
+    ///
+    /// Returns the ABI coder v1 warning.
+    ///
+    pub fn warning_abi_coder_v1(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+This source is compiled with ABI coder v1, either with 'pragma abicoder v1' or by default before solc v0.8.0.
+ABI coder v1 does not validate the values it decodes, while the IR pipeline always generates the
+ABI coder v2 code, which reverts on invalid calldata and returndata, e.g. with dirty higher-order bits.
+Contracts relying on the lenient decoding may behave differently on EraVM and across the pipelines.
+Please add 'pragma abicoder v2;', or 'pragma experimental ABIEncoderV2;' before solc v0.7.5, to this source.
+
+You may disable this warning with:
+    a. `suppressedWarnings = ["abicoderv1"]` in standard JSON.
+    b. `--suppress-warnings abicoderv1` in the CLI.
+"#;
+
+        let mut warning = Self::new_warning(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        warning.error_code = Some(Self::CODE_ABI_CODER_V1.to_owned());
+        warning.warning_type = Some(WarningType::AbiCoderV1);
+        warning
     }
 
     ///
@@ -163,11 +632,13 @@ You may disable this error with:
     2. `--suppress-errors sendtransfer` in the CLI.
 "#;
 
-        Self::new_error(
+        let mut error = Self::new_error(
             message,
             node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
             Some(sources),
-        )
+        );
+        error.error_code = Some(Self::CODE_SEND_TRANSFER.to_owned());
+        error
     }
 
     ///
@@ -190,11 +661,67 @@ You may disable this error with:
     2. `--suppress-errors assemblycreate` in the CLI.
 "#;
 
-        Self::new_error(
+        let mut error = Self::new_error(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        error.error_code = Some(Self::CODE_ASSEMBLY_CREATE.to_owned());
+        error
+    }
+
+    ///
+    /// Returns the `selfdestruct` usage error.
+    ///
+    pub fn error_selfdestruct(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+You are using 'selfdestruct', which is not supported by EraVM, as its semantics differ fundamentally from the EVM ones.
+The contract code and storage cannot be removed, so the contract would fail to compile at the code generation stage anyway.
+Please consider removing the functionality relying on 'selfdestruct', e.g. by disabling the contract with a storage flag instead.
+In Solidity v0.5 and older, it can be a false-positive error if there is 'selfdestruct(' in comments within assembly.
+"#;
+
+        let mut error = Self::new_error(
+            message,
+            node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
+            Some(sources),
+        );
+        error.error_code = Some(Self::CODE_SELFDESTRUCT.to_owned());
+        error
+    }
+
+    ///
+    /// Returns the creation code usage warning.
+    ///
+    pub fn warning_creation_code(
+        node: Option<&str>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let message = r#"
+You are using 'type(T).creationCode', whose value differs on EraVM.
+EraVM deploys contracts by their bytecode hashes, so the value is not the contract bytecode, and
+hashing it, e.g. to predict a CREATE2 address, does not produce the EVM result. `T` is added to
+the factory dependencies automatically, so it can still be deployed with the `new` operator.
+Learn more about the deployment on EraVM at https://docs.zksync.io/zksync-protocol/differences/evm-instructions#create-create2
+
+You may disable this warning with:
+    a. `suppressedWarnings = ["creationcode"]` in standard JSON.
+    b. `--suppress-warnings creationcode` in the CLI.
+"#;
+
+        let mut warning = Self::new_warning(
             message,
             node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
             Some(sources),
-        )
+        );
+        warning.error_code = Some(Self::CODE_CREATION_CODE.to_owned());
+        warning.warning_type = Some(WarningType::CreationCode);
+        warning
     }
 
     ///
@@ -210,11 +737,13 @@ Deploy and runtime code are merged in EraVM, so accessing `type(T).runtimeCode`
 Please consider changing the functionality relying on reading runtime code to a different approach.
 "#;
 
-        Self::new_error(
+        let mut error = Self::new_error(
             message,
             node.and_then(|node| SourceLocation::try_from_ast(node, id_paths)),
             Some(sources),
-        )
+        );
+        error.error_code = Some(Self::CODE_RUNTIME_CODE.to_owned());
+        error
     }
 }
 
@@ -0,0 +1,312 @@
+//!
+//! The SARIF 2.1.0 log of the compiler diagnostics.
+//!
+
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use crate::standard_json::output::error::source_location::SourceLocation;
+use crate::standard_json::output::error::Error;
+
+///
+/// The SARIF 2.1.0 log of the compiler diagnostics.
+///
+/// Only the subset of the format required by code scanning services, such as GitHub, is emitted.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Sarif {
+    /// The SARIF format version.
+    pub version: &'static str,
+    /// The SARIF JSON schema.
+    #[serde(rename = "$schema")]
+    pub schema: &'static str,
+    /// The analysis runs.
+    pub runs: Vec<Run>,
+}
+
+///
+/// The SARIF analysis run.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Run {
+    /// The analysis tool.
+    pub tool: Tool,
+    /// The reported diagnostics.
+    pub results: Vec<RunResult>,
+}
+
+///
+/// The SARIF analysis tool.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Tool {
+    /// The tool component performing the analysis.
+    pub driver: Driver,
+}
+
+///
+/// The SARIF tool component.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Driver {
+    /// The tool name.
+    pub name: &'static str,
+    /// The tool version.
+    pub version: String,
+    /// The tool documentation URI.
+    pub information_uri: &'static str,
+    /// The rules, one per distinct diagnostic code.
+    pub rules: Vec<Rule>,
+}
+
+///
+/// The SARIF rule.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Rule {
+    /// The diagnostic code, e.g. `txorigin` or a `solc` error code.
+    pub id: String,
+}
+
+///
+/// The SARIF result, which is a single diagnostic.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RunResult {
+    /// The diagnostic code.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rule_id: Option<String>,
+    /// The diagnostic level: `error`, `warning`, or `note`.
+    pub level: &'static str,
+    /// The diagnostic message.
+    pub message: Message,
+    /// The diagnostic locations.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub locations: Vec<Location>,
+}
+
+///
+/// The SARIF message.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct Message {
+    /// The plain message text.
+    pub text: String,
+}
+
+///
+/// The SARIF location.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Location {
+    /// The location in a source file.
+    pub physical_location: PhysicalLocation,
+}
+
+///
+/// The SARIF physical location.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PhysicalLocation {
+    /// The source file.
+    pub artifact_location: ArtifactLocation,
+    /// The area in the source file.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<Region>,
+}
+
+///
+/// The SARIF artifact location.
+///
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct ArtifactLocation {
+    /// The source file path.
+    pub uri: String,
+}
+
+///
+/// The SARIF region.
+///
+/// The byte offsets are taken from the `solc` source location, while the line, the column,
+/// and the character offsets are only known if the source code has been available when the
+/// diagnostic was created.
+///
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    /// The one-based start line.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_line: Option<usize>,
+    /// The one-based start column, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<usize>,
+    /// The zero-based start offset, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_offset: Option<usize>,
+    /// The length, in characters.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub char_length: Option<usize>,
+    /// The zero-based start offset, in bytes.
+    pub byte_offset: usize,
+    /// The length, in bytes.
+    pub byte_length: usize,
+}
+
+impl Sarif {
+    /// The SARIF format version.
+    pub const VERSION: &'static str = "2.1.0";
+
+    /// The SARIF JSON schema.
+    pub const SCHEMA: &'static str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+    /// The tool documentation URI.
+    pub const INFORMATION_URI: &'static str =
+        "https://github.com/matter-labs/era-compiler-solidity";
+
+    ///
+    /// Creates the log of the diagnostics.
+    ///
+    pub fn new(messages: &[Error]) -> Self {
+        let rules = messages
+            .iter()
+            .filter_map(|message| message.error_code.clone())
+            .collect::<BTreeSet<String>>()
+            .into_iter()
+            .map(|id| Rule { id })
+            .collect();
+        let results = messages.iter().map(RunResult::from).collect();
+
+        Self {
+            version: Self::VERSION,
+            schema: Self::SCHEMA,
+            runs: vec![Run {
+                tool: Tool {
+                    driver: Driver {
+                        name: "zksolc",
+                        version: crate::version(),
+                        information_uri: Self::INFORMATION_URI,
+                        rules,
+                    },
+                },
+                results,
+            }],
+        }
+    }
+}
+
+///
+/// The diagnostics deferred until the SARIF log is written in the basic CLI mode.
+///
+/// Only created if the SARIF error format is enabled, and passed to the steps printing the
+/// diagnostics. The clones share the deferred diagnostics.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Deferred(Arc<Mutex<Vec<Error>>>);
+
+impl Deferred {
+    ///
+    /// Defers the diagnostics until the log is written.
+    ///
+    pub fn defer(&self, messages: Vec<Error>) {
+        self.0.lock().expect("Sync").extend(messages);
+    }
+
+    ///
+    /// Writes the log of the deferred diagnostics and `messages` to stderr.
+    ///
+    pub fn write(&self, messages: Vec<Error>) {
+        let mut deferred = std::mem::take(&mut *self.0.lock().expect("Sync"));
+        deferred.extend(messages);
+
+        let sarif = serde_json::to_string(&Sarif::new(deferred.as_slice())).expect("Always valid");
+        writeln!(std::io::stderr(), "{sarif}").expect("Stderr writing error");
+    }
+}
+
+impl From<&Error> for RunResult {
+    fn from(error: &Error) -> Self {
+        let level = match error.severity.as_str() {
+            "error" => "error",
+            "warning" => "warning",
+            _ => "note",
+        };
+
+        let locations = error
+            .source_location
+            .as_ref()
+            .map(|source_location| {
+                let region = error
+                    .region
+                    .clone()
+                    .or_else(|| Region::try_from_source_location(source_location, None));
+                Location {
+                    physical_location: PhysicalLocation {
+                        artifact_location: ArtifactLocation {
+                            uri: source_location.file.clone(),
+                        },
+                        region,
+                    },
+                }
+            })
+            .into_iter()
+            .collect();
+
+        Self {
+            rule_id: error.error_code.clone(),
+            level,
+            message: Message {
+                text: error.message.trim().to_owned(),
+            },
+            locations,
+        }
+    }
+}
+
+impl Region {
+    ///
+    /// Creates the region of the `solc` source location, if it is valid.
+    ///
+    /// The line, the column, and the character offsets are only resolved if the `source_code`
+    /// is provided and the location points to character boundaries in it.
+    ///
+    pub fn try_from_source_location(
+        source_location: &SourceLocation,
+        source_code: Option<&str>,
+    ) -> Option<Self> {
+        if source_location.start < 0 || source_location.end < source_location.start {
+            return None;
+        }
+        let start = source_location.start as usize;
+        let end = source_location.end as usize;
+        let mut region = Self {
+            start_line: None,
+            start_column: None,
+            char_offset: None,
+            char_length: None,
+            byte_offset: start,
+            byte_length: end - start,
+        };
+
+        let (Some(prefix), Some(area)) = (
+            source_code.and_then(|source_code| source_code.get(..start)),
+            source_code.and_then(|source_code| source_code.get(start..end)),
+        ) else {
+            return Some(region);
+        };
+        let line_start = prefix
+            .rfind('\n')
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        region.start_line = Some(prefix.matches('\n').count() + 1);
+        region.start_column = Some(prefix[line_start..].chars().count() + 1);
+        region.char_offset = Some(prefix.chars().count());
+        region.char_length = Some(area.chars().count());
+        Some(region)
+    }
+}
@@ -0,0 +1,74 @@
+//!
+//! The `solc --standard-json` output error suggested fix.
+//!
+
+use crate::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The `solc --standard-json` output error suggested fix.
+///
+/// Describes a mechanical fix as a replacement of a source code span, so IDEs and codemod tools
+/// can apply it without parsing the message.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuggestedFix {
+    /// The human-readable description of the fix.
+    pub description: String,
+    /// The source code span to replace.
+    pub source_location: SourceLocation,
+    /// The replacement source code.
+    pub replacement: String,
+}
+
+impl SuggestedFix {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(description: String, source_location: SourceLocation, replacement: String) -> Self {
+        Self {
+            description,
+            source_location,
+            replacement,
+        }
+    }
+
+    ///
+    /// Returns the fix replacing `tx.origin` with `msg.sender`.
+    ///
+    pub fn tx_origin(source_location: SourceLocation) -> Self {
+        Self::new(
+            "Replace `tx.origin` with `msg.sender`".to_owned(),
+            source_location,
+            "msg.sender".to_owned(),
+        )
+    }
+
+    ///
+    /// Returns the fix replacing the `origin()` assembly instruction with `caller()`.
+    ///
+    pub fn assembly_origin(source_location: SourceLocation) -> Self {
+        Self::new(
+            "Replace `origin()` with `caller()`".to_owned(),
+            source_location,
+            "caller()".to_owned(),
+        )
+    }
+
+    ///
+    /// Returns the fix replacing the `<recipient>.transfer(<amount>);` statement with a low-level call.
+    ///
+    /// The replacement is wrapped into a block, so it is valid as an unbraced `if` body, and
+    /// the `success` variables of several fixes in the same scope do not clash.
+    /// The call forwards all gas, so the caller must be protected against reentrancy.
+    ///
+    pub fn transfer(source_location: SourceLocation, recipient: &str, amount: &str) -> Self {
+        Self::new(
+            "Replace `transfer` with a low-level call reverting on failure. The call forwards all gas, so make sure the caller is protected against reentrancy".to_owned(),
+            source_location,
+            format!(
+                r#"{{ (bool success, ) = {recipient}.call{{value: {amount}}}(""); require(success, "Transfer failed"); }}"#
+            ),
+        )
+    }
+}
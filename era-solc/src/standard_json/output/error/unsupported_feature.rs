@@ -0,0 +1,27 @@
+//!
+//! The `solc --standard-json` output error unsupported feature.
+//!
+
+use crate::standard_json::input::settings::warning_type::WarningType;
+use crate::standard_json::output::error::source_location::SourceLocation;
+
+///
+/// The `solc --standard-json` output error unsupported feature.
+///
+/// An entry of the consolidated unsupported feature report of a contract, which keeps the code
+/// and the warning type of the feature messages, so tooling can process them without parsing the report.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnsupportedFeature {
+    /// The feature description.
+    pub message: String,
+    /// The error code of the feature messages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error_code: Option<String>,
+    /// The suppressible warning type of the feature messages, if they are such warnings.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning_type: Option<WarningType>,
+    /// The locations of the feature usages.
+    pub locations: Vec<SourceLocation>,
+}
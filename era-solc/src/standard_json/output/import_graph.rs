@@ -0,0 +1,111 @@
+//!
+//! The source import graph extracted from the `solc` AST.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::path::Path;
+
+///
+/// The import directive.
+///
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub struct Import {
+    /// The imported source path, with remappings and base and include paths resolved by `solc`.
+    pub path: String,
+    /// The import path as it is written in the source code.
+    pub file: String,
+}
+
+impl Import {
+    ///
+    /// Extracts the import from a top-level AST node, if it is an import directive.
+    ///
+    pub fn try_from_node(node: &serde_json::Value) -> Option<Self> {
+        let node = node.as_object()?;
+        if node.get("nodeType")?.as_str()? != "ImportDirective" {
+            return None;
+        }
+
+        Some(Self {
+            path: node.get("absolutePath")?.as_str()?.to_owned(),
+            file: node.get("file")?.as_str()?.to_owned(),
+        })
+    }
+}
+
+///
+/// The import graph node.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Node {
+    /// The direct imports of the source.
+    pub imports: Vec<Import>,
+    /// The sources importing this one directly.
+    pub dependents: BTreeSet<String>,
+}
+
+///
+/// The import graph.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ImportGraph {
+    /// The graph nodes, keyed by source path.
+    pub sources: BTreeMap<String, Node>,
+}
+
+impl ImportGraph {
+    ///
+    /// Builds the graph from the imports collected from all sources, keyed by importing source path.
+    ///
+    pub fn new(imports: BTreeMap<String, Vec<Import>>) -> Self {
+        let mut sources: BTreeMap<String, Node> = imports
+            .keys()
+            .map(|path| (path.to_owned(), Node::default()))
+            .collect();
+        for (path, mut imports) in imports.into_iter() {
+            imports.sort();
+            imports.dedup();
+            for import in imports.iter() {
+                sources
+                    .entry(import.path.to_owned())
+                    .or_default()
+                    .dependents
+                    .insert(path.to_owned());
+            }
+            sources.entry(path).or_default().imports = imports;
+        }
+        Self { sources }
+    }
+
+    ///
+    /// Returns the source at `path` and all sources it imports, directly or transitively.
+    ///
+    pub fn closure(&self, path: &str) -> BTreeSet<String> {
+        let mut closure = BTreeSet::new();
+        let mut paths = vec![path.to_owned()];
+        while let Some(path) = paths.pop() {
+            if let Some(node) = self.sources.get(path.as_str()) {
+                paths.extend(
+                    node.imports
+                        .iter()
+                        .filter(|import| !closure.contains(import.path.as_str()))
+                        .map(|import| import.path.to_owned()),
+                );
+            }
+            closure.insert(path);
+        }
+        closure
+    }
+
+    ///
+    /// Writes the graph to the specified file in JSON format.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+}
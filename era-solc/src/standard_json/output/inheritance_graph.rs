@@ -0,0 +1,154 @@
+//!
+//! The inheritance graph extracted from the `solc` AST.
+//!
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+///
+/// The inheritance graph node.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct Node {
+    /// The contract kind: `contract`, `interface`, or `library`.
+    pub kind: String,
+    /// Whether the contract is abstract.
+    #[serde(rename = "abstract")]
+    pub is_abstract: bool,
+    /// The direct base contracts in declaration order, as full paths.
+    pub bases: Vec<String>,
+    /// The C3-linearized inheritance chain, from the most derived contract to the most base one.
+    /// The contract itself is always the first element.
+    pub linearization: Vec<String>,
+}
+
+///
+/// The inheritance graph.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct InheritanceGraph {
+    /// The graph nodes, keyed by contract full path.
+    pub contracts: BTreeMap<String, Node>,
+}
+
+impl InheritanceGraph {
+    ///
+    /// Builds the graph from the contract definitions collected from all sources.
+    ///
+    /// The AST node IDs are resolved to full paths here, as bases may be declared in other sources.
+    ///
+    pub fn new(definitions: Vec<Definition>) -> Self {
+        let id_paths: BTreeMap<usize, String> = definitions
+            .iter()
+            .map(|definition| (definition.id, definition.full_path.to_owned()))
+            .collect();
+        let resolve = |ids: &[usize]| -> Vec<String> {
+            ids.iter()
+                .filter_map(|id| id_paths.get(id).cloned())
+                .collect()
+        };
+
+        let contracts = definitions
+            .iter()
+            .map(|definition| {
+                (
+                    definition.full_path.to_owned(),
+                    Node {
+                        kind: definition.kind.to_owned(),
+                        is_abstract: definition.is_abstract,
+                        bases: resolve(definition.base_ids.as_slice()),
+                        linearization: resolve(definition.linearization_ids.as_slice()),
+                    },
+                )
+            })
+            .collect();
+        Self { contracts }
+    }
+
+    ///
+    /// Writes the graph to the specified file in JSON format.
+    ///
+    pub fn write_to_file(&self, path: &Path) -> anyhow::Result<()> {
+        std::fs::write(
+            path,
+            serde_json::to_string_pretty(self).expect("Always valid"),
+        )
+        .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+}
+
+///
+/// The contract definition with unresolved AST node IDs.
+///
+#[derive(Debug, Clone)]
+pub struct Definition {
+    /// The contract definition AST node ID.
+    pub id: usize,
+    /// The contract full path.
+    pub full_path: String,
+    /// The contract kind.
+    pub kind: String,
+    /// Whether the contract is abstract.
+    pub is_abstract: bool,
+    /// The direct base contract IDs.
+    pub base_ids: Vec<usize>,
+    /// The linearized base contract IDs, as computed by `solc`.
+    pub linearization_ids: Vec<usize>,
+}
+
+impl Definition {
+    ///
+    /// Extracts the contract definition from a top-level AST node, if it is one.
+    ///
+    pub fn try_from_node(path: &str, node: &serde_json::Value) -> Option<Self> {
+        let node = node.as_object()?;
+        if node.get("nodeType")?.as_str()? != "ContractDefinition" {
+            return None;
+        }
+
+        let id = node.get("id")?.as_u64()? as usize;
+        let name = node.get("name")?.as_str()?;
+        let kind = node
+            .get("contractKind")
+            .and_then(|kind| kind.as_str())
+            .unwrap_or("contract")
+            .to_owned();
+        let is_abstract = node
+            .get("abstract")
+            .and_then(|is_abstract| is_abstract.as_bool())
+            .unwrap_or_default();
+        let base_ids = node
+            .get("baseContracts")
+            .and_then(|bases| bases.as_array())
+            .map(|bases| {
+                bases
+                    .iter()
+                    .filter_map(|base| {
+                        base.get("baseName")?
+                            .get("referencedDeclaration")?
+                            .as_u64()
+                            .map(|id| id as usize)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        let linearization_ids = node
+            .get("linearizedBaseContracts")
+            .and_then(|ids| ids.as_array())
+            .map(|ids| {
+                ids.iter()
+                    .filter_map(|id| id.as_u64().map(|id| id as usize))
+                    .collect()
+            })
+            .unwrap_or_else(|| vec![id]);
+
+        Some(Self {
+            id,
+            full_path: format!("{path}:{name}"),
+            kind,
+            is_abstract,
+            base_ids,
+            linearization_ids,
+        })
+    }
+}
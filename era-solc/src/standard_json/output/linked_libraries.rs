@@ -0,0 +1,184 @@
+//!
+//! The libraries linked to the contracts, extracted from the `solc` AST.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::standard_json::output::inheritance_graph::InheritanceGraph;
+
+///
+/// The libraries linked to the contracts, extracted from the `solc` AST.
+///
+/// A library is linked to a contract if the contract calls its public or external functions,
+/// regardless of whether the library address is known at compile time.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct LinkedLibraries {
+    /// The library full paths, keyed by contract full path.
+    /// Calls in base contracts, internal library functions, and free functions are attributed to the calling contracts.
+    pub contracts: BTreeMap<String, BTreeSet<String>>,
+}
+
+///
+/// The library references of a source, with unresolved AST node IDs.
+///
+#[derive(Debug, Default, Clone)]
+pub struct References {
+    /// The referenced declaration IDs, keyed by contract full path.
+    pub contracts: BTreeMap<String, BTreeSet<usize>>,
+    /// The library full paths, keyed by the IDs of their public and external functions,
+    /// which are called via linking.
+    pub linked_functions: BTreeMap<usize, String>,
+    /// The referenced declaration IDs, keyed by the IDs of internal library functions and free functions,
+    /// whose code is inlined into the callers.
+    pub inlined_functions: BTreeMap<usize, BTreeSet<usize>>,
+}
+
+impl References {
+    ///
+    /// Collects the library references from the top-level nodes of the source `ast`.
+    ///
+    pub fn new(path: &str, ast: &serde_json::Value) -> Self {
+        let mut references = Self::default();
+        for node in ast
+            .get("nodes")
+            .and_then(|nodes| nodes.as_array())
+            .map(|nodes| nodes.as_slice())
+            .unwrap_or_default()
+        {
+            match node.get("nodeType").and_then(|r#type| r#type.as_str()) {
+                Some("ContractDefinition") => {
+                    let Some(name) = node.get("name").and_then(|name| name.as_str()) else {
+                        continue;
+                    };
+                    let full_path = format!("{path}:{name}");
+                    let is_library =
+                        node.get("contractKind").and_then(|kind| kind.as_str()) == Some("library");
+                    if is_library {
+                        references.extend_library(full_path.as_str(), node);
+                    }
+                    references
+                        .contracts
+                        .insert(full_path, Self::get_referenced_declarations(node));
+                }
+                Some("FunctionDefinition") => {
+                    if let Some(id) = node.get("id").and_then(|id| id.as_u64()) {
+                        references
+                            .inlined_functions
+                            .insert(id as usize, Self::get_referenced_declarations(node));
+                    }
+                }
+                _ => {}
+            }
+        }
+        references
+    }
+
+    ///
+    /// Merges the references of another source.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        self.contracts.extend(other.contracts);
+        self.linked_functions.extend(other.linked_functions);
+        self.inlined_functions.extend(other.inlined_functions);
+    }
+
+    ///
+    /// Collects the linked and inlined functions of the library definition `node`.
+    ///
+    fn extend_library(&mut self, full_path: &str, node: &serde_json::Value) {
+        for function in node
+            .get("nodes")
+            .and_then(|nodes| nodes.as_array())
+            .map(|nodes| nodes.as_slice())
+            .unwrap_or_default()
+        {
+            if function.get("nodeType").and_then(|r#type| r#type.as_str())
+                != Some("FunctionDefinition")
+            {
+                continue;
+            }
+            let Some(id) = function.get("id").and_then(|id| id.as_u64()) else {
+                continue;
+            };
+            match function
+                .get("visibility")
+                .and_then(|visibility| visibility.as_str())
+            {
+                Some("public" | "external") => {
+                    self.linked_functions
+                        .insert(id as usize, full_path.to_owned());
+                }
+                _ => {
+                    self.inlined_functions
+                        .insert(id as usize, Self::get_referenced_declarations(function));
+                }
+            }
+        }
+    }
+
+    ///
+    /// Returns the IDs of the declarations referenced within the AST node.
+    ///
+    fn get_referenced_declarations(ast: &serde_json::Value) -> BTreeSet<usize> {
+        let mut references = BTreeSet::new();
+        let mut nodes = vec![ast];
+        while let Some(ast) = nodes.pop() {
+            match ast {
+                serde_json::Value::Array(array) => nodes.extend(array.iter()),
+                serde_json::Value::Object(object) => {
+                    if let Some(id) = object
+                        .get("referencedDeclaration")
+                        .and_then(|id| id.as_u64())
+                    {
+                        references.insert(id as usize);
+                    }
+                    nodes.extend(object.values());
+                }
+                _ => {}
+            }
+        }
+        references
+    }
+}
+
+impl LinkedLibraries {
+    ///
+    /// Resolves the library references collected from all sources.
+    ///
+    /// The references of the inlined functions are followed transitively, so a library called
+    /// from an internal function of another library is linked to the contracts calling the latter.
+    ///
+    pub fn new(references: References, inheritance_graph: &InheritanceGraph) -> Self {
+        let mut result = Self::default();
+        for (full_path, node) in inheritance_graph.contracts.iter() {
+            let mut visited = BTreeSet::new();
+            let mut declarations: Vec<usize> = node
+                .linearization
+                .iter()
+                .filter_map(|base| references.contracts.get(base))
+                .flatten()
+                .copied()
+                .collect();
+            let mut libraries = BTreeSet::new();
+            while let Some(id) = declarations.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                if let Some(library) = references.linked_functions.get(&id) {
+                    if library != full_path {
+                        libraries.insert(library.to_owned());
+                    }
+                }
+                if let Some(inlined) = references.inlined_functions.get(&id) {
+                    declarations.extend(inlined.iter().copied());
+                }
+            }
+            if !libraries.is_empty() {
+                result.contracts.insert(full_path.to_owned(), libraries);
+            }
+        }
+        result
+    }
+}
@@ -2,27 +2,65 @@
 //! The `solc --standard-json` output.
 //!
 
+pub mod ast_cache;
+pub mod compatibility_report;
 pub mod contract;
 pub mod error;
+pub mod import_graph;
+pub mod inheritance_graph;
+pub mod linked_libraries;
+pub mod revert_sites;
 pub mod source;
+pub mod statistics;
+pub mod summary;
+pub mod timings;
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::io::Write;
+use std::path::Path;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 
 use rayon::iter::IntoParallelRefIterator;
 use rayon::iter::ParallelIterator;
 
+use crate::exit_code::ExitCode;
+use crate::exit_code::ExitCodeError;
+use crate::standard_json::input::settings::diagnostics::Diagnostics as StandardJsonInputSettingsDiagnostics;
+use crate::standard_json::input::settings::error_format::ErrorFormat as StandardJsonInputSettingsErrorFormat;
 use crate::standard_json::input::settings::error_type::ErrorType as StandardJsonInputSettingsErrorType;
+use crate::standard_json::input::settings::policy::Policy as StandardJsonInputSettingsPolicy;
 use crate::standard_json::input::settings::selection::selector::Selector;
 use crate::standard_json::input::settings::selection::Selection;
+use crate::standard_json::input::settings::suppressions::Suppressions as StandardJsonInputSettingsSuppressions;
 use crate::standard_json::input::settings::warning_type::WarningType as StandardJsonInputSettingsWarningType;
 use crate::standard_json::input::source::Source as StandardJSONInputSource;
 use crate::version::Version;
 
+use self::ast_cache::AstCache;
+use self::ast_cache::Entry as AstCacheEntry;
+use self::ast_cache::Message as AstCacheMessage;
+use self::compatibility_report::CompatibilityReport;
+use self::compatibility_report::Finding as CompatibilityReportFinding;
 use self::contract::Contract;
 use self::error::collectable::Collectable as CollectableError;
+use self::error::sarif::Sarif;
 use self::error::source_location::SourceLocation as JsonOutputErrorSourceLocation;
 use self::error::Error as JsonOutputError;
+use self::import_graph::Import;
+use self::import_graph::ImportGraph;
+use self::inheritance_graph::Definition as InheritanceGraphDefinition;
+use self::inheritance_graph::InheritanceGraph;
+use self::linked_libraries::LinkedLibraries;
+use self::linked_libraries::References as LinkedLibrariesReferences;
+use self::revert_sites::Collection as RevertSitesCollection;
+use self::revert_sites::RevertSites;
 use self::source::Source;
+use self::statistics::Statistics;
+use self::summary::cache::Cache as SummaryCache;
+use self::summary::Summary;
+use self::timings::Timings;
 
 ///
 /// The `solc --standard-json` output.
@@ -48,6 +86,59 @@ pub struct Output {
     /// The `zksolc` compiler version.
     #[serde(default = "crate::version")]
     pub zk_version: String,
+    /// The project-level summary.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Summary>,
+    /// The SARIF log of the errors and warnings, only set if requested.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub sarif: Option<Sarif>,
+    /// The exact standard JSON input passed to `solc`, only set if requested.
+    /// Kept as raw JSON, so the echo is byte-for-byte identical to the input.
+    #[serde(
+        rename = "solcInput",
+        skip_deserializing,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub solc_input: Option<Box<serde_json::value::RawValue>>,
+    /// The per-source and per-contract compilation timings, only set if requested.
+    #[serde(skip_deserializing, skip_serializing_if = "Option::is_none")]
+    pub timings: Option<Timings>,
+
+    /// The format of the diagnostics, additionally written to the output.
+    #[serde(skip)]
+    pub error_format: StandardJsonInputSettingsErrorFormat,
+    /// Whether to write the output as newline-delimited JSON, one contract per line.
+    #[serde(skip)]
+    pub stream_output: bool,
+
+    /// The EVM and EraVM semantic difference report extracted from the AST.
+    #[serde(skip)]
+    pub compatibility_report: CompatibilityReport,
+    /// The source import graph extracted from the AST.
+    #[serde(skip)]
+    pub import_graph: ImportGraph,
+    /// The inheritance graph extracted from the AST.
+    #[serde(skip)]
+    pub inheritance_graph: InheritanceGraph,
+    /// The AST check cache statistics, only set if the cache is used.
+    #[serde(skip)]
+    pub ast_cache_statistics: Option<SummaryCache>,
+    /// The contracts whose creation code is accessed with `type(C).creationCode`, keyed by the
+    /// accessing contract full path. Accesses in base contracts are attributed to the derived ones.
+    #[serde(skip)]
+    pub creation_code_dependencies: BTreeMap<String, BTreeSet<String>>,
+    /// The libraries linked to the contracts, extracted from the AST.
+    #[serde(skip)]
+    pub linked_libraries: LinkedLibraries,
+    /// The custom error revert sites extracted from the AST.
+    #[serde(skip)]
+    pub revert_sites: RevertSites,
+    /// The time spent in the AST analysis, keyed by source path.
+    #[serde(skip)]
+    pub analysis_durations: BTreeMap<String, std::time::Duration>,
+    /// The time spent in the backend, keyed by contract full path.
+    #[serde(skip)]
+    pub backend_durations: BTreeMap<String, std::time::Duration>,
 }
 
 impl Output {
@@ -74,6 +165,22 @@ impl Output {
             version: None,
             long_version: None,
             zk_version: crate::version(),
+            summary: None,
+            sarif: None,
+            solc_input: None,
+            timings: None,
+
+            error_format: StandardJsonInputSettingsErrorFormat::default(),
+            stream_output: false,
+            compatibility_report: CompatibilityReport::default(),
+            import_graph: ImportGraph::default(),
+            inheritance_graph: InheritanceGraph::default(),
+            ast_cache_statistics: None,
+            creation_code_dependencies: BTreeMap::new(),
+            linked_libraries: LinkedLibraries::default(),
+            revert_sites: RevertSites::default(),
+            analysis_durations: BTreeMap::new(),
+            backend_durations: BTreeMap::new(),
         }
     }
 
@@ -91,13 +198,76 @@ impl Output {
             version: None,
             long_version: None,
             zk_version: crate::version(),
+            summary: None,
+            sarif: None,
+            solc_input: None,
+            timings: None,
+
+            error_format: StandardJsonInputSettingsErrorFormat::default(),
+            stream_output: false,
+            compatibility_report: CompatibilityReport::default(),
+            import_graph: ImportGraph::default(),
+            inheritance_graph: InheritanceGraph::default(),
+            ast_cache_statistics: None,
+            creation_code_dependencies: BTreeMap::new(),
+            linked_libraries: LinkedLibraries::default(),
+            revert_sites: RevertSites::default(),
+            analysis_durations: BTreeMap::new(),
+            backend_durations: BTreeMap::new(),
         }
     }
 
+    ///
+    /// Returns the statistics of the errors and warnings in the output.
+    ///
+    pub fn statistics(&self) -> Statistics {
+        Statistics::new(self.errors.as_slice())
+    }
+
+    ///
+    /// Sets the project-level summary of the contracts, messages, and caches in the output.
+    ///
+    /// `build_cache` must only be set if the build cache is used.
+    ///
+    pub fn set_summary(
+        &mut self,
+        build_cache: Option<SummaryCache>,
+        solc_duration: Option<std::time::Duration>,
+        total_duration: std::time::Duration,
+    ) {
+        self.summary = Some(Summary::new(
+            &self.contracts,
+            self.errors.as_slice(),
+            self.ast_cache_statistics,
+            build_cache,
+            solc_duration,
+            total_duration,
+        ));
+    }
+
+    ///
+    /// Sets the per-source and per-contract compilation timings.
+    ///
+    pub fn set_timings(&mut self, solc_duration: Option<std::time::Duration>) {
+        self.timings = Some(Timings::new(
+            solc_duration,
+            &self.analysis_durations,
+            &self.backend_durations,
+        ));
+    }
+
     ///
     /// Prunes the output JSON and prints it to stdout.
     ///
-    pub fn write_and_exit(mut self, selection_to_prune: Selection) -> ! {
+    pub fn write_and_exit(self, selection_to_prune: Selection) -> ! {
+        self.finalize(&selection_to_prune)
+            .write_finalized_and_exit();
+    }
+
+    ///
+    /// Prunes the output JSON, and attaches the SARIF log if requested.
+    ///
+    pub fn finalize(mut self, selection_to_prune: &Selection) -> Self {
         let sources = self.sources.values_mut().collect::<Vec<&mut Source>>();
         for source in sources.into_iter() {
             if selection_to_prune.contains(&Selector::AST) {
@@ -117,13 +287,32 @@ impl Output {
             if selection_to_prune.contains(&Selector::Yul) {
                 contract.ir_optimized = String::new();
             }
+            if let Some(ref mut eravm) = contract.eravm {
+                if !selection_to_prune.contains(&Selector::EraVMAssemblyJSON) {
+                    eravm.set_assembly_json();
+                }
+                if selection_to_prune.contains(&Selector::EraVMAssembly) {
+                    eravm.assembly = None;
+                }
+            }
             if let Some(ref mut evm) = contract.evm {
+                if selection_to_prune.contains(&Selector::EraVMAssembly) {
+                    evm.assembly = None;
+                }
                 if selection_to_prune.contains(&Selector::EVMLA) {
                     evm.legacy_assembly = serde_json::Value::Null;
                 }
                 if selection_to_prune.contains(&Selector::MethodIdentifiers) {
                     evm.method_identifiers.clear();
                 }
+                if selection_to_prune.contains(&Selector::EVMBytecodeRaw) {
+                    for bytecode in [evm.bytecode.as_mut(), evm.deployed_bytecode.as_mut()]
+                        .into_iter()
+                        .flatten()
+                    {
+                        bytecode.raw = None;
+                    }
+                }
                 evm.extra_metadata = None;
             }
         }
@@ -133,8 +322,126 @@ impl Output {
             !contracts.is_empty()
         });
 
-        serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
-        std::process::exit(era_compiler_common::EXIT_CODE_SUCCESS);
+        if let StandardJsonInputSettingsErrorFormat::Sarif = self.error_format {
+            self.sarif = Some(Sarif::new(self.errors.as_slice()));
+        }
+        self
+    }
+
+    ///
+    /// Prints the output JSON finalized with [`Self::finalize`] to stdout.
+    ///
+    /// Exits with a non-zero code only if some warnings have been promoted to errors
+    /// by the severity overrides, as the other errors are only reported in the output.
+    ///
+    pub fn write_finalized_and_exit(self) -> ! {
+        let exit_code = self
+            .errors
+            .iter()
+            .filter(|error| error.is_promoted)
+            .map(|error| error.exit_code())
+            .max()
+            .unwrap_or(ExitCode::Success);
+        if self.stream_output {
+            self.write_streamed();
+        } else {
+            serde_json::to_writer(std::io::stdout(), &self).expect("Stdout writing error");
+        }
+        exit_code.exit();
+    }
+
+    ///
+    /// Writes the output to stdout as newline-delimited JSON.
+    ///
+    /// Each contract and source is serialized straight to stdout on a separate line, and dropped
+    /// right afterwards, so the whole output is never serialized at once, and no intermediate
+    /// copies of the contracts are made. The last line contains the rest of
+    /// the output, such as the errors and versions:
+    ///
+    /// ```json
+    /// {"path":"Test.sol","name":"Test","contract":{...}}
+    /// {"path":"Test.sol","source":{...}}
+    /// {"errors":[...],"version":"0.8.28",...}
+    /// ```
+    ///
+    fn write_streamed(mut self) {
+        ///
+        /// The contract line of the streamed output.
+        ///
+        #[derive(serde::Serialize)]
+        struct ContractLine<'a> {
+            /// The contract source path.
+            path: &'a str,
+            /// The contract name.
+            name: &'a str,
+            /// The contract output.
+            contract: &'a Contract,
+        }
+
+        ///
+        /// The source line of the streamed output.
+        ///
+        #[derive(serde::Serialize)]
+        struct SourceLine<'a> {
+            /// The source path.
+            path: &'a str,
+            /// The source output.
+            source: &'a Source,
+        }
+
+        let mut stdout = std::io::BufWriter::new(std::io::stdout().lock());
+        while let Some((path, contracts)) = self.contracts.pop_first() {
+            for (name, contract) in contracts.into_iter() {
+                let line = ContractLine {
+                    path: path.as_str(),
+                    name: name.as_str(),
+                    contract: &contract,
+                };
+                serde_json::to_writer(&mut stdout, &line).expect("Stdout writing error");
+                writeln!(stdout).expect("Stdout writing error");
+            }
+        }
+        while let Some((path, source)) = self.sources.pop_first() {
+            let line = SourceLine {
+                path: path.as_str(),
+                source: &source,
+            };
+            serde_json::to_writer(&mut stdout, &line).expect("Stdout writing error");
+            writeln!(stdout).expect("Stdout writing error");
+        }
+        serde_json::to_writer(&mut stdout, &self).expect("Stdout writing error");
+        writeln!(stdout).expect("Stdout writing error");
+        stdout.flush().expect("Stdout writing error");
+    }
+
+    ///
+    /// Reports the assembly builtins unavailable in the `evm_version`.
+    ///
+    /// `solc` either rejects such builtins with a generic message, or does not know them at all
+    /// if it is older than the builtins, so the errors name the EVM version they are available since.
+    /// Either way, the compilation fails, so the check is only needed if `solc` has reported errors.
+    ///
+    pub fn check_evm_version_builtins(
+        &mut self,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+        evm_version: &era_compiler_common::EVMVersion,
+    ) {
+        let id_paths: BTreeMap<usize, &String> = self
+            .sources
+            .iter()
+            .map(|(path, source)| (source.id, path))
+            .collect();
+        let evm_version = evm_version.to_string();
+
+        let messages: Vec<JsonOutputError> = self
+            .sources
+            .values()
+            .filter_map(|source| source.ast.as_ref())
+            .flat_map(|ast| {
+                Source::get_evm_version_messages(evm_version.as_str(), ast, &id_paths, sources)
+            })
+            .collect();
+        self.errors.extend(messages);
     }
 
     ///
@@ -150,57 +457,354 @@ impl Output {
         }
     }
 
+    ///
+    /// Takes the source ASTs out of the output, keyed by source path.
+    ///
+    pub fn take_ast_jsons(&mut self) -> BTreeMap<String, serde_json::Value> {
+        self.sources
+            .iter_mut()
+            .filter_map(|(path, source)| Some((path.to_owned(), source.ast.take()?)))
+            .collect()
+    }
+
+    ///
+    /// Takes the contract ABIs out of the output, keyed by contract full path.
+    ///
+    pub fn take_abi_jsons(&mut self) -> BTreeMap<String, serde_json::Value> {
+        self.contracts
+            .iter_mut()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .iter_mut()
+                    .map(move |(name, contract)| (format!("{path}:{name}"), contract.abi.take()))
+            })
+            .filter(|(_, abi)| !abi.is_null())
+            .collect()
+    }
+
+    ///
+    /// Returns the contract ABIs, keyed by contract full path.
+    ///
+    pub fn abi_jsons(&self) -> BTreeMap<String, &serde_json::Value> {
+        self.contracts
+            .iter()
+            .flat_map(|(path, contracts)| {
+                contracts
+                    .iter()
+                    .map(move |(name, contract)| (format!("{path}:{name}"), &contract.abi))
+            })
+            .filter(|(_, abi)| !abi.is_null())
+            .collect()
+    }
+
+    ///
+    /// Takes the echoed `solc` input out of the output and writes it to the file at `path`.
+    ///
+    pub fn take_and_write_solc_input(&mut self, path: &Path) -> anyhow::Result<()> {
+        let solc_input = match self.solc_input.take() {
+            Some(solc_input) => solc_input,
+            None => return Ok(()),
+        };
+        std::fs::write(path, solc_input.get())
+            .map_err(|error| anyhow::anyhow!("File {path:?} writing: {error}"))
+    }
+
+    ///
+    /// Removes all contracts except the one with the specified path and name.
+    ///
+    pub fn retain_contract(&mut self, path: &str, name: &str) {
+        self.contracts.retain(|contract_path, contracts| {
+            contracts.retain(|contract_name, _| contract_name == name);
+            contract_path == path && !contracts.is_empty()
+        });
+    }
+
+    ///
+    /// Removes all contracts except the ones with the specified full paths.
+    ///
+    pub fn retain_contracts(&mut self, full_paths: &BTreeSet<String>) {
+        self.contracts.retain(|path, contracts| {
+            contracts.retain(|name, _| full_paths.contains(format!("{path}:{name}").as_str()));
+            !contracts.is_empty()
+        });
+    }
+
     ///
     /// Pushes an arbitrary error with path.
     ///
     /// Please do not push project-general errors without paths here.
+    /// The exit code attached to the `error`, if any, is preserved.
     ///
     pub fn push_error(&mut self, path: Option<String>, error: anyhow::Error) {
-        self.errors.push(JsonOutputError::new_error(
-            error,
-            path.map(JsonOutputErrorSourceLocation::new),
-            None,
-        ));
+        let exit_code = error
+            .chain()
+            .find_map(|cause| cause.downcast_ref::<ExitCodeError>())
+            .map(|error| error.exit_code);
+        let mut error =
+            JsonOutputError::new_error(error, path.map(JsonOutputErrorSourceLocation::new), None);
+        error.exit_code = exit_code;
+        self.errors.push(error);
     }
 
     ///
     /// Traverses the AST and returns the list of additional errors and warnings.
     ///
+    /// The inheritance graph, the creation code dependencies, and the linked libraries
+    /// are extracted during the same traversal, as well as the compatibility report and the revert
+    /// sites if `compatibility_report` and `revert_sites` are set, respectively. The import graph
+    /// is extracted beforehand, as the cache keys depend on it.
+    /// If `ast_cache` is set, the errors, warnings, and compatibility findings of the sources unchanged
+    /// along with their imports are taken from the cache, skipping the checks.
+    ///
     pub fn preprocess_ast(
         &mut self,
         sources: &BTreeMap<String, StandardJSONInputSource>,
         version: &Version,
         suppressed_errors: &[StandardJsonInputSettingsErrorType],
         suppressed_warnings: &[StandardJsonInputSettingsWarningType],
+        suppressions: &BTreeMap<String, StandardJsonInputSettingsSuppressions>,
+        diagnostics: &StandardJsonInputSettingsDiagnostics,
+        policy: &StandardJsonInputSettingsPolicy,
+        strict_eravm: bool,
+        compatibility_report: bool,
+        revert_sites: bool,
+        ast_cache: Option<&Path>,
     ) -> anyhow::Result<()> {
         let id_paths: BTreeMap<usize, &String> = self
             .sources
             .iter()
             .map(|(path, source)| (source.id, path))
             .collect();
+        let ast_cache = ast_cache.map(|directory| {
+            AstCache::new(
+                directory,
+                version,
+                suppressed_errors,
+                suppressed_warnings,
+                suppressions,
+                diagnostics,
+                policy,
+                strict_eravm,
+                compatibility_report,
+            )
+        });
 
-        let messages: Vec<JsonOutputError> = self
+        let ast_cache_hits = AtomicUsize::new(0);
+        let ast_cache_misses = AtomicUsize::new(0);
+        let imports: BTreeMap<String, Vec<Import>> = self
             .sources
-            .par_iter()
-            .map(|(_path, source)| {
-                source
+            .iter()
+            .map(|(path, source)| {
+                let imports = source
                     .ast
                     .as_ref()
-                    .map(|ast| {
-                        Source::get_messages(
+                    .and_then(|ast| ast.get("nodes"))
+                    .and_then(|nodes| nodes.as_array())
+                    .map(|nodes| nodes.iter().filter_map(Import::try_from_node).collect())
+                    .unwrap_or_default();
+                (path.to_owned(), imports)
+            })
+            .collect();
+        self.import_graph = ImportGraph::new(imports);
+
+        #[allow(clippy::type_complexity)]
+        let results: Vec<(
+            &String,
+            Option<std::time::Duration>,
+            Vec<JsonOutputError>,
+            Vec<InheritanceGraphDefinition>,
+            Vec<(String, BTreeSet<usize>)>,
+            Vec<(String, Vec<CompatibilityReportFinding>)>,
+            RevertSitesCollection,
+            LinkedLibrariesReferences,
+        )> = self
+            .sources
+            .par_iter()
+            .map(|(path, source)| {
+                let Some(ast) = source.ast.as_ref() else {
+                    return (
+                        path,
+                        None,
+                        vec![],
+                        vec![],
+                        vec![],
+                        vec![],
+                        RevertSitesCollection::default(),
+                        LinkedLibrariesReferences::default(),
+                    );
+                };
+                let start_time = std::time::Instant::now();
+
+                let cache_key = ast_cache.as_ref().and_then(|ast_cache| {
+                    ast_cache.key(path.as_str(), &self.import_graph, sources)
+                });
+                let cached_entry = ast_cache
+                    .as_ref()
+                    .zip(cache_key.as_deref())
+                    .and_then(|(ast_cache, key)| ast_cache.get(key));
+                if ast_cache.is_some() {
+                    let counter = if cached_entry.is_some() {
+                        &ast_cache_hits
+                    } else {
+                        &ast_cache_misses
+                    };
+                    counter.fetch_add(1, Ordering::Relaxed);
+                }
+                let (messages, findings) = match cached_entry {
+                    Some(entry) => (
+                        entry
+                            .messages
+                            .into_iter()
+                            .map(JsonOutputError::from)
+                            .collect(),
+                        entry.findings,
+                    ),
+                    None => {
+                        let messages = Source::get_reports(
+                            path.as_str(),
                             ast,
                             &id_paths,
                             sources,
                             version,
                             suppressed_errors,
                             suppressed_warnings,
-                        )
-                    })
+                            suppressions,
+                            diagnostics,
+                            policy,
+                            strict_eravm,
+                        );
+                        let findings = if compatibility_report {
+                            CompatibilityReport::get_findings(
+                                path.as_str(),
+                                ast,
+                                &id_paths,
+                                sources,
+                            )
+                        } else {
+                            vec![]
+                        };
+                        if let (Some(ast_cache), Some(key)) = (ast_cache.as_ref(), cache_key) {
+                            let entry = AstCacheEntry {
+                                messages: messages
+                                    .iter()
+                                    .cloned()
+                                    .map(AstCacheMessage::from)
+                                    .collect(),
+                                findings: findings.clone(),
+                            };
+                            ast_cache.insert(key.as_str(), &entry);
+                        }
+                        (messages, findings)
+                    }
+                };
+
+                let mut definitions = Vec::new();
+                let mut creation_code_references = Vec::new();
+                for node in ast
+                    .get("nodes")
+                    .and_then(|nodes| nodes.as_array())
+                    .map(|nodes| nodes.as_slice())
                     .unwrap_or_default()
+                {
+                    if let Some(definition) =
+                        InheritanceGraphDefinition::try_from_node(path.as_str(), node)
+                    {
+                        let references = Source::get_creation_code_references(node);
+                        if !references.is_empty() {
+                            creation_code_references
+                                .push((definition.full_path.to_owned(), references));
+                        }
+                        definitions.push(definition);
+                    }
+                }
+                let revert_sites = if revert_sites {
+                    RevertSitesCollection::new(path.as_str(), ast, &id_paths, sources)
+                } else {
+                    RevertSitesCollection::default()
+                };
+                let library_references = LinkedLibrariesReferences::new(path.as_str(), ast);
+                (
+                    path,
+                    Some(start_time.elapsed()),
+                    messages,
+                    definitions,
+                    creation_code_references,
+                    findings,
+                    revert_sites,
+                    library_references,
+                )
+            })
+            .collect();
+        if ast_cache.is_some() {
+            self.ast_cache_statistics = Some(SummaryCache::new(
+                ast_cache_hits.into_inner(),
+                ast_cache_misses.into_inner(),
+            ));
+        }
+        if let Some(ast_cache) = ast_cache.as_ref() {
+            ast_cache.evict();
+        }
+
+        let mut definitions = Vec::new();
+        let mut creation_code_references = Vec::new();
+        let mut findings = Vec::new();
+        let mut revert_sites = RevertSitesCollection::default();
+        let mut library_references = LinkedLibrariesReferences::default();
+        for (
+            path,
+            source_duration,
+            source_messages,
+            source_definitions,
+            source_creation_code_references,
+            source_findings,
+            source_revert_sites,
+            source_library_references,
+        ) in results.into_iter()
+        {
+            if let Some(duration) = source_duration {
+                self.analysis_durations.insert(path.to_owned(), duration);
+            }
+            self.errors.extend(source_messages);
+            definitions.extend(source_definitions);
+            creation_code_references.extend(source_creation_code_references);
+            findings.extend(source_findings);
+            revert_sites.extend(source_revert_sites);
+            library_references.extend(source_library_references);
+        }
+        let contract_paths: BTreeMap<usize, String> = definitions
+            .iter()
+            .map(|definition| (definition.id, definition.full_path.to_owned()))
+            .collect();
+        let creation_code_references: BTreeMap<String, BTreeSet<String>> = creation_code_references
+            .into_iter()
+            .map(|(full_path, ids)| {
+                let dependencies = ids
+                    .iter()
+                    .filter_map(|id| contract_paths.get(id).cloned())
+                    .collect();
+                (full_path, dependencies)
+            })
+            .collect();
+        self.compatibility_report = CompatibilityReport::new(findings);
+        self.inheritance_graph = InheritanceGraph::new(definitions);
+        self.revert_sites =
+            RevertSites::new(revert_sites, &library_references, &self.inheritance_graph);
+        self.linked_libraries = LinkedLibraries::new(library_references, &self.inheritance_graph);
+        self.creation_code_dependencies = self
+            .inheritance_graph
+            .contracts
+            .iter()
+            .filter_map(|(full_path, node)| {
+                let dependencies: BTreeSet<String> = node
+                    .linearization
+                    .iter()
+                    .filter_map(|base| creation_code_references.get(base))
+                    .flatten()
+                    .cloned()
+                    .collect();
+                (!dependencies.is_empty()).then(|| (full_path.to_owned(), dependencies))
             })
-            .flatten()
             .collect();
-        self.errors.extend(messages);
 
         Ok(())
     }
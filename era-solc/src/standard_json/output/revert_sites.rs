@@ -0,0 +1,272 @@
+//!
+//! The custom error revert sites extracted from the `solc` AST.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::standard_json::input::source::Source as StandardJsonInputSource;
+use crate::standard_json::output::error::mapped_location::MappedLocation;
+use crate::standard_json::output::error::source_location::SourceLocation;
+use crate::standard_json::output::inheritance_graph::InheritanceGraph;
+use crate::standard_json::output::linked_libraries::References as LinkedLibrariesReferences;
+
+///
+/// The custom error revert sites extracted from the `solc` AST.
+///
+/// Covers `revert E(...)` statements and `require(condition, E(...))` calls.
+///
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+pub struct RevertSites {
+    /// The locations in the `path:line:column` format, keyed by contract full path and error selector.
+    /// Reverts in base contracts, library functions, and free functions are attributed to the calling contracts.
+    pub contracts: BTreeMap<String, BTreeMap<String, Vec<String>>>,
+}
+
+///
+/// The revert sites of a source, with unresolved AST node IDs.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Collection {
+    /// The custom error selectors, keyed by the error definition AST node ID.
+    pub selectors: BTreeMap<usize, String>,
+    /// The pairs of the error definition AST node ID and the location, keyed by the full path
+    /// of the contract containing them.
+    pub contracts: BTreeMap<String, Vec<(usize, String)>>,
+    /// The pairs of the error definition AST node ID and the location, keyed by the AST node ID
+    /// of the library function or free function containing them.
+    pub functions: BTreeMap<usize, Vec<(usize, String)>>,
+}
+
+impl Collection {
+    ///
+    /// Collects the custom error selectors and revert sites from the top-level nodes of the source `ast`.
+    ///
+    /// The sites in libraries are collected per function, so they can be attributed to the
+    /// contracts calling the functions, as libraries are not a part of their linearization.
+    ///
+    pub fn new(
+        path: &str,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Self {
+        let mut collection = Self::default();
+        for node in ast
+            .get("nodes")
+            .and_then(|nodes| nodes.as_array())
+            .map(|nodes| nodes.as_slice())
+            .unwrap_or_default()
+        {
+            match node.get("nodeType").and_then(|r#type| r#type.as_str()) {
+                Some("ContractDefinition") => {
+                    let Some(name) = node.get("name").and_then(|name| name.as_str()) else {
+                        continue;
+                    };
+                    let is_library =
+                        node.get("contractKind").and_then(|kind| kind.as_str()) == Some("library");
+                    if !is_library {
+                        let sites = collection.collect(node, id_paths, sources);
+                        if !sites.is_empty() {
+                            collection.contracts.insert(format!("{path}:{name}"), sites);
+                        }
+                        continue;
+                    }
+                    for child in node
+                        .get("nodes")
+                        .and_then(|nodes| nodes.as_array())
+                        .map(|nodes| nodes.as_slice())
+                        .unwrap_or_default()
+                    {
+                        collection.collect_function(child, id_paths, sources);
+                    }
+                }
+                Some("FunctionDefinition") => {
+                    collection.collect_function(node, id_paths, sources);
+                }
+                _ => {
+                    collection.collect(node, id_paths, sources);
+                }
+            }
+        }
+        collection
+    }
+
+    ///
+    /// Merges the collection of another source.
+    ///
+    pub fn extend(&mut self, other: Self) {
+        self.selectors.extend(other.selectors);
+        self.contracts.extend(other.contracts);
+        self.functions.extend(other.functions);
+    }
+
+    ///
+    /// Collects the revert sites of the function definition `node`, keyed by its AST node ID.
+    ///
+    /// The other nodes are only searched for the custom error definitions.
+    ///
+    fn collect_function(
+        &mut self,
+        node: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) {
+        let sites = self.collect(node, id_paths, sources);
+        if node.get("nodeType").and_then(|r#type| r#type.as_str()) != Some("FunctionDefinition") {
+            return;
+        }
+        if let Some(id) = node.get("id").and_then(|id| id.as_u64()) {
+            if !sites.is_empty() {
+                self.functions.insert(id as usize, sites);
+            }
+        }
+    }
+
+    ///
+    /// Collects the custom error selectors in the AST node, and returns its revert sites as pairs
+    /// of the error definition AST node ID and the location.
+    ///
+    fn collect(
+        &mut self,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Vec<(usize, String)> {
+        let mut sites = Vec::new();
+        let mut nodes = vec![ast];
+        while let Some(ast) = nodes.pop() {
+            match ast {
+                serde_json::Value::Array(array) => nodes.extend(array.iter()),
+                serde_json::Value::Object(object) => {
+                    let error_call = match object.get("nodeType").and_then(|r#type| r#type.as_str())
+                    {
+                        Some("ErrorDefinition") => {
+                            if let (Some(id), Some(selector)) = (
+                                object.get("id").and_then(|id| id.as_u64()),
+                                object
+                                    .get("errorSelector")
+                                    .and_then(|selector| selector.as_str()),
+                            ) {
+                                self.selectors.insert(id as usize, selector.to_owned());
+                            }
+                            None
+                        }
+                        Some("RevertStatement") => object.get("errorCall"),
+                        Some("FunctionCall") if RevertSites::is_require(object) => object
+                            .get("arguments")
+                            .and_then(|arguments| arguments.get(1)),
+                        _ => None,
+                    };
+                    if let Some(site) = error_call.and_then(|error_call| {
+                        RevertSites::try_resolve(error_call, ast, id_paths, sources)
+                    }) {
+                        sites.push(site);
+                    }
+                    nodes.extend(object.values());
+                }
+                _ => {}
+            }
+        }
+        sites
+    }
+}
+
+impl RevertSites {
+    ///
+    /// Builds the revert sites from the data collected from all sources.
+    ///
+    /// The library functions and free functions called by a contract are found by following the
+    /// `references` transitively, the same way as the linked libraries are resolved.
+    ///
+    pub fn new(
+        collection: Collection,
+        references: &LinkedLibrariesReferences,
+        inheritance_graph: &InheritanceGraph,
+    ) -> Self {
+        let mut result = Self::default();
+        for (full_path, node) in inheritance_graph.contracts.iter() {
+            let mut sites: Vec<&(usize, String)> = node
+                .linearization
+                .iter()
+                .filter_map(|base| collection.contracts.get(base))
+                .flatten()
+                .collect();
+
+            let mut visited = BTreeSet::new();
+            let mut declarations: Vec<usize> = node
+                .linearization
+                .iter()
+                .filter_map(|base| references.contracts.get(base))
+                .flatten()
+                .copied()
+                .collect();
+            while let Some(id) = declarations.pop() {
+                if !visited.insert(id) {
+                    continue;
+                }
+                if let Some(function_sites) = collection.functions.get(&id) {
+                    sites.extend(function_sites.iter());
+                }
+                if let Some(inlined) = references.inlined_functions.get(&id) {
+                    declarations.extend(inlined.iter().copied());
+                }
+            }
+
+            let mut contract: BTreeMap<String, Vec<String>> = BTreeMap::new();
+            for (id, location) in sites.into_iter() {
+                if let Some(selector) = collection.selectors.get(id) {
+                    let locations = contract.entry(selector.to_owned()).or_default();
+                    if !locations.contains(location) {
+                        locations.push(location.to_owned());
+                    }
+                }
+            }
+            if !contract.is_empty() {
+                result.contracts.insert(full_path.to_owned(), contract);
+            }
+        }
+        result
+    }
+
+    ///
+    /// Checks whether the function call node is a call to the built-in `require`.
+    ///
+    fn is_require(node: &serde_json::Map<String, serde_json::Value>) -> bool {
+        let Some(expression) = node.get("expression") else {
+            return false;
+        };
+        expression.get("name").and_then(|name| name.as_str()) == Some("require")
+            && expression
+                .get("referencedDeclaration")
+                .and_then(|declaration| declaration.as_i64())
+                .is_some_and(|declaration| declaration < 0)
+    }
+
+    ///
+    /// Resolves the error definition AST node ID of the error call node, and the location of the
+    /// `revert` or `require` node containing it.
+    ///
+    fn try_resolve(
+        error_call: &serde_json::Value,
+        node: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJsonInputSource>,
+    ) -> Option<(usize, String)> {
+        if error_call.get("nodeType")?.as_str()? != "FunctionCall" {
+            return None;
+        }
+        let id = error_call
+            .get("expression")?
+            .get("referencedDeclaration")?
+            .as_u64()? as usize;
+
+        let source_location = SourceLocation::try_from_ast(node.get("src")?.as_str()?, id_paths)?;
+        let source_code = sources
+            .get(source_location.file.as_str())
+            .and_then(|source| source.content());
+        let location = MappedLocation::try_from_source_location(&source_location, source_code)
+            .to_short_string();
+        Some((id, location))
+    }
+}
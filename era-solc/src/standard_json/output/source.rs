@@ -3,12 +3,19 @@
 //!
 
 use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::str::FromStr;
 
 use boolinator::Boolinator;
 
+use crate::standard_json::input::settings::diagnostics::Diagnostics as StandardJsonInputSettingsDiagnostics;
 use crate::standard_json::input::settings::error_type::ErrorType as StandardJsonInputSettingsErrorType;
+use crate::standard_json::input::settings::policy::Policy as StandardJsonInputSettingsPolicy;
+use crate::standard_json::input::settings::suppressions::Suppressions as StandardJsonInputSettingsSuppressions;
 use crate::standard_json::input::settings::warning_type::WarningType as StandardJsonInputSettingsWarningType;
 use crate::standard_json::input::source::Source as StandardJSONInputSource;
+use crate::standard_json::output::error::source_location::SourceLocation as StandardJsonOutputErrorSourceLocation;
+use crate::standard_json::output::error::suggested_fix::SuggestedFix as StandardJsonOutputErrorSuggestedFix;
 use crate::standard_json::output::error::Error as StandardJsonOutputError;
 use crate::version::Version;
 
@@ -26,6 +33,9 @@ pub struct Source {
 }
 
 impl Source {
+    /// The comment disabling the diagnostics on the next line, e.g. `// zksolc-disable-next-line txorigin`.
+    pub const DISABLE_NEXT_LINE_COMMENT: &'static str = "zksolc-disable-next-line";
+
     ///
     /// Initializes a standard JSON source.
     ///
@@ -62,13 +72,75 @@ impl Source {
         }
         affected_types.contains(&type_identifier).as_option()?;
 
-        Some(StandardJsonOutputError::error_send_and_transfer(
-            ast.get("src")?.as_str(),
+        let suggested_fix =
+            if member_name == "transfer" && solc_version.default >= semver::Version::new(0, 6, 2) {
+                Self::suggest_transfer_fix(ast, expression, id_paths, sources)
+            } else {
+                None
+            };
+
+        Some(
+            StandardJsonOutputError::error_send_and_transfer(
+                ast.get("src")?.as_str(),
+                id_paths,
+                sources,
+            )
+            .with_suggested_fix(suggested_fix),
+        )
+    }
+
+    ///
+    /// Suggests the fix replacing the `transfer` call with a low-level call.
+    ///
+    /// The `{value: <X>}` call option syntax is only available since Solidity v0.6.2.
+    ///
+    /// The replacement is a block statement, so the fix is only suggested if the call is a whole
+    /// statement terminated with `;`, and the span is extended to the terminator.
+    ///
+    fn suggest_transfer_fix(
+        call: &serde_json::Map<String, serde_json::Value>,
+        recipient: &serde_json::Map<String, serde_json::Value>,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputErrorSuggestedFix> {
+        let mut source_location = StandardJsonOutputErrorSourceLocation::try_from_ast(
+            call.get("src")?.as_str()?,
             id_paths,
-            sources,
+        )?;
+        let source_code = sources.get(source_location.file.as_str())?.content()?;
+
+        let call_end = usize::try_from(source_location.end).ok()?;
+        let remainder = source_code.get(call_end..)?;
+        let terminator_offset = remainder.len() - remainder.trim_start().len();
+        remainder[terminator_offset..]
+            .starts_with(';')
+            .as_option()?;
+        source_location.end += (terminator_offset + 1) as isize;
+
+        let recipient = Self::get_source_code(recipient, source_code)?;
+        let amount = call.get("arguments")?.as_array()?.first()?.as_object()?;
+        let amount = Self::get_source_code(amount, source_code)?;
+
+        Some(StandardJsonOutputErrorSuggestedFix::transfer(
+            source_location,
+            recipient,
+            amount,
         ))
     }
 
+    ///
+    /// Returns the source code of the AST node.
+    ///
+    fn get_source_code<'a>(
+        node: &serde_json::Map<String, serde_json::Value>,
+        source_code: &'a str,
+    ) -> Option<&'a str> {
+        let mut parts = node.get("src")?.as_str()?.split(':');
+        let start = parts.next()?.parse::<usize>().ok()?;
+        let length = parts.next()?.parse::<usize>().ok()?;
+        source_code.get(start..start + length)
+    }
+
     ///
     /// Checks the AST node for the usage of `create` and `create2` in assembly blocks.
     ///
@@ -108,6 +180,278 @@ impl Source {
         ))
     }
 
+    ///
+    /// Checks the assembly block for init code assembled in memory with `mstore` and passed
+    /// to `create` or `create2`.
+    ///
+    /// The whole block is checked at once, as the init code is usually stored in memory by
+    /// a few statements preceding the call. The warning points to the first `create` or `create2` call.
+    ///
+    /// The calls whose offset argument refers to a Solidity variable, e.g. `add(code, 0x20)` with
+    /// `code` set to `type(C).creationCode`, are skipped, as the init code is then produced by
+    /// the compiler. Before solc v0.6.0, the offset arguments are extracted from the assembly text.
+    ///
+    pub fn check_assembly_init_code(
+        solc_version: &Version,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        (ast.get("nodeType")?.as_str()? == "InlineAssembly").as_option()?;
+        let external_references = ast
+            .get("externalReferences")
+            .and_then(|references| references.as_array())
+            .map(|references| references.as_slice())
+            .unwrap_or_default();
+        let node = if solc_version.default < semver::Version::new(0, 6, 0) {
+            let external_references: BTreeSet<&str> = external_references
+                .iter()
+                .filter_map(|reference| reference.as_object())
+                .flat_map(|reference| reference.keys().map(|name| name.as_str()))
+                .collect();
+            let assembly = ast.get("operations")?.as_str()?;
+            ["create(", "create2("]
+                .iter()
+                .flat_map(|instruction| {
+                    assembly
+                        .match_indices(instruction)
+                        .map(|(index, instruction)| &assembly[index + instruction.len()..])
+                })
+                .any(|arguments| {
+                    !Self::get_offset_argument(arguments).is_some_and(|offset| {
+                        offset
+                            .split(|character: char| {
+                                !character.is_ascii_alphanumeric() && character != '_'
+                            })
+                            .any(|identifier| external_references.contains(identifier))
+                    })
+                })
+                .as_option()?;
+            ["mstore(", "mstore8("]
+                .iter()
+                .any(|instruction| assembly.contains(instruction))
+                .as_option()?;
+            ast.get("src")?.as_str()?
+        } else {
+            let external_references: BTreeSet<&str> = external_references
+                .iter()
+                .filter_map(|reference| reference.get("src").and_then(|src| src.as_str()))
+                .collect();
+
+            let mut create = None;
+            let mut is_memory_written = false;
+            let mut nodes = vec![ast.get("AST")?];
+            while let Some(node) = nodes.pop() {
+                match node {
+                    serde_json::Value::Array(array) => nodes.extend(array.iter().rev()),
+                    serde_json::Value::Object(object) => {
+                        if object.get("nodeType").and_then(|r#type| r#type.as_str())
+                            == Some("YulFunctionCall")
+                        {
+                            match object
+                                .get("functionName")
+                                .and_then(|name| name.get("name"))
+                                .and_then(|name| name.as_str())
+                            {
+                                Some("create" | "create2")
+                                    if create.is_none()
+                                        && !object
+                                            .get("arguments")
+                                            .and_then(|arguments| arguments.get(1))
+                                            .is_some_and(|offset| {
+                                                Self::is_external_reference(
+                                                    offset,
+                                                    &external_references,
+                                                )
+                                            }) =>
+                                {
+                                    create = object.get("src").and_then(|src| src.as_str());
+                                }
+                                Some("mstore" | "mstore8") => is_memory_written = true,
+                                _ => {}
+                            }
+                        }
+                        nodes.extend(object.values().rev());
+                    }
+                    _ => {}
+                }
+            }
+            is_memory_written.as_option()?;
+            create?
+        };
+
+        Some(StandardJsonOutputError::warning_assembly_init_code(
+            Some(node),
+            id_paths,
+            sources,
+        ))
+    }
+
+    ///
+    /// Returns the text of the second argument of the assembly call, whose `arguments` text
+    /// follows the opening parenthesis.
+    ///
+    fn get_offset_argument(arguments: &str) -> Option<&str> {
+        let mut depth = 0;
+        let mut start = None;
+        for (index, character) in arguments.char_indices() {
+            match character {
+                '(' => depth += 1,
+                ')' if depth == 0 => return start.map(|start| &arguments[start..index]),
+                ')' => depth -= 1,
+                ',' if depth == 0 => match start {
+                    None => start = Some(index + 1),
+                    Some(start) => return Some(&arguments[start..index]),
+                },
+                _ => {}
+            }
+        }
+        None
+    }
+
+    ///
+    /// Whether the Yul expression contains an identifier referring to a Solidity variable.
+    ///
+    fn is_external_reference(
+        expression: &serde_json::Value,
+        external_references: &BTreeSet<&str>,
+    ) -> bool {
+        let mut nodes = vec![expression];
+        while let Some(node) = nodes.pop() {
+            match node {
+                serde_json::Value::Array(array) => nodes.extend(array.iter()),
+                serde_json::Value::Object(object) => {
+                    if object.get("nodeType").and_then(|r#type| r#type.as_str())
+                        == Some("YulIdentifier")
+                        && object
+                            .get("src")
+                            .and_then(|src| src.as_str())
+                            .is_some_and(|src| external_references.contains(src))
+                    {
+                        return true;
+                    }
+                    nodes.extend(object.values());
+                }
+                _ => {}
+            }
+        }
+        false
+    }
+
+    ///
+    /// Checks the AST node for the usage of `selfdestruct`, including its deprecated `suicide` alias,
+    /// and the eponymous assembly instruction.
+    ///
+    pub fn check_selfdestruct(
+        solc_version: &Version,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        match ast.get("nodeType")?.as_str()? {
+            "FunctionCall" => {
+                let expression = ast.get("expression")?.as_object()?;
+                (expression.get("nodeType")?.as_str()? == "Identifier").as_option()?;
+                ["selfdestruct", "suicide"]
+                    .contains(&expression.get("name")?.as_str()?)
+                    .as_option()?;
+                let type_descriptions = expression.get("typeDescriptions")?.as_object()?;
+                type_descriptions
+                    .get("typeIdentifier")?
+                    .as_str()?
+                    .starts_with("t_function_selfdestruct")
+                    .as_option()?;
+            }
+            "InlineAssembly" if solc_version.default < semver::Version::new(0, 6, 0) => {
+                ast.get("operations")?
+                    .as_str()?
+                    .contains("selfdestruct(")
+                    .as_option()?;
+            }
+            "YulFunctionCall" if solc_version.default >= semver::Version::new(0, 6, 0) => {
+                (ast.get("functionName")?
+                    .as_object()?
+                    .get("name")?
+                    .as_str()?
+                    == "selfdestruct")
+                    .as_option()?;
+            }
+            _ => return None,
+        }
+
+        Some(StandardJsonOutputError::error_selfdestruct(
+            ast.get("src")?.as_str(),
+            id_paths,
+            sources,
+        ))
+    }
+
+    ///
+    /// Checks the AST node for the usage of creation code.
+    ///
+    pub fn check_creation_code(
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        (ast.get("nodeType")?.as_str()? == "MemberAccess").as_option()?;
+        (ast.get("memberName")?.as_str()? == "creationCode").as_option()?;
+
+        let expression = ast.get("expression")?.as_object()?;
+        let type_descriptions = expression.get("typeDescriptions")?.as_object()?;
+        type_descriptions
+            .get("typeIdentifier")?
+            .as_str()?
+            .starts_with("t_magic_meta_type")
+            .as_option()?;
+
+        Some(StandardJsonOutputError::warning_creation_code(
+            ast.get("src")?.as_str(),
+            id_paths,
+            sources,
+        ))
+    }
+
+    ///
+    /// Returns the AST node IDs of the contracts whose creation code is accessed with
+    /// `type(C).creationCode` within the AST node.
+    ///
+    pub fn get_creation_code_references(ast: &serde_json::Value) -> BTreeSet<usize> {
+        let mut references = BTreeSet::new();
+        let mut nodes = vec![ast];
+        while let Some(ast) = nodes.pop() {
+            match ast {
+                serde_json::Value::Array(array) => nodes.extend(array.iter()),
+                serde_json::Value::Object(object) => {
+                    if object.get("nodeType").and_then(|r#type| r#type.as_str())
+                        == Some("MemberAccess")
+                        && object.get("memberName").and_then(|name| name.as_str())
+                            == Some("creationCode")
+                    {
+                        if let Some(id) = object
+                            .get("expression")
+                            .and_then(|expression| expression.get("arguments"))
+                            .and_then(|arguments| arguments.get(0))
+                            .and_then(|argument| argument.get("referencedDeclaration"))
+                            .and_then(|id| id.as_u64())
+                        {
+                            references.insert(id as usize);
+                        }
+                    }
+                    nodes.extend(object.values());
+                }
+                _ => {}
+            }
+        }
+        references
+    }
+
     ///
     /// Checks the AST node for the usage of runtime code.
     ///
@@ -153,13 +497,166 @@ impl Source {
         (expression.get("nodeType")?.as_str()? == "Identifier").as_option()?;
         (expression.get("name")?.as_str()? == "tx").as_option()?;
 
-        Some(StandardJsonOutputError::warning_tx_origin(
+        let suggested_fix = ast
+            .get("src")?
+            .as_str()
+            .and_then(|node| StandardJsonOutputErrorSourceLocation::try_from_ast(node, id_paths))
+            .map(StandardJsonOutputErrorSuggestedFix::tx_origin);
+
+        Some(
+            StandardJsonOutputError::warning_tx_origin(ast.get("src")?.as_str(), id_paths, sources)
+                .with_suggested_fix(suggested_fix),
+        )
+    }
+
+    ///
+    /// Checks the AST node for the `blockhash(...)` calls and the `block.prevrandao` and
+    /// `block.difficulty` values usage.
+    ///
+    pub fn check_block_randomness(
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        match ast.get("nodeType")?.as_str()? {
+            "FunctionCall" => {
+                let expression = ast.get("expression")?.as_object()?;
+                (expression.get("nodeType")?.as_str()? == "Identifier").as_option()?;
+                (expression.get("name")?.as_str()? == "blockhash").as_option()?;
+                let type_descriptions = expression.get("typeDescriptions")?.as_object()?;
+                type_descriptions
+                    .get("typeIdentifier")?
+                    .as_str()?
+                    .starts_with("t_function_blockhash")
+                    .as_option()?;
+            }
+            "MemberAccess" => {
+                ["prevrandao", "difficulty", "blockhash"]
+                    .contains(&ast.get("memberName")?.as_str()?)
+                    .as_option()?;
+                let expression = ast.get("expression")?.as_object()?;
+                (expression.get("nodeType")?.as_str()? == "Identifier").as_option()?;
+                (expression.get("name")?.as_str()? == "block").as_option()?;
+            }
+            _ => return None,
+        }
+
+        Some(StandardJsonOutputError::warning_block_randomness(
+            ast.get("src")?.as_str(),
+            id_paths,
+            sources,
+        ))
+    }
+
+    ///
+    /// Checks the AST node for `staticcall` calls to the hardcoded addresses of the `ecrecover`,
+    /// `sha256`, and `ripemd160` precompiles, both in Solidity and in assembly blocks.
+    ///
+    /// Direct calls of the eponymous builtin functions are not affected.
+    ///
+    pub fn check_precompile_call(
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        let address = match ast.get("nodeType")?.as_str()? {
+            "FunctionCall" => {
+                let mut expression = ast.get("expression")?.as_object()?;
+                if expression.get("nodeType")?.as_str()? == "FunctionCallOptions" {
+                    expression = expression.get("expression")?.as_object()?;
+                }
+                (expression.get("nodeType")?.as_str()? == "MemberAccess").as_option()?;
+                (expression.get("memberName")?.as_str()? == "staticcall").as_option()?;
+
+                let conversion = expression.get("expression")?.as_object()?;
+                (conversion.get("nodeType")?.as_str()? == "FunctionCall").as_option()?;
+                (conversion.get("kind")?.as_str()? == "typeConversion").as_option()?;
+                let literal = conversion.get("arguments")?.as_array()?.first()?;
+                (literal.get("nodeType")?.as_str()? == "Literal").as_option()?;
+                literal.get("value")?.as_str()?
+            }
+            "YulFunctionCall" => {
+                (ast.get("functionName")?
+                    .as_object()?
+                    .get("name")?
+                    .as_str()?
+                    == "staticcall")
+                    .as_option()?;
+                let literal = ast.get("arguments")?.as_array()?.get(1)?;
+                (literal.get("nodeType")?.as_str()? == "YulLiteral").as_option()?;
+                literal.get("value")?.as_str()?
+            }
+            _ => return None,
+        };
+        let address = match address.strip_prefix("0x") {
+            Some(address) => u64::from_str_radix(address, 16).ok()?,
+            None => address.parse::<u64>().ok()?,
+        };
+        (1..=3).contains(&address).as_option()?;
+
+        Some(StandardJsonOutputError::warning_precompile_call(
             ast.get("src")?.as_str(),
             id_paths,
             sources,
         ))
     }
 
+    ///
+    /// Checks the AST node for the sources compiled with ABI coder v1.
+    ///
+    /// The warning is reported at `pragma abicoder v1`, or at `pragma solidity` if ABI coder v1 is
+    /// the default, that is, before solc v0.8.0 without `pragma abicoder v2` or `pragma experimental ABIEncoderV2`.
+    ///
+    pub fn check_abi_coder_v1(
+        ast: &serde_json::Value,
+        is_abi_coder_v1_default: bool,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+
+        (ast.get("nodeType")?.as_str()? == "PragmaDirective").as_option()?;
+        let literals = ast.get("literals")?.as_array()?;
+        match literals.first()?.as_str()? {
+            "abicoder" => (literals.get(1)?.as_str()? == "v1").as_option()?,
+            "solidity" => is_abi_coder_v1_default.as_option()?,
+            _ => return None,
+        }
+
+        Some(StandardJsonOutputError::warning_abi_coder_v1(
+            ast.get("src")?.as_str(),
+            id_paths,
+            sources,
+        ))
+    }
+
+    ///
+    /// Whether the AST node is `pragma abicoder v2` or `pragma experimental ABIEncoderV2`.
+    ///
+    fn is_abi_coder_v2_pragma(ast: &serde_json::Value) -> bool {
+        if ast.get("nodeType").and_then(|node_type| node_type.as_str()) != Some("PragmaDirective") {
+            return false;
+        }
+        let literals: Vec<&str> = ast
+            .get("literals")
+            .and_then(|literals| literals.as_array())
+            .map(|literals| {
+                literals
+                    .iter()
+                    .filter_map(|literal| literal.as_str())
+                    .collect()
+            })
+            .unwrap_or_default();
+        matches!(
+            literals.as_slice(),
+            ["abicoder", "v2"] | ["experimental", "ABIEncoderV2"]
+        )
+    }
+
     ///
     /// Checks the AST node for the `origin` assembly instruction usage.
     ///
@@ -171,12 +668,13 @@ impl Source {
     ) -> Option<StandardJsonOutputError> {
         let ast = ast.as_object()?;
 
-        match ast.get("nodeType")?.as_str()? {
+        let suggested_fix = match ast.get("nodeType")?.as_str()? {
             "InlineAssembly" if solc_version.default < semver::Version::new(0, 6, 0) => {
                 ast.get("operations")?
                     .as_str()?
                     .contains("origin()")
                     .as_option()?;
+                None
             }
             "YulFunctionCall" if solc_version.default >= semver::Version::new(0, 6, 0) => {
                 (ast.get("functionName")?
@@ -185,106 +683,424 @@ impl Source {
                     .as_str()?
                     == "origin")
                     .as_option()?;
+                ast.get("src")?
+                    .as_str()
+                    .and_then(|node| {
+                        StandardJsonOutputErrorSourceLocation::try_from_ast(node, id_paths)
+                    })
+                    .map(StandardJsonOutputErrorSuggestedFix::assembly_origin)
             }
             _ => return None,
-        }
+        };
 
-        Some(StandardJsonOutputError::warning_tx_origin(
-            ast.get("src")?.as_str(),
+        Some(
+            StandardJsonOutputError::warning_tx_origin(ast.get("src")?.as_str(), id_paths, sources)
+                .with_suggested_fix(suggested_fix),
+        )
+    }
+
+    ///
+    /// Checks whether the assembly builtin called by the AST node is available in the `evm_version`.
+    ///
+    pub fn check_evm_version_builtin(
+        evm_version: &str,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Option<StandardJsonOutputError> {
+        let ast = ast.as_object()?;
+        (ast.get("nodeType")?.as_str()? == "YulFunctionCall").as_option()?;
+
+        let function_name = ast.get("functionName")?.as_object()?;
+        let builtin = function_name.get("name")?.as_str()?;
+        let required = crate::evm_version::builtin_minimum_version(builtin)?;
+        crate::evm_version::is_older(evm_version, required)?.as_option()?;
+
+        Some(StandardJsonOutputError::error_evm_version_builtin(
+            builtin,
+            required,
+            evm_version,
+            function_name
+                .get("src")
+                .or_else(|| ast.get("src"))
+                .and_then(|src| src.as_str()),
             id_paths,
             sources,
         ))
     }
 
+    ///
+    /// Returns the errors of the assembly builtins unavailable in the `evm_version`.
+    ///
+    /// The AST is traversed in pre-order with an explicit work list instead of recursion,
+    /// so deeply nested ASTs cannot overflow the stack.
+    ///
+    pub fn get_evm_version_messages(
+        evm_version: &str,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> Vec<StandardJsonOutputError> {
+        let mut messages = Vec::new();
+        let mut nodes = vec![ast];
+        while let Some(ast) = nodes.pop() {
+            if let Some(message) =
+                Self::check_evm_version_builtin(evm_version, ast, id_paths, sources)
+            {
+                messages.push(message);
+            }
+
+            match ast {
+                serde_json::Value::Array(array) => nodes.extend(array.iter().rev()),
+                serde_json::Value::Object(object) => nodes.extend(object.values().rev()),
+                _ => {}
+            }
+        }
+        messages
+    }
+
     ///
     /// Returns the list of messages for some specific parts of the AST.
     ///
+    /// The AST is traversed in pre-order with an explicit work list instead of recursion,
+    /// so deeply nested ASTs cannot overflow the stack.
+    ///
+    /// `is_abi_coder_v1_default` is set if the source has no ABI coder pragma and is compiled with solc older than v0.8.0.
+    ///
     pub fn get_messages(
         ast: &serde_json::Value,
         id_paths: &BTreeMap<usize, &String>,
         sources: &BTreeMap<String, StandardJSONInputSource>,
         solc_version: &Version,
+        is_abi_coder_v1_default: bool,
         suppressed_errors: &[StandardJsonInputSettingsErrorType],
         suppressed_warnings: &[StandardJsonInputSettingsWarningType],
+        suppressions: &BTreeMap<String, StandardJsonInputSettingsSuppressions>,
     ) -> Vec<StandardJsonOutputError> {
+        let source_suppressions = |message: &StandardJsonOutputError| {
+            message
+                .source_location
+                .as_ref()
+                .and_then(|location| suppressions.get(location.file.as_str()))
+        };
+        let is_error_suppressed_in_source =
+            |error_type: StandardJsonInputSettingsErrorType, message: &StandardJsonOutputError| {
+                source_suppressions(message)
+                    .is_some_and(|suppressions| suppressions.errors.contains(&error_type))
+            };
+        let is_warning_suppressed_in_source =
+            |warning_type: StandardJsonInputSettingsWarningType,
+             message: &StandardJsonOutputError| {
+                source_suppressions(message)
+                    .is_some_and(|suppressions| suppressions.warnings.contains(&warning_type))
+            };
+
         let mut messages = Vec::new();
-        if !suppressed_errors.contains(&StandardJsonInputSettingsErrorType::SendTransfer) {
-            if let Some(message) =
-                Self::check_send_and_transfer(solc_version, ast, id_paths, sources)
-            {
+        let mut nodes = vec![ast];
+        while let Some(ast) = nodes.pop() {
+            if !suppressed_errors.contains(&StandardJsonInputSettingsErrorType::SendTransfer) {
+                if let Some(message) =
+                    Self::check_send_and_transfer(solc_version, ast, id_paths, sources).filter(
+                        |message| {
+                            !is_error_suppressed_in_source(
+                                StandardJsonInputSettingsErrorType::SendTransfer,
+                                message,
+                            )
+                        },
+                    )
+                {
+                    messages.push(message);
+                }
+            }
+            if !suppressed_errors.contains(&StandardJsonInputSettingsErrorType::AssemblyCreate) {
+                if let Some(message) =
+                    Self::check_assembly_create(solc_version, ast, id_paths, sources).filter(
+                        |message| {
+                            !is_error_suppressed_in_source(
+                                StandardJsonInputSettingsErrorType::AssemblyCreate,
+                                message,
+                            )
+                        },
+                    )
+                {
+                    messages.push(message);
+                }
+            }
+            if let Some(message) = Self::check_selfdestruct(solc_version, ast, id_paths, sources) {
                 messages.push(message);
             }
-        }
-        if !suppressed_errors.contains(&StandardJsonInputSettingsErrorType::AssemblyCreate) {
-            if let Some(message) = Self::check_assembly_create(solc_version, ast, id_paths, sources)
-            {
+            if let Some(message) = Self::check_runtime_code(ast, id_paths, sources) {
                 messages.push(message);
             }
-        }
-        if let Some(message) = Self::check_runtime_code(ast, id_paths, sources) {
-            messages.push(message);
-        }
-        if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::TxOrigin) {
-            if let Some(message) = Self::check_assembly_origin(solc_version, ast, id_paths, sources)
+            if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::CreationCode) {
+                if let Some(message) =
+                    Self::check_creation_code(ast, id_paths, sources).filter(|message| {
+                        !is_warning_suppressed_in_source(
+                            StandardJsonInputSettingsWarningType::CreationCode,
+                            message,
+                        )
+                    })
+                {
+                    messages.push(message);
+                }
+            }
+            if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::TxOrigin) {
+                if let Some(message) =
+                    Self::check_assembly_origin(solc_version, ast, id_paths, sources).filter(
+                        |message| {
+                            !is_warning_suppressed_in_source(
+                                StandardJsonInputSettingsWarningType::TxOrigin,
+                                message,
+                            )
+                        },
+                    )
+                {
+                    messages.push(message);
+                }
+                if let Some(message) =
+                    Self::check_tx_origin(ast, id_paths, sources).filter(|message| {
+                        !is_warning_suppressed_in_source(
+                            StandardJsonInputSettingsWarningType::TxOrigin,
+                            message,
+                        )
+                    })
+                {
+                    messages.push(message);
+                }
+            }
+            if !suppressed_warnings
+                .contains(&StandardJsonInputSettingsWarningType::AssemblyInitCode)
             {
-                messages.push(message);
+                if let Some(message) =
+                    Self::check_assembly_init_code(solc_version, ast, id_paths, sources).filter(
+                        |message| {
+                            (suppressed_errors
+                                .contains(&StandardJsonInputSettingsErrorType::AssemblyCreate)
+                                || is_error_suppressed_in_source(
+                                    StandardJsonInputSettingsErrorType::AssemblyCreate,
+                                    message,
+                                ))
+                                && !is_warning_suppressed_in_source(
+                                    StandardJsonInputSettingsWarningType::AssemblyInitCode,
+                                    message,
+                                )
+                        },
+                    )
+                {
+                    messages.push(message);
+                }
             }
-            if let Some(message) = Self::check_tx_origin(ast, id_paths, sources) {
-                messages.push(message);
+            if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::PrecompileCall)
+            {
+                if let Some(message) =
+                    Self::check_precompile_call(ast, id_paths, sources).filter(|message| {
+                        !is_warning_suppressed_in_source(
+                            StandardJsonInputSettingsWarningType::PrecompileCall,
+                            message,
+                        )
+                    })
+                {
+                    messages.push(message);
+                }
             }
-        }
-
-        match ast {
-            serde_json::Value::Array(array) => {
-                for element in array.iter() {
-                    messages.extend(Self::get_messages(
-                        element,
-                        id_paths,
-                        sources,
-                        solc_version,
-                        suppressed_errors,
-                        suppressed_warnings,
-                    ));
+            if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::AbiCoderV1) {
+                if let Some(message) =
+                    Self::check_abi_coder_v1(ast, is_abi_coder_v1_default, id_paths, sources)
+                        .filter(|message| {
+                            !is_warning_suppressed_in_source(
+                                StandardJsonInputSettingsWarningType::AbiCoderV1,
+                                message,
+                            )
+                        })
+                {
+                    messages.push(message);
                 }
             }
-            serde_json::Value::Object(object) => {
-                for (_key, value) in object.iter() {
-                    messages.extend(Self::get_messages(
-                        value,
-                        id_paths,
-                        sources,
-                        solc_version,
-                        suppressed_errors,
-                        suppressed_warnings,
-                    ));
+            if !suppressed_warnings.contains(&StandardJsonInputSettingsWarningType::BlockRandomness)
+            {
+                if let Some(message) =
+                    Self::check_block_randomness(ast, id_paths, sources).filter(|message| {
+                        !is_warning_suppressed_in_source(
+                            StandardJsonInputSettingsWarningType::BlockRandomness,
+                            message,
+                        )
+                    })
+                {
+                    messages.push(message);
                 }
             }
-            _ => {}
-        }
 
+            match ast {
+                serde_json::Value::Array(array) => nodes.extend(array.iter().rev()),
+                serde_json::Value::Object(object) => nodes.extend(object.values().rev()),
+                _ => {}
+            }
+        }
         messages
     }
 
     ///
-    /// Returns the name of the last contract.
+    /// Returns the consolidated reports of unsupported features, one per contract and severity.
     ///
-    pub fn last_contract_name(&self) -> anyhow::Result<String> {
-        self.ast
-            .as_ref()
-            .ok_or_else(|| anyhow::anyhow!("The AST is empty"))?
-            .get("nodes")
-            .and_then(|value| value.as_array())
-            .ok_or_else(|| {
-                anyhow::anyhow!("The last contract cannot be found in an empty list of nodes")
-            })?
+    /// Features used outside of contracts, e.g. in free functions, are reported per source file.
+    /// Warnings forbidden by the policy cannot be suppressed, and are converted into errors before consolidation.
+    /// In strict EraVM mode, nothing can be suppressed, and all messages are converted into errors.
+    /// Otherwise, the severity overrides from the diagnostics settings are applied.
+    ///
+    pub fn get_reports(
+        path: &str,
+        ast: &serde_json::Value,
+        id_paths: &BTreeMap<usize, &String>,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+        solc_version: &Version,
+        suppressed_errors: &[StandardJsonInputSettingsErrorType],
+        suppressed_warnings: &[StandardJsonInputSettingsWarningType],
+        suppressions: &BTreeMap<String, StandardJsonInputSettingsSuppressions>,
+        diagnostics: &StandardJsonInputSettingsDiagnostics,
+        policy: &StandardJsonInputSettingsPolicy,
+        strict_eravm: bool,
+    ) -> Vec<StandardJsonOutputError> {
+        let nodes = match ast.get("nodes").and_then(|nodes| nodes.as_array()) {
+            Some(nodes) => nodes.as_slice(),
+            None => std::slice::from_ref(ast),
+        };
+        let suppressed_errors: &[StandardJsonInputSettingsErrorType] =
+            if strict_eravm { &[] } else { suppressed_errors };
+        let suppressed_warnings: Vec<StandardJsonInputSettingsWarningType> = suppressed_warnings
             .iter()
-            .filter_map(
-                |node| match node.get("nodeType").and_then(|node| node.as_str()) {
-                    Some("ContractDefinition") => Some(node.get("name")?.as_str()?.to_owned()),
-                    _ => None,
-                },
-            )
-            .last()
-            .ok_or_else(|| anyhow::anyhow!("The last contract not found in the AST"))
+            .copied()
+            .filter(|warning_type| !strict_eravm && policy.forbids_warning(*warning_type).is_none())
+            .collect();
+        let suppressions: BTreeMap<String, StandardJsonInputSettingsSuppressions> = if strict_eravm
+        {
+            BTreeMap::new()
+        } else {
+            suppressions
+                .iter()
+                .map(|(path, suppressions)| {
+                    (path.to_owned(), suppressions.without_forbidden(policy))
+                })
+                .collect()
+        };
+
+        let is_abi_coder_v1_default = solc_version.default < semver::Version::new(0, 8, 0)
+            && !nodes.iter().any(Self::is_abi_coder_v2_pragma);
+
+        let mut units: Vec<(String, Vec<StandardJsonOutputError>)> = Vec::new();
+        for node in nodes.iter() {
+            let mut messages = Self::get_messages(
+                node,
+                id_paths,
+                sources,
+                solc_version,
+                is_abi_coder_v1_default,
+                suppressed_errors,
+                suppressed_warnings.as_slice(),
+                &suppressions,
+            );
+            if !strict_eravm {
+                messages.retain(|message| !Self::is_disabled_inline(message, policy, sources));
+            }
+            if messages.is_empty() {
+                continue;
+            }
+            let messages = messages
+                .into_iter()
+                .map(|message| {
+                    match message
+                        .warning_type
+                        .and_then(|warning_type| policy.forbids_warning(warning_type))
+                    {
+                        Some(rule_id) => message.into_policy_violation(rule_id, sources),
+                        None if strict_eravm => message.into_strict_violation(sources),
+                        None => message.with_severity(diagnostics, sources),
+                    }
+                })
+                .collect::<Vec<StandardJsonOutputError>>();
+
+            let unit = match (
+                node.get("nodeType")
+                    .and_then(|node_type| node_type.as_str()),
+                node.get("name").and_then(|name| name.as_str()),
+            ) {
+                (Some("ContractDefinition"), Some(name)) => format!("{path}:{name}"),
+                _ => path.to_owned(),
+            };
+            match units.iter_mut().find(|(name, _)| name == &unit) {
+                Some((_, unit_messages)) => unit_messages.extend(messages),
+                None => units.push((unit, messages)),
+            }
+        }
+
+        units
+            .into_iter()
+            .flat_map(|(unit, messages)| {
+                StandardJsonOutputError::new_unsupported_feature_reports(
+                    unit.as_str(),
+                    messages,
+                    sources,
+                )
+            })
+            .collect()
+    }
+
+    ///
+    /// Whether the message is disabled by the `// zksolc-disable-next-line` comment on the line
+    /// preceding its location.
+    ///
+    /// The comment may list the stable codes of the disabled diagnostics, separated by whitespace
+    /// or commas, and disables all of them if there are none. Hyphens in codes are ignored, so
+    /// `tx-origin` is the same as `txorigin`. Only the diagnostics that can be suppressed globally
+    /// can be disabled, and warnings forbidden by the policy cannot.
+    ///
+    fn is_disabled_inline(
+        message: &StandardJsonOutputError,
+        policy: &StandardJsonInputSettingsPolicy,
+        sources: &BTreeMap<String, StandardJSONInputSource>,
+    ) -> bool {
+        let Some(code) = message.error_code.as_deref() else {
+            return false;
+        };
+        let is_suppressible = match message.warning_type {
+            Some(warning_type) => policy.forbids_warning(warning_type).is_none(),
+            None => StandardJsonInputSettingsErrorType::from_str(code).is_ok(),
+        };
+        if !is_suppressible {
+            return false;
+        }
+
+        let Some(location) = message.source_location.as_ref() else {
+            return false;
+        };
+        let Some(preceding_code) = usize::try_from(location.start)
+            .ok()
+            .and_then(|start| sources.get(location.file.as_str())?.content()?.get(..start))
+        else {
+            return false;
+        };
+        let Some((preceding_lines, _)) = preceding_code.rsplit_once('\n') else {
+            return false;
+        };
+        let previous_line = preceding_lines
+            .rsplit_once('\n')
+            .map(|(_, line)| line)
+            .unwrap_or(preceding_lines);
+
+        let Some(codes) = previous_line
+            .trim()
+            .strip_prefix("//")
+            .and_then(|comment| comment.trim().strip_prefix(Self::DISABLE_NEXT_LINE_COMMENT))
+        else {
+            return false;
+        };
+        if !codes.is_empty() && !codes.starts_with(char::is_whitespace) {
+            return false;
+        }
+        let codes: Vec<String> = codes
+            .split(|character: char| character.is_whitespace() || character == ',')
+            .filter(|code| !code.is_empty())
+            .map(|code| code.replace('-', ""))
+            .collect();
+        codes.is_empty() || codes.iter().any(|disabled| disabled == code)
     }
 }
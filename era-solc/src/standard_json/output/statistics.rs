@@ -0,0 +1,203 @@
+//!
+//! The `solc --standard-json` output message statistics.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+
+use crate::standard_json::input::settings::warning_type::WarningType;
+use crate::standard_json::output::error::Error as OutputError;
+
+///
+/// The `solc --standard-json` output message statistics.
+///
+/// Aggregates the errors and warnings of a finished build by warning type or code, and by source,
+/// so IDE plugins and dashboards can track the EraVM readiness of a codebase over time. The statistics
+/// are serializable, so they can be stored and compared with the ones of later builds.
+///
+/// The consolidated unsupported feature reports are counted per feature usage, as the messages
+/// would have been counted before the consolidation.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Statistics {
+    /// The total numbers of messages.
+    pub total: Counts,
+    /// The numbers of messages, keyed by warning type for the suppressible warnings, by error code
+    /// for the other messages with a code, and by error type otherwise.
+    pub codes: BTreeMap<String, Counts>,
+    /// The numbers of messages, keyed by source path. Messages without a location are not included.
+    pub sources: BTreeMap<String, Counts>,
+}
+
+///
+/// The numbers of errors and warnings.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Counts {
+    /// The number of errors.
+    pub errors: usize,
+    /// The number of warnings.
+    pub warnings: usize,
+}
+
+///
+/// The changes of the statistics relative to a previous build.
+///
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Trend {
+    /// The changes of the total numbers of messages.
+    pub total: Delta,
+    /// The changed numbers of messages, keyed the same way as the statistics.
+    pub codes: BTreeMap<String, Delta>,
+    /// The changed numbers of messages, keyed by source path.
+    pub sources: BTreeMap<String, Delta>,
+}
+
+///
+/// The changes of the numbers of errors and warnings.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Delta {
+    /// The change of the number of errors.
+    pub errors: i64,
+    /// The change of the number of warnings.
+    pub warnings: i64,
+}
+
+impl Statistics {
+    ///
+    /// Aggregates the errors and warnings among `messages`. Other severities are ignored.
+    ///
+    pub fn new(messages: &[OutputError]) -> Self {
+        let mut statistics = Self::default();
+        for message in messages.iter() {
+            let is_error = match message.severity.as_str() {
+                "error" => true,
+                "warning" => false,
+                _ => continue,
+            };
+            if message.unsupported_features.is_empty() {
+                let code = Self::code(
+                    message.warning_type,
+                    message.error_code.as_deref(),
+                    message.r#type.as_str(),
+                );
+                let path = message
+                    .source_location
+                    .as_ref()
+                    .map(|location| location.file.as_str());
+                statistics.add(code, path, is_error);
+                continue;
+            }
+            for feature in message.unsupported_features.iter() {
+                let code = Self::code(
+                    feature.warning_type,
+                    feature.error_code.as_deref(),
+                    message.r#type.as_str(),
+                );
+                if feature.locations.is_empty() {
+                    statistics.add(code, None, is_error);
+                }
+                for location in feature.locations.iter() {
+                    statistics.add(code.clone(), Some(location.file.as_str()), is_error);
+                }
+            }
+        }
+        statistics
+    }
+
+    ///
+    /// Returns the changes relative to the `previous` statistics.
+    ///
+    /// Only the codes and sources with changed numbers of messages are included.
+    ///
+    pub fn trend(&self, previous: &Self) -> Trend {
+        Trend {
+            total: self.total.delta(&previous.total),
+            codes: Self::deltas(&self.codes, &previous.codes),
+            sources: Self::deltas(&self.sources, &previous.sources),
+        }
+    }
+
+    ///
+    /// Counts a message with the `code` located in the source at `path`.
+    ///
+    fn add(&mut self, code: String, path: Option<&str>, is_error: bool) {
+        self.total.add(is_error);
+        self.codes.entry(code).or_default().add(is_error);
+        if let Some(path) = path {
+            self.sources
+                .entry(path.to_owned())
+                .or_default()
+                .add(is_error);
+        }
+    }
+
+    ///
+    /// Returns the key a message is counted by.
+    ///
+    fn code(warning_type: Option<WarningType>, error_code: Option<&str>, r#type: &str) -> String {
+        match (warning_type, error_code) {
+            (Some(warning_type), _) => warning_type.to_string(),
+            (None, Some(error_code)) => error_code.to_owned(),
+            (None, None) => r#type.to_owned(),
+        }
+    }
+
+    ///
+    /// Returns the non-zero changes of the `current` counts relative to the `previous` ones.
+    ///
+    fn deltas(
+        current: &BTreeMap<String, Counts>,
+        previous: &BTreeMap<String, Counts>,
+    ) -> BTreeMap<String, Delta> {
+        current
+            .keys()
+            .chain(previous.keys())
+            .collect::<BTreeSet<&String>>()
+            .into_iter()
+            .filter_map(|key| {
+                let current = current.get(key).copied().unwrap_or_default();
+                let previous = previous.get(key).copied().unwrap_or_default();
+                let delta = current.delta(&previous);
+                (!delta.is_zero()).then(|| (key.to_owned(), delta))
+            })
+            .collect()
+    }
+}
+
+impl Counts {
+    ///
+    /// Counts an error or a warning.
+    ///
+    fn add(&mut self, is_error: bool) {
+        if is_error {
+            self.errors += 1;
+        } else {
+            self.warnings += 1;
+        }
+    }
+
+    ///
+    /// Returns the change relative to the `previous` counts.
+    ///
+    fn delta(&self, previous: &Self) -> Delta {
+        Delta {
+            errors: self.errors as i64 - previous.errors as i64,
+            warnings: self.warnings as i64 - previous.warnings as i64,
+        }
+    }
+}
+
+impl Delta {
+    ///
+    /// Whether the numbers of messages have not changed.
+    ///
+    pub fn is_zero(&self) -> bool {
+        self.errors == 0 && self.warnings == 0
+    }
+}
@@ -0,0 +1,24 @@
+//!
+//! The `solc --standard-json` output summary cache statistics.
+//!
+
+///
+/// The `solc --standard-json` output summary cache statistics.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Cache {
+    /// The number of entries taken from the cache.
+    pub hits: usize,
+    /// The number of entries missing from the cache, which were computed and cached.
+    pub misses: usize,
+}
+
+impl Cache {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(hits: usize, misses: usize) -> Self {
+        Self { hits, misses }
+    }
+}
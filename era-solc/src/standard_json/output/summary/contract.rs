@@ -0,0 +1,25 @@
+//!
+//! The `solc --standard-json` output summary contract.
+//!
+
+///
+/// The `solc --standard-json` output summary contract.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The bytecode size in bytes.
+    pub size: usize,
+    /// The bytecode hash, only set for linked EraVM bytecode.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hash: Option<String>,
+}
+
+impl Contract {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(size: usize, hash: Option<String>) -> Self {
+        Self { size, hash }
+    }
+}
@@ -0,0 +1,94 @@
+//!
+//! The `solc --standard-json` output summary.
+//!
+
+pub mod cache;
+pub mod contract;
+
+use std::collections::BTreeMap;
+
+use crate::standard_json::output::contract::Contract as OutputContract;
+use crate::standard_json::output::error::Error as OutputError;
+
+use self::cache::Cache;
+use self::contract::Contract;
+
+///
+/// The `solc --standard-json` output summary.
+///
+/// Aggregates the project-level figures, so CI dashboards can consume one stable section
+/// instead of walking the whole contracts map.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Summary {
+    /// The total number of contracts with bytecode.
+    pub total_contracts: usize,
+    /// The contract bytecode sizes and hashes, keyed by contract full path.
+    pub contracts: BTreeMap<String, Contract>,
+    /// The number of errors.
+    pub errors: usize,
+    /// The number of warnings.
+    pub warnings: usize,
+    /// The AST check cache statistics, only set if the cache is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ast_cache: Option<Cache>,
+    /// The build cache statistics, only set if the cache is used.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub build_cache: Option<Cache>,
+    /// The time spent in `solc` in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solc_duration_ms: Option<u64>,
+    /// The total compilation time in milliseconds.
+    pub total_duration_ms: u64,
+}
+
+impl Summary {
+    ///
+    /// Summarizes the output `contracts` and `messages`.
+    ///
+    pub fn new(
+        contracts: &BTreeMap<String, BTreeMap<String, OutputContract>>,
+        messages: &[OutputError],
+        ast_cache: Option<Cache>,
+        build_cache: Option<Cache>,
+        solc_duration: Option<std::time::Duration>,
+        total_duration: std::time::Duration,
+    ) -> Self {
+        let contracts: BTreeMap<String, Contract> = contracts
+            .iter()
+            .flat_map(|(path, contracts)| {
+                contracts.iter().filter_map(move |(name, contract)| {
+                    let bytecode = match contract.eravm {
+                        Some(ref eravm) => eravm.bytecode.as_str(),
+                        None => contract.evm.as_ref()?.bytecode.as_ref()?.object.as_str(),
+                    };
+                    if bytecode.is_empty() {
+                        return None;
+                    }
+                    Some((
+                        format!("{path}:{name}"),
+                        Contract::new(bytecode.len() / 2, contract.hash.clone()),
+                    ))
+                })
+            })
+            .collect();
+
+        Self {
+            total_contracts: contracts.len(),
+            contracts,
+            errors: messages
+                .iter()
+                .filter(|message| message.severity == "error")
+                .count(),
+            warnings: messages
+                .iter()
+                .filter(|message| message.severity == "warning")
+                .count(),
+            ast_cache,
+            build_cache,
+            solc_duration_ms: solc_duration.map(|duration| duration.as_millis() as u64),
+            total_duration_ms: total_duration.as_millis() as u64,
+        }
+    }
+}
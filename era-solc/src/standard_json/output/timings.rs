@@ -0,0 +1,81 @@
+//!
+//! The `solc --standard-json` output compilation timings.
+//!
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+///
+/// The source compilation timings.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Source {
+    /// The time spent in the zksolc AST analysis in milliseconds.
+    pub analysis_duration_ms: u64,
+}
+
+///
+/// The contract compilation timings.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Contract {
+    /// The time spent in the zksolc backend, including the LLVM optimizations and code generation, in milliseconds.
+    pub backend_duration_ms: u64,
+}
+
+///
+/// The `solc --standard-json` output compilation timings.
+///
+/// `solc` does not report its timings per source, so its parsing and analysis time is only
+/// available for the whole project. Contracts taken from the build cache are not included.
+///
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Timings {
+    /// The time spent in `solc` in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solc_duration_ms: Option<u64>,
+    /// The source timings, keyed by source path.
+    pub sources: BTreeMap<String, Source>,
+    /// The contract timings, keyed by contract full path.
+    pub contracts: BTreeMap<String, Contract>,
+}
+
+impl Timings {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        solc_duration: Option<Duration>,
+        analysis_durations: &BTreeMap<String, Duration>,
+        backend_durations: &BTreeMap<String, Duration>,
+    ) -> Self {
+        Self {
+            solc_duration_ms: solc_duration.map(|duration| duration.as_millis() as u64),
+            sources: analysis_durations
+                .iter()
+                .map(|(path, duration)| {
+                    (
+                        path.to_owned(),
+                        Source {
+                            analysis_duration_ms: duration.as_millis() as u64,
+                        },
+                    )
+                })
+                .collect(),
+            contracts: backend_durations
+                .iter()
+                .map(|(path, duration)| {
+                    (
+                        path.to_owned(),
+                        Contract {
+                            backend_duration_ms: duration.as_millis() as u64,
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+}
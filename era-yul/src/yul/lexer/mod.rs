@@ -30,9 +30,16 @@ pub struct Lexer {
     location: Location,
     /// The peeked lexeme, waiting to be fetched.
     peeked: Option<Token>,
+    /// The current nesting depth of blocks, function calls, and objects.
+    depth: usize,
+    /// The maximum nesting depth of blocks, function calls, and objects.
+    max_depth: usize,
 }
 
 impl Lexer {
+    /// The default maximum nesting depth.
+    pub const DEFAULT_MAX_DEPTH: usize = 256;
+
     ///
     /// A shortcut constructor.
     ///
@@ -44,9 +51,47 @@ impl Lexer {
             offset: 0,
             location: Location::default(),
             peeked: None,
+            depth: 0,
+            max_depth: Self::DEFAULT_MAX_DEPTH,
         }
     }
 
+    ///
+    /// Sets the maximum nesting depth of blocks, function calls, and objects.
+    ///
+    pub fn with_max_depth(mut self, max_depth: usize) -> Self {
+        self.max_depth = max_depth;
+        self
+    }
+
+    ///
+    /// Enters a nested construct, returning `false` if the maximum nesting depth is exceeded.
+    ///
+    /// The parser is recursive, so the depth is limited to fail gracefully instead of
+    /// overflowing the stack on deeply nested or adversarial inputs.
+    ///
+    pub fn enter(&mut self) -> bool {
+        if self.depth >= self.max_depth {
+            return false;
+        }
+        self.depth += 1;
+        true
+    }
+
+    ///
+    /// Leaves a nested construct.
+    ///
+    pub fn leave(&mut self) {
+        self.depth = self.depth.saturating_sub(1);
+    }
+
+    ///
+    /// Returns the maximum nesting depth.
+    ///
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
     ///
     /// Advances the lexer, returning the next lexeme.
     ///
@@ -61,4 +61,12 @@ pub enum Error {
         /// The list of invalid attributes.
         values: BTreeSet<String>,
     },
+    /// The input is nested deeper than the parser allows.
+    #[error("{location} The input is too deeply nested: the maximum nesting depth is {max_depth}")]
+    TooDeeplyNested {
+        /// The location of the construct exceeding the limit.
+        location: Location,
+        /// The maximum nesting depth.
+        max_depth: usize,
+    },
 }
@@ -8,10 +8,14 @@ pub mod identifier;
 pub mod statement;
 pub mod r#type;
 
+use crate::yul::error::Error;
 use crate::yul::lexer::error::Error as LexerError;
+use crate::yul::lexer::token::location::Location;
 use crate::yul::lexer::token::Token;
 use crate::yul::lexer::Lexer;
 
+use self::error::Error as ParserError;
+
 ///
 /// Returns the `token` value if it is `Some(_)`, otherwise takes the next token from the `stream`.
 ///
@@ -21,3 +25,18 @@ pub fn take_or_next(mut token: Option<Token>, lexer: &mut Lexer) -> Result<Token
         None => lexer.next(),
     }
 }
+
+///
+/// Enters a nested construct at `location`, returning an error if the input is too deeply nested.
+///
+pub fn enter(lexer: &mut Lexer, location: Location) -> Result<(), Error> {
+    if lexer.enter() {
+        Ok(())
+    } else {
+        Err(ParserError::TooDeeplyNested {
+            location,
+            max_depth: lexer.max_depth(),
+        }
+        .into())
+    }
+}
@@ -59,6 +59,8 @@ where
             }
         };
 
+        crate::yul::parser::enter(lexer, location)?;
+
         let mut remaining = None;
 
         loop {
@@ -123,6 +125,8 @@ where
             }
         }
 
+        lexer.leave();
+
         Ok(Self {
             location,
             statements,
@@ -215,4 +219,56 @@ object "Test" {
             .into())
         );
     }
+
+    #[test]
+    fn error_too_deeply_nested_block() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            {
+                {
+                    return(0, 0)
+                }
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned()).with_max_depth(4);
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::TooDeeplyNested {
+                location: Location::new(6, 17),
+                max_depth: 4,
+            }
+            .into())
+        );
+    }
+
+    #[test]
+    fn error_too_deeply_nested_function_call() {
+        let input = r#"
+object "Test" {
+    code {
+        {
+            return(add(add(1, 2), 3), 0)
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned()).with_max_depth(5);
+        let result = Object::<DefaultDialect>::parse(&mut lexer, None);
+        assert_eq!(
+            result,
+            Err(Error::TooDeeplyNested {
+                location: Location::new(5, 24),
+                max_depth: 5,
+            }
+            .into())
+        );
+    }
 }
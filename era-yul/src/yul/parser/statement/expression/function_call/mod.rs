@@ -55,6 +55,8 @@ impl FunctionCall {
             }
         };
 
+        crate::yul::parser::enter(lexer, location)?;
+
         let mut arguments = Vec::new();
         loop {
             let argument = match lexer.next()? {
@@ -86,6 +88,8 @@ impl FunctionCall {
             }
         }
 
+        lexer.leave();
+
         Ok(Self {
             location,
             name,
@@ -192,4 +192,25 @@ where
             Self::Leave(location) => *location,
         }
     }
+
+    ///
+    /// Returns the statement kind, named after its keyword where there is one.
+    ///
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Object(_) => "object",
+            Self::Code(_) => "code",
+            Self::Block(_) => "block",
+            Self::Expression(_) => "expression",
+            Self::FunctionDefinition(_) => "function",
+            Self::VariableDeclaration(_) => "let",
+            Self::Assignment(_) => "assignment",
+            Self::IfConditional(_) => "if",
+            Self::Switch(_) => "switch",
+            Self::ForLoop(_) => "for",
+            Self::Continue(_) => "continue",
+            Self::Break(_) => "break",
+            Self::Leave(_) => "leave",
+        }
+    }
 }
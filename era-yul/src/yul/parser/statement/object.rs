@@ -3,7 +3,6 @@
 //!
 
 use std::collections::BTreeSet;
-use std::collections::HashSet;
 
 use crate::yul::error::Error;
 use crate::yul::lexer::token::lexeme::keyword::Keyword;
@@ -37,7 +36,12 @@ where
     /// The factory dependency objects, which are represented by nested Yul object. The nested
     /// objects are duplicates of the upper-level objects describing the dependencies, so only
     /// their identifiers are preserved. The identifiers are used to address upper-level objects.
-    pub factory_dependencies: HashSet<String>,
+    pub factory_dependencies: BTreeSet<String>,
+    /// The nested factory dependency objects. In the `solc` output, they duplicate the upper-level
+    /// objects, whereas hand-written Yul objects may only define their dependencies here.
+    pub dependency_objects: Vec<Self>,
+    /// The identifiers of the data segments, e.g. `.metadata`.
+    pub data_segments: BTreeSet<String>,
 }
 
 impl<P> Object<P>
@@ -66,6 +70,8 @@ where
             }
         };
 
+        crate::yul::parser::enter(lexer, location)?;
+
         let identifier = match lexer.next()? {
             Token {
                 lexeme: Lexeme::Literal(Literal::String(literal)),
@@ -99,43 +105,9 @@ where
 
         let code = Code::parse(lexer, None)?;
         let mut inner_object = None;
-        let mut factory_dependencies = HashSet::new();
-
-        if !is_runtime_code {
-            inner_object = match lexer.peek()? {
-                Token {
-                    lexeme: Lexeme::Keyword(Keyword::Object),
-                    ..
-                } => {
-                    let mut object = Self::parse(lexer, None)?;
-
-                    if format!("{identifier}_deployed") != object.identifier {
-                        return Err(ParserError::InvalidObjectName {
-                            location: object.location,
-                            expected: format!("{identifier}_deployed"),
-                            found: object.identifier,
-                        }
-                        .into());
-                    }
-
-                    factory_dependencies.extend(object.factory_dependencies.drain());
-                    Some(Box::new(object))
-                }
-                _ => None,
-            };
-
-            if let Token {
-                lexeme: Lexeme::Identifier(identifier),
-                ..
-            } = lexer.peek()?
-            {
-                if identifier.inner.as_str() == "data" {
-                    let _data = lexer.next()?;
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
-                }
-            };
-        }
+        let mut factory_dependencies = BTreeSet::new();
+        let mut dependency_objects = Vec::new();
+        let mut data_segments = BTreeSet::new();
 
         loop {
             match lexer.next()? {
@@ -147,15 +119,47 @@ where
                     lexeme: Lexeme::Keyword(Keyword::Object),
                     ..
                 } => {
-                    let dependency = Self::parse(lexer, Some(token))?;
-                    factory_dependencies.insert(dependency.identifier);
+                    let mut object = Self::parse(lexer, Some(token))?;
+
+                    if !is_runtime_code && inner_object.is_none() {
+                        if format!("{identifier}_deployed") != object.identifier {
+                            return Err(ParserError::InvalidObjectName {
+                                location: object.location,
+                                expected: format!("{identifier}_deployed"),
+                                found: object.identifier,
+                            }
+                            .into());
+                        }
+
+                        factory_dependencies.append(&mut object.factory_dependencies);
+                        dependency_objects.append(&mut object.dependency_objects);
+                        inner_object = Some(Box::new(object));
+                    } else {
+                        factory_dependencies.insert(object.identifier.clone());
+                        dependency_objects.push(object);
+                    }
                 }
                 Token {
-                    lexeme: Lexeme::Identifier(identifier),
+                    lexeme: Lexeme::Identifier(data),
                     ..
-                } if identifier.inner.as_str() == "data" => {
-                    let _identifier = lexer.next()?;
-                    let _metadata = lexer.next()?;
+                } if data.inner.as_str() == "data" => {
+                    match lexer.next()? {
+                        Token {
+                            lexeme: Lexeme::Literal(Literal::String(literal)),
+                            ..
+                        } => {
+                            data_segments.insert(literal.inner);
+                        }
+                        token => {
+                            return Err(ParserError::InvalidToken {
+                                location: token.location,
+                                expected: vec!["{string}"],
+                                found: token.lexeme.to_string(),
+                            }
+                            .into());
+                        }
+                    }
+                    let _value = lexer.next()?;
                 }
                 token => {
                     return Err(ParserError::InvalidToken {
@@ -168,15 +172,32 @@ where
             }
         }
 
+        lexer.leave();
+
         Ok(Self {
             location,
             identifier,
             code,
             inner_object,
             factory_dependencies,
+            dependency_objects,
+            data_segments,
         })
     }
 
+    ///
+    /// Takes the nested factory dependency objects, including the ones nested in the taken objects.
+    ///
+    pub fn take_dependency_objects(&mut self) -> Vec<Self> {
+        let mut objects = Vec::new();
+        for mut object in std::mem::take(&mut self.dependency_objects).into_iter() {
+            let nested = object.take_dependency_objects();
+            objects.push(object);
+            objects.extend(nested);
+        }
+        objects
+    }
+
     ///
     /// Get the list of missing deployable libraries.
     ///
@@ -197,6 +218,60 @@ mod tests {
     use crate::yul::parser::error::Error;
     use crate::yul::parser::statement::object::Object;
 
+    #[test]
+    fn ok_dependency_objects_and_data_segments() {
+        let input = r#"
+object "Deployer" {
+    code {
+        {
+            return(0, 0)
+        }
+    }
+    data "Seed" hex"deadbeef"
+    object "Deployer_deployed" {
+        code {
+            {
+                datacopy(0, dataoffset("Child"), datasize("Child"))
+                return(0, 0)
+            }
+        }
+        object "Child" {
+            code {
+                {
+                    return(0, 0)
+                }
+            }
+            object "Child_deployed" {
+                code {
+                    {
+                        return(0, 0)
+                    }
+                }
+                data ".metadata" hex"a2646970667358"
+            }
+        }
+    }
+}
+    "#;
+
+        let mut lexer = Lexer::new(input.to_owned());
+        let mut object = Object::<DefaultDialect>::parse(&mut lexer, None).expect("Always valid");
+        assert_eq!(
+            object
+                .inner_object
+                .as_ref()
+                .map(|object| object.identifier.as_str()),
+            Some("Deployer_deployed")
+        );
+        assert!(object.factory_dependencies.contains("Child"));
+        assert!(object.data_segments.contains("Seed"));
+
+        let dependency_objects = object.take_dependency_objects();
+        assert_eq!(dependency_objects.len(), 1);
+        assert_eq!(dependency_objects[0].identifier, "Child");
+        assert!(object.dependency_objects.is_empty());
+    }
+
     #[test]
     fn error_invalid_token_object() {
         let input = r#"